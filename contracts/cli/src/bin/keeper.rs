@@ -0,0 +1,348 @@
+//! `keeper` — runs the Kisan-DePIN program's permissionless cranks so
+//! nobody has to send them by hand.
+//!
+//! Every `--interval` seconds it reads chain state, works out which
+//! cranks are due and sends them, signed and paid for by `--keypair`:
+//!
+//!   - `advance_season` once the current season has ended
+//!   - `reset_leaderboard` once the leaderboard period has ended
+//!   - `settle_cover` for cover whose weather report triggered a payout,
+//!     or whose final report stayed within thresholds
+//!   - `settle_subscription` for subscriptions past their settlement time
+//!     with enough escrow for every elapsed epoch, filled from the first
+//!     offtake offer holding enough credits
+//!   - `reclaim_onboarding_bonus` for bonuses past their deadline
+//!   - `cancel_otc` for OTC deals past their expiry, refunding the seller
+//!
+//! The cranks are safe to repeat: each one moves the state it checks
+//! (closes the cover, advances the season or settlement time, zeroes the
+//! bonus), so a second keeper, a scheduler thread calling the same
+//! instructions, or a retry after a dropped confirmation fails without
+//! effect. A failed crank is logged and retried on the next tick.
+//!
+//!     cargo run --release --bin keeper -- --interval 60
+
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::time::Duration;
+
+use anchor_lang::{AccountDeserialize, Discriminator};
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{spl_token, TokenAccount};
+use anyhow::{Context, Result};
+use clap::Parser;
+use kisan_depin::{
+    Cover, FarmerProfile, OfftakeOffer, OtcDeal, RegionConfig, Subscription, WeatherReport,
+};
+use kisan_depin_client::{accounts, fetch, instruction, ix, pda};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_sdk::clock::Clock;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use solana_sdk::sysvar;
+use solana_sdk::transaction::Transaction;
+
+#[derive(Parser)]
+#[command(name = "keeper", version, about)]
+struct Args {
+    /// RPC endpoint
+    #[arg(
+        long,
+        short = 'u',
+        env = "KISAN_RPC_URL",
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+
+    /// Fee payer; also pays the rent of the next season's accounts
+    #[arg(
+        long,
+        short = 'k',
+        env = "KISAN_KEYPAIR",
+        default_value = "~/.config/solana/id.json"
+    )]
+    keypair: String,
+
+    /// Seconds between passes
+    #[arg(long, default_value_t = 60)]
+    interval: u64,
+
+    /// Run a single pass and exit, e.g. from cron
+    #[arg(long)]
+    once: bool,
+}
+
+/// Every program account of type `T`, deserialized. Accounts that fail
+/// to deserialize (an older layout awaiting `migrate_account`) are
+/// skipped.
+async fn scan<T>(rpc: &RpcClient) -> Result<Vec<(Pubkey, T)>>
+where
+    T: AccountDeserialize + Discriminator,
+{
+    let config = RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            0,
+            T::DISCRIMINATOR.to_vec(),
+        ))]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(rpc.commitment()),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    Ok(rpc
+        .get_program_accounts_with_config(&kisan_depin::ID, config)
+        .await?
+        .into_iter()
+        .filter_map(|(key, account)| {
+            T::try_deserialize(&mut account.data.as_slice())
+                .ok()
+                .map(|t| (key, t))
+        })
+        .collect())
+}
+
+/// The cluster's clock, which is what the program checks deadlines
+/// against.
+async fn now(rpc: &RpcClient) -> Result<i64> {
+    let account = rpc.get_account(&sysvar::clock::ID).await?;
+    let clock: Clock = solana_sdk::account::from_account(&account).context("clock sysvar")?;
+    Ok(clock.unix_timestamp)
+}
+
+/// Send one crank, logging rather than returning its failure so the rest
+/// of the pass still runs.
+async fn crank(rpc: &RpcClient, payer: &Keypair, name: &str, ix: Instruction) {
+    let result = async {
+        let blockhash = rpc.get_latest_blockhash().await?;
+        let tx =
+            Transaction::new_signed_with_payer(&[ix], Some(&payer.pubkey()), &[payer], blockhash);
+        rpc.send_and_confirm_transaction(&tx).await
+    }
+    .await;
+    match result {
+        Ok(signature) => println!("{name}: {signature}"),
+        Err(e) => eprintln!("{name} failed: {e}"),
+    }
+}
+
+async fn seasons(rpc: &RpcClient, payer: &Keypair, now: i64) -> Result<()> {
+    let state = fetch::program_state(rpc).await?;
+    let index = state.current_season;
+    let Ok(season) = fetch::season(rpc, index).await else {
+        return Ok(());
+    };
+    if now < season.end {
+        return Ok(());
+    }
+    let ix = ix(
+        accounts::AdvanceSeason {
+            payer: payer.pubkey(),
+            program_state: pda::program_state(),
+            current_season: pda::season(index),
+            next_season: pda::season(index + 1),
+            current_tally: pda::season_tally(index),
+            snapshot: pda::season_snapshot(index),
+            next_tally: pda::season_tally(index + 1),
+            leaderboard: pda::leaderboard(),
+            config: pda::config(),
+            system_program: solana_sdk::system_program::ID,
+        },
+        instruction::AdvanceSeason {},
+    );
+    crank(rpc, payer, &format!("advance_season {index}"), ix).await;
+    Ok(())
+}
+
+async fn leaderboard(rpc: &RpcClient, payer: &Keypair, now: i64) -> Result<()> {
+    let Ok(leaderboard) = fetch::leaderboard(rpc).await else {
+        return Ok(());
+    };
+    if now < leaderboard.reset_at + leaderboard.period_seconds {
+        return Ok(());
+    }
+    let ix = ix(
+        accounts::ResetLeaderboard {
+            leaderboard: pda::leaderboard(),
+        },
+        instruction::ResetLeaderboard {},
+    );
+    crank(rpc, payer, "reset_leaderboard", ix).await;
+    Ok(())
+}
+
+async fn covers(rpc: &RpcClient, payer: &Keypair) -> Result<()> {
+    let mut regions: HashMap<u16, Option<RegionConfig>> = HashMap::new();
+    let mut reports: HashMap<(u16, u32), Option<WeatherReport>> = HashMap::new();
+    for (address, cover) in scan::<Cover>(rpc).await? {
+        let region_code = cover.region_code;
+        if let Entry::Vacant(entry) = regions.entry(region_code) {
+            entry.insert(fetch::account_optional(rpc, &pda::region(region_code)).await?);
+        }
+        let key = (region_code, cover.season);
+        if let Entry::Vacant(entry) = reports.entry(key) {
+            let address = pda::weather_report(region_code, cover.season);
+            entry.insert(fetch::account_optional(rpc, &address).await?);
+        }
+        let (Some(region), Some(report)) = (&regions[&region_code], &reports[&key]) else {
+            continue;
+        };
+        if !region.weather_triggered(report) && !report.is_final {
+            continue;
+        }
+        let ix = ix(
+            accounts::SettleCover {
+                farmer: cover.farmer,
+                cover: address,
+                region_config: pda::region(region_code),
+                weather_report: pda::weather_report(region_code, cover.season),
+                insurance_pool: pda::insurance_pool(),
+            },
+            instruction::SettleCover {},
+        );
+        crank(rpc, payer, &format!("settle_cover {address}"), ix).await;
+    }
+    Ok(())
+}
+
+async fn subscriptions(rpc: &RpcClient, payer: &Keypair, now: i64) -> Result<()> {
+    let subscriptions = scan::<Subscription>(rpc).await?;
+    if subscriptions.is_empty() {
+        return Ok(());
+    }
+    let next_serial = fetch::program_state(rpc).await?.next_serial;
+    // Offers and the credits each still holds, drawn down as this pass
+    // settles against them
+    let mut offers = Vec::new();
+    for (address, offer) in scan::<OfftakeOffer>(rpc).await? {
+        let vault = pda::offtake_vault(&address);
+        if let Some(vault) = fetch::account_optional::<TokenAccount>(rpc, &vault).await? {
+            offers.push((address, offer.farmer, vault.amount));
+        }
+    }
+    for (address, sub) in subscriptions {
+        let Ok((_, due)) = sub.amount_due(now) else {
+            continue;
+        };
+        let escrow = pda::subscription_escrow(&address);
+        let Some(escrow_account) = fetch::account_optional::<TokenAccount>(rpc, &escrow).await?
+        else {
+            continue;
+        };
+        let credits = sub.credits_due(next_serial);
+        if escrow_account.amount < due || credits == 0 {
+            continue;
+        }
+        let Some(offer) = offers.iter_mut().find(|(_, _, held)| *held >= credits) else {
+            continue;
+        };
+        offer.2 -= credits;
+        let (offer, farmer, _) = *offer;
+        let ix = ix(
+            accounts::SettleSubscription {
+                program_state: pda::program_state(),
+                subscription: address,
+                subscription_escrow: escrow,
+                treasury_config: pda::treasury_config(),
+                treasury_usdc: pda::treasury_usdc(),
+                offtake_offer: offer,
+                offtake_vault: pda::offtake_vault(&offer),
+                farmer_usdc_account: get_associated_token_address(&farmer, &escrow_account.mint),
+                buyer_green_account: get_associated_token_address(&sub.buyer, &pda::green_mint()),
+                token_program: spl_token::ID,
+            },
+            instruction::SettleSubscription {},
+        );
+        crank(rpc, payer, &format!("settle_subscription {address}"), ix).await;
+    }
+    Ok(())
+}
+
+async fn onboarding_bonuses(rpc: &RpcClient, payer: &Keypair, now: i64) -> Result<()> {
+    for (address, profile) in scan::<FarmerProfile>(rpc).await? {
+        if profile.onboarding_bonus == 0 || now <= profile.onboarding_deadline {
+            continue;
+        }
+        let ix = ix(
+            accounts::ReclaimOnboardingBonus {
+                farmer_profile: address,
+                program_state: pda::program_state(),
+                green_mint: pda::green_mint(),
+                onboarding_vault: pda::onboarding_vault(),
+                token_program: spl_token::ID,
+            },
+            instruction::ReclaimOnboardingBonus {},
+        );
+        crank(rpc, payer, &format!("reclaim_onboarding_bonus {}", profile.farmer), ix).await;
+    }
+    Ok(())
+}
+
+async fn otc_deals(rpc: &RpcClient, payer: &Keypair, now: i64) -> Result<()> {
+    for (address, otc) in scan::<OtcDeal>(rpc).await? {
+        if now < otc.expiry {
+            continue;
+        }
+        let ix = ix(
+            accounts::CancelOtc {
+                caller: payer.pubkey(),
+                maker: otc.maker,
+                program_state: pda::program_state(),
+                otc: address,
+                otc_escrow: pda::otc_escrow(&address),
+                maker_token_account: get_associated_token_address(&otc.maker, &pda::green_mint()),
+                token_program: spl_token::ID,
+            },
+            instruction::CancelOtc {},
+        );
+        crank(rpc, payer, &format!("cancel_otc {address}"), ix).await;
+    }
+    Ok(())
+}
+
+/// One pass over every crank. Settling cover and subscriptions first
+/// keeps them on the season they were due in.
+async fn pass(rpc: &RpcClient, payer: &Keypair) -> Result<()> {
+    let now = now(rpc).await?;
+    covers(rpc, payer).await?;
+    subscriptions(rpc, payer, now).await?;
+    onboarding_bonuses(rpc, payer, now).await?;
+    otc_deals(rpc, payer, now).await?;
+    seasons(rpc, payer, now).await?;
+    leaderboard(rpc, payer, now).await
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse();
+    let keypair_path = shellexpand(&args.keypair);
+    let payer = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("reading keypair {keypair_path}: {e}"))?;
+    let rpc = RpcClient::new_with_commitment(args.url, CommitmentConfig::confirmed());
+
+    let mut interval = tokio::time::interval(Duration::from_secs(args.interval));
+    loop {
+        interval.tick().await;
+        let result = pass(&rpc, &payer).await;
+        if args.once {
+            return result;
+        }
+        if let Err(e) = result {
+            eprintln!("pass failed: {e:#}");
+        }
+    }
+}
+
+/// Expand a leading `~/` to the home directory.
+fn shellexpand(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
@@ -0,0 +1,572 @@
+//! `simulate` — localnet capacity run for the Kisan-DePIN program.
+//!
+//! Starts `solana-test-validator` with the program loaded (or uses
+//! `--url` with the program's upgrade authority), initializes the program, registers `--farmers` synthetic
+//! farmers and drives `--claims` `verify_and_mint` claims through it,
+//! `--claims-per-tx` to a transaction, `--concurrency` transactions in
+//! flight. Reports throughput, confirmation latency, rent consumed and
+//! how claims spread over slots: every claim write-locks the same
+//! program-wide accounts, so claims per slot is the contention ceiling.
+//!
+//! Claims carry real Groth16 proofs from the client's seeded dev circuit
+//! (`kisan_depin_client::dev_circuit`), registered as the circuit's key,
+//! under Poseidon commitments; aggregate batches need a PLONK or external
+//! verifier and aren't driven here.
+//!
+//!     anchor build && cargo run --release --bin simulate -- --farmers 200 --claims 5000
+
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anchor_lang::{InstructionData, Space, ToAccountMetas};
+use anchor_spl::associated_token::{
+    get_associated_token_address, spl_associated_token_account::instruction as ata,
+};
+use anchor_spl::token::spl_token;
+use anyhow::{bail, Context, Result};
+use clap::Parser;
+use kisan_depin::commitment::{CommitmentOpening, SCHEME_POSEIDON};
+use kisan_depin::payload::ProofPayload;
+use kisan_depin::{compute, FarmerProfile, ProofRecord, ProofSystem, CLAIM_INDEX_PAGE_LEN};
+use kisan_depin_client::{accounts, bootstrap, dev_circuit, instruction, ix, pda};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::packet::PACKET_DATA_SIZE;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signature, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::Transaction;
+use tokio::sync::Semaphore;
+
+const REGION: u16 = 1;
+const CIRCUIT: u16 = 1;
+/// Lamports given to each farmer for proof-record rent and fees
+const FARMER_FUNDING: u64 = LAMPORTS_PER_SOL;
+
+#[derive(Parser)]
+#[command(
+    name = "simulate",
+    version,
+    about = "Localnet capacity run for Kisan-DePIN"
+)]
+struct Args {
+    /// Use a running validator instead of starting one
+    #[arg(long, short = 'u')]
+    url: Option<String>,
+
+    /// Upgrade authority keypair, which `initialize` must be signed by;
+    /// required with `--url`, generated for the validator it starts
+    #[arg(long, short = 'k')]
+    authority: Option<PathBuf>,
+
+    /// Program binary loaded into the validator it starts
+    #[arg(long, default_value = "target/deploy/kisan_depin.so")]
+    program: PathBuf,
+
+    /// Ledger directory of the validator it starts (reset on start)
+    #[arg(long, default_value = "target/simulate-ledger")]
+    ledger: PathBuf,
+
+    #[arg(long, default_value_t = 100)]
+    farmers: usize,
+
+    #[arg(long, default_value_t = 1_000)]
+    claims: usize,
+
+    /// `verify_and_mint` instructions per transaction
+    #[arg(long, default_value_t = 1)]
+    claims_per_tx: usize,
+
+    /// Transactions in flight
+    #[arg(long, default_value_t = 64)]
+    concurrency: usize,
+}
+
+/// `solana-test-validator`, killed on drop.
+struct Validator(Child);
+
+impl Validator {
+    fn start(program: &PathBuf, ledger: &PathBuf, authority: &Pubkey) -> Result<Validator> {
+        if !program.exists() {
+            bail!("{} not found; run `anchor build` first", program.display());
+        }
+        let child = Command::new("solana-test-validator")
+            .arg("--reset")
+            .arg("--quiet")
+            .arg("--ledger")
+            .arg(ledger)
+            .arg("--upgradeable-program")
+            .arg(kisan_depin::ID.to_string())
+            .arg(program)
+            .arg(authority.to_string())
+            .stdout(Stdio::null())
+            .spawn()
+            .context("starting solana-test-validator")?;
+        Ok(Validator(child))
+    }
+}
+
+impl Drop for Validator {
+    fn drop(&mut self) {
+        let _ = self.0.kill();
+        let _ = self.0.wait();
+    }
+}
+
+async fn send(rpc: &RpcClient, ixs: &[Instruction], signers: &[&Keypair]) -> Result<Signature> {
+    let blockhash = rpc.get_latest_blockhash().await?;
+    let tx =
+        Transaction::new_signed_with_payer(ixs, Some(&signers[0].pubkey()), signers, blockhash);
+    Ok(rpc.send_and_confirm_transaction(&tx).await?)
+}
+
+async fn wait_for_validator(rpc: &RpcClient) -> Result<()> {
+    let deadline = Instant::now() + Duration::from_secs(60);
+    while rpc.get_health().await.is_err() {
+        if Instant::now() > deadline {
+            bail!("validator did not come up within 60s");
+        }
+        tokio::time::sleep(Duration::from_millis(500)).await;
+    }
+    Ok(())
+}
+
+async fn initialize(rpc: &RpcClient, authority: &Keypair) -> Result<()> {
+    let me = authority.pubkey();
+    let system_program = solana_sdk::system_program::ID;
+    let bootstrap = bootstrap(&me);
+    send(rpc, &bootstrap[..2], &[authority]).await?;
+    let mut setup = bootstrap[2..].to_vec();
+    setup.extend([
+        ix(
+            accounts::InitializeSeasons {
+                authority: me,
+                program_state: pda::program_state(),
+                admin_log: pda::admin_log(),
+                season: pda::season(0),
+                season_tally: pda::season_tally(0),
+                config: pda::config(),
+                system_program,
+            },
+            instruction::InitializeSeasons {
+                duration: 365 * 86_400,
+                reward_amount: 1_000_000_000,
+                mint_cap: u64::MAX / 2,
+                max_claims_per_farmer: u32::MAX,
+            },
+        ),
+        ix(
+            accounts::ConfigureRegion {
+                authority: me,
+                program_state: pda::program_state(),
+                admin_log: pda::admin_log(),
+                region_config: pda::region(REGION),
+                system_program,
+            },
+            instruction::ConfigureRegion {
+                region_code: REGION,
+                require_identity: false,
+            },
+        ),
+        ix(
+            accounts::ConfigureCircuit {
+                authority: me,
+                program_state: pda::program_state(),
+                admin_log: pda::admin_log(),
+                circuit_config: pda::circuit(CIRCUIT),
+                system_program,
+            },
+            instruction::ConfigureCircuit {
+                circuit_id: CIRCUIT,
+                active: true,
+                green_enabled: true,
+                water_amount: 0,
+                soil_amount: 0,
+                methodology_id: 0,
+                proof_system: ProofSystem::Groth16,
+            },
+        ),
+    ]);
+    send(rpc, &setup, &[authority]).await?;
+    send(
+        rpc,
+        &[ix(
+            accounts::SetGroth16VerifyingKey {
+                authority: me,
+                program_state: pda::program_state(),
+                admin_log: pda::admin_log(),
+                circuit_config: pda::circuit(CIRCUIT),
+                groth16_vk: pda::groth16_vk(CIRCUIT),
+                previous_groth16_vk: pda::previous_groth16_vk(CIRCUIT),
+                system_program,
+            },
+            instruction::SetGroth16VerifyingKey {
+                key: dev_circuit::verifying_key(1),
+            },
+        )],
+        &[authority],
+    )
+    .await?;
+    Ok(())
+}
+
+/// Fund, register and open a $GREEN account for one farmer.
+async fn register(rpc: &RpcClient, authority: &Keypair, farmer: &Keypair) -> Result<()> {
+    let me = authority.pubkey();
+    let wallet = farmer.pubkey();
+    send(
+        rpc,
+        &[
+            system_instruction::transfer(&me, &wallet, FARMER_FUNDING),
+            ix(
+                accounts::RegisterFarmer {
+                    payer: me,
+                    farmer: wallet,
+                    program_state: pda::program_state(),
+                    personhood_verifier: None,
+                    personhood_record: None,
+                    referral_code: None,
+                    region_config: pda::region(REGION),
+                    farmer_profile: pda::farmer_profile(&wallet),
+                    config: pda::config(),
+                    green_mint: None,
+                    onboarding_vault: None,
+                    token_program: None,
+                    system_program: solana_sdk::system_program::ID,
+                },
+                instruction::RegisterFarmer {
+                    region_code: REGION,
+                    personhood_nullifier: [0; 32],
+                },
+            ),
+            ata::create_associated_token_account_idempotent(
+                &me,
+                &wallet,
+                &pda::green_mint(),
+                &spl_token::ID,
+            ),
+        ],
+        &[authority, farmer],
+    )
+    .await?;
+    Ok(())
+}
+
+/// Claim number `n` by `farmer`, paid and signed by the farmer. `position`
+/// is how many claims the farmer has minted before this one, which picks
+/// its claim index page.
+fn claim(farmer: &Pubkey, n: usize, position: usize) -> Instruction {
+    let mut nonce = [0u8; 32];
+    nonce[24..].copy_from_slice(&(n as u64).to_be_bytes());
+    let opening = CommitmentOpening {
+        scheme: SCHEME_POSEIDON,
+        parcel: pda::parcel(farmer, 0),
+        nonce,
+    };
+    let commitment = opening.commit(CIRCUIT, farmer, 0).unwrap();
+    Instruction {
+        program_id: kisan_depin::ID,
+        accounts: accounts::VerifyAndMint {
+            payer: *farmer,
+            claimant: *farmer,
+            farmer: *farmer,
+            delegation: None,
+            session_key: None,
+            relayer_info: None,
+            rent_pool: None,
+            program_state: pda::program_state(),
+            green_mint: pda::green_mint(),
+            farmer_token_account: get_associated_token_address(farmer, &pda::green_mint()),
+            custodial_link: None,
+            payout_split: pda::payout_split(&opening.parcel),
+            landowner_token_account: None,
+            farmer_profile: Some(pda::farmer_profile(farmer)),
+            claim_index: Some(pda::claim_index(farmer, (position / CLAIM_INDEX_PAGE_LEN) as u64)),
+            config: pda::config(),
+            region_config: Some(pda::region(REGION)),
+            identity_attestation: None,
+            pm_kisan_attestation: None,
+            referral_code: None,
+            referrer_token_account: None,
+            onboarding_vault: None,
+            proof_record: pda::proof_record(&commitment),
+            leaderboard: pda::leaderboard(),
+            season: pda::season(0),
+            season_tally: pda::season_tally(0),
+            mint_stats: pda::mint_stats(),
+            circuit_config: pda::circuit(CIRCUIT),
+            feature_flags: pda::feature_flags(),
+            pricing_config: None,
+            price_feed: None,
+            groth16_vk: Some(pda::groth16_vk(CIRCUIT)),
+            previous_groth16_vk: None,
+            plonk_vk: None,
+            previous_plonk_vk: None,
+            pre_commit: None,
+            verifier_program: None,
+            water_mint: None,
+            water_token_account: None,
+            soil_mint: None,
+            soil_token_account: None,
+            receipt_config: None,
+            receipt_authority: None,
+            receipt_tree_config: None,
+            receipt_merkle_tree: None,
+            log_wrapper: None,
+            compression_program: None,
+            bubblegum_program: None,
+            instructions_sysvar: None,
+            cpi_integrator: None,
+            memo_program: None,
+            token_program: spl_token::ID,
+            system_program: solana_sdk::system_program::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::VerifyAndMint {
+            payload: ProofPayload::groth16(&dev_circuit::prove(&commitment), commitment.to_vec()),
+            compliance_commitment: commitment,
+            opening,
+            evidence_cid: None,
+        }
+        .data(),
+    }
+}
+
+fn percentile(sorted: &[Duration], p: f64) -> Duration {
+    if sorted.is_empty() {
+        return Duration::ZERO;
+    }
+    sorted[((sorted.len() - 1) as f64 * p).round() as usize]
+}
+
+async fn balance(rpc: &RpcClient, keys: &[Pubkey]) -> Result<u64> {
+    let mut total = 0;
+    for chunk in keys.chunks(100) {
+        for account in rpc
+            .get_multiple_accounts(chunk)
+            .await?
+            .into_iter()
+            .flatten()
+        {
+            total += account.lamports;
+        }
+    }
+    Ok(total)
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse();
+    if args.claims_per_tx == 0 || args.farmers == 0 {
+        bail!("--farmers and --claims-per-tx must be positive");
+    }
+    let authority = Arc::new(match (&args.authority, &args.url) {
+        (Some(path), _) => read_keypair_file(path)
+            .map_err(|e| anyhow::anyhow!("reading keypair {}: {e}", path.display()))?,
+        (None, None) => Keypair::new(),
+        (None, Some(_)) => bail!("--url needs the program's upgrade authority as --authority"),
+    });
+    let _validator = match args.url {
+        Some(_) => None,
+        None => Some(Validator::start(
+            &args.program,
+            &args.ledger,
+            &authority.pubkey(),
+        )?),
+    };
+    let url = args.url.unwrap_or_else(|| "http://127.0.0.1:8899".into());
+    let rpc = Arc::new(RpcClient::new_with_commitment(
+        url,
+        CommitmentConfig::confirmed(),
+    ));
+    wait_for_validator(&rpc).await?;
+
+    // ── Setup ──
+    let funding = (args.farmers as u64 + 10) * FARMER_FUNDING;
+    let airdrop = rpc.request_airdrop(&authority.pubkey(), funding).await?;
+    while !rpc.confirm_transaction(&airdrop).await? {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+    }
+    initialize(&rpc, &authority).await?;
+    println!("program initialized");
+
+    let started = Instant::now();
+    let farmers: Vec<Arc<Keypair>> = (0..args.farmers)
+        .map(|_| Arc::new(Keypair::new()))
+        .collect();
+    let gate = Arc::new(Semaphore::new(args.concurrency));
+    let mut tasks = Vec::new();
+    for farmer in &farmers {
+        let (rpc, authority, farmer, gate) =
+            (rpc.clone(), authority.clone(), farmer.clone(), gate.clone());
+        tasks.push(tokio::spawn(async move {
+            let _permit = gate.acquire().await;
+            register(&rpc, &authority, &farmer).await
+        }));
+    }
+    for task in tasks {
+        task.await??;
+    }
+    let registration = started.elapsed();
+    println!(
+        "registered {} farmers in {:.1}s ({:.1}/s)",
+        args.farmers,
+        registration.as_secs_f64(),
+        args.farmers as f64 / registration.as_secs_f64()
+    );
+
+    // ── Claims ──
+    let budget = compute::VERIFY_AND_MINT * args.claims_per_tx as u32;
+    let sample = farmers[0].pubkey();
+    let mut ixs = vec![ComputeBudgetInstruction::set_compute_unit_limit(budget)];
+    ixs.extend((0..args.claims_per_tx).map(|n| claim(&sample, n, n)));
+    let size = 1
+        + 64
+        + Transaction::new_with_payer(&ixs, Some(&sample))
+            .message_data()
+            .len();
+    if size > PACKET_DATA_SIZE {
+        bail!(
+            "{} claims make a {size}-byte transaction, over the {PACKET_DATA_SIZE}-byte limit",
+            args.claims_per_tx
+        );
+    }
+
+    let wallets: Vec<Pubkey> = farmers.iter().map(|f| f.pubkey()).collect();
+    let before = balance(&rpc, &wallets).await?;
+    let started = Instant::now();
+    let mut tasks = Vec::new();
+    for (batch, first) in (0..args.claims).step_by(args.claims_per_tx).enumerate() {
+        let count = args.claims_per_tx.min(args.claims - first);
+        // One farmer per transaction: it signs and pays for its claims
+        let farmer = farmers[batch % farmers.len()].clone();
+        // Where this batch starts in the farmer's claim history, assuming
+        // its earlier batches landed first. One that lands out of order
+        // across a claim index page boundary fails like any other error.
+        let position = (batch / farmers.len()) * args.claims_per_tx;
+        let (rpc, gate) = (rpc.clone(), gate.clone());
+        tasks.push(tokio::spawn(async move {
+            let _permit = gate.acquire().await;
+            let mut ixs = vec![ComputeBudgetInstruction::set_compute_unit_limit(budget)];
+            ixs.extend(
+                (first..first + count).map(|n| claim(&farmer.pubkey(), n, position + n - first)),
+            );
+            let sent = Instant::now();
+            let result = send(&rpc, &ixs, &[&farmer]).await;
+            (count, sent.elapsed(), result)
+        }));
+    }
+
+    let mut latencies = Vec::new();
+    let mut landed = Vec::new();
+    let mut claims_landed = 0;
+    let mut errors: HashMap<String, usize> = HashMap::new();
+    for task in tasks {
+        let (count, latency, result) = task.await?;
+        match result {
+            Ok(signature) => {
+                latencies.push(latency);
+                landed.push((signature, count));
+                claims_landed += count;
+            }
+            Err(e) => *errors.entry(e.to_string()).or_default() += count,
+        }
+    }
+    let elapsed = started.elapsed();
+    let after = balance(&rpc, &wallets).await?;
+
+    // Claims per slot, from the confirmed signatures
+    let mut per_slot: BTreeMap<u64, usize> = BTreeMap::new();
+    for chunk in landed.chunks(256) {
+        let signatures: Vec<Signature> = chunk.iter().map(|(s, _)| *s).collect();
+        let statuses = rpc.get_signature_statuses(&signatures).await?.value;
+        for (status, (_, count)) in statuses.iter().zip(chunk) {
+            if let Some(status) = status {
+                *per_slot.entry(status.slot).or_default() += count;
+            }
+        }
+    }
+
+    // ── Report ──
+    latencies.sort();
+    let proof_rent = rpc
+        .get_minimum_balance_for_rent_exemption(8 + ProofRecord::INIT_SPACE)
+        .await?;
+    let profile_rent = rpc
+        .get_minimum_balance_for_rent_exemption(8 + FarmerProfile::INIT_SPACE)
+        .await?;
+    let token_rent = rpc
+        .get_minimum_balance_for_rent_exemption(spl_token::state::Account::LEN)
+        .await?;
+    let sol = |lamports: u64| lamports as f64 / LAMPORTS_PER_SOL as f64;
+
+    println!();
+    println!(
+        "claims            {} landed / {} sent",
+        claims_landed, args.claims
+    );
+    println!(
+        "transactions      {} landed, {} claims each, {} in flight",
+        landed.len(),
+        args.claims_per_tx,
+        args.concurrency
+    );
+    println!(
+        "throughput        {:.1} claims/s over {:.1}s",
+        claims_landed as f64 / elapsed.as_secs_f64(),
+        elapsed.as_secs_f64()
+    );
+    println!(
+        "confirmation      p50 {:?}  p95 {:?}  p99 {:?}  max {:?}",
+        percentile(&latencies, 0.50),
+        percentile(&latencies, 0.95),
+        percentile(&latencies, 0.99),
+        latencies.last().copied().unwrap_or_default()
+    );
+    if let (Some((first, _)), Some((last, _))) =
+        (per_slot.first_key_value(), per_slot.last_key_value())
+    {
+        let busiest = per_slot.values().max().copied().unwrap_or_default();
+        println!(
+            "slots             {} with claims over {} slots; busiest slot {} claims",
+            per_slot.len(),
+            last - first + 1,
+            busiest
+        );
+    }
+    println!(
+        "rent              {:.6} SOL per claim (proof record), {:.6} SOL per farmer (profile + ATA)",
+        sol(proof_rent),
+        sol(profile_rent + token_rent)
+    );
+    println!(
+        "farmer spend      {:.4} SOL total ({:.6} per landed claim, fees included)",
+        sol(before - after),
+        sol((before - after) / claims_landed.max(1) as u64)
+    );
+    println!(
+        "hot accounts      program_state {}, green_mint {}, leaderboard {}, season {}, \
+         mint_stats {}",
+        pda::program_state(),
+        pda::green_mint(),
+        pda::leaderboard(),
+        pda::season(0),
+        pda::mint_stats()
+    );
+    if !errors.is_empty() {
+        println!("failures:");
+        let mut errors: Vec<_> = errors.into_iter().collect();
+        errors.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        for (error, count) in errors.iter().take(5) {
+            println!("  {count:>6}  {error}");
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,824 @@
+//! `kisan` — admin and field-operator CLI for the Kisan-DePIN program.
+//!
+//! Reads snarkjs output (`proof.json`, `public.json`,
+//! `verification_key.json`) directly and signs with a Solana CLI keypair.
+
+use std::path::PathBuf;
+
+use anchor_spl::associated_token::{
+    get_associated_token_address, spl_associated_token_account::instruction as ata,
+};
+use anchor_spl::token::spl_token;
+use anyhow::{bail, Context, Result};
+use clap::{Parser, Subcommand, ValueEnum};
+use kisan_depin::commitment::{CommitmentOpening, SCHEME_POSEIDON, SCHEME_SHA256};
+use kisan_depin::{
+    AllocationKind, AllocationTerms, CircuitConfig, Distribution, PreCommit, ProofSystem, Season,
+    TreasuryConfig, TreasuryWithdrawal, ALLOCATION_KINDS,
+};
+use kisan_depin_client::claim::{self, VerifyAndMint};
+use kisan_depin_client::{accounts, fetch, instruction, ix, pda, snarkjs};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use solana_sdk::transaction::Transaction;
+
+#[derive(Parser)]
+#[command(name = "kisan", version, about)]
+struct Cli {
+    /// RPC endpoint
+    #[arg(
+        long,
+        short = 'u',
+        env = "KISAN_RPC_URL",
+        default_value = "http://127.0.0.1:8899"
+    )]
+    url: String,
+
+    /// Signer keypair (authority for admin commands, farmer for claims)
+    #[arg(
+        long,
+        short = 'k',
+        env = "KISAN_KEYPAIR",
+        default_value = "~/.config/solana/id.json"
+    )]
+    keypair: String,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the program state and the $GREEN mint, minting the reserved
+    /// allocations into their vesting escrows
+    Initialize {
+        /// CSV of `kind,beneficiary,amount,cliff,duration` lines, kind one
+        /// of community, team, investors or ecosystem, amounts in $GREEN
+        /// base units and times in seconds; omitted kinds reserve nothing
+        #[arg(long)]
+        cap_table: Option<PathBuf>,
+    },
+
+    /// Create or update a circuit's configuration
+    RegisterCircuit {
+        #[arg(long)]
+        id: u16,
+        #[arg(long, value_enum, default_value = "groth16")]
+        proof_system: ProofSystemArg,
+        /// Pay no $GREEN for this circuit's claims
+        #[arg(long)]
+        no_green: bool,
+        /// $WATER per claim
+        #[arg(long, default_value_t = 0)]
+        water: u64,
+        /// $SOIL per claim
+        #[arg(long, default_value_t = 0)]
+        soil: u64,
+        /// Registry methodology of issued credits
+        #[arg(long, default_value_t = 0)]
+        methodology: u16,
+        /// Register without accepting claims yet
+        #[arg(long)]
+        inactive: bool,
+    },
+
+    /// Upload a snarkjs Groth16 or PLONK verification key for a circuit
+    UploadVk {
+        #[arg(long)]
+        circuit: u16,
+        /// `verification_key.json` from `snarkjs zkey export verificationkey`
+        #[arg(long)]
+        vk: PathBuf,
+        /// Rotate: keep the current key valid for this many more slots
+        #[arg(long)]
+        overlap_slots: Option<u64>,
+    },
+
+    /// Stop (or with --resume, restart) claims against a circuit
+    Pause {
+        #[arg(long)]
+        circuit: u16,
+        #[arg(long)]
+        resume: bool,
+    },
+
+    /// Print program, season and optionally farmer statistics
+    Stats {
+        /// Farmer wallet to include
+        #[arg(long)]
+        farmer: Option<Pubkey>,
+    },
+
+    /// Submit a Groth16 claim as the signing farmer
+    VerifyAndMint {
+        /// `proof.json` from `snarkjs groth16 prove`
+        #[arg(long)]
+        proof: PathBuf,
+        /// `public.json`; the first input is the commitment
+        #[arg(long)]
+        public: PathBuf,
+        #[arg(long)]
+        circuit: u16,
+        /// Parcel the claim is about
+        #[arg(long)]
+        parcel: Pubkey,
+        /// Commitment nonce, 64 hex characters
+        #[arg(long)]
+        nonce: String,
+        #[arg(long, value_enum, default_value = "sha256")]
+        scheme: SchemeArg,
+        /// Send the points compressed, halving their size
+        #[arg(long)]
+        compressed: bool,
+        /// Attach a memo describing the claim (circuit, season, region)
+        #[arg(long)]
+        memo: bool,
+    },
+
+    /// Timestamp a claim's commitment, hidden, ahead of `verify-and-mint`,
+    /// which then reveals it
+    PreCommit {
+        /// Commitment, 64 hex characters
+        #[arg(long)]
+        commitment: String,
+    },
+
+    /// Close a proof record past its retention period, refunding its rent
+    CloseProof {
+        /// Commitment, 64 hex characters
+        #[arg(long)]
+        commitment: String,
+    },
+
+    /// Freeze (or with --thaw, thaw) a $GREEN token account
+    Freeze {
+        /// $GREEN token account
+        #[arg(long)]
+        account: Pubkey,
+        /// SHA-256 of the case record, 64 hex characters
+        #[arg(long)]
+        reason: String,
+        #[arg(long)]
+        thaw: bool,
+    },
+
+    /// Set the lifetime cap on `distribute`
+    IncentivesBudget {
+        /// $GREEN base units
+        #[arg(long)]
+        amount: u64,
+    },
+
+    /// Mint pilot incentives to every wallet in a CSV of `wallet,amount`
+    /// lines (amounts in $GREEN base units), creating token accounts as
+    /// needed
+    Distribute {
+        #[arg(long)]
+        file: PathBuf,
+    },
+
+    /// Withdraw treasury USDC within the daily limit
+    TreasuryWithdraw {
+        /// USDC base units
+        #[arg(long)]
+        amount: u64,
+        /// Destination USDC token account
+        #[arg(long)]
+        to: Pubkey,
+    },
+
+    /// Propose a timelocked treasury withdrawal of any size
+    TreasuryPropose {
+        /// USDC base units
+        #[arg(long)]
+        amount: u64,
+        /// Destination USDC token account
+        #[arg(long)]
+        to: Pubkey,
+    },
+
+    /// Execute a proposed treasury withdrawal once its timelock has passed
+    TreasuryExecute {
+        /// Proposal id
+        #[arg(long)]
+        id: u64,
+        /// The treasury guardian's keypair, co-signing the withdrawal
+        #[arg(long)]
+        guardian: String,
+    },
+
+    /// Close every program account and zero the state's counters
+    #[cfg(feature = "devnet")]
+    DevnetReset,
+
+    /// Mint $GREEN to a wallet, creating its token account if needed
+    #[cfg(feature = "devnet")]
+    DevnetAirdrop {
+        #[arg(long)]
+        to: Pubkey,
+        /// Whole $GREEN
+        #[arg(long, default_value_t = 100)]
+        amount: u64,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum ProofSystemArg {
+    Groth16,
+    Plonk,
+    External,
+}
+
+impl From<ProofSystemArg> for ProofSystem {
+    fn from(arg: ProofSystemArg) -> Self {
+        match arg {
+            ProofSystemArg::Groth16 => ProofSystem::Groth16,
+            ProofSystemArg::Plonk => ProofSystem::Plonk,
+            ProofSystemArg::External => ProofSystem::External,
+        }
+    }
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum SchemeArg {
+    Sha256,
+    Poseidon,
+}
+
+fn hex32(s: &str) -> Result<[u8; 32]> {
+    let s = s.trim_start_matches("0x");
+    if s.len() != 64 {
+        bail!("expected 64 hex characters, got {}", s.len());
+    }
+    let mut out = [0u8; 32];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).context("invalid hex")?;
+    }
+    Ok(out)
+}
+
+fn read(path: &PathBuf) -> Result<String> {
+    std::fs::read_to_string(path).with_context(|| format!("reading {}", path.display()))
+}
+
+async fn send(rpc: &RpcClient, signer: &Keypair, ixs: &[Instruction]) -> Result<()> {
+    let blockhash = rpc.get_latest_blockhash().await?;
+    let tx = Transaction::new_signed_with_payer(ixs, Some(&signer.pubkey()), &[signer], blockhash);
+    let signature = rpc.send_and_confirm_transaction(&tx).await?;
+    println!("{signature}");
+    Ok(())
+}
+
+/// Recipients paid per `distribute` transaction, with their token-account
+/// creation, to stay under the transaction size limit
+const DISTRIBUTE_BATCH: usize = 8;
+
+/// Parse `wallet,amount` lines; blank lines and `#` comments are skipped.
+fn parse_distribution(csv: &str) -> Result<Vec<Distribution>> {
+    let mut out = Vec::new();
+    for (n, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (wallet, amount) = line
+            .split_once(',')
+            .with_context(|| format!("line {}: expected `wallet,amount`", n + 1))?;
+        out.push(Distribution {
+            wallet: wallet.trim().parse().with_context(|| format!("line {}: wallet", n + 1))?,
+            amount: amount.trim().parse().with_context(|| format!("line {}: amount", n + 1))?,
+        });
+    }
+    Ok(out)
+}
+
+/// Parse `kind,beneficiary,amount,cliff,duration` lines; blank lines and
+/// `#` comments are skipped.
+fn parse_cap_table(csv: &str) -> Result<[AllocationTerms; ALLOCATION_KINDS]> {
+    let mut out = [AllocationTerms::default(); ALLOCATION_KINDS];
+    for (n, line) in csv.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+        let [kind, beneficiary, amount, cliff, duration] = fields[..] else {
+            bail!("line {}: expected `kind,beneficiary,amount,cliff,duration`", n + 1);
+        };
+        let kind = match kind {
+            "community" => AllocationKind::Community,
+            "team" => AllocationKind::Team,
+            "investors" => AllocationKind::Investors,
+            "ecosystem" => AllocationKind::Ecosystem,
+            other => bail!("line {}: unknown allocation `{other}`", n + 1),
+        };
+        out[kind as usize] = AllocationTerms {
+            beneficiary: beneficiary
+                .parse()
+                .with_context(|| format!("line {}: beneficiary", n + 1))?,
+            amount: amount.parse().with_context(|| format!("line {}: amount", n + 1))?,
+            cliff: cliff.parse().with_context(|| format!("line {}: cliff", n + 1))?,
+            duration: duration.parse().with_context(|| format!("line {}: duration", n + 1))?,
+        };
+    }
+    Ok(out)
+}
+
+/// Genesis hash of mainnet-beta, where the devnet instructions must never run
+#[cfg(feature = "devnet")]
+const MAINNET_GENESIS_HASH: &str = "5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d";
+
+/// Writable accounts closed per `reset_state` transaction
+#[cfg(feature = "devnet")]
+const RESET_BATCH: usize = 24;
+
+#[cfg(feature = "devnet")]
+async fn ensure_not_mainnet(rpc: &RpcClient) -> Result<()> {
+    if rpc.get_genesis_hash().await?.to_string() == MAINNET_GENESIS_HASH {
+        bail!("refusing to run a devnet instruction against mainnet-beta");
+    }
+    Ok(())
+}
+
+fn configure_circuit(authority: Pubkey, circuit: &CircuitConfig, active: bool) -> Instruction {
+    ix(
+        accounts::ConfigureCircuit {
+            authority,
+            program_state: pda::program_state(),
+            admin_log: pda::admin_log(),
+            circuit_config: pda::circuit(circuit.circuit_id),
+            system_program: solana_sdk::system_program::ID,
+        },
+        instruction::ConfigureCircuit {
+            circuit_id: circuit.circuit_id,
+            active,
+            green_enabled: circuit.green_enabled,
+            water_amount: circuit.water_amount,
+            soil_amount: circuit.soil_amount,
+            methodology_id: circuit.methodology_id,
+            proof_system: circuit.proof_system,
+        },
+    )
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let keypair_path = shellexpand(&cli.keypair);
+    let signer = read_keypair_file(&keypair_path)
+        .map_err(|e| anyhow::anyhow!("reading keypair {keypair_path}: {e}"))?;
+    let rpc = RpcClient::new_with_commitment(cli.url, CommitmentConfig::confirmed());
+    let me = signer.pubkey();
+
+    match cli.command {
+        Command::Initialize { cap_table } => {
+            let allocations = match cap_table {
+                Some(file) => parse_cap_table(&read(&file)?)?,
+                None => Default::default(),
+            };
+            let ix = ix(
+                accounts::Initialize {
+                    authority: me,
+                    program: kisan_depin_client::PROGRAM_ID,
+                    program_data: pda::program_data(),
+                    program_state: pda::program_state(),
+                    green_mint: pda::green_mint(),
+                    cap_table: pda::cap_table(),
+                    community_vault: pda::allocation_vault(AllocationKind::Community),
+                    team_vault: pda::allocation_vault(AllocationKind::Team),
+                    investors_vault: pda::allocation_vault(AllocationKind::Investors),
+                    ecosystem_vault: pda::allocation_vault(AllocationKind::Ecosystem),
+                    token_program: spl_token::ID,
+                    system_program: solana_sdk::system_program::ID,
+                    rent: solana_sdk::sysvar::rent::ID,
+                },
+                instruction::Initialize { allocations },
+            );
+            send(&rpc, &signer, &[ix]).await
+        }
+
+        Command::RegisterCircuit {
+            id,
+            proof_system,
+            no_green,
+            water,
+            soil,
+            methodology,
+            inactive,
+        } => {
+            let ix = ix(
+                accounts::ConfigureCircuit {
+                    authority: me,
+                    program_state: pda::program_state(),
+                    admin_log: pda::admin_log(),
+                    circuit_config: pda::circuit(id),
+                    system_program: solana_sdk::system_program::ID,
+                },
+                instruction::ConfigureCircuit {
+                    circuit_id: id,
+                    active: !inactive,
+                    green_enabled: !no_green,
+                    water_amount: water,
+                    soil_amount: soil,
+                    methodology_id: methodology,
+                    proof_system: proof_system.into(),
+                },
+            );
+            send(&rpc, &signer, &[ix]).await
+        }
+
+        Command::UploadVk {
+            circuit,
+            vk,
+            overlap_slots,
+        } => {
+            let json = read(&vk)?;
+            let ix = match (snarkjs::protocol(&json)?.as_str(), overlap_slots) {
+                ("groth16", Some(overlap_slots)) => ix(
+                    accounts::RotateGroth16VerifyingKey {
+                        authority: me,
+                        program_state: pda::program_state(),
+                        admin_log: pda::admin_log(),
+                        circuit_config: pda::circuit(circuit),
+                        groth16_vk: pda::groth16_vk(circuit),
+                        previous_groth16_vk: pda::previous_groth16_vk(circuit),
+                        system_program: solana_sdk::system_program::ID,
+                    },
+                    instruction::RotateGroth16VerifyingKey {
+                        key: snarkjs::groth16_vk(&json)?,
+                        overlap_slots,
+                    },
+                ),
+                ("groth16", None) => ix(
+                    accounts::SetGroth16VerifyingKey {
+                        authority: me,
+                        program_state: pda::program_state(),
+                        admin_log: pda::admin_log(),
+                        circuit_config: pda::circuit(circuit),
+                        groth16_vk: pda::groth16_vk(circuit),
+                        previous_groth16_vk: pda::previous_groth16_vk(circuit),
+                        system_program: solana_sdk::system_program::ID,
+                    },
+                    instruction::SetGroth16VerifyingKey {
+                        key: snarkjs::groth16_vk(&json)?,
+                    },
+                ),
+                (_, Some(overlap_slots)) => ix(
+                    accounts::RotatePlonkVerifyingKey {
+                        authority: me,
+                        program_state: pda::program_state(),
+                        admin_log: pda::admin_log(),
+                        circuit_config: pda::circuit(circuit),
+                        plonk_vk: pda::plonk_vk(circuit),
+                        previous_plonk_vk: pda::previous_plonk_vk(circuit),
+                        system_program: solana_sdk::system_program::ID,
+                    },
+                    instruction::RotatePlonkVerifyingKey {
+                        key: snarkjs::plonk_vk(&json)?,
+                        overlap_slots,
+                    },
+                ),
+                (_, None) => ix(
+                    accounts::SetPlonkVerifyingKey {
+                        authority: me,
+                        program_state: pda::program_state(),
+                        admin_log: pda::admin_log(),
+                        circuit_config: pda::circuit(circuit),
+                        plonk_vk: pda::plonk_vk(circuit),
+                        previous_plonk_vk: pda::previous_plonk_vk(circuit),
+                        system_program: solana_sdk::system_program::ID,
+                    },
+                    instruction::SetPlonkVerifyingKey {
+                        key: snarkjs::plonk_vk(&json)?,
+                    },
+                ),
+            };
+            send(&rpc, &signer, &[ix]).await
+        }
+
+        Command::Pause { circuit, resume } => {
+            let config: CircuitConfig = fetch::account(&rpc, &pda::circuit(circuit)).await?;
+            send(&rpc, &signer, &[configure_circuit(me, &config, resume)]).await
+        }
+
+        Command::Stats { farmer } => {
+            let state = fetch::program_state(&rpc).await?;
+            println!("authority            {}", state.authority);
+            println!("$GREEN mint          {}", state.mint);
+            println!("proofs verified      {}", state.total_proofs_verified);
+            println!("$GREEN minted        {}", state.total_tokens_minted);
+            println!("$GREEN burned        {}", state.total_burned);
+
+            let season: Season = fetch::season(&rpc, state.current_season).await?;
+            println!(
+                "season               {} ({} → {})",
+                season.index, season.start, season.end
+            );
+            println!("  proofs             {}", season.total_proofs);
+            println!(
+                "  minted / cap       {} / {}",
+                season.total_minted, season.mint_cap
+            );
+
+            if let Some(farmer) = farmer {
+                let profile = fetch::farmer_profile(&rpc, &farmer).await?;
+                println!("farmer               {}", profile.farmer);
+                println!("  region             {}", profile.region_code);
+                println!("  proofs             {}", profile.total_proofs);
+                println!("  streak             {}", profile.streak);
+                println!("  last claim         {}", profile.last_claim_at);
+            }
+            Ok(())
+        }
+
+        Command::VerifyAndMint {
+            proof,
+            public,
+            circuit,
+            parcel,
+            nonce,
+            scheme,
+            compressed,
+            memo,
+        } => {
+            let mut proof = snarkjs::groth16_proof(&read(&proof)?)?;
+            if compressed {
+                proof = snarkjs::compress(proof)?;
+            }
+            let public_signals = snarkjs::public_signals(&read(&public)?)?;
+            let commitment: [u8; 32] = public_signals
+                .get(..32)
+                .context("public.json has no inputs")?
+                .try_into()?;
+            let opening = CommitmentOpening {
+                scheme: match scheme {
+                    SchemeArg::Sha256 => SCHEME_SHA256,
+                    SchemeArg::Poseidon => SCHEME_POSEIDON,
+                },
+                parcel,
+                nonce: hex32(&nonce)?,
+            };
+            let signature = claim::verify_and_mint(
+                &rpc,
+                &signer,
+                VerifyAndMint {
+                    proof,
+                    public_signals,
+                    commitment,
+                    opening,
+                    circuit_id: circuit,
+                    evidence_cid: None,
+                    memo,
+                },
+            )
+            .await?;
+            println!("{signature}");
+            Ok(())
+        }
+
+        Command::PreCommit { commitment } => {
+            let hash = PreCommit::hash_of(&hex32(&commitment)?, &me);
+            let ix = ix(
+                accounts::CreatePreCommit {
+                    farmer: me,
+                    pre_commit: pda::pre_commit(&me, &hash),
+                    system_program: solana_sdk::system_program::ID,
+                },
+                instruction::PreCommit { hash },
+            );
+            send(&rpc, &signer, &[ix]).await
+        }
+
+        Command::CloseProof { commitment } => {
+            let commitment = hex32(&commitment)?;
+            let record = fetch::proof_record(&rpc, &commitment)
+                .await?
+                .context("no proof record for this commitment")?;
+            let sponsored = record.rent_payer == pda::rent_pool();
+            let ix = ix(
+                accounts::CloseProofRecord {
+                    authority: me,
+                    program_state: pda::program_state(),
+                    admin_log: pda::admin_log(),
+                    proof_record: pda::proof_record(&commitment),
+                    rent_pool: sponsored.then(pda::rent_pool),
+                    rent_recipient: (!sponsored).then_some(record.rent_payer),
+                },
+                instruction::CloseProofRecord {},
+            );
+            send(&rpc, &signer, &[ix]).await
+        }
+
+        Command::Freeze {
+            account,
+            reason,
+            thaw,
+        } => {
+            let accounts = accounts::FreezeGreenAccount {
+                authority: me,
+                program_state: pda::program_state(),
+                admin_log: pda::admin_log(),
+                green_mint: pda::green_mint(),
+                token_account: account,
+                token_program: spl_token::ID,
+            };
+            let reason_hash = hex32(&reason)?;
+            let ix = if thaw {
+                ix(accounts, instruction::ThawAccount { reason_hash })
+            } else {
+                ix(accounts, instruction::FreezeAccount { reason_hash })
+            };
+            send(&rpc, &signer, &[ix]).await
+        }
+
+        Command::IncentivesBudget { amount } => {
+            let ix = ix(
+                accounts::SetIncentivesBudget {
+                    authority: me,
+                    program_state: pda::program_state(),
+                    admin_log: pda::admin_log(),
+                    config: pda::config(),
+                },
+                instruction::SetIncentivesBudget { budget: amount },
+            );
+            send(&rpc, &signer, &[ix]).await
+        }
+
+        Command::Distribute { file } => {
+            let recipients = parse_distribution(&read(&file)?)?;
+            let mint = pda::green_mint();
+            for batch in recipients.chunks(DISTRIBUTE_BATCH) {
+                let mut ixs: Vec<Instruction> = batch
+                    .iter()
+                    .map(|r| {
+                        ata::create_associated_token_account_idempotent(
+                            &me,
+                            &r.wallet,
+                            &mint,
+                            &spl_token::ID,
+                        )
+                    })
+                    .collect();
+                let mut distribute = ix(
+                    accounts::Distribute {
+                        authority: me,
+                        program_state: pda::program_state(),
+                        admin_log: pda::admin_log(),
+                        config: pda::config(),
+                        green_mint: mint,
+                        token_program: spl_token::ID,
+                    },
+                    instruction::Distribute {
+                        recipients: batch.to_vec(),
+                    },
+                );
+                distribute.accounts.extend(batch.iter().map(|r| {
+                    AccountMeta::new(get_associated_token_address(&r.wallet, &mint), false)
+                }));
+                ixs.push(distribute);
+                send(&rpc, &signer, &ixs).await?;
+            }
+            Ok(())
+        }
+
+        Command::TreasuryWithdraw { amount, to } => {
+            let ix = ix(
+                accounts::WithdrawTreasury {
+                    authority: me,
+                    program_state: pda::program_state(),
+                    admin_log: pda::admin_log(),
+                    treasury_config: pda::treasury_config(),
+                    treasury_authority: pda::treasury_authority(),
+                    treasury_usdc: pda::treasury_usdc(),
+                    destination: to,
+                    token_program: spl_token::ID,
+                },
+                instruction::WithdrawTreasury { amount },
+            );
+            send(&rpc, &signer, &[ix]).await
+        }
+
+        Command::TreasuryPropose { amount, to } => {
+            let config: TreasuryConfig = fetch::account(&rpc, &pda::treasury_config()).await?;
+            let ix = ix(
+                accounts::ProposeTreasuryWithdrawal {
+                    authority: me,
+                    program_state: pda::program_state(),
+                    admin_log: pda::admin_log(),
+                    treasury_config: pda::treasury_config(),
+                    treasury_usdc: pda::treasury_usdc(),
+                    destination: to,
+                    withdrawal: pda::treasury_withdrawal(config.next_withdrawal_id),
+                    system_program: solana_sdk::system_program::ID,
+                },
+                instruction::ProposeTreasuryWithdrawal { amount },
+            );
+            println!("proposal {}", config.next_withdrawal_id);
+            send(&rpc, &signer, &[ix]).await
+        }
+
+        Command::TreasuryExecute { id, guardian } => {
+            let guardian_path = shellexpand(&guardian);
+            let guardian = read_keypair_file(&guardian_path)
+                .map_err(|e| anyhow::anyhow!("reading keypair {guardian_path}: {e}"))?;
+            let withdrawal: TreasuryWithdrawal =
+                fetch::account(&rpc, &pda::treasury_withdrawal(id)).await?;
+            let ix = ix(
+                accounts::ExecuteTreasuryWithdrawal {
+                    authority: me,
+                    guardian: guardian.pubkey(),
+                    program_state: pda::program_state(),
+                    admin_log: pda::admin_log(),
+                    treasury_config: pda::treasury_config(),
+                    withdrawal: pda::treasury_withdrawal(id),
+                    treasury_authority: pda::treasury_authority(),
+                    treasury_usdc: pda::treasury_usdc(),
+                    destination: withdrawal.destination,
+                    token_program: spl_token::ID,
+                },
+                instruction::ExecuteTreasuryWithdrawal {},
+            );
+            let blockhash = rpc.get_latest_blockhash().await?;
+            let tx = Transaction::new_signed_with_payer(
+                &[ix],
+                Some(&me),
+                &[&signer, &guardian],
+                blockhash,
+            );
+            let signature = rpc.send_and_confirm_transaction(&tx).await?;
+            println!("{signature}");
+            Ok(())
+        }
+
+        #[cfg(feature = "devnet")]
+        Command::DevnetReset => {
+            ensure_not_mainnet(&rpc).await?;
+            let keep = [pda::program_state(), pda::admin_log()];
+            let targets: Vec<Pubkey> = rpc
+                .get_program_accounts(&kisan_depin_client::PROGRAM_ID)
+                .await?
+                .into_iter()
+                .map(|(key, _)| key)
+                .filter(|key| !keep.contains(key))
+                .collect();
+            println!("closing {} accounts", targets.len());
+
+            // An empty batch still zeroes the counters
+            let batches: Vec<&[Pubkey]> = if targets.is_empty() {
+                vec![&[]]
+            } else {
+                targets.chunks(RESET_BATCH).collect()
+            };
+            for batch in batches {
+                let mut ix = ix(
+                    accounts::ResetState {
+                        authority: me,
+                        program_state: pda::program_state(),
+                        admin_log: pda::admin_log(),
+                    },
+                    instruction::ResetState {},
+                );
+                ix.accounts
+                    .extend(batch.iter().map(|key| AccountMeta::new(*key, false)));
+                send(&rpc, &signer, &[ix]).await?;
+            }
+            Ok(())
+        }
+
+        #[cfg(feature = "devnet")]
+        Command::DevnetAirdrop { to, amount } => {
+            ensure_not_mainnet(&rpc).await?;
+            let mint = pda::green_mint();
+            let recipient_token_account = get_associated_token_address(&to, &mint);
+            let create =
+                ata::create_associated_token_account_idempotent(&me, &to, &mint, &spl_token::ID);
+            let airdrop = ix(
+                accounts::AirdropGreen {
+                    authority: me,
+                    program_state: pda::program_state(),
+                    admin_log: pda::admin_log(),
+                    green_mint: mint,
+                    recipient_token_account,
+                    token_program: spl_token::ID,
+                },
+                instruction::AirdropGreen {
+                    amount: amount
+                        .checked_mul(10u64.pow(9))
+                        .context("amount overflows u64")?,
+                },
+            );
+            send(&rpc, &signer, &[create, airdrop]).await
+        }
+    }
+}
+
+/// Expand a leading `~/` to the home directory.
+fn shellexpand(path: &str) -> String {
+    match (path.strip_prefix("~/"), std::env::var("HOME")) {
+        (Some(rest), Ok(home)) => format!("{home}/{rest}"),
+        _ => path.to_string(),
+    }
+}
@@ -0,0 +1,232 @@
+//! JSON views of program state, as served by `kisan-gateway`.
+//!
+//! Keys are base58, commitments hex, and token amounts decimal strings
+//! (they overflow a JavaScript number). Deserialize these on the client
+//! side to consume the gateway from Rust.
+
+use kisan_depin::{Leaderboard, MintStats, MintStatsEntry, ProgramState, ProofRecord, Season};
+use serde::{Deserialize, Serialize};
+
+/// `u64` as a decimal string.
+mod amount {
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(v: &u64, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(v)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<u64, D::Error> {
+        String::deserialize(d)?.parse().map_err(D::Error::custom)
+    }
+}
+
+pub fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Body of every non-2xx response.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ErrorBody {
+    pub error: String,
+}
+
+/// A claim as materialized by the indexer.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ClaimView {
+    pub commitment: String,
+    /// dMRV `Claim` account; `None` for `verify_and_mint` claims
+    pub claim_account: Option<String>,
+    /// `zk` or `dmrv`
+    pub kind: String,
+    /// `submitted`, `verified`, `issued` or `retired`
+    pub status: String,
+    pub farmer: String,
+    pub circuit_id: u16,
+    pub season: Option<u32>,
+    /// $GREEN base units, once issued
+    pub amount: Option<String>,
+    pub serial_start: Option<String>,
+    pub serial_end: Option<String>,
+    /// Sequential on-chain claim ID, once issued
+    pub claim_id: Option<String>,
+    pub signature: String,
+    pub slot: u64,
+}
+
+/// `GET /farmer/{pubkey}/claims`, newest first.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct FarmerClaims {
+    pub farmer: String,
+    pub claims: Vec<ClaimView>,
+    /// Pass as `before_slot` to fetch the next page; `None` on the last
+    pub next_before_slot: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SeasonView {
+    pub index: u32,
+    pub start: i64,
+    pub end: i64,
+    #[serde(with = "amount")]
+    pub reward_amount: u64,
+    #[serde(with = "amount")]
+    pub mint_cap: u64,
+    pub max_claims_per_farmer: u32,
+    pub total_proofs: u64,
+    #[serde(with = "amount")]
+    pub total_minted: u64,
+}
+
+impl From<&Season> for SeasonView {
+    fn from(s: &Season) -> Self {
+        SeasonView {
+            index: s.index,
+            start: s.start,
+            end: s.end,
+            reward_amount: s.reward_amount,
+            mint_cap: s.mint_cap,
+            max_claims_per_farmer: s.max_claims_per_farmer,
+            total_proofs: s.total_proofs,
+            total_minted: s.total_minted,
+        }
+    }
+}
+
+/// `GET /stats`: program totals from chain, counts from the indexer.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Stats {
+    pub total_proofs_verified: u64,
+    #[serde(with = "amount")]
+    pub total_tokens_minted: u64,
+    #[serde(with = "amount")]
+    pub total_burned: u64,
+    pub next_serial: u64,
+    pub season: SeasonView,
+    pub farmers: u64,
+    #[serde(with = "amount")]
+    pub total_retired: u64,
+}
+
+impl Stats {
+    pub fn new(state: &ProgramState, season: &Season, farmers: u64, total_retired: u64) -> Self {
+        Stats {
+            total_proofs_verified: state.total_proofs_verified,
+            total_tokens_minted: state.total_tokens_minted,
+            total_burned: state.total_burned,
+            next_serial: state.next_serial,
+            season: season.into(),
+            farmers,
+            total_retired,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LeaderboardEntryView {
+    pub farmer: String,
+    pub proofs: u64,
+}
+
+/// `GET /leaderboard`: the current epoch's ranking.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LeaderboardView {
+    pub epoch: u32,
+    pub reset_at: i64,
+    pub period_seconds: i64,
+    pub entries: Vec<LeaderboardEntryView>,
+}
+
+impl From<&Leaderboard> for LeaderboardView {
+    fn from(l: &Leaderboard) -> Self {
+        LeaderboardView {
+            epoch: l.epoch,
+            reset_at: l.reset_at,
+            period_seconds: l.period_seconds,
+            entries: l.entries[..l.len as usize]
+                .iter()
+                .map(|e| LeaderboardEntryView {
+                    farmer: e.farmer.to_string(),
+                    proofs: e.proofs,
+                })
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MintStatsEntryView {
+    pub key: u16,
+    pub proofs: u64,
+    #[serde(with = "amount")]
+    pub minted: u64,
+}
+
+impl From<&MintStatsEntry> for MintStatsEntryView {
+    fn from(e: &MintStatsEntry) -> Self {
+        MintStatsEntryView {
+            key: e.key,
+            proofs: e.proofs,
+            minted: e.minted,
+        }
+    }
+}
+
+/// `GET /stats/breakdown`: lifetime farmer-claim totals per region and
+/// per circuit. `other_*` sum the regions and circuits past the on-chain
+/// caps, under key 0.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MintStatsView {
+    pub regions: Vec<MintStatsEntryView>,
+    pub circuits: Vec<MintStatsEntryView>,
+    pub other_regions: MintStatsEntryView,
+    pub other_circuits: MintStatsEntryView,
+}
+
+impl From<&MintStats> for MintStatsView {
+    fn from(s: &MintStats) -> Self {
+        MintStatsView {
+            regions: s.regions[..s.region_len as usize].iter().map(Into::into).collect(),
+            circuits: s.circuits[..s.circuit_len as usize].iter().map(Into::into).collect(),
+            other_regions: (&s.other_regions).into(),
+            other_circuits: (&s.other_circuits).into(),
+        }
+    }
+}
+
+/// `GET /proof/{commitment}`: the on-chain proof record.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ProofView {
+    pub commitment: String,
+    pub farmer: String,
+    pub timestamp: i64,
+    pub season: u32,
+    pub vintage: u16,
+    pub methodology_id: u16,
+    pub serial_start: u64,
+    pub serial_end: u64,
+    /// Sequential claim ID; 0 for claims minted before IDs existed
+    pub claim_id: u64,
+    /// IPFS CID (base58) of the source evidence
+    pub evidence_cid: Option<String>,
+}
+
+impl From<&ProofRecord> for ProofView {
+    fn from(r: &ProofRecord) -> Self {
+        ProofView {
+            commitment: hex(&r.commitment),
+            farmer: r.farmer.to_string(),
+            timestamp: r.timestamp,
+            season: r.season,
+            vintage: r.vintage,
+            methodology_id: r.methodology_id,
+            serial_start: r.serial_start,
+            serial_end: r.serial_end,
+            claim_id: r.claim_id,
+            evidence_cid: r
+                .evidence_cid
+                .iter()
+                .any(|&b| b != 0)
+                .then(|| bs58::encode(r.evidence_cid).into_string()),
+        }
+    }
+}
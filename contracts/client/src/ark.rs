@@ -0,0 +1,51 @@
+//! arkworks Groth16 proofs and keys in the program's encodings:
+//! big-endian field elements, G1 = x | y, G2 = x_c1 | x_c0 | y_c1 | y_c0,
+//! the point at infinity as all zeros.
+
+use ark_bn254::{Bn254, Fq, G1Affine, G2Affine};
+use ark_ff::{BigInteger, PrimeField};
+use kisan_depin::{groth16, ProofPoints};
+
+fn fq(x: &Fq) -> [u8; 32] {
+    x.into_bigint().to_bytes_be().try_into().unwrap()
+}
+
+pub fn g1(p: &G1Affine) -> [u8; 64] {
+    let mut out = [0u8; 64];
+    if !p.infinity {
+        out[..32].copy_from_slice(&fq(&p.x));
+        out[32..].copy_from_slice(&fq(&p.y));
+    }
+    out
+}
+
+pub fn g2(p: &G2Affine) -> [u8; 128] {
+    let mut out = [0u8; 128];
+    if !p.infinity {
+        out[..32].copy_from_slice(&fq(&p.x.c1));
+        out[32..64].copy_from_slice(&fq(&p.x.c0));
+        out[64..96].copy_from_slice(&fq(&p.y.c1));
+        out[96..].copy_from_slice(&fq(&p.y.c0));
+    }
+    out
+}
+
+/// A proof, uncompressed.
+pub fn proof_points(proof: &ark_groth16::Proof<Bn254>) -> ProofPoints {
+    ProofPoints::Uncompressed {
+        a: g1(&proof.a),
+        b: g2(&proof.b),
+        c: g1(&proof.c),
+    }
+}
+
+/// A verifying key, as `set_groth16_verifying_key` takes it.
+pub fn verifying_key(vk: &ark_groth16::VerifyingKey<Bn254>) -> groth16::VerifyingKey {
+    groth16::VerifyingKey {
+        alpha_g1: g1(&vk.alpha_g1),
+        beta_g2: g2(&vk.beta_g2),
+        gamma_g2: g2(&vk.gamma_g2),
+        delta_g2: g2(&vk.delta_g2),
+        ic: vk.gamma_abc_g1.iter().map(g1).collect(),
+    }
+}
@@ -0,0 +1,121 @@
+//! `vk-convert` — turn a snarkjs `verification_key.json` into what the
+//! program consumes, with coordinates converted to big-endian and G2
+//! limbs swapped to x_c1 | x_c0 | y_c1 | y_c0.
+//!
+//!     vk-convert verification_key.json --rust vk.rs
+//!     vk-convert verification_key.json --borsh vk.bin
+//!
+//! `--rust` writes constants to embed in a verifier (Groth16 or PLONK);
+//! with neither flag they go to stdout. `--borsh` writes the serialized
+//! `groth16::VerifyingKey` or `plonk::VerifyingKey`, byte-for-byte the
+//! argument of `set_groth16_verifying_key` or `set_plonk_verifying_key`.
+
+use std::fmt::Write as _;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use anchor_lang::AnchorSerialize;
+use kisan_depin::{groth16, plonk};
+use kisan_depin_client::snarkjs;
+
+const USAGE: &str =
+    "usage: vk-convert <verification_key.json> [--rust <out.rs>] [--borsh <out.bin>]";
+
+fn bytes(out: &mut String, name: &str, value: &[u8]) {
+    writeln!(out, "pub const {name}: [u8; {}] = [", value.len()).unwrap();
+    for row in value.chunks(16) {
+        let row: Vec<String> = row.iter().map(|b| format!("0x{b:02x}")).collect();
+        writeln!(out, "    {},", row.join(", ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+}
+
+fn groth16_rust(vk: &groth16::VerifyingKey) -> String {
+    let mut out = String::from("// Generated by vk-convert from a snarkjs Groth16 key.\n\n");
+    bytes(&mut out, "VK_ALPHA_G1", &vk.alpha_g1);
+    bytes(&mut out, "VK_BETA_G2", &vk.beta_g2);
+    bytes(&mut out, "VK_GAMMA_G2", &vk.gamma_g2);
+    bytes(&mut out, "VK_DELTA_G2", &vk.delta_g2);
+    writeln!(out, "pub const VK_IC: [[u8; 64]; {}] = [", vk.ic.len()).unwrap();
+    for point in &vk.ic {
+        let row: Vec<String> = point.iter().map(|b| format!("0x{b:02x}")).collect();
+        writeln!(out, "    [{}],", row.join(", ")).unwrap();
+    }
+    writeln!(out, "];").unwrap();
+    out
+}
+
+fn plonk_rust(vk: &plonk::VerifyingKey) -> String {
+    let mut out = String::from("// Generated by vk-convert from a snarkjs PLONK key.\n\n");
+    writeln!(out, "pub const VK_POWER: u8 = {};", vk.power).unwrap();
+    writeln!(out, "pub const VK_N_PUBLIC: u32 = {};", vk.n_public).unwrap();
+    for (name, value) in [
+        ("VK_K1", &vk.k1[..]),
+        ("VK_K2", &vk.k2[..]),
+        ("VK_W", &vk.w[..]),
+        ("VK_QM", &vk.qm[..]),
+        ("VK_QL", &vk.ql[..]),
+        ("VK_QR", &vk.qr[..]),
+        ("VK_QO", &vk.qo[..]),
+        ("VK_QC", &vk.qc[..]),
+        ("VK_S1", &vk.s1[..]),
+        ("VK_S2", &vk.s2[..]),
+        ("VK_S3", &vk.s3[..]),
+        ("VK_X_2", &vk.x_2[..]),
+    ] {
+        bytes(&mut out, name, value);
+    }
+    out
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let (input, mut rust, mut borsh) = (args.first().ok_or(USAGE)?, None, None);
+    let mut rest = args[1..].iter();
+    while let Some(flag) = rest.next() {
+        let path = rest.next().map(PathBuf::from).ok_or(USAGE)?;
+        match flag.as_str() {
+            "--rust" => rust = Some(path),
+            "--borsh" => borsh = Some(path),
+            _ => return Err(USAGE.into()),
+        }
+    }
+    let json = std::fs::read_to_string(input).map_err(|e| format!("{input}: {e}"))?;
+    let protocol = snarkjs::protocol(&json).map_err(|e| e.to_string())?;
+
+    let (code, serialized) = match protocol.as_str() {
+        "groth16" => {
+            let vk = snarkjs::groth16_vk(&json).map_err(|e| e.to_string())?;
+            (
+                groth16_rust(&vk),
+                vk.try_to_vec().map_err(|e| e.to_string())?,
+            )
+        }
+        "plonk" => {
+            let vk = snarkjs::plonk_vk(&json).map_err(|e| e.to_string())?;
+            (plonk_rust(&vk), vk.try_to_vec().map_err(|e| e.to_string())?)
+        }
+        other => return Err(format!("unsupported protocol `{other}`")),
+    };
+
+    if rust.is_none() && borsh.is_none() {
+        print!("{code}");
+    }
+    if let Some(path) = rust {
+        std::fs::write(&path, code).map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+    if let Some(path) = borsh {
+        std::fs::write(&path, serialized).map_err(|e| format!("{}: {e}", path.display()))?;
+    }
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("vk-convert: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! `verify_and_mint` end to end: resolves every account the claim needs
+//! from on-chain state, creates missing token accounts and sends it.
+
+use anchor_spl::associated_token::{
+    get_associated_token_address, spl_associated_token_account::instruction as ata,
+};
+use anchor_spl::token::spl_token;
+use kisan_depin::commitment::CommitmentOpening;
+use kisan_depin::payload::ProofPayload;
+use kisan_depin::{
+    accounts, compute, instruction, CircuitConfig, CustodialLink, PayoutSplit, PreCommit,
+    PricingConfig, ProofPoints, ReceiptConfig, ReferralCode, CLAIM_INDEX_PAGE_LEN,
+};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature, Signer};
+use solana_sdk::transaction::Transaction;
+
+use crate::{fetch, ix, pda, Result};
+
+/// Arguments of a farmer's own `verify_and_mint` claim.
+pub struct VerifyAndMint {
+    pub proof: ProofPoints,
+    pub public_signals: Vec<u8>,
+    pub commitment: [u8; 32],
+    pub opening: CommitmentOpening,
+    pub circuit_id: u16,
+    pub evidence_cid: Option<[u8; 34]>,
+    /// Attach a memo describing the claim, for explorers and exchanges
+    pub memo: bool,
+}
+
+/// Instructions for `farmer` to claim: compute budget, any missing token
+/// accounts, then `verify_and_mint`. The farmer signs and pays.
+pub async fn verify_and_mint_instructions(
+    rpc: &RpcClient,
+    farmer: &Pubkey,
+    args: VerifyAndMint,
+) -> Result<Vec<Instruction>> {
+    let state = fetch::program_state(rpc).await?;
+    let profile = fetch::farmer_profile(rpc, farmer).await?;
+    let circuit_config = pda::circuit(args.circuit_id);
+    let circuit: CircuitConfig = fetch::account(rpc, &circuit_config).await?;
+    let payout_split = pda::payout_split(&args.opening.parcel);
+    let split = fetch::account_optional::<PayoutSplit>(rpc, &payout_split).await?;
+
+    let mut budget = match args.proof {
+        ProofPoints::Uncompressed { .. } => compute::VERIFY_AND_MINT,
+        ProofPoints::Compressed { .. } => compute::VERIFY_AND_MINT_COMPRESSED,
+    };
+    if args.memo {
+        budget += compute::CLAIM_MEMO;
+    }
+    if split.is_some() {
+        budget += compute::PAYOUT_SPLIT;
+    }
+    let mut ixs = vec![ComputeBudgetInstruction::set_compute_unit_limit(budget)];
+
+    // Token accounts the claim pays into, created if missing
+    let token_account = |ixs: &mut Vec<Instruction>, wallet: &Pubkey, mint: &Pubkey| {
+        ixs.push(ata::create_associated_token_account_idempotent(
+            farmer,
+            wallet,
+            mint,
+            &spl_token::ID,
+        ));
+        get_associated_token_address(wallet, mint)
+    };
+    // Rewards go to the farmer's linked custodial wallet, if any
+    let custodial_link = pda::custodial_link(farmer);
+    let custodial = fetch::account_optional::<CustodialLink>(rpc, &custodial_link).await?;
+    let wallet = custodial.as_ref().map_or(*farmer, |link| link.wallet);
+    let farmer_token_account = token_account(&mut ixs, &wallet, &state.mint);
+    // A split parcel pays the landowner their share directly
+    let landowner_token_account = split
+        .as_ref()
+        .map(|split| token_account(&mut ixs, &split.landowner, &state.mint));
+    let water = (circuit.water_amount > 0).then(|| {
+        let mint = pda::water_mint();
+        (mint, token_account(&mut ixs, &wallet, &mint))
+    });
+    let soil = (circuit.soil_amount > 0).then(|| {
+        let mint = pda::soil_mint();
+        (mint, token_account(&mut ixs, &wallet, &mint))
+    });
+
+    // First verified mint of a referred farmer pays the referrer
+    let referral = if profile.total_proofs == 0 && profile.referred_by != Pubkey::default() {
+        let code: ReferralCode = fetch::account(rpc, &profile.referred_by).await?;
+        Some((
+            profile.referred_by,
+            token_account(&mut ixs, &code.referrer, &state.mint),
+        ))
+    } else {
+        None
+    };
+
+    let pricing = fetch::account_optional::<PricingConfig>(rpc, &pda::pricing_config()).await?;
+
+    let receipt = if state.issuance_receipts {
+        let config: ReceiptConfig = fetch::account(rpc, &pda::receipt_config()).await?;
+        let tree_config = Pubkey::find_program_address(
+            &[config.merkle_tree.as_ref()],
+            &kisan_depin::bubblegum::BUBBLEGUM_PROGRAM_ID,
+        )
+        .0;
+        Some((config.merkle_tree, tree_config))
+    } else {
+        None
+    };
+
+    // Reveal the farmer's pre-commitment of this claim, if they made one
+    let pre_commit = pda::pre_commit(farmer, &PreCommit::hash_of(&args.commitment, farmer));
+    let pre_committed = fetch::account_optional::<PreCommit>(rpc, &pre_commit)
+        .await?
+        .is_some();
+
+    let attested = |key: Pubkey| (key != Pubkey::default()).then_some(key);
+    ixs.push(ix(
+        accounts::VerifyAndMint {
+            payer: *farmer,
+            claimant: *farmer,
+            farmer: *farmer,
+            delegation: None,
+            session_key: None,
+            relayer_info: None,
+            rent_pool: None,
+            program_state: pda::program_state(),
+            green_mint: state.mint,
+            farmer_token_account,
+            custodial_link: custodial.is_some().then_some(custodial_link),
+            payout_split,
+            landowner_token_account,
+            farmer_profile: Some(pda::farmer_profile(farmer)),
+            claim_index: Some(pda::claim_index(
+                farmer,
+                profile.total_proofs / CLAIM_INDEX_PAGE_LEN as u64,
+            )),
+            config: pda::config(),
+            region_config: Some(pda::region(profile.region_code)),
+            identity_attestation: attested(profile.identity_attestation),
+            pm_kisan_attestation: attested(profile.pm_kisan_attestation),
+            referral_code: referral.map(|(code, _)| code),
+            referrer_token_account: referral.map(|(_, account)| account),
+            onboarding_vault: (profile.onboarding_bonus > 0).then(pda::onboarding_vault),
+            proof_record: pda::proof_record(&args.commitment),
+            leaderboard: pda::leaderboard(),
+            season: pda::season(state.current_season),
+            season_tally: pda::season_tally(state.current_season),
+            mint_stats: pda::mint_stats(),
+            circuit_config,
+            feature_flags: pda::feature_flags(),
+            pricing_config: pricing.as_ref().map(|_| pda::pricing_config()),
+            price_feed: pricing.as_ref().map(|p| p.price_feed),
+            groth16_vk: Some(pda::groth16_vk(args.circuit_id)),
+            previous_groth16_vk: None,
+            plonk_vk: None,
+            previous_plonk_vk: None,
+            pre_commit: pre_committed.then_some(pre_commit),
+            verifier_program: None,
+            water_mint: water.map(|(mint, _)| mint),
+            water_token_account: water.map(|(_, account)| account),
+            soil_mint: soil.map(|(mint, _)| mint),
+            soil_token_account: soil.map(|(_, account)| account),
+            receipt_config: receipt.map(|_| pda::receipt_config()),
+            receipt_authority: receipt.map(|_| pda::receipt_authority()),
+            receipt_tree_config: receipt.map(|(_, tree_config)| tree_config),
+            receipt_merkle_tree: receipt.map(|(tree, _)| tree),
+            log_wrapper: receipt.map(|_| kisan_depin::bubblegum::NOOP_PROGRAM_ID),
+            compression_program: receipt.map(|_| kisan_depin::bubblegum::COMPRESSION_PROGRAM_ID),
+            bubblegum_program: receipt.map(|_| kisan_depin::bubblegum::BUBBLEGUM_PROGRAM_ID),
+            // Top-level claim; the CPI policy accounts are for integrators
+            instructions_sysvar: None,
+            cpi_integrator: None,
+            memo_program: args.memo.then_some(kisan_depin::memo::MEMO_PROGRAM_ID),
+            token_program: spl_token::ID,
+            system_program: solana_sdk::system_program::ID,
+        },
+        instruction::VerifyAndMint {
+            payload: ProofPayload::groth16(&args.proof, args.public_signals),
+            compliance_commitment: args.commitment,
+            opening: args.opening,
+            evidence_cid: args.evidence_cid,
+        },
+    ));
+    Ok(ixs)
+}
+
+/// Submit a Groth16 claim signed and paid for by `farmer`.
+pub async fn verify_and_mint(
+    rpc: &RpcClient,
+    farmer: &Keypair,
+    args: VerifyAndMint,
+) -> Result<Signature> {
+    let ixs = verify_and_mint_instructions(rpc, &farmer.pubkey(), args).await?;
+    let blockhash = rpc.get_latest_blockhash().await?;
+    let tx = Transaction::new_signed_with_payer(&ixs, Some(&farmer.pubkey()), &[farmer], blockhash);
+    Ok(rpc.send_and_confirm_transaction(&tx).await?)
+}
@@ -0,0 +1,78 @@
+//! A throwaway Groth16 circuit for tests, benches and local simulation:
+//! it proves knowledge of private copies of its public inputs, so any
+//! public signals (each below the scalar modulus) get a real proof the
+//! program's pairing check accepts.
+//!
+//! The setup is seeded, so a given input count always has the same key;
+//! never register it on a cluster that mints anything of value.
+
+use ark_bn254::{Bn254, Fr};
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::{Groth16, ProvingKey};
+use ark_relations::lc;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError, Variable};
+use ark_std::rand::rngs::StdRng;
+use ark_std::rand::SeedableRng;
+use kisan_depin::{groth16, ProofPoints};
+
+const SEED: u64 = u64::from_be_bytes(*b"kisandev");
+
+/// `x_i · 1 = w_i` for each public input `x_i` and witness `w_i`.
+#[derive(Clone)]
+struct DevCircuit {
+    inputs: Vec<Option<Fr>>,
+}
+
+impl ConstraintSynthesizer<Fr> for DevCircuit {
+    fn generate_constraints(self, cs: ConstraintSystemRef<Fr>) -> Result<(), SynthesisError> {
+        for input in self.inputs {
+            let value = || input.ok_or(SynthesisError::AssignmentMissing);
+            let x = cs.new_input_variable(value)?;
+            let w = cs.new_witness_variable(value)?;
+            cs.enforce_constraint(lc!() + x, lc!() + Variable::One, lc!() + w)?;
+        }
+        Ok(())
+    }
+}
+
+fn proving_key(n_public: usize) -> ProvingKey<Bn254> {
+    let shape = DevCircuit {
+        inputs: vec![None; n_public],
+    };
+    Groth16::<Bn254>::generate_random_parameters_with_reduction(
+        shape,
+        &mut StdRng::seed_from_u64(SEED),
+    )
+    .expect("dev circuit setup")
+}
+
+/// The key proofs over `n_public` inputs verify against.
+pub fn verifying_key(n_public: usize) -> groth16::VerifyingKey {
+    crate::ark::verifying_key(&proving_key(n_public).vk)
+}
+
+/// A proof over `public_signals`, 32-byte big-endian words.
+///
+/// Panics if a word is not below the scalar modulus.
+pub fn prove(public_signals: &[u8]) -> ProofPoints {
+    let inputs: Vec<_> = public_signals
+        .chunks(32)
+        .map(|word| {
+            let x = Fr::from_be_bytes_mod_order(word);
+            assert_eq!(
+                x.into_bigint().to_bytes_be(),
+                word,
+                "public input not below the scalar modulus"
+            );
+            Some(x)
+        })
+        .collect();
+    let pk = proving_key(inputs.len());
+    let proof = Groth16::<Bn254>::create_random_proof_with_reduction(
+        DevCircuit { inputs },
+        &pk,
+        &mut StdRng::seed_from_u64(SEED + 1),
+    )
+    .expect("dev circuit proof");
+    crate::ark::proof_points(&proof)
+}
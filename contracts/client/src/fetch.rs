@@ -0,0 +1,116 @@
+//! Account reads, deserialized into the program's own types.
+
+use anchor_lang::{AccountDeserialize, Discriminator, ZeroCopy};
+use kisan_depin::{
+    ClaimIndexPage, Config, FarmerProfile, FeatureFlags, Leaderboard, MintStats, ProgramState,
+    ProofRecord, Season, SeasonSnapshot, CLAIM_INDEX_PAGE_LEN,
+};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+
+use crate::{pda, ClientError, Result};
+
+/// Fetch and deserialize an Anchor account, or `None` if it doesn't exist.
+pub async fn account_optional<T: AccountDeserialize>(
+    rpc: &RpcClient,
+    address: &Pubkey,
+) -> Result<Option<T>> {
+    let Some(account) = rpc
+        .get_account_with_commitment(address, rpc.commitment())
+        .await?
+        .value
+    else {
+        return Ok(None);
+    };
+    T::try_deserialize(&mut account.data.as_slice())
+        .map(Some)
+        .map_err(|e| ClientError::Deserialize(*address, e))
+}
+
+/// Fetch and deserialize an Anchor account that must exist.
+pub async fn account<T: AccountDeserialize>(rpc: &RpcClient, address: &Pubkey) -> Result<T> {
+    account_optional(rpc, address)
+        .await?
+        .ok_or(ClientError::AccountNotFound(*address))
+}
+
+/// Fetch a zero-copy account (`Leaderboard`, `AdminLog`, ...).
+pub async fn zero_copy<T: ZeroCopy + Discriminator>(
+    rpc: &RpcClient,
+    address: &Pubkey,
+) -> Result<T> {
+    let data = rpc.get_account_data(address).await?;
+    let body = data
+        .get(8..8 + std::mem::size_of::<T>())
+        .filter(|_| data[..8] == T::DISCRIMINATOR)
+        .ok_or_else(|| {
+            ClientError::Deserialize(
+                *address,
+                anchor_lang::error::ErrorCode::AccountDidNotDeserialize.into(),
+            )
+        })?;
+    Ok(bytemuck::pod_read_unaligned(body))
+}
+
+pub async fn program_state(rpc: &RpcClient) -> Result<ProgramState> {
+    account(rpc, &pda::program_state()).await
+}
+
+pub async fn feature_flags(rpc: &RpcClient) -> Result<FeatureFlags> {
+    account(rpc, &pda::feature_flags()).await
+}
+
+pub async fn config(rpc: &RpcClient) -> Result<Config> {
+    account(rpc, &pda::config()).await
+}
+
+pub async fn season(rpc: &RpcClient, index: u32) -> Result<Season> {
+    account(rpc, &pda::season(index)).await
+}
+
+/// The frozen totals of season `index`, once it has been rolled over.
+pub async fn season_snapshot(rpc: &RpcClient, index: u32) -> Result<Option<SeasonSnapshot>> {
+    account_optional(rpc, &pda::season_snapshot(index)).await
+}
+
+pub async fn leaderboard(rpc: &RpcClient) -> Result<Leaderboard> {
+    zero_copy(rpc, &pda::leaderboard()).await
+}
+
+pub async fn mint_stats(rpc: &RpcClient) -> Result<MintStats> {
+    zero_copy(rpc, &pda::mint_stats()).await
+}
+
+pub async fn farmer_profile(rpc: &RpcClient, farmer: &Pubkey) -> Result<FarmerProfile> {
+    account(rpc, &pda::farmer_profile(farmer)).await
+}
+
+/// The proof record for `commitment`, if it has been claimed.
+pub async fn proof_record(rpc: &RpcClient, commitment: &[u8; 32]) -> Result<Option<ProofRecord>> {
+    account_optional(rpc, &pda::proof_record(commitment)).await
+}
+
+/// Commitments of `farmer`'s claims, oldest first, walked page by page
+/// through the claim index. Claims minted before the index existed
+/// aren't listed.
+pub async fn claim_history(rpc: &RpcClient, farmer: &Pubkey) -> Result<Vec<[u8; 32]>> {
+    let profile = farmer_profile(rpc, farmer).await?;
+    let pages = profile.total_proofs.div_ceil(CLAIM_INDEX_PAGE_LEN as u64);
+    let addresses: Vec<Pubkey> = (0..pages).map(|p| pda::claim_index(farmer, p)).collect();
+
+    let mut commitments = Vec::new();
+    // getMultipleAccounts takes at most 100 keys
+    for chunk in addresses.chunks(100) {
+        for (address, account) in chunk.iter().zip(rpc.get_multiple_accounts(chunk).await?) {
+            let Some(account) = account else { continue };
+            let page = ClaimIndexPage::try_deserialize(&mut account.data.as_slice())
+                .map_err(|e| ClientError::Deserialize(*address, e))?;
+            commitments.extend(
+                page.commitments[..page.count as usize]
+                    .iter()
+                    .filter(|c| **c != [0; 32]),
+            );
+        }
+    }
+    Ok(commitments)
+}
@@ -0,0 +1,170 @@
+// ============================================================
+// Kisan-DePIN client — typed access to the on-chain program
+// ============================================================
+//
+// Instruction data and account lists come from the program
+// crate itself (`kisan_depin::instruction` / `accounts`), so
+// every instruction is covered and stays in sync with the IDL;
+// `ix` pairs them into an `Instruction`. On top of that:
+//
+//   - `pda`    — addresses of every program-derived account
+//   - `api`    — JSON views served by the gateway
+//   - `fetch`  — RPC reads deserialized into program types
+//   - `claim`  — `verify_and_mint` end to end, ATAs included
+//   - `snarkjs` — proofs, public inputs and keys from snarkjs JSON
+//   - `bootstrap` — the one-time global account setup
+//   - `ark`    — arkworks Groth16 proofs and keys (`ark` feature)
+//   - `dev_circuit` — a seeded test circuit with real proofs
+//     (`dev-circuit` feature)
+//
+// `fetch` and `claim` need the default `rpc` feature; the rest
+// builds for wasm32 (see `kisan-depin-wasm`).
+// ============================================================
+
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::pubkey::Pubkey;
+use anchor_lang::solana_program::{system_program, sysvar};
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use kisan_depin::AllocationKind;
+
+pub mod api;
+#[cfg(feature = "ark")]
+pub mod ark;
+#[cfg(feature = "rpc")]
+pub mod claim;
+#[cfg(feature = "dev-circuit")]
+pub mod dev_circuit;
+#[cfg(feature = "rpc")]
+pub mod fetch;
+pub mod pda;
+pub mod snarkjs;
+
+pub use kisan_depin::{accounts, instruction, ID as PROGRAM_ID};
+
+/// Errors from client helpers.
+#[derive(Debug, thiserror::Error)]
+pub enum ClientError {
+    #[cfg(feature = "rpc")]
+    #[error("rpc: {0}")]
+    Rpc(Box<solana_client::client_error::ClientError>),
+    #[error("account {0} not found")]
+    AccountNotFound(Pubkey),
+    #[error("account {0} failed to deserialize: {1}")]
+    Deserialize(Pubkey, anchor_lang::error::Error),
+    #[error("snarkjs: {0}")]
+    Snarkjs(String),
+}
+
+#[cfg(feature = "rpc")]
+impl From<solana_client::client_error::ClientError> for ClientError {
+    // Boxed: the RPC error is large enough to bloat every `Result`
+    fn from(e: solana_client::client_error::ClientError) -> Self {
+        ClientError::Rpc(Box::new(e))
+    }
+}
+
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+/// Build a program instruction from its accounts and arguments, e.g.
+///
+/// ```ignore
+/// let ix = ix(
+///     accounts::RevokeDelegate { farmer, delegation },
+///     instruction::RevokeDelegate {},
+/// );
+/// ```
+pub fn ix(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: PROGRAM_ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// The global accounts every deployment needs before seasons, regions
+/// and circuits can be configured, in order: `initialize` (with the
+/// cap-table vaults), then the admin log, feature flags, config, a
+/// weekly leaderboard and the mint stats. `authority` must be the
+/// program's upgrade authority.
+///
+/// The first two fit in one transaction and the rest in a second; the
+/// admin log has to land before anything that writes to it.
+pub fn bootstrap(authority: &Pubkey) -> Vec<Instruction> {
+    let authority = *authority;
+    let program_state = pda::program_state();
+    let admin_log = pda::admin_log();
+    vec![
+        ix(
+            accounts::Initialize {
+                authority,
+                program: PROGRAM_ID,
+                program_data: pda::program_data(),
+                program_state,
+                green_mint: pda::green_mint(),
+                cap_table: pda::cap_table(),
+                community_vault: pda::allocation_vault(AllocationKind::Community),
+                team_vault: pda::allocation_vault(AllocationKind::Team),
+                investors_vault: pda::allocation_vault(AllocationKind::Investors),
+                ecosystem_vault: pda::allocation_vault(AllocationKind::Ecosystem),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+                rent: sysvar::rent::ID,
+            },
+            instruction::Initialize {
+                allocations: Default::default(),
+            },
+        ),
+        ix(
+            accounts::InitializeAdminLog {
+                authority,
+                program_state,
+                admin_log,
+                system_program: system_program::ID,
+            },
+            instruction::InitializeAdminLog {},
+        ),
+        ix(
+            accounts::InitializeFeatureFlags {
+                authority,
+                program_state,
+                admin_log,
+                feature_flags: pda::feature_flags(),
+                system_program: system_program::ID,
+            },
+            instruction::InitializeFeatureFlags {},
+        ),
+        ix(
+            accounts::InitializeConfig {
+                authority,
+                program_state,
+                admin_log,
+                config: pda::config(),
+                system_program: system_program::ID,
+            },
+            instruction::InitializeConfig {},
+        ),
+        ix(
+            accounts::InitializeLeaderboard {
+                authority,
+                program_state,
+                admin_log,
+                leaderboard: pda::leaderboard(),
+                system_program: system_program::ID,
+            },
+            instruction::InitializeLeaderboard {
+                period_seconds: 7 * 86_400,
+            },
+        ),
+        ix(
+            accounts::InitializeMintStats {
+                authority,
+                program_state,
+                admin_log,
+                mint_stats: pda::mint_stats(),
+                system_program: system_program::ID,
+            },
+            instruction::InitializeMintStats {},
+        ),
+    ]
+}
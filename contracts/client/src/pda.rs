@@ -0,0 +1,364 @@
+//! Program-derived addresses, from the seeds the program itself uses.
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::solana_program::bpf_loader_upgradeable;
+use kisan_common::seeds::*;
+use kisan_depin::{AllocationKind, ProgramAddresses};
+
+use crate::PROGRAM_ID;
+
+fn find(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &PROGRAM_ID).0
+}
+
+/// The program's `ProgramData` account, holding its upgrade authority
+pub fn program_data() -> Pubkey {
+    Pubkey::find_program_address(&[PROGRAM_ID.as_ref()], &bpf_loader_upgradeable::ID).0
+}
+
+/// Every address `get_addresses` returns for this circuit, region, season
+/// and farmer, derived locally with the program's own seeds.
+pub fn addresses(
+    circuit_id: u16,
+    region_code: u16,
+    season: u32,
+    farmer: &Pubkey,
+) -> ProgramAddresses {
+    ProgramAddresses::derive(circuit_id, region_code, season, farmer)
+}
+
+pub fn program_state() -> Pubkey {
+    find(&[STATE_SEED])
+}
+
+pub fn green_mint() -> Pubkey {
+    find(&[MINT_SEED])
+}
+
+pub fn water_mint() -> Pubkey {
+    find(&[WATER_MINT_SEED])
+}
+
+pub fn soil_mint() -> Pubkey {
+    find(&[SOIL_MINT_SEED])
+}
+
+pub fn admin_log() -> Pubkey {
+    find(&[ADMIN_LOG_SEED])
+}
+
+pub fn feature_flags() -> Pubkey {
+    find(&[FEATURE_FLAGS_SEED])
+}
+
+pub fn config() -> Pubkey {
+    find(&[CONFIG_SEED])
+}
+
+pub fn leaderboard() -> Pubkey {
+    find(&[LEADERBOARD_SEED])
+}
+
+pub fn mint_stats() -> Pubkey {
+    find(&[MINT_STATS_SEED])
+}
+
+pub fn rent_pool() -> Pubkey {
+    find(&[RENT_POOL_SEED])
+}
+
+pub fn pricing_config() -> Pubkey {
+    find(&[PRICING_SEED])
+}
+
+pub fn receipt_config() -> Pubkey {
+    find(&[RECEIPT_CONFIG_SEED])
+}
+
+pub fn receipt_authority() -> Pubkey {
+    find(&[RECEIPT_AUTHORITY_SEED])
+}
+
+pub fn season(index: u32) -> Pubkey {
+    find(&[SEASON_SEED, &index.to_le_bytes()])
+}
+
+pub fn season_tally(index: u32) -> Pubkey {
+    find(&[SEASON_TALLY_SEED, &index.to_le_bytes()])
+}
+
+pub fn season_snapshot(index: u32) -> Pubkey {
+    find(&[SEASON_SNAPSHOT_SEED, &index.to_le_bytes()])
+}
+
+pub fn region(region_code: u16) -> Pubkey {
+    find(&[REGION_SEED, &region_code.to_le_bytes()])
+}
+
+pub fn circuit(circuit_id: u16) -> Pubkey {
+    find(&[CIRCUIT_SEED, &circuit_id.to_le_bytes()])
+}
+
+pub fn groth16_vk(circuit_id: u16) -> Pubkey {
+    find(&[GROTH16_VK_SEED, &circuit_id.to_le_bytes()])
+}
+
+pub fn plonk_vk(circuit_id: u16) -> Pubkey {
+    find(&[PLONK_VK_SEED, &circuit_id.to_le_bytes()])
+}
+
+pub fn previous_plonk_vk(circuit_id: u16) -> Pubkey {
+    find(&[PLONK_VK_PREVIOUS_SEED, &circuit_id.to_le_bytes()])
+}
+
+pub fn previous_groth16_vk(circuit_id: u16) -> Pubkey {
+    find(&[GROTH16_VK_PREVIOUS_SEED, &circuit_id.to_le_bytes()])
+}
+
+pub fn farmer_profile(farmer: &Pubkey) -> Pubkey {
+    find(&[FARMER_SEED, farmer.as_ref()])
+}
+
+pub fn proof_record(commitment: &[u8; 32]) -> Pubkey {
+    find(&[PROOF_SEED, commitment])
+}
+
+/// Page `page` of `farmer`'s claim index
+pub fn claim_index(farmer: &Pubkey, page: u64) -> Pubkey {
+    find(&[CLAIM_INDEX_SEED, farmer.as_ref(), &page.to_le_bytes()])
+}
+
+pub fn claim(commitment: &[u8; 32]) -> Pubkey {
+    find(&[CLAIM_SEED, commitment])
+}
+
+pub fn claim_queue() -> Pubkey {
+    find(&[CLAIM_QUEUE_SEED])
+}
+
+/// Entry `position` of the claim queue
+pub fn queued_claim(position: u64) -> Pubkey {
+    find(&[QUEUED_CLAIM_SEED, &position.to_le_bytes()])
+}
+
+pub fn delegation(farmer: &Pubkey, delegate: &Pubkey) -> Pubkey {
+    find(&[DELEGATION_SEED, farmer.as_ref(), delegate.as_ref()])
+}
+
+pub fn session_key(farmer: &Pubkey, ephemeral: &Pubkey) -> Pubkey {
+    find(&[SESSION_SEED, farmer.as_ref(), ephemeral.as_ref()])
+}
+
+pub fn custodian(program: &Pubkey) -> Pubkey {
+    find(&[CUSTODIAN_SEED, program.as_ref()])
+}
+
+pub fn custodial_link(farmer: &Pubkey) -> Pubkey {
+    find(&[CUSTODIAL_LINK_SEED, farmer.as_ref()])
+}
+
+pub fn pre_commit(farmer: &Pubkey, hash: &[u8; 32]) -> Pubkey {
+    find(&[PRE_COMMIT_SEED, farmer.as_ref(), hash])
+}
+
+pub fn relayer(relayer: &Pubkey) -> Pubkey {
+    find(&[RELAYER_SEED, relayer.as_ref()])
+}
+
+pub fn relay_slash(relayer: &Pubkey, commitment: &[u8; 32]) -> Pubkey {
+    find(&[RELAY_SLASH_SEED, relayer.as_ref(), commitment])
+}
+
+pub fn cpi_integrator(program: &Pubkey) -> Pubkey {
+    find(&[CPI_INTEGRATOR_SEED, program.as_ref()])
+}
+
+pub fn referral(code: &[u8; 8]) -> Pubkey {
+    find(&[REFERRAL_SEED, code])
+}
+
+pub fn parcel(farmer: &Pubkey, parcel_id: u32) -> Pubkey {
+    find(&[PARCEL_SEED, farmer.as_ref(), &parcel_id.to_le_bytes()])
+}
+
+pub fn payout_split(parcel: &Pubkey) -> Pubkey {
+    find(&[PAYOUT_SPLIT_SEED, parcel.as_ref()])
+}
+
+pub fn attester(attester: &Pubkey) -> Pubkey {
+    find(&[ATTESTER_SEED, attester.as_ref()])
+}
+
+pub fn claim_attestation(claim: &Pubkey) -> Pubkey {
+    find(&[CLAIM_ATTESTATION_SEED, claim.as_ref()])
+}
+
+pub fn node_reward_config() -> Pubkey {
+    find(&[NODE_REWARD_CONFIG_SEED])
+}
+
+pub fn node_reward_vault() -> Pubkey {
+    find(&[NODE_REWARD_VAULT_SEED])
+}
+
+pub fn node_rewards(node: &Pubkey) -> Pubkey {
+    find(&[NODE_REWARDS_SEED, node.as_ref()])
+}
+
+pub fn escrow(identity_hash: &[u8; 32]) -> Pubkey {
+    find(&[ESCROW_SEED, identity_hash])
+}
+
+pub fn escrow_vault() -> Pubkey {
+    find(&[ESCROW_VAULT_SEED])
+}
+
+pub fn onboarding_vault() -> Pubkey {
+    find(&[ONBOARDING_VAULT_SEED])
+}
+
+pub fn payout_config() -> Pubkey {
+    find(&[PAYOUT_CONFIG_SEED])
+}
+
+pub fn voucher(payout_ref_hash: &[u8; 32]) -> Pubkey {
+    find(&[VOUCHER_SEED, payout_ref_hash])
+}
+
+pub fn price_floor(methodology_id: u16) -> Pubkey {
+    find(&[PRICE_FLOOR_SEED, &methodology_id.to_le_bytes()])
+}
+
+pub fn listing(seller: &Pubkey, listing_id: u64) -> Pubkey {
+    find(&[LISTING_SEED, seller.as_ref(), &listing_id.to_le_bytes()])
+}
+
+pub fn listing_escrow(listing: &Pubkey) -> Pubkey {
+    find(&[LISTING_ESCROW_SEED, listing.as_ref()])
+}
+
+pub fn otc(maker: &Pubkey, counterparty: &Pubkey) -> Pubkey {
+    find(&[OTC_SEED, maker.as_ref(), counterparty.as_ref()])
+}
+
+pub fn otc_escrow(otc: &Pubkey) -> Pubkey {
+    find(&[OTC_ESCROW_SEED, otc.as_ref()])
+}
+
+pub fn sgreen_pool() -> Pubkey {
+    find(&[SGREEN_POOL_SEED])
+}
+
+/// The Token-2022 sGREEN mint
+pub fn sgreen_mint() -> Pubkey {
+    find(&[SGREEN_MINT_SEED])
+}
+
+pub fn sgreen_vault() -> Pubkey {
+    find(&[SGREEN_VAULT_SEED])
+}
+
+pub fn cap_table() -> Pubkey {
+    find(&[CAP_TABLE_SEED])
+}
+
+/// Escrow holding one reserved allocation until it vests
+pub fn allocation_vault(kind: AllocationKind) -> Pubkey {
+    find(&[ALLOCATION_VAULT_SEED, &[kind as u8]])
+}
+
+pub fn governance_config() -> Pubkey {
+    find(&[GOVERNANCE_SEED])
+}
+
+pub fn governance_vault() -> Pubkey {
+    find(&[GOVERNANCE_VAULT_SEED])
+}
+
+pub fn voter_record(owner: &Pubkey) -> Pubkey {
+    find(&[VOTER_SEED, owner.as_ref()])
+}
+
+pub fn proposal(id: u64) -> Pubkey {
+    find(&[PROPOSAL_SEED, &id.to_le_bytes()])
+}
+
+pub fn vote_record(proposal: &Pubkey, voter: &Pubkey) -> Pubkey {
+    find(&[VOTE_SEED, proposal.as_ref(), voter.as_ref()])
+}
+
+pub fn market_config() -> Pubkey {
+    find(&[MARKET_CONFIG_SEED])
+}
+
+pub fn royalty_vault() -> Pubkey {
+    find(&[ROYALTY_VAULT_SEED])
+}
+
+pub fn royalty(farmer: &Pubkey) -> Pubkey {
+    find(&[ROYALTY_SEED, farmer.as_ref()])
+}
+
+pub fn buyer_profile(buyer: &Pubkey) -> Pubkey {
+    find(&[BUYER_PROFILE_SEED, buyer.as_ref()])
+}
+
+pub fn insurance_pool() -> Pubkey {
+    find(&[INSURANCE_POOL_SEED])
+}
+
+pub fn oracle_reporter(reporter: &Pubkey) -> Pubkey {
+    find(&[ORACLE_REPORTER_SEED, reporter.as_ref()])
+}
+
+pub fn oracle_config() -> Pubkey {
+    find(&[ORACLE_CONFIG_SEED])
+}
+
+pub fn recovery_config() -> Pubkey {
+    find(&[RECOVERY_SEED])
+}
+
+pub fn weather_report(region_code: u16, season: u32) -> Pubkey {
+    find(&[WEATHER_REPORT_SEED, &region_code.to_le_bytes(), &season.to_le_bytes()])
+}
+
+pub fn treasury_usdc() -> Pubkey {
+    find(&[TREASURY_USDC_SEED])
+}
+
+pub fn subscription_escrow(subscription: &Pubkey) -> Pubkey {
+    find(&[SUBSCRIPTION_ESCROW_SEED, subscription.as_ref()])
+}
+
+pub fn offtake_offer(farmer: &Pubkey) -> Pubkey {
+    find(&[OFFTAKE_OFFER_SEED, farmer.as_ref()])
+}
+
+pub fn offtake_vault(offer: &Pubkey) -> Pubkey {
+    find(&[OFFTAKE_VAULT_SEED, offer.as_ref()])
+}
+
+pub fn treasury_config() -> Pubkey {
+    find(&[TREASURY_CONFIG_SEED])
+}
+
+pub fn treasury_authority() -> Pubkey {
+    find(&[TREASURY_AUTHORITY_SEED])
+}
+
+pub fn treasury_withdrawal(id: u64) -> Pubkey {
+    find(&[TREASURY_WITHDRAWAL_SEED, &id.to_le_bytes()])
+}
+
+pub fn bounty_config() -> Pubkey {
+    find(&[BOUNTY_CONFIG_SEED])
+}
+
+pub fn bounty_vault() -> Pubkey {
+    find(&[BOUNTY_VAULT_SEED])
+}
+
+pub fn fraud_report(claim_id: u64) -> Pubkey {
+    find(&[FRAUD_REPORT_SEED, &claim_id.to_le_bytes()])
+}
@@ -0,0 +1,202 @@
+//! Readers for snarkjs JSON output — proofs, public inputs and verifying
+//! keys — converted to the program's encodings: big-endian
+//! field elements, G1 = x | y, G2 = x_c1 | x_c0 | y_c1 | y_c0.
+//!
+//! snarkjs writes field elements as decimal strings and points in
+//! projective form `[x, y, z]`; z is 1 for affine points and 0 for the
+//! point at infinity, which the program encodes as all zeros.
+
+use kisan_depin::{groth16, plonk, ProofPoints};
+use serde_json::Value;
+
+use crate::{ClientError, Result};
+
+fn invalid(what: impl Into<String>) -> ClientError {
+    ClientError::Snarkjs(what.into())
+}
+
+/// A decimal field element as 32 big-endian bytes.
+pub fn field(decimal: &str) -> Result<[u8; 32]> {
+    let mut out = [0u8; 32];
+    if decimal.is_empty() {
+        return Err(invalid("empty field element"));
+    }
+    for c in decimal.chars() {
+        let mut carry = c
+            .to_digit(10)
+            .ok_or_else(|| invalid(format!("not a decimal number: {decimal}")))?;
+        for byte in out.iter_mut().rev() {
+            let v = *byte as u32 * 10 + carry;
+            *byte = v as u8;
+            carry = v >> 8;
+        }
+        if carry != 0 {
+            return Err(invalid(format!("field element too large: {decimal}")));
+        }
+    }
+    Ok(out)
+}
+
+fn str_at(v: &Value, i: usize) -> Result<&str> {
+    v.get(i)
+        .and_then(Value::as_str)
+        .ok_or_else(|| invalid(format!("expected a string at index {i} of {v}")))
+}
+
+fn is_infinity(v: &Value) -> bool {
+    match v.get(2) {
+        Some(Value::String(z)) => z == "0",
+        Some(Value::Array(z)) => z.iter().all(|c| c.as_str() == Some("0")),
+        _ => false,
+    }
+}
+
+/// A G1 point `[x, y, z]`.
+pub fn g1(v: &Value) -> Result<[u8; 64]> {
+    let mut out = [0u8; 64];
+    if is_infinity(v) {
+        return Ok(out);
+    }
+    out[..32].copy_from_slice(&field(str_at(v, 0)?)?);
+    out[32..].copy_from_slice(&field(str_at(v, 1)?)?);
+    Ok(out)
+}
+
+/// A G2 point `[[x_c0, x_c1], [y_c0, y_c1], z]`.
+pub fn g2(v: &Value) -> Result<[u8; 128]> {
+    let mut out = [0u8; 128];
+    if is_infinity(v) {
+        return Ok(out);
+    }
+    let (x, y) = match (v.get(0), v.get(1)) {
+        (Some(x), Some(y)) => (x, y),
+        _ => return Err(invalid(format!("expected a G2 point, got {v}"))),
+    };
+    out[..32].copy_from_slice(&field(str_at(x, 1)?)?);
+    out[32..64].copy_from_slice(&field(str_at(x, 0)?)?);
+    out[64..96].copy_from_slice(&field(str_at(y, 1)?)?);
+    out[96..].copy_from_slice(&field(str_at(y, 0)?)?);
+    Ok(out)
+}
+
+fn key<'a>(v: &'a Value, name: &str) -> Result<&'a Value> {
+    v.get(name)
+        .ok_or_else(|| invalid(format!("missing field `{name}`")))
+}
+
+fn parse(json: &str) -> Result<Value> {
+    serde_json::from_str(json).map_err(|e| invalid(e.to_string()))
+}
+
+/// A `snarkjs groth16 prove` proof, uncompressed.
+pub fn groth16_proof(json: &str) -> Result<ProofPoints> {
+    let v = parse(json)?;
+    Ok(ProofPoints::Uncompressed {
+        a: g1(key(&v, "pi_a")?)?,
+        b: g2(key(&v, "pi_b")?)?,
+        c: g1(key(&v, "pi_c")?)?,
+    })
+}
+
+/// Compress a proof's points, halving their size on the wire.
+pub fn compress(proof: ProofPoints) -> Result<ProofPoints> {
+    use anchor_lang::solana_program::alt_bn128::compression::prelude::{
+        alt_bn128_g1_compress, alt_bn128_g2_compress,
+    };
+    match proof {
+        ProofPoints::Uncompressed { a, b, c } => Ok(ProofPoints::Compressed {
+            a: alt_bn128_g1_compress(&a).map_err(|e| invalid(format!("pi_a: {e:?}")))?,
+            b: alt_bn128_g2_compress(&b).map_err(|e| invalid(format!("pi_b: {e:?}")))?,
+            c: alt_bn128_g1_compress(&c).map_err(|e| invalid(format!("pi_c: {e:?}")))?,
+        }),
+        compressed => Ok(compressed),
+    }
+}
+
+/// `public.json` as the concatenated 32-byte inputs `verify_and_mint`
+/// takes; the first is the commitment.
+pub fn public_signals(json: &str) -> Result<Vec<u8>> {
+    let v = parse(json)?;
+    let inputs = v
+        .as_array()
+        .ok_or_else(|| invalid("public inputs must be an array"))?;
+    let mut out = Vec::with_capacity(32 * inputs.len());
+    for i in 0..inputs.len() {
+        out.extend_from_slice(&field(str_at(&v, i)?)?);
+    }
+    Ok(out)
+}
+
+/// The `protocol` of a snarkjs key: `groth16`, `plonk`, ...
+pub fn protocol(json: &str) -> Result<String> {
+    key(&parse(json)?, "protocol")?
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| invalid("`protocol` must be a string"))
+}
+
+/// A `snarkjs zkey export verificationkey` PLONK key.
+pub fn plonk_vk(json: &str) -> Result<plonk::VerifyingKey> {
+    let v = parse(json)?;
+    let protocol = key(&v, "protocol")?.as_str();
+    if protocol != Some("plonk") {
+        return Err(invalid(format!("expected a plonk key, got {protocol:?}")));
+    }
+    let int = |name| {
+        key(&v, name)?
+            .as_u64()
+            .ok_or_else(|| invalid(format!("`{name}` must be an integer")))
+    };
+    let scalar = |name| {
+        key(&v, name)?
+            .as_str()
+            .ok_or_else(|| invalid(format!("`{name}` must be a decimal string")))
+            .and_then(field)
+    };
+    Ok(plonk::VerifyingKey {
+        power: int("power")?
+            .try_into()
+            .map_err(|_| invalid("`power` out of range"))?,
+        n_public: int("nPublic")?
+            .try_into()
+            .map_err(|_| invalid("`nPublic` out of range"))?,
+        k1: scalar("k1")?,
+        k2: scalar("k2")?,
+        w: scalar("w")?,
+        qm: g1(key(&v, "Qm")?)?,
+        ql: g1(key(&v, "Ql")?)?,
+        qr: g1(key(&v, "Qr")?)?,
+        qo: g1(key(&v, "Qo")?)?,
+        qc: g1(key(&v, "Qc")?)?,
+        s1: g1(key(&v, "S1")?)?,
+        s2: g1(key(&v, "S2")?)?,
+        s3: g1(key(&v, "S3")?)?,
+        x_2: g2(key(&v, "X_2")?)?,
+    })
+}
+
+/// A `snarkjs zkey export verificationkey` Groth16 key.
+pub fn groth16_vk(json: &str) -> Result<groth16::VerifyingKey> {
+    let v = parse(json)?;
+    let protocol = key(&v, "protocol")?.as_str();
+    if protocol != Some("groth16") {
+        return Err(invalid(format!("expected a groth16 key, got {protocol:?}")));
+    }
+    let ic = key(&v, "IC")?
+        .as_array()
+        .ok_or_else(|| invalid("`IC` must be an array"))?
+        .iter()
+        .map(g1)
+        .collect::<Result<Vec<_>>>()?;
+    let n_public = key(&v, "nPublic")?.as_u64();
+    if n_public.map(|n| n as usize + 1) != Some(ic.len()) {
+        return Err(invalid("`IC` must hold nPublic + 1 points"));
+    }
+    Ok(groth16::VerifyingKey {
+        alpha_g1: g1(key(&v, "vk_alpha_1")?)?,
+        beta_g2: g2(key(&v, "vk_beta_2")?)?,
+        gamma_g2: g2(key(&v, "vk_gamma_2")?)?,
+        delta_g2: g2(key(&v, "vk_delta_2")?)?,
+        ic,
+    })
+}
@@ -0,0 +1,7 @@
+//! Definitions shared across the Kisan-DePIN workspace: the on-chain
+//! programs and the client, CLI, indexer and gateway that talk to them.
+//!
+//! Kept free of the Anchor runtime so any of them can depend on it
+//! without pulling in a program's entrypoint.
+
+pub mod seeds;
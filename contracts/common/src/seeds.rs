@@ -0,0 +1,133 @@
+//! PDA seeds, shared by the on-chain programs and every off-chain client
+//! so an address is derived from one definition. Every seed derives
+//! under `kisan_depin`, the only program; the groups name the program
+//! that would own each account if the workspace is split.
+//!
+//! Changing a value here moves every existing account it derives.
+
+// Core issuance: program state, mints, circuits, claims and farmers
+pub const STATE_SEED: &[u8] = b"kisan-depin-state";
+pub const MINT_SEED: &[u8] = b"green-token-mint";
+pub const WATER_MINT_SEED: &[u8] = b"water-token-mint";
+pub const SOIL_MINT_SEED: &[u8] = b"soil-token-mint";
+pub const CIRCUIT_SEED: &[u8] = b"circuit";
+pub const GROTH16_VK_SEED: &[u8] = b"groth16-vk";
+pub const PLONK_VK_SEED: &[u8] = b"plonk-vk";
+pub const PLONK_VK_PREVIOUS_SEED: &[u8] = b"plonk-vk-previous";
+pub const GROTH16_VK_PREVIOUS_SEED: &[u8] = b"groth16-vk-previous";
+pub const AGGREGATE_SEED: &[u8] = b"aggregate";
+pub const PRICING_SEED: &[u8] = b"pricing";
+pub const PARCEL_SEED: &[u8] = b"parcel";
+pub const PAYOUT_SPLIT_SEED: &[u8] = b"payout-split";
+pub const PROOF_SEED: &[u8] = b"proof";
+pub const CLAIM_SEED: &[u8] = b"claim";
+pub const CLAIM_INDEX_SEED: &[u8] = b"claim-index";
+pub const CLAIM_QUEUE_SEED: &[u8] = b"claim-queue";
+pub const QUEUED_CLAIM_SEED: &[u8] = b"queued-claim";
+pub const FARMER_SEED: &[u8] = b"farmer";
+pub const IDENTITY_SEED: &[u8] = b"identity";
+pub const PERSONHOOD_SEED: &[u8] = b"personhood";
+pub const PM_KISAN_SEED: &[u8] = b"pm-kisan";
+pub const REFERRAL_SEED: &[u8] = b"referral";
+pub const REGION_SEED: &[u8] = b"region";
+pub const LAND_RECORD_SEED: &[u8] = b"land-record";
+pub const ATTESTER_SEED: &[u8] = b"attester";
+pub const CLAIM_ATTESTATION_SEED: &[u8] = b"claim-attestation";
+pub const NODE_REWARD_CONFIG_SEED: &[u8] = b"node-reward-config";
+pub const NODE_REWARD_VAULT_SEED: &[u8] = b"node-reward-vault";
+pub const NODE_REWARDS_SEED: &[u8] = b"node-rewards";
+pub const ESCROW_SEED: &[u8] = b"escrow";
+pub const ESCROW_VAULT_SEED: &[u8] = b"escrow-vault";
+pub const ONBOARDING_VAULT_SEED: &[u8] = b"onboarding-vault";
+pub const DELEGATION_SEED: &[u8] = b"delegation";
+pub const SESSION_SEED: &[u8] = b"session";
+pub const CUSTODIAN_SEED: &[u8] = b"custodian";
+pub const CUSTODIAL_LINK_SEED: &[u8] = b"custodial-link";
+pub const PRE_COMMIT_SEED: &[u8] = b"pre-commit";
+pub const RELAYER_SEED: &[u8] = b"relayer";
+pub const RELAY_SLASH_SEED: &[u8] = b"relay-slash";
+pub const CPI_INTEGRATOR_SEED: &[u8] = b"cpi-integrator";
+pub const RENT_POOL_SEED: &[u8] = b"rent-pool";
+pub const LOOKUP_TABLE_SEED: &[u8] = b"lookup-table";
+pub const LOOKUP_TABLE_AUTHORITY_SEED: &[u8] = b"lookup-table-authority";
+pub const STATE_TREE_SEED: &[u8] = b"state-tree";
+pub const STATE_TREE_AUTHORITY_SEED: &[u8] = b"state-tree-authority";
+pub const SEASON_SEED: &[u8] = b"season";
+pub const SEASON_TALLY_SEED: &[u8] = b"season-tally";
+pub const SEASON_SNAPSHOT_SEED: &[u8] = b"season-snapshot";
+pub const MINT_STATS_SEED: &[u8] = b"mint-stats";
+pub const LEADERBOARD_SEED: &[u8] = b"leaderboard";
+pub const BADGE_CONFIG_SEED: &[u8] = b"badge-config";
+pub const BADGE_AUTHORITY_SEED: &[u8] = b"badge-authority";
+pub const RECEIPT_CONFIG_SEED: &[u8] = b"receipt-config";
+pub const RECEIPT_AUTHORITY_SEED: &[u8] = b"receipt-authority";
+pub const CAP_TABLE_SEED: &[u8] = b"cap-table";
+pub const ALLOCATION_VAULT_SEED: &[u8] = b"allocation-vault";
+
+// Administration
+pub const FEATURE_FLAGS_SEED: &[u8] = b"feature-flags";
+pub const CONFIG_SEED: &[u8] = b"config";
+pub const ADMIN_LOG_SEED: &[u8] = b"admin-log";
+pub const RECOVERY_SEED: &[u8] = b"recovery";
+
+// Oracles and off-chain attestations
+pub const WEATHER_ORACLE_SEED: &[u8] = b"weather-oracle";
+pub const WEATHER_REPORT_SEED: &[u8] = b"weather";
+pub const EVM_ORACLE_SEED: &[u8] = b"evm-oracle";
+pub const IMAGERY_SEED: &[u8] = b"imagery";
+pub const DRONE_CONFIG_SEED: &[u8] = b"drone-config";
+pub const DRONE_OPERATOR_SEED: &[u8] = b"drone-operator";
+pub const DRONE_FLIGHT_SEED: &[u8] = b"drone-flight";
+pub const MANDI_REPORTER_SEED: &[u8] = b"mandi-reporter";
+pub const ORACLE_REPORTER_SEED: &[u8] = b"oracle-reporter";
+pub const ORACLE_CONFIG_SEED: &[u8] = b"oracle-config";
+pub const MANDI_FEED_SEED: &[u8] = b"mandi-price";
+
+// Cross-chain
+pub const WORMHOLE_EMITTER_SEED: &[u8] = b"emitter";
+pub const WORMHOLE_MESSAGE_SEED: &[u8] = b"attestation";
+pub const FOREIGN_EMITTER_SEED: &[u8] = b"foreign-emitter";
+pub const VAA_RECEIPT_SEED: &[u8] = b"vaa";
+
+// Marketplace: listings, OTC, bonding curve, subscriptions, payouts, cover
+// and loans
+pub const MARKET_CONFIG_SEED: &[u8] = b"market-config";
+pub const LISTING_SEED: &[u8] = b"listing";
+pub const LISTING_ESCROW_SEED: &[u8] = b"listing-escrow";
+pub const OTC_SEED: &[u8] = b"otc";
+pub const OTC_ESCROW_SEED: &[u8] = b"otc-escrow";
+pub const ROYALTY_SEED: &[u8] = b"royalty";
+pub const ROYALTY_VAULT_SEED: &[u8] = b"royalty-vault";
+pub const BUYER_PROFILE_SEED: &[u8] = b"buyer-profile";
+pub const CURVE_SEED: &[u8] = b"bonding-curve";
+pub const CURVE_RESERVE_SEED: &[u8] = b"curve-reserve";
+pub const SUBSCRIPTION_SEED: &[u8] = b"subscription";
+pub const SUBSCRIPTION_ESCROW_SEED: &[u8] = b"subscription-escrow";
+pub const OFFTAKE_OFFER_SEED: &[u8] = b"offtake-offer";
+pub const OFFTAKE_VAULT_SEED: &[u8] = b"offtake-vault";
+pub const PAYOUT_CONFIG_SEED: &[u8] = b"payout-config";
+pub const VOUCHER_SEED: &[u8] = b"voucher";
+pub const PRICE_FLOOR_SEED: &[u8] = b"price-floor";
+pub const INSURANCE_POOL_SEED: &[u8] = b"insurance-pool";
+pub const COVER_SEED: &[u8] = b"cover";
+pub const LOAN_CONFIG_SEED: &[u8] = b"loan-config";
+pub const LOAN_SEED: &[u8] = b"loan";
+pub const LOAN_COLLATERAL_SEED: &[u8] = b"loan-collateral";
+
+// Governance, staking and treasury
+pub const GOVERNANCE_SEED: &[u8] = b"governance";
+pub const GOVERNANCE_VAULT_SEED: &[u8] = b"governance-vault";
+pub const VOTER_SEED: &[u8] = b"voter";
+pub const PROPOSAL_SEED: &[u8] = b"proposal";
+pub const VOTE_SEED: &[u8] = b"vote";
+pub const SGREEN_POOL_SEED: &[u8] = b"sgreen-pool";
+pub const SGREEN_MINT_SEED: &[u8] = b"sgreen-mint";
+pub const SGREEN_VAULT_SEED: &[u8] = b"sgreen-vault";
+pub const TREASURY_USDC_SEED: &[u8] = b"treasury-usdc";
+pub const TREASURY_GREEN_SEED: &[u8] = b"treasury-green";
+pub const TREASURY_CONFIG_SEED: &[u8] = b"treasury-config";
+pub const TREASURY_AUTHORITY_SEED: &[u8] = b"treasury-authority";
+pub const TREASURY_WITHDRAWAL_SEED: &[u8] = b"treasury-withdrawal";
+pub const BOUNTY_CONFIG_SEED: &[u8] = b"bounty-config";
+pub const BOUNTY_VAULT_SEED: &[u8] = b"bounty-vault";
+pub const FRAUD_REPORT_SEED: &[u8] = b"fraud-report";
@@ -0,0 +1,222 @@
+//! `kisan-gateway` — read-only HTTP API for the dashboard and partners.
+//!
+//!   GET /farmer/{pubkey}/claims   indexer; `?limit=&before_slot=` pages
+//!   GET /stats                    program state + current season, indexer counts
+//!   GET /stats/breakdown          mint totals per region and per circuit
+//!   GET /leaderboard              current epoch's ranking
+//!   GET /proof/{commitment}       proof record, commitment in hex
+//!
+//! Chain reads go through `kisan_depin_client::fetch`; response bodies
+//! are the client's `api` types.
+
+use std::sync::Arc;
+
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::routing::get;
+use axum::{Json, Router};
+use clap::Parser;
+use kisan_depin_client::api::{
+    ClaimView, ErrorBody, FarmerClaims, LeaderboardView, MintStatsView, ProofView, Stats,
+};
+use kisan_depin_client::{fetch, ClientError};
+use serde::Deserialize;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::pubkey::Pubkey;
+use tokio_postgres::NoTls;
+
+const DEFAULT_PAGE: i64 = 50;
+const MAX_PAGE: i64 = 500;
+
+#[derive(Parser)]
+#[command(name = "kisan-gateway", version, about)]
+struct Args {
+    /// RPC endpoint
+    #[arg(long, env = "KISAN_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Indexer database (see `kisan-indexer`)
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: String,
+
+    #[arg(long, env = "KISAN_GATEWAY_LISTEN", default_value = "0.0.0.0:8080")]
+    listen: String,
+}
+
+struct AppState {
+    rpc: RpcClient,
+    db: tokio_postgres::Client,
+}
+
+type Shared = State<Arc<AppState>>;
+
+enum ApiError {
+    BadRequest(String),
+    NotFound(String),
+    Upstream(String),
+}
+
+impl From<ClientError> for ApiError {
+    fn from(e: ClientError) -> Self {
+        match e {
+            ClientError::AccountNotFound(key) => ApiError::NotFound(format!("account {key}")),
+            e => ApiError::Upstream(e.to_string()),
+        }
+    }
+}
+
+impl From<tokio_postgres::Error> for ApiError {
+    fn from(e: tokio_postgres::Error) -> Self {
+        ApiError::Upstream(format!("indexer: {e}"))
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let (status, error) = match self {
+            ApiError::BadRequest(e) => (StatusCode::BAD_REQUEST, e),
+            ApiError::NotFound(e) => (StatusCode::NOT_FOUND, format!("not found: {e}")),
+            ApiError::Upstream(e) => (StatusCode::BAD_GATEWAY, e),
+        };
+        (status, Json(ErrorBody { error })).into_response()
+    }
+}
+
+type ApiResult<T> = Result<Json<T>, ApiError>;
+
+fn parse_pubkey(s: &str) -> Result<Pubkey, ApiError> {
+    s.parse()
+        .map_err(|_| ApiError::BadRequest(format!("invalid pubkey {s}")))
+}
+
+fn parse_commitment(s: &str) -> Result<[u8; 32], ApiError> {
+    let invalid = || ApiError::BadRequest("commitment must be 64 hex characters".into());
+    if s.len() != 64 || !s.is_ascii() {
+        return Err(invalid());
+    }
+    let mut out = [0u8; 32];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).map_err(|_| invalid())?;
+    }
+    Ok(out)
+}
+
+#[derive(Deserialize)]
+struct Page {
+    limit: Option<i64>,
+    before_slot: Option<u64>,
+}
+
+async fn farmer_claims(
+    State(app): Shared,
+    Path(farmer): Path<String>,
+    Query(page): Query<Page>,
+) -> ApiResult<FarmerClaims> {
+    let farmer = parse_pubkey(&farmer)?.to_string();
+    let limit = page.limit.unwrap_or(DEFAULT_PAGE).clamp(1, MAX_PAGE);
+    let before = page.before_slot.map_or(i64::MAX, |s| s as i64);
+    let rows = app
+        .db
+        .query(
+            "SELECT commitment, claim_account, kind, status, farmer, circuit_id, season,
+                 amount::TEXT, serial_start::TEXT, serial_end::TEXT, claim_id::TEXT,
+                 signature, slot
+             FROM claims WHERE farmer = $1 AND slot < $2
+             ORDER BY slot DESC LIMIT $3",
+            &[&farmer, &before, &limit],
+        )
+        .await?;
+    let claims: Vec<ClaimView> = rows
+        .iter()
+        .map(|r| ClaimView {
+            commitment: r.get(0),
+            claim_account: r.get(1),
+            kind: r.get(2),
+            status: r.get(3),
+            farmer: r.get(4),
+            circuit_id: r.get::<_, i32>(5) as u16,
+            season: r.get::<_, Option<i64>>(6).map(|s| s as u32),
+            amount: r.get(7),
+            serial_start: r.get(8),
+            serial_end: r.get(9),
+            claim_id: r.get(10),
+            signature: r.get(11),
+            slot: r.get::<_, i64>(12) as u64,
+        })
+        .collect();
+    let next_before_slot = (claims.len() as i64 == limit)
+        .then(|| claims.last().map(|c| c.slot))
+        .flatten();
+    Ok(Json(FarmerClaims {
+        farmer,
+        claims,
+        next_before_slot,
+    }))
+}
+
+async fn stats(State(app): Shared) -> ApiResult<Stats> {
+    let state = fetch::program_state(&app.rpc).await?;
+    let season = fetch::season(&app.rpc, state.current_season).await?;
+    let row = app
+        .db
+        .query_one(
+            "SELECT (SELECT count(*) FROM farmers),
+                    (SELECT coalesce(sum(amount), 0)::TEXT FROM retirements)",
+            &[],
+        )
+        .await?;
+    let farmers = row.get::<_, i64>(0) as u64;
+    let retired = row
+        .get::<_, String>(1)
+        .parse()
+        .map_err(|_| ApiError::Upstream("indexer: retired total overflows u64".into()))?;
+    Ok(Json(Stats::new(&state, &season, farmers, retired)))
+}
+
+async fn stats_breakdown(State(app): Shared) -> ApiResult<MintStatsView> {
+    let stats = fetch::mint_stats(&app.rpc).await?;
+    Ok(Json((&stats).into()))
+}
+
+async fn leaderboard(State(app): Shared) -> ApiResult<LeaderboardView> {
+    let leaderboard = fetch::leaderboard(&app.rpc).await?;
+    Ok(Json((&leaderboard).into()))
+}
+
+async fn proof(State(app): Shared, Path(commitment): Path<String>) -> ApiResult<ProofView> {
+    let bytes = parse_commitment(&commitment)?;
+    let record = fetch::proof_record(&app.rpc, &bytes)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("proof {commitment}")))?;
+    Ok(Json((&record).into()))
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let (db, connection) = tokio_postgres::connect(&args.database_url, NoTls).await?;
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            eprintln!("postgres connection closed: {e}");
+        }
+    });
+    let app = Arc::new(AppState {
+        rpc: RpcClient::new_with_commitment(args.url, CommitmentConfig::confirmed()),
+        db,
+    });
+
+    let router = Router::new()
+        .route("/farmer/:pubkey/claims", get(farmer_claims))
+        .route("/stats", get(stats))
+        .route("/stats/breakdown", get(stats_breakdown))
+        .route("/leaderboard", get(leaderboard))
+        .route("/proof/:commitment", get(proof))
+        .with_state(app);
+
+    let listener = tokio::net::TcpListener::bind(&args.listen).await?;
+    println!("listening on {}", args.listen);
+    axum::serve(listener, router).await?;
+    Ok(())
+}
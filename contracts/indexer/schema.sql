@@ -0,0 +1,76 @@
+-- Kisan-DePIN indexer schema. Applied on every start; idempotent.
+-- Keys and commitments are stored base58 / hex, as the dashboard shows them.
+
+CREATE TABLE IF NOT EXISTS farmers (
+    wallet          TEXT PRIMARY KEY,
+    region_code     INTEGER NOT NULL,
+    referred_by     TEXT,
+    streak          BIGINT NOT NULL,
+    season_index    BIGINT NOT NULL,
+    season_claims   BIGINT NOT NULL,
+    total_proofs    BIGINT NOT NULL,
+    registered_at   TIMESTAMPTZ NOT NULL,
+    last_claim_at   TIMESTAMPTZ,
+    slot            BIGINT NOT NULL
+);
+
+-- One row per commitment: `zk` claims come from verify_and_mint,
+-- `dmrv` claims from submit_claim / verify_claim / issue_credits.
+CREATE TABLE IF NOT EXISTS claims (
+    commitment      TEXT PRIMARY KEY,
+    claim_account   TEXT UNIQUE,
+    kind            TEXT NOT NULL CHECK (kind IN ('zk', 'dmrv')),
+    status          TEXT NOT NULL CHECK (status IN ('submitted', 'verified', 'issued', 'retired')),
+    farmer          TEXT NOT NULL,
+    circuit_id      INTEGER NOT NULL,
+    season          BIGINT,
+    amount          NUMERIC(20, 0),
+    referral_bonus  NUMERIC(20, 0),
+    serial_start    NUMERIC(20, 0),
+    serial_end      NUMERIC(20, 0),
+    claim_id        NUMERIC(20, 0) UNIQUE,
+    verified_by     TEXT,
+    signature       TEXT NOT NULL,
+    slot            BIGINT NOT NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT now()
+);
+CREATE INDEX IF NOT EXISTS claims_farmer ON claims (farmer, slot DESC);
+-- Databases created before claim IDs
+ALTER TABLE claims ADD COLUMN IF NOT EXISTS claim_id NUMERIC(20, 0) UNIQUE;
+
+CREATE TABLE IF NOT EXISTS retirements (
+    claim_account   TEXT NOT NULL,
+    retired_by      TEXT NOT NULL,
+    amount          NUMERIC(20, 0) NOT NULL,
+    signature       TEXT NOT NULL,
+    slot            BIGINT NOT NULL,
+    retired_at      TIMESTAMPTZ NOT NULL DEFAULT now(),
+    PRIMARY KEY (signature, claim_account)
+);
+CREATE INDEX IF NOT EXISTS retirements_retired_by ON retirements (retired_by);
+
+-- Every BuyerActivity event: the auditable purchase / retirement history
+-- behind each on-chain BuyerProfile, with its running totals.
+CREATE TABLE IF NOT EXISTS buyer_activity (
+    buyer           TEXT NOT NULL,
+    kind            TEXT NOT NULL CHECK (kind IN ('purchase', 'retirement')),
+    reference       TEXT NOT NULL,
+    amount          NUMERIC(20, 0) NOT NULL,
+    usdc_paid       NUMERIC(20, 0) NOT NULL,
+    total_purchased NUMERIC(20, 0) NOT NULL,
+    total_spent     NUMERIC(20, 0) NOT NULL,
+    total_retired   NUMERIC(20, 0) NOT NULL,
+    occurred_at     TIMESTAMPTZ NOT NULL,
+    signature       TEXT NOT NULL,
+    slot            BIGINT NOT NULL,
+    PRIMARY KEY (signature, buyer, reference, kind)
+);
+CREATE INDEX IF NOT EXISTS buyer_activity_buyer ON buyer_activity (buyer, slot);
+
+-- Newest transaction applied, so a restart backfills from there.
+CREATE TABLE IF NOT EXISTS cursor (
+    id              BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    signature       TEXT NOT NULL,
+    slot            BIGINT NOT NULL
+);
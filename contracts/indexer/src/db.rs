@@ -0,0 +1,220 @@
+//! Postgres writes. Every write is idempotent, so replaying a
+//! transaction (backfill overlapping the live stream) is harmless.
+
+use anyhow::Result;
+use kisan_types::{BuyerActivityKind, FarmerProfile};
+use solana_sdk::pubkey::Pubkey;
+use tokio_postgres::{Client, NoTls};
+
+use crate::events::Event;
+
+const SCHEMA: &str = include_str!("../schema.sql");
+
+pub struct Db {
+    client: Client,
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Pubkey column value, `NULL` for the default key.
+fn optional(key: &Pubkey) -> Option<String> {
+    (*key != Pubkey::default()).then(|| key.to_string())
+}
+
+impl Db {
+    pub async fn connect(url: &str) -> Result<Db> {
+        let (client, connection) = tokio_postgres::connect(url, NoTls).await?;
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                eprintln!("postgres connection closed: {e}");
+            }
+        });
+        client.batch_execute(SCHEMA).await?;
+        Ok(Db { client })
+    }
+
+    pub async fn cursor(&self) -> Result<Option<String>> {
+        let row = self
+            .client
+            .query_opt("SELECT signature FROM cursor", &[])
+            .await?;
+        Ok(row.map(|r| r.get(0)))
+    }
+
+    pub async fn set_cursor(&self, signature: &str, slot: u64) -> Result<()> {
+        self.client
+            .execute(
+                "INSERT INTO cursor (signature, slot) VALUES ($1, $2)
+                 ON CONFLICT (id) DO UPDATE SET signature = $1, slot = $2
+                 WHERE cursor.slot <= $2",
+                &[&signature, &(slot as i64)],
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn upsert_farmer(&self, profile: &FarmerProfile, slot: u64) -> Result<()> {
+        self.client
+            .execute(
+                "INSERT INTO farmers (wallet, region_code, referred_by, streak, season_index,
+                     season_claims, total_proofs, registered_at, last_claim_at, slot)
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, to_timestamp($8::BIGINT),
+                     CASE WHEN $9::BIGINT = 0 THEN NULL ELSE to_timestamp($9::BIGINT) END, $10)
+                 ON CONFLICT (wallet) DO UPDATE SET
+                     region_code = EXCLUDED.region_code, referred_by = EXCLUDED.referred_by,
+                     streak = EXCLUDED.streak, season_index = EXCLUDED.season_index,
+                     season_claims = EXCLUDED.season_claims, total_proofs = EXCLUDED.total_proofs,
+                     last_claim_at = EXCLUDED.last_claim_at, slot = EXCLUDED.slot
+                 WHERE farmers.slot <= EXCLUDED.slot",
+                &[
+                    &profile.farmer.to_string(),
+                    &(profile.region_code as i32),
+                    &optional(&profile.referred_by),
+                    &(profile.streak as i64),
+                    &(profile.season_index as i64),
+                    &(profile.season_claims as i64),
+                    &(profile.total_proofs as i64),
+                    &profile.registered_at,
+                    &profile.last_claim_at,
+                    &(slot as i64),
+                ],
+            )
+            .await?;
+        Ok(())
+    }
+
+    pub async fn apply(&self, event: &Event, signature: &str, slot: u64) -> Result<()> {
+        let slot = slot as i64;
+        match event {
+            Event::ProofMinted(e) => {
+                self.client
+                    .execute(
+                        "INSERT INTO claims (commitment, kind, status, farmer, circuit_id, season,
+                             amount, referral_bonus, serial_start, serial_end, claim_id,
+                             verified_by, signature, slot)
+                         VALUES ($1, 'zk', 'issued', $2, $3, $4, $5::TEXT::NUMERIC,
+                             $6::TEXT::NUMERIC, $7::TEXT::NUMERIC, $8::TEXT::NUMERIC,
+                             $9::TEXT::NUMERIC, $10, $11, $12)
+                         ON CONFLICT (commitment) DO NOTHING",
+                        &[
+                            &hex(&e.commitment),
+                            &e.farmer.to_string(),
+                            &(e.circuit_id as i32),
+                            &(e.season as i64),
+                            &e.amount.to_string(),
+                            &e.referral_bonus.to_string(),
+                            &e.serial_start.to_string(),
+                            &e.serial_end.to_string(),
+                            &e.claim_id.to_string(),
+                            &kisan_types::ID.to_string(),
+                            &signature,
+                            &slot,
+                        ],
+                    )
+                    .await?;
+            }
+            Event::ClaimSubmitted(e) => {
+                self.client
+                    .execute(
+                        "INSERT INTO claims (commitment, claim_account, kind, status, farmer,
+                             circuit_id, signature, slot)
+                         VALUES ($1, $2, 'dmrv', 'submitted', $3, $4, $5, $6)
+                         ON CONFLICT (commitment) DO NOTHING",
+                        &[
+                            &hex(&e.commitment),
+                            &e.claim.to_string(),
+                            &e.farmer.to_string(),
+                            &(e.circuit_id as i32),
+                            &signature,
+                            &slot,
+                        ],
+                    )
+                    .await?;
+            }
+            Event::ClaimVerified(e) => {
+                self.client
+                    .execute(
+                        "UPDATE claims SET status = 'verified', verified_by = $2, slot = $3,
+                             updated_at = now()
+                         WHERE claim_account = $1 AND status = 'submitted'",
+                        &[&e.claim.to_string(), &e.verified_by.to_string(), &slot],
+                    )
+                    .await?;
+            }
+            Event::CreditsIssued(e) => {
+                self.client
+                    .execute(
+                        "UPDATE claims SET status = 'issued', amount = $2::TEXT::NUMERIC,
+                             serial_start = $3::TEXT::NUMERIC, serial_end = $4::TEXT::NUMERIC,
+                             claim_id = $5::TEXT::NUMERIC, slot = $6, updated_at = now()
+                         WHERE claim_account = $1 AND status IN ('submitted', 'verified')",
+                        &[
+                            &e.claim.to_string(),
+                            &e.amount.to_string(),
+                            &e.serial_start.to_string(),
+                            &e.serial_end.to_string(),
+                            &e.claim_id.to_string(),
+                            &slot,
+                        ],
+                    )
+                    .await?;
+            }
+            Event::ClaimRetired(e) => {
+                self.client
+                    .execute(
+                        "INSERT INTO retirements (claim_account, retired_by, amount, signature, slot)
+                         VALUES ($1, $2, $3::TEXT::NUMERIC, $4, $5)
+                         ON CONFLICT DO NOTHING",
+                        &[
+                            &e.claim.to_string(),
+                            &e.retired_by.to_string(),
+                            &e.amount.to_string(),
+                            &signature,
+                            &slot,
+                        ],
+                    )
+                    .await?;
+                self.client
+                    .execute(
+                        "UPDATE claims SET status = 'retired', slot = $2, updated_at = now()
+                         WHERE claim_account = $1",
+                        &[&e.claim.to_string(), &slot],
+                    )
+                    .await?;
+            }
+            Event::BuyerActivity(e) => {
+                let kind = match e.kind {
+                    BuyerActivityKind::Purchase => "purchase",
+                    BuyerActivityKind::Retirement => "retirement",
+                };
+                self.client
+                    .execute(
+                        "INSERT INTO buyer_activity (buyer, kind, reference, amount, usdc_paid,
+                             total_purchased, total_spent, total_retired, occurred_at,
+                             signature, slot)
+                         VALUES ($1, $2, $3, $4::TEXT::NUMERIC, $5::TEXT::NUMERIC,
+                             $6::TEXT::NUMERIC, $7::TEXT::NUMERIC, $8::TEXT::NUMERIC,
+                             to_timestamp($9::BIGINT), $10, $11)
+                         ON CONFLICT DO NOTHING",
+                        &[
+                            &e.buyer.to_string(),
+                            &kind,
+                            &e.reference.to_string(),
+                            &e.amount.to_string(),
+                            &e.usdc_paid.to_string(),
+                            &e.total_purchased.to_string(),
+                            &e.total_spent.to_string(),
+                            &e.total_retired.to_string(),
+                            &e.timestamp,
+                            &signature,
+                            &slot,
+                        ],
+                    )
+                    .await?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+//! Anchor events out of transaction logs.
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use kisan_types::{
+    decode_event, BuyerActivity, ClaimRetired, ClaimSubmitted, ClaimVerified, CreditsIssued,
+    ProofMinted,
+};
+use solana_sdk::pubkey::Pubkey;
+
+pub enum Event {
+    ProofMinted(ProofMinted),
+    ClaimSubmitted(ClaimSubmitted),
+    ClaimVerified(ClaimVerified),
+    CreditsIssued(CreditsIssued),
+    ClaimRetired(ClaimRetired),
+    BuyerActivity(BuyerActivity),
+}
+
+impl Event {
+    /// Decode one `Program data:` payload; `None` for events the indexer
+    /// doesn't store.
+    fn decode(data: &[u8]) -> Option<Event> {
+        decode_event(data)
+            .map(Event::ProofMinted)
+            .or_else(|| decode_event(data).map(Event::ClaimSubmitted))
+            .or_else(|| decode_event(data).map(Event::ClaimVerified))
+            .or_else(|| decode_event(data).map(Event::CreditsIssued))
+            .or_else(|| decode_event(data).map(Event::ClaimRetired))
+            .or_else(|| decode_event(data).map(Event::BuyerActivity))
+    }
+}
+
+/// Events emitted by `program_id` in a transaction's logs, in order.
+///
+/// Follows the invoke stack so `Program data:` lines from other programs
+/// (CPI targets, or programs that invoke this one) are ignored.
+pub fn parse(program_id: &Pubkey, logs: &[String]) -> Vec<Event> {
+    let id = program_id.to_string();
+    let mut stack: Vec<&str> = Vec::new();
+    let mut events = Vec::new();
+    for line in logs {
+        let Some(rest) = line.strip_prefix("Program ") else {
+            continue;
+        };
+        if let Some(data) = rest.strip_prefix("data: ") {
+            if stack.last() == Some(&id.as_str()) {
+                if let Some(event) = STANDARD.decode(data).ok().and_then(|d| Event::decode(&d)) {
+                    events.push(event);
+                }
+            }
+        } else if let Some((program, tail)) = rest.split_once(' ') {
+            if tail.starts_with("invoke [") {
+                stack.push(program);
+            } else if tail == "success" || tail.starts_with("failed") {
+                stack.pop();
+            }
+        }
+    }
+    events
+}
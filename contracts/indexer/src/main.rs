@@ -0,0 +1,187 @@
+//! `kisan-indexer` — materializes Kisan-DePIN farmers, claims,
+//! retirements and buyer activity into Postgres for the dashboard.
+//!
+//! Farmers come from `FarmerProfile` accounts (one scan at startup, then
+//! a program-account subscription). Claims, retirements and buyer
+//! activity come from the program's events: on startup the indexer
+//! replays every transaction since its cursor, then follows the live log
+//! subscription. Marketplace listings are short-lived and read straight
+//! from chain, so there is no listings table.
+
+mod db;
+mod events;
+
+use anyhow::Result;
+use clap::Parser;
+use futures_util::StreamExt;
+use kisan_types::{Discriminator, FarmerProfile};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::{
+    RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcTransactionConfig, RpcTransactionLogsConfig,
+    RpcTransactionLogsFilter,
+};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_sdk::account::Account;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::signature::Signature;
+use solana_transaction_status::UiTransactionEncoding;
+
+use db::Db;
+
+#[derive(Parser)]
+#[command(name = "kisan-indexer", version, about)]
+struct Args {
+    /// RPC endpoint
+    #[arg(long, env = "KISAN_RPC_URL", default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Websocket endpoint
+    #[arg(long, env = "KISAN_WS_URL", default_value = "ws://127.0.0.1:8900")]
+    ws_url: String,
+
+    /// Postgres connection string
+    #[arg(long, env = "DATABASE_URL")]
+    database_url: String,
+}
+
+fn farmer_accounts_config() -> RpcProgramAccountsConfig {
+    RpcProgramAccountsConfig {
+        filters: Some(vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            0,
+            FarmerProfile::DISCRIMINATOR.to_vec(),
+        ))]),
+        account_config: RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
+
+async fn store_farmer(db: &Db, account: &Account, slot: u64) -> Result<()> {
+    match kisan_types::decode_account::<FarmerProfile>(&account.data) {
+        Some(profile) => db.upsert_farmer(&profile, slot).await,
+        None => {
+            eprintln!("skipping undecodable farmer profile");
+            Ok(())
+        }
+    }
+}
+
+/// Apply one transaction's events and advance the cursor past it.
+async fn apply_logs(db: &Db, signature: &str, slot: u64, logs: &[String]) -> Result<()> {
+    for event in events::parse(&kisan_types::ID, logs) {
+        db.apply(&event, signature, slot).await?;
+    }
+    db.set_cursor(signature, slot).await
+}
+
+/// Replay every program transaction newer than the cursor, oldest first.
+async fn backfill(rpc: &RpcClient, db: &Db) -> Result<()> {
+    let until = db
+        .cursor()
+        .await?
+        .map(|s| s.parse::<Signature>())
+        .transpose()?;
+    let mut before = None;
+    let mut pending = Vec::new();
+    loop {
+        let page = rpc
+            .get_signatures_for_address_with_config(
+                &kisan_types::ID,
+                GetConfirmedSignaturesForAddress2Config {
+                    before,
+                    until,
+                    limit: None,
+                    commitment: Some(CommitmentConfig::confirmed()),
+                },
+            )
+            .await?;
+        let Some(last) = page.last() else { break };
+        before = Some(last.signature.parse()?);
+        pending.extend(page);
+    }
+
+    println!("backfilling {} transactions", pending.len());
+    for status in pending.into_iter().rev() {
+        if status.err.is_some() {
+            db.set_cursor(&status.signature, status.slot).await?;
+            continue;
+        }
+        let tx = rpc
+            .get_transaction_with_config(
+                &status.signature.parse()?,
+                RpcTransactionConfig {
+                    encoding: Some(UiTransactionEncoding::Json),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                    max_supported_transaction_version: Some(0),
+                },
+            )
+            .await?;
+        let logs: Option<Vec<String>> = tx.transaction.meta.and_then(|m| m.log_messages.into());
+        apply_logs(
+            db,
+            &status.signature,
+            status.slot,
+            &logs.unwrap_or_default(),
+        )
+        .await?;
+    }
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    let args = Args::parse();
+    let rpc = RpcClient::new_with_commitment(args.url, CommitmentConfig::confirmed());
+    let db = Db::connect(&args.database_url).await?;
+
+    // Subscribe first so nothing lands between the backfill and the stream.
+    let pubsub = PubsubClient::new(&args.ws_url).await?;
+    let (mut logs, _unsubscribe_logs) = pubsub
+        .logs_subscribe(
+            RpcTransactionLogsFilter::Mentions(vec![kisan_types::ID.to_string()]),
+            RpcTransactionLogsConfig {
+                commitment: Some(CommitmentConfig::confirmed()),
+            },
+        )
+        .await?;
+    let (mut farmers, _unsubscribe_farmers) = pubsub
+        .program_subscribe(&kisan_types::ID, Some(farmer_accounts_config()))
+        .await?;
+
+    let slot = rpc.get_slot().await?;
+    let profiles = rpc
+        .get_program_accounts_with_config(&kisan_types::ID, farmer_accounts_config())
+        .await?;
+    println!("loaded {} farmer profiles", profiles.len());
+    for (_, account) in &profiles {
+        store_farmer(&db, account, slot).await?;
+    }
+    backfill(&rpc, &db).await?;
+
+    println!("following {}", kisan_types::ID);
+    loop {
+        tokio::select! {
+            Some(response) = logs.next() => {
+                let slot = response.context.slot;
+                let tx = response.value;
+                if tx.err.is_none() {
+                    apply_logs(&db, &tx.signature, slot, &tx.logs).await?;
+                } else {
+                    db.set_cursor(&tx.signature, slot).await?;
+                }
+            }
+            Some(response) = farmers.next() => {
+                if let Some(account) = response.value.account.decode::<Account>() {
+                    store_farmer(&db, &account, response.context.slot).await?;
+                }
+            }
+            else => anyhow::bail!("subscription closed"),
+        }
+    }
+}
@@ -0,0 +1,423 @@
+//! Compute-unit regression harness.
+//!
+//! Runs the claim path on a `solana-program-test` bank and checks each
+//! instruction's measured cost against its budget in `kisan_depin::compute`.
+//! Build the program first so the bank can load it:
+//!
+//!     anchor build && SBF_OUT_DIR=../../target/deploy cargo bench --bench compute_units
+//!
+//! Exits non-zero if any instruction goes over budget. The `suggested`
+//! column is the measured cost plus 10%, rounded up to 5,000 CU: the
+//! value to set a budget to when measuring it.
+
+use anchor_lang::solana_program::alt_bn128::compression::prelude::{
+    alt_bn128_g1_compress, alt_bn128_g2_compress,
+};
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use kisan_depin::commitment::{CommitmentOpening, SCHEME_POSEIDON};
+use kisan_depin::payload::ProofPayload;
+use kisan_depin::{accounts, compute, instruction, ProofPoints, ProofSystem};
+use kisan_depin_client::dev_circuit;
+use solana_program_test::BanksClient;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::Transaction;
+
+#[path = "../tests/common/mod.rs"]
+mod common;
+
+const REGION: u16 = 1;
+const CIRCUIT: u16 = 1;
+
+/// Headroom over a measured cost, in percent, and the step budgets round to
+const HEADROOM_PCT: u64 = 10;
+const BUDGET_STEP: u64 = 5_000;
+
+fn suggested_budget(used: u64) -> u64 {
+    (used * (100 + HEADROOM_PCT) / 100).div_ceil(BUDGET_STEP) * BUDGET_STEP
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &kisan_depin::ID).0
+}
+
+struct Bench {
+    banks: BanksClient,
+    authority: Keypair,
+    results: Vec<(&'static str, u64, u32)>,
+}
+
+impl Bench {
+    /// Run `ix` and record its cost against `budget`.
+    async fn run(&mut self, name: &'static str, ix: Instruction, signers: &[&Keypair], budget: u32) {
+        let mut all_signers = vec![&self.authority];
+        all_signers.extend_from_slice(signers);
+        let blockhash = self.banks.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            &[ComputeBudgetInstruction::set_compute_unit_limit(1_400_000), ix],
+            Some(&self.authority.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        let outcome = self.banks.process_transaction_with_metadata(tx).await.unwrap();
+        let metadata = outcome.metadata.expect("transaction metadata");
+        if let Err(err) = outcome.result {
+            panic!("{name} failed: {err}\n{}", metadata.log_messages.join("\n"));
+        }
+        // The compute-budget instruction itself costs 150 CU.
+        self.results.push((name, metadata.compute_units_consumed - 150, budget));
+    }
+
+    async fn send(&mut self, ixs: &[Instruction], signers: &[&Keypair]) {
+        let mut all_signers = vec![&self.authority];
+        all_signers.extend_from_slice(signers);
+        let blockhash = self.banks.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            ixs,
+            Some(&self.authority.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+        self.banks.process_transaction(tx).await.unwrap();
+    }
+}
+
+#[tokio::main]
+async fn main() {
+    let authority = Keypair::new();
+    let (banks, _, _) = common::program_test(&authority.pubkey()).start().await;
+    let mut bench = Bench { banks, authority, results: Vec::new() };
+    let authority = bench.authority.pubkey();
+
+    let program_state = pda(&[b"kisan-depin-state"]);
+    let green_mint = pda(&[b"green-token-mint"]);
+    let admin_log = pda(&[b"admin-log"]);
+    let feature_flags = pda(&[b"feature-flags"]);
+    let config = pda(&[b"config"]);
+    let leaderboard = pda(&[b"leaderboard"]);
+    let mint_stats = pda(&[b"mint-stats"]);
+    let season = pda(&[b"season", &0u32.to_le_bytes()]);
+    let season_tally = pda(&[b"season-tally", &0u32.to_le_bytes()]);
+    let region_config = pda(&[b"region", &REGION.to_le_bytes()]);
+    let circuit_config = pda(&[b"circuit", &CIRCUIT.to_le_bytes()]);
+
+    // ── Setup ──
+    let budgets = [
+        ("initialize", compute::INITIALIZE),
+        ("initialize_admin_log", compute::INITIALIZE_ADMIN_LOG),
+        ("initialize_feature_flags", compute::INITIALIZE_FEATURE_FLAGS),
+        ("initialize_config", compute::INITIALIZE_CONFIG),
+        ("initialize_leaderboard", compute::INITIALIZE_LEADERBOARD),
+        ("initialize_mint_stats", compute::INITIALIZE_MINT_STATS),
+    ];
+    for (ix, (name, budget)) in kisan_depin_client::bootstrap(&authority).into_iter().zip(budgets) {
+        bench.run(name, ix, &[], budget).await;
+    }
+    bench
+        .run(
+            "initialize_seasons",
+            Instruction {
+                program_id: kisan_depin::ID,
+                accounts: accounts::InitializeSeasons {
+                    authority,
+                    program_state,
+                    admin_log,
+                    season,
+                    season_tally,
+                    config,
+                    system_program: solana_sdk::system_program::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::InitializeSeasons {
+                    duration: 180 * 86_400,
+                    reward_amount: 1_000_000_000,
+                    mint_cap: u64::MAX / 2,
+                    max_claims_per_farmer: 100,
+                }
+                .data(),
+            },
+            &[],
+            compute::INITIALIZE_SEASONS,
+        )
+        .await;
+    bench
+        .run(
+            "configure_region",
+            Instruction {
+                program_id: kisan_depin::ID,
+                accounts: accounts::ConfigureRegion {
+                    authority,
+                    program_state,
+                    admin_log,
+                    region_config,
+                    system_program: solana_sdk::system_program::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::ConfigureRegion {
+                    region_code: REGION,
+                    require_identity: false,
+                }
+                .data(),
+            },
+            &[],
+            compute::CONFIGURE_REGION,
+        )
+        .await;
+    bench
+        .run(
+            "configure_circuit",
+            Instruction {
+                program_id: kisan_depin::ID,
+                accounts: accounts::ConfigureCircuit {
+                    authority,
+                    program_state,
+                    admin_log,
+                    circuit_config,
+                    system_program: solana_sdk::system_program::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::ConfigureCircuit {
+                    circuit_id: CIRCUIT,
+                    active: true,
+                    green_enabled: true,
+                    water_amount: 0,
+                    soil_amount: 0,
+                    methodology_id: 0,
+                    proof_system: ProofSystem::Groth16,
+                }
+                .data(),
+            },
+            &[],
+            compute::CONFIGURE_CIRCUIT,
+        )
+        .await;
+    let groth16_vk = pda(&[b"groth16-vk", &CIRCUIT.to_le_bytes()]);
+    let previous_groth16_vk = pda(&[b"groth16-vk-previous", &CIRCUIT.to_le_bytes()]);
+    bench
+        .send(
+            &[Instruction {
+                program_id: kisan_depin::ID,
+                accounts: accounts::SetGroth16VerifyingKey {
+                    authority,
+                    program_state,
+                    admin_log,
+                    circuit_config,
+                    groth16_vk,
+                    previous_groth16_vk,
+                    system_program: solana_sdk::system_program::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::SetGroth16VerifyingKey {
+                    key: dev_circuit::verifying_key(2),
+                }
+                .data(),
+            }],
+            &[],
+        )
+        .await;
+
+    // ── Farmer ──
+    let farmer = Keypair::new();
+    let farmer_profile = pda(&[b"farmer", farmer.pubkey().as_ref()]);
+    // Every benched claim lands on the first page
+    let claim_index = pda(&[b"claim-index", farmer.pubkey().as_ref(), &0u64.to_le_bytes()]);
+    bench
+        .run(
+            "register_farmer",
+            Instruction {
+                program_id: kisan_depin::ID,
+                accounts: accounts::RegisterFarmer {
+                    payer: authority,
+                    farmer: farmer.pubkey(),
+                    program_state,
+                    personhood_verifier: None,
+                    personhood_record: None,
+                    referral_code: None,
+                    region_config,
+                    farmer_profile,
+                    config,
+                    green_mint: None,
+                    onboarding_vault: None,
+                    token_program: None,
+                    system_program: solana_sdk::system_program::ID,
+                }
+                .to_account_metas(None),
+                data: instruction::RegisterFarmer {
+                    region_code: REGION,
+                    personhood_nullifier: [0; 32],
+                }
+                .data(),
+            },
+            &[&farmer],
+            compute::REGISTER_FARMER,
+        )
+        .await;
+
+    let token_account = Keypair::new();
+    let rent = bench.banks.get_rent().await.unwrap();
+    bench
+        .send(
+            &[
+                // The farmer pays proof-record rent on its own claims
+                system_instruction::transfer(&authority, &farmer.pubkey(), 1_000_000_000),
+                system_instruction::create_account(
+                    &authority,
+                    &token_account.pubkey(),
+                    rent.minimum_balance(spl_token::state::Account::LEN),
+                    spl_token::state::Account::LEN as u64,
+                    &spl_token::ID,
+                ),
+                spl_token::instruction::initialize_account3(
+                    &spl_token::ID,
+                    &token_account.pubkey(),
+                    &green_mint,
+                    &farmer.pubkey(),
+                )
+                .unwrap(),
+            ],
+            &[&token_account],
+        )
+        .await;
+
+    // ── Claims ──
+    // Two public inputs, as the compliance circuit has: the commitment and
+    // the expected status hash
+    for (name, compress, budget, nonce, memo) in [
+        ("verify_and_mint", false, compute::VERIFY_AND_MINT, 1u8, false),
+        (
+            "verify_and_mint (compressed)",
+            true,
+            compute::VERIFY_AND_MINT_COMPRESSED,
+            2u8,
+            false,
+        ),
+        (
+            "verify_and_mint (memo)",
+            false,
+            compute::VERIFY_AND_MINT + compute::CLAIM_MEMO,
+            3u8,
+            true,
+        ),
+    ] {
+        let opening = CommitmentOpening {
+            scheme: SCHEME_POSEIDON,
+            parcel: Pubkey::new_unique(),
+            nonce: [nonce; 32],
+        };
+        let commitment = opening.commit(CIRCUIT, &farmer.pubkey(), 0).unwrap();
+        let mut public_signals = commitment.to_vec();
+        public_signals.extend_from_slice(&[0x0b; 32]);
+        let proof = match dev_circuit::prove(&public_signals) {
+            ProofPoints::Uncompressed { a, b, c } if compress => ProofPoints::Compressed {
+                a: alt_bn128_g1_compress(&a).unwrap(),
+                b: alt_bn128_g2_compress(&b).unwrap(),
+                c: alt_bn128_g1_compress(&c).unwrap(),
+            },
+            proof => proof,
+        };
+
+        bench
+            .run(
+                name,
+                Instruction {
+                    program_id: kisan_depin::ID,
+                    accounts: accounts::VerifyAndMint {
+                        payer: farmer.pubkey(),
+                        claimant: farmer.pubkey(),
+                        farmer: farmer.pubkey(),
+                        delegation: None,
+                        session_key: None,
+                        relayer_info: None,
+                        rent_pool: None,
+                        program_state,
+                        green_mint,
+                        farmer_token_account: token_account.pubkey(),
+                        custodial_link: None,
+                        payout_split: pda(&[b"payout-split", opening.parcel.as_ref()]),
+                        landowner_token_account: None,
+                        farmer_profile: Some(farmer_profile),
+                        claim_index: Some(claim_index),
+                        config,
+                        region_config: Some(region_config),
+                        identity_attestation: None,
+                        pm_kisan_attestation: None,
+                        referral_code: None,
+                        referrer_token_account: None,
+                        onboarding_vault: None,
+                        proof_record: pda(&[b"proof", &commitment]),
+                        leaderboard,
+                        season,
+                        season_tally,
+                        mint_stats,
+                        circuit_config,
+                        feature_flags,
+                        pricing_config: None,
+                        price_feed: None,
+                        groth16_vk: Some(groth16_vk),
+                        previous_groth16_vk: None,
+                        plonk_vk: None,
+                        previous_plonk_vk: None,
+                        pre_commit: None,
+                        verifier_program: None,
+                        water_mint: None,
+                        water_token_account: None,
+                        soil_mint: None,
+                        soil_token_account: None,
+                        receipt_config: None,
+                        receipt_authority: None,
+                        receipt_tree_config: None,
+                        receipt_merkle_tree: None,
+                        log_wrapper: None,
+                        compression_program: None,
+                        bubblegum_program: None,
+                        instructions_sysvar: None,
+                        cpi_integrator: None,
+                        memo_program: memo.then_some(kisan_depin::memo::MEMO_PROGRAM_ID),
+                        token_program: spl_token::ID,
+                        system_program: solana_sdk::system_program::ID,
+                    }
+                    .to_account_metas(None),
+                    data: instruction::VerifyAndMint {
+                        payload: ProofPayload::groth16(&proof, public_signals),
+                        compliance_commitment: commitment,
+                        opening,
+                        evidence_cid: None,
+                    }
+                    .data(),
+                },
+                &[&farmer],
+                budget,
+            )
+            .await;
+    }
+
+    // ── Report ──
+    println!(
+        "{:<32} {:>10} {:>10} {:>10}",
+        "instruction", "CU", "budget", "suggested"
+    );
+    let mut over = 0;
+    for (name, used, budget) in &bench.results {
+        let flag = if *used > *budget as u64 { "  OVER" } else { "" };
+        println!(
+            "{:<32} {:>10} {:>10} {:>10}{}",
+            name,
+            used,
+            budget,
+            suggested_budget(*used),
+            flag
+        );
+        if !flag.is_empty() {
+            over += 1;
+        }
+    }
+    if over > 0 {
+        eprintln!("{over} instruction(s) over their compute budget");
+        std::process::exit(1);
+    }
+}
@@ -0,0 +1,459 @@
+// ============================================================
+// BN254 point validation
+// ============================================================
+//
+// The alt_bn128 syscalls reject malformed points with a single
+// generic error, so proof points are checked here first and
+// each failure gets its own code:
+//
+//   - coordinates ≥ q, or points off the curve, fail with
+//     `InvalidPointEncoding`
+//   - G2 points outside the order-r subgroup fail with
+//     `PointNotInSubgroup`
+//
+// G1 has cofactor 1, so a G1 point on the curve is in the
+// subgroup. For G2 we check the twist equation here and leave
+// the subgroup check to the pairing syscall, which deserializes
+// its inputs with full validation: a G2 point that is on the
+// twist but rejected there lies outside the subgroup.
+//
+// Encodings are big-endian, as for the Ethereum precompiles:
+// G1 = x | y, G2 = x_c1 | x_c0 | y_c1 | y_c0. All zeros is the
+// point at infinity. Compressed points (32 / 64 bytes) carry x
+// only and are expanded with the alt_bn128 compression syscalls.
+//
+// The G1 syscall wrappers the PLONK and Groth16 verifiers share
+// live here too.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::alt_bn128::compression::prelude::{
+    alt_bn128_g1_decompress, alt_bn128_g2_decompress,
+};
+use anchor_lang::solana_program::alt_bn128::prelude::{
+    alt_bn128_addition, alt_bn128_multiplication, alt_bn128_pairing,
+};
+
+use crate::KisanError;
+
+/// BN254 base field modulus q, little-endian limbs
+pub(crate) const Q: [u64; 4] = [
+    0x3c208c16d87cfd47,
+    0x97816a916871ca8d,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+/// BN254 scalar field modulus r, little-endian limbs
+pub(crate) const R: [u64; 4] = [
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+/// -q⁻¹ mod 2⁶⁴
+const Q_INV: u64 = 0x87d20782e4866389;
+/// 2⁵¹² mod q, to enter Montgomery form
+const Q_R2: [u64; 4] = [
+    0xf32cfc5b538afa89,
+    0xb5e71911d44501fb,
+    0x47ab1eff0a417ff6,
+    0x06d89f71cab8351f,
+];
+/// Twist coefficient b' = 3 / (9 + u), as (c0, c1) little-endian limbs
+const TWIST_B: ([u64; 4], [u64; 4]) = (
+    [
+        0x3267e6dc24a138e5,
+        0xb5b4c5e559dbefa3,
+        0x81be18991be06ac3,
+        0x2b149d40ceb8aaae,
+    ],
+    [
+        0xe4a2bd0685c315d2,
+        0xa74fa084e52d1852,
+        0xcd2cafadeed8fdf4,
+        0x009713b03af0fed4,
+    ],
+);
+
+/// G1 generator (1, 2)
+pub const G1_GENERATOR: [u8; 64] = {
+    let mut p = [0u8; 64];
+    p[31] = 1;
+    p[63] = 2;
+    p
+};
+
+/// G2 generator, as x_c1 | x_c0 | y_c1 | y_c0
+pub const G2_GENERATOR: [u8; 128] = [
+    0x19, 0x8e, 0x93, 0x93, 0x92, 0x0d, 0x48, 0x3a, 0x72, 0x60, 0xbf, 0xb7, 0x31, 0xfb, 0x5d, 0x25,
+    0xf1, 0xaa, 0x49, 0x33, 0x35, 0xa9, 0xe7, 0x12, 0x97, 0xe4, 0x85, 0xb7, 0xae, 0xf3, 0x12, 0xc2,
+    0x18, 0x00, 0xde, 0xef, 0x12, 0x1f, 0x1e, 0x76, 0x42, 0x6a, 0x00, 0x66, 0x5e, 0x5c, 0x44, 0x79,
+    0x67, 0x43, 0x22, 0xd4, 0xf7, 0x5e, 0xda, 0xdd, 0x46, 0xde, 0xbd, 0x5c, 0xd9, 0x92, 0xf6, 0xed,
+    0x09, 0x06, 0x89, 0xd0, 0x58, 0x5f, 0xf0, 0x75, 0xec, 0x9e, 0x99, 0xad, 0x69, 0x0c, 0x33, 0x95,
+    0xbc, 0x4b, 0x31, 0x33, 0x70, 0xb3, 0x8e, 0xf3, 0x55, 0xac, 0xda, 0xdc, 0xd1, 0x22, 0x97, 0x5b,
+    0x12, 0xc8, 0x5e, 0xa5, 0xdb, 0x8c, 0x6d, 0xeb, 0x4a, 0xab, 0x71, 0x80, 0x8d, 0xcb, 0x40, 0x8f,
+    0xe3, 0xd1, 0xe7, 0x69, 0x0c, 0x43, 0xd3, 0x7b, 0x4c, 0xe6, 0xcc, 0x01, 0x66, 0xfa, 0x7d, 0xaa,
+];
+
+// ─────────────────────────────────────────────────────────────
+// Limb arithmetic
+// ─────────────────────────────────────────────────────────────
+
+pub(crate) fn geq(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+pub(crate) fn sub_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut out = [0u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(borrow as u64);
+        out[i] = d;
+        borrow = b1 || b2;
+    }
+    (out, borrow)
+}
+
+pub(crate) fn add_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut out = [0u64; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(carry as u64);
+        out[i] = s;
+        carry = c1 || c2;
+    }
+    (out, carry)
+}
+
+pub(crate) fn limbs_from_be(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut limbs = [0u64; 4];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let start = 32 - 8 * (i + 1);
+        *limb = u64::from_be_bytes(bytes[start..start + 8].try_into().unwrap());
+    }
+    limbs
+}
+
+pub(crate) fn limbs_to_be(limbs: &[u64; 4]) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    for (i, limb) in limbs.iter().enumerate() {
+        let start = 32 - 8 * (i + 1);
+        bytes[start..start + 8].copy_from_slice(&limb.to_be_bytes());
+    }
+    bytes
+}
+
+// ─────────────────────────────────────────────────────────────
+// Base field
+// ─────────────────────────────────────────────────────────────
+
+/// Element of the base field, in Montgomery form.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Fq([u64; 4]);
+
+impl Fq {
+    fn from_limbs(limbs: [u64; 4]) -> Fq {
+        Fq(limbs).mul(&Fq(Q_R2))
+    }
+
+    /// Parse a canonical (< q) big-endian coordinate.
+    fn from_be(bytes: &[u8]) -> Result<Fq> {
+        let limbs = limbs_from_be(bytes.try_into().unwrap());
+        require!(!geq(&limbs, &Q), KisanError::InvalidPointEncoding);
+        Ok(Fq::from_limbs(limbs))
+    }
+
+    fn add(&self, other: &Fq) -> Fq {
+        let (sum, carry) = add_limbs(&self.0, &other.0);
+        if carry || geq(&sum, &Q) {
+            Fq(sub_limbs(&sum, &Q).0)
+        } else {
+            Fq(sum)
+        }
+    }
+
+    fn sub(&self, other: &Fq) -> Fq {
+        let (diff, borrow) = sub_limbs(&self.0, &other.0);
+        if borrow {
+            Fq(add_limbs(&diff, &Q).0)
+        } else {
+            Fq(diff)
+        }
+    }
+
+    /// Montgomery multiplication (CIOS).
+    fn mul(&self, other: &Fq) -> Fq {
+        let (a, b) = (&self.0, &other.0);
+        let mut t = [0u64; 6];
+        for &bi in b.iter() {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let s = t[j] as u128 + a[j] as u128 * bi as u128 + carry;
+                t[j] = s as u64;
+                carry = s >> 64;
+            }
+            let s = t[4] as u128 + carry;
+            t[4] = s as u64;
+            t[5] = (s >> 64) as u64;
+
+            let m = t[0].wrapping_mul(Q_INV);
+            let s = t[0] as u128 + m as u128 * Q[0] as u128;
+            let mut carry = s >> 64;
+            for j in 1..4 {
+                let s = t[j] as u128 + m as u128 * Q[j] as u128 + carry;
+                t[j - 1] = s as u64;
+                carry = s >> 64;
+            }
+            let s = t[4] as u128 + carry;
+            t[3] = s as u64;
+            t[4] = t[5] + (s >> 64) as u64;
+        }
+        let out = [t[0], t[1], t[2], t[3]];
+        if t[4] != 0 || geq(&out, &Q) {
+            Fq(sub_limbs(&out, &Q).0)
+        } else {
+            Fq(out)
+        }
+    }
+
+    fn square(&self) -> Fq {
+        self.mul(self)
+    }
+}
+
+/// Element of Fq2 = Fq[u] / (u² + 1), as c0 + c1·u.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Fq2(Fq, Fq);
+
+impl Fq2 {
+    /// Parse `c1 | c0`, the precompile order.
+    fn from_be(bytes: &[u8]) -> Result<Fq2> {
+        Ok(Fq2(Fq::from_be(&bytes[32..64])?, Fq::from_be(&bytes[..32])?))
+    }
+
+    fn add(&self, other: &Fq2) -> Fq2 {
+        Fq2(self.0.add(&other.0), self.1.add(&other.1))
+    }
+
+    fn mul(&self, other: &Fq2) -> Fq2 {
+        let (a0, a1, b0, b1) = (&self.0, &self.1, &other.0, &other.1);
+        Fq2(
+            a0.mul(b0).sub(&a1.mul(b1)),
+            a0.mul(b1).add(&a1.mul(b0)),
+        )
+    }
+
+    fn square(&self) -> Fq2 {
+        self.mul(self)
+    }
+}
+
+// ─────────────────────────────────────────────────────────────
+// Points
+// ─────────────────────────────────────────────────────────────
+
+/// Check that `p` is the point at infinity or a point on y² = x³ + 3.
+pub fn validate_g1(p: &[u8; 64]) -> Result<()> {
+    if p.iter().all(|&b| b == 0) {
+        return Ok(());
+    }
+    let x = Fq::from_be(&p[..32])?;
+    let y = Fq::from_be(&p[32..])?;
+    let rhs = x.square().mul(&x).add(&Fq::from_limbs([3, 0, 0, 0]));
+    require!(y.square() == rhs, KisanError::InvalidPointEncoding);
+    Ok(())
+}
+
+/// Check that `p` is the point at infinity or a point of the order-r
+/// subgroup of the twist y² = x³ + 3 / (9 + u).
+pub fn validate_g2(p: &[u8; 128]) -> Result<()> {
+    if p.iter().all(|&b| b == 0) {
+        return Ok(());
+    }
+    let x = Fq2::from_be(&p[..64])?;
+    let y = Fq2::from_be(&p[64..])?;
+    let b = Fq2(Fq::from_limbs(TWIST_B.0), Fq::from_limbs(TWIST_B.1));
+    require!(
+        y.square() == x.square().mul(&x).add(&b),
+        KisanError::InvalidPointEncoding
+    );
+
+    // e(O, p) = 1 for any valid p; the syscall only fails here when its
+    // own validation rejects p, i.e. p is outside the subgroup.
+    let mut input = [0u8; 192];
+    input[64..].copy_from_slice(p);
+    alt_bn128_pairing(&input).map_err(|_| KisanError::PointNotInSubgroup)?;
+    Ok(())
+}
+
+/// Expand a compressed G1 point to the 64-byte form.
+pub fn decompress_g1(p: &[u8; 32]) -> Result<[u8; 64]> {
+    alt_bn128_g1_decompress(p).map_err(|_| KisanError::InvalidPointEncoding.into())
+}
+
+/// Expand a compressed G2 point to the 128-byte form.
+pub fn decompress_g2(p: &[u8; 64]) -> Result<[u8; 128]> {
+    alt_bn128_g2_decompress(p).map_err(|_| KisanError::InvalidPointEncoding.into())
+}
+
+// ─────────────────────────────────────────────────────────────
+// G1 arithmetic
+// ─────────────────────────────────────────────────────────────
+
+pub(crate) fn g1_add(p: &[u8; 64], q: &[u8; 64]) -> Result<[u8; 64]> {
+    let mut input = [0u8; 128];
+    input[..64].copy_from_slice(p);
+    input[64..].copy_from_slice(q);
+    let out = alt_bn128_addition(&input).map_err(|_| KisanError::InvalidProof)?;
+    out.try_into().map_err(|_| KisanError::InvalidProof.into())
+}
+
+/// `s · p`, for a big-endian scalar `s`.
+pub(crate) fn g1_mul(p: &[u8; 64], s: &[u8; 32]) -> Result<[u8; 64]> {
+    let mut input = [0u8; 96];
+    input[..64].copy_from_slice(p);
+    input[64..].copy_from_slice(s);
+    let out = alt_bn128_multiplication(&input).map_err(|_| KisanError::InvalidProof)?;
+    out.try_into().map_err(|_| KisanError::InvalidProof.into())
+}
+
+pub(crate) fn g1_neg(p: &[u8; 64]) -> [u8; 64] {
+    if p.iter().all(|&b| b == 0) {
+        return *p;
+    }
+    let y = limbs_from_be(p[32..].try_into().unwrap());
+    let mut out = *p;
+    out[32..].copy_from_slice(&limbs_to_be(&sub_limbs(&Q, &y).0));
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fq(value: u64) -> Fq {
+        Fq::from_limbs([value, 0, 0, 0])
+    }
+
+    /// Leave Montgomery form.
+    fn canonical(a: &Fq) -> [u64; 4] {
+        a.mul(&Fq([1, 0, 0, 0])).0
+    }
+
+    fn code(result: Result<()>) -> u32 {
+        match result {
+            Err(Error::AnchorError(e)) => e.error_code_number,
+            other => panic!("expected a program error, got {other:?}"),
+        }
+    }
+
+    fn expected(e: KisanError) -> u32 {
+        e.into()
+    }
+
+    #[test]
+    fn fq_arithmetic() {
+        let q_minus = |n: u64| sub_limbs(&Q, &[n, 0, 0, 0]).0;
+        // In and out of Montgomery form, so Q_R2 and Q_INV agree with Q
+        assert_eq!(canonical(&fq(7)), [7, 0, 0, 0]);
+        assert_eq!(canonical(&fq(2).mul(&fq(3))), [6, 0, 0, 0]);
+        assert_eq!(canonical(&fq(5).sub(&fq(7))), q_minus(2));
+        let minus_one = Fq::from_limbs(q_minus(1));
+        assert_eq!(canonical(&minus_one.add(&fq(2))), [1, 0, 0, 0]);
+        assert_eq!(canonical(&minus_one.square()), [1, 0, 0, 0]);
+        // 2¹²⁸ · 2¹²⁸ = 2²⁵⁶, which in Montgomery form is 2⁵¹² mod q
+        let two_128 = Fq::from_limbs([0, 0, 1, 0]);
+        assert_eq!(two_128.square().0, Q_R2);
+    }
+
+    #[test]
+    fn fq2_arithmetic() {
+        let one = fq(1);
+        let zero = fq(0);
+        let minus = |n: u64| fq(0).sub(&fq(n));
+        // u² = -1
+        assert!(Fq2(zero, one).square() == Fq2(minus(1), zero));
+        // (1 + 2u)(3 + 4u) = -5 + 10u
+        assert!(Fq2(fq(1), fq(2)).mul(&Fq2(fq(3), fq(4))) == Fq2(minus(5), fq(10)));
+        // b' (9 + u) = 3
+        let b = Fq2(Fq::from_limbs(TWIST_B.0), Fq::from_limbs(TWIST_B.1));
+        assert!(b.mul(&Fq2(fq(9), one)) == Fq2(fq(3), zero));
+    }
+
+    #[test]
+    fn accepts_the_generators_and_infinity() {
+        validate_g1(&G1_GENERATOR).unwrap();
+        validate_g2(&G2_GENERATOR).unwrap();
+        validate_g1(&[0; 64]).unwrap();
+        validate_g2(&[0; 128]).unwrap();
+    }
+
+    #[test]
+    fn rejects_points_off_the_curve() {
+        let mut p = G1_GENERATOR;
+        p[63] = 3;
+        assert_eq!(
+            code(validate_g1(&p)),
+            expected(KisanError::InvalidPointEncoding)
+        );
+
+        let mut p = G2_GENERATOR;
+        p[127] ^= 1;
+        assert_eq!(
+            code(validate_g2(&p)),
+            expected(KisanError::InvalidPointEncoding)
+        );
+    }
+
+    #[test]
+    fn rejects_coordinates_above_the_modulus() {
+        // y + q: the same point mod q, but not canonical
+        let mut p = G1_GENERATOR;
+        let y = limbs_from_be(p[32..].try_into().unwrap());
+        p[32..].copy_from_slice(&limbs_to_be(&add_limbs(&y, &Q).0));
+        assert_eq!(
+            code(validate_g1(&p)),
+            expected(KisanError::InvalidPointEncoding)
+        );
+
+        let mut p = [0u8; 64];
+        p[..32].copy_from_slice(&limbs_to_be(&Q));
+        assert_eq!(
+            code(validate_g1(&p)),
+            expected(KisanError::InvalidPointEncoding)
+        );
+
+        let mut p = G2_GENERATOR;
+        let x_c0 = limbs_from_be(p[32..64].try_into().unwrap());
+        p[32..64].copy_from_slice(&limbs_to_be(&add_limbs(&x_c0, &Q).0));
+        assert_eq!(
+            code(validate_g2(&p)),
+            expected(KisanError::InvalidPointEncoding)
+        );
+    }
+
+    #[test]
+    fn rejects_a_g2_point_outside_the_subgroup() {
+        // x = 1 on the twist, outside the order-r subgroup
+        let mut p = [0u8; 128];
+        p[63] = 1;
+        p[64..].copy_from_slice(&[
+            0x0d, 0x12, 0x71, 0x95, 0x3e, 0xd9, 0xea, 0x08, 0x36, 0x84, 0x6e, 0x70, 0xa1, 0x93,
+            0x41, 0x87, 0x99, 0x8c, 0x7f, 0x79, 0x0c, 0xb4, 0xd7, 0x51, 0x1b, 0x7f, 0x8d, 0xa8,
+            0x2d, 0xe0, 0x48, 0xa4, 0x28, 0x69, 0x11, 0x1d, 0x53, 0x81, 0xf0, 0x72, 0xf8, 0xe2,
+            0x72, 0x8f, 0xdb, 0x82, 0x5a, 0x51, 0xaa, 0xdd, 0x70, 0xe5, 0x2c, 0x98, 0x30, 0xe9,
+            0xab, 0x4b, 0x87, 0x1c, 0x05, 0x31, 0xf1, 0xbb,
+        ]);
+        assert_eq!(
+            code(validate_g2(&p)),
+            expected(KisanError::PointNotInSubgroup)
+        );
+    }
+}
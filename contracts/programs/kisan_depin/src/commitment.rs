@@ -0,0 +1,234 @@
+// ============================================================
+// Compliance commitments — versioned, domain-separated
+// ============================================================
+//
+// A claim's commitment binds the proof to who, what and when:
+//
+//   H(DOMAIN_TAG || scheme || circuit_id || farmer || parcel
+//     || season || nonce)
+//
+// with integers little-endian, as in PDA seeds (SHA-256), or as
+// field elements (Poseidon, see `hash.rs`). The farmer
+// submits the opening (scheme, parcel, nonce); the rest comes
+// from the claim's accounts, so a commitment can't be replayed
+// under another farmer, circuit or season. The scheme byte lets
+// the hash change without breaking commitments already on-chain.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+
+use crate::hash;
+use crate::KisanError;
+
+/// Domain tag prefixed to every commitment preimage
+pub const DOMAIN_TAG: &[u8] = b"kisan-depin:compliance-commitment";
+
+/// SHA-256 over the preimage
+pub const SCHEME_SHA256: u8 = 1;
+
+/// Poseidon over the preimage as field elements, matching the circuits
+pub const SCHEME_POSEIDON: u8 = 2;
+
+/// Domain tag for Poseidon commitments; must fit one field element
+pub const POSEIDON_DOMAIN_TAG: &[u8] = b"kisan-depin:commitment";
+
+/// Preimage fields the claimant supplies alongside the commitment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct CommitmentOpening {
+    /// Hash scheme the commitment was built with
+    pub scheme: u8,
+    /// Parcel the claim is about
+    pub parcel: Pubkey,
+    /// Farmer-chosen randomness, so commitments can't be guessed
+    pub nonce: [u8; 32],
+}
+
+impl CommitmentOpening {
+    /// Recompute the commitment for this opening.
+    pub fn commit(&self, circuit_id: u16, farmer: &Pubkey, season: u32) -> Result<[u8; 32]> {
+        let scheme = [self.scheme];
+        let preimage: [&[u8]; 7] = [
+            DOMAIN_TAG,
+            &scheme,
+            &circuit_id.to_le_bytes(),
+            farmer.as_ref(),
+            self.parcel.as_ref(),
+            &season.to_le_bytes(),
+            &self.nonce,
+        ];
+        match self.scheme {
+            SCHEME_SHA256 => Ok(hashv(&preimage).to_bytes()),
+            SCHEME_POSEIDON => {
+                let [farmer_hi, farmer_lo] = hash::field_from_pubkey(farmer);
+                let [parcel_hi, parcel_lo] = hash::field_from_pubkey(&self.parcel);
+                hash::poseidon(&[
+                    hash::field_from_bytes(POSEIDON_DOMAIN_TAG),
+                    hash::field_from_u64(circuit_id as u64),
+                    farmer_hi,
+                    farmer_lo,
+                    parcel_hi,
+                    parcel_lo,
+                    hash::field_from_u64(season as u64),
+                    self.nonce,
+                ])
+            }
+            _ => err!(KisanError::UnsupportedCommitmentScheme),
+        }
+    }
+}
+
+/// Check that `commitment` opens to `opening` for this claim and is the
+/// first public input of the proof.
+pub fn verify(
+    commitment: &[u8; 32],
+    opening: &CommitmentOpening,
+    circuit_id: u16,
+    farmer: &Pubkey,
+    season: u32,
+    public_signals: &[u8],
+) -> Result<()> {
+    require!(
+        opening.commit(circuit_id, farmer, season)? == *commitment,
+        KisanError::CommitmentMismatch
+    );
+    require!(
+        public_signals.get(..32) == Some(&commitment[..]),
+        KisanError::InvalidPublicSignals
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    /// A pubkey from its 16-byte halves, as the circuit takes it.
+    fn pubkey(hi: u128, lo: u128) -> Pubkey {
+        let mut bytes = [0u8; 32];
+        bytes[..16].copy_from_slice(&hi.to_be_bytes());
+        bytes[16..].copy_from_slice(&lo.to_be_bytes());
+        Pubkey::new_from_array(bytes)
+    }
+
+    fn field(value: u128) -> [u8; 32] {
+        hash::field_from_bytes(&value.to_be_bytes())
+    }
+
+    fn code(result: Result<()>) -> u32 {
+        match result {
+            Err(Error::AnchorError(e)) => e.error_code_number,
+            other => panic!("expected a program error, got {other:?}"),
+        }
+    }
+
+    fn expected(e: KisanError) -> u32 {
+        e.into()
+    }
+
+    #[test]
+    fn sha256_known_answer() {
+        let opening = CommitmentOpening {
+            scheme: SCHEME_SHA256,
+            parcel: Pubkey::new_from_array([2; 32]),
+            nonce: [4; 32],
+        };
+        let farmer = Pubkey::new_from_array([1; 32]);
+        assert_eq!(
+            opening.commit(7, &farmer, 3).unwrap(),
+            hex("5e8b715430b3fde5edb64df9d729f0fbb100fdca49d0fca964cd8015ae40f724")
+        );
+    }
+
+    #[test]
+    fn poseidon_matches_the_circuit() {
+        // The private inputs of zk-proofs/input.json
+        let (farmer_hi, farmer_lo) = (
+            123456789012345678901234567890,
+            98765432109876543210987654321,
+        );
+        let (parcel_hi, parcel_lo) = (
+            111111111111111111111111111111,
+            222222222222222222222222222222,
+        );
+        let opening = CommitmentOpening {
+            scheme: SCHEME_POSEIDON,
+            parcel: pubkey(parcel_hi, parcel_lo),
+            nonce: field(123_456_789),
+        };
+        let commitment = opening.commit(1, &pubkey(farmer_hi, farmer_lo), 0).unwrap();
+
+        // Poseidon(8) over the inputs in the circuit's order, with its
+        // domain tag constant
+        let domain_tag = hex("000000000000000000006b6973616e2d646570696e3a636f6d6d69746d656e74");
+        assert_eq!(hash::field_from_bytes(POSEIDON_DOMAIN_TAG), domain_tag);
+        let circuit = hash::poseidon(&[
+            domain_tag,
+            field(1),
+            field(farmer_hi),
+            field(farmer_lo),
+            field(parcel_hi),
+            field(parcel_lo),
+            field(0),
+            field(123_456_789),
+        ])
+        .unwrap();
+        assert_eq!(commitment, circuit);
+        // complianceCommitment for input.json
+        assert_eq!(
+            commitment,
+            hex("0831761534480a2ae8acc47b4c5158077c72703c22293f6875d857e6f8a60d49")
+        );
+    }
+
+    #[test]
+    fn verify_binds_the_claim() {
+        let farmer = Pubkey::new_from_array([1; 32]);
+        let opening = CommitmentOpening {
+            scheme: SCHEME_POSEIDON,
+            parcel: Pubkey::new_from_array([2; 32]),
+            nonce: field(9),
+        };
+        let commitment = opening.commit(7, &farmer, 3).unwrap();
+        let mut signals = commitment.to_vec();
+        signals.extend_from_slice(&field(5));
+        verify(&commitment, &opening, 7, &farmer, 3, &signals).unwrap();
+
+        // Another farmer, circuit or season
+        let other = Pubkey::new_from_array([3; 32]);
+        for (circuit_id, farmer, season) in [(7, &other, 3), (8, &farmer, 3), (7, &farmer, 4)] {
+            let result = verify(&commitment, &opening, circuit_id, farmer, season, &signals);
+            assert_eq!(code(result), expected(KisanError::CommitmentMismatch));
+        }
+        // Another opening
+        let other_nonce = CommitmentOpening {
+            nonce: field(10),
+            ..opening
+        };
+        assert_eq!(
+            code(verify(&commitment, &other_nonce, 7, &farmer, 3, &signals)),
+            expected(KisanError::CommitmentMismatch)
+        );
+        // A proof of something else
+        assert_eq!(
+            code(verify(&commitment, &opening, 7, &farmer, 3, &signals[32..])),
+            expected(KisanError::InvalidPublicSignals)
+        );
+        let unknown = CommitmentOpening {
+            scheme: 9,
+            ..opening
+        };
+        assert_eq!(
+            code(verify(&commitment, &unknown, 7, &farmer, 3, &signals)),
+            expected(KisanError::UnsupportedCommitmentScheme)
+        );
+    }
+}
@@ -0,0 +1,160 @@
+// ============================================================
+// SPL Account Compression — minimal CPI interface
+// ============================================================
+//
+// Hand-rolled builders for the concurrent-merkle-tree
+// instructions used to keep per-claim state in compressed form
+// (`init_empty_merkle_tree`, `append`, `replace_leaf`,
+// `verify_leaf`), so we don't pull in spl-account-compression
+// and its dependency tree. The leaf preimage is logged through
+// the Noop program so indexers can serve it back with proofs.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+
+pub use crate::bubblegum::{COMPRESSION_PROGRAM_ID, NOOP_PROGRAM_ID};
+
+/// sha256("global:init_empty_merkle_tree")[..8]
+const INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR: [u8; 8] = [191, 11, 119, 7, 180, 107, 220, 110];
+/// sha256("global:append")[..8]
+const APPEND_DISCRIMINATOR: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+/// sha256("global:replace_leaf")[..8]
+const REPLACE_LEAF_DISCRIMINATOR: [u8; 8] = [204, 165, 76, 100, 73, 147, 0, 128];
+/// sha256("global:verify_leaf")[..8]
+const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+/// Leaf value left behind when a compressed record is decompressed
+pub const EMPTY_LEAF: [u8; 32] = [0; 32];
+
+/// Leaf for a compressed record: keccak256(tag | borsh(record)). The tag
+/// keeps records of different types apart in the shared tree.
+pub fn leaf_hash<T: AnchorSerialize>(tag: &[u8], record: &T) -> Result<[u8; 32]> {
+    let data = record.try_to_vec()?;
+    Ok(keccak::hashv(&[tag, &data]).to_bytes())
+}
+
+pub struct TreeAccounts<'a, 'info> {
+    pub merkle_tree: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub noop: &'a AccountInfo<'info>,
+    pub compression_program: &'a AccountInfo<'info>,
+}
+
+impl TreeAccounts<'_, '_> {
+    fn metas(&self) -> Vec<AccountMeta> {
+        vec![
+            AccountMeta::new(self.merkle_tree.key(), false),
+            AccountMeta::new_readonly(self.authority.key(), true),
+            AccountMeta::new_readonly(self.noop.key(), false),
+        ]
+    }
+}
+
+fn invoke_tree<'info>(
+    accounts: &TreeAccounts<'_, 'info>,
+    data: Vec<u8>,
+    proof: &[AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut metas = accounts.metas();
+    metas.extend(proof.iter().map(|p| AccountMeta::new_readonly(p.key(), false)));
+
+    let ix = Instruction {
+        program_id: COMPRESSION_PROGRAM_ID,
+        accounts: metas,
+        data,
+    };
+
+    let mut infos = vec![
+        accounts.merkle_tree.clone(),
+        accounts.authority.clone(),
+        accounts.noop.clone(),
+    ];
+    infos.extend_from_slice(proof);
+    infos.push(accounts.compression_program.clone());
+
+    invoke_signed(&ix, &infos, signer_seeds).map_err(Into::into)
+}
+
+/// CPI into `init_empty_merkle_tree`. The tree account must already be
+/// allocated (owned by the compression program) by the client.
+pub fn init_empty_merkle_tree(
+    accounts: TreeAccounts,
+    max_depth: u32,
+    max_buffer_size: u32,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR.to_vec();
+    max_depth.serialize(&mut data)?;
+    max_buffer_size.serialize(&mut data)?;
+    invoke_tree(&accounts, data, &[], signer_seeds)
+}
+
+/// CPI into `append`, adding `leaf` at the tree's next index.
+pub fn append(accounts: TreeAccounts, leaf: [u8; 32], signer_seeds: &[&[&[u8]]]) -> Result<()> {
+    let mut data = APPEND_DISCRIMINATOR.to_vec();
+    leaf.serialize(&mut data)?;
+    invoke_tree(&accounts, data, &[], signer_seeds)
+}
+
+/// CPI into `replace_leaf`; fails unless `previous_leaf` is at `index`
+/// under `root`, proven by the `proof` node accounts.
+pub fn replace_leaf<'info>(
+    accounts: TreeAccounts<'_, 'info>,
+    root: [u8; 32],
+    previous_leaf: [u8; 32],
+    new_leaf: [u8; 32],
+    index: u32,
+    proof: &[AccountInfo<'info>],
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = REPLACE_LEAF_DISCRIMINATOR.to_vec();
+    root.serialize(&mut data)?;
+    previous_leaf.serialize(&mut data)?;
+    new_leaf.serialize(&mut data)?;
+    index.serialize(&mut data)?;
+    invoke_tree(&accounts, data, proof, signer_seeds)
+}
+
+/// CPI into `verify_leaf`; fails unless `leaf` is at `index` under `root`.
+pub fn verify_leaf<'info>(
+    merkle_tree: &AccountInfo<'info>,
+    compression_program: &AccountInfo<'info>,
+    root: [u8; 32],
+    leaf: [u8; 32],
+    index: u32,
+    proof: &[AccountInfo<'info>],
+) -> Result<()> {
+    let mut data = VERIFY_LEAF_DISCRIMINATOR.to_vec();
+    root.serialize(&mut data)?;
+    leaf.serialize(&mut data)?;
+    index.serialize(&mut data)?;
+
+    let mut metas = vec![AccountMeta::new_readonly(merkle_tree.key(), false)];
+    metas.extend(proof.iter().map(|p| AccountMeta::new_readonly(p.key(), false)));
+    let ix = Instruction {
+        program_id: COMPRESSION_PROGRAM_ID,
+        accounts: metas,
+        data,
+    };
+
+    let mut infos = vec![merkle_tree.clone()];
+    infos.extend_from_slice(proof);
+    infos.push(compression_program.clone());
+
+    invoke(&ix, &infos).map_err(Into::into)
+}
+
+/// Log `data` through the Noop program so indexers see the leaf preimage
+/// in the transaction's inner instructions.
+pub fn log_leaf_data<'info>(noop: &AccountInfo<'info>, data: Vec<u8>) -> Result<()> {
+    let ix = Instruction {
+        program_id: NOOP_PROGRAM_ID,
+        accounts: vec![],
+        data,
+    };
+    invoke(&ix, std::slice::from_ref(noop)).map_err(Into::into)
+}
@@ -0,0 +1,77 @@
+// ============================================================
+// Linear bonding curve
+// ============================================================
+//
+// Bootstrap pricing for $GREEN before DEX liquidity exists:
+//
+//   price(s) = base_price + slope · s
+//
+// where `s` is the $GREEN sold so far (whole tokens) and prices
+// are USDC base units per whole $GREEN. The cost of moving the
+// curve from `s` to `s + Δ` is the area under the line, so a
+// round trip at the same point returns exactly what was paid
+// (less rounding, which always favours the reserve).
+// ============================================================
+
+/// Base units per whole $GREEN, as u128 for curve arithmetic.
+const UNIT: u128 = crate::GREEN_UNIT as u128;
+
+/// Area under the curve from 0 to `supply` base units, scaled by UNIT²
+/// (i.e. in USDC base units · UNIT).
+fn area(base_price: u64, slope: u64, supply: u64) -> Option<u128> {
+    let s = supply as u128;
+    let linear = (base_price as u128).checked_mul(s)?;
+    let quadratic = (slope as u128).checked_mul(s.checked_mul(s)?)? / (2 * UNIT);
+    linear.checked_add(quadratic)
+}
+
+/// USDC cost of buying `amount` base units when `sold` are outstanding,
+/// rounded up.
+pub fn buy_cost(base_price: u64, slope: u64, sold: u64, amount: u64) -> Option<u64> {
+    let end = sold.checked_add(amount)?;
+    let delta = area(base_price, slope, end)? - area(base_price, slope, sold)?;
+    u64::try_from(delta.div_ceil(UNIT)).ok()
+}
+
+/// USDC returned for selling `amount` base units back when `sold` are
+/// outstanding, rounded down.
+pub fn sell_proceeds(base_price: u64, slope: u64, sold: u64, amount: u64) -> Option<u64> {
+    let start = sold.checked_sub(amount)?;
+    let delta = area(base_price, slope, sold)? - area(base_price, slope, start)?;
+    u64::try_from(delta / UNIT).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: u64 = UNIT as u64;
+
+    #[test]
+    fn costs_the_area_under_the_line() {
+        // 100 + 10·s: the first token costs 105, the second 115
+        assert_eq!(buy_cost(100, 10, 0, TOKEN), Some(105));
+        assert_eq!(buy_cost(100, 10, TOKEN, TOKEN), Some(115));
+        assert_eq!(buy_cost(100, 10, 0, 2 * TOKEN), Some(220));
+        assert_eq!(sell_proceeds(100, 10, 2 * TOKEN, TOKEN), Some(115));
+        // Flat curve
+        assert_eq!(buy_cost(1_000_000, 0, TOKEN, 3 * TOKEN), Some(3_000_000));
+    }
+
+    #[test]
+    fn rounding_favours_the_reserve() {
+        // A third of a token at 100 + 10·s
+        let amount = TOKEN / 3;
+        let cost = buy_cost(100, 10, 0, amount).unwrap();
+        let proceeds = sell_proceeds(100, 10, amount, amount).unwrap();
+        assert!(proceeds <= cost);
+        assert_eq!(cost - proceeds, 1);
+    }
+
+    #[test]
+    fn rejects_overflow_and_overselling() {
+        assert_eq!(buy_cost(u64::MAX, u64::MAX, 0, u64::MAX), None);
+        assert_eq!(buy_cost(100, 10, u64::MAX, 1), None);
+        assert_eq!(sell_proceeds(100, 10, TOKEN, TOKEN + 1), None);
+    }
+}
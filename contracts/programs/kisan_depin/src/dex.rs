@@ -0,0 +1,239 @@
+// ============================================================
+// DEX routing — allowlisted swap CPI
+// ============================================================
+//
+// Treasury buybacks swap through an aggregator or AMM chosen
+// off-chain. The route (instruction data and accounts) is built
+// by the client and forwarded as-is, so no DEX SDK is needed
+// here. Before it is signed for, the route must be one of the
+// allowlisted swap instructions below, with the treasury's own
+// authority and vaults in the positions that instruction moves
+// tokens from and to. Pools, tick arrays and the hops in between
+// are the DEX's to check.
+//
+// The route is signed by the treasury-authority PDA, which owns
+// the two treasury vaults and nothing else, so even a route that
+// slipped past these checks couldn't reach escrows, collateral
+// or any other program-owned account.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::KisanError;
+
+pub const JUPITER_V6_PROGRAM_ID: Pubkey = pubkey!("JUP6LkbZbjS1jKKwapdHNy74zcZ3tLUZoi5QNyVTaV4");
+pub const ORCA_WHIRLPOOL_PROGRAM_ID: Pubkey = pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+
+/// sha256("global:route")[..8]
+const JUPITER_ROUTE: [u8; 8] = [229, 23, 203, 151, 122, 227, 173, 42];
+/// sha256("global:shared_accounts_route")[..8]
+const JUPITER_SHARED_ACCOUNTS_ROUTE: [u8; 8] = [193, 32, 155, 51, 65, 214, 156, 129];
+/// sha256("global:swap")[..8]
+const WHIRLPOOL_SWAP: [u8; 8] = [248, 198, 158, 145, 225, 117, 135, 200];
+/// sha256("global:swap_v2")[..8]
+const WHIRLPOOL_SWAP_V2: [u8; 8] = [43, 4, 237, 11, 26, 201, 30, 98];
+
+/// Programs treasury swaps may be routed through.
+pub fn is_whitelisted(program_id: &Pubkey) -> bool {
+    *program_id == JUPITER_V6_PROGRAM_ID || *program_id == ORCA_WHIRLPOOL_PROGRAM_ID
+}
+
+/// Check a client-built route swaps `source` into `destination` on
+/// behalf of `authority`. `keys` are the route's accounts in order.
+///
+/// Jupiter routes must name the treasury vaults as the user's source and
+/// destination (and `route` may not redirect the output elsewhere);
+/// Whirlpool swaps must use them as the two owner accounts, in either
+/// order since the pool's mint order fixes the direction.
+pub fn check_route(
+    program_id: &Pubkey,
+    data: &[u8],
+    keys: &[Pubkey],
+    authority: &Pubkey,
+    source: &Pubkey,
+    destination: &Pubkey,
+) -> Result<()> {
+    require!(
+        is_whitelisted(program_id),
+        KisanError::SwapRouteNotWhitelisted
+    );
+    let discriminator: [u8; 8] = data
+        .get(..8)
+        .and_then(|d| d.try_into().ok())
+        .ok_or(KisanError::SwapRouteNotWhitelisted)?;
+    let key = |i: usize| keys.get(i).ok_or(KisanError::SwapRouteAccountsMismatch);
+
+    let matches = if *program_id == JUPITER_V6_PROGRAM_ID && discriminator == JUPITER_ROUTE {
+        // token program, user transfer authority, user source, user
+        // destination, destination override (the program itself for none)
+        let output = key(4)?;
+        key(1)? == authority
+            && key(2)? == source
+            && key(3)? == destination
+            && (output == destination || *output == JUPITER_V6_PROGRAM_ID)
+    } else if *program_id == JUPITER_V6_PROGRAM_ID && discriminator == JUPITER_SHARED_ACCOUNTS_ROUTE
+    {
+        // token program, program authority, user transfer authority,
+        // source, program source, program destination, destination
+        key(2)? == authority && key(3)? == source && key(6)? == destination
+    } else if *program_id == ORCA_WHIRLPOOL_PROGRAM_ID && discriminator == WHIRLPOOL_SWAP {
+        // token program, token authority, whirlpool, owner A, vault A,
+        // owner B, vault B
+        key(1)? == authority && is_pair(key(3)?, key(5)?, source, destination)
+    } else if *program_id == ORCA_WHIRLPOOL_PROGRAM_ID && discriminator == WHIRLPOOL_SWAP_V2 {
+        // token programs A and B, memo program, token authority, whirlpool,
+        // mints A and B, owner A, vault A, owner B, vault B
+        key(3)? == authority && is_pair(key(7)?, key(9)?, source, destination)
+    } else {
+        return err!(KisanError::SwapRouteNotWhitelisted);
+    };
+    require!(matches, KisanError::SwapRouteAccountsMismatch);
+    Ok(())
+}
+
+fn is_pair(a: &Pubkey, b: &Pubkey, x: &Pubkey, y: &Pubkey) -> bool {
+    (a == x && b == y) || (a == y && b == x)
+}
+
+/// CPI into `swap_program` with a client-built route. `signer` (the PDA
+/// that owns the source tokens) is marked as a signer wherever it appears
+/// in `accounts`, and signs with `signer_seeds`.
+pub fn invoke_route<'info>(
+    swap_program: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    data: Vec<u8>,
+    signer: &Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let metas = accounts
+        .iter()
+        .map(|a| AccountMeta {
+            pubkey: a.key(),
+            is_signer: a.is_signer || a.key == signer,
+            is_writable: a.is_writable,
+        })
+        .collect();
+
+    let ix = Instruction {
+        program_id: swap_program.key(),
+        accounts: metas,
+        data,
+    };
+
+    let mut infos = accounts.to_vec();
+    infos.push(swap_program.clone());
+
+    invoke_signed(&ix, &infos, signer_seeds).map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    fn code(result: Result<()>) -> u32 {
+        match result {
+            Err(Error::AnchorError(e)) => e.error_code_number,
+            other => panic!("expected an Anchor error, got {other:?}"),
+        }
+    }
+
+    fn expected(e: KisanError) -> u32 {
+        e.into()
+    }
+
+    const AUTHORITY: u8 = 1;
+    const USDC: u8 = 2;
+    const GREEN: u8 = 3;
+
+    fn check(program_id: &Pubkey, discriminator: [u8; 8], keys: &[Pubkey]) -> Result<()> {
+        let mut data = discriminator.to_vec();
+        data.extend_from_slice(&[0; 24]);
+        check_route(
+            program_id,
+            &data,
+            keys,
+            &key(AUTHORITY),
+            &key(USDC),
+            &key(GREEN),
+        )
+    }
+
+    #[test]
+    fn accepts_routes_between_the_treasury_vaults() {
+        let jupiter = JUPITER_V6_PROGRAM_ID;
+        let route = [9, AUTHORITY, USDC, GREEN].map(key);
+        let mut keys = route.to_vec();
+        keys.extend([jupiter, key(10), key(11)]);
+        assert!(check(&jupiter, JUPITER_ROUTE, &keys).is_ok());
+        keys[4] = key(GREEN);
+        assert!(check(&jupiter, JUPITER_ROUTE, &keys).is_ok());
+
+        let keys = [9, 10, AUTHORITY, USDC, 11, 12, GREEN, 13].map(key);
+        assert!(check(&jupiter, JUPITER_SHARED_ACCOUNTS_ROUTE, &keys).is_ok());
+
+        // Either direction through the pool
+        let orca = ORCA_WHIRLPOOL_PROGRAM_ID;
+        let keys = [9, AUTHORITY, 10, GREEN, 11, USDC, 12, 13].map(key);
+        assert!(check(&orca, WHIRLPOOL_SWAP, &keys).is_ok());
+        let keys = [9, 9, 10, AUTHORITY, 11, 12, 13, USDC, 14, GREEN, 15].map(key);
+        assert!(check(&orca, WHIRLPOOL_SWAP_V2, &keys).is_ok());
+    }
+
+    #[test]
+    fn rejects_other_programs_and_instructions() {
+        let keys = [9, AUTHORITY, USDC, GREEN, 10].map(key);
+        assert_eq!(
+            code(check(&key(42), JUPITER_ROUTE, &keys)),
+            expected(KisanError::SwapRouteNotWhitelisted)
+        );
+        // An allowlisted program, but not one of its swaps
+        assert_eq!(
+            code(check(&JUPITER_V6_PROGRAM_ID, WHIRLPOOL_SWAP, &keys)),
+            expected(KisanError::SwapRouteNotWhitelisted)
+        );
+        let data = &JUPITER_ROUTE[..4];
+        assert_eq!(
+            code(check_route(
+                &JUPITER_V6_PROGRAM_ID,
+                data,
+                &keys,
+                &key(AUTHORITY),
+                &key(USDC),
+                &key(GREEN)
+            )),
+            expected(KisanError::SwapRouteNotWhitelisted)
+        );
+    }
+
+    #[test]
+    fn rejects_routes_through_other_accounts() {
+        let jupiter = JUPITER_V6_PROGRAM_ID;
+        let mismatch = expected(KisanError::SwapRouteAccountsMismatch);
+
+        // Output redirected away from the treasury
+        let keys = [9, AUTHORITY, USDC, GREEN, 10].map(key);
+        assert_eq!(code(check(&jupiter, JUPITER_ROUTE, &keys)), mismatch);
+        // Someone else's tokens as the source
+        let keys = [key(9), key(AUTHORITY), key(10), key(GREEN), jupiter];
+        assert_eq!(code(check(&jupiter, JUPITER_ROUTE, &keys)), mismatch);
+        // Another authority
+        let keys = [9, 10, 11, USDC, 12, 13, GREEN].map(key);
+        assert_eq!(
+            code(check(&jupiter, JUPITER_SHARED_ACCOUNTS_ROUTE, &keys)),
+            mismatch
+        );
+        // Too short to name the accounts at all
+        let keys = [9, AUTHORITY].map(key);
+        assert_eq!(code(check(&jupiter, JUPITER_ROUTE, &keys)), mismatch);
+
+        let orca = ORCA_WHIRLPOOL_PROGRAM_ID;
+        let keys = [9, AUTHORITY, 10, USDC, 11, USDC, 12].map(key);
+        assert_eq!(code(check(&orca, WHIRLPOOL_SWAP, &keys)), mismatch);
+    }
+}
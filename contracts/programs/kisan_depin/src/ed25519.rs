@@ -0,0 +1,81 @@
+// ============================================================
+// Ed25519 signature introspection
+// ============================================================
+//
+// For attestations signed off-chain by a Solana key — e.g. a
+// government department whose signing key never touches a
+// relayer's transaction. The client puts an Ed25519 precompile
+// instruction right before ours and we read it back through the
+// instructions sysvar: if the transaction executed, the
+// precompile verified the signature.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::KisanError;
+
+const SIGNATURE_OFFSETS_START: usize = 2;
+/// signature_offset (2) | signature_instruction_index (2) |
+/// public_key_offset (2) | public_key_instruction_index (2) |
+/// message_data_offset (2) | message_data_size (2) |
+/// message_instruction_index (2)
+const SIGNATURE_OFFSETS_LEN: usize = 14;
+/// Instruction index meaning "this instruction"
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// A signature verified by the Ed25519 precompile.
+pub struct Ed25519SignedMessage {
+    pub signer: Pubkey,
+    pub signature: [u8; 64],
+    pub message: Vec<u8>,
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<u16> {
+    let bytes = data
+        .get(offset..offset + 2)
+        .ok_or(KisanError::InvalidEd25519Signature)?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]))
+}
+
+fn slice(data: &[u8], offset: u16, len: usize) -> Result<&[u8]> {
+    data.get(offset as usize..offset as usize + len)
+        .ok_or(KisanError::InvalidEd25519Signature.into())
+}
+
+/// Read the single signature checked by the Ed25519 precompile instruction
+/// immediately before the current one. The signature, key and message must
+/// all live in that instruction's own data.
+pub fn load_preceding_signed_message(
+    instructions_sysvar: &AccountInfo,
+) -> Result<Ed25519SignedMessage> {
+    let current = load_current_index_checked(instructions_sysvar)?;
+    let ix_index = current
+        .checked_sub(1)
+        .ok_or(KisanError::InvalidEd25519Signature)?;
+    let ix = load_instruction_at_checked(ix_index as usize, instructions_sysvar)?;
+    require_keys_eq!(ix.program_id, ed25519_program::ID, KisanError::InvalidEd25519Signature);
+
+    let data = &ix.data;
+    require!(data.first() == Some(&1), KisanError::InvalidEd25519Signature);
+    let offsets = data
+        .get(SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN)
+        .ok_or(KisanError::InvalidEd25519Signature)?;
+    for field in [2, 6, 12] {
+        let index = read_u16(offsets, field)?;
+        require!(
+            index == CURRENT_INSTRUCTION || index == ix_index,
+            KisanError::InvalidEd25519Signature
+        );
+    }
+
+    let signature = slice(data, read_u16(offsets, 0)?, 64)?.try_into().unwrap();
+    let signer = Pubkey::try_from(slice(data, read_u16(offsets, 4)?, 32)?).unwrap();
+    let message_size = read_u16(offsets, 10)? as usize;
+    let message = slice(data, read_u16(offsets, 8)?, message_size)?.to_vec();
+
+    Ok(Ed25519SignedMessage { signer, signature, message })
+}
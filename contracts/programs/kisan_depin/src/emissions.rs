@@ -0,0 +1,102 @@
+// ============================================================
+// Emissions — per-season reward curves
+// ============================================================
+//
+// The per-claim $GREEN reward of season `n` is the configured
+// base reward run through an `EmissionCurve`:
+//
+//   Fixed             base
+//   LinearDecay       base − rate · n
+//   ExponentialDecay  base · (1 − rate / 10⁴)ⁿ
+//   StepHalving       base / 2^⌊n / interval⌋
+//
+// each clamped to [min(floor, base), base]. The reward depends
+// only on the curve, the base and the season index, in integer
+// arithmetic, so anyone can recompute a season's reward off-chain
+// and get the same answer. The curve is a flat struct rather than
+// an enum with payloads so `Config` keeps a fixed layout for
+// `migrate_account`.
+// ============================================================
+
+use anchor_lang::prelude::*;
+
+/// Fixed-point one for `ExponentialDecay`, 18 decimals.
+const WAD: u128 = 1_000_000_000_000_000_000;
+const BPS: u64 = 10_000;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum CurveKind {
+    /// Every season pays the base reward
+    Fixed,
+    /// `rate` base units less each season
+    LinearDecay,
+    /// `rate` basis points less each season, compounding
+    ExponentialDecay,
+    /// Halves every `interval` seasons
+    StepHalving,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct EmissionCurve {
+    pub kind: CurveKind,
+    /// `LinearDecay`: base units per season; `ExponentialDecay`: basis
+    /// points per season
+    pub rate: u64,
+    /// `StepHalving`: seasons between halvings
+    pub interval: u32,
+    /// The reward never decays below this (or the base, if lower)
+    pub floor: u64,
+}
+
+impl EmissionCurve {
+    /// No decay: what the program did before curves existed, and what a
+    /// zeroed, freshly migrated `Config` decodes to.
+    pub const FIXED: Self = Self {
+        kind: CurveKind::Fixed,
+        rate: 0,
+        interval: 0,
+        floor: 0,
+    };
+
+    /// Parameters the curve's kind can use: a decay that actually
+    /// decays, at most 100% a season, and a non-zero halving interval.
+    pub fn is_valid(&self) -> bool {
+        match self.kind {
+            CurveKind::Fixed => true,
+            CurveKind::LinearDecay => self.rate > 0,
+            CurveKind::ExponentialDecay => self.rate > 0 && self.rate <= BPS,
+            CurveKind::StepHalving => self.interval > 0,
+        }
+    }
+
+    /// Per-claim reward in season `season` for a base reward of `base`.
+    pub fn reward(&self, base: u64, season: u32) -> u64 {
+        let decayed = match self.kind {
+            CurveKind::Fixed => base,
+            CurveKind::LinearDecay => base.saturating_sub(self.rate.saturating_mul(season as u64)),
+            CurveKind::ExponentialDecay => {
+                let step = (BPS - self.rate.min(BPS)) as u128 * (WAD / BPS as u128);
+                (base as u128 * pow_wad(step, season) / WAD) as u64
+            }
+            CurveKind::StepHalving => {
+                let halvings = season / self.interval.max(1);
+                base.checked_shr(halvings).unwrap_or(0)
+            }
+        };
+        decayed.max(self.floor.min(base))
+    }
+}
+
+/// `x^n` for a WAD fixed-point `x` ≤ 1, by squaring, rounding down at
+/// each step. Stops early once the result reaches zero.
+fn pow_wad(mut x: u128, mut n: u32) -> u128 {
+    let mut acc = WAD;
+    while n > 0 && acc > 0 {
+        if n & 1 == 1 {
+            acc = acc * x / WAD;
+        }
+        x = x * x / WAD;
+        n >>= 1;
+    }
+    acc
+}
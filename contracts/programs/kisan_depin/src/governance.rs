@@ -0,0 +1,205 @@
+// ============================================================
+// Governance — proposal actions and voting weight strategies
+// ============================================================
+//
+// A proposal carries one `ProposalAction`: a typed payload for
+// one of the program's own setters. There is deliberately no
+// "call any program with these accounts" action; governance can
+// only do what the enum spells out, each payload is validated
+// when the proposal is created and again when it executes, and
+// anything that doesn't deserialize as a known variant is
+// rejected by Borsh before a handler runs.
+//
+// Plain token-weighted voting lets one large $GREEN buyer
+// outvote every farmer. A `VoteWeighting` turns a voter's
+// balance into voting weight instead, and each kind of proposal
+// picks its own: routine parameter changes can stay linear while
+// treasury spends use quadratic weighting, a per-wallet cap, or
+// both. The cap applies after the curve, so it's in units of
+// weight, not $GREEN.
+//
+// Quadratic weighting only dampens whales who vote from one
+// wallet; splitting a balance across wallets recovers weight.
+// Pair it with a cap and with voting power that comes from
+// registered farmer profiles where sybil resistance matters.
+// ============================================================
+
+use anchor_lang::prelude::*;
+
+use crate::{groth16, plonk, report, KisanError, MAX_MARKET_FEE_BPS, MAX_VK_OVERLAP_SLOTS};
+
+/// What a passed proposal does when executed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum ProposalAction {
+    /// Set the treasury's protocol fee on marketplace and OTC trades
+    SetFee { market_fee_bps: u16 },
+    /// Set the per-claim $GREEN reward for the next season
+    SetRewardAmount { reward_amount: u64 },
+    /// Rotate a circuit's verification key; the outgoing key stays valid
+    /// for `overlap_slots`, as when the authority rotates it
+    SetVerifyingKey {
+        circuit_id: u16,
+        key: ProposedKey,
+        overlap_slots: u64,
+    },
+    /// Halt or resume farmer claims
+    Pause { paused: bool },
+    /// Pay USDC from the treasury to a token account
+    TreasurySpend { amount: u64, destination: Pubkey },
+    /// Close the bonding curve and move its USDC reserve to a token
+    /// account, typically the one seeding the DEX pool
+    MigrateLiquidity { destination: Pubkey },
+}
+
+// `InitSpace` can't size a `Box`, which serializes as its contents
+impl Space for ProposalAction {
+    // Tag plus the largest variant, `SetVerifyingKey`
+    const INIT_SPACE: usize = 1 + 2 + ProposedKey::INIT_SPACE + 8;
+}
+
+/// A verification key carried by a `SetVerifyingKey` proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum ProposedKey {
+    Groth16(Box<groth16::VerifyingKey>),
+    Plonk(Box<plonk::VerifyingKey>),
+}
+
+impl Space for ProposedKey {
+    // Tag plus the larger key
+    const INIT_SPACE: usize =
+        1 + if groth16::VerifyingKey::INIT_SPACE > plonk::VerifyingKey::INIT_SPACE {
+            groth16::VerifyingKey::INIT_SPACE
+        } else {
+            plonk::VerifyingKey::INIT_SPACE
+        };
+}
+
+impl ProposedKey {
+    pub fn is_well_formed(&self) -> bool {
+        match self {
+            Self::Groth16(key) => key.is_well_formed(),
+            Self::Plonk(key) => key.is_well_formed(),
+        }
+    }
+}
+
+impl ProposalAction {
+    /// Index of this action's kind, below `PROPOSAL_KINDS`, into
+    /// `GovernanceConfig::weightings`.
+    pub fn kind(&self) -> u8 {
+        match self {
+            Self::SetFee { .. } => 0,
+            Self::SetRewardAmount { .. } => 1,
+            Self::SetVerifyingKey { .. } => 2,
+            Self::Pause { .. } => 3,
+            Self::TreasurySpend { .. } => 4,
+            Self::MigrateLiquidity { .. } => 5,
+        }
+    }
+
+    /// Check the payload on its own terms. Checks against current state
+    /// (the season's mint cap, the treasury balance) happen on execution.
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            Self::SetFee { market_fee_bps } => {
+                if *market_fee_bps > MAX_MARKET_FEE_BPS {
+                    return Err(report::exceeded(
+                        KisanError::MarketFeeTooHigh,
+                        market_fee_bps,
+                        MAX_MARKET_FEE_BPS,
+                    ));
+                }
+            }
+            Self::SetRewardAmount { reward_amount } => {
+                require!(*reward_amount > 0, KisanError::InvalidProposalAction);
+            }
+            Self::SetVerifyingKey {
+                key, overlap_slots, ..
+            } => {
+                require!(key.is_well_formed(), KisanError::InvalidVerifyingKey);
+                if *overlap_slots > MAX_VK_OVERLAP_SLOTS {
+                    return Err(report::exceeded(
+                        KisanError::InvalidVkOverlap,
+                        overlap_slots,
+                        MAX_VK_OVERLAP_SLOTS,
+                    ));
+                }
+            }
+            Self::Pause { .. } => {}
+            Self::TreasurySpend {
+                amount,
+                destination,
+            } => {
+                require!(
+                    *amount > 0 && *destination != Pubkey::default(),
+                    KisanError::InvalidProposalAction
+                );
+            }
+            Self::MigrateLiquidity { destination } => {
+                require!(
+                    *destination != Pubkey::default(),
+                    KisanError::InvalidProposalAction
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+/// How balance maps to weight before any cap.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum WeightCurve {
+    /// One base unit, one vote
+    Linear,
+    /// Weight grows with the square root of the balance
+    Quadratic,
+}
+
+/// A voting weight strategy: a curve, then an optional per-wallet cap.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub struct VoteWeighting {
+    pub curve: WeightCurve,
+    /// Most weight one wallet can cast; 0 for no cap
+    pub cap: u64,
+}
+
+impl VoteWeighting {
+    /// Plain token-weighted voting.
+    pub const LINEAR: Self = Self {
+        curve: WeightCurve::Linear,
+        cap: 0,
+    };
+
+    /// Voting weight of a wallet holding `balance` base units.
+    pub fn weight(&self, balance: u64) -> u64 {
+        let weight = match self.curve {
+            WeightCurve::Linear => balance,
+            WeightCurve::Quadratic => isqrt(balance),
+        };
+        if self.cap == 0 {
+            weight
+        } else {
+            weight.min(self.cap)
+        }
+    }
+}
+
+impl Default for VoteWeighting {
+    fn default() -> Self {
+        Self::LINEAR
+    }
+}
+
+/// ⌊√n⌋, by Newton's method: on-chain toolchains predate `u64::isqrt`.
+pub fn isqrt(n: u64) -> u64 {
+    if n < 2 {
+        return n;
+    }
+    let mut x = n;
+    let mut y = n / 2;
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+    x
+}
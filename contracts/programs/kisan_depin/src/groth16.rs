@@ -0,0 +1,351 @@
+// ============================================================
+// Groth16 verifier (BN254, snarkjs-compatible)
+// ============================================================
+//
+// Verifies proofs produced by `snarkjs groth16 prove` (or any
+// arkworks Groth16 prover) against the verifying key registered
+// for the circuit:
+//
+//   e(-A, B) · e(α, β) · e(vk_x, γ) · e(C, δ) == 1
+//
+// with vk_x = IC₀ + Σ inputᵢ · ICᵢ₊₁, as one four-pair call to
+// the alt_bn128 pairing syscall. Proof points are validated
+// first (see `bn254.rs`) so a malformed point gets its own
+// error code rather than failing the pairing.
+//
+// Encodings are big-endian, as for the Ethereum precompiles:
+// G1 = x | y (64 bytes), G2 = x_c1 | x_c0 | y_c1 | y_c0 (128
+// bytes), public inputs 32 bytes each and below r.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::alt_bn128::prelude::alt_bn128_pairing;
+
+use crate::bn254::{self, g1_add, g1_mul, g1_neg, geq, limbs_from_be, R};
+use crate::{KisanError, ProofPoints};
+
+/// Most public inputs a registered key may have
+pub const MAX_PUBLIC_INPUTS: usize = 16;
+/// IC points in a key with `MAX_PUBLIC_INPUTS`. `max_len` pastes its
+/// argument into a product, so it has to be a single constant.
+const MAX_IC_POINTS: usize = MAX_PUBLIC_INPUTS + 1;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct VerifyingKey {
+    pub alpha_g1: [u8; 64],
+    pub beta_g2: [u8; 128],
+    pub gamma_g2: [u8; 128],
+    pub delta_g2: [u8; 128],
+    /// IC₀, then one point per public input
+    #[max_len(MAX_IC_POINTS)]
+    pub ic: Vec<[u8; 64]>,
+}
+
+impl VerifyingKey {
+    pub fn n_public(&self) -> usize {
+        self.ic.len().saturating_sub(1)
+    }
+
+    /// Between one and `MAX_PUBLIC_INPUTS` public inputs, and every point
+    /// on its curve.
+    pub fn is_well_formed(&self) -> bool {
+        (1..=MAX_PUBLIC_INPUTS).contains(&self.n_public())
+            && bn254::validate_g1(&self.alpha_g1).is_ok()
+            && [&self.beta_g2, &self.gamma_g2, &self.delta_g2]
+                .iter()
+                .all(|p| bn254::validate_g2(p).is_ok())
+            && self.ic.iter().all(|p| bn254::validate_g1(p).is_ok())
+    }
+}
+
+/// Verify `proof` for `public_inputs` (32-byte big-endian words, one per
+/// input of `vk`).
+pub fn verify(vk: &VerifyingKey, proof: &ProofPoints, public_inputs: &[u8]) -> Result<()> {
+    let (a, b, c) = &proof.decompress()?;
+    for p in [a.as_slice(), b, c] {
+        require!(p.iter().any(|&b| b != 0), KisanError::InvalidProof);
+    }
+    bn254::validate_g1(a)?;
+    bn254::validate_g2(b)?;
+    bn254::validate_g1(c)?;
+
+    require!(
+        vk.n_public() > 0 && public_inputs.len() == vk.n_public() * 32,
+        KisanError::InvalidPublicSignals
+    );
+    let mut vk_x = vk.ic[0];
+    for (input, ic) in public_inputs.chunks(32).zip(&vk.ic[1..]) {
+        let input: &[u8; 32] = input.try_into().unwrap();
+        require!(
+            !geq(&limbs_from_be(input), &R),
+            KisanError::InvalidPublicSignals
+        );
+        vk_x = g1_add(&vk_x, &g1_mul(ic, input)?)?;
+    }
+
+    let mut pairing = Vec::with_capacity(4 * 192);
+    for (g1, g2) in [
+        (&g1_neg(a), b),
+        (&vk.alpha_g1, &vk.beta_g2),
+        (&vk_x, &vk.gamma_g2),
+        (c, &vk.delta_g2),
+    ] {
+        pairing.extend_from_slice(g1);
+        pairing.extend_from_slice(g2);
+    }
+    let result = alt_bn128_pairing(&pairing).map_err(|_| KisanError::InvalidProof)?;
+    require!(result.last() == Some(&1), KisanError::InvalidProof);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // snarkjs vectors for a nine-input circuit, from the groth16-solana
+    // crate's tests
+    const IC: [&str; 10] = [
+        concat!(
+            "03b7afbddb49b71c84c853084116b8515224b5ba19d8ea199702ebc20ddf2091",
+            "0f25717a5d3b5b19ec68e3ee3a9a43faba5b5d8d12f1963bca30b30135cf9bc7",
+        ),
+        concat!(
+            "2efd5554a6f047af6faef43e5760ebc4d055ba2fa3ed35ccb0be3ec9bdd88447",
+            "065be4614a0500ff9371a198eeb14e516f0d8edc18851b9542732257e0ed2ca2",
+        ),
+        concat!(
+            "1d9de8feeeb2520f98cdaf815a6c723c52a225ea7345bf7dd455b0b071291754",
+            "08e5c429bff37069a64b71a08c228bb335b4f5c305182a12523cadc04395d3fa",
+        ),
+        concat!(
+            "12045c693721de8590b96383a78f34782c4fa43f77dfc79a1a5616d032359f41",
+            "0eab359fff855b1ea2d19812fb70695a41ea2c042aad1fe6e589b170f18e3eb0",
+        ),
+        concat!(
+            "0d7538fa832677cddde420b9ec52661dc6357597130affd329d248dd4f6bfb96",
+            "23bb1e20c611dc04440a47331fa904ae0a26e3e5c181964c5ee0b60da641af59",
+        ),
+        concat!(
+            "15a7a0d6d584d0c573c3816f81263834293948f932bbb831f0e48e93bb606066",
+            "22a32bdac7bbfaf57797ed43e746ec439db5d8ae195278ffbf59e6a5b3f1bcda",
+        ),
+        concat!(
+            "0488db82375915e0291e35ea42a081ae9a8b9721a3dd96c0ab66f1a130821faf",
+            "062fb07f0d0824e4efdb069e161f16a25bc484bc9ce41e01b2f6c5baecf9ec93",
+        ),
+        concat!(
+            "092978504318f0dd889c89b6a811b0767748aabce31f0f16fc25c69ac3a3407d",
+            "25d3eb43f9852d5aa209ad13509ad0adddcbcefe51c5681ab14e56d233743c57",
+        ),
+        concat!(
+            "032956d07d9335bbd5dcc38dd8285c8946d2a86769ec5525a5d1f64b7afb4b5d",
+            "1c6c9ab50f10235841d3080b7b54b9bbb801538d432ef1dee8873b2c98d9ed6a",
+        ),
+        concat!(
+            "2262bd7677c566c12496c88fe23c00ef1528059c4907f70ef99d02f1b5d09000",
+            "222d56857435eba06b24c37d7a0ace5855a63e96419f8207ffe0e3e5ce8a4447",
+        ),
+    ];
+
+    fn hex<const N: usize>(s: &str) -> [u8; N] {
+        let mut out = [0u8; N];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    fn proof() -> ProofPoints {
+        ProofPoints::Uncompressed {
+            a: hex(concat!(
+                "2dceffa69837808a4fd991a4194a78eaead94495a22c8578b8cd0c2caf62a8ac",
+                "1418d80fd1af6a4b93ec5a657bdbf597d1cada68940820fef3bfda7a2a51c154",
+            )),
+            b: hex(concat!(
+                "2839e9cdb42e236fd705175d0c4776e1072ef7932f826abdb85092678d34f219",
+                "00cb7cb06e2297d442b4ee97ecbd85d11189cdb7a8c45c9f4bae51a81256b038",
+                "101ad21412517a8e683efba9628d15fd3282b60f216de41f4fb75893ae6c0416",
+                "0e81a80650f6fe64da835e31f7d303f516c8b15b3c9093ae5a1113bd3e939812",
+            )),
+            c: hex(concat!(
+                "298bb7d0f6c6767f59a0091b3d1a7bb4dd6c11a62f735230848bfd41985cd135",
+                "2519533dfc2ab5f3101502c77b60da97fd5645b5ca6d40817cfec019b1c71a32",
+            )),
+        }
+    }
+
+    fn inputs() -> Vec<u8> {
+        [
+            "22eefbb6eaf8d6bd2e432a19473a913a3d1c746e3c115295b2bba0d325e2aee7",
+            "0000000000000000000000000000000000000000000000000000000033981193",
+            "04f7c757e655675a1cb75f64c82e039ef7c4ad92cfa76c21c7120dccc665dfba",
+            "0000000000000000000000000000000000000000000000000000000007314129",
+            "07823741c5e8afd92c9795e14b569e692be541573396a8f3b0af0bcbb4954867",
+            "2e5db13e2a42df9933c192319a2945c6e00d5750deab258d000132ac121cd5d5",
+            "288d2d03b4c8fa706c5e238f523f7d099325bf4b3edd8a14a697dbedfe3ae6bd",
+            "21648ff10bfb498de53981a85317eb938ae1b1fa0d61e2a206e8345f80545aca",
+            "19b201d0dba9de7b71caa54db76267edbb5db25fa99c26647dda685e68770d15",
+        ]
+        .iter()
+        .flat_map(|x| hex::<32>(x))
+        .collect()
+    }
+
+    fn key() -> VerifyingKey {
+        VerifyingKey {
+            alpha_g1: hex(concat!(
+                "2d4d9aa7e302d9df41749d5507949d05dbea33fbb16c643b22f599a2be6df2e2",
+                "14bedd503c37ceb061d8ec60209fe345ce89830a19230301f076caff004d1926",
+            )),
+            beta_g2: hex(concat!(
+                "0967032fcbf776d1afc985f88877f182d38480a653f2decaa9794cbc3bf3060c",
+                "0e187847ad4c798374d0d6732bf501847dd68bc0e071241e0213bc7fc13db7ab",
+                "304cfbd1e08a704a99f5e847d93f8c3caafddec46b7a0d379da69a4d112346a7",
+                "1739c1b1a457a8c7313123d24d2f9192f896b7c63eea05a9d57f06547ad0cec8",
+            )),
+            gamma_g2: hex(concat!(
+                "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+                "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+                "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+                "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            )),
+            delta_g2: hex(concat!(
+                "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+                "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+                "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+                "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            )),
+            ic: IC.iter().map(|p| hex(p)).collect(),
+        }
+    }
+
+    fn code(result: Result<()>) -> u32 {
+        match result {
+            Err(Error::AnchorError(e)) => e.error_code_number,
+            other => panic!("expected a program error, got {other:?}"),
+        }
+    }
+
+    fn expected(e: KisanError) -> u32 {
+        e.into()
+    }
+
+    #[test]
+    fn accepts_a_valid_proof() {
+        verify(&key(), &proof(), &inputs()).unwrap();
+    }
+
+    #[test]
+    fn accepts_a_compressed_proof() {
+        use anchor_lang::solana_program::alt_bn128::compression::prelude::{
+            alt_bn128_g1_compress, alt_bn128_g2_compress,
+        };
+        let ProofPoints::Uncompressed { a, b, c } = proof() else {
+            unreachable!()
+        };
+        let compressed = ProofPoints::Compressed {
+            a: alt_bn128_g1_compress(&a).unwrap(),
+            b: alt_bn128_g2_compress(&b).unwrap(),
+            c: alt_bn128_g1_compress(&c).unwrap(),
+        };
+        verify(&key(), &compressed, &inputs()).unwrap();
+    }
+
+    #[test]
+    fn rejects_a_flipped_proof_byte() {
+        let ProofPoints::Uncompressed { a, b, mut c } = proof() else {
+            unreachable!()
+        };
+        c[63] ^= 1;
+        let tampered = ProofPoints::Uncompressed { a, b, c };
+        assert_eq!(
+            code(verify(&key(), &tampered, &inputs())),
+            expected(KisanError::InvalidPointEncoding)
+        );
+    }
+
+    #[test]
+    fn rejects_another_valid_point() {
+        // On the curve, so it passes validation, but not the proof's C
+        let ProofPoints::Uncompressed { a, b, .. } = proof() else {
+            unreachable!()
+        };
+        let tampered = ProofPoints::Uncompressed {
+            a,
+            b,
+            c: bn254::G1_GENERATOR,
+        };
+        assert_eq!(
+            code(verify(&key(), &tampered, &inputs())),
+            expected(KisanError::InvalidProof)
+        );
+    }
+
+    #[test]
+    fn rejects_a_wrong_public_input() {
+        let mut inputs = inputs();
+        inputs[31] ^= 1;
+        assert_eq!(
+            code(verify(&key(), &proof(), &inputs)),
+            expected(KisanError::InvalidProof)
+        );
+    }
+
+    #[test]
+    fn rejects_a_public_input_above_the_modulus() {
+        let mut inputs = inputs();
+        inputs[..32].fill(0xff);
+        assert_eq!(
+            code(verify(&key(), &proof(), &inputs)),
+            expected(KisanError::InvalidPublicSignals)
+        );
+    }
+
+    #[test]
+    fn rejects_a_wrong_input_count() {
+        let inputs = inputs();
+        assert_eq!(
+            code(verify(&key(), &proof(), &inputs[32..])),
+            expected(KisanError::InvalidPublicSignals)
+        );
+    }
+
+    #[test]
+    fn rejects_the_wrong_key() {
+        let mut vk = key();
+        vk.ic.swap(1, 2);
+        assert_eq!(
+            code(verify(&vk, &proof(), &inputs())),
+            expected(KisanError::InvalidProof)
+        );
+        // gamma and delta are both the generator in this setup
+        let mut vk = key();
+        std::mem::swap(&mut vk.beta_g2, &mut vk.gamma_g2);
+        assert_eq!(
+            code(verify(&vk, &proof(), &inputs())),
+            expected(KisanError::InvalidProof)
+        );
+    }
+
+    #[test]
+    fn checks_key_shape() {
+        assert!(key().is_well_formed());
+        let mut vk = key();
+        vk.ic.truncate(1);
+        assert!(!vk.is_well_formed());
+        let mut vk = key();
+        vk.ic.resize(MAX_PUBLIC_INPUTS + 2, bn254::G1_GENERATOR);
+        assert!(!vk.is_well_formed());
+        let mut vk = key();
+        vk.alpha_g1[63] ^= 1;
+        assert!(!vk.is_well_formed());
+    }
+
+    #[test]
+    fn space_fits_the_largest_key() {
+        let mut vk = key();
+        vk.ic.resize(MAX_PUBLIC_INPUTS + 1, bn254::G1_GENERATOR);
+        assert!(vk.is_well_formed());
+        assert_eq!(vk.try_to_vec().unwrap().len(), VerifyingKey::INIT_SPACE);
+    }
+}
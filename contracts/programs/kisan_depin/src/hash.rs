@@ -0,0 +1,103 @@
+// ============================================================
+// Poseidon — circuit-compatible hashing via syscall
+// ============================================================
+//
+// Thin wrapper over the `sol_poseidon` syscall with the
+// circomlib parameters (BN254 scalar field, x⁵ S-box, big-endian
+// inputs), so values hashed here match `Poseidon(n)` in our
+// circuits. Every input is a field element: a 32-byte big-endian
+// integer below the scalar-field modulus r. Wider values, like
+// pubkeys, are split into 128-bit halves first.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::poseidon::{hashv, Endianness, Parameters};
+
+use crate::KisanError;
+
+/// Most inputs the syscall hashes in one call
+pub const MAX_INPUTS: usize = 12;
+
+/// Poseidon hash of `inputs`, as circomlib's `Poseidon(inputs.len())`.
+pub fn poseidon(inputs: &[[u8; 32]]) -> Result<[u8; 32]> {
+    require!(
+        !inputs.is_empty() && inputs.len() <= MAX_INPUTS,
+        KisanError::InvalidPoseidonInput
+    );
+    let refs: Vec<&[u8]> = inputs.iter().map(|i| i.as_slice()).collect();
+    hashv(Parameters::Bn254X5, Endianness::BigEndian, &refs)
+        .map(|h| h.to_bytes())
+        .map_err(|_| KisanError::InvalidPoseidonInput.into())
+}
+
+/// `bytes` (at most 31) as a field element, left-padded with zeros.
+pub fn field_from_bytes(bytes: &[u8]) -> [u8; 32] {
+    let mut out = [0u8; 32];
+    out[32 - bytes.len()..].copy_from_slice(bytes);
+    out
+}
+
+/// An integer as a field element.
+pub fn field_from_u64(value: u64) -> [u8; 32] {
+    field_from_bytes(&value.to_be_bytes())
+}
+
+/// A pubkey as two field elements: its high and low 16 bytes.
+pub fn field_from_pubkey(key: &Pubkey) -> [[u8; 32]; 2] {
+    let bytes = key.to_bytes();
+    [field_from_bytes(&bytes[..16]), field_from_bytes(&bytes[16..])]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hex(s: &str) -> [u8; 32] {
+        let mut out = [0u8; 32];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    fn code(result: Result<[u8; 32]>) -> u32 {
+        match result {
+            Err(Error::AnchorError(e)) => e.error_code_number,
+            other => panic!("expected a program error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn matches_circomlib() {
+        // circomlib's Poseidon(1) and Poseidon(2) test vectors; the latter
+        // is also the "COMPLIANT" hash in zk-proofs/input.json
+        assert_eq!(
+            poseidon(&[field_from_u64(1)]).unwrap(),
+            hex("29176100eaa962bdc1fe6c654d6a3c130e96a4d1168b33848b897dc502820133")
+        );
+        assert_eq!(
+            poseidon(&[field_from_u64(1), field_from_u64(2)]).unwrap(),
+            hex("115cc0f5e7d690413df64c6b9662e9cf2a3617f2743245519e19607a4417189a")
+        );
+    }
+
+    #[test]
+    fn splits_pubkeys_into_halves() {
+        let mut bytes = [0u8; 32];
+        bytes[15] = 1;
+        bytes[31] = 2;
+        let [hi, lo] = field_from_pubkey(&Pubkey::new_from_array(bytes));
+        assert_eq!(hi, field_from_u64(1));
+        assert_eq!(lo, field_from_u64(2));
+    }
+
+    #[test]
+    fn rejects_bad_inputs() {
+        let expected: u32 = KisanError::InvalidPoseidonInput.into();
+        assert_eq!(code(poseidon(&[])), expected);
+        let too_many = [field_from_u64(1); MAX_INPUTS + 1];
+        assert_eq!(code(poseidon(&too_many)), expected);
+        // Not below the scalar-field modulus
+        assert_eq!(code(poseidon(&[[0xff; 32]])), expected);
+    }
+}
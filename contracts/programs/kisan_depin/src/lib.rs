@@ -6,15 +6,234 @@
 //   1. initialize   — Creates the $GREEN token mint and program state
 //   2. verify_and_mint — Accepts a ZK-SNARK proof, verifies it,
 //                        and mints 1 $GREEN token to the farmer
+//   3. grant_delegate / revoke_delegate — Power-of-attorney letting a
+//                        field agent claim on a farmer's behalf
+//   4. create_session_key / revoke_session_key — Short-lived mobile
+//                        keys scoped to verify_and_mint
+//   5. register_relayer / remove_relayer — Allowlisted fee payers for
+//                        gasless claiming, with per-relayer rate limits
+//                        scaled by bonded stake (see 77)
+//   6. initialize_rent_pool / fund_rent_pool / close_proof_record —
+//                        Program-owned SOL pool that sponsors proof rent
+//   7. register_attester / verify_and_escrow / claim_escrow — Custodial
+//                        escrow for farmers identified by phone-number hash
+//   8. configure_region / register_farmer / attest_identity — Farmer
+//                        profiles with optional per-region ID attestation
+//   9. set_personhood_requirement — Proof-of-personhood gate so one
+//                        human operates one farmer profile
+//  10. create_referral_code / set_referral_budget — Referral bonuses
+//                        paid on a referred farmer's first verified mint
+//  11. initialize_badge_tree / claim_badge — Compressed-NFT milestone
+//                        badges (1st, 10th, 50th proof) via Bubblegum
+//  12. initialize_leaderboard / reset_leaderboard — Zero-copy top-N
+//                        farmers by verified proofs, updated on each mint
+//  13. initialize_seasons / set_season_config / advance_season — Seasonal
+//                        reward config, per-season caps and rollover crank
+//  14. set_streak_curve — Bonus multiplier for consecutive-season streaks
+//  15. initialize_mint / configure_circuit — $WATER and $SOIL companion
+//                        mints paid out per circuit
+//  16. configure_pricing — Oracle-priced $GREEN rewards targeting a fixed
+//                        fiat value per claim
+//  17. register_parcel / buy_cover / claim_payout — Weather-indexed crop
+//                        insurance paid from a program-owned SOL pool
+//  18. request_loan / fund_loan / repay_loan / liquidate_loan — USDC
+//                        micro-loans against locked $GREEN and reputation
+//  19. initialize_treasury / buyback_and_burn — Treasury USDC swapped for
+//                        $GREEN through a whitelisted DEX and burned
+//  20. initialize_curve / buy_from_curve / sell_to_curve — Linear bonding
+//                        curve bootstrap sale, migrated by governance
+//  21. create_subscription / settle_subscription — Corporate offtake
+//                        streams: USDC per epoch for a share of issuance,
+//                        delivered from credits farmers offer
+//  22. lookup_serial   — Registry-style serial ranges, vintage and
+//                        methodology recorded on every issuance
+//  23. update_evidence — IPFS CID of a claim's source evidence, editable
+//                        by the farmer for a short window after minting
+//  24. submit_claim / verify_claim / issue_credits / retire — dMRV claim
+//                        lifecycle with an event at each transition
+//  25. get_proof_status / get_farmer_stats — Read-only views returning
+//                        Borsh data via return data for CPI callers
+//  26. verify_proof    — CPI-friendly verify-only step recording a
+//                        verified claim that issue_credits later mints
+//  27. emit_cross_chain_attestation — Wormhole message per issuance for
+//                        buyers on other chains (behind a config flag)
+//  28. register_foreign_emitter / mint_from_vaa — Mint against claims
+//                        attested on another chain via a Wormhole VAA
+//  29. register_evm_oracle / verify_claim_with_evm_report — Claims
+//                        verified by Ethereum-key (secp256k1) signed reports
+//  30. configure_imagery / verify_imagery_and_mint — Satellite NDVI proofs
+//                        over provider-signed observations
+//  31. register_drone_operator / verify_claim_with_drone — Staked drone
+//                        operators co-sign claims with a flight-log root
+//  32. publish_mandi_price — Local crop prices from whitelisted reporters,
+//                        kept in per-market ring buffers
+//  33. anchor_land_record — Government land-record hashes, signed by a
+//                        land-records attester, that parcels can link to
+//  34. attest_pm_kisan_beneficiary — Optional gate limiting claims to
+//                        registered PM-KISAN beneficiaries
+//  35. set_groth16_verifying_key / set_plonk_verifying_key — Groth16
+//                        and PLONK keys, picked per circuit and verified
+//                        with alt_bn128 syscalls
+//  36. set_circuit_verifier — Route a circuit's proofs to an external
+//                        verifier program over a standard CPI interface
+//  37. verify_aggregate_and_mint / redeem_aggregate_leaf — One recursive
+//                        proof over a Merkle root of N claims, redeemed
+//                        leaf by leaf
+//  38. initialize_state_tree / compress_proof_record /
+//      compress_farmer_profile — Archive proof records and dormant
+//                        profiles as concurrent-merkle-tree leaves, with
+//                        inclusion proofs on the read and decompress paths
+//  39. initialize_receipt_tree / set_issuance_receipts — Compressed-NFT
+//                        receipt minted to the farmer on every claim
+//  40. create_lookup_table / extend_lookup_table — Program-owned address
+//                        lookup tables so relayers fit more claims per tx
+//  41. migrate_account — Realloc and backfill versioned accounts after a
+//                        layout upgrade
+//  42. initialize_feature_flags / set_feature — Bitset PDA gating new
+//                        subsystems so they can ship dark
+//  43. initialize_admin_log — Zero-copy ring buffer of every authority
+//                        action (who, what, when, old → new value)
+//  44. Versioned commitments — verify_and_mint recomputes the commitment
+//                        from a domain-separated, versioned preimage,
+//                        with SHA-256 or Poseidon (circuit-compatible)
+//  45. reset_state / airdrop_green — Demo-cluster reset and $GREEN faucet,
+//                        refused unless the program was built and
+//                        initialized with the `devnet` feature
+//  46. register_cpi_integrator / remove_cpi_integrator — verify_and_mint
+//                        is top-level only unless the transaction comes
+//                        from an allowlisted integrator program
+//  47. freeze_account / thaw_account — Authority freezes $GREEN accounts
+//                        tied to sanctions or fraud, with a reason hash
+//  48. configure_payouts / redeem_for_voucher / mark_paid /
+//      dispute_voucher / resolve_voucher_dispute — Burn $GREEN for a
+//                        fiat (UPI) voucher paid by a payout partner,
+//                        with a dispute window
+//  49. set_price_floor / create_listing / buy_listing / cancel_listing —
+//                        USDC credit marketplace with a per-methodology
+//                        floor price, fixed or oracle-derived
+//  50. configure_marketplace / claim_royalties — Royalty on resales paid
+//                        to the farmer whose issuance the credits came from
+//  51. BuyerProfile    — Per-wallet lifetime purchases and retirements,
+//                        updated by buy_listing and retire, with a
+//                        BuyerActivity event per change for ESG exports
+//  52. Claim memos     — Claim mints optionally CPI the SPL Memo program
+//                        with a JSON descriptor (circuit, season, region)
+//  53. ClaimIndexPage  — Per-farmer pages of claim commitments, appended
+//                        at mint time, for walking a farmer's history
+//  54. Claim IDs       — Sequential u64 ID per minted claim, stored in the
+//                        ProofRecord and emitted for registries / invoices
+//  55. Config          — Season parameters, streak curve and pause flag moved
+//                        out of ProgramState into a versioned Config account
+//  56. Oracle checks   — Shared age / confidence / range checks with typed
+//                        errors for price, weather and imagery feeds
+//  57. SeasonSnapshot  — Immutable per-season totals (proofs, minted, unique
+//                        farmers, per-region) written at rollover
+//  58. MintStats       — Zero-copy lifetime mint totals per region and per
+//                        circuit, updated by every farmer claim
+//  59. Profile updates — Farmer changes region / contact hash with an
+//                        attester's Ed25519 countersignature
+//  60. Compromised     — Attesters flag stolen farmer wallets, blocking their
+//      wallets           claims; the profile then moves to a new wallet
+//  61. distribute      — Batch $GREEN incentives to many wallets, capped by
+//                        an incentives budget in Config
+//  62. Onboarding bonus — Minted into escrow at registration, released by
+//                        the first verify_and_mint, burned after a deadline
+//  63. Keeper cranks   — settle_cover pays or expires cover without the
+//                        farmer; `keeper` runs every due crank unattended
+//  64. Treasury policy — Daily-limited USDC withdrawals; larger ones are
+//                        timelocked and guardian co-signed. Marketplace
+//                        fees and subscriptions settle into the treasury
+//  65. VK rotation     — A rotated-out Groth16 or PLONK key stays valid for
+//                        an overlap window of slots, so in-flight claims
+//                        still land
+//  66. pre_commit      — Farmers timestamp a hidden claim commitment before
+//                        revealing it in verify_and_mint (commit-reveal)
+//  67. get_addresses   — View returning the program's PDAs, derived from
+//                        its own seeds, for integrators and explorers
+//  68. OTC deals       — A seller escrows $GREEN for a named counterparty at
+//                        a negotiated USDC price; accepting settles both
+//                        legs at once, expiry refunds the seller
+//  69. sGREEN          — Staked $GREEN is represented by an interest-bearing
+//                        Token-2022 mint usable as collateral elsewhere;
+//                        redeeming burns it for the stake plus rewards
+//  70. Governance      — $GREEN deposited as voting power passes typed
+//                        proposals (fee, reward, verifying key, pause,
+//                        treasury spend), weighted per proposal kind
+//  71. Vote delegation — Farmers delegate their deposit's voting power to
+//                        a cooperative, which votes with it; revocable
+//  72. Emission curves — Season rewards decay by a configured curve: fixed,
+//                        linear, exponential or step halving
+//  73. Cap table       — Community, team, investor and ecosystem allocations
+//                        minted at initialize into vesting escrows
+//  74. Oracle reporters — One registry of reporter keys, scoped per feed and
+//                        rotatable; weather and mandi reports can require a
+//                        quorum of co-signing reporters
+//  75. Authority recovery — A recovery key claims authority after a countdown
+//                        the current authority can cancel by acting
+//  76. Proof payloads  — Proofs arrive as one versioned, length-prefixed
+//                        `ProofPayload`, decoded in one place
+//  77. Relayer stake   — Relayer quotas scale with bonded stake, slashed
+//                        when a signed relay receipt is never honoured
+//  78. Claim queue     — Verified claims past an exhausted season budget
+//                        wait in a FIFO queue, cranked in the next season
+//  79. Custodial wallets — Farmers link a registered custodian's wallet PDA
+//                        and receive mints into token accounts it owns
+//  80. Payout splits   — Tenant and landowner agree a parcel's reward split;
+//                        claims on the parcel mint each their share
+//  81. Node rewards    — Claims verified by a quorum of attester nodes pay
+//                        those nodes a configured share of the issuance
+//  82. Fraud bounties  — Bonded fraud reports against a claim; an upheld
+//                        report's clawback moves to the treasury, paying
+//                        the reporter a share from a treasury-funded pool
 //
 // Architecture:
 //   - PDA-controlled token mint (no single authority)
 //   - Commitment-based replay protection (each proof used once)
-//   - On-chain proof verification (simplified for demo)
+//   - On-chain proof verification: Groth16 and PLONK proofs checked
+//     with the alt_bn128 pairing syscalls against each circuit's
+//     registered key; other proof systems by CPI to the circuit's
+//     external verifier program
 // ============================================================
 
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use anchor_lang::solana_program::keccak;
+use anchor_lang::Discriminator;
+use anchor_spl::token::{
+    self, Burn, CloseAccount, FreezeAccount, Mint, MintTo, ThawAccount, Token, TokenAccount,
+    Transfer,
+};
+use anchor_spl::token_2022::{self, Token2022};
+use anchor_spl::token_interface;
+use kisan_common::seeds::*;
+
+use commitment::CommitmentOpening;
+use emissions::EmissionCurve;
+use governance::{ProposalAction, ProposedKey, VoteWeighting};
+use payload::{Proof, ProofPayload};
+use report::{require_ctx, verbose};
+
+pub mod bn254;
+pub mod bubblegum;
+pub mod commitment;
+pub mod compression;
+pub mod compute;
+pub mod curve;
+pub mod dex;
+pub mod ed25519;
+pub mod emissions;
+pub mod governance;
+pub mod groth16;
+pub mod hash;
+pub mod lookup_table;
+pub mod memo;
+pub mod oracle;
+pub mod payload;
+pub mod plonk;
+pub mod report;
+pub mod secp256k1;
+pub mod sgreen;
+pub mod verifier;
+pub mod wormhole;
 
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
@@ -24,8 +243,121 @@ declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
 const GREEN_TOKEN_DECIMALS: u8 = 9;
 const MINT_AMOUNT: u64 = 1_000_000_000; // 1 $GREEN (with 9 decimals)
-const STATE_SEED: &[u8] = b"kisan-depin-state";
-const MINT_SEED: &[u8] = b"green-token-mint";
+const GREEN_UNIT: u64 = 10u64.pow(GREEN_TOKEN_DECIMALS as u32);
+/// Longest a rotated-out verifying key stays valid: about a week of
+/// 400 ms slots
+const MAX_VK_OVERLAP_SLOTS: u64 = 1_512_000;
+/// Deepest Merkle proof accepted for an aggregate leaf (2³² leaves)
+const MAX_AGGREGATE_DEPTH: usize = 32;
+/// Decimals of `PricingConfig::target_value` (micro-units of the quote currency)
+const PRICE_TARGET_DECIMALS: u8 = 6;
+/// Length of the window `TreasuryConfig::daily_limit` applies to
+const TREASURY_WINDOW: i64 = 24 * 60 * 60;
+/// Shortest delay between proposing and executing a large withdrawal
+const MIN_TREASURY_TIMELOCK: i64 = 24 * 60 * 60;
+
+const ADMIN_LOG_LEN: usize = 64;
+
+/// Shortest authority silence `set_recovery` accepts before the recovery
+/// key can take over
+const MIN_RECOVERY_PERIOD: i64 = 30 * 24 * 60 * 60; // 30 days
+
+/// Wait between `begin_relayer_unbonding` and `withdraw_relayer_stake`;
+/// stake stays slashable throughout
+const RELAYER_UNBONDING_PERIOD: i64 = 7 * 24 * 60 * 60; // 7 days
+/// Share of a relayer's stake slashed per dropped claim
+const RELAYER_SLASH_BPS: u64 = 1_000; // 10%
+/// How long after its deadline a relay receipt can be reported, well
+/// inside `PROOF_RETENTION_PERIOD` so a closed proof record can't pass
+/// for a dropped claim
+const RELAY_REPORT_WINDOW: i64 = 30 * 24 * 60 * 60; // 30 days
+
+/// Feature bits in `FeatureFlags::flags`. Everything starts disabled.
+const FEATURE_ORACLE_PRICING: u64 = 1 << 0; // oracle-priced $GREEN rewards
+const FEATURE_BATCHING: u64 = 1 << 1; // aggregate-proof batch verification
+const FEATURE_MARKETPLACE: u64 = 1 << 2; // USDC credit marketplace
+const FEATURE_UNREGISTERED_CLAIMS: u64 = 1 << 3; // verify_and_mint without a farmer profile
+const KNOWN_FEATURES: u64 = FEATURE_ORACLE_PRICING
+    | FEATURE_BATCHING
+    | FEATURE_MARKETPLACE
+    | FEATURE_UNREGISTERED_CLAIMS;
+
+/// Region code recorded in stats and memos for claims without a profile
+const UNREGISTERED_REGION: u16 = u16::MAX;
+
+/// Layout version stamped on `ProgramState`, `FarmerProfile`,
+/// `ProofRecord` and `Config`. Accounts created before the version byte read as 0 and
+/// are treated as v1. Bump this whenever one of them gains a field and
+/// backfill it in `Versioned::backfill`.
+const ACCOUNT_VERSION: u8 = 9;
+const PROOF_RETENTION_PERIOD: i64 = 365 * 24 * 60 * 60; // 1 year
+/// Time since a farmer's last claim before their profile may be compressed
+const PROFILE_DORMANCY_PERIOD: i64 = 365 * 24 * 60 * 60; // 1 year
+/// Leaf tags of compressed records, see `compression::leaf_hash`
+const PROOF_RECORD_LEAF_TAG: &[u8] = b"proof-record";
+const FARMER_PROFILE_LEAF_TAG: &[u8] = b"farmer-profile";
+const EVIDENCE_UPDATE_WINDOW: i64 = 24 * 60 * 60; // 24 hours
+/// Length of a binary CIDv0 (sha2-256 multihash)
+const EVIDENCE_CID_LEN: usize = 34;
+/// Version byte leading every cross-chain attestation payload
+const ATTESTATION_PAYLOAD_VERSION: u8 = 1;
+/// Version byte of inbound registry claim payloads
+const REGISTRY_CLAIM_PAYLOAD_VERSION: u8 = 1;
+/// Version byte of EVM-signed oracle reports
+const EVM_REPORT_VERSION: u8 = 1;
+/// Version byte of imagery-provider observation messages
+const IMAGERY_OBSERVATION_VERSION: u8 = 1;
+/// Oracle reporter scope bits: the feeds a reporter key may publish to.
+pub const ORACLE_SCOPE_WEATHER: u8 = 1 << 0;
+pub const ORACLE_SCOPE_MANDI_PRICE: u8 = 1 << 1;
+const ORACLE_SCOPE_ALL: u8 = ORACLE_SCOPE_WEATHER | ORACLE_SCOPE_MANDI_PRICE;
+/// Most reporters a feed can require to co-sign one report
+const MAX_ORACLE_QUORUM: u8 = 5;
+/// Most attester nodes recorded on one quorum-verified claim
+const MAX_ATTESTATION_QUORUM: usize = 5;
+/// Reports kept per (commodity, region) market
+const MANDI_HISTORY_LEN: usize = 32;
+const MAX_BADGE_URI_PREFIX_LEN: usize = 64;
+/// Longest seed prefix a custodian's wallet PDAs can be derived with
+const MAX_CUSTODIAN_PREFIX_LEN: usize = 32;
+
+/// Verified-proof counts that unlock a milestone badge. Bit `i` of
+/// `FarmerProfile::badges_claimed` records that milestone `i` was claimed.
+const BADGE_MILESTONES: [u64; 3] = [1, 10, 50];
+
+/// Claims per `ClaimIndexPage`; a farmer's claim number `n` (0-based) is
+/// on page `n / CLAIM_INDEX_PAGE_LEN`
+pub const CLAIM_INDEX_PAGE_LEN: usize = 32;
+const LEADERBOARD_SIZE: usize = 100;
+/// Regions and circuits broken out in `MintStats`; mints past either cap
+/// only count towards its `other_*` entries.
+pub const MINT_STATS_REGIONS: usize = 128;
+pub const MINT_STATS_CIRCUITS: usize = 32;
+
+/// Regions broken out in a season tally; claims from further regions
+/// only count towards the `untracked_*` totals.
+const MAX_TALLY_REGIONS: usize = 64;
+
+/// Number of points on the streak bonus curve; longer streaks use the last.
+const STREAK_CURVE_LEN: usize = 8;
+const PROPOSAL_KINDS: usize = 6; // variants of `ProposalAction`
+pub const ALLOCATION_KINDS: usize = 4; // variants of `AllocationKind`
+const MAX_STREAK_BONUS_BPS: u16 = 10_000; // at most doubles the reward
+const MAX_ROYALTY_BPS: u16 = 1_000; // resale royalty, at most 10% of the price
+const MAX_NODE_SHARE_BPS: u16 = 2_000; // attester nodes, at most 20% of an issuance
+const MAX_BOUNTY_BPS: u16 = 2_500; // fraud bounty, at most 25% of the recovered amount
+const MAX_MARKET_FEE_BPS: u16 = 500; // protocol fee, at most 5% of the price
+const MAX_BUYBACK_SLIPPAGE_BPS: u16 = 1_000; // buybacks accept at most 10% under the oracle
+const MAX_SGREEN_RATE_BPS: i16 = 2_000; // staking yield, at most 20% a year
+const BPS_DENOMINATOR: u64 = 10_000;
+const MAX_SESSION_DURATION: i64 = 24 * 60 * 60; // 24 hours
+
+/// Session-key scope bits. Only `verify_and_mint` can be delegated today.
+const SESSION_SCOPE_VERIFY_AND_MINT: u8 = 1 << 0;
+const SESSION_SCOPE_ALL: u8 = SESSION_SCOPE_VERIFY_AND_MINT;
+
+/// Largest single `airdrop_green`, so a typo can't mint a whale balance
+const DEVNET_AIRDROP_MAX: u64 = 1_000 * GREEN_UNIT;
 
 // ─────────────────────────────────────────────────────────────
 // Program
@@ -36,15 +368,68 @@ pub mod kisan_depin {
     use super::*;
 
     /// Initialize the program state and $GREEN token mint.
-    /// Called once by the deployer.
-    pub fn initialize(ctx: Context<Initialize>) -> Result<()> {
+    /// Called once by the deployer: the signer must be the program's
+    /// upgrade authority, so nobody can race the deploy and take the
+    /// authority seat. A second call fails with `AlreadyInitialized`.
+    ///
+    /// The reserved allocations of the token distribution are minted here,
+    /// each into its own escrow, and vest from this moment; everything else
+    /// is issued through proofs. See `release_allocation`.
+    ///
+    /// # Arguments
+    /// * `allocations` — Terms of the community, team, investor and
+    ///   ecosystem allocations, in `AllocationKind` order; zero amounts
+    ///   reserve nothing
+    pub fn initialize(
+        ctx: Context<Initialize>,
+        allocations: [AllocationTerms; ALLOCATION_KINDS],
+    ) -> Result<()> {
         let state = &mut ctx.accounts.program_state;
+        require!(state.mint == Pubkey::default(), KisanError::AlreadyInitialized);
         state.authority = ctx.accounts.authority.key();
         state.total_proofs_verified = 0;
         state.total_tokens_minted = 0;
         state.mint = ctx.accounts.green_mint.key();
         state.bump = ctx.bumps.program_state;
         state.mint_bump = ctx.bumps.green_mint;
+        state.version = ACCOUNT_VERSION;
+        state.devnet = cfg!(feature = "devnet");
+        // Default reward, carried into `Config` by `initialize_config`
+        state.legacy_season_reward_amount = MINT_AMOUNT;
+
+        let vaults = [
+            &ctx.accounts.community_vault,
+            &ctx.accounts.team_vault,
+            &ctx.accounts.investors_vault,
+            &ctx.accounts.ecosystem_vault,
+        ];
+        let cap_table = &mut ctx.accounts.cap_table;
+        cap_table.start = Clock::get()?.unix_timestamp;
+        cap_table.bump = ctx.bumps.cap_table;
+        let mut reserved: u64 = 0;
+        for ((terms, vault), allocation) in
+            allocations.iter().zip(vaults).zip(cap_table.allocations.iter_mut())
+        {
+            require!(terms.is_valid(), KisanError::InvalidAllocation);
+            reserved = reserved.checked_add(terms.amount).ok_or(KisanError::InvalidAllocation)?;
+            if terms.amount > 0 {
+                mint_tokens(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.green_mint,
+                    vault,
+                    state,
+                    terms.amount,
+                )?;
+            }
+            *allocation = Allocation {
+                beneficiary: terms.beneficiary,
+                amount: terms.amount,
+                cliff: terms.cliff,
+                duration: terms.duration,
+                released: 0,
+            };
+        }
+        state.total_tokens_minted = reserved;
 
         msg!("Kisan-DePIN initialized!");
         msg!("$GREEN mint: {}", ctx.accounts.green_mint.key());
@@ -53,224 +438,20067 @@ pub mod kisan_depin {
         Ok(())
     }
 
-    /// Verify a ZK-SNARK proof and mint 1 $GREEN token to the farmer.
+    /// Pay an allocation's beneficiary whatever has vested since the last
+    /// release. Nothing vests before the cliff; after it the allocation
+    /// vests linearly from `initialize` until `duration` has passed.
+    /// Anyone can call; tokens only ever go to the beneficiary.
+    pub fn release_allocation(ctx: Context<ReleaseAllocation>, kind: AllocationKind) -> Result<()> {
+        let cap_table = &mut ctx.accounts.cap_table;
+        let start = cap_table.start;
+        let allocation = &mut cap_table.allocations[kind as usize];
+        let amount = allocation.vested(start, Clock::get()?.unix_timestamp) - allocation.released;
+        require!(amount > 0, KisanError::NothingVested);
+        allocation.released += amount;
+
+        let program_state = &ctx.accounts.program_state;
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.allocation_vault.to_account_info(),
+                    to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                    authority: program_state.to_account_info(),
+                },
+                &[&[STATE_SEED, &[program_state.bump]]],
+            ),
+            amount,
+        )?;
+
+        emit!(AllocationReleased {
+            kind,
+            beneficiary: allocation.beneficiary,
+            amount,
+            released: allocation.released,
+            remaining: allocation.amount - allocation.released,
+        });
+
+        Ok(())
+    }
+
+    /// Designate the key that can recover the authority seat if the
+    /// authority key is lost, and how long the authority must stay silent
+    /// first. Cancels any recovery in progress. `Pubkey::default()`
+    /// disables recovery.
+    ///
+    /// # Arguments
+    /// * `recovery_key` — Wallet that may claim authority
+    /// * `inactivity_period` — Seconds, at least `MIN_RECOVERY_PERIOD`
+    pub fn set_recovery(
+        ctx: Context<SetRecovery>,
+        recovery_key: Pubkey,
+        inactivity_period: i64,
+    ) -> Result<()> {
+        if inactivity_period < MIN_RECOVERY_PERIOD {
+            return Err(report::exceeded(
+                KisanError::RecoveryPeriodTooShort,
+                inactivity_period,
+                MIN_RECOVERY_PERIOD,
+            ));
+        }
+
+        let recovery = &mut ctx.accounts.recovery_config;
+        let old_period = recovery.inactivity_period;
+        recovery.recovery_key = recovery_key;
+        recovery.inactivity_period = inactivity_period;
+        recovery.initiated_at = 0;
+        recovery.bump = ctx.bumps.recovery_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetRecovery,
+            recovery_key,
+            old_period as u64,
+            inactivity_period as u64,
+        )?;
+
+        msg!("Recovery key {} after {} s", recovery_key, inactivity_period);
+
+        Ok(())
+    }
+
+    /// Start the recovery countdown. The recovery key can claim authority
+    /// once `inactivity_period` has passed with no admin action logged in
+    /// between; any authority instruction, or `cancel_recovery`, stops it.
+    pub fn initiate_recovery(ctx: Context<InitiateRecovery>) -> Result<()> {
+        let recovery = &mut ctx.accounts.recovery_config;
+        require!(recovery.initiated_at == 0, KisanError::RecoveryInProgress);
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.recovery_key,
+            AdminAction::InitiateRecovery,
+            ctx.accounts.program_state.authority,
+            0,
+            recovery.inactivity_period as u64,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        recovery.initiated_at = now;
+        recovery.actions_at_initiation = ctx.accounts.admin_log.load()?.total_actions;
+
+        emit!(RecoveryInitiated {
+            recovery_key: recovery.recovery_key,
+            authority: ctx.accounts.program_state.authority,
+            claimable_at: now + recovery.inactivity_period,
+        });
+
+        Ok(())
+    }
+
+    /// Stop a recovery countdown.
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+        let recovery = &mut ctx.accounts.recovery_config;
+        require!(recovery.initiated_at != 0, KisanError::RecoveryNotInProgress);
+        let initiated_at = recovery.initiated_at;
+        recovery.initiated_at = 0;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::CancelRecovery,
+            recovery.recovery_key,
+            initiated_at as u64,
+            0,
+        )?;
+
+        emit!(RecoveryCancelled {
+            recovery_key: recovery.recovery_key,
+            initiated_at,
+        });
+
+        Ok(())
+    }
+
+    /// Take the authority seat once the countdown has run out with the
+    /// authority silent throughout.
+    pub fn claim_recovery(ctx: Context<ClaimRecovery>) -> Result<()> {
+        let recovery = &mut ctx.accounts.recovery_config;
+        require!(recovery.initiated_at != 0, KisanError::RecoveryNotInProgress);
+        let claimable_at = recovery.initiated_at + recovery.inactivity_period;
+        let now = Clock::get()?.unix_timestamp;
+        require_ctx!(
+            now >= claimable_at,
+            KisanError::RecoveryCountdownRunning,
+            "Recovery claimable at {} (now {})",
+            claimable_at,
+            now
+        );
+        require!(
+            ctx.accounts.admin_log.load()?.total_actions == recovery.actions_at_initiation,
+            KisanError::AuthorityActive
+        );
+
+        let state = &mut ctx.accounts.program_state;
+        let old_authority = state.authority;
+        state.authority = recovery.recovery_key;
+        recovery.initiated_at = 0;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.recovery_key,
+            AdminAction::ClaimRecovery,
+            old_authority,
+            0,
+            0,
+        )?;
+
+        emit!(AuthorityRecovered {
+            old_authority,
+            new_authority: state.authority,
+        });
+
+        Ok(())
+    }
+
+    /// Verify a ZK-SNARK proof and mint the current season's reward
+    /// (1 $GREEN by default) to the farmer.
+    ///
+    /// The transaction is signed by the `claimant`, which is either the
+    /// farmer themselves, a mobile session key, or a field agent holding
+    /// a live `Delegation`. Tokens are always minted to the farmer's ATA.
+    ///
+    /// Rent for the proof record is paid by `payer`. When the payer is not
+    /// the claimant it must be an allowlisted relayer within its quota,
+    /// so farmers never need SOL of their own.
     ///
     /// # Arguments
-    /// * `proof_a` — G1 point (pi_a) from Groth16 proof
-    /// * `proof_b` — G2 point (pi_b) from Groth16 proof  
-    /// * `proof_c` — G1 point (pi_c) from Groth16 proof
-    /// * `public_signals` — Public inputs [commitment, expectedHash]
+    /// * `payload` — The proof, in the circuit's proof system, and its
+    ///   public inputs [commitment, expectedHash]; see `payload.rs`
     /// * `compliance_commitment` — The unique commitment hash (replay protection)
+    /// * `opening` — Commitment preimage fields (scheme, parcel, nonce); see
+    ///   `commitment.rs`
+    /// * `evidence_cid` — Optional IPFS CID of the claim's source evidence
     ///
     /// # Verification Logic
-    /// In production: Perform full Groth16 pairing check on-chain using
-    /// Solana's alt_bn128 precompile (available since v1.16).
-    /// For demo: Verify the proof structure is well-formed and the
-    /// commitment hasn't been used before.
-    pub fn verify_and_mint(
-        ctx: Context<VerifyAndMint>,
-        proof_a: [u8; 64],
-        proof_b: [u8; 128],
-        proof_c: [u8; 64],
-        public_signals: Vec<u8>,
+    /// Groth16 and PLONK circuits get the full pairing check against the
+    /// circuit's registered key (see `groth16.rs`, `plonk.rs`), with the
+    /// commitment as the first public input. Circuits with an external
+    /// verifier are checked by CPI (see `verifier.rs`); its accounts are
+    /// passed as remaining accounts.
+    pub fn verify_and_mint<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyAndMint<'info>>,
+        payload: ProofPayload,
         compliance_commitment: [u8; 32],
+        opening: CommitmentOpening,
+        evidence_cid: Option<[u8; EVIDENCE_CID_LEN]>,
     ) -> Result<()> {
-        let state = &mut ctx.accounts.program_state;
-        let proof_record = &mut ctx.accounts.proof_record;
+        ctx.accounts.proof_record.ensure_unused()?;
+        let proof = payload.decode(ctx.accounts.circuit_config.proof_system)?;
+        let public_signals = &payload.public_signals;
+        enforce_cpi_policy(
+            ctx.accounts.instructions_sysvar.as_ref(),
+            ctx.accounts.cpi_integrator.as_deref().map(|a| &**a),
+        )?;
 
-        // ── Step 1: Verify proof hasn't been used before ──
-        // The proof_record PDA is derived from the commitment,
-        // so attempting to reuse a commitment will fail (account exists)
-        msg!("Step 1: Verifying proof uniqueness...");
-        msg!("Commitment: {:?}", &compliance_commitment[..8]);
+        let now = Clock::get()?.unix_timestamp;
+        let farmer = ctx.accounts.farmer.key();
+        let claimant = ctx.accounts.claimant.key();
 
-        // ── Step 2: Verify the ZK-SNARK proof ──
-        // In production, this would call the alt_bn128 precompile:
-        //   sol_alt_bn128_pairing(proof_a, proof_b, proof_c, vk, public_signals)
-        //
-        // For the hackathon demo, we verify:
-        //   a) Proof components are non-zero (well-formed)
-        //   b) Public signals are present
-        //   c) Commitment is 32 bytes
-        msg!("Step 2: Verifying ZK-SNARK proof (Groth16)...");
-        
-        require!(
-            proof_a.iter().any(|&b| b != 0),
-            KisanError::InvalidProof
-        );
-        require!(
-            proof_b.iter().any(|&b| b != 0),
-            KisanError::InvalidProof
-        );
-        require!(
-            proof_c.iter().any(|&b| b != 0),
-            KisanError::InvalidProof
-        );
-        require!(
-            !public_signals.is_empty(),
-            KisanError::InvalidPublicSignals
-        );
-        require!(
-            compliance_commitment.iter().any(|&b| b != 0),
-            KisanError::InvalidCommitment
+        // ── Step 0: Authorize the claimant ──
+        // A farmer may always claim for themselves; anyone else must
+        // present a live session key or delegation granted by the farmer.
+        if claimant != farmer {
+            if let Some(session) = ctx.accounts.session_key.as_ref() {
+                require_ctx!(
+                    now < session.expiry,
+                    KisanError::SessionExpired,
+                    "Session expired at {} (now {})",
+                    session.expiry,
+                    now
+                );
+                require!(
+                    session.scope & SESSION_SCOPE_VERIFY_AND_MINT != 0,
+                    KisanError::SessionScopeViolation
+                );
+                verbose!("Session-key claim by: {}", claimant);
+            } else {
+                let delegation = ctx
+                    .accounts
+                    .delegation
+                    .as_mut()
+                    .ok_or(KisanError::UnauthorizedClaimant)?;
+                require_ctx!(
+                    now < delegation.expiry,
+                    KisanError::DelegationExpired,
+                    "Delegation expired at {} (now {})",
+                    delegation.expiry,
+                    now
+                );
+                require_ctx!(
+                    delegation.claims_used < delegation.max_claims,
+                    KisanError::DelegationExhausted,
+                    "Delegation used {} of {} claims",
+                    delegation.claims_used,
+                    delegation.max_claims
+                );
+                delegation.claims_used += 1;
+                verbose!("Delegated claim by agent: {}", claimant);
+            }
+        }
+
+        // ── Step 0b: Authorize the fee payer ──
+        // Third-party payers must be registered relayers and are held to
+        // a quota of claims per rolling window, scaled by their stake.
+        let payer = ctx.accounts.payer.key();
+        if payer != claimant {
+            let relayer = ctx
+                .accounts
+                .relayer_info
+                .as_mut()
+                .ok_or(KisanError::UnauthorizedRelayer)?;
+            if now >= relayer.window_start + relayer.window_seconds {
+                relayer.window_start = now;
+                relayer.claims_in_window = 0;
+            }
+            let quota = relayer.quota();
+            require_ctx!(
+                relayer.claims_in_window < quota,
+                KisanError::RelayerRateLimited,
+                "Relayer at {} of {} claims this window, resets at {}",
+                relayer.claims_in_window,
+                quota,
+                relayer.window_start + relayer.window_seconds
+            );
+            relayer.claims_in_window += 1;
+            relayer.total_relayed += 1;
+            verbose!("Relayed by: {}", payer);
+        }
+
+        // ── Step 0b': Check the season is open and under its caps ──
+        // The base reward is the season's fixed amount, or the oracle-priced
+        // amount when dynamic pricing is on. Unbroken consecutive-season
+        // streaks earn a bonus on top, per the configured curve.
+        require!(ctx.accounts.circuit_config.active, KisanError::CircuitInactive);
+        let base_reward = base_reward(
+            &ctx.accounts.circuit_config,
+            &ctx.accounts.program_state,
+            &ctx.accounts.feature_flags,
+            &ctx.accounts.season,
+            ctx.accounts.pricing_config.as_deref(),
+            ctx.accounts.price_feed.as_ref(),
+            now,
+        )?;
+        // Without a profile there is no per-farmer cap, streak or region
+        // to apply, so such claims need the authority's opt-in.
+        let (streak, bonus_bps) = match ctx.accounts.farmer_profile.as_mut() {
+            Some(profile) => {
+                let streak = profile.record_season_claim(&ctx.accounts.season)?;
+                (streak, ctx.accounts.config.streak_bonus_bps(streak))
+            }
+            None => {
+                require!(
+                    ctx.accounts.feature_flags.is_enabled(FEATURE_UNREGISTERED_CLAIMS),
+                    KisanError::FarmerProfileRequired
+                );
+                (0, 0)
+            }
+        };
+        let reward = ctx.accounts.season.record_claim(now, base_reward, bonus_bps)?;
+        let region_code = match ctx.accounts.farmer_profile.as_deref() {
+            Some(profile) => {
+                ctx.accounts.season_tally.record(profile, reward);
+                profile.region_code
+            }
+            None => {
+                ctx.accounts.season_tally.record_unregistered(reward);
+                UNREGISTERED_REGION
+            }
+        };
+        ctx.accounts.mint_stats.load_mut()?.record(
+            region_code,
+            ctx.accounts.circuit_config.circuit_id,
+            reward,
         );
+        let season_index = ctx.accounts.season.index;
+        if bonus_bps > 0 {
+            verbose!("Streak of {} seasons: +{} bps", streak, bonus_bps);
+        }
+
+        // ── Step 0c: Enforce the region's identity requirement ──
+        if let Some(profile) = ctx.accounts.farmer_profile.as_deref() {
+            let region = ctx
+                .accounts
+                .region_config
+                .as_deref()
+                .ok_or(KisanError::ProfileAccountsRequired)?;
+            require_region_identity(
+                profile,
+                region,
+                ctx.accounts.identity_attestation.as_deref(),
+            )?;
+        }
+        if let Some(attestation) = ctx.accounts.pm_kisan_attestation.as_ref() {
+            let expected = ctx
+                .accounts
+                .farmer_profile
+                .as_ref()
+                .map(|p| p.pm_kisan_attestation)
+                .ok_or(KisanError::PmKisanAttestationRequired)?;
+            require_keys_eq!(attestation.key(), expected, KisanError::PmKisanAttestationRequired);
+        }
+        require_pm_kisan_beneficiary(
+            &ctx.accounts.program_state,
+            ctx.accounts.pm_kisan_attestation.as_deref(),
+        )?;
+
+        // ── Step 1: Verify proof hasn't been used before ──
+        // The proof_record PDA is derived from the commitment, so reusing
+        // one fails up front in `ProofRecord::ensure_unused`
+        verbose!("Step 1: Verifying proof uniqueness...");
+        verbose!("Commitment: {:?}", &compliance_commitment[..8]);
+
+        // ── Step 1b: Check the commitment opens to this claim ──
+        commitment::verify(
+            &compliance_commitment,
+            &opening,
+            ctx.accounts.circuit_config.circuit_id,
+            &farmer,
+            season_index,
+            public_signals,
+        )?;
+
+        // ── Step 1c: Reveal the farmer's pre-commitment, if any ──
+        if let Some(pre_commit) = ctx.accounts.pre_commit.as_mut() {
+            require!(
+                pre_commit.hash == PreCommit::hash_of(&compliance_commitment, &farmer),
+                KisanError::PreCommitMismatch
+            );
+            pre_commit.revealed_slot = Clock::get()?.slot;
+            emit!(PreCommitRevealed {
+                farmer,
+                commitment: compliance_commitment,
+                committed_slot: pre_commit.committed_slot,
+                revealed_slot: pre_commit.revealed_slot,
+            });
+        }
 
-        msg!("Step 2: Proof structure verified ✓");
+        // ── Step 2: Verify the ZK-SNARK proof ──
+        verbose!("Step 2: Verifying ZK-SNARK proof ({:?})...", payload.format);
+        verify_circuit_proof(
+            &ctx.accounts.circuit_config,
+            ctx.accounts.groth16_vk.as_deref(),
+            ctx.accounts.previous_groth16_vk.as_deref(),
+            ctx.accounts.plonk_vk.as_deref(),
+            ctx.accounts.previous_plonk_vk.as_deref(),
+            ctx.accounts.verifier_program.as_ref(),
+            ctx.remaining_accounts,
+            &proof,
+            public_signals,
+        )?;
+        verbose!("Step 2: Proof verified ✓");
 
         // ── Step 3: Record the proof (replay protection) ──
+        let proof_record = &mut ctx.accounts.proof_record;
         proof_record.commitment = compliance_commitment;
-        proof_record.farmer = ctx.accounts.farmer.key();
-        proof_record.timestamp = Clock::get()?.unix_timestamp;
+        proof_record.farmer = farmer;
+        proof_record.timestamp = now;
         proof_record.verified = true;
+        proof_record.rent_payer = payer;
+        proof_record.bump = ctx.bumps.proof_record;
+        proof_record.version = ACCOUNT_VERSION;
+        proof_record.season = season_index;
+        proof_record.vintage = vintage_year(now);
+        proof_record.methodology_id = ctx.accounts.circuit_config.methodology_id;
+        (proof_record.serial_start, proof_record.serial_end) =
+            ctx.accounts.program_state.allocate_serials(reward);
+        proof_record.claim_id = ctx.accounts.program_state.next_claim_id();
+        proof_record.evidence_cid = evidence_cid.unwrap_or([0; EVIDENCE_CID_LEN]);
 
-        // ── Step 4: Mint 1 $GREEN token to the farmer ──
-        msg!("Step 3: Minting 1 $GREEN to farmer: {}", ctx.accounts.farmer.key());
+        // ── Step 3b: Reimburse proof rent from the sponsorship pool ──
+        // The payer fronts the rent during account creation; the pool pays
+        // it back in the same instruction and is recorded as the recipient
+        // of the rent once the record is closed.
+        if let Some(rent_pool) = ctx.accounts.rent_pool.as_mut() {
+            let record_info = ctx.accounts.proof_record.to_account_info();
+            let rent = record_info.lamports();
+            let pool_info = rent_pool.to_account_info();
+            let pool_floor = Rent::get()?.minimum_balance(pool_info.data_len());
+            require!(
+                pool_info.lamports() >= pool_floor + rent,
+                KisanError::RentPoolDepleted
+            );
 
-        let state_bump = state.bump;
-        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[state_bump]]];
+            **pool_info.try_borrow_mut_lamports()? -= rent;
+            **ctx.accounts.payer.to_account_info().try_borrow_mut_lamports()? += rent;
 
-        token::mint_to(
-            CpiContext::new_with_signer(
-                ctx.accounts.token_program.to_account_info(),
-                MintTo {
-                    mint: ctx.accounts.green_mint.to_account_info(),
-                    to: ctx.accounts.farmer_token_account.to_account_info(),
-                    authority: ctx.accounts.program_state.to_account_info(),
+            rent_pool.total_sponsored += rent;
+            rent_pool.active_sponsorships += 1;
+            ctx.accounts.proof_record.rent_payer = rent_pool.key();
+            verbose!("Proof rent sponsored: {} lamports", rent);
+        }
+
+        // ── Step 4: Mint the season reward to the farmer ──
+        // A parcel under a payout split sends the landowner their share.
+        verbose!("Step 3: Minting season {} reward to farmer: {}", season_index, farmer);
+
+        let mut farmer_share = reward;
+        if let Some(split) = PayoutSplit::load(&ctx.accounts.payout_split)? {
+            let landowner_share = split.landowner_share(reward);
+            let landowner_account = ctx
+                .accounts
+                .landowner_token_account
+                .as_ref()
+                .ok_or(KisanError::LandownerAccountRequired)?;
+            require_keys_eq!(
+                landowner_account.owner,
+                split.landowner,
+                KisanError::LandownerAccountRequired
+            );
+            if landowner_share > 0 {
+                mint_tokens(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.green_mint,
+                    landowner_account,
+                    &ctx.accounts.program_state,
+                    landowner_share,
+                )?;
+            }
+            farmer_share -= landowner_share;
+            emit!(RewardSplit {
+                parcel: split.parcel,
+                farmer,
+                landowner: split.landowner,
+                farmer_amount: farmer_share,
+                landowner_amount: landowner_share,
+            });
+        }
+
+        if farmer_share > 0 {
+            mint_tokens(
+                &ctx.accounts.token_program,
+                &ctx.accounts.green_mint,
+                &ctx.accounts.farmer_token_account,
+                &ctx.accounts.program_state,
+                farmer_share,
+            )?;
+        }
+
+        // ── Step 4a: Pay the circuit's companion rewards ──
+        let circuit = &ctx.accounts.circuit_config;
+        for (kind, mint, to) in [
+            (
+                MintKind::Water,
+                ctx.accounts.water_mint.as_ref(),
+                ctx.accounts.water_token_account.as_ref(),
+            ),
+            (
+                MintKind::Soil,
+                ctx.accounts.soil_mint.as_ref(),
+                ctx.accounts.soil_token_account.as_ref(),
+            ),
+        ] {
+            let amount = circuit.reward_for(kind);
+            if amount == 0 {
+                continue;
+            }
+            let (mint, to) = mint.zip(to).ok_or(KisanError::RewardAccountsRequired)?;
+            require_keys_eq!(to.mint, mint.key(), KisanError::RewardAccountsRequired);
+            require!(
+                is_farmer_wallet(&to.owner, &farmer, ctx.accounts.custodial_link.as_deref()),
+                KisanError::RewardAccountsRequired
+            );
+            mint_tokens(
+                &ctx.accounts.token_program,
+                mint,
+                to,
+                &ctx.accounts.program_state,
+                amount,
+            )?;
+        }
+        ctx.accounts.circuit_config.total_claims += 1;
+
+        // ── Step 4b: Pay the referral bonus on the first verified mint ──
+        let mut referral_paid = 0;
+        let (first_claim, referred_by) = ctx
+            .accounts
+            .farmer_profile
+            .as_ref()
+            .map_or((false, Pubkey::default()), |p| (p.total_proofs == 0, p.referred_by));
+        if first_claim && referred_by != Pubkey::default() {
+            let referral = ctx
+                .accounts
+                .referral_code
+                .as_mut()
+                .ok_or(KisanError::ReferralAccountsRequired)?;
+            require_keys_eq!(referral.key(), referred_by, KisanError::ReferralAccountsRequired);
+            let referrer_account = ctx
+                .accounts
+                .referrer_token_account
+                .as_ref()
+                .ok_or(KisanError::ReferralAccountsRequired)?;
+            require_keys_eq!(
+                referrer_account.owner,
+                referral.referrer,
+                KisanError::ReferralAccountsRequired
+            );
+
+            let bonus = ctx.accounts.program_state.referral_bonus;
+            if bonus > 0 && ctx.accounts.program_state.referral_budget_remaining >= bonus {
+                mint_tokens(
+                    &ctx.accounts.token_program,
+                    &ctx.accounts.green_mint,
+                    referrer_account,
+                    &ctx.accounts.program_state,
+                    bonus,
+                )?;
+                referral.bonuses_paid += bonus;
+                ctx.accounts.program_state.referral_budget_remaining -= bonus;
+                referral_paid = bonus;
+                verbose!("Referral bonus paid to {}", referral.referrer);
+            } else {
+                verbose!("Referral budget exhausted; no bonus paid");
+            }
+        }
+
+        // ── Step 4b': Release the escrowed onboarding bonus ──
+        let (onboarding_bonus, onboarding_deadline) = ctx
+            .accounts
+            .farmer_profile
+            .as_ref()
+            .map_or((0, 0), |p| (p.onboarding_bonus, p.onboarding_deadline));
+        if onboarding_bonus > 0 && now <= onboarding_deadline {
+            let vault = ctx
+                .accounts
+                .onboarding_vault
+                .as_ref()
+                .ok_or(KisanError::OnboardingAccountsRequired)?;
+            let state_bump = ctx.accounts.program_state.bump;
+            let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[state_bump]]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: vault.to_account_info(),
+                        to: ctx.accounts.farmer_token_account.to_account_info(),
+                        authority: ctx.accounts.program_state.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                onboarding_bonus,
+            )?;
+            if let Some(profile) = ctx.accounts.farmer_profile.as_mut() {
+                profile.onboarding_bonus = 0;
+            }
+            verbose!("Onboarding bonus released: {}", onboarding_bonus);
+        }
+
+        // ── Step 4c: Mint the compressed-NFT issuance receipt ──
+        if ctx.accounts.program_state.issuance_receipts {
+            let config = ctx
+                .accounts
+                .receipt_config
+                .as_mut()
+                .ok_or(KisanError::ReceiptAccountsRequired)?;
+            let (tree_config, merkle_tree, log_wrapper, compression_program, bubblegum_program) =
+                match (
+                    ctx.accounts.receipt_tree_config.as_ref(),
+                    ctx.accounts.receipt_merkle_tree.as_ref(),
+                    ctx.accounts.log_wrapper.as_ref(),
+                    ctx.accounts.compression_program.as_ref(),
+                    ctx.accounts.bubblegum_program.as_ref(),
+                ) {
+                    (Some(a), Some(b), Some(c), Some(d), Some(e)) => (a, b, c, d, e),
+                    _ => return err!(KisanError::ReceiptAccountsRequired),
+                };
+            let authority = ctx
+                .accounts
+                .receipt_authority
+                .as_ref()
+                .ok_or(KisanError::ReceiptAccountsRequired)?;
+
+            let mut uri = String::with_capacity(config.uri_prefix.len() + 69);
+            uri.push_str(&config.uri_prefix);
+            push_hex(&mut uri, &compliance_commitment);
+            uri.push_str(".json");
+            let metadata = bubblegum::MetadataArgs::simple(
+                "Kisan-DePIN Claim".to_string(),
+                "KCLAIM".to_string(),
+                uri,
+            );
+            let signer_seeds: &[&[&[u8]]] =
+                &[&[RECEIPT_AUTHORITY_SEED, &[config.authority_bump]]];
+
+            bubblegum::mint_v1(
+                bubblegum::MintV1Accounts {
+                    tree_config: &tree_config.to_account_info(),
+                    leaf_owner: &ctx.accounts.farmer.to_account_info(),
+                    merkle_tree: &merkle_tree.to_account_info(),
+                    payer: &ctx.accounts.payer.to_account_info(),
+                    tree_delegate: &authority.to_account_info(),
+                    log_wrapper: &log_wrapper.to_account_info(),
+                    compression_program: &compression_program.to_account_info(),
+                    system_program: &ctx.accounts.system_program.to_account_info(),
+                    bubblegum_program: &bubblegum_program.to_account_info(),
                 },
+                &metadata,
                 signer_seeds,
-            ),
-            MINT_AMOUNT,
-        )?;
+            )?;
+            config.receipts_minted += 1;
+        }
 
         // ── Step 5: Update global state ──
+        if let Some(profile) = ctx.accounts.farmer_profile.as_mut() {
+            let (claim_index, bump) = ctx
+                .accounts
+                .claim_index
+                .as_mut()
+                .zip(ctx.bumps.claim_index)
+                .ok_or(KisanError::ProfileAccountsRequired)?;
+            claim_index.record(profile, compliance_commitment, bump);
+
+            let mut leaderboard = ctx.accounts.leaderboard.load_mut()?;
+            profile.total_proofs += 1;
+            profile.last_claim_at = now;
+            if profile.leaderboard_epoch != leaderboard.epoch {
+                profile.leaderboard_epoch = leaderboard.epoch;
+                profile.epoch_proofs = 0;
+            }
+            profile.epoch_proofs += 1;
+            leaderboard.record(farmer, profile.epoch_proofs);
+        }
+
+        memo::attach(
+            ctx.accounts.memo_program.as_deref(),
+            &memo::ClaimMemo {
+                circuit_id: ctx.accounts.circuit_config.circuit_id,
+                season: season_index,
+                region_code,
+            },
+        )?;
+
+        let state = &mut ctx.accounts.program_state;
         state.total_proofs_verified += 1;
-        state.total_tokens_minted += MINT_AMOUNT;
+        state.total_tokens_minted += reward + referral_paid;
 
-        msg!("═══════════════════════════════════════════");
-        msg!("  ✅ $GREEN Token Minted Successfully!");
-        msg!("  Farmer: {}", ctx.accounts.farmer.key());
-        msg!("  Amount: 1.000000000 $GREEN");
-        msg!("  Total proofs verified: {}", state.total_proofs_verified);
-        msg!("═══════════════════════════════════════════");
+        emit!(ProofMinted {
+            farmer,
+            claimant,
+            payer,
+            commitment: compliance_commitment,
+            circuit_id: ctx.accounts.circuit_config.circuit_id,
+            season: season_index,
+            streak,
+            amount: reward,
+            referral_bonus: referral_paid,
+            serial_start: ctx.accounts.proof_record.serial_start,
+            serial_end: ctx.accounts.proof_record.serial_end,
+            claim_id: ctx.accounts.proof_record.claim_id,
+        });
+
+        verbose!("═══════════════════════════════════════════");
+        verbose!("  ✅ $GREEN Token Minted Successfully!");
+        verbose!("  Farmer: {}", farmer);
+        verbose!("  Amount: {}.{:09} $GREEN", reward / GREEN_UNIT, reward % GREEN_UNIT);
+        verbose!("  Total proofs verified: {}", state.total_proofs_verified);
+        verbose!("═══════════════════════════════════════════");
 
         Ok(())
     }
-}
 
-// ─────────────────────────────────────────────────────────────
-// Account Structures
-// ─────────────────────────────────────────────────────────────
+    /// Grant a field agent power-of-attorney to submit claims for the
+    /// signing farmer. To change the terms, revoke and grant again.
+    ///
+    /// # Arguments
+    /// * `delegate` — Wallet allowed to sign `verify_and_mint` for the farmer
+    /// * `expiry` — Unix timestamp after which the delegation is void
+    /// * `max_claims` — Maximum number of claims the delegate may submit
+    pub fn grant_delegate(
+        ctx: Context<GrantDelegate>,
+        delegate: Pubkey,
+        expiry: i64,
+        max_claims: u32,
+    ) -> Result<()> {
+        require!(
+            delegate != ctx.accounts.farmer.key(),
+            KisanError::InvalidDelegate
+        );
+        require!(
+            expiry > Clock::get()?.unix_timestamp,
+            KisanError::DelegationExpired
+        );
+        require!(max_claims > 0, KisanError::DelegationExhausted);
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    #[account(mut)]
-    pub authority: Signer<'info>,
+        let delegation = &mut ctx.accounts.delegation;
+        delegation.farmer = ctx.accounts.farmer.key();
+        delegation.delegate = delegate;
+        delegation.expiry = expiry;
+        delegation.max_claims = max_claims;
+        delegation.bump = ctx.bumps.delegation;
 
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + ProgramState::INIT_SPACE,
-        seeds = [STATE_SEED],
-        bump,
-    )]
-    pub program_state: Account<'info, ProgramState>,
+        msg!("Delegation granted to {} until {}", delegate, expiry);
 
-    #[account(
-        init,
-        payer = authority,
-        mint::decimals = GREEN_TOKEN_DECIMALS,
-        mint::authority = program_state,
-        seeds = [MINT_SEED],
-        bump,
-    )]
-    pub green_mint: Account<'info, Mint>,
+        Ok(())
+    }
 
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-    pub rent: Sysvar<'info, Rent>,
-}
+    /// Revoke a delegation. The farmer can do this at any time; the
+    /// account is closed and its rent returned to the farmer.
+    pub fn revoke_delegate(ctx: Context<RevokeDelegate>) -> Result<()> {
+        msg!("Delegation revoked for {}", ctx.accounts.delegation.delegate);
+        Ok(())
+    }
 
-#[derive(Accounts)]
-#[instruction(proof_a: [u8; 64], proof_b: [u8; 128], proof_c: [u8; 64], public_signals: Vec<u8>, compliance_commitment: [u8; 32])]
-pub struct VerifyAndMint<'info> {
-    #[account(mut)]
-    pub farmer: Signer<'info>,
+    /// Pre-authorize a short-lived ephemeral key held by the mobile app.
+    /// The key can only be used for the instructions in `scope` and lapses
+    /// after at most `MAX_SESSION_DURATION` seconds.
+    ///
+    /// # Arguments
+    /// * `ephemeral_pubkey` — Public key generated on the device
+    /// * `expiry` — Unix timestamp after which the session is void
+    /// * `scope` — Bitmask of permitted instructions (`SESSION_SCOPE_*`)
+    pub fn create_session_key(
+        ctx: Context<CreateSessionKey>,
+        ephemeral_pubkey: Pubkey,
+        expiry: i64,
+        scope: u8,
+    ) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            ephemeral_pubkey != ctx.accounts.farmer.key(),
+            KisanError::InvalidDelegate
+        );
+        require!(expiry > now, KisanError::SessionExpired);
+        require!(
+            expiry - now <= MAX_SESSION_DURATION,
+            KisanError::SessionTooLong
+        );
+        require!(
+            scope != 0 && scope & !SESSION_SCOPE_ALL == 0,
+            KisanError::SessionScopeViolation
+        );
 
-    #[account(
-        mut,
-        seeds = [STATE_SEED],
-        bump = program_state.bump,
-    )]
-    pub program_state: Account<'info, ProgramState>,
+        let session = &mut ctx.accounts.session_key;
+        session.farmer = ctx.accounts.farmer.key();
+        session.ephemeral_key = ephemeral_pubkey;
+        session.expiry = expiry;
+        session.scope = scope;
+        session.bump = ctx.bumps.session_key;
 
-    #[account(
-        mut,
-        seeds = [MINT_SEED],
-        bump = program_state.mint_bump,
-    )]
-    pub green_mint: Account<'info, Mint>,
+        msg!("Session key {} valid until {}", ephemeral_pubkey, expiry);
 
-    /// The farmer's $GREEN token account (ATA)
-    #[account(
-        mut,
-        token::mint = green_mint,
-        token::authority = farmer,
-    )]
-    pub farmer_token_account: Account<'info, TokenAccount>,
+        Ok(())
+    }
 
-    /// PDA derived from commitment — ensures each proof is used only once
-    #[account(
-        init,
-        payer = farmer,
-        space = 8 + ProofRecord::INIT_SPACE,
-        seeds = [b"proof", compliance_commitment.as_ref()],
-        bump,
-    )]
-    pub proof_record: Account<'info, ProofRecord>,
+    /// Revoke a session key early, e.g. when the phone is lost.
+    /// Rent is returned to the farmer.
+    pub fn revoke_session_key(ctx: Context<RevokeSessionKey>) -> Result<()> {
+        msg!("Session key revoked: {}", ctx.accounts.session_key.ephemeral_key);
+        Ok(())
+    }
 
-    pub token_program: Program<'info, Token>,
-    pub system_program: Program<'info, System>,
-}
+    /// Register a wallet-as-a-service program whose PDAs hold farmers'
+    /// funds. Its wallet for a farmer is the PDA of `program` over
+    /// `[seed_prefix, farmer]`; farmers opt in with `link_custodial_wallet`.
+    ///
+    /// # Arguments
+    /// * `program` — The custodial wallet program
+    /// * `seed_prefix` — Leading seed of its wallet PDAs, at most 32 bytes
+    pub fn register_custodian(
+        ctx: Context<RegisterCustodian>,
+        program: Pubkey,
+        seed_prefix: Vec<u8>,
+    ) -> Result<()> {
+        if seed_prefix.len() > MAX_CUSTODIAN_PREFIX_LEN {
+            return Err(report::exceeded(
+                KisanError::InvalidCustodian,
+                seed_prefix.len(),
+                MAX_CUSTODIAN_PREFIX_LEN,
+            ));
+        }
 
-// ─────────────────────────────────────────────────────────────
-// State Accounts
-// ─────────────────────────────────────────────────────────────
+        let custodian = &mut ctx.accounts.custodian;
+        custodian.program = program;
+        custodian.seed_prefix = seed_prefix;
+        custodian.registered_at = Clock::get()?.unix_timestamp;
+        custodian.bump = ctx.bumps.custodian;
 
-#[account]
-#[derive(InitSpace)]
-pub struct ProgramState {
-    pub authority: Pubkey,            // 32
-    pub mint: Pubkey,                 // 32
-    pub total_proofs_verified: u64,   // 8
-    pub total_tokens_minted: u64,     // 8
-    pub bump: u8,                     // 1
-    pub mint_bump: u8,                // 1
-}
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RegisterCustodian,
+            program,
+            0,
+            0,
+        )?;
 
-#[account]
-#[derive(InitSpace)]
-pub struct ProofRecord {
-    pub commitment: [u8; 32],         // 32 — unique proof commitment
-    pub farmer: Pubkey,               // 32 — farmer wallet
-    pub timestamp: i64,               // 8  — verification timestamp
-    pub verified: bool,               // 1  — always true (only stored if valid)
-}
+        msg!("Custodian registered: {}", program);
 
-// ─────────────────────────────────────────────────────────────
-// Error Codes
-// ─────────────────────────────────────────────────────────────
+        Ok(())
+    }
 
-#[error_code]
-pub enum KisanError {
-    #[msg("Invalid ZK-SNARK proof: proof components must be non-zero")]
-    InvalidProof,
+    /// Remove a custodian. No new wallets can be linked to it; farmers
+    /// already linked keep receiving into it until they unlink.
+    pub fn remove_custodian(ctx: Context<RemoveCustodian>) -> Result<()> {
+        let program = ctx.accounts.custodian.program;
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RemoveCustodian,
+            program,
+            0,
+            0,
+        )?;
+        msg!("Custodian removed: {}", program);
+        Ok(())
+    }
 
-    #[msg("Invalid public signals: signals array must not be empty")]
-    InvalidPublicSignals,
+    /// Let mints to the farmer land in token accounts owned by their
+    /// wallet at a registered custodian, instead of only ones the farmer's
+    /// key owns. The farmer signs, so nobody else can redirect their
+    /// rewards; one custodial wallet per farmer.
+    pub fn link_custodial_wallet(ctx: Context<LinkCustodialWallet>) -> Result<()> {
+        let farmer = ctx.accounts.farmer.key();
+        let custodian = &ctx.accounts.custodian;
+        let wallet = custodian.wallet_of(&farmer);
 
-    #[msg("Invalid commitment: commitment hash must be non-zero")]
-    InvalidCommitment,
+        let link = &mut ctx.accounts.custodial_link;
+        link.farmer = farmer;
+        link.custodian = custodian.program;
+        link.wallet = wallet;
+        link.linked_at = Clock::get()?.unix_timestamp;
+        link.bump = ctx.bumps.custodial_link;
 
-    #[msg("Proof already used: this compliance commitment has been verified before")]
-    ProofAlreadyUsed,
+        msg!("Farmer {} linked custodial wallet {}", farmer, wallet);
+
+        Ok(())
+    }
+
+    /// Stop accepting the custodial wallet. Rent returns to the farmer.
+    pub fn unlink_custodial_wallet(ctx: Context<UnlinkCustodialWallet>) -> Result<()> {
+        msg!("Custodial wallet unlinked: {}", ctx.accounts.custodial_link.wallet);
+        Ok(())
+    }
+
+    /// Optional first half of a commit-reveal claim: record the slot at
+    /// which the farmer held a claim, without revealing its commitment.
+    /// Passing the pre-commit to `verify_and_mint` later reveals it and
+    /// emits `PreCommitRevealed` with both slots, so who proved first can
+    /// be settled by pre-commit order whatever order a relayer lands the
+    /// claims in.
+    ///
+    /// # Arguments
+    /// * `hash` — `PreCommit::hash_of(commitment, farmer)`
+    pub fn pre_commit(ctx: Context<CreatePreCommit>, hash: [u8; 32]) -> Result<()> {
+        let clock = Clock::get()?;
+        let pre_commit = &mut ctx.accounts.pre_commit;
+        pre_commit.farmer = ctx.accounts.farmer.key();
+        pre_commit.hash = hash;
+        pre_commit.committed_slot = clock.slot;
+        pre_commit.committed_at = clock.unix_timestamp;
+        pre_commit.bump = ctx.bumps.pre_commit;
+
+        msg!("Pre-commit {} at slot {}", pre_commit.key(), clock.slot);
+
+        Ok(())
+    }
+
+    /// Close a pre-commit, revealed or abandoned, returning its rent to
+    /// the farmer. Revealed ones stay on chain until closed as evidence
+    /// of the commit order.
+    pub fn close_pre_commit(ctx: Context<ClosePreCommit>) -> Result<()> {
+        msg!("Pre-commit closed: {}", ctx.accounts.pre_commit.key());
+        Ok(())
+    }
+
+    /// Add a relayer to the allowlist. Relayers pay rent and fees for
+    /// farmers' claims and are limited to `max_claims_per_window` claims
+    /// every `window_seconds`.
+    ///
+    /// # Arguments
+    /// * `stake_per_claim` — Lamports the relayer bonds per claim of
+    ///   quota, so the quota is `stake / stake_per_claim` up to
+    ///   `max_claims_per_window`; 0 keeps the flat limit with no stake
+    pub fn register_relayer(
+        ctx: Context<RegisterRelayer>,
+        relayer: Pubkey,
+        max_claims_per_window: u32,
+        window_seconds: i64,
+        stake_per_claim: u64,
+    ) -> Result<()> {
+        require!(
+            max_claims_per_window > 0 && window_seconds > 0,
+            KisanError::InvalidRateLimit
+        );
+
+        let info = &mut ctx.accounts.relayer_info;
+        info.relayer = relayer;
+        info.max_claims_per_window = max_claims_per_window;
+        info.window_seconds = window_seconds;
+        info.window_start = Clock::get()?.unix_timestamp;
+        info.stake_per_claim = stake_per_claim;
+        info.bump = ctx.bumps.relayer_info;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RegisterRelayer,
+            relayer,
+            0,
+            max_claims_per_window as u64,
+        )?;
+
+        msg!("Relayer registered: {}", relayer);
+
+        Ok(())
+    }
+
+    /// Remove a relayer from the allowlist. Rent returns to the authority.
+    /// A relayer with stake bonded leaves through unbonding instead.
+    pub fn remove_relayer(ctx: Context<RemoveRelayer>) -> Result<()> {
+        let info = &ctx.accounts.relayer_info;
+        require!(info.stake == 0, KisanError::RelayerStakeOutstanding);
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RemoveRelayer,
+            info.relayer,
+            info.max_claims_per_window as u64,
+            0,
+        )?;
+        msg!("Relayer removed: {}", info.relayer);
+        Ok(())
+    }
+
+    /// Bond SOL stake to a relayer account, raising its quota. The stake
+    /// is held in the relayer PDA.
+    pub fn stake_relayer(ctx: Context<StakeRelayer>, amount: u64) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+        require!(
+            ctx.accounts.relayer_info.unbonding_at == 0,
+            KisanError::RelayerUnbonding
+        );
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.relayer.to_account_info(),
+                    to: ctx.accounts.relayer_info.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let info = &mut ctx.accounts.relayer_info;
+        info.stake += amount;
+        msg!(
+            "Relayer {} staked: {} lamports, quota {}",
+            info.relayer,
+            info.stake,
+            info.quota()
+        );
+
+        Ok(())
+    }
+
+    /// Slash a relayer that dropped a claim. A relayer that accepts a
+    /// farmer's signed claim hands back a receipt: its Ed25519 signature
+    /// over `program_id | commitment | deadline (i64 LE)`. If the deadline
+    /// passes with no proof record for the commitment, anyone holding the
+    /// receipt can slash `RELAYER_SLASH_BPS` of the relayer's stake to
+    /// themselves, once per receipt. The transaction must carry the
+    /// Ed25519 precompile instruction directly before this one.
+    ///
+    /// Relayers should simulate a claim before signing for it. A duplicate
+    /// submission needs no slash: it fails on the existing proof record,
+    /// counts against nothing and only costs the relayer its fee.
+    ///
+    /// # Arguments
+    /// * `commitment` — Commitment of the dropped claim
+    /// * `deadline` — Time by which the relayer promised to land it
+    /// * `relayer_sig` — The relayer's Ed25519 signature on the receipt
+    pub fn slash_relayer(
+        ctx: Context<SlashRelayer>,
+        commitment: [u8; 32],
+        deadline: i64,
+        relayer_sig: [u8; 64],
+    ) -> Result<()> {
+        let signed = ed25519::load_preceding_signed_message(&ctx.accounts.instructions_sysvar)?;
+        let relayer = ctx.accounts.relayer_info.relayer;
+        let mut message = crate::ID.to_bytes().to_vec();
+        message.extend_from_slice(&commitment);
+        message.extend_from_slice(&deadline.to_le_bytes());
+        require!(
+            signed.signer == relayer
+                && signed.signature == relayer_sig
+                && signed.message == message,
+            KisanError::InvalidEd25519Signature
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        require_ctx!(
+            now > deadline,
+            KisanError::RelayDeadlinePending,
+            "Receipt deadline {}, now {}",
+            deadline,
+            now
+        );
+        require_ctx!(
+            now <= deadline + RELAY_REPORT_WINDOW,
+            KisanError::RelayReportExpired,
+            "Receipt deadline {}, reportable until {}",
+            deadline,
+            deadline + RELAY_REPORT_WINDOW
+        );
+        require!(
+            ctx.accounts.proof_record.data_is_empty(),
+            KisanError::RelayLanded
+        );
+
+        let info = &mut ctx.accounts.relayer_info;
+        let amount =
+            (info.stake as u128 * RELAYER_SLASH_BPS as u128 / BPS_DENOMINATOR as u128) as u64;
+        require!(amount > 0, KisanError::InvalidAmount);
+        info.stake -= amount;
+        info.total_slashed += amount;
+        **info.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.reporter.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        let slash = &mut ctx.accounts.relay_slash;
+        slash.relayer = relayer;
+        slash.commitment = commitment;
+        slash.reporter = ctx.accounts.reporter.key();
+        slash.amount = amount;
+        slash.slashed_at = now;
+        slash.bump = ctx.bumps.relay_slash;
+
+        emit!(RelayerSlashed {
+            relayer,
+            commitment,
+            reporter: slash.reporter,
+            amount,
+            stake_left: info.stake,
+        });
+
+        Ok(())
+    }
+
+    /// Stop relaying and start the unbonding period. The quota drops to
+    /// zero at once; the stake stays slashable until withdrawn.
+    pub fn begin_relayer_unbonding(ctx: Context<BeginRelayerUnbonding>) -> Result<()> {
+        let info = &mut ctx.accounts.relayer_info;
+        require!(info.unbonding_at == 0, KisanError::RelayerUnbonding);
+        info.unbonding_at = Clock::get()?.unix_timestamp;
+
+        msg!("Relayer {} unbonding", info.relayer);
+
+        Ok(())
+    }
+
+    /// Withdraw the remaining stake once unbonding is complete. Closes the
+    /// relayer account; relaying again requires the authority.
+    pub fn withdraw_relayer_stake(ctx: Context<WithdrawRelayerStake>) -> Result<()> {
+        let info = &ctx.accounts.relayer_info;
+        require!(
+            info.unbonding_at != 0
+                && Clock::get()?.unix_timestamp >= info.unbonding_at + RELAYER_UNBONDING_PERIOD,
+            KisanError::UnbondingIncomplete
+        );
+
+        msg!("Relayer {} withdrew {} lamports", info.relayer, info.stake);
+
+        Ok(())
+    }
+
+    /// Allow `program` to invoke `verify_and_mint` via CPI. Claims are
+    /// top-level only by default, so a malicious wrapper can't bundle a
+    /// farmer's signed claim with instructions the farmer never saw.
+    ///
+    /// # Arguments
+    /// * `program` — Integrator program; it must be the transaction's
+    ///   top-level instruction when it calls in
+    pub fn register_cpi_integrator(
+        ctx: Context<RegisterCpiIntegrator>,
+        program: Pubkey,
+    ) -> Result<()> {
+        let integrator = &mut ctx.accounts.cpi_integrator;
+        integrator.program = program;
+        integrator.registered_at = Clock::get()?.unix_timestamp;
+        integrator.bump = ctx.bumps.cpi_integrator;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RegisterCpiIntegrator,
+            program,
+            0,
+            1,
+        )?;
+
+        msg!("CPI integrator registered: {}", program);
+
+        Ok(())
+    }
+
+    /// Revoke an integrator's CPI access. Rent returns to the authority.
+    pub fn remove_cpi_integrator(ctx: Context<RemoveCpiIntegrator>) -> Result<()> {
+        let program = ctx.accounts.cpi_integrator.program;
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RemoveCpiIntegrator,
+            program,
+            1,
+            0,
+        )?;
+        msg!("CPI integrator removed: {}", program);
+        Ok(())
+    }
+
+    /// Create a program-owned address lookup table, pre-filled with the
+    /// accounts every claim touches (program, state, $GREEN mint, token
+    /// and system programs). Circuit configs and other hot accounts are
+    /// added with `extend_lookup_table`.
+    ///
+    /// # Arguments
+    /// * `recent_slot` — Recent slot the table address is derived from
+    pub fn create_lookup_table(ctx: Context<CreateLookupTable>, recent_slot: u64) -> Result<()> {
+        let authority_bump = ctx.bumps.lookup_table_authority;
+        let signer_seeds: &[&[&[u8]]] = &[&[LOOKUP_TABLE_AUTHORITY_SEED, &[authority_bump]]];
+        let accounts = || lookup_table::LookupTableAccounts {
+            lookup_table: ctx.accounts.lookup_table.as_ref(),
+            authority: ctx.accounts.lookup_table_authority.as_ref(),
+            payer: ctx.accounts.authority.as_ref(),
+            system_program: ctx.accounts.system_program.as_ref(),
+            lookup_table_program: ctx.accounts.lookup_table_program.as_ref(),
+        };
+
+        let table = lookup_table::create(accounts(), recent_slot, signer_seeds)?;
+        let core = vec![
+            crate::ID,
+            ctx.accounts.program_state.key(),
+            ctx.accounts.program_state.mint,
+            Token::id(),
+            System::id(),
+        ];
+        let count = core.len() as u16;
+        lookup_table::extend(accounts(), core, signer_seeds)?;
+
+        let info = &mut ctx.accounts.lookup_table_info;
+        info.table = table;
+        info.address_count = count;
+        info.created_at = Clock::get()?.unix_timestamp;
+        info.bump = ctx.bumps.lookup_table_info;
+        info.authority_bump = authority_bump;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::CreateLookupTable,
+            table,
+            0,
+            count as u64,
+        )?;
+
+        msg!("Lookup table created: {}", table);
+
+        Ok(())
+    }
+
+    /// Append addresses to a program-owned lookup table.
+    pub fn extend_lookup_table(
+        ctx: Context<ExtendLookupTable>,
+        addresses: Vec<Pubkey>,
+    ) -> Result<()> {
+        let info = &ctx.accounts.lookup_table_info;
+        require!(
+            !addresses.is_empty()
+                && addresses.len() <= lookup_table::MAX_EXTEND_ADDRESSES
+                && info.address_count as usize + addresses.len() <= lookup_table::MAX_ADDRESSES,
+            KisanError::InvalidLookupTable
+        );
+        require!(!info.deactivated, KisanError::InvalidLookupTable);
+
+        let count = addresses.len() as u16;
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[LOOKUP_TABLE_AUTHORITY_SEED, &[info.authority_bump]]];
+        lookup_table::extend(
+            lookup_table::LookupTableAccounts {
+                lookup_table: ctx.accounts.lookup_table.as_ref(),
+                authority: ctx.accounts.lookup_table_authority.as_ref(),
+                payer: ctx.accounts.authority.as_ref(),
+                system_program: ctx.accounts.system_program.as_ref(),
+                lookup_table_program: ctx.accounts.lookup_table_program.as_ref(),
+            },
+            addresses,
+            signer_seeds,
+        )?;
+
+        let info = &mut ctx.accounts.lookup_table_info;
+        info.address_count += count;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ExtendLookupTable,
+            info.table,
+            (info.address_count - count) as u64,
+            info.address_count as u64,
+        )?;
+
+        msg!("Lookup table {}: {} addresses", info.table, info.address_count);
+
+        Ok(())
+    }
+
+    /// Deactivate a lookup table ahead of `close_lookup_table`. Relayers
+    /// should stop referencing it.
+    pub fn deactivate_lookup_table(ctx: Context<DeactivateLookupTable>) -> Result<()> {
+        let info = &mut ctx.accounts.lookup_table_info;
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[LOOKUP_TABLE_AUTHORITY_SEED, &[info.authority_bump]]];
+        lookup_table::deactivate(
+            &ctx.accounts.lookup_table,
+            &ctx.accounts.lookup_table_authority,
+            &ctx.accounts.lookup_table_program,
+            signer_seeds,
+        )?;
+        info.deactivated = true;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::DeactivateLookupTable,
+            info.table,
+            0,
+            1,
+        )?;
+
+        msg!("Lookup table deactivated: {}", info.table);
+
+        Ok(())
+    }
+
+    /// Close a deactivated lookup table once its cooldown has passed,
+    /// returning both rents to the authority.
+    pub fn close_lookup_table(ctx: Context<CloseLookupTable>) -> Result<()> {
+        let info = &ctx.accounts.lookup_table_info;
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[LOOKUP_TABLE_AUTHORITY_SEED, &[info.authority_bump]]];
+        lookup_table::close(
+            &ctx.accounts.lookup_table,
+            &ctx.accounts.lookup_table_authority,
+            &ctx.accounts.authority,
+            &ctx.accounts.lookup_table_program,
+            signer_seeds,
+        )?;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::CloseLookupTable,
+            info.table,
+            info.address_count as u64,
+            0,
+        )?;
+
+        msg!("Lookup table closed: {}", info.table);
+
+        Ok(())
+    }
+
+    /// Upgrade a `ProgramState`, `FarmerProfile`, `ProofRecord` or `Config`
+    /// to the current layout: realloc to the new size (the payer tops up rent),
+    /// backfill fields added since its version and stamp
+    /// `ACCOUNT_VERSION`. Permissionless, since it changes no balances or
+    /// ownership; the account type is read from its discriminator.
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        let target = ctx.accounts.target.to_account_info();
+        let payer = ctx.accounts.payer.to_account_info();
+        let system_program = ctx.accounts.system_program.to_account_info();
+
+        let discriminator: [u8; 8] = target
+            .try_borrow_data()?
+            .get(..8)
+            .and_then(|d| d.try_into().ok())
+            .ok_or(KisanError::UnknownAccountType)?;
+
+        let from = if discriminator == ProgramState::DISCRIMINATOR {
+            migrate::<ProgramState>(&target, &payer, &system_program)?
+        } else if discriminator == FarmerProfile::DISCRIMINATOR {
+            migrate::<FarmerProfile>(&target, &payer, &system_program)?
+        } else if discriminator == ProofRecord::DISCRIMINATOR {
+            migrate::<ProofRecord>(&target, &payer, &system_program)?
+        } else if discriminator == Config::DISCRIMINATOR {
+            migrate::<Config>(&target, &payer, &system_program)?
+        } else {
+            return err!(KisanError::UnknownAccountType);
+        };
+
+        msg!(
+            "Account {} migrated: v{} -> v{}",
+            target.key(),
+            from.max(1),
+            ACCOUNT_VERSION
+        );
+
+        Ok(())
+    }
+
+    /// Create the admin log. Every authority instruction after this one
+    /// appends an entry to it, so it must exist before any other
+    /// configuration is done.
+    pub fn initialize_admin_log(ctx: Context<InitializeAdminLog>) -> Result<()> {
+        let mut log = ctx.accounts.admin_log.load_init()?;
+        log.bump = ctx.bumps.admin_log;
+        log.push(AdminLogEntry {
+            authority: ctx.accounts.authority.key(),
+            target: ctx.accounts.admin_log.key(),
+            old_value: 0,
+            new_value: 0,
+            timestamp: Clock::get()?.unix_timestamp,
+            action: AdminAction::InitializeAdminLog as u16,
+            _padding: [0; 6],
+        });
+
+        msg!("Admin log: {}", ctx.accounts.admin_log.key());
+
+        Ok(())
+    }
+
+    /// Create the feature-flag account with every feature disabled, so
+    /// new subsystems deploy dark until enabled per cluster.
+    pub fn initialize_feature_flags(ctx: Context<InitializeFeatureFlags>) -> Result<()> {
+        let flags = &mut ctx.accounts.feature_flags;
+        flags.updated_at = Clock::get()?.unix_timestamp;
+        flags.bump = ctx.bumps.feature_flags;
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeFeatureFlags,
+            flags.key(),
+            0,
+            0,
+        )?;
+        msg!("Feature flags: {}", flags.key());
+        Ok(())
+    }
+
+    /// Devnet only: wipe the program back to its just-initialized state
+    /// without redeploying. Every account passed as a writable remaining
+    /// account must be owned by this program; each is closed with its rent
+    /// refunded to the authority. `ProgramState` and the admin log are kept
+    /// (the log records the reset) and the state's counters and season are
+    /// zeroed, so setup (`initialize_seasons`, circuits, regions, ...) can
+    /// be re-run. Token balances are untouched.
+    ///
+    /// Programs cannot read the cluster's genesis hash, so the state
+    /// records at `initialize` whether the program was built with the
+    /// `devnet` feature, and both that flag and the running build must
+    /// agree: a mainnet deployment initialized from a release build stays
+    /// locked even if a devnet artifact is later deployed over it.
+    /// `kisan devnet-reset` also refuses to run against mainnet-beta.
+    pub fn reset_state<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ResetState<'info>>,
+    ) -> Result<()> {
+        require_devnet(&ctx.accounts.program_state)?;
+        let authority = ctx.accounts.authority.to_account_info();
+        let protected = [ctx.accounts.program_state.key(), ctx.accounts.admin_log.key()];
+
+        for info in ctx.remaining_accounts {
+            require!(info.owner == &crate::ID, KisanError::ProtectedAccount);
+            require!(!protected.contains(info.key), KisanError::ProtectedAccount);
+            require!(info.is_writable, KisanError::ProtectedAccount);
+
+            let lamports = info.lamports();
+            **authority.try_borrow_mut_lamports()? += lamports;
+            **info.try_borrow_mut_lamports()? = 0;
+            info.assign(&anchor_lang::system_program::ID);
+            info.realloc(0, false)?;
+        }
+
+        let state = &mut ctx.accounts.program_state;
+        let old_proofs = state.total_proofs_verified;
+        state.total_proofs_verified = 0;
+        state.total_tokens_minted = 0;
+        state.total_burned = 0;
+        state.total_offtake_delivered = 0;
+        state.next_serial = 0;
+        state.last_claim_id = 0;
+        state.current_season = 0;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ResetState,
+            state.key(),
+            old_proofs,
+            ctx.remaining_accounts.len() as u64,
+        )?;
+
+        msg!("State reset: {} accounts closed", ctx.remaining_accounts.len());
+
+        Ok(())
+    }
+
+    /// Devnet only: mint `amount` $GREEN to any $GREEN token account, for
+    /// demo wallets that have not claimed yet. Counted in
+    /// `total_tokens_minted` so supply still reconciles with the state.
+    ///
+    /// # Arguments
+    /// * `amount` — Base units, at most `DEVNET_AIRDROP_MAX`
+    pub fn airdrop_green(ctx: Context<AirdropGreen>, amount: u64) -> Result<()> {
+        require_devnet(&ctx.accounts.program_state)?;
+        require!(amount > 0 && amount <= DEVNET_AIRDROP_MAX, KisanError::AirdropTooLarge);
+
+        mint_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.green_mint,
+            &ctx.accounts.recipient_token_account,
+            &ctx.accounts.program_state,
+            amount,
+        )?;
+
+        let state = &mut ctx.accounts.program_state;
+        state.total_tokens_minted += amount;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::AirdropGreen,
+            ctx.accounts.recipient_token_account.key(),
+            0,
+            amount,
+        )?;
+
+        msg!(
+            "Airdropped {} $GREEN to {}",
+            amount,
+            ctx.accounts.recipient_token_account.key()
+        );
+
+        Ok(())
+    }
+
+    /// Turn one feature on or off.
+    ///
+    /// # Arguments
+    /// * `feature` — A single `FEATURE_*` bit
+    /// * `enabled` — New state of the feature
+    pub fn set_feature(ctx: Context<SetFeature>, feature: u64, enabled: bool) -> Result<()> {
+        require!(
+            feature.count_ones() == 1 && feature & KNOWN_FEATURES == feature,
+            KisanError::UnknownFeature
+        );
+
+        let old_flags = ctx.accounts.feature_flags.flags;
+        let flags = &mut ctx.accounts.feature_flags;
+        if enabled {
+            flags.flags |= feature;
+        } else {
+            flags.flags &= !feature;
+        }
+        flags.updated_at = Clock::get()?.unix_timestamp;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetFeature,
+            flags.key(),
+            old_flags,
+            flags.flags,
+        )?;
+
+        msg!(
+            "Feature {:#x} {}",
+            feature,
+            if enabled { "enabled" } else { "disabled" }
+        );
+
+        Ok(())
+    }
+
+    /// Create the config account, carrying over the season parameters and
+    /// streak curve that earlier layouts kept in `ProgramState`. From here
+    /// on those settings are read from and written to `Config` only.
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        let state = &ctx.accounts.program_state;
+        let config = &mut ctx.accounts.config;
+        config.season_duration = state.legacy_season_duration;
+        config.season_reward_amount = state.legacy_season_reward_amount;
+        config.season_mint_cap = state.legacy_season_mint_cap;
+        config.season_max_claims_per_farmer = state.legacy_season_max_claims_per_farmer;
+        config.streak_curve = state.legacy_streak_curve;
+        config.emission_curve = EmissionCurve::FIXED;
+        config.updated_at = Clock::get()?.unix_timestamp;
+        config.bump = ctx.bumps.config;
+        config.version = ACCOUNT_VERSION;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeConfig,
+            config.key(),
+            0,
+            config.season_reward_amount,
+        )?;
+
+        msg!("Config: {}", config.key());
+
+        Ok(())
+    }
+
+    /// Set the lifetime cap on $GREEN minted by `distribute`. It can't be
+    /// set below what has already been distributed.
+    ///
+    /// # Arguments
+    /// * `budget` — Base units
+    pub fn set_incentives_budget(ctx: Context<SetIncentivesBudget>, budget: u64) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        require!(
+            budget >= config.incentives_distributed,
+            KisanError::IncentivesBudgetExceeded
+        );
+        let old_budget = config.incentives_budget;
+        config.incentives_budget = budget;
+        config.updated_at = Clock::get()?.unix_timestamp;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetIncentivesBudget,
+            config.key(),
+            old_budget,
+            budget,
+        )?;
+
+        msg!(
+            "Incentives budget: {} ({} distributed)",
+            budget,
+            config.incentives_distributed
+        );
+
+        Ok(())
+    }
+
+    /// Mint pilot incentives (e.g. onboarding bonuses) to many wallets in
+    /// one instruction. `remaining_accounts` are the recipients' $GREEN
+    /// token accounts, writable and in the order of `recipients`. The
+    /// batch fails as a whole if it would take `incentives_distributed`
+    /// past `incentives_budget`.
+    ///
+    /// # Arguments
+    /// * `recipients` — Wallet and amount (base units) per token account
+    pub fn distribute<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Distribute<'info>>,
+        recipients: Vec<Distribution>,
+    ) -> Result<()> {
+        require!(
+            !recipients.is_empty() && recipients.len() == ctx.remaining_accounts.len(),
+            KisanError::DistributionMismatch
+        );
+        let total = recipients
+            .iter()
+            .try_fold(0u64, |sum, r| sum.checked_add(r.amount))
+            .ok_or(KisanError::IncentivesBudgetExceeded)?;
+        let config = &ctx.accounts.config;
+        let distributed = config
+            .incentives_distributed
+            .checked_add(total)
+            .filter(|&d| d <= config.incentives_budget)
+            .ok_or(KisanError::IncentivesBudgetExceeded)?;
+
+        let mint = ctx.accounts.green_mint.key();
+        for (recipient, info) in recipients.iter().zip(ctx.remaining_accounts) {
+            let token_account = Account::<TokenAccount>::try_from(info)?;
+            require!(
+                token_account.mint == mint && token_account.owner == recipient.wallet,
+                KisanError::DistributionMismatch
+            );
+            mint_tokens(
+                &ctx.accounts.token_program,
+                &ctx.accounts.green_mint,
+                &token_account,
+                &ctx.accounts.program_state,
+                recipient.amount,
+            )?;
+            emit!(IncentivePaid {
+                wallet: recipient.wallet,
+                token_account: info.key(),
+                amount: recipient.amount,
+            });
+        }
+
+        ctx.accounts.program_state.total_tokens_minted += total;
+        let config = &mut ctx.accounts.config;
+        let old_distributed = config.incentives_distributed;
+        config.incentives_distributed = distributed;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::Distribute,
+            config.key(),
+            old_distributed,
+            distributed,
+        )?;
+
+        msg!(
+            "Distributed {} $GREEN to {} wallets ({} of {} budget used)",
+            total,
+            recipients.len(),
+            distributed,
+            config.incentives_budget
+        );
+
+        Ok(())
+    }
+
+    /// Create the token account that holds escrowed onboarding bonuses.
+    pub fn initialize_onboarding_vault(ctx: Context<InitializeOnboardingVault>) -> Result<()> {
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeOnboardingVault,
+            ctx.accounts.onboarding_vault.key(),
+            0,
+            0,
+        )?;
+        msg!("Onboarding vault: {}", ctx.accounts.onboarding_vault.key());
+        Ok(())
+    }
+
+    /// Set the bonus escrowed for each new farmer and how long after
+    /// registration their first `verify_and_mint` has to release it.
+    /// Farmers already registered keep the terms they registered under.
+    ///
+    /// # Arguments
+    /// * `amount` — Base units; 0 turns the bonus off
+    /// * `window` — Seconds from registration to the deadline
+    pub fn set_onboarding_bonus(
+        ctx: Context<SetOnboardingBonus>,
+        amount: u64,
+        window: i64,
+    ) -> Result<()> {
+        require!(amount == 0 || window > 0, KisanError::InvalidOnboardingBonus);
+
+        let config = &mut ctx.accounts.config;
+        let old_amount = config.onboarding_bonus;
+        config.onboarding_bonus = amount;
+        config.onboarding_window = window;
+        config.updated_at = Clock::get()?.unix_timestamp;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetOnboardingBonus,
+            config.key(),
+            old_amount,
+            amount,
+        )?;
+
+        msg!("Onboarding bonus: {} within {}s of registration", amount, window);
+
+        Ok(())
+    }
+
+    /// Permissionless crank: burn a farmer's escrowed onboarding bonus once
+    /// its deadline has passed without a verified claim releasing it.
+    pub fn reclaim_onboarding_bonus(ctx: Context<ReclaimOnboardingBonus>) -> Result<()> {
+        let profile = &ctx.accounts.farmer_profile;
+        let amount = profile.onboarding_bonus;
+        require!(amount > 0, KisanError::NoOnboardingBonus);
+        require!(
+            Clock::get()?.unix_timestamp > profile.onboarding_deadline,
+            KisanError::OnboardingBonusNotExpired
+        );
+
+        let state_bump = ctx.accounts.program_state.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[state_bump]]];
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.green_mint.to_account_info(),
+                    from: ctx.accounts.onboarding_vault.to_account_info(),
+                    authority: ctx.accounts.program_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        ctx.accounts.farmer_profile.onboarding_bonus = 0;
+        ctx.accounts.program_state.total_burned += amount;
+
+        msg!(
+            "Onboarding bonus of {} reclaimed from {}",
+            amount,
+            ctx.accounts.farmer_profile.farmer
+        );
+
+        Ok(())
+    }
+
+    /// Halt or resume farmer claims. While paused `verify_and_mint`,
+    /// `verify_imagery_and_mint`, `redeem_aggregate_leaf` and
+    /// `issue_credits` fail with `ProgramPaused`; everything else,
+    /// admin instructions included, keeps working.
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        let was_paused = config.paused;
+        config.paused = paused;
+        config.updated_at = Clock::get()?.unix_timestamp;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetPaused,
+            config.key(),
+            was_paused as u64,
+            paused as u64,
+        )?;
+
+        msg!("Claims {}", if paused { "paused" } else { "resumed" });
+
+        Ok(())
+    }
+
+    /// Create the rent sponsorship pool. The pool is a program-owned PDA
+    /// whose lamports (above its own rent-exempt minimum) pay proof-record
+    /// rent on behalf of farmers.
+    pub fn initialize_rent_pool(ctx: Context<InitializeRentPool>) -> Result<()> {
+        ctx.accounts.rent_pool.bump = ctx.bumps.rent_pool;
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeRentPool,
+            ctx.accounts.rent_pool.key(),
+            0,
+            0,
+        )?;
+        msg!("Rent pool: {}", ctx.accounts.rent_pool.key());
+        Ok(())
+    }
+
+    /// Deposit SOL into the rent sponsorship pool. Anyone may fund it;
+    /// in practice this is the treasury.
+    pub fn fund_rent_pool(ctx: Context<FundRentPool>, amount: u64) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.funder.to_account_info(),
+                    to: ctx.accounts.rent_pool.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        ctx.accounts.rent_pool.total_deposited += amount;
+        msg!("Rent pool funded: {} lamports", amount);
+
+        Ok(())
+    }
+
+    /// Close a proof record once it is past the retention period and
+    /// return its rent to whoever paid for it — the rent pool for
+    /// sponsored claims, otherwise the original payer.
+    ///
+    /// Closing a record drops on-chain replay protection for its
+    /// commitment, so this is restricted to the authority, should only be
+    /// used for claims archived off-chain, and is refused while the
+    /// record's season could still take the commitment again (see
+    /// `ProofRecord::ensure_closable`).
+    pub fn close_proof_record(ctx: Context<CloseProofRecord>) -> Result<()> {
+        let proof_record = &ctx.accounts.proof_record;
+        proof_record.ensure_closable(&ctx.accounts.program_state, Clock::get()?.unix_timestamp)?;
+
+        let rent = proof_record.to_account_info().lamports();
+        let destination = proof_rent_destination(
+            proof_record,
+            rent,
+            ctx.accounts.rent_pool.as_mut(),
+            ctx.accounts.rent_recipient.as_ref(),
+        )?;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::CloseProofRecord,
+            proof_record.key(),
+            rent,
+            0,
+        )?;
+
+        msg!("Proof record closed, {} lamports to {}", rent, destination.key());
+        ctx.accounts.proof_record.close(destination)
+    }
+
+    /// Create the concurrent merkle tree holding compressed proof records
+    /// and farmer profiles. The tree account must be pre-allocated by the
+    /// client; its authority is a program PDA.
+    pub fn initialize_state_tree(
+        ctx: Context<InitializeStateTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        let authority_bump = ctx.bumps.tree_authority;
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_TREE_AUTHORITY_SEED, &[authority_bump]]];
+
+        compression::init_empty_merkle_tree(
+            compression::TreeAccounts {
+                merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+                authority: &ctx.accounts.tree_authority.to_account_info(),
+                noop: &ctx.accounts.noop_program.to_account_info(),
+                compression_program: &ctx.accounts.compression_program.to_account_info(),
+            },
+            max_depth,
+            max_buffer_size,
+            signer_seeds,
+        )?;
+
+        let config = &mut ctx.accounts.state_tree_config;
+        config.merkle_tree = ctx.accounts.merkle_tree.key();
+        config.bump = ctx.bumps.state_tree_config;
+        config.authority_bump = authority_bump;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeStateTree,
+            config.merkle_tree,
+            0,
+            max_depth as u64,
+        )?;
+
+        msg!("State tree: {}", config.merkle_tree);
+
+        Ok(())
+    }
+
+    /// Like `close_proof_record`, but the record is first appended to the
+    /// state tree, so it stays provable (`get_compressed_proof_status`)
+    /// after its rent is returned.
+    pub fn compress_proof_record(ctx: Context<CompressProofRecord>) -> Result<()> {
+        let proof_record = &ctx.accounts.proof_record;
+        proof_record.ensure_closable(&ctx.accounts.program_state, Clock::get()?.unix_timestamp)?;
+
+        let leaf_index = append_compressed(
+            &mut ctx.accounts.state_tree_config,
+            &ctx.accounts.tree_authority,
+            &ctx.accounts.merkle_tree,
+            &ctx.accounts.noop_program,
+            &ctx.accounts.compression_program,
+            PROOF_RECORD_LEAF_TAG,
+            &***proof_record,
+        )?;
+
+        let rent = proof_record.to_account_info().lamports();
+        let destination = proof_rent_destination(
+            proof_record,
+            rent,
+            ctx.accounts.rent_pool.as_mut(),
+            ctx.accounts.rent_recipient.as_ref(),
+        )?;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::CompressProofRecord,
+            proof_record.key(),
+            0,
+            leaf_index,
+        )?;
+
+        msg!(
+            "Proof record compressed to leaf {}, {} lamports to {}",
+            leaf_index,
+            rent,
+            destination.key()
+        );
+        ctx.accounts.proof_record.close(destination)
+    }
+
+    /// Compress the signing farmer's profile into the state tree and
+    /// return its rent to them. Only dormant profiles (no claim for
+    /// `PROFILE_DORMANCY_PERIOD`) qualify; `decompress_farmer_profile`
+    /// restores it before the farmer claims again.
+    pub fn compress_farmer_profile(ctx: Context<CompressFarmerProfile>) -> Result<()> {
+        let profile = &ctx.accounts.farmer_profile;
+        require!(
+            Clock::get()?.unix_timestamp
+                >= profile.last_claim_at.max(profile.registered_at) + PROFILE_DORMANCY_PERIOD,
+            KisanError::ProfileNotDormant
+        );
+
+        let leaf_index = append_compressed(
+            &mut ctx.accounts.state_tree_config,
+            &ctx.accounts.tree_authority,
+            &ctx.accounts.merkle_tree,
+            &ctx.accounts.noop_program,
+            &ctx.accounts.compression_program,
+            FARMER_PROFILE_LEAF_TAG,
+            &***profile,
+        )?;
+
+        msg!("Farmer profile {} compressed to leaf {}", profile.farmer, leaf_index);
+
+        Ok(())
+    }
+
+    /// Restore a compressed farmer profile. The leaf is proven against
+    /// `root` (proof nodes as remaining accounts) and replaced with an
+    /// empty leaf, so a profile can be decompressed once.
+    ///
+    /// # Arguments
+    /// * `profile` — The profile as it was compressed (logged via Noop)
+    /// * `root` — A recent root of the state tree
+    /// * `leaf_index` — Index the profile was appended at
+    pub fn decompress_farmer_profile<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DecompressFarmerProfile<'info>>,
+        profile: FarmerProfile,
+        root: [u8; 32],
+        leaf_index: u32,
+    ) -> Result<()> {
+        require_keys_eq!(
+            profile.farmer,
+            ctx.accounts.farmer.key(),
+            KisanError::InvalidCompressedRecord
+        );
+        require!(
+            profile.bump == ctx.bumps.farmer_profile,
+            KisanError::InvalidCompressedRecord
+        );
+
+        let config = &ctx.accounts.state_tree_config;
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_TREE_AUTHORITY_SEED, &[config.authority_bump]]];
+        compression::replace_leaf(
+            compression::TreeAccounts {
+                merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+                authority: &ctx.accounts.tree_authority.to_account_info(),
+                noop: &ctx.accounts.noop_program.to_account_info(),
+                compression_program: &ctx.accounts.compression_program.to_account_info(),
+            },
+            root,
+            compression::leaf_hash(FARMER_PROFILE_LEAF_TAG, &profile)?,
+            compression::EMPTY_LEAF,
+            leaf_index,
+            ctx.remaining_accounts,
+            signer_seeds,
+        )?;
+
+        ctx.accounts.farmer_profile.set_inner(profile);
+
+        msg!(
+            "Farmer profile {} decompressed from leaf {}",
+            ctx.accounts.farmer.key(),
+            leaf_index
+        );
+
+        Ok(())
+    }
+
+    /// Whitelist an attester (KYC partner or field operator) allowed to
+    /// vouch for farmer identities.
+    pub fn register_attester(ctx: Context<RegisterAttester>, attester: Pubkey) -> Result<()> {
+        let info = &mut ctx.accounts.attester_info;
+        info.attester = attester;
+        info.registered_at = Clock::get()?.unix_timestamp;
+        info.bump = ctx.bumps.attester_info;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RegisterAttester,
+            attester,
+            0,
+            0,
+        )?;
+
+        msg!("Attester registered: {}", attester);
+
+        Ok(())
+    }
+
+    /// Remove an attester from the whitelist. Rent returns to the authority.
+    pub fn remove_attester(ctx: Context<RemoveAttester>) -> Result<()> {
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RemoveAttester,
+            ctx.accounts.attester_info.attester,
+            0,
+            0,
+        )?;
+        msg!("Attester removed: {}", ctx.accounts.attester_info.attester);
+        Ok(())
+    }
+
+    /// Create the program-owned token account that holds escrowed $GREEN
+    /// for farmers who don't have a wallet yet.
+    pub fn initialize_escrow_vault(ctx: Context<InitializeEscrowVault>) -> Result<()> {
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeEscrowVault,
+            ctx.accounts.escrow_vault.key(),
+            0,
+            0,
+        )?;
+        msg!("Escrow vault: {}", ctx.accounts.escrow_vault.key());
+        Ok(())
+    }
+
+    /// Register a wallet-less farmer, identified by a salted hash of their
+    /// phone number, so verified mints can accumulate in escrow.
+    pub fn register_escrow_identity(
+        ctx: Context<RegisterEscrowIdentity>,
+        identity_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(
+            identity_hash.iter().any(|&b| b != 0),
+            KisanError::InvalidIdentityHash
+        );
+
+        let escrow = &mut ctx.accounts.escrow_account;
+        escrow.identity_hash = identity_hash;
+        escrow.registered_by = ctx.accounts.attester.key();
+        escrow.bump = ctx.bumps.escrow_account;
+
+        msg!("Escrow identity registered by {}", ctx.accounts.attester.key());
+
+        Ok(())
+    }
+
+    /// Verify a ZK-SNARK proof on behalf of a wallet-less farmer and mint
+    /// the season reward into the program escrow, credited to their
+    /// identity. Must be submitted by a whitelisted attester, with a
+    /// Groth16 proof for `circuit_config` whose first public input is the
+    /// commitment.
+    pub fn verify_and_escrow(
+        ctx: Context<VerifyAndEscrow>,
+        payload: ProofPayload,
+        compliance_commitment: [u8; 32],
+        identity_hash: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.proof_record.ensure_unused()?;
+        let circuit = &ctx.accounts.circuit_config;
+        require!(circuit.active, KisanError::CircuitInactive);
+        let proof = payload.decode(ProofSystem::Groth16)?;
+        require!(
+            payload.public_signals.get(..32) == Some(&compliance_commitment[..]),
+            KisanError::InvalidPublicSignals
+        );
+        verify_circuit_proof(
+            circuit,
+            Some(&*ctx.accounts.groth16_vk),
+            ctx.accounts.previous_groth16_vk.as_deref(),
+            None,
+            None,
+            None,
+            &[],
+            &proof,
+            &payload.public_signals,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let base_reward = ctx.accounts.season.reward_amount;
+        let reward = ctx.accounts.season.record_claim(now, base_reward, 0)?;
+
+        let escrow_key = ctx.accounts.escrow_account.key();
+        let proof_record = &mut ctx.accounts.proof_record;
+        proof_record.commitment = compliance_commitment;
+        proof_record.farmer = escrow_key;
+        proof_record.timestamp = now;
+        proof_record.verified = true;
+        proof_record.rent_payer = ctx.accounts.attester.key();
+        proof_record.bump = ctx.bumps.proof_record;
+        proof_record.version = ACCOUNT_VERSION;
+        proof_record.season = ctx.accounts.season.index;
+        proof_record.vintage = vintage_year(now);
+        proof_record.methodology_id = 0;
+        (proof_record.serial_start, proof_record.serial_end) =
+            ctx.accounts.program_state.allocate_serials(reward);
+        proof_record.claim_id = ctx.accounts.program_state.next_claim_id();
+
+        mint_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.green_mint,
+            &ctx.accounts.escrow_vault,
+            &ctx.accounts.program_state,
+            reward,
+        )?;
+
+        let escrow = &mut ctx.accounts.escrow_account;
+        escrow.balance += reward;
+        escrow.total_escrowed += reward;
+
+        let state = &mut ctx.accounts.program_state;
+        state.total_proofs_verified += 1;
+        state.total_tokens_minted += reward;
+
+        msg!(
+            "Escrowed {} for identity {:?} as claim #{}",
+            reward,
+            &identity_hash[..8],
+            ctx.accounts.proof_record.claim_id
+        );
+
+        Ok(())
+    }
+
+    /// Release an identity's escrowed balance to the farmer's new wallet.
+    /// Both the farmer and the attester who registered the identity must
+    /// sign; the attester's signature attests that the wallet belongs to
+    /// the escrowed identity. The first release binds that wallet, and
+    /// later balances only go to it.
+    pub fn claim_escrow(ctx: Context<ClaimEscrow>) -> Result<()> {
+        let escrow = &ctx.accounts.escrow_account;
+        let amount = escrow.balance;
+        require!(amount > 0, KisanError::EmptyEscrow);
+        require!(
+            escrow.claimed_wallet == Pubkey::default()
+                || escrow.claimed_wallet == ctx.accounts.farmer.key(),
+            KisanError::EscrowWalletMismatch
+        );
+
+        let state_bump = ctx.accounts.program_state.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[state_bump]]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.escrow_vault.to_account_info(),
+                    to: ctx.accounts.farmer_token_account.to_account_info(),
+                    authority: ctx.accounts.program_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        let escrow = &mut ctx.accounts.escrow_account;
+        escrow.balance = 0;
+        escrow.claimed_wallet = ctx.accounts.farmer.key();
+
+        msg!("Escrow released: {} to {}", amount, ctx.accounts.farmer.key());
+
+        Ok(())
+    }
+
+    /// Create or update a region's onboarding policy. Farmers can only
+    /// register in regions that have been configured.
+    ///
+    /// # Arguments
+    /// * `region_code` — Numeric region identifier (e.g. state/district code)
+    /// * `require_identity` — Whether claims need a government-ID attestation
+    pub fn configure_region(
+        ctx: Context<ConfigureRegion>,
+        region_code: u16,
+        require_identity: bool,
+    ) -> Result<()> {
+        let was_required = ctx.accounts.region_config.require_identity;
+        let region = &mut ctx.accounts.region_config;
+        region.region_code = region_code;
+        region.require_identity = require_identity;
+        region.bump = ctx.bumps.region_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigureRegion,
+            region.key(),
+            was_required as u64,
+            require_identity as u64,
+        )?;
+
+        msg!("Region {} configured (identity required: {})", region_code, require_identity);
+
+        Ok(())
+    }
+
+    /// Create a farmer profile in a configured region. The `payer` may be a
+    /// sponsor so farmers can onboard without SOL.
+    ///
+    /// When proof-of-personhood is required, the configured verifier must
+    /// co-sign and `personhood_nullifier` (the provider's per-human unique
+    /// identifier) is claimed in a PDA, so a second profile for the same
+    /// human fails. Otherwise the nullifier is ignored.
+    pub fn register_farmer(
+        ctx: Context<RegisterFarmer>,
+        region_code: u16,
+        personhood_nullifier: [u8; 32],
+    ) -> Result<()> {
+        let state = &ctx.accounts.program_state;
+        if state.require_personhood {
+            let verifier = ctx
+                .accounts
+                .personhood_verifier
+                .as_ref()
+                .ok_or(KisanError::PersonhoodRequired)?;
+            require_keys_eq!(
+                verifier.key(),
+                state.personhood_verifier,
+                KisanError::InvalidPersonhoodVerifier
+            );
+            require!(
+                personhood_nullifier.iter().any(|&b| b != 0),
+                KisanError::PersonhoodRequired
+            );
+
+            let record = ctx
+                .accounts
+                .personhood_record
+                .as_mut()
+                .ok_or(KisanError::PersonhoodRequired)?;
+            record.nullifier = personhood_nullifier;
+            record.farmer = ctx.accounts.farmer.key();
+            record.verifier = verifier.key();
+            record.bump = ctx.bumps.personhood_record.ok_or(KisanError::PersonhoodRequired)?;
+
+            ctx.accounts.farmer_profile.personhood_record = record.key();
+        }
+
+        if let Some(referral) = ctx.accounts.referral_code.as_mut() {
+            require_keys_neq!(
+                referral.referrer,
+                ctx.accounts.farmer.key(),
+                KisanError::SelfReferral
+            );
+            referral.referrals += 1;
+            ctx.accounts.farmer_profile.referred_by = referral.key();
+        }
+
+        let profile = &mut ctx.accounts.farmer_profile;
+        profile.farmer = ctx.accounts.farmer.key();
+        profile.region_code = region_code;
+        profile.registered_at = Clock::get()?.unix_timestamp;
+        profile.bump = ctx.bumps.farmer_profile;
+        profile.version = ACCOUNT_VERSION;
+
+        // Escrow the onboarding bonus until the first verified claim
+        let bonus = ctx.accounts.config.onboarding_bonus;
+        if bonus > 0 {
+            let (green_mint, vault, token_program) = match (
+                ctx.accounts.green_mint.as_ref(),
+                ctx.accounts.onboarding_vault.as_ref(),
+                ctx.accounts.token_program.as_ref(),
+            ) {
+                (Some(a), Some(b), Some(c)) => (a, b, c),
+                _ => return err!(KisanError::OnboardingAccountsRequired),
+            };
+            mint_tokens(token_program, green_mint, vault, &ctx.accounts.program_state, bonus)?;
+            ctx.accounts.program_state.total_tokens_minted += bonus;
+            profile.onboarding_bonus = bonus;
+            profile.onboarding_deadline =
+                profile.registered_at.saturating_add(ctx.accounts.config.onboarding_window);
+        }
+
+        msg!("Farmer registered: {} (region {})", profile.farmer, region_code);
+
+        Ok(())
+    }
+
+    /// Move a farmer to another configured region and/or replace their
+    /// contact hash. The farmer signs the transaction and a whitelisted
+    /// attester (the field partner who checked the move) countersigns
+    /// off-chain: an Ed25519 precompile instruction directly before this
+    /// one must check the attester's signature over
+    /// `program_id | farmer | profile_updates (u32 LE) | new_region (u16 LE)
+    /// | new_contact_hash`. The update counter is part of the message, so a
+    /// countersignature can't be replayed to hop back.
+    ///
+    /// # Arguments
+    /// * `new_region` — Region code to move to (may be the current one)
+    /// * `new_contact_hash` — Salted hash of the farmer's contact details
+    pub fn update_farmer_profile(
+        ctx: Context<UpdateFarmerProfile>,
+        new_region: u16,
+        new_contact_hash: [u8; 32],
+    ) -> Result<()> {
+        let signed = ed25519::load_preceding_signed_message(&ctx.accounts.instructions_sysvar)?;
+        let attester = ctx.accounts.attester_info.attester;
+        let profile = &mut ctx.accounts.farmer_profile;
+        let mut message = crate::ID.to_bytes().to_vec();
+        message.extend_from_slice(profile.farmer.as_ref());
+        message.extend_from_slice(&profile.profile_updates.to_le_bytes());
+        message.extend_from_slice(&new_region.to_le_bytes());
+        message.extend_from_slice(&new_contact_hash);
+        require!(
+            signed.signer == attester && signed.message == message,
+            KisanError::InvalidEd25519Signature
+        );
+
+        let old_region = profile.region_code;
+        profile.region_code = new_region;
+        profile.contact_hash = new_contact_hash;
+        profile.profile_updates += 1;
+
+        emit!(FarmerProfileUpdated {
+            farmer: profile.farmer,
+            attester,
+            old_region,
+            new_region,
+            contact_hash: new_contact_hash,
+            update: profile.profile_updates,
+        });
+
+        msg!(
+            "Farmer {} moved from region {} to {} (attested by {})",
+            profile.farmer,
+            old_region,
+            new_region,
+            attester
+        );
+
+        Ok(())
+    }
+
+    /// Flag a farmer's wallet as compromised (e.g. a stolen phone) on the
+    /// farmer's report to a whitelisted attester. Takes effect at once:
+    /// every claim, badge, parcel, loan or profile update for the wallet
+    /// fails with `WalletCompromised` until the profile is migrated.
+    ///
+    /// # Arguments
+    /// * `wallet` — The farmer wallet to flag
+    pub fn flag_compromised(ctx: Context<FlagCompromised>, wallet: Pubkey) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        ctx.accounts.farmer_profile.compromised_at = now;
+
+        emit!(FarmerWalletCompromised {
+            wallet,
+            attester: ctx.accounts.attester.key(),
+            timestamp: now,
+        });
+
+        msg!("Farmer wallet {} flagged compromised by {}", wallet, ctx.accounts.attester.key());
+
+        Ok(())
+    }
+
+    /// Move a compromised farmer's profile to a new wallet, which signs and
+    /// pays for it, with a whitelisted attester co-signing. The new profile
+    /// keeps the region, attestations, streaks, badges and proof counts;
+    /// the identity, personhood and PM-KISAN records it points to are
+    /// rebound to the new wallet, and unclaimed marketplace royalties move
+    /// with it (the old royalty account is locked). The old profile stays
+    /// behind, flagged, as a tombstone pointing at the new wallet, so the
+    /// old wallet can neither claim nor register again.
+    ///
+    /// # Arguments
+    /// * `old` — The compromised wallet
+    pub fn migrate_profile(ctx: Context<MigrateProfile>, old: Pubkey) -> Result<()> {
+        let new_wallet = ctx.accounts.new_wallet.key();
+        let old_profile = &mut ctx.accounts.old_profile;
+
+        let mut moved = FarmerProfile::clone(old_profile);
+        moved.farmer = new_wallet;
+        moved.bump = ctx.bumps.new_profile;
+        moved.compromised_at = 0;
+        moved.migrated_to = Pubkey::default();
+        moved.version = ACCOUNT_VERSION;
+        old_profile.migrated_to = new_wallet;
+
+        if old_profile.identity_attestation != Pubkey::default() {
+            ctx.accounts
+                .identity_attestation
+                .as_mut()
+                .ok_or(KisanError::IdentityAttestationRequired)?
+                .farmer = new_wallet;
+        }
+        if old_profile.personhood_record != Pubkey::default() {
+            ctx.accounts
+                .personhood_record
+                .as_mut()
+                .ok_or(KisanError::PersonhoodRequired)?
+                .farmer = new_wallet;
+        }
+        if old_profile.pm_kisan_attestation != Pubkey::default() {
+            ctx.accounts
+                .pm_kisan_attestation
+                .as_mut()
+                .ok_or(KisanError::PmKisanAttestationRequired)?
+                .farmer = new_wallet;
+        }
+
+        let mut royalties = 0;
+        if let Some(old_royalty) = ctx.accounts.old_royalty.as_mut() {
+            let new_royalty = ctx
+                .accounts
+                .new_royalty
+                .as_mut()
+                .ok_or(KisanError::RoyaltyAccountRequired)?;
+            royalties = old_royalty.unclaimed;
+            new_royalty.farmer = new_wallet;
+            new_royalty.unclaimed += royalties;
+            new_royalty.total_earned += royalties;
+            new_royalty.bump = ctx.bumps.new_royalty.ok_or(KisanError::RoyaltyAccountRequired)?;
+            // Rebinding locks the old account against the old wallet
+            old_royalty.unclaimed = 0;
+            old_royalty.farmer = new_wallet;
+        }
+
+        emit!(FarmerProfileMigrated {
+            old,
+            new: new_wallet,
+            attester: ctx.accounts.attester.key(),
+            total_proofs: moved.total_proofs,
+            streak: moved.streak,
+            royalties,
+        });
+        ctx.accounts.new_profile.set_inner(moved);
+
+        msg!("Farmer profile {} migrated to {}", old, new_wallet);
+
+        Ok(())
+    }
+
+    /// Record a whitelisted attester's (KYC partner's) attestation that a
+    /// farmer holds the government ID behind `id_hash`, a salted hash of
+    /// e.g. an Aadhaar number. The PDA is keyed by the hash, so one ID can
+    /// back only one farmer profile.
+    pub fn attest_identity(ctx: Context<AttestIdentity>, id_hash: [u8; 32]) -> Result<()> {
+        require!(
+            id_hash.iter().any(|&b| b != 0),
+            KisanError::InvalidIdentityHash
+        );
+        require!(
+            ctx.accounts.farmer_profile.identity_attestation == Pubkey::default(),
+            KisanError::IdentityAlreadyAttested
+        );
+
+        let attestation = &mut ctx.accounts.identity_attestation;
+        attestation.id_hash = id_hash;
+        attestation.farmer = ctx.accounts.farmer_profile.farmer;
+        attestation.attester = ctx.accounts.attester.key();
+        attestation.attested_at = Clock::get()?.unix_timestamp;
+        attestation.bump = ctx.bumps.identity_attestation;
+
+        ctx.accounts.farmer_profile.identity_attestation = attestation.key();
+
+        msg!("Identity attested for farmer {}", attestation.farmer);
+
+        Ok(())
+    }
+
+    /// Toggle the proof-of-personhood requirement for new farmer profiles
+    /// and set the provider's verifier key (e.g. a Civic or WorldID-style
+    /// verification service).
+    pub fn set_personhood_requirement(
+        ctx: Context<SetPersonhoodRequirement>,
+        required: bool,
+        verifier: Pubkey,
+    ) -> Result<()> {
+        require!(
+            !required || verifier != Pubkey::default(),
+            KisanError::InvalidPersonhoodVerifier
+        );
+
+        let was_required = ctx.accounts.program_state.require_personhood;
+        let state = &mut ctx.accounts.program_state;
+        state.require_personhood = required;
+        state.personhood_verifier = verifier;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetPersonhoodRequirement,
+            verifier,
+            was_required as u64,
+            required as u64,
+        )?;
+
+        msg!("Proof-of-personhood required: {} (verifier {})", required, verifier);
+
+        Ok(())
+    }
+
+    /// Toggle the PM-KISAN beneficiary requirement for claims and set the
+    /// government-integration attester allowed to vouch for beneficiaries.
+    pub fn set_pm_kisan_requirement(
+        ctx: Context<SetPmKisanRequirement>,
+        required: bool,
+        attester: Pubkey,
+    ) -> Result<()> {
+        require!(
+            !required || attester != Pubkey::default(),
+            KisanError::InvalidPmKisanAttester
+        );
+
+        let was_required = ctx.accounts.program_state.require_pm_kisan;
+        let state = &mut ctx.accounts.program_state;
+        state.require_pm_kisan = required;
+        state.pm_kisan_attester = attester;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetPmKisanRequirement,
+            attester,
+            was_required as u64,
+            required as u64,
+        )?;
+
+        msg!("PM-KISAN beneficiary required: {} (attester {})", required, attester);
+
+        Ok(())
+    }
+
+    /// Record the government-integration attester's confirmation that the
+    /// identity behind a farmer's ID attestation is a registered PM-KISAN
+    /// beneficiary. Keyed by the ID hash, like the identity attestation.
+    pub fn attest_pm_kisan_beneficiary(ctx: Context<AttestPmKisanBeneficiary>) -> Result<()> {
+        require_keys_eq!(
+            ctx.accounts.attester.key(),
+            ctx.accounts.program_state.pm_kisan_attester,
+            KisanError::InvalidPmKisanAttester
+        );
+        let identity = &ctx.accounts.identity_attestation;
+        require!(!identity.revoked, KisanError::IdentityAttestationRevoked);
+
+        let attestation = &mut ctx.accounts.pm_kisan_attestation;
+        attestation.id_hash = identity.id_hash;
+        attestation.farmer = identity.farmer;
+        attestation.attester = ctx.accounts.attester.key();
+        attestation.attested_at = Clock::get()?.unix_timestamp;
+        attestation.bump = ctx.bumps.pm_kisan_attestation;
+
+        ctx.accounts.farmer_profile.pm_kisan_attestation = attestation.key();
+
+        msg!("PM-KISAN beneficiary attested for farmer {}", attestation.farmer);
+
+        Ok(())
+    }
+
+    /// Revoke a PM-KISAN attestation, e.g. when the farmer leaves the
+    /// scheme. Only the attester that issued it can revoke it.
+    pub fn revoke_pm_kisan_attestation(ctx: Context<RevokePmKisanAttestation>) -> Result<()> {
+        ctx.accounts.pm_kisan_attestation.revoked = true;
+        msg!(
+            "PM-KISAN attestation revoked for {}",
+            ctx.accounts.pm_kisan_attestation.farmer
+        );
+        Ok(())
+    }
+
+    /// Create a referral code owned by the signer. Farmers who register
+    /// with it earn the referrer a bonus on their first verified mint.
+    ///
+    /// # Arguments
+    /// * `code` — Short human-shareable code, e.g. `b"RAMESH01"`
+    pub fn create_referral_code(ctx: Context<CreateReferralCode>, code: [u8; 8]) -> Result<()> {
+        require!(code.iter().any(|&b| b != 0), KisanError::InvalidReferralCode);
+
+        let referral = &mut ctx.accounts.referral_code;
+        referral.code = code;
+        referral.referrer = ctx.accounts.referrer.key();
+        referral.bump = ctx.bumps.referral_code;
+
+        msg!("Referral code created by {}", referral.referrer);
+
+        Ok(())
+    }
+
+    /// Set the per-referral bonus and the total budget available for
+    /// referral bonuses. The budget is drawn down as bonuses are paid.
+    pub fn set_referral_budget(
+        ctx: Context<SetReferralBudget>,
+        bonus: u64,
+        budget: u64,
+    ) -> Result<()> {
+        let old_budget = ctx.accounts.program_state.referral_budget_remaining;
+        let state = &mut ctx.accounts.program_state;
+        state.referral_bonus = bonus;
+        state.referral_budget_remaining = budget;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetReferralBudget,
+            state.key(),
+            old_budget,
+            budget,
+        )?;
+
+        msg!("Referral bonus {} with budget {}", bonus, budget);
+
+        Ok(())
+    }
+
+    /// Create the Bubblegum merkle tree that holds milestone badges. The
+    /// tree account must be pre-allocated by the client; the program's
+    /// badge-authority PDA becomes its creator so only this program mints.
+    ///
+    /// # Arguments
+    /// * `max_depth` / `max_buffer_size` — Concurrent merkle tree parameters
+    /// * `uri_prefix` — Metadata URI prefix; badges use `{prefix}{milestone}.json`
+    pub fn initialize_badge_tree(
+        ctx: Context<InitializeBadgeTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+        uri_prefix: String,
+    ) -> Result<()> {
+        require!(
+            uri_prefix.len() <= MAX_BADGE_URI_PREFIX_LEN,
+            KisanError::BadgeUriTooLong
+        );
+
+        let authority_bump = ctx.bumps.badge_authority;
+        let signer_seeds: &[&[&[u8]]] = &[&[BADGE_AUTHORITY_SEED, &[authority_bump]]];
+
+        bubblegum::create_tree(
+            bubblegum::CreateTreeAccounts {
+                tree_config: &ctx.accounts.tree_config.to_account_info(),
+                merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+                payer: &ctx.accounts.authority.to_account_info(),
+                tree_creator: &ctx.accounts.badge_authority.to_account_info(),
+                log_wrapper: &ctx.accounts.log_wrapper.to_account_info(),
+                compression_program: &ctx.accounts.compression_program.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                bubblegum_program: &ctx.accounts.bubblegum_program.to_account_info(),
+            },
+            max_depth,
+            max_buffer_size,
+            signer_seeds,
+        )?;
+
+        let config = &mut ctx.accounts.badge_config;
+        config.merkle_tree = ctx.accounts.merkle_tree.key();
+        config.uri_prefix = uri_prefix;
+        config.bump = ctx.bumps.badge_config;
+        config.authority_bump = authority_bump;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeBadgeTree,
+            config.merkle_tree,
+            0,
+            max_depth as u64,
+        )?;
+
+        msg!("Badge tree: {}", config.merkle_tree);
+
+        Ok(())
+    }
+
+    /// Mint a compressed-NFT badge for a milestone the farmer has reached.
+    /// Pull-based so `verify_and_mint` stays cheap; each badge can be
+    /// claimed once.
+    ///
+    /// # Arguments
+    /// * `milestone` — Index into `BADGE_MILESTONES`
+    pub fn claim_badge(ctx: Context<ClaimBadge>, milestone: u8) -> Result<()> {
+        let threshold = *BADGE_MILESTONES
+            .get(milestone as usize)
+            .ok_or(KisanError::InvalidMilestone)?;
+        let profile = &ctx.accounts.farmer_profile;
+        let bit = 1u8 << milestone;
+        require!(profile.badges_claimed & bit == 0, KisanError::BadgeAlreadyClaimed);
+        require!(profile.total_proofs >= threshold, KisanError::MilestoneNotReached);
+
+        let config = &ctx.accounts.badge_config;
+        let metadata = bubblegum::MetadataArgs::simple(
+            format!("Kisan-DePIN {} Proofs", threshold),
+            "KISAN".to_string(),
+            format!("{}{}.json", config.uri_prefix, threshold),
+        );
+        let signer_seeds: &[&[&[u8]]] = &[&[BADGE_AUTHORITY_SEED, &[config.authority_bump]]];
+
+        bubblegum::mint_v1(
+            bubblegum::MintV1Accounts {
+                tree_config: &ctx.accounts.tree_config.to_account_info(),
+                leaf_owner: &ctx.accounts.farmer.to_account_info(),
+                merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+                payer: &ctx.accounts.payer.to_account_info(),
+                tree_delegate: &ctx.accounts.badge_authority.to_account_info(),
+                log_wrapper: &ctx.accounts.log_wrapper.to_account_info(),
+                compression_program: &ctx.accounts.compression_program.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                bubblegum_program: &ctx.accounts.bubblegum_program.to_account_info(),
+            },
+            &metadata,
+            signer_seeds,
+        )?;
+
+        ctx.accounts.farmer_profile.badges_claimed |= bit;
+        ctx.accounts.badge_config.badges_minted += 1;
+
+        msg!("Milestone badge minted: {} proofs", threshold);
+
+        Ok(())
+    }
+
+    /// Set up the Bubblegum tree for issuance receipts and turn receipts
+    /// on. From then on every `verify_and_mint` mints the farmer a
+    /// compressed NFT whose URI is `uri_prefix` + hex(commitment) + ".json".
+    /// The merkle tree account must be pre-allocated by the client.
+    pub fn initialize_receipt_tree(
+        ctx: Context<InitializeReceiptTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+        uri_prefix: String,
+    ) -> Result<()> {
+        require!(
+            uri_prefix.len() <= MAX_BADGE_URI_PREFIX_LEN,
+            KisanError::BadgeUriTooLong
+        );
+
+        let authority_bump = ctx.bumps.receipt_authority;
+        let signer_seeds: &[&[&[u8]]] = &[&[RECEIPT_AUTHORITY_SEED, &[authority_bump]]];
+
+        bubblegum::create_tree(
+            bubblegum::CreateTreeAccounts {
+                tree_config: &ctx.accounts.tree_config.to_account_info(),
+                merkle_tree: &ctx.accounts.merkle_tree.to_account_info(),
+                payer: &ctx.accounts.authority.to_account_info(),
+                tree_creator: &ctx.accounts.receipt_authority.to_account_info(),
+                log_wrapper: &ctx.accounts.log_wrapper.to_account_info(),
+                compression_program: &ctx.accounts.compression_program.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                bubblegum_program: &ctx.accounts.bubblegum_program.to_account_info(),
+            },
+            max_depth,
+            max_buffer_size,
+            signer_seeds,
+        )?;
+
+        let was_enabled = ctx.accounts.program_state.issuance_receipts;
+        let config = &mut ctx.accounts.receipt_config;
+        config.merkle_tree = ctx.accounts.merkle_tree.key();
+        config.uri_prefix = uri_prefix;
+        config.bump = ctx.bumps.receipt_config;
+        config.authority_bump = authority_bump;
+        ctx.accounts.program_state.issuance_receipts = true;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeReceiptTree,
+            config.merkle_tree,
+            was_enabled as u64,
+            1,
+        )?;
+
+        msg!("Receipt tree: {}", config.merkle_tree);
+
+        Ok(())
+    }
+
+    /// Pause or resume issuance receipts, e.g. while the tree is full and
+    /// a new one is being set up.
+    pub fn set_issuance_receipts(ctx: Context<SetIssuanceReceipts>, enabled: bool) -> Result<()> {
+        let was_enabled = ctx.accounts.program_state.issuance_receipts;
+        ctx.accounts.program_state.issuance_receipts = enabled;
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetIssuanceReceipts,
+            ctx.accounts.program_state.key(),
+            was_enabled as u64,
+            enabled as u64,
+        )?;
+        msg!("Issuance receipts {}", if enabled { "enabled" } else { "disabled" });
+        Ok(())
+    }
+
+    /// Create the leaderboard. Rankings cover one period of
+    /// `period_seconds`, after which anyone can crank `reset_leaderboard`.
+    pub fn initialize_leaderboard(
+        ctx: Context<InitializeLeaderboard>,
+        period_seconds: i64,
+    ) -> Result<()> {
+        require!(period_seconds > 0, KisanError::InvalidLeaderboardPeriod);
+
+        let mut leaderboard = ctx.accounts.leaderboard.load_init()?;
+        leaderboard.reset_at = Clock::get()?.unix_timestamp;
+        leaderboard.period_seconds = period_seconds;
+        leaderboard.bump = ctx.bumps.leaderboard;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeLeaderboard,
+            ctx.accounts.leaderboard.key(),
+            0,
+            period_seconds as u64,
+        )?;
+
+        msg!("Leaderboard: {}", ctx.accounts.leaderboard.key());
+
+        Ok(())
+    }
+
+    /// Create the per-region and per-circuit mint statistics. Must exist
+    /// before the next farmer claim; earlier mints are only in the
+    /// `ProgramState` totals.
+    pub fn initialize_mint_stats(ctx: Context<InitializeMintStats>) -> Result<()> {
+        ctx.accounts.mint_stats.load_init()?.bump = ctx.bumps.mint_stats;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeMintStats,
+            ctx.accounts.mint_stats.key(),
+            0,
+            0,
+        )?;
+
+        msg!("Mint stats: {}", ctx.accounts.mint_stats.key());
+
+        Ok(())
+    }
+
+    /// Permissionless crank: clear the rankings once the current period
+    /// has ended. Farmers' per-period counts reset lazily on their next
+    /// claim because the epoch number changes.
+    pub fn reset_leaderboard(ctx: Context<ResetLeaderboard>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let mut leaderboard = ctx.accounts.leaderboard.load_mut()?;
+        require!(
+            now >= leaderboard.reset_at + leaderboard.period_seconds,
+            KisanError::LeaderboardPeriodActive
+        );
+
+        leaderboard.reset(now);
+
+        msg!("Leaderboard reset, epoch {}", leaderboard.epoch);
+
+        Ok(())
+    }
+
+    /// Open season 0 and set the parameters applied to every season that
+    /// follows (until changed with `set_season_config`).
+    ///
+    /// # Arguments
+    /// * `duration` — Season length in seconds
+    /// * `reward_amount` — $GREEN (base units) minted per verified claim
+    /// * `mint_cap` — Maximum $GREEN minted through claims in one season
+    /// * `max_claims_per_farmer` — Claims allowed per farmer per season
+    pub fn initialize_seasons(
+        ctx: Context<InitializeSeasons>,
+        duration: i64,
+        reward_amount: u64,
+        mint_cap: u64,
+        max_claims_per_farmer: u32,
+    ) -> Result<()> {
+        let config = &mut ctx.accounts.config;
+        config.set_season_config(duration, reward_amount, mint_cap, max_claims_per_farmer)?;
+        ctx.accounts.program_state.current_season = 0;
+
+        let now = Clock::get()?.unix_timestamp;
+        ctx.accounts
+            .season
+            .open(0, now, config, ctx.bumps.season);
+        ctx.accounts.season_tally.open(0, ctx.bumps.season_tally);
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeSeasons,
+            ctx.accounts.season.key(),
+            0,
+            reward_amount,
+        )?;
+
+        msg!("Season 0 open until {}", ctx.accounts.season.end);
+
+        Ok(())
+    }
+
+    /// Change the parameters used for the next season. The running season
+    /// keeps the configuration it was opened with.
+    pub fn set_season_config(
+        ctx: Context<SetSeasonConfig>,
+        duration: i64,
+        reward_amount: u64,
+        mint_cap: u64,
+        max_claims_per_farmer: u32,
+    ) -> Result<()> {
+        let old_reward = ctx.accounts.config.season_reward_amount;
+        ctx.accounts.config.set_season_config(
+            duration,
+            reward_amount,
+            mint_cap,
+            max_claims_per_farmer,
+        )?;
+        ctx.accounts.config.updated_at = Clock::get()?.unix_timestamp;
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetSeasonConfig,
+            ctx.accounts.config.key(),
+            old_reward,
+            reward_amount,
+        )?;
+        msg!("Season config updated (reward {}, cap {})", reward_amount, mint_cap);
+        Ok(())
+    }
+
+    /// Set the curve that decays the season reward over seasons. Each
+    /// season's reward is fixed when it opens, from the configured base
+    /// reward and the season's index, so the running season is unaffected.
+    ///
+    /// # Arguments
+    /// * `curve` — Curve kind and its parameters; see `emissions.rs`
+    pub fn set_emission_curve(ctx: Context<SetSeasonConfig>, curve: EmissionCurve) -> Result<()> {
+        require!(curve.is_valid(), KisanError::InvalidEmissionCurve);
+        let config = &mut ctx.accounts.config;
+        let old_kind = config.emission_curve.kind as u64;
+        config.emission_curve = curve;
+        config.updated_at = Clock::get()?.unix_timestamp;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetEmissionCurve,
+            config.key(),
+            old_kind,
+            curve.kind as u64,
+        )?;
+
+        msg!("Emission curve: {:?}", curve);
+
+        Ok(())
+    }
+
+    /// Create a companion reward mint ($WATER or $SOIL), controlled by the
+    /// program-state PDA like $GREEN. $GREEN itself is created by
+    /// `initialize`.
+    pub fn initialize_mint(ctx: Context<InitializeMint>, kind: MintKind) -> Result<()> {
+        let state = &mut ctx.accounts.program_state;
+        let bump = ctx.bumps.mint;
+        let slot = match kind {
+            MintKind::Green => return err!(KisanError::InvalidMintKind),
+            MintKind::Water => &mut state.water_mint_bump,
+            MintKind::Soil => &mut state.soil_mint_bump,
+        };
+        require!(*slot == 0, KisanError::AlreadyInitialized);
+        *slot = bump;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeMint,
+            ctx.accounts.mint.key(),
+            0,
+            kind as u64,
+        )?;
+
+        msg!("{:?} mint: {}", kind, ctx.accounts.mint.key());
+
+        Ok(())
+    }
+
+    /// Create or update a circuit's payout configuration.
+    ///
+    /// # Arguments
+    /// * `circuit_id` — Identifier of the compliance circuit
+    /// * `active` — Whether claims against this circuit are accepted
+    /// * `green_enabled` — Whether the circuit pays the season's $GREEN reward
+    /// * `water_amount` / `soil_amount` — Companion tokens per claim (0 = none)
+    /// * `methodology_id` — Registry methodology recorded on each issuance
+    /// * `proof_system` — Proof system `verify_and_mint` verifies against
+    #[allow(clippy::too_many_arguments)]
+    pub fn configure_circuit(
+        ctx: Context<ConfigureCircuit>,
+        circuit_id: u16,
+        active: bool,
+        green_enabled: bool,
+        water_amount: u64,
+        soil_amount: u64,
+        methodology_id: u16,
+        proof_system: ProofSystem,
+    ) -> Result<()> {
+        let state = &ctx.accounts.program_state;
+        require!(
+            (water_amount == 0 || state.water_mint_bump != 0)
+                && (soil_amount == 0 || state.soil_mint_bump != 0),
+            KisanError::InvalidMintKind
+        );
+
+        let circuit = &mut ctx.accounts.circuit_config;
+        require!(
+            proof_system != ProofSystem::External || circuit.verifier_program != Pubkey::default(),
+            KisanError::InvalidVerifierProgram
+        );
+        let was_active = circuit.active;
+        circuit.circuit_id = circuit_id;
+        circuit.active = active;
+        circuit.green_enabled = green_enabled;
+        circuit.water_amount = water_amount;
+        circuit.soil_amount = soil_amount;
+        circuit.methodology_id = methodology_id;
+        circuit.proof_system = proof_system;
+        circuit.bump = ctx.bumps.circuit_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigureCircuit,
+            circuit.key(),
+            was_active as u64,
+            active as u64,
+        )?;
+
+        msg!(
+            "Circuit {} configured (GREEN: {}, WATER: {}, SOIL: {}, {:?})",
+            circuit_id,
+            green_enabled,
+            water_amount,
+            soil_amount,
+            proof_system
+        );
+
+        Ok(())
+    }
+
+    /// Upload (or replace) a circuit's Groth16 verification key, as exported
+    /// by snarkjs and converted to the big-endian encodings in `groth16.rs`.
+    /// Also opens the circuit's previous-key account, holding no valid key
+    /// yet, so governance can rotate the key later without a payer.
+    pub fn set_groth16_verifying_key(
+        ctx: Context<SetGroth16VerifyingKey>,
+        key: groth16::VerifyingKey,
+    ) -> Result<()> {
+        require!(key.is_well_formed(), KisanError::InvalidVerifyingKey);
+
+        let vk = &mut ctx.accounts.groth16_vk;
+        vk.circuit_id = ctx.accounts.circuit_config.circuit_id;
+        vk.key = key;
+        vk.bump = ctx.bumps.groth16_vk;
+        let previous = &mut ctx.accounts.previous_groth16_vk;
+        previous.circuit_id = vk.circuit_id;
+        previous.bump = ctx.bumps.previous_groth16_vk;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetGroth16VerifyingKey,
+            vk.key(),
+            0,
+            vk.key.n_public() as u64,
+        )?;
+
+        msg!(
+            "Groth16 key for circuit {}: {} public inputs",
+            vk.circuit_id,
+            vk.key.n_public()
+        );
+
+        Ok(())
+    }
+
+    /// Upload (or replace) a circuit's PLONK verification key, as exported
+    /// by snarkjs and converted to the big-endian encodings in `plonk.rs`.
+    /// Opens the previous-key account the same way.
+    pub fn set_plonk_verifying_key(
+        ctx: Context<SetPlonkVerifyingKey>,
+        key: plonk::VerifyingKey,
+    ) -> Result<()> {
+        require!(key.is_well_formed(), KisanError::InvalidVerifyingKey);
+
+        let vk = &mut ctx.accounts.plonk_vk;
+        vk.circuit_id = ctx.accounts.circuit_config.circuit_id;
+        vk.key = key;
+        vk.bump = ctx.bumps.plonk_vk;
+        let previous = &mut ctx.accounts.previous_plonk_vk;
+        previous.circuit_id = vk.circuit_id;
+        previous.bump = ctx.bumps.previous_plonk_vk;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetPlonkVerifyingKey,
+            vk.key(),
+            0,
+            vk.key.power as u64,
+        )?;
+
+        msg!(
+            "PLONK key for circuit {}: 2^{} gates, {} public inputs",
+            vk.circuit_id,
+            vk.key.power,
+            vk.key.n_public
+        );
+
+        Ok(())
+    }
+
+    /// Replace a circuit's PLONK key while keeping the outgoing one valid
+    /// for `overlap_slots` more slots, so claims already proven against it
+    /// still land: they pass `previous_plonk_vk`, and after the window fail
+    /// with `VerifyingKeyRetired`. Rotating again replaces the previous key,
+    /// ending its window early.
+    ///
+    /// # Arguments
+    /// * `key` — The new verification key
+    /// * `overlap_slots` — Slots the outgoing key stays valid, at most
+    ///   `MAX_VK_OVERLAP_SLOTS`
+    pub fn rotate_plonk_verifying_key(
+        ctx: Context<RotatePlonkVerifyingKey>,
+        key: plonk::VerifyingKey,
+        overlap_slots: u64,
+    ) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let vk = &mut ctx.accounts.plonk_vk;
+        let previous = &mut ctx.accounts.previous_plonk_vk;
+        let valid_until = vk.rotate(previous, key, overlap_slots, slot)?;
+        previous.bump = ctx.bumps.previous_plonk_vk;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RotatePlonkVerifyingKey,
+            vk.key(),
+            slot,
+            valid_until,
+        )?;
+
+        msg!(
+            "PLONK key for circuit {} rotated; previous key valid through slot {}",
+            vk.circuit_id,
+            valid_until
+        );
+
+        Ok(())
+    }
+
+    /// Replace a circuit's Groth16 key the same way: the outgoing key
+    /// verifies claims that pass `previous_groth16_vk` for `overlap_slots`
+    /// more slots.
+    ///
+    /// # Arguments
+    /// * `key` — The new verification key
+    /// * `overlap_slots` — Slots the outgoing key stays valid, at most
+    ///   `MAX_VK_OVERLAP_SLOTS`
+    pub fn rotate_groth16_verifying_key(
+        ctx: Context<RotateGroth16VerifyingKey>,
+        key: groth16::VerifyingKey,
+        overlap_slots: u64,
+    ) -> Result<()> {
+        let slot = Clock::get()?.slot;
+        let vk = &mut ctx.accounts.groth16_vk;
+        let previous = &mut ctx.accounts.previous_groth16_vk;
+        let valid_until = vk.rotate(previous, key, overlap_slots, slot)?;
+        previous.bump = ctx.bumps.previous_groth16_vk;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RotateGroth16VerifyingKey,
+            vk.key(),
+            slot,
+            valid_until,
+        )?;
+
+        msg!(
+            "Groth16 key for circuit {} rotated; previous key valid through slot {}",
+            vk.circuit_id,
+            valid_until
+        );
+
+        Ok(())
+    }
+
+    /// Route a circuit's proofs to an external verifier program, switching
+    /// it to `ProofSystem::External`. The verifier must implement the
+    /// interface in `verifier.rs`; `configure_circuit` switches back.
+    pub fn set_circuit_verifier(
+        ctx: Context<SetCircuitVerifier>,
+        verifier_program: Pubkey,
+    ) -> Result<()> {
+        require!(
+            verifier_program != Pubkey::default() && verifier_program != crate::ID,
+            KisanError::InvalidVerifierProgram
+        );
+
+        let circuit = &mut ctx.accounts.circuit_config;
+        let old_system = circuit.proof_system;
+        circuit.proof_system = ProofSystem::External;
+        circuit.verifier_program = verifier_program;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetCircuitVerifier,
+            verifier_program,
+            old_system as u64,
+            ProofSystem::External as u64,
+        )?;
+
+        msg!("Circuit {} verified by {}", circuit.circuit_id, verifier_program);
+
+        Ok(())
+    }
+
+    /// Configure oracle-priced rewards. While enabled, each $GREEN claim
+    /// mints `target_value` worth of tokens at the feed's price; a stale or
+    /// low-confidence price falls back to `fallback_amount`.
+    ///
+    /// # Arguments
+    /// * `price_feed` — Pyth `PriceUpdateV2` account to read
+    /// * `feed_id` — Pyth feed id the account must carry
+    /// * `target_value` — Value per claim, in micro-units of the quote currency
+    /// * `max_staleness` — Oldest acceptable price, in seconds
+    /// * `max_conf_bps` — Widest acceptable confidence interval, in bps of price
+    /// * `fallback_amount` — $GREEN base units minted when the price is unusable;
+    ///   0 rejects such claims with `StaleOraclePrice` instead
+    /// * `enabled` — Turns dynamic pricing on or off
+    #[allow(clippy::too_many_arguments)]
+    pub fn configure_pricing(
+        ctx: Context<ConfigurePricing>,
+        price_feed: Pubkey,
+        feed_id: [u8; 32],
+        target_value: u64,
+        max_staleness: i64,
+        max_conf_bps: u16,
+        fallback_amount: u64,
+        enabled: bool,
+    ) -> Result<()> {
+        require!(
+            target_value > 0 && max_staleness > 0 && max_conf_bps as u64 <= BPS_DENOMINATOR,
+            KisanError::InvalidPricingConfig
+        );
+
+        let old_target = ctx.accounts.pricing_config.target_value;
+        let pricing = &mut ctx.accounts.pricing_config;
+        pricing.price_feed = price_feed;
+        pricing.feed_id = feed_id;
+        pricing.target_value = target_value;
+        pricing.max_staleness = max_staleness;
+        pricing.max_conf_bps = max_conf_bps;
+        pricing.fallback_amount = fallback_amount;
+        pricing.bump = ctx.bumps.pricing_config;
+
+        ctx.accounts.program_state.dynamic_pricing = enabled;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigurePricing,
+            price_feed,
+            old_target,
+            target_value,
+        )?;
+
+        msg!(
+            "Dynamic pricing {}: target {} micro-units per claim, feed {}",
+            if enabled { "enabled" } else { "disabled" },
+            target_value,
+            price_feed
+        );
+
+        Ok(())
+    }
+
+    /// Set the streak bonus curve. `bonus_bps[i]` is the extra reward, in
+    /// basis points of the season reward, for a streak of `i + 1`
+    /// consecutive seasons; longer streaks use the last entry.
+    pub fn set_streak_curve(
+        ctx: Context<SetStreakCurve>,
+        bonus_bps: [u16; STREAK_CURVE_LEN],
+    ) -> Result<()> {
+        require!(
+            bonus_bps.iter().all(|&bps| bps <= MAX_STREAK_BONUS_BPS),
+            KisanError::InvalidStreakCurve
+        );
+        let old_max = ctx.accounts.config.streak_curve[STREAK_CURVE_LEN - 1];
+        ctx.accounts.config.streak_curve = bonus_bps;
+        ctx.accounts.config.updated_at = Clock::get()?.unix_timestamp;
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetStreakCurve,
+            ctx.accounts.config.key(),
+            old_max as u64,
+            bonus_bps[STREAK_CURVE_LEN - 1] as u64,
+        )?;
+        msg!("Streak curve updated: {:?}", bonus_bps);
+        Ok(())
+    }
+
+    /// Permissionless crank: once the current season has ended, open the
+    /// next one with the configured parameters and reset the leaderboard.
+    /// The ended season's tally is frozen into its `SeasonSnapshot`, and
+    /// its rent goes towards the snapshot and the next season's accounts,
+    /// which the cranker pays for.
+    pub fn advance_season(ctx: Context<AdvanceSeason>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let ended = &ctx.accounts.current_season;
+        require!(now >= ended.end, KisanError::SeasonActive);
+
+        // Seasons are contiguous unless the crank ran so late that the
+        // next season would already be over, in which case it starts now.
+        let duration = ctx.accounts.config.season_duration;
+        let start = if now < ended.end + duration { ended.end } else { now };
+        let next_index = ended.index + 1;
+
+        ctx.accounts.snapshot.set_inner(SeasonSnapshot::take(
+            ended,
+            &ctx.accounts.current_tally,
+            now,
+            ctx.bumps.snapshot,
+        ));
+
+        ctx.accounts.program_state.current_season = next_index;
+        ctx.accounts.next_season.open(
+            next_index,
+            start,
+            &ctx.accounts.config,
+            ctx.bumps.next_season,
+        );
+        ctx.accounts.next_tally.open(next_index, ctx.bumps.next_tally);
+
+        ctx.accounts.leaderboard.load_mut()?.reset(now);
+
+        msg!(
+            "Season {} closed ({} proofs); season {} open until {}",
+            ended.index,
+            ended.total_proofs,
+            next_index,
+            ctx.accounts.next_season.end
+        );
+
+        Ok(())
+    }
+
+    /// Permissionless: create the tally of a season opened before season
+    /// tallies existed, so its claims and rollover can proceed. Claims
+    /// made before the call aren't in the tally's farmer and region counts.
+    pub fn initialize_season_tally(ctx: Context<InitializeSeasonTally>) -> Result<()> {
+        let index = ctx.accounts.program_state.current_season;
+        ctx.accounts.season_tally.open(index, ctx.bumps.season_tally);
+        msg!("Season {} tally: {}", index, ctx.accounts.season_tally.key());
+        Ok(())
+    }
+
+    /// Revoke an identity attestation, e.g. after a KYC failure. Only the
+    /// attester that issued it can revoke it.
+    pub fn revoke_identity_attestation(ctx: Context<RevokeIdentityAttestation>) -> Result<()> {
+        ctx.accounts.identity_attestation.revoked = true;
+        msg!("Identity attestation revoked for {}", ctx.accounts.identity_attestation.farmer);
+        Ok(())
+    }
+
+    /// Register a land parcel under the farmer's profile. The parcel takes
+    /// the farmer's region, which decides its weather triggers.
+    ///
+    /// Passing an anchored `land_record` links the parcel to it; each
+    /// record backs at most one parcel. Regions can require the link.
+    pub fn register_parcel(ctx: Context<RegisterParcel>, parcel_id: u32) -> Result<()> {
+        let parcel_key = ctx.accounts.parcel.key();
+        let land_record_key = match ctx.accounts.land_record.as_mut() {
+            Some(record) => {
+                require!(record.parcel == Pubkey::default(), KisanError::LandRecordLinked);
+                record.parcel = parcel_key;
+                record.key()
+            }
+            None => {
+                require!(
+                    !ctx.accounts.region_config.require_land_record,
+                    KisanError::LandRecordRequired
+                );
+                Pubkey::default()
+            }
+        };
+
+        let parcel = &mut ctx.accounts.parcel;
+        parcel.farmer = ctx.accounts.farmer.key();
+        parcel.parcel_id = parcel_id;
+        parcel.region_code = ctx.accounts.farmer_profile.region_code;
+        parcel.registered_at = Clock::get()?.unix_timestamp;
+        parcel.bump = ctx.bumps.parcel;
+        parcel.land_record = land_record_key;
+
+        msg!("Parcel {} registered in region {}", parcel_id, parcel.region_code);
+
+        Ok(())
+    }
+
+    /// Split the rewards of claims on a parcel between the farmer working
+    /// it and its landowner, for tenant farming. Both sign, so the split
+    /// records each party's consent; `verify_and_mint` then mints the
+    /// landowner's share to them on every claim whose commitment names
+    /// the parcel.
+    ///
+    /// # Arguments
+    /// * `farmer_bps` — Farmer's share of each reward, in basis points
+    /// * `landowner_bps` — Landowner's share; the two must sum to 10 000
+    pub fn create_payout_split(
+        ctx: Context<CreatePayoutSplit>,
+        farmer_bps: u16,
+        landowner_bps: u16,
+    ) -> Result<()> {
+        require!(
+            landowner_bps > 0 && farmer_bps as u64 + landowner_bps as u64 == BPS_DENOMINATOR,
+            KisanError::InvalidPayoutSplit
+        );
+
+        let split = &mut ctx.accounts.payout_split;
+        split.parcel = ctx.accounts.parcel.key();
+        split.farmer = ctx.accounts.farmer.key();
+        split.landowner = ctx.accounts.landowner.key();
+        split.farmer_bps = farmer_bps;
+        split.landowner_bps = landowner_bps;
+        split.agreed_at = Clock::get()?.unix_timestamp;
+        split.bump = ctx.bumps.payout_split;
+
+        emit!(PayoutSplitAgreed {
+            parcel: split.parcel,
+            farmer: split.farmer,
+            landowner: split.landowner,
+            farmer_bps,
+            landowner_bps,
+            agreed_at: split.agreed_at,
+        });
+
+        Ok(())
+    }
+
+    /// End a payout split; claims on the parcel pay the farmer in full
+    /// again. Both parties sign, as when it was created. Rent returns to
+    /// the farmer.
+    pub fn dissolve_payout_split(ctx: Context<DissolvePayoutSplit>) -> Result<()> {
+        msg!("Payout split dissolved: parcel {}", ctx.accounts.payout_split.parcel);
+        Ok(())
+    }
+
+    /// Set the weather thresholds outside which cover in a region pays out.
+    ///
+    /// # Arguments
+    /// * `min_rainfall_mm` — Season rainfall below this is a drought (final reports only)
+    /// * `max_rainfall_mm` — Season rainfall above this is a flood
+    /// * `max_temperature_dc` — Peak temperature above this, in tenths of °C
+    pub fn set_weather_thresholds(
+        ctx: Context<SetWeatherThresholds>,
+        min_rainfall_mm: u32,
+        max_rainfall_mm: u32,
+        max_temperature_dc: i16,
+    ) -> Result<()> {
+        require!(
+            min_rainfall_mm < max_rainfall_mm,
+            KisanError::InvalidWeatherThresholds
+        );
+
+        let region = &mut ctx.accounts.region_config;
+        let old_max = region.max_rainfall_mm;
+        region.min_rainfall_mm = min_rainfall_mm;
+        region.max_rainfall_mm = max_rainfall_mm;
+        region.max_temperature_dc = max_temperature_dc;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetWeatherThresholds,
+            region.key(),
+            old_max as u64,
+            max_rainfall_mm as u64,
+        )?;
+
+        msg!(
+            "Region {} weather thresholds: {}–{} mm, ≤ {} dC",
+            region.region_code,
+            min_rainfall_mm,
+            max_rainfall_mm,
+            max_temperature_dc
+        );
+
+        Ok(())
+    }
+
+    /// Register an oracle reporter key, allowed to publish to the feeds in
+    /// `scopes` (`ORACLE_SCOPE_*` bits). Every weather and mandi price report
+    /// is checked against this registry.
+    pub fn add_oracle_reporter(
+        ctx: Context<AddOracleReporter>,
+        reporter: Pubkey,
+        scopes: u8,
+    ) -> Result<()> {
+        require!(
+            scopes != 0 && scopes & !ORACLE_SCOPE_ALL == 0,
+            KisanError::InvalidOracleScopes
+        );
+
+        let entry = &mut ctx.accounts.oracle_reporter;
+        entry.reporter = reporter;
+        entry.scopes = scopes;
+        entry.added_at = Clock::get()?.unix_timestamp;
+        entry.bump = ctx.bumps.oracle_reporter;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::AddOracleReporter,
+            reporter,
+            0,
+            scopes as u64,
+        )?;
+
+        msg!("Oracle reporter added: {} (scopes {:#04b})", reporter, scopes);
+
+        Ok(())
+    }
+
+    /// Change the feeds a registered reporter may publish to.
+    pub fn set_oracle_reporter_scopes(
+        ctx: Context<SetOracleReporterScopes>,
+        scopes: u8,
+    ) -> Result<()> {
+        require!(
+            scopes != 0 && scopes & !ORACLE_SCOPE_ALL == 0,
+            KisanError::InvalidOracleScopes
+        );
+
+        let entry = &mut ctx.accounts.oracle_reporter;
+        let old_scopes = entry.scopes;
+        entry.scopes = scopes;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetOracleReporterScopes,
+            entry.reporter,
+            old_scopes as u64,
+            scopes as u64,
+        )?;
+
+        msg!("Oracle reporter {} scopes: {:#04b}", entry.reporter, scopes);
+
+        Ok(())
+    }
+
+    /// Deregister an oracle reporter. Rent returns to the authority.
+    pub fn remove_oracle_reporter(ctx: Context<RemoveOracleReporter>) -> Result<()> {
+        let entry = &ctx.accounts.oracle_reporter;
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RemoveOracleReporter,
+            entry.reporter,
+            entry.scopes as u64,
+            0,
+        )?;
+        msg!("Oracle reporter removed: {}", entry.reporter);
+        Ok(())
+    }
+
+    /// Move a reporter's registration to `new_reporter` with the same
+    /// scopes, deregistering the old key in the same instruction, so a
+    /// leaked or retired key is replaced without a gap in reporting.
+    pub fn rotate_oracle_reporter(
+        ctx: Context<RotateOracleReporter>,
+        new_reporter: Pubkey,
+    ) -> Result<()> {
+        let old = &ctx.accounts.oracle_reporter;
+        let entry = &mut ctx.accounts.new_oracle_reporter;
+        entry.reporter = new_reporter;
+        entry.scopes = old.scopes;
+        entry.added_at = Clock::get()?.unix_timestamp;
+        entry.bump = ctx.bumps.new_oracle_reporter;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RotateOracleReporter,
+            new_reporter,
+            0,
+            old.scopes as u64,
+        )?;
+
+        msg!("Oracle reporter rotated: {} -> {}", old.reporter, new_reporter);
+
+        Ok(())
+    }
+
+    /// Set how many distinct reporters must sign each report, per feed.
+    /// With a quorum of 1 any single registered reporter can publish; above
+    /// 1, one compromised key can't move a feed on its own.
+    pub fn configure_oracle_quorum(
+        ctx: Context<ConfigureOracleQuorum>,
+        weather_quorum: u8,
+        mandi_price_quorum: u8,
+    ) -> Result<()> {
+        for quorum in [weather_quorum, mandi_price_quorum] {
+            require!(quorum > 0, KisanError::InvalidOracleQuorum);
+            if quorum > MAX_ORACLE_QUORUM {
+                return Err(report::exceeded(
+                    KisanError::InvalidOracleQuorum,
+                    quorum,
+                    MAX_ORACLE_QUORUM,
+                ));
+            }
+        }
+
+        let config = &mut ctx.accounts.oracle_config;
+        let old_quorum = config.weather_quorum;
+        config.weather_quorum = weather_quorum;
+        config.mandi_price_quorum = mandi_price_quorum;
+        config.bump = ctx.bumps.oracle_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigureOracleQuorum,
+            config.key(),
+            old_quorum as u64,
+            weather_quorum as u64,
+        )?;
+
+        msg!(
+            "Oracle quorum: weather {}, mandi price {}",
+            weather_quorum,
+            mandi_price_quorum
+        );
+
+        Ok(())
+    }
+
+    /// Close a legacy weather-oracle whitelist entry. Weather reporters are
+    /// now registered with `add_oracle_reporter`; rent returns to the
+    /// authority.
+    pub fn remove_weather_oracle(ctx: Context<RemoveWeatherOracle>) -> Result<()> {
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RemoveWeatherOracle,
+            ctx.accounts.oracle_info.oracle,
+            0,
+            0,
+        )?;
+        msg!("Weather oracle removed: {}", ctx.accounts.oracle_info.oracle);
+        Ok(())
+    }
+
+    /// Publish (or update) a region's weather observations for a season.
+    /// `rainfall_mm` is cumulative for the season so far; `is_final` marks
+    /// the closing report, after which drought triggers can be evaluated.
+    ///
+    /// The signer must be a reporter scoped to weather. Under a weather
+    /// quorum above 1 the other reporters co-sign the same transaction and
+    /// are passed as (wallet, `OracleReporter`) pairs in remaining accounts.
+    pub fn report_weather<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ReportWeather<'info>>,
+        region_code: u16,
+        season: u32,
+        rainfall_mm: u32,
+        max_temperature_dc: i16,
+        is_final: bool,
+    ) -> Result<()> {
+        oracle::check_range(rainfall_mm, oracle::RAINFALL_MM_BOUNDS)?;
+        oracle::check_range(max_temperature_dc, oracle::TEMPERATURE_DC_BOUNDS)?;
+        check_oracle_quorum(
+            &ctx.accounts.oracle_reporter,
+            ctx.remaining_accounts,
+            ORACLE_SCOPE_WEATHER,
+            ctx.accounts.oracle_config.weather_quorum,
+        )?;
+
+        let report = &mut ctx.accounts.weather_report;
+        require!(!report.is_final, KisanError::WeatherReportFinal);
+
+        report.region_code = region_code;
+        report.season = season;
+        report.rainfall_mm = rainfall_mm;
+        report.max_temperature_dc = max_temperature_dc;
+        report.is_final = is_final;
+        report.reported_by = ctx.accounts.oracle.key();
+        report.reported_at = Clock::get()?.unix_timestamp;
+        report.bump = ctx.bumps.weather_report;
+
+        msg!(
+            "Weather report region {} season {}: {} mm, {} dC{}",
+            region_code,
+            season,
+            rainfall_mm,
+            max_temperature_dc,
+            if is_final { " (final)" } else { "" }
+        );
+
+        Ok(())
+    }
+
+    /// Create the insurance pool. Cover pays `payout_multiple_bps` basis
+    /// points of the premium when triggered.
+    pub fn initialize_insurance_pool(
+        ctx: Context<InitializeInsurancePool>,
+        payout_multiple_bps: u32,
+    ) -> Result<()> {
+        require!(
+            payout_multiple_bps as u64 > BPS_DENOMINATOR,
+            KisanError::InvalidPayoutMultiple
+        );
+
+        let pool = &mut ctx.accounts.insurance_pool;
+        pool.payout_multiple_bps = payout_multiple_bps;
+        pool.bump = ctx.bumps.insurance_pool;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeInsurancePool,
+            pool.key(),
+            0,
+            payout_multiple_bps as u64,
+        )?;
+
+        msg!("Insurance pool: {}", pool.key());
+
+        Ok(())
+    }
+
+    /// Deposit SOL into the insurance pool. This is the pool's only
+    /// funding besides premiums: no protocol fees are routed here, so the
+    /// authority or anyone else tops it up by hand.
+    pub fn fund_insurance_pool(ctx: Context<FundInsurancePool>, amount: u64) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.funder.to_account_info(),
+                    to: ctx.accounts.insurance_pool.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        ctx.accounts.insurance_pool.total_funded += amount;
+        msg!("Insurance pool funded: {} lamports", amount);
+
+        Ok(())
+    }
+
+    /// Buy weather cover for a parcel for an upcoming season. The premium
+    /// (in lamports) goes to the pool, which must be able to cover every
+    /// outstanding payout including this one.
+    pub fn buy_cover(ctx: Context<BuyCover>, season: u32, premium: u64) -> Result<()> {
+        require!(premium > 0, KisanError::InvalidAmount);
+        require!(
+            season > ctx.accounts.program_state.current_season,
+            KisanError::CoverSeasonStarted
+        );
+        require!(
+            ctx.accounts.region_config.max_rainfall_mm > 0,
+            KisanError::InvalidWeatherThresholds
+        );
+
+        let pool = &ctx.accounts.insurance_pool;
+        let pool_info = pool.to_account_info();
+        let pool_floor = Rent::get()?.minimum_balance(pool_info.data_len());
+        let payout = pool.cover_payout(premium, pool_info.lamports(), pool_floor)?;
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.farmer.to_account_info(),
+                    to: pool_info,
+                },
+            ),
+            premium,
+        )?;
+
+        let pool = &mut ctx.accounts.insurance_pool;
+        pool.total_premiums += premium;
+        pool.cover_outstanding += payout;
+
+        let parcel = &ctx.accounts.parcel;
+        let cover = &mut ctx.accounts.cover;
+        cover.farmer = parcel.farmer;
+        cover.parcel = parcel.key();
+        cover.region_code = parcel.region_code;
+        cover.season = season;
+        cover.premium = premium;
+        cover.payout = payout;
+        cover.bump = ctx.bumps.cover;
+
+        msg!(
+            "Cover bought for parcel {} season {}: premium {}, payout {} lamports",
+            parcel.parcel_id,
+            season,
+            premium,
+            payout
+        );
+
+        Ok(())
+    }
+
+    /// Pay out cover whose region's weather report is outside the
+    /// thresholds. The cover account is closed to the farmer.
+    pub fn claim_payout(ctx: Context<ClaimPayout>) -> Result<()> {
+        require!(
+            ctx.accounts.region_config.weather_triggered(&ctx.accounts.weather_report),
+            KisanError::CoverNotTriggered
+        );
+
+        let payout = ctx.accounts.cover.payout;
+        let pool = &mut ctx.accounts.insurance_pool;
+        **pool.to_account_info().try_borrow_mut_lamports()? -= payout;
+        **ctx.accounts.farmer.to_account_info().try_borrow_mut_lamports()? += payout;
+        pool.cover_outstanding -= payout;
+        pool.total_paid_out += payout;
+
+        msg!(
+            "Cover paid out: {} lamports to {} for season {}",
+            payout,
+            ctx.accounts.farmer.key(),
+            ctx.accounts.cover.season
+        );
+
+        Ok(())
+    }
+
+    /// Permissionless crank: close cover whose season's final weather
+    /// report stayed within thresholds, releasing the pool's reserved
+    /// payout. Rent returns to the farmer.
+    pub fn expire_cover(ctx: Context<ExpireCover>) -> Result<()> {
+        let report = &ctx.accounts.weather_report;
+        require!(report.is_final, KisanError::WeatherReportPending);
+        require!(
+            !ctx.accounts.region_config.weather_triggered(report),
+            KisanError::CoverTriggered
+        );
+
+        ctx.accounts.insurance_pool.cover_outstanding -= ctx.accounts.cover.payout;
+        msg!("Cover expired for season {}", ctx.accounts.cover.season);
+
+        Ok(())
+    }
+
+    /// Permissionless crank: `claim_payout` or `expire_cover`, whichever
+    /// the weather report calls for, without the farmer signing. Triggered
+    /// cover pays out to the farmer; in-threshold cover expires once the
+    /// report is final. Either way the cover is closed, so a repeated crank
+    /// fails without effect.
+    pub fn settle_cover(ctx: Context<SettleCover>) -> Result<()> {
+        let report = &ctx.accounts.weather_report;
+        let triggered = ctx.accounts.region_config.weather_triggered(report);
+        require!(triggered || report.is_final, KisanError::WeatherReportPending);
+
+        let payout = ctx.accounts.cover.payout;
+        let pool = &mut ctx.accounts.insurance_pool;
+        pool.cover_outstanding -= payout;
+        if !triggered {
+            msg!("Cover expired for season {}", ctx.accounts.cover.season);
+            return Ok(());
+        }
+
+        **pool.to_account_info().try_borrow_mut_lamports()? -= payout;
+        **ctx.accounts.farmer.to_account_info().try_borrow_mut_lamports()? += payout;
+        pool.total_paid_out += payout;
+
+        msg!(
+            "Cover paid out: {} lamports to {} for season {}",
+            payout,
+            ctx.accounts.farmer.key(),
+            ctx.accounts.cover.season
+        );
+
+        Ok(())
+    }
+
+    /// Create or update the micro-loan parameters. A farmer's loan-to-value
+    /// ratio grows with their verified proofs: `base_ltv_bps` plus
+    /// `ltv_per_proof_bps` per proof, capped at `max_ltv_bps`.
+    ///
+    /// # Arguments
+    /// * `usdc_mint` — Mint loans are denominated in
+    /// * `green_price` — Collateral value of 1 $GREEN, in USDC base units
+    /// * `grace_period` — Seconds after the due date before liquidation
+    #[allow(clippy::too_many_arguments)]
+    pub fn configure_loans(
+        ctx: Context<ConfigureLoans>,
+        usdc_mint: Pubkey,
+        green_price: u64,
+        base_ltv_bps: u16,
+        ltv_per_proof_bps: u16,
+        max_ltv_bps: u16,
+        grace_period: i64,
+    ) -> Result<()> {
+        require!(
+            green_price > 0
+                && base_ltv_bps <= max_ltv_bps
+                && max_ltv_bps as u64 <= BPS_DENOMINATOR
+                && grace_period >= 0,
+            KisanError::InvalidLoanConfig
+        );
+
+        let old_max_ltv = ctx.accounts.loan_config.max_ltv_bps;
+        let config = &mut ctx.accounts.loan_config;
+        config.usdc_mint = usdc_mint;
+        config.green_price = green_price;
+        config.base_ltv_bps = base_ltv_bps;
+        config.ltv_per_proof_bps = ltv_per_proof_bps;
+        config.max_ltv_bps = max_ltv_bps;
+        config.grace_period = grace_period;
+        config.bump = ctx.bumps.loan_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigureLoans,
+            usdc_mint,
+            old_max_ltv as u64,
+            max_ltv_bps as u64,
+        )?;
+
+        msg!(
+            "Loans configured: {} per $GREEN, LTV {}–{} bps",
+            green_price,
+            base_ltv_bps,
+            max_ltv_bps
+        );
+
+        Ok(())
+    }
+
+    /// Request a USDC loan, locking `collateral` $GREEN in a per-loan vault.
+    /// The principal may not exceed the collateral's value at the farmer's
+    /// reputation-based LTV.
+    ///
+    /// # Arguments
+    /// * `loan_id` — Farmer-scoped loan number
+    /// * `principal` — USDC base units requested
+    /// * `collateral` — $GREEN base units locked
+    /// * `interest_bps` — Flat interest over the term, in bps of principal
+    /// * `duration` — Seconds from funding until the loan is due
+    pub fn request_loan(
+        ctx: Context<RequestLoan>,
+        loan_id: u32,
+        principal: u64,
+        collateral: u64,
+        interest_bps: u16,
+        duration: i64,
+    ) -> Result<()> {
+        require!(principal > 0 && collateral > 0, KisanError::InvalidAmount);
+        require!(duration > 0, KisanError::InvalidLoanTerms);
+
+        let max_principal = ctx
+            .accounts
+            .loan_config
+            .max_principal(collateral, ctx.accounts.farmer_profile.total_proofs);
+        if principal > max_principal {
+            return Err(report::exceeded(KisanError::LoanExceedsLtv, principal, max_principal));
+        }
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.farmer_green_account.to_account_info(),
+                    to: ctx.accounts.collateral_vault.to_account_info(),
+                    authority: ctx.accounts.farmer.to_account_info(),
+                },
+            ),
+            collateral,
+        )?;
+
+        let loan = &mut ctx.accounts.loan;
+        loan.farmer = ctx.accounts.farmer.key();
+        loan.loan_id = loan_id;
+        loan.principal = principal;
+        loan.collateral = collateral;
+        loan.interest_bps = interest_bps;
+        loan.duration = duration;
+        loan.status = LoanStatus::Requested;
+        loan.bump = ctx.bumps.loan;
+        require!(loan.amount_due().is_some(), KisanError::InvalidLoanTerms);
+
+        msg!(
+            "Loan {} requested: {} USDC against {} $GREEN (max {})",
+            loan_id,
+            principal,
+            collateral,
+            max_principal
+        );
+
+        Ok(())
+    }
+
+    /// Withdraw an unfunded loan request and unlock the collateral.
+    pub fn cancel_loan(ctx: Context<CancelLoan>) -> Result<()> {
+        require!(
+            ctx.accounts.loan.status == LoanStatus::Requested,
+            KisanError::InvalidLoanStatus
+        );
+
+        release_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.collateral_vault,
+            &ctx.accounts.farmer_green_account,
+            &ctx.accounts.farmer.to_account_info(),
+            &ctx.accounts.program_state,
+        )?;
+
+        msg!("Loan {} cancelled", ctx.accounts.loan.loan_id);
+
+        Ok(())
+    }
+
+    /// Fund a requested loan. The principal goes straight to the farmer
+    /// and the term starts now. The loan keeps the currency and grace
+    /// period in force at funding, so a later `configure_loans` can't
+    /// change its terms.
+    pub fn fund_loan(ctx: Context<FundLoan>) -> Result<()> {
+        let loan = &mut ctx.accounts.loan;
+        require!(loan.status == LoanStatus::Requested, KisanError::InvalidLoanStatus);
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.lender_usdc_account.to_account_info(),
+                    to: ctx.accounts.farmer_usdc_account.to_account_info(),
+                    authority: ctx.accounts.lender.to_account_info(),
+                },
+            ),
+            loan.principal,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        loan.lender = ctx.accounts.lender.key();
+        loan.funded_at = now;
+        loan.due_at = now
+            .checked_add(loan.duration)
+            .ok_or(KisanError::InvalidLoanTerms)?;
+        loan.status = LoanStatus::Active;
+        loan.usdc_mint = ctx.accounts.loan_config.usdc_mint;
+        loan.grace_period = ctx.accounts.loan_config.grace_period;
+
+        msg!("Loan {} funded by {}, due {}", loan.loan_id, loan.lender, loan.due_at);
+
+        Ok(())
+    }
+
+    /// Repay principal plus interest to the lender and unlock the
+    /// collateral. Allowed until the loan is liquidated.
+    pub fn repay_loan(ctx: Context<RepayLoan>) -> Result<()> {
+        let loan = &ctx.accounts.loan;
+        require!(loan.status == LoanStatus::Active, KisanError::InvalidLoanStatus);
+        let amount_due = loan.amount_due().ok_or(KisanError::InvalidLoanTerms)?;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.farmer_usdc_account.to_account_info(),
+                    to: ctx.accounts.lender_usdc_account.to_account_info(),
+                    authority: ctx.accounts.farmer.to_account_info(),
+                },
+            ),
+            amount_due,
+        )?;
+
+        release_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.collateral_vault,
+            &ctx.accounts.farmer_green_account,
+            &ctx.accounts.farmer.to_account_info(),
+            &ctx.accounts.program_state,
+        )?;
+
+        msg!("Loan {} repaid: {} USDC", loan.loan_id, amount_due);
+
+        Ok(())
+    }
+
+    /// Seize the collateral of a loan that is unpaid past its due date plus
+    /// the grace period. Only the lender may liquidate.
+    pub fn liquidate_loan(ctx: Context<LiquidateLoan>) -> Result<()> {
+        let loan = &ctx.accounts.loan;
+        require!(loan.status == LoanStatus::Active, KisanError::InvalidLoanStatus);
+        require!(
+            Clock::get()?.unix_timestamp >= loan.due_at.saturating_add(loan.grace_period),
+            KisanError::LoanNotInDefault
+        );
+
+        release_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.collateral_vault,
+            &ctx.accounts.lender_green_account,
+            &ctx.accounts.farmer.to_account_info(),
+            &ctx.accounts.program_state,
+        )?;
+
+        msg!("Loan {} liquidated: {} $GREEN to {}", loan.loan_id, loan.collateral, loan.lender);
+
+        Ok(())
+    }
+
+    /// Create the treasury vaults: USDC revenue and the $GREEN staging
+    /// account used by buybacks. Both are owned by the treasury-authority
+    /// PDA, which owns nothing else, so a signature for the treasury can't
+    /// move any other program-owned tokens.
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeTreasury,
+            ctx.accounts.treasury_usdc.key(),
+            0,
+            0,
+        )?;
+        msg!(
+            "Treasury vaults: USDC {}, $GREEN {}",
+            ctx.accounts.treasury_usdc.key(),
+            ctx.accounts.treasury_green.key()
+        );
+        Ok(())
+    }
+
+    /// Spend up to `usdc_amount` treasury USDC on $GREEN through a
+    /// whitelisted DEX and burn everything bought.
+    ///
+    /// The swap route is built off-chain: `route_data` is the swap
+    /// program's instruction data and its accounts are passed as remaining
+    /// accounts. It must be an allowlisted swap instruction with the
+    /// treasury authority and vaults in its authority, source and
+    /// destination positions (see `dex::check_route`); the treasury-
+    /// authority PDA signs it. `min_green_out` may not be below the
+    /// oracle's quote for `usdc_amount` less the treasury's
+    /// `buyback_slippage_bps`, so a buyback can't sell treasury USDC
+    /// for nothing. The USDC spent counts towards the treasury's daily
+    /// limit; past it the guardian must co-sign.
+    ///
+    /// # Arguments
+    /// * `usdc_amount` — Most USDC the route may spend
+    /// * `min_green_out` — Least $GREEN the route must deliver (slippage bound)
+    /// * `route_data` — Instruction data for the swap program
+    pub fn buyback_and_burn<'info>(
+        ctx: Context<'_, '_, 'info, 'info, BuybackAndBurn<'info>>,
+        usdc_amount: u64,
+        min_green_out: u64,
+        route_data: Vec<u8>,
+    ) -> Result<()> {
+        require!(usdc_amount > 0, KisanError::InvalidAmount);
+        let route_keys: Vec<Pubkey> = ctx.remaining_accounts.iter().map(|a| a.key()).collect();
+        dex::check_route(
+            ctx.accounts.swap_program.key,
+            &route_data,
+            &route_keys,
+            ctx.accounts.treasury_authority.key,
+            &ctx.accounts.treasury_usdc.key(),
+            &ctx.accounts.treasury_green.key(),
+        )?;
+
+        let pricing = &ctx.accounts.pricing_config;
+        let price = oracle::load_pyth_price(&ctx.accounts.price_feed, &pricing.feed_id)?;
+        price.validate(
+            Clock::get()?.unix_timestamp,
+            pricing.max_staleness,
+            pricing.max_conf_bps,
+        )?;
+        let floor = ctx.accounts.treasury_config.buyback_floor(
+            &price,
+            usdc_amount,
+            ctx.accounts.usdc_mint.decimals,
+        )?;
+        if min_green_out < floor {
+            return Err(report::exceeded(
+                KisanError::BuybackMinimumTooLow,
+                min_green_out,
+                floor,
+            ));
+        }
+
+        let usdc_before = ctx.accounts.treasury_usdc.amount;
+        let green_before = ctx.accounts.treasury_green.amount;
+
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[TREASURY_AUTHORITY_SEED, &[ctx.bumps.treasury_authority]]];
+        dex::invoke_route(
+            &ctx.accounts.swap_program,
+            ctx.remaining_accounts,
+            route_data,
+            ctx.accounts.treasury_authority.key,
+            signer_seeds,
+        )?;
+
+        ctx.accounts.treasury_usdc.reload()?;
+        ctx.accounts.treasury_green.reload()?;
+        let spent = usdc_before.saturating_sub(ctx.accounts.treasury_usdc.amount);
+        let bought = ctx.accounts.treasury_green.amount.saturating_sub(green_before);
+        if spent > usdc_amount {
+            return Err(report::exceeded(KisanError::SlippageExceeded, spent, usdc_amount));
+        }
+        if bought < min_green_out {
+            return Err(report::exceeded(KisanError::SlippageExceeded, bought, min_green_out));
+        }
+        ctx.accounts.treasury_config.authorize_outflow(
+            spent,
+            Clock::get()?.unix_timestamp,
+            ctx.accounts.guardian.as_ref(),
+        )?;
+
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.green_mint.to_account_info(),
+                    from: ctx.accounts.treasury_green.to_account_info(),
+                    authority: ctx.accounts.treasury_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            bought,
+        )?;
+
+        ctx.accounts.program_state.total_burned += bought;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::BuybackAndBurn,
+            ctx.accounts.swap_program.key(),
+            spent,
+            bought,
+        )?;
+
+        msg!("Buyback: {} USDC → {} $GREEN burned", spent, bought);
+
+        Ok(())
+    }
+
+    /// Create or update the treasury's withdrawal policy and the protocol
+    /// fee marketplace purchases pay into it. Once a guardian is set,
+    /// changing the policy also needs the guardian's signature, so the
+    /// authority key alone can't lift its own limits.
+    ///
+    /// # Arguments
+    /// * `guardian` — Co-signer of withdrawals above the daily limit
+    /// * `daily_limit` — USDC base units `withdraw_treasury` may move per
+    ///   24 hours
+    /// * `timelock` — Seconds between proposing and executing a large
+    ///   withdrawal, at least `MIN_TREASURY_TIMELOCK`
+    /// * `market_fee_bps` — Share of each marketplace purchase paid to the
+    ///   treasury, at most `MAX_MARKET_FEE_BPS`
+    /// * `buyback_slippage_bps` — How far below the oracle quote a
+    ///   buyback's `min_green_out` may be, at most `MAX_BUYBACK_SLIPPAGE_BPS`
+    pub fn configure_treasury(
+        ctx: Context<ConfigureTreasury>,
+        guardian: Pubkey,
+        daily_limit: u64,
+        timelock: i64,
+        market_fee_bps: u16,
+        buyback_slippage_bps: u16,
+    ) -> Result<()> {
+        require!(guardian != Pubkey::default(), KisanError::InvalidGuardian);
+        require!(timelock >= MIN_TREASURY_TIMELOCK, KisanError::InvalidTimelock);
+        if market_fee_bps > MAX_MARKET_FEE_BPS {
+            return Err(report::exceeded(
+                KisanError::MarketFeeTooHigh,
+                market_fee_bps,
+                MAX_MARKET_FEE_BPS,
+            ));
+        }
+        if buyback_slippage_bps > MAX_BUYBACK_SLIPPAGE_BPS {
+            return Err(report::exceeded(
+                KisanError::BuybackSlippageTooHigh,
+                buyback_slippage_bps,
+                MAX_BUYBACK_SLIPPAGE_BPS,
+            ));
+        }
+
+        let config = &mut ctx.accounts.treasury_config;
+        if config.guardian != Pubkey::default() {
+            require!(
+                ctx.accounts
+                    .guardian
+                    .as_ref()
+                    .is_some_and(|g| g.key() == config.guardian),
+                KisanError::GuardianSignatureRequired
+            );
+        }
+
+        let old_limit = config.daily_limit;
+        config.guardian = guardian;
+        config.daily_limit = daily_limit;
+        config.timelock = timelock;
+        config.market_fee_bps = market_fee_bps;
+        config.buyback_slippage_bps = buyback_slippage_bps;
+        config.bump = ctx.bumps.treasury_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigureTreasury,
+            guardian,
+            old_limit,
+            daily_limit,
+        )?;
+
+        msg!(
+            "Treasury: {} per day, {}s timelock, guardian {}, market fee {} bps",
+            daily_limit,
+            timelock,
+            guardian,
+            market_fee_bps
+        );
+
+        Ok(())
+    }
+
+    /// Move treasury USDC to `destination` within the daily limit. The
+    /// limit applies to a 24-hour window opened by the first outflow after
+    /// the previous window ended and counts every path out of the
+    /// treasury; larger amounts go through `propose_treasury_withdrawal`.
+    ///
+    /// # Arguments
+    /// * `amount` — USDC base units
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+
+        let withdrawn = pay_from_treasury(
+            &mut ctx.accounts.treasury_config,
+            None,
+            &ctx.accounts.token_program,
+            &ctx.accounts.treasury_usdc,
+            &ctx.accounts.destination,
+            &ctx.accounts.treasury_authority,
+            ctx.bumps.treasury_authority,
+            amount,
+        )?;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::WithdrawTreasury,
+            ctx.accounts.destination.key(),
+            withdrawn,
+            amount,
+        )?;
+
+        msg!(
+            "Treasury withdrawal: {} USDC to {} ({} today)",
+            amount,
+            ctx.accounts.destination.key(),
+            withdrawn
+        );
+
+        Ok(())
+    }
+
+    /// Queue a withdrawal of any size. It can be executed once the
+    /// treasury's timelock has passed, with the guardian co-signing, and
+    /// cancelled by either of them until then.
+    ///
+    /// # Arguments
+    /// * `amount` — USDC base units
+    pub fn propose_treasury_withdrawal(
+        ctx: Context<ProposeTreasuryWithdrawal>,
+        amount: u64,
+    ) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+
+        let now = Clock::get()?.unix_timestamp;
+        let config = &mut ctx.accounts.treasury_config;
+        let id = config.next_withdrawal_id;
+        config.next_withdrawal_id += 1;
+
+        let withdrawal = &mut ctx.accounts.withdrawal;
+        withdrawal.id = id;
+        withdrawal.amount = amount;
+        withdrawal.destination = ctx.accounts.destination.key();
+        withdrawal.proposed_at = now;
+        withdrawal.executable_at = now + config.timelock;
+        withdrawal.bump = ctx.bumps.withdrawal;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ProposeTreasuryWithdrawal,
+            withdrawal.key(),
+            id,
+            amount,
+        )?;
+
+        msg!(
+            "Treasury withdrawal {} proposed: {} USDC to {}, executable at {}",
+            id,
+            amount,
+            withdrawal.destination,
+            withdrawal.executable_at
+        );
+
+        Ok(())
+    }
+
+    /// Authority and guardian together: pay out a proposed withdrawal
+    /// whose timelock has passed. It counts towards the day's outflows
+    /// like any other spend, but with the guardian signing it may take
+    /// them past the daily limit. The proposal is closed to the authority.
+    pub fn execute_treasury_withdrawal(ctx: Context<ExecuteTreasuryWithdrawal>) -> Result<()> {
+        let withdrawal = &ctx.accounts.withdrawal;
+        require!(
+            Clock::get()?.unix_timestamp >= withdrawal.executable_at,
+            KisanError::WithdrawalTimelocked
+        );
+
+        pay_from_treasury(
+            &mut ctx.accounts.treasury_config,
+            Some(&ctx.accounts.guardian),
+            &ctx.accounts.token_program,
+            &ctx.accounts.treasury_usdc,
+            &ctx.accounts.destination,
+            &ctx.accounts.treasury_authority,
+            ctx.bumps.treasury_authority,
+            withdrawal.amount,
+        )?;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ExecuteTreasuryWithdrawal,
+            withdrawal.key(),
+            withdrawal.id,
+            withdrawal.amount,
+        )?;
+
+        msg!(
+            "Treasury withdrawal {} executed: {} USDC to {}",
+            withdrawal.id,
+            withdrawal.amount,
+            withdrawal.destination
+        );
+
+        Ok(())
+    }
+
+    /// Authority or guardian: drop a proposed withdrawal. Rent returns to
+    /// the authority, who paid it.
+    pub fn cancel_treasury_withdrawal(ctx: Context<CancelTreasuryWithdrawal>) -> Result<()> {
+        let withdrawal = &ctx.accounts.withdrawal;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.signer,
+            AdminAction::CancelTreasuryWithdrawal,
+            withdrawal.key(),
+            withdrawal.id,
+            withdrawal.amount,
+        )?;
+
+        msg!("Treasury withdrawal {} cancelled", withdrawal.id);
+
+        Ok(())
+    }
+
+    /// Open the bootstrap bonding curve. Parameters are fixed for the
+    /// curve's lifetime.
+    ///
+    /// # Arguments
+    /// * `base_price` — Price of the first $GREEN, in USDC base units
+    /// * `slope` — Price increase per whole $GREEN sold, in USDC base units
+    /// * `max_supply` — Most $GREEN (base units) the curve may sell
+    pub fn initialize_curve(
+        ctx: Context<InitializeCurve>,
+        base_price: u64,
+        slope: u64,
+        max_supply: u64,
+    ) -> Result<()> {
+        require!(base_price > 0 && max_supply > 0, KisanError::InvalidCurveConfig);
+        require!(
+            curve::buy_cost(base_price, slope, 0, max_supply).is_some(),
+            KisanError::InvalidCurveConfig
+        );
+
+        let curve = &mut ctx.accounts.bonding_curve;
+        curve.usdc_mint = ctx.accounts.usdc_mint.key();
+        curve.base_price = base_price;
+        curve.slope = slope;
+        curve.max_supply = max_supply;
+        curve.active = true;
+        curve.bump = ctx.bumps.bonding_curve;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeCurve,
+            curve.usdc_mint,
+            0,
+            max_supply,
+        )?;
+
+        msg!(
+            "Bonding curve opened: base {} + {} per $GREEN, up to {}",
+            base_price,
+            slope,
+            max_supply
+        );
+
+        Ok(())
+    }
+
+    /// Buy `amount` $GREEN base units from the curve, paying at most
+    /// `max_cost` USDC into the reserve.
+    pub fn buy_from_curve(ctx: Context<BuyFromCurve>, amount: u64, max_cost: u64) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+        let curve = &ctx.accounts.bonding_curve;
+        require!(curve.active, KisanError::CurveClosed);
+        let sold = curve
+            .sold
+            .checked_add(amount)
+            .filter(|&sold| sold <= curve.max_supply)
+            .ok_or(KisanError::CurveSoldOut)?;
+        let cost = curve::buy_cost(curve.base_price, curve.slope, curve.sold, amount)
+            .ok_or(KisanError::InvalidCurveConfig)?;
+        if cost > max_cost {
+            return Err(report::exceeded(KisanError::SlippageExceeded, cost, max_cost));
+        }
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.buyer_usdc_account.to_account_info(),
+                    to: ctx.accounts.curve_reserve.to_account_info(),
+                    authority: ctx.accounts.buyer.to_account_info(),
+                },
+            ),
+            cost,
+        )?;
+
+        mint_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.green_mint,
+            &ctx.accounts.buyer_green_account,
+            &ctx.accounts.program_state,
+            amount,
+        )?;
+
+        let curve = &mut ctx.accounts.bonding_curve;
+        curve.sold = sold;
+        curve.reserve += cost;
+
+        msg!("Curve buy: {} $GREEN for {} USDC", amount, cost);
+
+        Ok(())
+    }
+
+    /// Sell `amount` $GREEN base units back to the curve for at least
+    /// `min_proceeds` USDC from the reserve. The $GREEN is burned.
+    pub fn sell_to_curve(
+        ctx: Context<SellToCurve>,
+        amount: u64,
+        min_proceeds: u64,
+    ) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+        let curve = &ctx.accounts.bonding_curve;
+        require!(curve.active, KisanError::CurveClosed);
+        let proceeds = curve::sell_proceeds(curve.base_price, curve.slope, curve.sold, amount)
+            .ok_or(KisanError::CurveSoldOut)?
+            .min(curve.reserve);
+        if proceeds < min_proceeds {
+            return Err(report::exceeded(KisanError::SlippageExceeded, proceeds, min_proceeds));
+        }
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.green_mint.to_account_info(),
+                    from: ctx.accounts.seller_green_account.to_account_info(),
+                    authority: ctx.accounts.seller.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let state_bump = ctx.accounts.program_state.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[state_bump]]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.curve_reserve.to_account_info(),
+                    to: ctx.accounts.seller_usdc_account.to_account_info(),
+                    authority: ctx.accounts.program_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            proceeds,
+        )?;
+
+        let curve = &mut ctx.accounts.bonding_curve;
+        curve.sold -= amount;
+        curve.reserve -= proceeds;
+
+        msg!("Curve sell: {} $GREEN for {} USDC", amount, proceeds);
+
+        Ok(())
+    }
+
+    /// Open an offtake subscription. Each epoch the buyer pays
+    /// `rate_per_epoch` USDC from escrow and receives `share_bps` of the
+    /// credits issued to farmers during that epoch, bought from farmers'
+    /// offtake offers. Shares across all subscriptions may not exceed 100%.
+    ///
+    /// The escrow can be topped up by transferring USDC to it directly.
+    ///
+    /// # Arguments
+    /// * `rate_per_epoch` — USDC base units paid per settled epoch
+    /// * `share_bps` — Share of each epoch's issuance delivered to the buyer
+    /// * `epoch_seconds` — Settlement interval
+    /// * `usdc_escrow` — Initial USDC deposit
+    pub fn create_subscription(
+        ctx: Context<CreateSubscription>,
+        rate_per_epoch: u64,
+        share_bps: u16,
+        epoch_seconds: i64,
+        usdc_escrow: u64,
+    ) -> Result<()> {
+        require!(
+            rate_per_epoch > 0 && share_bps > 0 && epoch_seconds > 0,
+            KisanError::InvalidSubscription
+        );
+        let state = &mut ctx.accounts.program_state;
+        let total_share = state.offtake_share_bps + share_bps;
+        require!(
+            total_share as u64 <= BPS_DENOMINATOR,
+            KisanError::OfftakeOversubscribed
+        );
+        state.offtake_share_bps = total_share;
+
+        if usdc_escrow > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.buyer_usdc_account.to_account_info(),
+                        to: ctx.accounts.subscription_escrow.to_account_info(),
+                        authority: ctx.accounts.buyer.to_account_info(),
+                    },
+                ),
+                usdc_escrow,
+            )?;
+        }
+
+        let now = Clock::get()?.unix_timestamp;
+        let sub = &mut ctx.accounts.subscription;
+        sub.buyer = ctx.accounts.buyer.key();
+        sub.rate_per_epoch = rate_per_epoch;
+        sub.share_bps = share_bps;
+        sub.epoch_seconds = epoch_seconds;
+        sub.next_settlement_at = now + epoch_seconds;
+        sub.issued_snapshot = ctx.accounts.program_state.next_serial;
+        sub.bump = ctx.bumps.subscription;
+
+        msg!(
+            "Subscription opened: {} USDC per {}s for {} bps of issuance",
+            rate_per_epoch,
+            epoch_seconds,
+            share_bps
+        );
+
+        Ok(())
+    }
+
+    /// Permissionless crank: settle the epochs elapsed since a
+    /// subscription's last settlement. The buyer pays `rate_per_epoch` for
+    /// every one of them and receives its share of the credits farmers
+    /// were issued meanwhile. No $GREEN is minted: the credits come out of
+    /// one farmer's offtake offer, and that farmer is paid the escrowed
+    /// USDC less the protocol fee. Fails if the escrow can't cover every
+    /// elapsed epoch or the offer holds too few credits, so the crank
+    /// picks an offer that can fill the settlement.
+    pub fn settle_subscription(ctx: Context<SettleSubscription>) -> Result<()> {
+        let sub = &ctx.accounts.subscription;
+        let (epochs, due) = sub.amount_due(Clock::get()?.unix_timestamp)?;
+        let escrowed = ctx.accounts.subscription_escrow.amount;
+        if due > escrowed {
+            return Err(report::exceeded(
+                KisanError::SubscriptionUnderfunded,
+                due,
+                escrowed,
+            ));
+        }
+        let issued = ctx.accounts.program_state.next_serial;
+        let credits = sub.credits_due(issued);
+        require!(credits > 0, KisanError::NoOfftakeIssuance);
+        let offered = ctx.accounts.offtake_vault.amount;
+        if credits > offered {
+            return Err(report::exceeded(
+                KisanError::OfftakeOfferShort,
+                credits,
+                offered,
+            ));
+        }
+        let protocol_fee = (due as u128 * ctx.accounts.treasury_config.market_fee_bps as u128
+            / BPS_DENOMINATOR as u128) as u64;
+        let proceeds = due - protocol_fee;
+
+        let state_bump = ctx.accounts.program_state.bump;
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[state_bump]]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.subscription_escrow.to_account_info(),
+                    to: ctx.accounts.farmer_usdc_account.to_account_info(),
+                    authority: ctx.accounts.program_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            proceeds,
+        )?;
+        if protocol_fee > 0 {
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.subscription_escrow.to_account_info(),
+                        to: ctx.accounts.treasury_usdc.to_account_info(),
+                        authority: ctx.accounts.program_state.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                protocol_fee,
+            )?;
+            ctx.accounts.treasury_config.total_fees += protocol_fee;
+        }
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.offtake_vault.to_account_info(),
+                    to: ctx.accounts.buyer_green_account.to_account_info(),
+                    authority: ctx.accounts.program_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            credits,
+        )?;
+
+        ctx.accounts.program_state.total_offtake_delivered += credits;
+        let offer = &mut ctx.accounts.offtake_offer;
+        offer.total_sold += credits;
+        offer.total_earned += proceeds;
+        let sub = &mut ctx.accounts.subscription;
+        sub.issued_snapshot = issued;
+        sub.next_settlement_at += epochs * sub.epoch_seconds;
+        sub.total_paid += due;
+        sub.total_delivered += credits;
+
+        msg!(
+            "Subscription settled: {} USDC for {} $GREEN over {} epochs from {}",
+            due,
+            credits,
+            epochs,
+            offer.farmer
+        );
+
+        Ok(())
+    }
+
+    /// End a subscription, refunding unspent escrow to the buyer and
+    /// freeing its issuance share.
+    pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
+        release_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.subscription_escrow,
+            &ctx.accounts.buyer_usdc_account,
+            &ctx.accounts.buyer.to_account_info(),
+            &ctx.accounts.program_state,
+        )?;
+
+        ctx.accounts.program_state.offtake_share_bps -= ctx.accounts.subscription.share_bps;
+        msg!("Subscription cancelled for {}", ctx.accounts.buyer.key());
+
+        Ok(())
+    }
+
+    /// Offer $GREEN to offtake subscribers. The credits wait in the
+    /// farmer's offtake vault until a `settle_subscription` delivers them
+    /// and pays the farmer for them. Only registered farmers can offer.
+    ///
+    /// # Arguments
+    /// * `amount` — $GREEN base units added to the offer
+    pub fn offer_to_offtake(ctx: Context<OfferToOfftake>, amount: u64) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.farmer_green_account.to_account_info(),
+                    to: ctx.accounts.offtake_vault.to_account_info(),
+                    authority: ctx.accounts.farmer.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let offer = &mut ctx.accounts.offtake_offer;
+        offer.farmer = ctx.accounts.farmer.key();
+        offer.bump = ctx.bumps.offtake_offer;
+
+        msg!(
+            "Offtake offer from {}: {} $GREEN added",
+            offer.farmer,
+            amount
+        );
+
+        Ok(())
+    }
+
+    /// Take back a farmer's unsold offtake credits and close the offer.
+    pub fn withdraw_offtake_offer(ctx: Context<WithdrawOfftakeOffer>) -> Result<()> {
+        let amount = ctx.accounts.offtake_vault.amount;
+        release_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.offtake_vault,
+            &ctx.accounts.farmer_green_account,
+            &ctx.accounts.farmer.to_account_info(),
+            &ctx.accounts.program_state,
+        )?;
+
+        msg!(
+            "Offtake offer withdrawn: {} $GREEN returned to {}",
+            amount,
+            ctx.accounts.farmer.key()
+        );
+
+        Ok(())
+    }
+
+    /// Attach or correct the evidence CID of a claim. Only the farmer may
+    /// do this, and only within `EVIDENCE_UPDATE_WINDOW` of verification,
+    /// so auditors can rely on evidence not changing after review.
+    pub fn update_evidence(
+        ctx: Context<UpdateEvidence>,
+        evidence_cid: [u8; EVIDENCE_CID_LEN],
+    ) -> Result<()> {
+        let record = &mut ctx.accounts.proof_record;
+        require!(
+            Clock::get()?.unix_timestamp < record.timestamp + EVIDENCE_UPDATE_WINDOW,
+            KisanError::EvidenceWindowClosed
+        );
+        record.evidence_cid = evidence_cid;
+
+        msg!("Evidence updated for commitment {:?}", &record.commitment[..8]);
+
+        Ok(())
+    }
+
+    /// View: confirm that `serial` was issued by `proof_record` and return
+    /// its registry details. Clients locate the record off-chain (serial
+    /// ranges are contiguous and increasing) and simulate this call.
+    pub fn lookup_serial(ctx: Context<LookupSerial>, serial: u64) -> Result<SerialInfo> {
+        let record = &ctx.accounts.proof_record;
+        require!(
+            serial >= record.serial_start && serial < record.serial_end,
+            KisanError::SerialNotInRecord
+        );
+
+        msg!(
+            "Serial {} — vintage {}, methodology {}, farmer {}",
+            serial,
+            record.vintage,
+            record.methodology_id,
+            record.farmer
+        );
+
+        Ok(SerialInfo {
+            commitment: record.commitment,
+            farmer: record.farmer,
+            vintage: record.vintage,
+            methodology_id: record.methodology_id,
+            serial_start: record.serial_start,
+            serial_end: record.serial_end,
+            claim_id: record.claim_id,
+        })
+    }
+
+    /// dMRV step 1 — submit a measurement claim for later verification.
+    ///
+    /// # Arguments
+    /// * `commitment` — Compliance commitment the claim is about
+    /// * `circuit_id` — Circuit the claim will be verified and paid under
+    pub fn submit_claim(
+        ctx: Context<SubmitClaim>,
+        commitment: [u8; 32],
+        circuit_id: u16,
+    ) -> Result<()> {
+        require_pm_kisan_beneficiary(
+            &ctx.accounts.program_state,
+            ctx.accounts.pm_kisan_attestation.as_deref(),
+        )?;
+
+        let claim = &mut ctx.accounts.claim;
+        claim.farmer = ctx.accounts.farmer.key();
+        claim.commitment = commitment;
+        claim.circuit_id = circuit_id;
+        claim.status = ClaimStatus::Submitted;
+        claim.submitted_at = Clock::get()?.unix_timestamp;
+        claim.bump = ctx.bumps.claim;
+
+        emit!(ClaimSubmitted {
+            claim: claim.key(),
+            farmer: claim.farmer,
+            commitment,
+            circuit_id,
+        });
+
+        Ok(())
+    }
+
+    /// dMRV step 2 — verify a submitted claim, either with a ZK proof over
+    /// its commitment (anyone may submit it) or, when `proof` is `None`, by
+    /// the signature of a whitelisted attester.
+    pub fn verify_claim(ctx: Context<VerifyClaim>, proof: Option<Groth16Proof>) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        require!(claim.status == ClaimStatus::Submitted, KisanError::InvalidClaimStatus);
+
+        let verified_by = match proof {
+            Some(proof) => {
+                proof.verify(
+                    &ctx.accounts.circuit_config,
+                    ctx.accounts.groth16_vk.as_deref(),
+                    ctx.accounts.previous_groth16_vk.as_deref(),
+                    &claim.commitment,
+                )?;
+                crate::ID
+            }
+            None => {
+                require!(
+                    ctx.accounts.attester_info.is_some(),
+                    KisanError::UnauthorizedAttester
+                );
+                ctx.accounts.verifier.key()
+            }
+        };
+
+        claim.status = ClaimStatus::Verified;
+        claim.verified_by = verified_by;
+        claim.verified_at = Clock::get()?.unix_timestamp;
+
+        emit!(ClaimVerified {
+            claim: claim.key(),
+            verified_by,
+        });
+
+        Ok(())
+    }
+
+    /// dMRV step 2, quorum variant — verify a submitted claim by the
+    /// signatures of at least `NodeRewardConfig::quorum` whitelisted
+    /// attesters. The other attesters co-sign the same transaction and are
+    /// passed as (wallet, `AttesterInfo`, `NodeRewards`) triples in
+    /// remaining accounts. The nodes are recorded in a `ClaimAttestation`,
+    /// which becomes the claim's `verified_by`; `issue_credits` pays them
+    /// their share of the claim's issuance.
+    pub fn verify_claim_with_quorum<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyClaimWithQuorum<'info>>,
+    ) -> Result<()> {
+        let nodes = check_attestation_quorum(
+            ctx.accounts.attester.key(),
+            ctx.remaining_accounts,
+            ctx.accounts.node_reward_config.quorum,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let claim = &mut ctx.accounts.claim;
+        require!(claim.status == ClaimStatus::Submitted, KisanError::InvalidClaimStatus);
+
+        let attestation = &mut ctx.accounts.claim_attestation;
+        attestation.claim = claim.key();
+        attestation.nodes = nodes;
+        attestation.attested_at = now;
+        attestation.bump = ctx.bumps.claim_attestation;
+
+        let verified_by = attestation.key();
+        claim.status = ClaimStatus::Verified;
+        claim.verified_by = verified_by;
+        claim.verified_at = now;
+
+        emit!(ClaimVerified {
+            claim: claim.key(),
+            verified_by,
+        });
+
+        Ok(())
+    }
+
+    /// Create or update the share of each quorum-verified issuance paid to
+    /// the attesting nodes, and the attester quorum that path requires.
+    /// The share is minted into a program-owned vault and credited to the
+    /// nodes evenly; they withdraw it with `claim_node_rewards`.
+    ///
+    /// # Arguments
+    /// * `share_bps` — Share of the claim's reward, at most `MAX_NODE_SHARE_BPS`
+    /// * `quorum` — Distinct attesters that must sign `verify_claim_with_quorum`
+    pub fn configure_node_rewards(
+        ctx: Context<ConfigureNodeRewards>,
+        share_bps: u16,
+        quorum: u8,
+    ) -> Result<()> {
+        if share_bps > MAX_NODE_SHARE_BPS {
+            return Err(report::exceeded(
+                KisanError::NodeShareTooHigh,
+                share_bps,
+                MAX_NODE_SHARE_BPS,
+            ));
+        }
+        require!(quorum > 0, KisanError::InvalidAttestationQuorum);
+        if quorum as usize > MAX_ATTESTATION_QUORUM {
+            return Err(report::exceeded(
+                KisanError::InvalidAttestationQuorum,
+                quorum,
+                MAX_ATTESTATION_QUORUM,
+            ));
+        }
+
+        let old_bps = ctx.accounts.node_reward_config.share_bps;
+        let config = &mut ctx.accounts.node_reward_config;
+        config.share_bps = share_bps;
+        config.quorum = quorum;
+        config.bump = ctx.bumps.node_reward_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigureNodeRewards,
+            ctx.accounts.node_reward_vault.key(),
+            old_bps as u64,
+            share_bps as u64,
+        )?;
+
+        msg!("Node rewards: {} bps, quorum {}", share_bps, quorum);
+
+        Ok(())
+    }
+
+    /// Attester: open the account node rewards accrue to. Required before
+    /// the attester can sign a quorum verification.
+    pub fn open_node_rewards(ctx: Context<OpenNodeRewards>) -> Result<()> {
+        let rewards = &mut ctx.accounts.node_rewards;
+        rewards.node = ctx.accounts.node.key();
+        rewards.bump = ctx.bumps.node_rewards;
+        Ok(())
+    }
+
+    /// Attester: withdraw the $GREEN accrued from claims their quorums
+    /// verified.
+    pub fn claim_node_rewards(ctx: Context<ClaimNodeRewards>) -> Result<()> {
+        let amount = ctx.accounts.node_rewards.unclaimed;
+        require!(amount > 0, KisanError::NoNodeRewardsToClaim);
+
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[ctx.accounts.program_state.bump]]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.node_reward_vault.to_account_info(),
+                    to: ctx.accounts.node_token_account.to_account_info(),
+                    authority: ctx.accounts.program_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+        ctx.accounts.node_rewards.unclaimed = 0;
+
+        emit!(NodeRewardsClaimed {
+            node: ctx.accounts.node.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// Verify a farmer's ZK compliance proof without minting, for partner
+    /// programs (lending, insurance) to call via CPI. The farmer must sign.
+    ///
+    /// Records the commitment as a Verified claim — its nullifier, so the
+    /// proof can't be verified again — and returns `true` as return data.
+    /// Minting is a separate step: `issue_credits` consumes the claim.
+    pub fn verify_proof(
+        ctx: Context<VerifyProof>,
+        commitment: [u8; 32],
+        circuit_id: u16,
+        proof: Groth16Proof,
+    ) -> Result<bool> {
+        proof.verify(
+            &ctx.accounts.circuit_config,
+            Some(&ctx.accounts.groth16_vk),
+            ctx.accounts.previous_groth16_vk.as_deref(),
+            &commitment,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let claim = &mut ctx.accounts.claim;
+        claim.farmer = ctx.accounts.farmer.key();
+        claim.commitment = commitment;
+        claim.circuit_id = circuit_id;
+        claim.status = ClaimStatus::Verified;
+        claim.submitted_at = now;
+        claim.verified_by = crate::ID;
+        claim.verified_at = now;
+        claim.bump = ctx.bumps.claim;
+
+        emit!(ClaimSubmitted {
+            claim: claim.key(),
+            farmer: claim.farmer,
+            commitment,
+            circuit_id,
+        });
+        emit!(ClaimVerified {
+            claim: claim.key(),
+            verified_by: crate::ID,
+        });
+
+        Ok(true)
+    }
+
+    /// Turn Wormhole attestations on or off and set the core bridge
+    /// program for this cluster. Devnet deployments can leave it off.
+    pub fn set_cross_chain_config(
+        ctx: Context<SetCrossChainConfig>,
+        enabled: bool,
+        wormhole_program: Pubkey,
+    ) -> Result<()> {
+        let was_enabled = ctx.accounts.program_state.cross_chain_enabled;
+        let state = &mut ctx.accounts.program_state;
+        state.cross_chain_enabled = enabled;
+        state.wormhole_program = wormhole_program;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetCrossChainConfig,
+            wormhole_program,
+            was_enabled as u64,
+            enabled as u64,
+        )?;
+
+        msg!(
+            "Cross-chain attestations {} (core bridge {})",
+            if enabled { "enabled" } else { "disabled" },
+            wormhole_program
+        );
+
+        Ok(())
+    }
+
+    /// Publish an issuance over Wormhole: commitment, farmer, amount and
+    /// serial range of a ProofRecord, for buyers on other chains. Run it
+    /// after the mint, typically in the same transaction. Each record can
+    /// be attested once, as the message account is derived from the
+    /// commitment. The payer covers the bridge fee.
+    pub fn emit_cross_chain_attestation(ctx: Context<EmitCrossChainAttestation>) -> Result<()> {
+        require!(
+            ctx.accounts.program_state.cross_chain_enabled,
+            KisanError::CrossChainDisabled
+        );
+
+        let fee = wormhole::message_fee(&ctx.accounts.wormhole_bridge)?;
+        if fee > 0 {
+            anchor_lang::system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    anchor_lang::system_program::Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: ctx.accounts.wormhole_fee_collector.to_account_info(),
+                    },
+                ),
+                fee,
+            )?;
+        }
+
+        let record = &ctx.accounts.proof_record;
+        let emitter_bump = ctx.bumps.wormhole_emitter;
+        let message_bump = ctx.bumps.wormhole_message;
+        let signer_seeds: &[&[&[u8]]] = &[
+            &[WORMHOLE_EMITTER_SEED, &[emitter_bump]],
+            &[WORMHOLE_MESSAGE_SEED, record.commitment.as_ref(), &[message_bump]],
+        ];
+
+        wormhole::post_message(
+            wormhole::PostMessageAccounts {
+                bridge: &ctx.accounts.wormhole_bridge,
+                message: &ctx.accounts.wormhole_message,
+                emitter: &ctx.accounts.wormhole_emitter,
+                sequence: &ctx.accounts.wormhole_sequence,
+                payer: &ctx.accounts.payer,
+                fee_collector: &ctx.accounts.wormhole_fee_collector,
+                clock: &ctx.accounts.clock.to_account_info(),
+                rent: &ctx.accounts.rent.to_account_info(),
+                system_program: &ctx.accounts.system_program.to_account_info(),
+                wormhole_program: &ctx.accounts.wormhole_program,
+            },
+            0,
+            record.attestation_payload(),
+            wormhole::CONSISTENCY_FINALIZED,
+            signer_seeds,
+        )?;
+
+        msg!(
+            "Cross-chain attestation posted: serials {}..{}",
+            record.serial_start,
+            record.serial_end
+        );
+
+        Ok(())
+    }
+
+    /// Whitelist (or update) the Wormhole emitter allowed to send registry
+    /// claims from `chain`.
+    pub fn register_foreign_emitter(
+        ctx: Context<RegisterForeignEmitter>,
+        chain: u16,
+        address: [u8; 32],
+    ) -> Result<()> {
+        let emitter = &mut ctx.accounts.foreign_emitter;
+        emitter.chain = chain;
+        emitter.address = address;
+        emitter.bump = ctx.bumps.foreign_emitter;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RegisterForeignEmitter,
+            Pubkey::new_from_array(address),
+            0,
+            chain as u64,
+        )?;
+
+        msg!("Foreign emitter registered for chain {}", chain);
+
+        Ok(())
+    }
+
+    /// Mint against a claim attested by a partner registry on another
+    /// chain. The VAA must already be posted (signature-verified) by the
+    /// core bridge and come from the chain's whitelisted emitter.
+    ///
+    /// Replay protection is twofold: a receipt PDA per VAA hash, and the
+    /// payload's commitment claims the same ProofRecord as local proofs.
+    /// The amount still counts against the season's mint cap.
+    ///
+    /// # Arguments
+    /// * `vaa_hash` — Hash of the VAA body, which derives the PostedVAA account
+    /// * `commitment` — Commitment carried in the payload
+    pub fn mint_from_vaa(
+        ctx: Context<MintFromVaa>,
+        vaa_hash: [u8; 32],
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        ctx.accounts.proof_record.ensure_unused()?;
+        let state = &ctx.accounts.program_state;
+        require!(state.cross_chain_enabled, KisanError::CrossChainDisabled);
+
+        let vaa = wormhole::load_posted_vaa(
+            &ctx.accounts.posted_vaa,
+            &state.wormhole_program,
+            &vaa_hash,
+        )?;
+        let emitter = &ctx.accounts.foreign_emitter;
+        require!(
+            vaa.emitter_chain == emitter.chain && vaa.emitter_address == emitter.address,
+            KisanError::UnknownEmitter
+        );
+
+        let claim = RegistryClaim::parse(&vaa.payload)?;
+        require!(claim.commitment == commitment, KisanError::InvalidVaa);
+        require_keys_eq!(
+            ctx.accounts.farmer_token_account.owner,
+            claim.farmer,
+            KisanError::InvalidVaa
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let amount = ctx.accounts.season.record_claim(now, claim.amount, 0)?;
+
+        let proof_record = &mut ctx.accounts.proof_record;
+        proof_record.commitment = commitment;
+        proof_record.farmer = claim.farmer;
+        proof_record.timestamp = now;
+        proof_record.verified = true;
+        proof_record.rent_payer = ctx.accounts.payer.key();
+        proof_record.bump = ctx.bumps.proof_record;
+        proof_record.version = ACCOUNT_VERSION;
+        proof_record.season = ctx.accounts.season.index;
+        proof_record.vintage = claim.vintage;
+        proof_record.methodology_id = claim.methodology_id;
+        (proof_record.serial_start, proof_record.serial_end) =
+            ctx.accounts.program_state.allocate_serials(amount);
+        proof_record.claim_id = ctx.accounts.program_state.next_claim_id();
+
+        mint_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.green_mint,
+            &ctx.accounts.farmer_token_account,
+            &ctx.accounts.program_state,
+            amount,
+        )?;
+
+        let receipt = &mut ctx.accounts.vaa_receipt;
+        receipt.vaa_hash = vaa_hash;
+        receipt.emitter_chain = vaa.emitter_chain;
+        receipt.sequence = vaa.sequence;
+        receipt.commitment = commitment;
+        receipt.redeemed_at = now;
+        receipt.bump = ctx.bumps.vaa_receipt;
+
+        let state = &mut ctx.accounts.program_state;
+        state.total_proofs_verified += 1;
+        state.total_tokens_minted += amount;
+
+        msg!(
+            "Minted {} from chain {} VAA #{} to {} as claim #{}",
+            amount,
+            vaa.emitter_chain,
+            vaa.sequence,
+            claim.farmer,
+            ctx.accounts.proof_record.claim_id
+        );
+
+        Ok(())
+    }
+
+    /// Verify one recursive proof covering a batch of claims. Its public
+    /// signals commit to a Merkle root of `(farmer, commitment, amount)`
+    /// leaves; nothing is minted until each farmer redeems their leaf with
+    /// `redeem_aggregate_leaf`. Anyone (typically the proving service) may
+    /// submit the batch.
+    ///
+    /// Only PLONK and external-verifier circuits are accepted, since the
+    /// amounts are taken from the proof.
+    ///
+    /// # Arguments
+    /// * `batch_root` — Merkle root of the batch's leaves
+    /// * `payload` — Serialized recursive proof; its public signals are
+    ///   32-byte big-endian words: batch_root | leaf_count
+    pub fn verify_aggregate_and_mint<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyAggregateAndMint<'info>>,
+        batch_root: [u8; 32],
+        payload: ProofPayload,
+    ) -> Result<()> {
+        ctx.accounts.feature_flags.require_enabled(FEATURE_BATCHING)?;
+        let circuit = &ctx.accounts.circuit_config;
+        require!(circuit.active, KisanError::CircuitInactive);
+
+        let proof = payload.decode(circuit.proof_system)?;
+        require!(
+            matches!(proof, Proof::Serialized(_)),
+            KisanError::ProofSystemUnsupported
+        );
+        let signals = AggregateSignals::parse(&payload.public_signals)?;
+        require!(signals.batch_root == batch_root, KisanError::InvalidPublicSignals);
+        verify_circuit_proof(
+            circuit,
+            None,
+            None,
+            ctx.accounts.plonk_vk.as_deref(),
+            ctx.accounts.previous_plonk_vk.as_deref(),
+            ctx.accounts.verifier_program.as_ref(),
+            ctx.remaining_accounts,
+            &proof,
+            &payload.public_signals,
+        )?;
+
+        let batch = &mut ctx.accounts.aggregate_batch;
+        batch.batch_root = batch_root;
+        batch.circuit_id = circuit.circuit_id;
+        batch.leaf_count = signals.leaf_count;
+        batch.submitter = ctx.accounts.payer.key();
+        batch.verified_at = Clock::get()?.unix_timestamp;
+        batch.bump = ctx.bumps.aggregate_batch;
+
+        msg!(
+            "Aggregate proof verified: {} claims on circuit {}",
+            signals.leaf_count,
+            circuit.circuit_id
+        );
+
+        Ok(())
+    }
+
+    /// Redeem the signing farmer's leaf of a verified aggregate batch,
+    /// minting its amount of $GREEN. The leaf's commitment gets a
+    /// ProofRecord like any other claim, so it can be redeemed once and
+    /// never claimed again through another path.
+    ///
+    /// # Arguments
+    /// * `commitment` — Compliance commitment of the leaf
+    /// * `amount` — $GREEN base units committed in the leaf
+    /// * `merkle_proof` — Sibling hashes from the leaf up to the root
+    pub fn redeem_aggregate_leaf(
+        ctx: Context<RedeemAggregateLeaf>,
+        commitment: [u8; 32],
+        amount: u64,
+        merkle_proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        ctx.accounts.proof_record.ensure_unused()?;
+        let farmer = ctx.accounts.farmer.key();
+        require!(
+            merkle_proof.len() <= MAX_AGGREGATE_DEPTH,
+            KisanError::InvalidMerkleProof
+        );
+        let batch = &ctx.accounts.aggregate_batch;
+        require!(
+            batch.leaves_redeemed < batch.leaf_count,
+            KisanError::InvalidMerkleProof
+        );
+        let leaf = AggregateBatch::leaf(&farmer, &commitment, amount);
+        require!(
+            batch.contains(leaf, &merkle_proof),
+            KisanError::InvalidMerkleProof
+        );
+        require!(ctx.accounts.circuit_config.active, KisanError::CircuitInactive);
+        require_region_identity(
+            &ctx.accounts.farmer_profile,
+            &ctx.accounts.region_config,
+            ctx.accounts.identity_attestation.as_deref(),
+        )?;
+        require_pm_kisan_beneficiary(
+            &ctx.accounts.program_state,
+            ctx.accounts.pm_kisan_attestation.as_deref(),
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        ctx.accounts.farmer_profile.record_season_claim(&ctx.accounts.season)?;
+        let amount = ctx.accounts.season.record_claim(now, amount, 0)?;
+        ctx.accounts.season_tally.record(&ctx.accounts.farmer_profile, amount);
+        ctx.accounts.mint_stats.load_mut()?.record(
+            ctx.accounts.farmer_profile.region_code,
+            ctx.accounts.circuit_config.circuit_id,
+            amount,
+        );
+
+        let proof_record = &mut ctx.accounts.proof_record;
+        proof_record.commitment = commitment;
+        proof_record.farmer = farmer;
+        proof_record.timestamp = now;
+        proof_record.verified = true;
+        proof_record.rent_payer = ctx.accounts.payer.key();
+        proof_record.bump = ctx.bumps.proof_record;
+        proof_record.version = ACCOUNT_VERSION;
+        proof_record.season = ctx.accounts.season.index;
+        proof_record.vintage = vintage_year(now);
+        proof_record.methodology_id = ctx.accounts.circuit_config.methodology_id;
+        (proof_record.serial_start, proof_record.serial_end) =
+            ctx.accounts.program_state.allocate_serials(amount);
+        proof_record.claim_id = ctx.accounts.program_state.next_claim_id();
+
+        mint_tokens(
+            &ctx.accounts.token_program,
+            &ctx.accounts.green_mint,
+            &ctx.accounts.farmer_token_account,
+            &ctx.accounts.program_state,
+            amount,
+        )?;
+
+        let batch = &mut ctx.accounts.aggregate_batch;
+        batch.leaves_redeemed += 1;
+        batch.amount_redeemed += amount;
+        ctx.accounts.circuit_config.total_claims += 1;
+
+        ctx.accounts.claim_index.record(
+            &ctx.accounts.farmer_profile,
+            commitment,
+            ctx.bumps.claim_index,
+        );
+        let profile = &mut ctx.accounts.farmer_profile;
+        profile.total_proofs += 1;
+        profile.last_claim_at = now;
+
+        let state = &mut ctx.accounts.program_state;
+        state.total_proofs_verified += 1;
+        state.total_tokens_minted += amount;
+
+        memo::attach(
+            ctx.accounts.memo_program.as_deref(),
+            &memo::ClaimMemo {
+                circuit_id: ctx.accounts.circuit_config.circuit_id,
+                season: ctx.accounts.season.index,
+                region_code: ctx.accounts.farmer_profile.region_code,
+            },
+        )?;
+
+        msg!(
+            "Aggregate leaf {}/{} redeemed: {} to {} as claim #{}",
+            batch.leaves_redeemed,
+            batch.leaf_count,
+            amount,
+            farmer,
+            ctx.accounts.proof_record.claim_id
+        );
+
+        Ok(())
+    }
+
+    /// Whitelist an oracle that signs reports with an Ethereum key.
+    ///
+    /// # Arguments
+    /// * `eth_address` — The oracle's 20-byte Ethereum address
+    pub fn register_evm_oracle(
+        ctx: Context<RegisterEvmOracle>,
+        eth_address: [u8; 20],
+    ) -> Result<()> {
+        let oracle = &mut ctx.accounts.evm_oracle;
+        oracle.eth_address = eth_address;
+        oracle.registered_at = Clock::get()?.unix_timestamp;
+        oracle.bump = ctx.bumps.evm_oracle;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RegisterEvmOracle,
+            oracle.key(),
+            0,
+            0,
+        )?;
+
+        msg!("EVM oracle registered: {:?}", eth_address);
+
+        Ok(())
+    }
+
+    /// Remove an EVM oracle from the whitelist. Rent returns to the authority.
+    pub fn remove_evm_oracle(ctx: Context<RemoveEvmOracle>) -> Result<()> {
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RemoveEvmOracle,
+            ctx.accounts.evm_oracle.key(),
+            0,
+            0,
+        )?;
+
+        msg!("EVM oracle removed: {:?}", ctx.accounts.evm_oracle.eth_address);
+
+        Ok(())
+    }
+
+    /// dMRV step 2, EVM variant — verify a submitted claim with a report
+    /// signed by a whitelisted EVM oracle. The transaction must include a
+    /// Secp256k1 precompile instruction checking the oracle's signature
+    /// over the report; anyone may relay it.
+    ///
+    /// # Arguments
+    /// * `secp_ix_index` — Index of the precompile instruction in the transaction
+    pub fn verify_claim_with_evm_report(
+        ctx: Context<VerifyClaimWithEvmReport>,
+        secp_ix_index: u8,
+    ) -> Result<()> {
+        let signed = secp256k1::load_evm_signed_message(
+            &ctx.accounts.instructions_sysvar,
+            secp_ix_index,
+        )?;
+        let oracle = &ctx.accounts.evm_oracle;
+        require!(
+            signed.eth_address == oracle.eth_address,
+            KisanError::InvalidEvmSignature
+        );
+
+        let report = EvmReport::parse(&signed.message)?;
+        let now = Clock::get()?.unix_timestamp;
+        let claim = &mut ctx.accounts.claim;
+        require!(claim.status == ClaimStatus::Submitted, KisanError::InvalidClaimStatus);
+        require!(
+            report.commitment == claim.commitment
+                && report.farmer == claim.farmer
+                && report.circuit_id == claim.circuit_id,
+            KisanError::InvalidEvmReport
+        );
+        require_ctx!(
+            now <= report.expires_at,
+            KisanError::EvmReportExpired,
+            "EVM report expired at {} (now {})",
+            report.expires_at,
+            now
+        );
+
+        let verified_by = oracle.key();
+        claim.status = ClaimStatus::Verified;
+        claim.verified_by = verified_by;
+        claim.verified_at = now;
+
+        emit!(ClaimVerified {
+            claim: claim.key(),
+            verified_by,
+        });
+
+        Ok(())
+    }
+
+    /// Create or update the NDVI thresholds and reward of a satellite
+    /// imagery circuit. The circuit must already be configured.
+    ///
+    /// # Arguments
+    /// * `provider` — EVM oracle account of the imagery provider
+    /// * `min_ndvi_delta` — Minimum NDVI improvement, ×10⁴
+    /// * `min_window` — Minimum observation window, in seconds
+    /// * `max_observation_age` — Maximum age of the window's end at claim time
+    /// * `reward_amount` — $GREEN per claim, replacing the season reward
+    pub fn configure_imagery(
+        ctx: Context<ConfigureImagery>,
+        provider: Pubkey,
+        min_ndvi_delta: u16,
+        min_window: i64,
+        max_observation_age: i64,
+        reward_amount: u64,
+    ) -> Result<()> {
+        require!(
+            min_window >= 0 && max_observation_age > 0,
+            KisanError::InvalidImageryConfig
+        );
+
+        let old_reward = ctx.accounts.imagery_config.reward_amount;
+        let imagery = &mut ctx.accounts.imagery_config;
+        imagery.circuit_id = ctx.accounts.circuit_config.circuit_id;
+        imagery.provider = provider;
+        imagery.min_ndvi_delta = min_ndvi_delta;
+        imagery.min_window = min_window;
+        imagery.max_observation_age = max_observation_age;
+        imagery.reward_amount = reward_amount;
+        imagery.bump = ctx.bumps.imagery_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigureImagery,
+            provider,
+            old_reward,
+            reward_amount,
+        )?;
+
+        msg!(
+            "Imagery circuit {}: ΔNDVI ≥ {}, reward {}",
+            imagery.circuit_id,
+            min_ndvi_delta,
+            reward_amount
+        );
+
+        Ok(())
+    }
+
+    /// Verify a satellite NDVI proof and mint the circuit's imagery reward.
+    ///
+    /// The public signals carry the NDVI delta and observation window; the
+    /// imagery provider must have signed the same values for `commitment`
+    /// (checked via a Secp256k1 precompile instruction), and the proof
+    /// shows the raw imagery behind them meets the thresholds.
+    ///
+    /// # Arguments
+    /// * `commitment` — Compliance commitment (replay protection)
+    /// * `proof` — Groth16 proof; public signals are 32-byte big-endian words
+    ///   [commitment, ndvi_delta, window_start, window_end]
+    /// * `secp_ix_index` — Index of the precompile instruction in the transaction
+    pub fn verify_imagery_and_mint(
+        ctx: Context<VerifyImageryAndMint>,
+        commitment: [u8; 32],
+        proof: Groth16Proof,
+        secp_ix_index: u8,
+    ) -> Result<()> {
+        ctx.accounts.proof_record.ensure_unused()?;
+        require!(ctx.accounts.circuit_config.active, KisanError::CircuitInactive);
+        require_region_identity(
+            &ctx.accounts.farmer_profile,
+            &ctx.accounts.region_config,
+            ctx.accounts.identity_attestation.as_deref(),
+        )?;
+        require_pm_kisan_beneficiary(
+            &ctx.accounts.program_state,
+            ctx.accounts.pm_kisan_attestation.as_deref(),
+        )?;
+        proof.verify(
+            &ctx.accounts.circuit_config,
+            Some(&ctx.accounts.groth16_vk),
+            ctx.accounts.previous_groth16_vk.as_deref(),
+            &commitment,
+        )?;
+
+        let signals = NdviSignals::parse(&proof.public_signals)?;
+        require!(signals.commitment == commitment, KisanError::InvalidPublicSignals);
+
+        let signed = secp256k1::load_evm_signed_message(
+            &ctx.accounts.instructions_sysvar,
+            secp_ix_index,
+        )?;
+        require!(
+            signed.eth_address == ctx.accounts.provider.eth_address,
+            KisanError::InvalidEvmSignature
+        );
+        require!(
+            signed.message == signals.observation_message(),
+            KisanError::InvalidEvmReport
+        );
+
+        let now = Clock::get()?.unix_timestamp;
+        let imagery = &ctx.accounts.imagery_config;
+        oracle::check_range(signals.ndvi_delta, oracle::NDVI_DELTA_BOUNDS)?;
+        oracle::check_age(signals.window_end, now, imagery.max_observation_age)?;
+        require!(
+            signals.ndvi_delta >= imagery.min_ndvi_delta as u64,
+            KisanError::ImageryThresholdNotMet
+        );
+        require!(
+            signals.window_end >= signals.window_start.saturating_add(imagery.min_window)
+                && signals.window_end <= now,
+            KisanError::ImageryThresholdNotMet
+        );
+
+        let base_reward = imagery.reward_amount;
+        let streak = ctx.accounts.farmer_profile.record_season_claim(&ctx.accounts.season)?;
+        let bonus_bps = ctx.accounts.config.streak_bonus_bps(streak);
+        let reward = ctx.accounts.season.record_claim(now, base_reward, bonus_bps)?;
+        ctx.accounts.season_tally.record(&ctx.accounts.farmer_profile, reward);
+        ctx.accounts.mint_stats.load_mut()?.record(
+            ctx.accounts.farmer_profile.region_code,
+            ctx.accounts.circuit_config.circuit_id,
+            reward,
+        );
+
+        let farmer = ctx.accounts.farmer.key();
+        let proof_record = &mut ctx.accounts.proof_record;
+        proof_record.commitment = commitment;
+        proof_record.farmer = farmer;
+        proof_record.timestamp = now;
+        proof_record.verified = true;
+        proof_record.rent_payer = farmer;
+        proof_record.bump = ctx.bumps.proof_record;
+        proof_record.version = ACCOUNT_VERSION;
+        proof_record.season = ctx.accounts.season.index;
+        proof_record.vintage = vintage_year(now);
+        proof_record.methodology_id = ctx.accounts.circuit_config.methodology_id;
+        (proof_record.serial_start, proof_record.serial_end) =
+            ctx.accounts.program_state.allocate_serials(reward);
+        proof_record.claim_id = ctx.accounts.program_state.next_claim_id();
+
+        if reward > 0 {
+            mint_tokens(
+                &ctx.accounts.token_program,
+                &ctx.accounts.green_mint,
+                &ctx.accounts.farmer_token_account,
+                &ctx.accounts.program_state,
+                reward,
+            )?;
+        }
+
+        ctx.accounts.claim_index.record(
+            &ctx.accounts.farmer_profile,
+            commitment,
+            ctx.bumps.claim_index,
+        );
+        let profile = &mut ctx.accounts.farmer_profile;
+        profile.total_proofs += 1;
+        profile.last_claim_at = now;
+
+        let state = &mut ctx.accounts.program_state;
+        state.total_proofs_verified += 1;
+        state.total_tokens_minted += reward;
+        ctx.accounts.circuit_config.total_claims += 1;
+
+        memo::attach(
+            ctx.accounts.memo_program.as_deref(),
+            &memo::ClaimMemo {
+                circuit_id: ctx.accounts.circuit_config.circuit_id,
+                season: ctx.accounts.season.index,
+                region_code: ctx.accounts.farmer_profile.region_code,
+            },
+        )?;
+
+        msg!(
+            "Imagery claim: ΔNDVI {} over {}s, minted {} to {} as claim #{}",
+            signals.ndvi_delta,
+            signals.window_end - signals.window_start,
+            reward,
+            farmer,
+            ctx.accounts.proof_record.claim_id
+        );
+
+        Ok(())
+    }
+
+    /// Set the stake drone operators must bond to attest claims, and how
+    /// long a withdrawing operator stays slashable.
+    ///
+    /// # Arguments
+    /// * `min_stake` — Minimum bonded stake, in lamports
+    /// * `unbonding_period` — Seconds between `begin_unbonding` and withdrawal
+    pub fn configure_drones(
+        ctx: Context<ConfigureDrones>,
+        min_stake: u64,
+        unbonding_period: i64,
+    ) -> Result<()> {
+        require!(
+            min_stake > 0 && unbonding_period > 0,
+            KisanError::InvalidDroneConfig
+        );
+
+        let old_min_stake = ctx.accounts.drone_config.min_stake;
+        let config = &mut ctx.accounts.drone_config;
+        config.min_stake = min_stake;
+        config.unbonding_period = unbonding_period;
+        config.bump = ctx.bumps.drone_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigureDrones,
+            config.key(),
+            old_min_stake,
+            min_stake,
+        )?;
+
+        msg!(
+            "Drone operators: min stake {} lamports, unbonding {}s",
+            min_stake,
+            unbonding_period
+        );
+
+        Ok(())
+    }
+
+    /// Register a drone operator. It can attest claims once it has bonded
+    /// at least the minimum stake.
+    ///
+    /// # Arguments
+    /// * `operator` — The operator's signing wallet
+    /// * `operator_id` — Operator licence number (e.g. DGCA remote pilot ID)
+    pub fn register_drone_operator(
+        ctx: Context<RegisterDroneOperator>,
+        operator: Pubkey,
+        operator_id: u32,
+    ) -> Result<()> {
+        let info = &mut ctx.accounts.drone_operator;
+        info.operator = operator;
+        info.operator_id = operator_id;
+        info.registered_at = Clock::get()?.unix_timestamp;
+        info.bump = ctx.bumps.drone_operator;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RegisterDroneOperator,
+            operator,
+            0,
+            operator_id as u64,
+        )?;
+
+        msg!("Drone operator {} registered: {}", operator_id, operator);
+
+        Ok(())
+    }
+
+    /// Bond SOL stake to a drone operator account. The stake is held in
+    /// the operator PDA and can be slashed by the authority.
+    pub fn stake_drone_operator(ctx: Context<StakeDroneOperator>, amount: u64) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+        require!(
+            ctx.accounts.drone_operator.unbonding_at == 0,
+            KisanError::OperatorUnbonding
+        );
+
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: ctx.accounts.operator.to_account_info(),
+                    to: ctx.accounts.drone_operator.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let info = &mut ctx.accounts.drone_operator;
+        info.stake += amount;
+        msg!("Drone operator {} staked: {} lamports", info.operator_id, info.stake);
+
+        Ok(())
+    }
+
+    /// dMRV step 2, drone variant — a bonded operator co-signs a submitted
+    /// claim, anchoring the Merkle root of the flight log it was measured
+    /// from. The flight record sits alongside the claim's future proof
+    /// record, under the same commitment.
+    ///
+    /// # Arguments
+    /// * `flight_log_root` — Merkle root of the flight's telemetry and imagery
+    pub fn verify_claim_with_drone(
+        ctx: Context<VerifyClaimWithDrone>,
+        flight_log_root: [u8; 32],
+    ) -> Result<()> {
+        let operator = &ctx.accounts.drone_operator;
+        require!(operator.unbonding_at == 0, KisanError::OperatorUnbonding);
+        if operator.stake < ctx.accounts.drone_config.min_stake {
+            return Err(report::exceeded(
+                KisanError::OperatorStakeTooLow,
+                operator.stake,
+                ctx.accounts.drone_config.min_stake,
+            ));
+        }
+        require!(
+            flight_log_root.iter().any(|&b| b != 0),
+            KisanError::InvalidCommitment
+        );
+
+        let claim = &mut ctx.accounts.claim;
+        require!(claim.status == ClaimStatus::Submitted, KisanError::InvalidClaimStatus);
+
+        let now = Clock::get()?.unix_timestamp;
+        let flight = &mut ctx.accounts.drone_flight;
+        flight.commitment = claim.commitment;
+        flight.operator = operator.key();
+        flight.operator_id = operator.operator_id;
+        flight.flight_log_root = flight_log_root;
+        flight.attested_at = now;
+        flight.bump = ctx.bumps.drone_flight;
+
+        let verified_by = operator.key();
+        claim.status = ClaimStatus::Verified;
+        claim.verified_by = verified_by;
+        claim.verified_at = now;
+
+        ctx.accounts.drone_operator.flights_attested += 1;
+
+        emit!(ClaimVerified {
+            claim: claim.key(),
+            verified_by,
+        });
+
+        Ok(())
+    }
+
+    /// Slash a drone operator's stake for a false attestation. Slashed
+    /// lamports go to the authority (treasury). Operators stay slashable
+    /// while unbonding.
+    pub fn slash_drone_operator(ctx: Context<SlashDroneOperator>, amount: u64) -> Result<()> {
+        let info = &mut ctx.accounts.drone_operator;
+        require!(amount > 0 && amount <= info.stake, KisanError::InvalidAmount);
+
+        info.stake -= amount;
+        info.total_slashed += amount;
+        **info.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SlashDroneOperator,
+            info.operator,
+            info.stake + amount,
+            info.stake,
+        )?;
+
+        msg!(
+            "Drone operator {} slashed {} lamports ({} left)",
+            info.operator_id,
+            amount,
+            info.stake
+        );
+
+        Ok(())
+    }
+
+    /// Stop attesting and start the unbonding period.
+    pub fn begin_unbonding(ctx: Context<BeginUnbonding>) -> Result<()> {
+        let info = &mut ctx.accounts.drone_operator;
+        require!(info.unbonding_at == 0, KisanError::OperatorUnbonding);
+        info.unbonding_at = Clock::get()?.unix_timestamp;
+
+        msg!("Drone operator {} unbonding", info.operator_id);
+
+        Ok(())
+    }
+
+    /// Withdraw the remaining stake once unbonding is complete. Closes the
+    /// operator account; re-registering requires the authority.
+    pub fn withdraw_drone_stake(ctx: Context<WithdrawDroneStake>) -> Result<()> {
+        let info = &ctx.accounts.drone_operator;
+        require!(
+            info.unbonding_at != 0
+                && Clock::get()?.unix_timestamp
+                    >= info.unbonding_at + ctx.accounts.drone_config.unbonding_period,
+            KisanError::UnbondingIncomplete
+        );
+
+        msg!(
+            "Drone operator {} withdrew {} lamports",
+            info.operator_id,
+            info.stake
+        );
+
+        Ok(())
+    }
+
+    /// Close a legacy mandi-reporter whitelist entry. Mandi (APMC market)
+    /// price reporters are now registered with `add_oracle_reporter`; rent
+    /// returns to the authority.
+    pub fn remove_mandi_reporter(ctx: Context<RemoveMandiReporter>) -> Result<()> {
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::RemoveMandiReporter,
+            ctx.accounts.reporter_info.reporter,
+            0,
+            0,
+        )?;
+
+        msg!("Mandi reporter removed: {}", ctx.accounts.reporter_info.reporter);
+
+        Ok(())
+    }
+
+    /// Create the price ring buffer for one commodity in one region.
+    ///
+    /// # Arguments
+    /// * `commodity` — Commodity code (e.g. Agmarknet commodity id)
+    /// * `region` — Region code, as used by `configure_region`
+    pub fn initialize_mandi_feed(
+        ctx: Context<InitializeMandiFeed>,
+        commodity: u16,
+        region: u16,
+    ) -> Result<()> {
+        let mut feed = ctx.accounts.mandi_feed.load_init()?;
+        feed.commodity = commodity;
+        feed.region = region;
+        feed.bump = ctx.bumps.mandi_feed;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeMandiFeed,
+            ctx.accounts.mandi_feed.key(),
+            0,
+            commodity as u64,
+        )?;
+
+        msg!("Mandi feed: commodity {} in region {}", commodity, region);
+
+        Ok(())
+    }
+
+    /// Publish a mandi price, overwriting the oldest report once the
+    /// buffer is full. The signer must be a reporter scoped to mandi
+    /// prices; co-signers meet the quorum as in `report_weather`.
+    ///
+    /// # Arguments
+    /// * `commodity` / `region` — Market the price is for
+    /// * `price` — Modal price in paise per quintal
+    pub fn publish_mandi_price<'info>(
+        ctx: Context<'_, '_, 'info, 'info, PublishMandiPrice<'info>>,
+        commodity: u16,
+        region: u16,
+        price: u64,
+    ) -> Result<()> {
+        require!(price > 0, KisanError::InvalidAmount);
+        check_oracle_quorum(
+            &ctx.accounts.oracle_reporter,
+            ctx.remaining_accounts,
+            ORACLE_SCOPE_MANDI_PRICE,
+            ctx.accounts.oracle_config.mandi_price_quorum,
+        )?;
+
+        let mut feed = ctx.accounts.mandi_feed.load_mut()?;
+        feed.push(MandiPrice {
+            reporter: ctx.accounts.reporter.key(),
+            price,
+            published_at: Clock::get()?.unix_timestamp,
+        });
+
+        msg!(
+            "Mandi price: commodity {} in region {} = {} paise/qtl",
+            commodity,
+            region,
+            price
+        );
+
+        Ok(())
+    }
+
+    /// Anchor the hash of a government land record, signed off-chain by a
+    /// whitelisted attester (a state land-records department). The
+    /// transaction must carry an Ed25519 precompile instruction, directly
+    /// before this one, checking `attester_sig` over
+    /// `program_id | parcel_id_hash | record_hash`; anyone may relay it.
+    ///
+    /// Re-anchoring replaces the hash (e.g. after a mutation entry) but
+    /// keeps any parcel already linked.
+    ///
+    /// # Arguments
+    /// * `parcel_id_hash` — Hash of the record's survey / khasra number
+    /// * `record_hash` — Hash of the record contents
+    /// * `attester_sig` — The attester's Ed25519 signature
+    pub fn anchor_land_record(
+        ctx: Context<AnchorLandRecord>,
+        parcel_id_hash: [u8; 32],
+        record_hash: [u8; 32],
+        attester_sig: [u8; 64],
+    ) -> Result<()> {
+        let signed = ed25519::load_preceding_signed_message(&ctx.accounts.instructions_sysvar)?;
+        let attester = ctx.accounts.attester_info.attester;
+        let mut message = crate::ID.to_bytes().to_vec();
+        message.extend_from_slice(&parcel_id_hash);
+        message.extend_from_slice(&record_hash);
+        require!(
+            signed.signer == attester
+                && signed.signature == attester_sig
+                && signed.message == message,
+            KisanError::InvalidEd25519Signature
+        );
+        require!(
+            record_hash.iter().any(|&b| b != 0),
+            KisanError::InvalidCommitment
+        );
+
+        let record = &mut ctx.accounts.land_record;
+        record.parcel_id_hash = parcel_id_hash;
+        record.record_hash = record_hash;
+        record.attester = attester;
+        record.anchored_at = Clock::get()?.unix_timestamp;
+        record.bump = ctx.bumps.land_record;
+
+        msg!("Land record {:?} anchored by {}", &parcel_id_hash[..8], attester);
+
+        Ok(())
+    }
+
+    /// Require (or stop requiring) parcels in a region to link an anchored
+    /// land record.
+    pub fn set_land_record_requirement(
+        ctx: Context<SetLandRecordRequirement>,
+        required: bool,
+    ) -> Result<()> {
+        let region = &mut ctx.accounts.region_config;
+        let was_required = region.require_land_record;
+        region.require_land_record = required;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetLandRecordRequirement,
+            region.key(),
+            was_required as u64,
+            required as u64,
+        )?;
+
+        msg!("Region {} land record required: {}", region.region_code, required);
+
+        Ok(())
+    }
+
+    /// dMRV step 3 — issue credits for a verified claim. Permissionless:
+    /// credits always go to the claim's farmer. Issuance follows the same
+    /// season, streak and serial rules as `verify_and_mint` and records the
+    /// commitment's ProofRecord, so it can't also be claimed there.
+    ///
+    /// A claim verified by an attester quorum also passes its
+    /// `ClaimAttestation`, the node reward config and vault, and each
+    /// node's `NodeRewards` in remaining accounts, in the attestation's
+    /// order.
+    pub fn issue_credits<'info>(
+        ctx: Context<'_, '_, 'info, 'info, IssueCredits<'info>>,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.claim.status == ClaimStatus::Verified,
+            KisanError::InvalidClaimStatus
+        );
+        issue_claim_credits(ctx.accounts, &ctx.bumps, ctx.remaining_accounts)
+    }
+
+    /// Queue a verified claim the season's mint budget can no longer pay,
+    /// instead of letting it fail. Permissionless; `payer` funds the queue
+    /// entry and gets its rent back when it is processed. Queued claims
+    /// are issued strictly in order by `process_queued_claim` once the
+    /// next season opens, so claims that reach the chain late (farmers on
+    /// poor connections, relayed in batches) are not the ones dropped.
+    pub fn queue_claim(ctx: Context<QueueClaim>) -> Result<()> {
+        let season = &ctx.accounts.season;
+        require_ctx!(
+            season.exhausted(),
+            KisanError::SeasonBudgetAvailable,
+            "Season {} has {} of {} left",
+            season.index,
+            season.mint_cap.saturating_sub(season.total_minted),
+            season.mint_cap
+        );
+        let claim = &mut ctx.accounts.claim;
+        require!(claim.status == ClaimStatus::Verified, KisanError::InvalidClaimStatus);
+        claim.status = ClaimStatus::Queued;
+
+        let queue = &mut ctx.accounts.claim_queue;
+        let position = queue.tail;
+        queue.tail += 1;
+        queue.bump = ctx.bumps.claim_queue;
+
+        let now = Clock::get()?.unix_timestamp;
+        let entry = &mut ctx.accounts.queued_claim;
+        entry.position = position;
+        entry.claim = claim.key();
+        entry.farmer = claim.farmer;
+        entry.season = season.index;
+        entry.queued_at = now;
+        entry.payer = ctx.accounts.payer.key();
+        entry.bump = ctx.bumps.queued_claim;
+
+        emit!(ClaimQueued {
+            claim: claim.key(),
+            farmer: claim.farmer,
+            position,
+            season: season.index,
+        });
+
+        Ok(())
+    }
+
+    /// Crank: issue the claim at the head of the queue. Permissionless,
+    /// and only in a season after the one it was queued in. If the new
+    /// season is exhausted too, the head waits for the next one; claims
+    /// behind it can't jump ahead. Closes the entry to its payer.
+    pub fn process_queued_claim<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ProcessQueuedClaim<'info>>,
+    ) -> Result<()> {
+        let issue = &mut ctx.accounts.issue;
+        require!(
+            issue.claim.status == ClaimStatus::Queued,
+            KisanError::InvalidClaimStatus
+        );
+        let queued_in = ctx.accounts.queued_claim.season;
+        require_ctx!(
+            issue.season.index > queued_in,
+            KisanError::QueuedForNextSeason,
+            "Queued in season {}, current season {}",
+            queued_in,
+            issue.season.index
+        );
+        issue_claim_credits(issue, &ctx.bumps.issue, ctx.remaining_accounts)?;
+        ctx.accounts.claim_queue.head += 1;
+        Ok(())
+    }
+
+    /// Drop the head of the queue when its claim can't be issued (circuit
+    /// retired, farmer wallet compromised), so the claims behind it can
+    /// move. The claim goes back to `Verified` and can still be issued
+    /// with `issue_credits`.
+    pub fn skip_queued_claim(ctx: Context<SkipQueuedClaim>) -> Result<()> {
+        let claim = &mut ctx.accounts.claim;
+        require!(claim.status == ClaimStatus::Queued, KisanError::InvalidClaimStatus);
+        claim.status = ClaimStatus::Verified;
+        let position = ctx.accounts.queued_claim.position;
+        ctx.accounts.claim_queue.head += 1;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SkipQueuedClaim,
+            claim.key(),
+            position,
+            position + 1,
+        )?;
+
+        msg!("Queued claim {} skipped at position {}", claim.key(), position);
+
+        Ok(())
+    }
+
+    /// dMRV step 4 — retire an issued claim's credits. The signer burns the
+    /// claim's full issued amount from their own $GREEN account, so credits
+    /// can be retired by whoever holds them, and the retirement counts
+    /// toward the signer's buyer profile.
+    pub fn retire(ctx: Context<Retire>) -> Result<()> {
+        let claim = &ctx.accounts.claim;
+        require!(claim.status == ClaimStatus::Issued, KisanError::InvalidClaimStatus);
+        let amount = claim.issued_amount;
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.green_mint.to_account_info(),
+                    from: ctx.accounts.holder_token_account.to_account_info(),
+                    authority: ctx.accounts.holder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let claim = &mut ctx.accounts.claim;
+        claim.status = ClaimStatus::Retired;
+        claim.retired_by = ctx.accounts.holder.key();
+        claim.retired_at = now;
+
+        emit!(ClaimRetired {
+            claim: claim.key(),
+            retired_by: claim.retired_by,
+            amount,
+        });
+
+        let profile = &mut ctx.accounts.buyer_profile;
+        profile.open(ctx.accounts.holder.key(), ctx.bumps.buyer_profile, now);
+        profile.total_retired += amount;
+        profile.retirements += 1;
+        profile.emit_activity(BuyerActivityKind::Retirement, claim.key(), amount, 0, now);
+
+        Ok(())
+    }
+
+    /// Freeze a $GREEN token account, for sanctioned holders or accounts
+    /// tied to fraudulent claims. The program state is the mint's freeze
+    /// authority; mints created before it was (no freeze authority) can't
+    /// be frozen.
+    ///
+    /// # Arguments
+    /// * `reason_hash` — Hash of the off-chain case record (sanctions
+    ///   listing, fraud finding); must be non-zero
+    pub fn freeze_account(ctx: Context<FreezeGreenAccount>, reason_hash: [u8; 32]) -> Result<()> {
+        require!(reason_hash != [0; 32], KisanError::MissingReasonHash);
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[ctx.accounts.program_state.bump]]];
+        token::freeze_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            FreezeAccount {
+                account: ctx.accounts.token_account.to_account_info(),
+                mint: ctx.accounts.green_mint.to_account_info(),
+                authority: ctx.accounts.program_state.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        let token_account = &ctx.accounts.token_account;
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::FreezeAccount,
+            token_account.key(),
+            0,
+            token_account.amount,
+        )?;
+
+        emit!(AccountFrozen {
+            token_account: token_account.key(),
+            owner: token_account.owner,
+            amount: token_account.amount,
+            reason_hash,
+            authority: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Thaw a frozen $GREEN token account.
+    ///
+    /// # Arguments
+    /// * `reason_hash` — Hash of the record closing the case; must be
+    ///   non-zero
+    pub fn thaw_account(ctx: Context<FreezeGreenAccount>, reason_hash: [u8; 32]) -> Result<()> {
+        require!(reason_hash != [0; 32], KisanError::MissingReasonHash);
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[ctx.accounts.program_state.bump]]];
+        token::thaw_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            ThawAccount {
+                account: ctx.accounts.token_account.to_account_info(),
+                mint: ctx.accounts.green_mint.to_account_info(),
+                authority: ctx.accounts.program_state.to_account_info(),
+            },
+            signer_seeds,
+        ))?;
+
+        let token_account = &ctx.accounts.token_account;
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ThawAccount,
+            token_account.key(),
+            token_account.amount,
+            0,
+        )?;
+
+        emit!(AccountThawed {
+            token_account: token_account.key(),
+            owner: token_account.owner,
+            reason_hash,
+            authority: ctx.accounts.authority.key(),
+        });
+
+        Ok(())
+    }
+
+    /// Create or update the fraud bounty terms and the $GREEN bounty pool's
+    /// vault.
+    ///
+    /// # Arguments
+    /// * `share_bps` — Share of the clawed amount paid to the reporter of
+    ///   an upheld report, at most `MAX_BOUNTY_BPS`
+    /// * `min_bond` — Least $GREEN a report must bond
+    pub fn configure_bounty(
+        ctx: Context<ConfigureBounty>,
+        share_bps: u16,
+        min_bond: u64,
+    ) -> Result<()> {
+        if share_bps > MAX_BOUNTY_BPS {
+            return Err(report::exceeded(KisanError::BountyTooHigh, share_bps, MAX_BOUNTY_BPS));
+        }
+
+        let old_bps = ctx.accounts.bounty_config.share_bps;
+        let config = &mut ctx.accounts.bounty_config;
+        config.share_bps = share_bps;
+        config.min_bond = min_bond;
+        config.bump = ctx.bumps.bounty_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigureBounty,
+            ctx.accounts.bounty_vault.key(),
+            old_bps as u64,
+            share_bps as u64,
+        )?;
+
+        msg!("Fraud bounty: {} bps, bond ≥ {}", share_bps, min_bond);
+
+        Ok(())
+    }
+
+    /// Move $GREEN from the treasury's $GREEN account into the bounty pool.
+    ///
+    /// # Arguments
+    /// * `amount` — $GREEN base units
+    pub fn fund_bounty_pool(ctx: Context<FundBountyPool>, amount: u64) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+
+        let signer_seeds: &[&[&[u8]]] =
+            &[&[TREASURY_AUTHORITY_SEED, &[ctx.bumps.treasury_authority]]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.treasury_green.to_account_info(),
+                    to: ctx.accounts.bounty_vault.to_account_info(),
+                    authority: ctx.accounts.treasury_authority.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+        let config = &mut ctx.accounts.bounty_config;
+        config.pool += amount;
+        config.total_funded += amount;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::FundBountyPool,
+            ctx.accounts.bounty_vault.key(),
+            config.pool - amount,
+            config.pool,
+        )?;
+
+        msg!("Bounty pool funded: {} $GREEN ({} available)", amount, config.pool);
+
+        Ok(())
+    }
+
+    /// Report a minted claim as fraudulent, bonding $GREEN against the
+    /// report. One open report per claim; the authority resolves it with
+    /// `resolve_report`.
+    ///
+    /// # Arguments
+    /// * `claim_id` — The claim's sequential ID, as in its ProofRecord
+    /// * `evidence_hash` — Hash of the off-chain evidence; must be non-zero
+    /// * `bond` — $GREEN bonded, at least the configured minimum. Returned
+    ///   if the report is upheld, added to the bounty pool if not
+    pub fn report_fraud(
+        ctx: Context<ReportFraud>,
+        claim_id: u64,
+        evidence_hash: [u8; 32],
+        bond: u64,
+    ) -> Result<()> {
+        require!(evidence_hash != [0; 32], KisanError::MissingEvidenceHash);
+        let record = &ctx.accounts.proof_record;
+        require!(
+            claim_id != 0 && record.claim_id == claim_id,
+            KisanError::ClaimIdMismatch
+        );
+        let min_bond = ctx.accounts.bounty_config.min_bond;
+        if bond < min_bond {
+            return Err(report::exceeded(KisanError::ReportBondTooLow, bond, min_bond));
+        }
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reporter_token_account.to_account_info(),
+                    to: ctx.accounts.bounty_vault.to_account_info(),
+                    authority: ctx.accounts.reporter.to_account_info(),
+                },
+            ),
+            bond,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let report = &mut ctx.accounts.fraud_report;
+        report.reporter = ctx.accounts.reporter.key();
+        report.claim_id = claim_id;
+        report.farmer = record.farmer;
+        report.claim_amount = record.serial_end - record.serial_start;
+        report.evidence_hash = evidence_hash;
+        report.bond = bond;
+        report.reported_at = now;
+        report.bump = ctx.bumps.fraud_report;
+
+        emit!(FraudReported {
+            claim_id,
+            farmer: report.farmer,
+            reporter: report.reporter,
+            evidence_hash,
+            bond,
+        });
+
+        Ok(())
+    }
+
+    /// Resolve an open fraud report.
+    ///
+    /// Upheld, the farmer owes the clawback: their $GREEN frozen with
+    /// `freeze_account`, capped at the claim's issuance. The reporter gets
+    /// their bond back now and the bounty once `recover_clawback` has
+    /// moved the clawback to the treasury, and the report stays so the
+    /// claim can't be reported twice. Rejected, the bond goes to the pool
+    /// and the report closes to the reporter, leaving the claim open to
+    /// better-evidenced reports.
+    ///
+    /// # Arguments
+    /// * `upheld` — Whether the claim was found fraudulent
+    pub fn resolve_report(ctx: Context<ResolveReport>, upheld: bool) -> Result<()> {
+        let report = &ctx.accounts.fraud_report;
+        require!(report.resolved_at == 0, KisanError::ReportResolved);
+        let (claim_id, bond) = (report.claim_id, report.bond);
+
+        let mut owed = 0;
+        if upheld {
+            let frozen = ctx
+                .accounts
+                .farmer_token_account
+                .as_ref()
+                .ok_or(KisanError::ClawbackAccountRequired)?;
+            require!(
+                frozen.owner == report.farmer && frozen.is_frozen(),
+                KisanError::ClawbackAccountRequired
+            );
+            owed = frozen.amount.min(report.claim_amount);
+
+            let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[ctx.accounts.program_state.bump]]];
+            token::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.bounty_vault.to_account_info(),
+                        to: ctx.accounts.reporter_token_account.to_account_info(),
+                        authority: ctx.accounts.program_state.to_account_info(),
+                    },
+                    signer_seeds,
+                ),
+                bond,
+            )?;
+        } else {
+            ctx.accounts.bounty_config.pool += bond;
+        }
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ResolveFraudReport,
+            ctx.accounts.fraud_report.key(),
+            0,
+            owed,
+        )?;
+
+        emit!(FraudReportResolved {
+            claim_id,
+            reporter: ctx.accounts.fraud_report.reporter,
+            upheld,
+            owed,
+        });
+
+        if upheld {
+            let report = &mut ctx.accounts.fraud_report;
+            report.resolved_at = Clock::get()?.unix_timestamp;
+            report.owed = owed;
+            Ok(())
+        } else {
+            ctx.accounts
+                .fraud_report
+                .close(ctx.accounts.reporter.to_account_info())
+        }
+    }
+
+    /// Pay an upheld report's clawback. The farmer's frozen $GREEN account
+    /// is thawed, the clawback moves from it to the treasury's $GREEN
+    /// account in the same instruction, and the rest of the balance is the
+    /// farmer's again. The reporter gets `share_bps` of the amount
+    /// recovered, as far as the pool covers it. Signed by the farmer:
+    /// classic SPL tokens can't be moved by the freeze authority, so the
+    /// account stays frozen until they pay.
+    pub fn recover_clawback(ctx: Context<RecoverClawback>) -> Result<()> {
+        let report = &ctx.accounts.fraud_report;
+        require!(
+            report.resolved_at != 0 && report.owed > report.clawed,
+            KisanError::NoClawbackOwed
+        );
+        require!(
+            ctx.accounts.farmer_token_account.is_frozen(),
+            KisanError::ClawbackAccountRequired
+        );
+        let recovered = report.owed - report.clawed;
+
+        let state_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[ctx.accounts.program_state.bump]]];
+        token::thaw_account(CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            ThawAccount {
+                account: ctx.accounts.farmer_token_account.to_account_info(),
+                mint: ctx.accounts.green_mint.to_account_info(),
+                authority: ctx.accounts.program_state.to_account_info(),
+            },
+            state_seeds,
+        ))?;
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.farmer_token_account.to_account_info(),
+                    to: ctx.accounts.treasury_green.to_account_info(),
+                    authority: ctx.accounts.farmer.to_account_info(),
+                },
+            ),
+            recovered,
+        )?;
+
+        let config = &mut ctx.accounts.bounty_config;
+        let bounty = config.bounty(recovered);
+        config.pool -= bounty;
+        config.total_paid += bounty;
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.bounty_vault.to_account_info(),
+                    to: ctx.accounts.reporter_token_account.to_account_info(),
+                    authority: ctx.accounts.program_state.to_account_info(),
+                },
+                state_seeds,
+            ),
+            bounty,
+        )?;
+
+        let report = &mut ctx.accounts.fraud_report;
+        report.clawed += recovered;
+        report.bounty += bounty;
+
+        emit!(ClawbackRecovered {
+            claim_id: report.claim_id,
+            farmer: report.farmer,
+            reporter: report.reporter,
+            recovered,
+            bounty,
+        });
+
+        Ok(())
+    }
+
+    /// Create or update the fiat off-ramp: the payout partner that settles
+    /// vouchers and how long holders have to dispute them.
+    ///
+    /// # Arguments
+    /// * `partner` — Key the payout partner signs `mark_paid` with
+    /// * `dispute_window` — Seconds a holder may dispute a paid voucher,
+    ///   and how long the partner has to pay before an unpaid one can be
+    ///   disputed
+    pub fn configure_payouts(
+        ctx: Context<ConfigurePayouts>,
+        partner: Pubkey,
+        dispute_window: i64,
+    ) -> Result<()> {
+        require!(dispute_window > 0, KisanError::InvalidPayoutConfig);
+
+        let old_window = ctx.accounts.payout_config.dispute_window;
+        let config = &mut ctx.accounts.payout_config;
+        config.partner = partner;
+        config.dispute_window = dispute_window;
+        config.bump = ctx.bumps.payout_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigurePayouts,
+            partner,
+            old_window as u64,
+            dispute_window as u64,
+        )?;
+
+        msg!("Payouts configured: partner {}, dispute window {}s", partner, dispute_window);
+
+        Ok(())
+    }
+
+    /// Burn `amount` $GREEN for a fiat voucher. The `VoucherRedemption`
+    /// PDA is what the payout partner watches to send the UPI transfer;
+    /// the payout details themselves stay off-chain.
+    ///
+    /// # Arguments
+    /// * `amount` — $GREEN base units to burn
+    /// * `payout_ref_hash` — Hash of the off-chain payout request (UPI ID,
+    ///   amount quoted); unique per voucher and the voucher's PDA seed
+    pub fn redeem_for_voucher(
+        ctx: Context<RedeemForVoucher>,
+        amount: u64,
+        payout_ref_hash: [u8; 32],
+    ) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+        require!(payout_ref_hash != [0; 32], KisanError::InvalidPayoutReference);
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.green_mint.to_account_info(),
+                    from: ctx.accounts.holder_token_account.to_account_info(),
+                    authority: ctx.accounts.holder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let voucher = &mut ctx.accounts.voucher;
+        voucher.holder = ctx.accounts.holder.key();
+        voucher.amount = amount;
+        voucher.payout_ref_hash = payout_ref_hash;
+        voucher.status = VoucherStatus::Pending;
+        voucher.requested_at = Clock::get()?.unix_timestamp;
+        voucher.bump = ctx.bumps.voucher;
+        ctx.accounts.payout_config.total_redeemed += amount;
+
+        emit!(VoucherRedeemed {
+            voucher: voucher.key(),
+            holder: voucher.holder,
+            amount,
+            payout_ref_hash,
+        });
+
+        Ok(())
+    }
+
+    /// Payout partner: confirm a voucher's fiat transfer.
+    ///
+    /// # Arguments
+    /// * `payment_ref_hash` — Hash of the transfer's reference (UPI
+    ///   transaction ID), for the holder to check against
+    pub fn mark_paid(ctx: Context<MarkVoucherPaid>, payment_ref_hash: [u8; 32]) -> Result<()> {
+        require!(payment_ref_hash != [0; 32], KisanError::InvalidPayoutReference);
+        let voucher = &mut ctx.accounts.voucher;
+        require!(voucher.status == VoucherStatus::Pending, KisanError::InvalidVoucherStatus);
+
+        voucher.status = VoucherStatus::Paid;
+        voucher.payment_ref_hash = payment_ref_hash;
+        voucher.paid_at = Clock::get()?.unix_timestamp;
+        ctx.accounts.payout_config.total_paid += voucher.amount;
+
+        emit!(VoucherPaid {
+            voucher: voucher.key(),
+            holder: voucher.holder,
+            amount: voucher.amount,
+            payment_ref_hash,
+        });
+
+        Ok(())
+    }
+
+    /// Holder: dispute a voucher, either a paid one whose transfer never
+    /// arrived (within the dispute window after `mark_paid`) or one the
+    /// partner hasn't paid a full window after redemption.
+    ///
+    /// # Arguments
+    /// * `reason_hash` — Hash of the holder's complaint record
+    pub fn dispute_voucher(ctx: Context<DisputeVoucher>, reason_hash: [u8; 32]) -> Result<()> {
+        require!(reason_hash != [0; 32], KisanError::MissingReasonHash);
+        let now = Clock::get()?.unix_timestamp;
+        let window = ctx.accounts.payout_config.dispute_window;
+        let voucher = &mut ctx.accounts.voucher;
+        match voucher.status {
+            VoucherStatus::Paid => require_ctx!(
+                now < voucher.paid_at + window,
+                KisanError::DisputeWindowClosed,
+                "Paid at {}, window closed at {}",
+                voucher.paid_at,
+                voucher.paid_at + window
+            ),
+            VoucherStatus::Pending => require_ctx!(
+                now >= voucher.requested_at + window,
+                KisanError::DisputeWindowOpen,
+                "Partner has until {} to pay",
+                voucher.requested_at + window
+            ),
+            _ => return err!(KisanError::InvalidVoucherStatus),
+        }
+
+        voucher.status = VoucherStatus::Disputed;
+        voucher.dispute_reason_hash = reason_hash;
+        voucher.disputed_at = now;
+
+        emit!(VoucherDisputed {
+            voucher: voucher.key(),
+            holder: voucher.holder,
+            reason_hash,
+        });
+
+        Ok(())
+    }
+
+    /// Settle a disputed voucher. A refund re-mints the burned $GREEN to
+    /// the holder; otherwise the voucher returns to `Paid` (or `Pending`
+    /// if the partner never marked it paid).
+    ///
+    /// # Arguments
+    /// * `refund` — Whether the holder gets their $GREEN back
+    pub fn resolve_voucher_dispute(
+        ctx: Context<ResolveVoucherDispute>,
+        refund: bool,
+    ) -> Result<()> {
+        let voucher = &ctx.accounts.voucher;
+        require!(voucher.status == VoucherStatus::Disputed, KisanError::InvalidVoucherStatus);
+        let amount = voucher.amount;
+
+        if refund {
+            mint_tokens(
+                &ctx.accounts.token_program,
+                &ctx.accounts.green_mint,
+                &ctx.accounts.holder_token_account,
+                &ctx.accounts.program_state,
+                amount,
+            )?;
+        }
+
+        let voucher = &mut ctx.accounts.voucher;
+        voucher.status = if refund {
+            VoucherStatus::Refunded
+        } else if voucher.paid_at > 0 {
+            VoucherStatus::Paid
+        } else {
+            VoucherStatus::Pending
+        };
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ResolveVoucherDispute,
+            voucher.key(),
+            amount,
+            refund as u64,
+        )?;
+
+        emit!(VoucherResolved {
+            voucher: voucher.key(),
+            holder: voucher.holder,
+            refunded: refund,
+        });
+
+        Ok(())
+    }
+
+    /// Set the floor price for credits of one methodology. Listings priced
+    /// below the floor are rejected, so smallholders can't be talked into
+    /// dumping credits on bulk buyers. The floor is `min_price`, raised to
+    /// `oracle_floor_bps` of the oracle price while a feed is set.
+    ///
+    /// # Arguments
+    /// * `methodology_id` — Registry methodology the floor applies to
+    /// * `min_price` — USDC base units per whole $GREEN
+    /// * `price_feed` — Pyth `PriceUpdateV2` account of the methodology's
+    ///   reference price, or the default pubkey for a fixed floor
+    /// * `feed_id` — Pyth feed id the account must carry
+    /// * `oracle_floor_bps` — Floor as a share of the oracle price
+    /// * `max_staleness` — Oldest oracle price accepted, in seconds
+    pub fn set_price_floor(
+        ctx: Context<SetPriceFloor>,
+        methodology_id: u16,
+        min_price: u64,
+        price_feed: Pubkey,
+        feed_id: [u8; 32],
+        oracle_floor_bps: u16,
+        max_staleness: i64,
+    ) -> Result<()> {
+        if price_feed != Pubkey::default() {
+            require!(
+                oracle_floor_bps as u64 <= BPS_DENOMINATOR && max_staleness > 0,
+                KisanError::InvalidPriceFloor
+            );
+        }
+
+        let old_price = ctx.accounts.price_floor.min_price;
+        let floor = &mut ctx.accounts.price_floor;
+        floor.methodology_id = methodology_id;
+        floor.min_price = min_price;
+        floor.price_feed = price_feed;
+        floor.feed_id = feed_id;
+        floor.oracle_floor_bps = oracle_floor_bps;
+        floor.max_staleness = max_staleness;
+        floor.bump = ctx.bumps.price_floor;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetPriceFloor,
+            floor.key(),
+            old_price,
+            min_price,
+        )?;
+
+        msg!(
+            "Price floor for methodology {}: {} USDC per $GREEN, oracle {} bps",
+            methodology_id,
+            min_price,
+            floor.oracle_floor_bps
+        );
+
+        Ok(())
+    }
+
+    /// List $GREEN for sale in USDC. The credits move into an escrow the
+    /// program releases to buyers. The listing names the issuance the
+    /// credits came from, which fixes the methodology whose floor the
+    /// price must meet; when the seller isn't that issuance's farmer the
+    /// listing is a resale and pays the farmer a royalty.
+    ///
+    /// # Arguments
+    /// * `listing_id` — Seller-chosen id, unique among their listings
+    /// * `amount` — $GREEN base units to sell, at most the issuance
+    /// * `price` — USDC base units per whole $GREEN
+    pub fn create_listing(
+        ctx: Context<CreateListing>,
+        listing_id: u64,
+        amount: u64,
+        price: u64,
+    ) -> Result<()> {
+        ctx.accounts.feature_flags.require_enabled(FEATURE_MARKETPLACE)?;
+        require!(amount > 0 && price > 0, KisanError::InvalidAmount);
+        let record = &ctx.accounts.proof_record;
+        let issued = record.serial_end - record.serial_start;
+        if amount > issued {
+            return Err(report::exceeded(KisanError::ListingExceedsIssuance, amount, issued));
+        }
+
+        let floor = ctx.accounts.price_floor.floor(
+            ctx.accounts.price_feed.as_ref(),
+            ctx.accounts.usdc_mint.decimals,
+            Clock::get()?.unix_timestamp,
+        )?;
+        if price < floor {
+            return Err(report::exceeded(KisanError::PriceBelowFloor, price, floor));
+        }
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.seller_token_account.to_account_info(),
+                    to: ctx.accounts.listing_escrow.to_account_info(),
+                    authority: ctx.accounts.seller.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let listing = &mut ctx.accounts.listing;
+        listing.seller = ctx.accounts.seller.key();
+        listing.listing_id = listing_id;
+        listing.proof_record = record.key();
+        listing.origin_farmer = record.farmer;
+        listing.methodology_id = record.methodology_id;
+        listing.usdc_mint = ctx.accounts.usdc_mint.key();
+        listing.price = price;
+        listing.remaining = amount;
+        listing.listed_at = Clock::get()?.unix_timestamp;
+        listing.bump = ctx.bumps.listing;
+
+        let royalty = &mut ctx.accounts.royalty;
+        royalty.farmer = record.farmer;
+        royalty.bump = ctx.bumps.royalty;
+
+        emit!(CreditsListed {
+            listing: listing.key(),
+            seller: listing.seller,
+            methodology_id: listing.methodology_id,
+            amount,
+            price,
+            floor,
+        });
+
+        Ok(())
+    }
+
+    /// Buy `amount` $GREEN from a listing. The buyer pays the seller
+    /// directly, rounded up to the next USDC base unit; on a resale the
+    /// marketplace's royalty share goes to the royalty vault, credited to
+    /// the originating farmer. The treasury's protocol fee is paid into
+    /// the treasury.
+    ///
+    /// # Arguments
+    /// * `amount` — $GREEN base units to buy
+    pub fn buy_listing(ctx: Context<BuyListing>, amount: u64) -> Result<()> {
+        ctx.accounts.feature_flags.require_enabled(FEATURE_MARKETPLACE)?;
+        let listing = &ctx.accounts.listing;
+        require!(amount > 0, KisanError::InvalidAmount);
+        if amount > listing.remaining {
+            return Err(report::exceeded(
+                KisanError::ListingExceedsIssuance,
+                amount,
+                listing.remaining,
+            ));
+        }
+        let cost = (amount as u128 * listing.price as u128).div_ceil(GREEN_UNIT as u128);
+        let cost = u64::try_from(cost).map_err(|_| KisanError::InvalidAmount)?;
+        let royalty = if listing.seller == listing.origin_farmer {
+            0
+        } else {
+            (cost as u128 * ctx.accounts.market_config.royalty_bps as u128
+                / BPS_DENOMINATOR as u128) as u64
+        };
+        let protocol_fee = (cost as u128 * ctx.accounts.treasury_config.market_fee_bps as u128
+            / BPS_DENOMINATOR as u128) as u64;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.buyer_usdc_account.to_account_info(),
+                    to: ctx.accounts.seller_usdc_account.to_account_info(),
+                    authority: ctx.accounts.buyer.to_account_info(),
+                },
+            ),
+            cost - royalty - protocol_fee,
+        )?;
+        if protocol_fee > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.buyer_usdc_account.to_account_info(),
+                        to: ctx.accounts.treasury_usdc.to_account_info(),
+                        authority: ctx.accounts.buyer.to_account_info(),
+                    },
+                ),
+                protocol_fee,
+            )?;
+            ctx.accounts.treasury_config.total_fees += protocol_fee;
+        }
+        if royalty > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.buyer_usdc_account.to_account_info(),
+                        to: ctx.accounts.royalty_vault.to_account_info(),
+                        authority: ctx.accounts.buyer.to_account_info(),
+                    },
+                ),
+                royalty,
+            )?;
+            ctx.accounts.royalty.unclaimed += royalty;
+            ctx.accounts.royalty.total_earned += royalty;
+            ctx.accounts.market_config.total_royalties += royalty;
+        }
+
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[ctx.accounts.program_state.bump]]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.listing_escrow.to_account_info(),
+                    to: ctx.accounts.buyer_token_account.to_account_info(),
+                    authority: ctx.accounts.program_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        let listing = &mut ctx.accounts.listing;
+        listing.remaining -= amount;
+
+        emit!(ListingPurchased {
+            listing: listing.key(),
+            seller: listing.seller,
+            buyer: ctx.accounts.buyer.key(),
+            amount,
+            usdc_paid: cost,
+            royalty,
+            protocol_fee,
+        });
+
+        let now = Clock::get()?.unix_timestamp;
+        let profile = &mut ctx.accounts.buyer_profile;
+        profile.open(ctx.accounts.buyer.key(), ctx.bumps.buyer_profile, now);
+        profile.total_purchased += amount;
+        profile.total_spent += cost;
+        profile.purchases += 1;
+        profile.emit_activity(BuyerActivityKind::Purchase, listing.key(), amount, cost, now);
+
+        Ok(())
+    }
+
+    /// Seller: close a listing, returning unsold $GREEN and both accounts'
+    /// rent. Also how a sold-out listing is cleaned up.
+    pub fn cancel_listing(ctx: Context<CancelListing>) -> Result<()> {
+        let remaining = ctx.accounts.listing_escrow.amount;
+        release_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.listing_escrow,
+            &ctx.accounts.seller_token_account,
+            &ctx.accounts.seller.to_account_info(),
+            &ctx.accounts.program_state,
+        )?;
+
+        msg!("Listing {} closed, {} $GREEN returned", ctx.accounts.listing.key(), remaining);
+
+        Ok(())
+    }
+
+    /// Open an OTC deal: escrow `green_amount` $GREEN that only
+    /// `counterparty` can take, for `usdc_amount` USDC, until `expiry`.
+    /// One open deal per seller and counterparty; cancel or settle it
+    /// before negotiating the next.
+    ///
+    /// # Arguments
+    /// * `counterparty` — The only wallet that can accept
+    /// * `green_amount` — $GREEN base units escrowed
+    /// * `usdc_amount` — USDC base units the counterparty pays in total
+    /// * `expiry` — Unix time after which the deal can't be accepted
+    pub fn create_otc(
+        ctx: Context<CreateOtc>,
+        counterparty: Pubkey,
+        green_amount: u64,
+        usdc_amount: u64,
+        expiry: i64,
+    ) -> Result<()> {
+        ctx.accounts.feature_flags.require_enabled(FEATURE_MARKETPLACE)?;
+        require!(green_amount > 0 && usdc_amount > 0, KisanError::InvalidAmount);
+        require_keys_neq!(
+            counterparty,
+            ctx.accounts.maker.key(),
+            KisanError::InvalidCounterparty
+        );
+        let now = Clock::get()?.unix_timestamp;
+        require!(expiry > now, KisanError::OtcExpired);
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.maker_token_account.to_account_info(),
+                    to: ctx.accounts.otc_escrow.to_account_info(),
+                    authority: ctx.accounts.maker.to_account_info(),
+                },
+            ),
+            green_amount,
+        )?;
+
+        let otc = &mut ctx.accounts.otc;
+        otc.maker = ctx.accounts.maker.key();
+        otc.counterparty = counterparty;
+        otc.usdc_mint = ctx.accounts.usdc_mint.key();
+        otc.green_amount = green_amount;
+        otc.usdc_amount = usdc_amount;
+        otc.expiry = expiry;
+        otc.created_at = now;
+        otc.bump = ctx.bumps.otc;
+
+        emit!(OtcCreated {
+            otc: otc.key(),
+            maker: otc.maker,
+            counterparty,
+            green_amount,
+            usdc_amount,
+            expiry,
+        });
+
+        Ok(())
+    }
+
+    /// Counterparty: settle an OTC deal before its expiry. In one
+    /// instruction the counterparty pays the USDC leg, less the treasury's
+    /// protocol fee, to the seller and receives the escrowed $GREEN; the
+    /// deal and its escrow close, refunding the seller's rent.
+    pub fn accept_otc(ctx: Context<AcceptOtc>) -> Result<()> {
+        ctx.accounts.feature_flags.require_enabled(FEATURE_MARKETPLACE)?;
+        let otc = &ctx.accounts.otc;
+        let now = Clock::get()?.unix_timestamp;
+        require!(now < otc.expiry, KisanError::OtcExpired);
+        let (green_amount, usdc_amount) = (otc.green_amount, otc.usdc_amount);
+        let protocol_fee = (usdc_amount as u128
+            * ctx.accounts.treasury_config.market_fee_bps as u128
+            / BPS_DENOMINATOR as u128) as u64;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.counterparty_usdc_account.to_account_info(),
+                    to: ctx.accounts.maker_usdc_account.to_account_info(),
+                    authority: ctx.accounts.counterparty.to_account_info(),
+                },
+            ),
+            usdc_amount - protocol_fee,
+        )?;
+        if protocol_fee > 0 {
+            token::transfer(
+                CpiContext::new(
+                    ctx.accounts.token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.counterparty_usdc_account.to_account_info(),
+                        to: ctx.accounts.treasury_usdc.to_account_info(),
+                        authority: ctx.accounts.counterparty.to_account_info(),
+                    },
+                ),
+                protocol_fee,
+            )?;
+            ctx.accounts.treasury_config.total_fees += protocol_fee;
+        }
+        release_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.otc_escrow,
+            &ctx.accounts.counterparty_token_account,
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.program_state,
+        )?;
+
+        emit!(OtcSettled {
+            otc: ctx.accounts.otc.key(),
+            maker: ctx.accounts.otc.maker,
+            counterparty: ctx.accounts.counterparty.key(),
+            green_amount,
+            usdc_amount,
+            protocol_fee,
+        });
+
+        Ok(())
+    }
+
+    /// Close an OTC deal that wasn't accepted, refunding the escrowed
+    /// $GREEN and both accounts' rent to the seller. The seller can cancel
+    /// at any time; once the deal has expired anyone can, so a keeper
+    /// returns expired escrow without the seller.
+    pub fn cancel_otc(ctx: Context<CancelOtc>) -> Result<()> {
+        let otc = &ctx.accounts.otc;
+        let expired = Clock::get()?.unix_timestamp >= otc.expiry;
+        require!(
+            expired || ctx.accounts.caller.key() == otc.maker,
+            KisanError::OtcNotExpired
+        );
+        let refunded = ctx.accounts.otc_escrow.amount;
+        release_vault(
+            &ctx.accounts.token_program,
+            &ctx.accounts.otc_escrow,
+            &ctx.accounts.maker_token_account,
+            &ctx.accounts.maker.to_account_info(),
+            &ctx.accounts.program_state,
+        )?;
+
+        emit!(OtcCancelled {
+            otc: otc.key(),
+            maker: otc.maker,
+            counterparty: otc.counterparty,
+            refunded,
+            expired,
+        });
+
+        Ok(())
+    }
+
+    /// Create the sGREEN staking pool: the Token-2022 interest-bearing
+    /// sGREEN mint and the vault holding staked $GREEN. The program-state
+    /// PDA is the mint's authority and rate authority.
+    ///
+    /// # Arguments
+    /// * `rate_bps` — Yearly staking yield, at most `MAX_SGREEN_RATE_BPS`
+    pub fn initialize_sgreen(ctx: Context<InitializeSgreen>, rate_bps: i16) -> Result<()> {
+        require!(rate_bps >= 0, KisanError::InvalidAmount);
+        if rate_bps > MAX_SGREEN_RATE_BPS {
+            return Err(report::exceeded(
+                KisanError::SgreenRateTooHigh,
+                rate_bps,
+                MAX_SGREEN_RATE_BPS,
+            ));
+        }
+
+        sgreen::create_mint(
+            &ctx.accounts.authority,
+            &ctx.accounts.sgreen_mint.to_account_info(),
+            ctx.bumps.sgreen_mint,
+            &ctx.accounts.program_state,
+            &ctx.accounts.token_2022_program,
+            &ctx.accounts.system_program,
+            ctx.accounts.green_mint.decimals,
+            rate_bps,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let pool = &mut ctx.accounts.sgreen_pool;
+        pool.rate_bps = rate_bps;
+        pool.pre_update_average_rate = rate_bps;
+        pool.initialized_at = now;
+        pool.last_update = now;
+        pool.mint_bump = ctx.bumps.sgreen_mint;
+        pool.bump = ctx.bumps.sgreen_pool;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::InitializeSgreen,
+            ctx.accounts.sgreen_mint.key(),
+            0,
+            rate_bps as u64,
+        )?;
+
+        msg!("sGREEN mint {} at {} bps", ctx.accounts.sgreen_mint.key(), rate_bps);
+
+        Ok(())
+    }
+
+    /// Change the sGREEN yield from now on, on the pool and the mint
+    /// alike. Yield already accrued is unaffected.
+    ///
+    /// # Arguments
+    /// * `rate_bps` — Yearly staking yield, at most `MAX_SGREEN_RATE_BPS`
+    pub fn set_sgreen_rate(ctx: Context<SetSgreenRate>, rate_bps: i16) -> Result<()> {
+        require!(rate_bps >= 0, KisanError::InvalidAmount);
+        if rate_bps > MAX_SGREEN_RATE_BPS {
+            return Err(report::exceeded(
+                KisanError::SgreenRateTooHigh,
+                rate_bps,
+                MAX_SGREEN_RATE_BPS,
+            ));
+        }
+
+        sgreen::update_rate(
+            &ctx.accounts.sgreen_mint.to_account_info(),
+            &ctx.accounts.program_state,
+            &ctx.accounts.token_2022_program,
+            rate_bps,
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        let pool = &mut ctx.accounts.sgreen_pool;
+        let old_rate = pool.rate_bps;
+        pool.pre_update_average_rate = sgreen::average_rate(
+            pool.initialized_at,
+            pool.last_update,
+            pool.pre_update_average_rate,
+            pool.rate_bps,
+            now,
+        );
+        pool.rate_bps = rate_bps;
+        pool.last_update = now;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetSgreenRate,
+            ctx.accounts.sgreen_mint.key(),
+            old_rate as u64,
+            rate_bps as u64,
+        )?;
+
+        Ok(())
+    }
+
+    /// Stake $GREEN for sGREEN at the current exchange rate, rounded down.
+    /// sGREEN is an ordinary Token-2022 token: it can be transferred or
+    /// posted as collateral, and whoever holds it can redeem.
+    ///
+    /// # Arguments
+    /// * `amount` — $GREEN base units to stake
+    pub fn stake_green(ctx: Context<SgreenExchange>, amount: u64) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+        let scale = accrue_sgreen(
+            &mut ctx.accounts.sgreen_pool,
+            &ctx.accounts.sgreen_mint,
+            &mut ctx.accounts.sgreen_vault,
+            &ctx.accounts.green_mint,
+            &mut ctx.accounts.program_state,
+            &ctx.accounts.token_program,
+        )?;
+        let minted = sgreen::to_sgreen(amount, scale);
+        require!(minted > 0, KisanError::InvalidAmount);
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.holder_token_account.to_account_info(),
+                    to: ctx.accounts.sgreen_vault.to_account_info(),
+                    authority: ctx.accounts.holder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[ctx.accounts.program_state.bump]]];
+        token_2022::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_2022_program.to_account_info(),
+                token_2022::MintTo {
+                    mint: ctx.accounts.sgreen_mint.to_account_info(),
+                    to: ctx.accounts.holder_sgreen_account.to_account_info(),
+                    authority: ctx.accounts.program_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            minted,
+        )?;
+        ctx.accounts.sgreen_pool.total_staked += amount;
+
+        emit!(GreenStaked {
+            holder: ctx.accounts.holder.key(),
+            green: amount,
+            sgreen: minted,
+        });
+
+        Ok(())
+    }
+
+    /// Burn sGREEN for the $GREEN it's worth now: the stake plus accrued
+    /// yield, rounded down. Equal to the sGREEN's balance as wallets
+    /// display it.
+    ///
+    /// # Arguments
+    /// * `amount` — Raw sGREEN base units to redeem
+    pub fn redeem_sgreen(ctx: Context<SgreenExchange>, amount: u64) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+        let scale = accrue_sgreen(
+            &mut ctx.accounts.sgreen_pool,
+            &ctx.accounts.sgreen_mint,
+            &mut ctx.accounts.sgreen_vault,
+            &ctx.accounts.green_mint,
+            &mut ctx.accounts.program_state,
+            &ctx.accounts.token_program,
+        )?;
+        let green = sgreen::to_green(amount, scale).min(ctx.accounts.sgreen_vault.amount);
+
+        token_2022::burn(
+            CpiContext::new(
+                ctx.accounts.token_2022_program.to_account_info(),
+                token_2022::Burn {
+                    mint: ctx.accounts.sgreen_mint.to_account_info(),
+                    from: ctx.accounts.holder_sgreen_account.to_account_info(),
+                    authority: ctx.accounts.holder.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[ctx.accounts.program_state.bump]]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.sgreen_vault.to_account_info(),
+                    to: ctx.accounts.holder_token_account.to_account_info(),
+                    authority: ctx.accounts.program_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            green,
+        )?;
+        ctx.accounts.sgreen_pool.total_redeemed += green;
+
+        emit!(SgreenRedeemed {
+            holder: ctx.accounts.holder.key(),
+            sgreen: amount,
+            green,
+        });
+
+        Ok(())
+    }
+
+    /// Create or update governance parameters, and on first call the vault
+    /// holding deposited voting power. Running proposals keep the period
+    /// and weighting they were created with.
+    ///
+    /// # Arguments
+    /// * `voting_period` — Seconds a proposal is open for votes
+    /// * `quorum` — Total weight (for and against) a proposal needs to pass
+    /// * `proposal_threshold` — $GREEN a wallet must deposit to propose
+    pub fn configure_governance(
+        ctx: Context<ConfigureGovernance>,
+        voting_period: i64,
+        quorum: u64,
+        proposal_threshold: u64,
+    ) -> Result<()> {
+        require!(voting_period > 0 && quorum > 0, KisanError::InvalidGovernanceConfig);
+
+        let config = &mut ctx.accounts.governance_config;
+        let old_period = config.voting_period;
+        config.voting_period = voting_period;
+        config.quorum = quorum;
+        config.proposal_threshold = proposal_threshold;
+        config.bump = ctx.bumps.governance_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigureGovernance,
+            config.key(),
+            old_period as u64,
+            voting_period as u64,
+        )?;
+
+        msg!(
+            "Governance: {}s voting, quorum {}, threshold {}",
+            voting_period,
+            quorum,
+            proposal_threshold
+        );
+
+        Ok(())
+    }
+
+    /// Choose how deposits turn into voting weight for one kind of
+    /// proposal. Applies to proposals created afterwards.
+    ///
+    /// # Arguments
+    /// * `kind` — `ProposalAction::kind` of the proposals affected
+    /// * `weighting` — Curve and per-wallet cap
+    pub fn set_vote_weighting(
+        ctx: Context<SetVoteWeighting>,
+        kind: u8,
+        weighting: VoteWeighting,
+    ) -> Result<()> {
+        require!(
+            (kind as usize) < PROPOSAL_KINDS,
+            KisanError::InvalidProposalAction
+        );
+        ctx.accounts.governance_config.weightings[kind as usize] = weighting;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::SetVoteWeighting,
+            ctx.accounts.governance_config.key(),
+            kind as u64,
+            weighting.cap,
+        )?;
+
+        msg!("Proposal kind {} weighted {:?}", kind, weighting);
+
+        Ok(())
+    }
+
+    /// Deposit $GREEN as voting power. Deposits can't change while the
+    /// voter has votes to relinquish, and only count on proposals created
+    /// after them.
+    ///
+    /// # Arguments
+    /// * `amount` — $GREEN base units to deposit
+    pub fn deposit_votes(ctx: Context<DepositVotes>, amount: u64) -> Result<()> {
+        require!(amount > 0, KisanError::InvalidAmount);
+        let voter = &mut ctx.accounts.voter_record;
+        require!(voter.active_votes == 0, KisanError::VotesLocked);
+        require_keys_eq!(voter.delegate, Pubkey::default(), KisanError::VotesDelegated);
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.owner_token_account.to_account_info(),
+                    to: ctx.accounts.governance_vault.to_account_info(),
+                    authority: ctx.accounts.owner.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        voter.owner = ctx.accounts.owner.key();
+        voter.deposited += amount;
+        voter.last_change_slot = Clock::get()?.slot;
+        voter.bump = ctx.bumps.voter_record;
+
+        msg!("Voter {} deposited {} (total {})", voter.owner, amount, voter.deposited);
+
+        Ok(())
+    }
+
+    /// Withdraw deposited $GREEN once every vote cast with it has been
+    /// relinquished.
+    ///
+    /// # Arguments
+    /// * `amount` — $GREEN base units to withdraw
+    pub fn withdraw_votes(ctx: Context<WithdrawVotes>, amount: u64) -> Result<()> {
+        let voter = &mut ctx.accounts.voter_record;
+        require!(voter.active_votes == 0, KisanError::VotesLocked);
+        require_keys_eq!(voter.delegate, Pubkey::default(), KisanError::VotesDelegated);
+        require!(amount > 0 && amount <= voter.deposited, KisanError::InvalidAmount);
+        voter.deposited -= amount;
+        voter.last_change_slot = Clock::get()?.slot;
+
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[ctx.accounts.program_state.bump]]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.governance_vault.to_account_info(),
+                    to: ctx.accounts.owner_token_account.to_account_info(),
+                    authority: ctx.accounts.program_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+
+        Ok(())
+    }
+
+    /// Propose an action. The payload is validated now, and the proposal
+    /// fixes the vote weighting of its kind and a snapshot slot: only
+    /// deposits made before it can vote.
+    ///
+    /// # Arguments
+    /// * `action` — What the proposal does if it passes
+    pub fn create_proposal(ctx: Context<CreateProposal>, action: ProposalAction) -> Result<()> {
+        action.validate()?;
+        let config = &mut ctx.accounts.governance_config;
+        let power = ctx.accounts.voter_record.voting_power();
+        if power < config.proposal_threshold {
+            return Err(report::exceeded(
+                KisanError::ProposalThresholdNotMet,
+                power,
+                config.proposal_threshold,
+            ));
+        }
+
+        let clock = Clock::get()?;
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.id = config.next_proposal_id;
+        proposal.proposer = ctx.accounts.proposer.key();
+        proposal.kind = action.kind();
+        proposal.weighting = config.weightings[proposal.kind as usize];
+        proposal.action = action;
+        proposal.snapshot_slot = clock.slot;
+        proposal.created_at = clock.unix_timestamp;
+        proposal.voting_ends_at = clock.unix_timestamp + config.voting_period;
+        proposal.quorum = config.quorum;
+        proposal.bump = ctx.bumps.proposal;
+        config.next_proposal_id += 1;
+
+        emit!(ProposalCreated {
+            proposal: proposal.key(),
+            id: proposal.id,
+            proposer: proposal.proposer,
+            kind: proposal.kind,
+            voting_ends_at: proposal.voting_ends_at,
+        });
+
+        Ok(())
+    }
+
+    /// Vote on an open proposal with the voter's deposit plus any delegated
+    /// to them, weighted by the proposal's strategy as a single wallet.
+    /// The deposit stays locked until the vote is relinquished after
+    /// voting ends, as do delegations to and from the voter.
+    ///
+    /// # Arguments
+    /// * `approve` — For (true) or against (false)
+    pub fn cast_vote(ctx: Context<CastVote>, approve: bool) -> Result<()> {
+        let proposal = &mut ctx.accounts.proposal;
+        let voter = &mut ctx.accounts.voter_record;
+        require!(
+            Clock::get()?.unix_timestamp < proposal.voting_ends_at,
+            KisanError::VotingClosed
+        );
+        require_keys_eq!(voter.delegate, Pubkey::default(), KisanError::VotesDelegated);
+        require!(
+            voter.last_change_slot < proposal.snapshot_slot,
+            KisanError::VoterWeightChanged
+        );
+        let weight = proposal.weighting.weight(voter.voting_power());
+        require!(weight > 0, KisanError::NoVotingWeight);
+
+        if approve {
+            proposal.yes_weight += weight;
+        } else {
+            proposal.no_weight += weight;
+        }
+        voter.active_votes += 1;
+
+        let vote = &mut ctx.accounts.vote_record;
+        vote.proposal = proposal.key();
+        vote.voter = voter.owner;
+        vote.approve = approve;
+        vote.weight = weight;
+        vote.bump = ctx.bumps.vote_record;
+
+        emit!(VoteCast {
+            proposal: proposal.key(),
+            voter: voter.owner,
+            approve,
+            weight,
+        });
+
+        Ok(())
+    }
+
+    /// Delegate the voter's deposit to `delegate`, typically their
+    /// cooperative, which then votes with it alongside its own. The
+    /// delegate's record is created if it has none. Both records must have
+    /// no votes outstanding, and neither can vote on proposals snapshotted
+    /// before the change, so weight is counted as it stood at each
+    /// proposal's snapshot slot. Delegations don't chain: a delegate can't
+    /// itself delegate.
+    ///
+    /// # Arguments
+    /// * `delegate` — Wallet to vote with this deposit
+    pub fn delegate_votes(ctx: Context<DelegateVotes>, delegate: Pubkey) -> Result<()> {
+        let voter = &mut ctx.accounts.voter_record;
+        let target = &mut ctx.accounts.delegate_record;
+        require_keys_eq!(voter.delegate, Pubkey::default(), KisanError::VotesDelegated);
+        require!(voter.deposited > 0, KisanError::NoVotingWeight);
+        require!(
+            voter.delegated == 0 && target.delegate == Pubkey::default(),
+            KisanError::InvalidVoteDelegate
+        );
+        require!(
+            voter.active_votes == 0 && target.active_votes == 0,
+            KisanError::VotesLocked
+        );
+
+        let slot = Clock::get()?.slot;
+        target.owner = delegate;
+        target.delegated += voter.deposited;
+        target.last_change_slot = slot;
+        target.bump = ctx.bumps.delegate_record;
+        voter.delegate = delegate;
+        voter.last_change_slot = slot;
+
+        emit!(VotesDelegated {
+            owner: voter.owner,
+            delegate,
+            weight: voter.deposited,
+        });
+
+        Ok(())
+    }
+
+    /// Take back a delegated deposit, so the voter can vote, deposit or
+    /// withdraw again. Like delegating, needs both records free of
+    /// outstanding votes.
+    pub fn revoke_vote_delegation(ctx: Context<RevokeVoteDelegation>) -> Result<()> {
+        let voter = &mut ctx.accounts.voter_record;
+        let target = &mut ctx.accounts.delegate_record;
+        require!(
+            voter.active_votes == 0 && target.active_votes == 0,
+            KisanError::VotesLocked
+        );
+
+        let slot = Clock::get()?.slot;
+        target.delegated -= voter.deposited;
+        target.last_change_slot = slot;
+        voter.delegate = Pubkey::default();
+        voter.last_change_slot = slot;
+
+        emit!(VoteDelegationRevoked {
+            owner: voter.owner,
+            delegate: target.owner,
+            weight: voter.deposited,
+        });
+
+        Ok(())
+    }
+
+    /// Close a vote once its proposal's voting has ended, unlocking the
+    /// voter's deposit when it was their last and refunding the rent.
+    pub fn relinquish_vote(ctx: Context<RelinquishVote>) -> Result<()> {
+        require!(
+            Clock::get()?.unix_timestamp >= ctx.accounts.proposal.voting_ends_at,
+            KisanError::VotingOpen
+        );
+        ctx.accounts.voter_record.active_votes -= 1;
+        Ok(())
+    }
+
+    /// Execute a passed proposal: voting has ended, the votes for
+    /// outnumber those against and together meet the quorum. Anyone can
+    /// execute. The action is validated again and dispatched to the
+    /// matching setter; the accounts it needs are passed as the optional
+    /// accounts, the rest left out. `SetVerifyingKey` rotates the key like
+    /// the authority's rotations, keeping the outgoing one valid for the
+    /// proposal's overlap window. Every execution is recorded in the admin
+    /// log under the proposal's address.
+    pub fn execute_proposal(ctx: Context<ExecuteProposal>) -> Result<()> {
+        let proposal = &ctx.accounts.proposal;
+        require!(!proposal.executed, KisanError::ProposalAlreadyExecuted);
+        require!(
+            Clock::get()?.unix_timestamp >= proposal.voting_ends_at,
+            KisanError::VotingOpen
+        );
+        let turnout = proposal.yes_weight + proposal.no_weight;
+        require!(
+            proposal.yes_weight > proposal.no_weight && turnout >= proposal.quorum,
+            KisanError::ProposalNotPassed
+        );
+        let action = proposal.action.clone();
+        action.validate()?;
+
+        // The account the action changed, for the admin log
+        let target = match action {
+            ProposalAction::SetFee { market_fee_bps } => {
+                let treasury = ctx
+                    .accounts
+                    .treasury_config
+                    .as_mut()
+                    .ok_or(KisanError::ProposalAccountsRequired)?;
+                treasury.market_fee_bps = market_fee_bps;
+                treasury.key()
+            }
+            ProposalAction::SetRewardAmount { reward_amount } => {
+                let config = ctx
+                    .accounts
+                    .config
+                    .as_mut()
+                    .ok_or(KisanError::ProposalAccountsRequired)?;
+                let (duration, mint_cap, max_claims) = (
+                    config.season_duration,
+                    config.season_mint_cap,
+                    config.season_max_claims_per_farmer,
+                );
+                config.set_season_config(duration, reward_amount, mint_cap, max_claims)?;
+                config.updated_at = Clock::get()?.unix_timestamp;
+                config.key()
+            }
+            ProposalAction::SetVerifyingKey {
+                circuit_id,
+                key,
+                overlap_slots,
+            } => {
+                let slot = Clock::get()?.slot;
+                match key {
+                    ProposedKey::Groth16(key) => {
+                        let (vk, previous) = match (
+                            ctx.accounts.groth16_vk.as_mut(),
+                            ctx.accounts.previous_groth16_vk.as_mut(),
+                        ) {
+                            (Some(vk), Some(previous)) => (vk, previous),
+                            _ => return err!(KisanError::ProposalAccountsRequired),
+                        };
+                        require!(
+                            vk.circuit_id == circuit_id && previous.circuit_id == circuit_id,
+                            KisanError::ProposalAccountsRequired
+                        );
+                        vk.rotate(previous, *key, overlap_slots, slot)?;
+                        vk.key()
+                    }
+                    ProposedKey::Plonk(key) => {
+                        let (vk, previous) = match (
+                            ctx.accounts.plonk_vk.as_mut(),
+                            ctx.accounts.previous_plonk_vk.as_mut(),
+                        ) {
+                            (Some(vk), Some(previous)) => (vk, previous),
+                            _ => return err!(KisanError::ProposalAccountsRequired),
+                        };
+                        require!(
+                            vk.circuit_id == circuit_id && previous.circuit_id == circuit_id,
+                            KisanError::ProposalAccountsRequired
+                        );
+                        vk.rotate(previous, *key, overlap_slots, slot)?;
+                        vk.key()
+                    }
+                }
+            }
+            ProposalAction::Pause { paused } => {
+                let config = ctx
+                    .accounts
+                    .config
+                    .as_mut()
+                    .ok_or(KisanError::ProposalAccountsRequired)?;
+                config.paused = paused;
+                config.updated_at = Clock::get()?.unix_timestamp;
+                config.key()
+            }
+            ProposalAction::TreasurySpend {
+                amount,
+                destination,
+            } => {
+                let (treasury, treasury_usdc, to, token_program, authority, bump) = match (
+                    ctx.accounts.treasury_config.as_mut(),
+                    ctx.accounts.treasury_usdc.as_ref(),
+                    ctx.accounts.destination.as_ref(),
+                    ctx.accounts.token_program.as_ref(),
+                    ctx.accounts.treasury_authority.as_ref(),
+                    ctx.bumps.treasury_authority,
+                ) {
+                    (Some(a), Some(b), Some(c), Some(d), Some(e), Some(f)) => (a, b, c, d, e, f),
+                    _ => return err!(KisanError::ProposalAccountsRequired),
+                };
+                require_keys_eq!(to.key(), destination, KisanError::ProposalAccountsRequired);
+                pay_from_treasury(
+                    treasury,
+                    ctx.accounts.guardian.as_ref(),
+                    token_program,
+                    treasury_usdc,
+                    to,
+                    authority,
+                    bump,
+                    amount,
+                )?;
+                to.key()
+            }
+            ProposalAction::MigrateLiquidity { destination } => {
+                let (curve, reserve, to, token_program) = match (
+                    ctx.accounts.bonding_curve.as_mut(),
+                    ctx.accounts.curve_reserve.as_ref(),
+                    ctx.accounts.destination.as_ref(),
+                    ctx.accounts.token_program.as_ref(),
+                ) {
+                    (Some(a), Some(b), Some(c), Some(d)) => (a, b, c, d),
+                    _ => return err!(KisanError::ProposalAccountsRequired),
+                };
+                require_keys_eq!(to.key(), destination, KisanError::ProposalAccountsRequired);
+                require_keys_eq!(
+                    to.mint,
+                    curve.usdc_mint,
+                    KisanError::ProposalAccountsRequired
+                );
+                require!(curve.active, KisanError::CurveClosed);
+
+                let amount = reserve.amount;
+                let state_bump = ctx.accounts.program_state.bump;
+                let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[state_bump]]];
+                token::transfer(
+                    CpiContext::new_with_signer(
+                        token_program.to_account_info(),
+                        Transfer {
+                            from: reserve.to_account_info(),
+                            to: to.to_account_info(),
+                            authority: ctx.accounts.program_state.to_account_info(),
+                        },
+                        signer_seeds,
+                    ),
+                    amount,
+                )?;
+                curve.active = false;
+                curve.reserve = 0;
+
+                msg!("Curve closed: {} USDC migrated to {}", amount, destination);
+                to.key()
+            }
+        };
+
+        let proposal = &mut ctx.accounts.proposal;
+        proposal.executed = true;
+
+        log_action(
+            &ctx.accounts.admin_log,
+            proposal.key(),
+            AdminAction::ExecuteProposal,
+            target,
+            proposal.id,
+            proposal.kind as u64,
+        )?;
+
+        emit!(ProposalExecuted {
+            proposal: proposal.key(),
+            id: proposal.id,
+            kind: proposal.kind,
+            yes_weight: proposal.yes_weight,
+            no_weight: proposal.no_weight,
+        });
+
+        Ok(())
+    }
+
+    /// Create or update the marketplace's resale royalty and its USDC
+    /// vault. $GREEN is a classic SPL Token mint, which has no transfer
+    /// hooks, so the royalty is taken where resales settle, in
+    /// `buy_listing`, rather than on every transfer.
+    ///
+    /// # Arguments
+    /// * `royalty_bps` — Share of a resale's price paid to the
+    ///   originating farmer, at most `MAX_ROYALTY_BPS`
+    pub fn configure_marketplace(
+        ctx: Context<ConfigureMarketplace>,
+        royalty_bps: u16,
+    ) -> Result<()> {
+        if royalty_bps > MAX_ROYALTY_BPS {
+            return Err(report::exceeded(KisanError::RoyaltyTooHigh, royalty_bps, MAX_ROYALTY_BPS));
+        }
+
+        let old_bps = ctx.accounts.market_config.royalty_bps;
+        let config = &mut ctx.accounts.market_config;
+        config.royalty_bps = royalty_bps;
+        config.bump = ctx.bumps.market_config;
+
+        log_admin_action(
+            &ctx.accounts.admin_log,
+            &ctx.accounts.authority,
+            AdminAction::ConfigureMarketplace,
+            ctx.accounts.royalty_vault.key(),
+            old_bps as u64,
+            royalty_bps as u64,
+        )?;
+
+        msg!("Marketplace royalty: {} bps", royalty_bps);
+
+        Ok(())
+    }
+
+    /// Farmer: withdraw the USDC royalties accrued from resales of their
+    /// credits.
+    pub fn claim_royalties(ctx: Context<ClaimRoyalties>) -> Result<()> {
+        let amount = ctx.accounts.royalty.unclaimed;
+        require!(amount > 0, KisanError::NoRoyaltiesToClaim);
+
+        let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[ctx.accounts.program_state.bump]]];
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.royalty_vault.to_account_info(),
+                    to: ctx.accounts.farmer_usdc_account.to_account_info(),
+                    authority: ctx.accounts.program_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            amount,
+        )?;
+        ctx.accounts.royalty.unclaimed = 0;
+
+        emit!(RoyaltiesClaimed {
+            farmer: ctx.accounts.farmer.key(),
+            amount,
+        });
+
+        Ok(())
+    }
+
+    /// View: whether `commitment` has been verified, with the claim's
+    /// details if so. The result is set as return data, so CPI callers and
+    /// simulations can read it without parsing accounts.
+    pub fn get_proof_status(
+        ctx: Context<GetProofStatus>,
+        _commitment: [u8; 32],
+    ) -> Result<ProofStatus> {
+        let info = &ctx.accounts.proof_record;
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(ProofStatus::default());
+        }
+
+        let record = ProofRecord::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        Ok(ProofStatus {
+            verified: record.verified,
+            farmer: record.farmer,
+            timestamp: record.timestamp,
+            season: record.season,
+            amount: record.serial_end - record.serial_start,
+        })
+    }
+
+    /// View: `get_proof_status` for a compressed proof record. The record
+    /// is proven against `root` (proof nodes as remaining accounts); an
+    /// invalid proof fails rather than returning an unverified status.
+    ///
+    /// # Arguments
+    /// * `record` — The record as it was compressed (logged via Noop)
+    /// * `root` — A recent root of the state tree
+    /// * `leaf_index` — Index the record was appended at
+    pub fn get_compressed_proof_status<'info>(
+        ctx: Context<'_, '_, 'info, 'info, GetCompressedProofStatus<'info>>,
+        record: ProofRecord,
+        root: [u8; 32],
+        leaf_index: u32,
+    ) -> Result<ProofStatus> {
+        compression::verify_leaf(
+            &ctx.accounts.merkle_tree,
+            &ctx.accounts.compression_program,
+            root,
+            compression::leaf_hash(PROOF_RECORD_LEAF_TAG, &record)?,
+            leaf_index,
+            ctx.remaining_accounts,
+        )?;
+
+        Ok(ProofStatus {
+            verified: record.verified,
+            farmer: record.farmer,
+            timestamp: record.timestamp,
+            season: record.season,
+            amount: record.serial_end - record.serial_start,
+        })
+    }
+
+    /// View: a farmer's claim statistics, set as return data.
+    pub fn get_farmer_stats(ctx: Context<GetFarmerStats>) -> Result<FarmerStats> {
+        let profile = &ctx.accounts.farmer_profile;
+        Ok(FarmerStats {
+            farmer: profile.farmer,
+            region_code: profile.region_code,
+            total_proofs: profile.total_proofs,
+            season_index: profile.season_index,
+            season_claims: profile.season_claims,
+            streak: profile.streak,
+            badges_claimed: profile.badges_claimed,
+            registered_at: profile.registered_at,
+            last_claim_at: profile.last_claim_at,
+        })
+    }
+
+    /// View: the program's global PDAs plus those of one circuit, region,
+    /// season and farmer, set as return data. Integrators simulate this
+    /// instead of copying the seeds.
+    pub fn get_addresses(
+        _ctx: Context<GetAddresses>,
+        circuit_id: u16,
+        region_code: u16,
+        season: u32,
+        farmer: Pubkey,
+    ) -> Result<ProgramAddresses> {
+        Ok(ProgramAddresses::derive(
+            circuit_id,
+            region_code,
+            season,
+            &farmer,
+        ))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────
+// Events
+// ─────────────────────────────────────────────────────────────
+
+#[event]
+pub struct ProofMinted {
+    pub farmer: Pubkey,
+    pub claimant: Pubkey,
+    pub payer: Pubkey,
+    pub commitment: [u8; 32],
+    pub circuit_id: u16,
+    pub season: u32,
+    pub streak: u32,
+    pub amount: u64,
+    pub referral_bonus: u64,
+    pub serial_start: u64,
+    pub serial_end: u64,
+    pub claim_id: u64,
+}
+
+#[event]
+pub struct ClaimSubmitted {
+    pub claim: Pubkey,
+    pub farmer: Pubkey,
+    pub commitment: [u8; 32],
+    pub circuit_id: u16,
+}
+
+#[event]
+pub struct ClaimVerified {
+    pub claim: Pubkey,
+    pub verified_by: Pubkey,
+}
+
+#[event]
+pub struct CreditsIssued {
+    pub claim: Pubkey,
+    pub farmer: Pubkey,
+    pub amount: u64,
+    pub serial_start: u64,
+    pub serial_end: u64,
+    pub claim_id: u64,
+}
+
+/// Logged by a claim rejected for reusing a commitment; the fields are
+/// the original claim's.
+#[event]
+pub struct ProofReused {
+    pub commitment: [u8; 32],
+    pub farmer: Pubkey,
+    pub timestamp: i64,
+    pub season: u32,
+    pub claim_id: u64,
+}
+
+#[event]
+pub struct PayoutSplitAgreed {
+    pub parcel: Pubkey,
+    pub farmer: Pubkey,
+    pub landowner: Pubkey,
+    pub farmer_bps: u16,
+    pub landowner_bps: u16,
+    pub agreed_at: i64,
+}
+
+#[event]
+pub struct RewardSplit {
+    pub parcel: Pubkey,
+    pub farmer: Pubkey,
+    pub landowner: Pubkey,
+    pub farmer_amount: u64,
+    pub landowner_amount: u64,
+}
+
+#[event]
+pub struct ClaimQueued {
+    pub claim: Pubkey,
+    pub farmer: Pubkey,
+    pub position: u64,
+    pub season: u32,
+}
+
+#[event]
+pub struct ClaimRetired {
+    pub claim: Pubkey,
+    pub retired_by: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct AccountFrozen {
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub amount: u64,
+    pub reason_hash: [u8; 32],
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct AccountThawed {
+    pub token_account: Pubkey,
+    pub owner: Pubkey,
+    pub reason_hash: [u8; 32],
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct VoucherRedeemed {
+    pub voucher: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub payout_ref_hash: [u8; 32],
+}
+
+#[event]
+pub struct VoucherPaid {
+    pub voucher: Pubkey,
+    pub holder: Pubkey,
+    pub amount: u64,
+    pub payment_ref_hash: [u8; 32],
+}
+
+#[event]
+pub struct VoucherDisputed {
+    pub voucher: Pubkey,
+    pub holder: Pubkey,
+    pub reason_hash: [u8; 32],
+}
+
+#[event]
+pub struct VoucherResolved {
+    pub voucher: Pubkey,
+    pub holder: Pubkey,
+    pub refunded: bool,
+}
+
+#[event]
+pub struct CreditsListed {
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub methodology_id: u16,
+    pub amount: u64,
+    pub price: u64,
+    pub floor: u64,
+}
+
+#[event]
+pub struct ListingPurchased {
+    pub listing: Pubkey,
+    pub seller: Pubkey,
+    pub buyer: Pubkey,
+    pub amount: u64,
+    pub usdc_paid: u64,
+    pub royalty: u64,
+    pub protocol_fee: u64,
+}
+
+#[event]
+pub struct OtcCreated {
+    pub otc: Pubkey,
+    pub maker: Pubkey,
+    pub counterparty: Pubkey,
+    pub green_amount: u64,
+    pub usdc_amount: u64,
+    pub expiry: i64,
+}
+
+#[event]
+pub struct OtcSettled {
+    pub otc: Pubkey,
+    pub maker: Pubkey,
+    pub counterparty: Pubkey,
+    pub green_amount: u64,
+    pub usdc_amount: u64,
+    pub protocol_fee: u64,
+}
+
+#[event]
+pub struct OtcCancelled {
+    pub otc: Pubkey,
+    pub maker: Pubkey,
+    pub counterparty: Pubkey,
+    pub refunded: u64,
+    /// Cancelled after expiry rather than withdrawn by the seller
+    pub expired: bool,
+}
+
+#[event]
+pub struct GreenStaked {
+    pub holder: Pubkey,
+    pub green: u64,
+    pub sgreen: u64,
+}
+
+#[event]
+pub struct SgreenRedeemed {
+    pub holder: Pubkey,
+    pub sgreen: u64,
+    pub green: u64,
+}
+
+#[event]
+pub struct ProposalCreated {
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub proposer: Pubkey,
+    pub kind: u8,
+    pub voting_ends_at: i64,
+}
+
+#[event]
+pub struct VoteCast {
+    pub proposal: Pubkey,
+    pub voter: Pubkey,
+    pub approve: bool,
+    pub weight: u64,
+}
+
+#[event]
+pub struct VotesDelegated {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub weight: u64,
+}
+
+#[event]
+pub struct VoteDelegationRevoked {
+    pub owner: Pubkey,
+    pub delegate: Pubkey,
+    pub weight: u64,
+}
+
+#[event]
+pub struct ProposalExecuted {
+    pub proposal: Pubkey,
+    pub id: u64,
+    pub kind: u8,
+    pub yes_weight: u64,
+    pub no_weight: u64,
+}
+
+#[event]
+pub struct RoyaltiesClaimed {
+    pub farmer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FraudReported {
+    pub claim_id: u64,
+    pub farmer: Pubkey,
+    pub reporter: Pubkey,
+    pub evidence_hash: [u8; 32],
+    pub bond: u64,
+}
+
+#[event]
+pub struct FraudReportResolved {
+    pub claim_id: u64,
+    pub reporter: Pubkey,
+    pub upheld: bool,
+    /// $GREEN the farmer owes; 0 if rejected
+    pub owed: u64,
+}
+
+#[event]
+pub struct ClawbackRecovered {
+    pub claim_id: u64,
+    pub farmer: Pubkey,
+    pub reporter: Pubkey,
+    pub recovered: u64,
+    pub bounty: u64,
+}
+
+#[event]
+pub struct NodeRewardsAccrued {
+    pub claim: Pubkey,
+    pub nodes: Vec<Pubkey>,
+    pub amount_per_node: u64,
+}
+
+#[event]
+pub struct NodeRewardsClaimed {
+    pub node: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct FarmerProfileUpdated {
+    pub farmer: Pubkey,
+    pub attester: Pubkey,
+    pub old_region: u16,
+    pub new_region: u16,
+    pub contact_hash: [u8; 32],
+    /// Value of `FarmerProfile::profile_updates` after this update
+    pub update: u32,
+}
+
+#[event]
+pub struct FarmerWalletCompromised {
+    pub wallet: Pubkey,
+    pub attester: Pubkey,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct FarmerProfileMigrated {
+    pub old: Pubkey,
+    pub new: Pubkey,
+    pub attester: Pubkey,
+    pub total_proofs: u64,
+    pub streak: u32,
+    /// Unclaimed USDC royalties moved to the new wallet's royalty account
+    pub royalties: u64,
+}
+
+#[event]
+pub struct PreCommitRevealed {
+    pub farmer: Pubkey,
+    pub commitment: [u8; 32],
+    pub committed_slot: u64,
+    pub revealed_slot: u64,
+}
+
+#[event]
+pub struct IncentivePaid {
+    pub wallet: Pubkey,
+    pub token_account: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BuyerActivityKind {
+    Purchase,
+    Retirement,
+}
+
+/// One change to a buyer profile, with the running totals after it, so an
+/// export of these events alone reconciles against the account.
+#[event]
+pub struct BuyerActivity {
+    pub buyer: Pubkey,
+    pub kind: BuyerActivityKind,
+    /// Listing bought from, or claim retired
+    pub reference: Pubkey,
+    pub amount: u64,
+    pub usdc_paid: u64,
+    pub total_purchased: u64,
+    pub total_spent: u64,
+    pub total_retired: u64,
+    pub timestamp: i64,
+}
+
+#[event]
+pub struct AllocationReleased {
+    pub kind: AllocationKind,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+    /// Paid from this allocation so far, including `amount`
+    pub released: u64,
+    /// Still held in escrow
+    pub remaining: u64,
+}
+
+#[event]
+pub struct RecoveryInitiated {
+    pub recovery_key: Pubkey,
+    pub authority: Pubkey,
+    /// Earliest `claim_recovery`, if the authority stays silent until then
+    pub claimable_at: i64,
+}
+
+#[event]
+pub struct RecoveryCancelled {
+    pub recovery_key: Pubkey,
+    pub initiated_at: i64,
+}
+
+#[event]
+pub struct AuthorityRecovered {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct RelayerSlashed {
+    pub relayer: Pubkey,
+    pub commitment: [u8; 32],
+    pub reporter: Pubkey,
+    pub amount: u64,
+    pub stake_left: u64,
+}
+
+// ─────────────────────────────────────────────────────────────
+// Helpers
+// ─────────────────────────────────────────────────────────────
+
+/// Fully verify a proof against its circuit: Groth16 and PLONK proofs with
+/// the alt_bn128 pairing against the circuit's registered key (see
+/// `groth16.rs`, `plonk.rs`), external-verifier circuits by CPI. A proof
+/// is checked against the circuit's previous key when the caller passes
+/// it, otherwise against the current one.
+#[allow(clippy::too_many_arguments)]
+fn verify_circuit_proof<'info>(
+    circuit: &CircuitConfig,
+    groth16_vk: Option<&Account<Groth16VerifyingKey>>,
+    previous_groth16_vk: Option<&Account<PreviousGroth16VerifyingKey>>,
+    plonk_vk: Option<&Account<PlonkVerifyingKey>>,
+    previous_plonk_vk: Option<&Account<PreviousPlonkVerifyingKey>>,
+    verifier_program: Option<&UncheckedAccount<'info>>,
+    verifier_accounts: &[AccountInfo<'info>],
+    proof: &Proof,
+    public_signals: &[u8],
+) -> Result<()> {
+    match (circuit.proof_system, proof) {
+        (ProofSystem::Groth16, Proof::Groth16(points)) => {
+            let key = match previous_groth16_vk {
+                Some(previous) => overlap_key(&previous.key, previous.valid_until_slot)?,
+                None => &groth16_vk.ok_or(KisanError::VerifyingKeyRequired)?.key,
+            };
+            groth16::verify(key, points, public_signals)
+        }
+        (ProofSystem::Plonk, Proof::Serialized(proof)) => {
+            let key = match previous_plonk_vk {
+                Some(previous) => overlap_key(&previous.key, previous.valid_until_slot)?,
+                None => &plonk_vk.ok_or(KisanError::VerifyingKeyRequired)?.key,
+            };
+            plonk::verify(key, proof, public_signals)
+        }
+        (ProofSystem::External, Proof::Serialized(proof)) => {
+            let program = verifier_program.ok_or(KisanError::InvalidVerifierProgram)?;
+            verifier::verify(
+                program,
+                verifier_accounts,
+                circuit.circuit_id,
+                proof,
+                public_signals,
+            )
+        }
+        _ => err!(KisanError::ProofFormatMismatch),
+    }
+}
+
+/// Where a closing proof record's rent goes: back to the rent pool for
+/// sponsored claims, otherwise to the original payer.
+fn proof_rent_destination<'info>(
+    proof_record: &ProofRecord,
+    rent: u64,
+    rent_pool: Option<&mut Account<'info, RentPool>>,
+    rent_recipient: Option<&UncheckedAccount<'info>>,
+) -> Result<AccountInfo<'info>> {
+    match rent_pool {
+        Some(rent_pool) if proof_record.rent_payer == rent_pool.key() => {
+            rent_pool.total_reclaimed += rent;
+            rent_pool.active_sponsorships -= 1;
+            Ok(rent_pool.to_account_info())
+        }
+        _ => {
+            let recipient = rent_recipient.ok_or(KisanError::InvalidRentRecipient)?;
+            require_keys_eq!(
+                recipient.key(),
+                proof_record.rent_payer,
+                KisanError::InvalidRentRecipient
+            );
+            Ok(recipient.to_account_info())
+        }
+    }
+}
+
+/// Append `record` to the state tree under `tag`, logging its Borsh data
+/// through the Noop program for indexers, and return its leaf index.
+fn append_compressed<'info, T: AnchorSerialize>(
+    config: &mut Account<'info, StateTreeConfig>,
+    tree_authority: &AccountInfo<'info>,
+    merkle_tree: &AccountInfo<'info>,
+    noop: &AccountInfo<'info>,
+    compression_program: &AccountInfo<'info>,
+    tag: &[u8],
+    record: &T,
+) -> Result<u64> {
+    let signer_seeds: &[&[&[u8]]] = &[&[STATE_TREE_AUTHORITY_SEED, &[config.authority_bump]]];
+    let mut data = tag.to_vec();
+    record.serialize(&mut data)?;
+    compression::log_leaf_data(noop, data)?;
+    compression::append(
+        compression::TreeAccounts {
+            merkle_tree,
+            authority: tree_authority,
+            noop,
+            compression_program,
+        },
+        compression::leaf_hash(tag, record)?,
+        signer_seeds,
+    )?;
+
+    let leaf_index = config.leaves_appended;
+    config.leaves_appended += 1;
+    Ok(leaf_index)
+}
+
+/// Mint reward tokens ($GREEN or a companion mint) to `to`, signed by the
+/// program-state PDA, which is the authority of every reward mint.
+fn mint_tokens<'info>(
+    token_program: &Program<'info, Token>,
+    mint: &Account<'info, Mint>,
+    to: &Account<'info, TokenAccount>,
+    program_state: &Account<'info, ProgramState>,
+    amount: u64,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[program_state.bump]]];
+
+    token::mint_to(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            MintTo {
+                mint: mint.to_account_info(),
+                to: to.to_account_info(),
+                authority: program_state.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )
+}
+
+/// `owner` may receive `farmer`'s tokens: the farmer's own wallet, or
+/// the custodial wallet they linked with `link_custodial_wallet`.
+fn is_farmer_wallet(owner: &Pubkey, farmer: &Pubkey, link: Option<&CustodialLink>) -> bool {
+    owner == farmer || link.is_some_and(|link| link.wallet == *owner)
+}
+
+/// dMRV issuance for `accounts.claim`, whose status the caller has
+/// checked: `issue_credits` for verified claims, `process_queued_claim`
+/// for the head of the queue.
+fn issue_claim_credits<'info>(
+    accounts: &mut IssueCredits<'info>,
+    bumps: &IssueCreditsBumps,
+    remaining: &'info [AccountInfo<'info>],
+) -> Result<()> {
+    accounts.proof_record.ensure_unused()?;
+    require!(accounts.circuit_config.active, KisanError::CircuitInactive);
+    require_region_identity(
+        &accounts.farmer_profile,
+        &accounts.region_config,
+        accounts.identity_attestation.as_deref(),
+    )?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let base_reward = base_reward(
+        &accounts.circuit_config,
+        &accounts.program_state,
+        &accounts.feature_flags,
+        &accounts.season,
+        accounts.pricing_config.as_deref(),
+        accounts.price_feed.as_ref(),
+        now,
+    )?;
+    let streak = accounts.farmer_profile.record_season_claim(&accounts.season)?;
+    let bonus_bps = accounts.config.streak_bonus_bps(streak);
+    let reward = accounts.season.record_claim(now, base_reward, bonus_bps)?;
+    accounts.season_tally.record(&accounts.farmer_profile, reward);
+    accounts.mint_stats.load_mut()?.record(
+        accounts.farmer_profile.region_code,
+        accounts.circuit_config.circuit_id,
+        reward,
+    );
+
+    let claim = &accounts.claim;
+    let proof_record = &mut accounts.proof_record;
+    proof_record.commitment = claim.commitment;
+    proof_record.farmer = claim.farmer;
+    proof_record.timestamp = now;
+    proof_record.verified = true;
+    proof_record.rent_payer = accounts.payer.key();
+    proof_record.bump = bumps.proof_record;
+    proof_record.version = ACCOUNT_VERSION;
+    proof_record.season = accounts.season.index;
+    proof_record.vintage = vintage_year(now);
+    proof_record.methodology_id = accounts.circuit_config.methodology_id;
+    (proof_record.serial_start, proof_record.serial_end) =
+        accounts.program_state.allocate_serials(reward);
+    proof_record.claim_id = accounts.program_state.next_claim_id();
+    let (serial_start, serial_end) = (proof_record.serial_start, proof_record.serial_end);
+    let claim_id = proof_record.claim_id;
+
+    let node_share = accrue_node_rewards(accounts, remaining, reward)?;
+    if reward > node_share {
+        mint_tokens(
+            &accounts.token_program,
+            &accounts.green_mint,
+            &accounts.farmer_token_account,
+            &accounts.program_state,
+            reward - node_share,
+        )?;
+    }
+
+    let commitment = accounts.claim.commitment;
+    accounts
+        .claim_index
+        .record(&accounts.farmer_profile, commitment, bumps.claim_index);
+    let profile = &mut accounts.farmer_profile;
+    profile.total_proofs += 1;
+    profile.last_claim_at = now;
+
+    let state = &mut accounts.program_state;
+    state.total_proofs_verified += 1;
+    state.total_tokens_minted += reward;
+    accounts.circuit_config.total_claims += 1;
+
+    memo::attach(
+        accounts.memo_program.as_deref(),
+        &memo::ClaimMemo {
+            circuit_id: accounts.circuit_config.circuit_id,
+            season: accounts.season.index,
+            region_code: accounts.farmer_profile.region_code,
+        },
+    )?;
+
+    let claim = &mut accounts.claim;
+    claim.status = ClaimStatus::Issued;
+    claim.issued_amount = reward;
+    claim.issued_at = now;
+
+    emit!(CreditsIssued {
+        claim: claim.key(),
+        farmer: claim.farmer,
+        amount: reward,
+        serial_start,
+        serial_end,
+        claim_id,
+    });
+
+    Ok(())
+}
+
+/// Pay the nodes of a quorum-verified claim their share of `reward`:
+/// minted into the node reward vault and credited evenly to each node's
+/// `NodeRewards`, passed in `remaining` in the attestation's order. The
+/// amount paid, which the farmer's mint is short by; zero for claims
+/// verified any other way.
+fn accrue_node_rewards<'info>(
+    accounts: &mut IssueCredits<'info>,
+    remaining: &'info [AccountInfo<'info>],
+    reward: u64,
+) -> Result<u64> {
+    let Some(attestation) = accounts.claim_attestation.as_deref() else {
+        // Quorum-verified claims name their attestation as the verifier,
+        // so the farmer can't leave it out to keep the nodes' share
+        let claim = &accounts.claim;
+        if claim.verified_by != crate::ID {
+            let attestation = Pubkey::find_program_address(
+                &[CLAIM_ATTESTATION_SEED, claim.key().as_ref()],
+                &crate::ID,
+            )
+            .0;
+            require_keys_neq!(
+                claim.verified_by,
+                attestation,
+                KisanError::NodeRewardAccountsRequired
+            );
+        }
+        return Ok(0);
+    };
+    let config = accounts
+        .node_reward_config
+        .as_deref_mut()
+        .ok_or(KisanError::NodeRewardAccountsRequired)?;
+    let vault = accounts
+        .node_reward_vault
+        .as_deref()
+        .ok_or(KisanError::NodeRewardAccountsRequired)?;
+    require!(
+        remaining.len() == attestation.nodes.len(),
+        KisanError::NodeRewardAccountsRequired
+    );
+
+    let share = (reward as u128 * config.share_bps as u128 / BPS_DENOMINATOR as u128) as u64;
+    let per_node = share / attestation.nodes.len() as u64;
+    if per_node == 0 {
+        return Ok(0);
+    }
+    for (node, info) in attestation.nodes.iter().zip(remaining) {
+        // Owner and discriminator checked; entries only exist at their
+        // node's PDA, so a matching `node` proves it is that node's
+        let mut entry = Account::<NodeRewards>::try_from(info)?;
+        require_keys_eq!(entry.node, *node, KisanError::NodeRewardAccountsRequired);
+        entry.unclaimed += per_node;
+        entry.total_earned += per_node;
+        entry.exit(&crate::ID)?;
+    }
+
+    let paid = per_node * attestation.nodes.len() as u64;
+    mint_tokens(
+        &accounts.token_program,
+        &accounts.green_mint,
+        vault,
+        &accounts.program_state,
+        paid,
+    )?;
+    config.total_accrued += paid;
+
+    emit!(NodeRewardsAccrued {
+        claim: attestation.claim,
+        nodes: attestation.nodes.clone(),
+        amount_per_node: per_node,
+    });
+
+    Ok(paid)
+}
+
+/// $GREEN base reward for a claim on `circuit` before streak bonuses: zero
+/// if the circuit doesn't pay $GREEN, the oracle-priced amount while
+/// dynamic pricing is on and its feature enabled, otherwise the season's
+/// fixed reward.
+fn base_reward(
+    circuit: &CircuitConfig,
+    state: &ProgramState,
+    flags: &FeatureFlags,
+    season: &Season,
+    pricing: Option<&Account<PricingConfig>>,
+    price_feed: Option<&UncheckedAccount>,
+    now: i64,
+) -> Result<u64> {
+    if !circuit.green_enabled {
+        return Ok(0);
+    }
+    if !state.dynamic_pricing || !flags.is_enabled(FEATURE_ORACLE_PRICING) {
+        return Ok(season.reward_amount);
+    }
+    let pricing = pricing.ok_or(KisanError::PricingAccountsRequired)?;
+    let feed = price_feed.ok_or(KisanError::PricingAccountsRequired)?;
+    pricing.reward_amount(feed, now)
+}
+
+/// Enforce the PM-KISAN beneficiary gate, if it is on. The account
+/// constraints already pin `attestation` to the farmer's profile.
+fn require_pm_kisan_beneficiary(
+    state: &ProgramState,
+    attestation: Option<&Account<PmKisanAttestation>>,
+) -> Result<()> {
+    if state.require_pm_kisan {
+        let attestation = attestation.ok_or(KisanError::PmKisanAttestationRequired)?;
+        require!(!attestation.revoked, KisanError::PmKisanAttestationRevoked);
+    }
+    Ok(())
+}
+
+/// Enforce the identity requirement of the farmer's region. `region`
+/// must be the profile's; where it demands an identity attestation,
+/// `attestation` must be the one on the profile and not revoked.
+fn require_region_identity(
+    profile: &FarmerProfile,
+    region: &RegionConfig,
+    attestation: Option<&Account<IdentityAttestation>>,
+) -> Result<()> {
+    require!(
+        region.region_code == profile.region_code,
+        KisanError::ProfileAccountsRequired
+    );
+    if region.require_identity {
+        let attestation = attestation.ok_or(KisanError::IdentityAttestationRequired)?;
+        require_keys_eq!(
+            attestation.key(),
+            profile.identity_attestation,
+            KisanError::IdentityAttestationRequired
+        );
+        require!(!attestation.revoked, KisanError::IdentityAttestationRevoked);
+    }
+    Ok(())
+}
+
+/// Calendar year (UTC) of a unix timestamp, used as the credit vintage.
+fn vintage_year(unix_timestamp: i64) -> u16 {
+    // Civil-from-days (H. Hinnant), with years starting in March.
+    let days = unix_timestamp.div_euclid(86_400) + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153; // 0 = March
+    let year = year_of_era + era * 400 + if month_index >= 10 { 1 } else { 0 };
+    year as u16
+}
+
+/// Append `bytes` to `out` as lowercase hex. Cheaper on-chain than
+/// `format!` per byte, which allocates a String for each.
+fn push_hex(out: &mut String, bytes: &[u8]) {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    for &b in bytes {
+        out.push(DIGITS[(b >> 4) as usize] as char);
+        out.push(DIGITS[(b & 0x0f) as usize] as char);
+    }
+}
+
+/// Require `quorum` distinct reporters scoped to `scope` to have signed
+/// the transaction: `primary`, whose entry the context already checked,
+/// plus co-signers passed in `remaining` as (wallet, `OracleReporter`)
+/// pairs.
+fn check_oracle_quorum<'info>(
+    primary: &OracleReporter,
+    remaining: &'info [AccountInfo<'info>],
+    scope: u8,
+    quorum: u8,
+) -> Result<()> {
+    let mut signers = vec![primary.reporter];
+    for pair in remaining.chunks(2) {
+        let [wallet, entry] = pair else {
+            return err!(KisanError::InvalidOracleCosigner);
+        };
+        require!(wallet.is_signer, KisanError::InvalidOracleCosigner);
+        // Owner and discriminator checked; entries only exist at their
+        // reporter's PDA, so a matching `reporter` proves registration
+        let entry = Account::<OracleReporter>::try_from(entry)?;
+        require!(entry.reporter == wallet.key(), KisanError::InvalidOracleCosigner);
+        require!(entry.scopes & scope != 0, KisanError::OracleScopeMissing);
+        if !signers.contains(&entry.reporter) {
+            signers.push(entry.reporter);
+        }
+    }
+    require_ctx!(
+        signers.len() >= quorum as usize,
+        KisanError::OracleQuorumNotMet,
+        "Reporters {} of quorum {}",
+        signers.len(),
+        quorum
+    );
+    Ok(())
+}
+
+/// Distinct whitelisted attesters that signed the transaction: `primary`,
+/// whose entries the context already checked, plus co-signers passed in
+/// `remaining` as (wallet, `AttesterInfo`, `NodeRewards`) triples. At
+/// least `quorum` and at most `MAX_ATTESTATION_QUORUM`.
+fn check_attestation_quorum<'info>(
+    primary: Pubkey,
+    remaining: &'info [AccountInfo<'info>],
+    quorum: u8,
+) -> Result<Vec<Pubkey>> {
+    let mut nodes = vec![primary];
+    for triple in remaining.chunks(3) {
+        let [wallet, attester, rewards] = triple else {
+            return err!(KisanError::InvalidAttestationCosigner);
+        };
+        require!(wallet.is_signer, KisanError::InvalidAttestationCosigner);
+        // As in `check_oracle_quorum`: both only exist at the wallet's PDAs
+        let attester = Account::<AttesterInfo>::try_from(attester)?;
+        let rewards = Account::<NodeRewards>::try_from(rewards)?;
+        require!(
+            attester.attester == wallet.key() && rewards.node == wallet.key(),
+            KisanError::InvalidAttestationCosigner
+        );
+        if !nodes.contains(wallet.key) {
+            nodes.push(wallet.key());
+        }
+    }
+    if nodes.len() > MAX_ATTESTATION_QUORUM {
+        return Err(report::exceeded(
+            KisanError::InvalidAttestationCosigner,
+            nodes.len(),
+            MAX_ATTESTATION_QUORUM,
+        ));
+    }
+    require_ctx!(
+        nodes.len() >= quorum as usize,
+        KisanError::AttestationQuorumNotMet,
+        "Attesters {} of quorum {}",
+        nodes.len(),
+        quorum
+    );
+    Ok(nodes)
+}
+
+/// Move a program-state-owned vault's whole balance to `to` and close the
+/// vault, returning its rent to `rent_to`.
+fn release_vault<'info>(
+    token_program: &Program<'info, Token>,
+    vault: &Account<'info, TokenAccount>,
+    to: &Account<'info, TokenAccount>,
+    rent_to: &AccountInfo<'info>,
+    program_state: &Account<'info, ProgramState>,
+) -> Result<()> {
+    let signer_seeds: &[&[&[u8]]] = &[&[STATE_SEED, &[program_state.bump]]];
+
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: to.to_account_info(),
+                authority: program_state.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        vault.amount,
+    )?;
+
+    token::close_account(CpiContext::new_with_signer(
+        token_program.to_account_info(),
+        CloseAccount {
+            account: vault.to_account_info(),
+            destination: rent_to.clone(),
+            authority: program_state.to_account_info(),
+        },
+        signer_seeds,
+    ))
+}
+
+/// Mint the sGREEN vault whatever yield it's owed, so it backs the whole
+/// sGREEN supply at the current exchange rate, and return that rate.
+fn accrue_sgreen<'info>(
+    pool: &mut Account<'info, SgreenPool>,
+    sgreen_mint: &InterfaceAccount<'info, token_interface::Mint>,
+    vault: &mut Account<'info, TokenAccount>,
+    green_mint: &Account<'info, Mint>,
+    program_state: &mut Account<'info, ProgramState>,
+    token_program: &Program<'info, Token>,
+) -> Result<f64> {
+    let scale = sgreen::scale(
+        pool.initialized_at,
+        pool.last_update,
+        pool.pre_update_average_rate,
+        pool.rate_bps,
+        Clock::get()?.unix_timestamp,
+    );
+    let backing = sgreen::to_green(sgreen_mint.supply, scale);
+    if backing > vault.amount {
+        let rewards = backing - vault.amount;
+        mint_tokens(token_program, green_mint, vault, program_state, rewards)?;
+        vault.reload()?;
+        pool.total_rewards += rewards;
+        program_state.total_tokens_minted += rewards;
+    }
+    Ok(scale)
+}
+
+/// Pay `amount` from the treasury's USDC vault to `to`, signed by the
+/// treasury-authority PDA (`authority_bump` is its bump), once
+/// `TreasuryConfig::authorize_outflow` has counted it against the daily
+/// limit. Returns the window's outflows including this payment.
+#[allow(clippy::too_many_arguments)]
+fn pay_from_treasury<'info>(
+    config: &mut TreasuryConfig,
+    guardian: Option<&Signer<'info>>,
+    token_program: &Program<'info, Token>,
+    treasury_usdc: &Account<'info, TokenAccount>,
+    to: &Account<'info, TokenAccount>,
+    treasury_authority: &AccountInfo<'info>,
+    authority_bump: u8,
+    amount: u64,
+) -> Result<u64> {
+    let withdrawn = config.authorize_outflow(amount, Clock::get()?.unix_timestamp, guardian)?;
+    let signer_seeds: &[&[&[u8]]] = &[&[TREASURY_AUTHORITY_SEED, &[authority_bump]]];
+    token::transfer(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Transfer {
+                from: treasury_usdc.to_account_info(),
+                to: to.to_account_info(),
+                authority: treasury_authority.clone(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    Ok(withdrawn)
+}
+
+/// Append an authority action to the admin log. `target` is the account
+/// or key acted on; `old_value` / `new_value` the main setting changed
+/// (flags as 0 / 1, amounts for fund movements), zero where none applies.
+fn log_admin_action(
+    admin_log: &AccountLoader<AdminLog>,
+    authority: &Signer,
+    action: AdminAction,
+    target: Pubkey,
+    old_value: u64,
+    new_value: u64,
+) -> Result<()> {
+    log_action(
+        admin_log,
+        authority.key(),
+        action,
+        target,
+        old_value,
+        new_value,
+    )
+}
+
+/// Append an entry made by `authority`, which may be a PDA rather than a
+/// signer: executed governance proposals log under the proposal.
+fn log_action(
+    admin_log: &AccountLoader<AdminLog>,
+    authority: Pubkey,
+    action: AdminAction,
+    target: Pubkey,
+    old_value: u64,
+    new_value: u64,
+) -> Result<()> {
+    admin_log.load_mut()?.push(AdminLogEntry {
+        authority,
+        target,
+        old_value,
+        new_value,
+        timestamp: Clock::get()?.unix_timestamp,
+        action: action as u16,
+        _padding: [0; 6],
+    });
+    Ok(())
+}
+
+/// Gate for the devnet-only instructions: the running build must have the
+/// `devnet` feature and the state must have been initialized by one.
+fn require_devnet(state: &ProgramState) -> Result<()> {
+    require!(cfg!(feature = "devnet") && state.devnet, KisanError::DevnetOnly);
+    Ok(())
+}
+
+/// Composability policy for `verify_and_mint`. Top-level calls always
+/// pass. Under CPI the transaction's top-level instruction, read from the
+/// instructions sysvar, must belong to a registered integrator: a callee
+/// can't see its direct caller, so the outermost program is what's
+/// allowlisted.
+fn enforce_cpi_policy(
+    instructions_sysvar: Option<&UncheckedAccount>,
+    integrator: Option<&CpiIntegrator>,
+) -> Result<()> {
+    use anchor_lang::solana_program::instruction::{
+        get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT,
+    };
+    use anchor_lang::solana_program::sysvar::instructions::{
+        load_current_index_checked, load_instruction_at_checked,
+    };
+
+    if get_stack_height() <= TRANSACTION_LEVEL_STACK_HEIGHT {
+        return Ok(());
+    }
+    let (Some(sysvar), Some(integrator)) = (instructions_sysvar, integrator) else {
+        return err!(KisanError::CpiNotAllowed);
+    };
+    let current = load_current_index_checked(sysvar)?;
+    let top_level = load_instruction_at_checked(current as usize, sysvar)?;
+    require_keys_eq!(top_level.program_id, integrator.program, KisanError::CpiNotAllowed);
+    Ok(())
+}
+
+/// Layout-versioned accounts that `migrate_account` can upgrade in place.
+trait Versioned: AccountSerialize + AccountDeserialize + Space {
+    fn version(&self) -> u8;
+
+    /// Fill in fields added after version `from` and stamp
+    /// `ACCOUNT_VERSION`. Fields added by a realloc start zeroed.
+    fn backfill(&mut self, from: u8);
+}
+
+impl Versioned for ProgramState {
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn backfill(&mut self, from: u8) {
+        // v3 appended `last_claim_id`. Earlier claims keep ID 0, so new IDs
+        // continue after them as if they had been numbered.
+        if from < 3 {
+            self.last_claim_id = self.total_proofs_verified;
+        }
+        // v9 appended `devnet`, left false: a state initialized before the
+        // flag existed never unlocks the devnet instructions
+        self.version = ACCOUNT_VERSION;
+    }
+}
+
+impl Versioned for FarmerProfile {
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn backfill(&mut self, _from: u8) {
+        // v2 appended `version`; v4 `contact_hash` and `profile_updates`;
+        // v5 `compromised_at` and `migrated_to`; v7 the onboarding bonus.
+        // All start zeroed.
+        self.version = ACCOUNT_VERSION;
+    }
+}
+
+impl Versioned for Config {
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn backfill(&mut self, _from: u8) {
+        // Created at v3; v6 appended the incentives budget and v7 the
+        // onboarding bonus, both 0 (off) until the authority sets them.
+        // v8 appended the emission curve, which decodes zeroed as Fixed.
+        // v9 changed only `ProgramState`.
+        self.version = ACCOUNT_VERSION;
+    }
+}
+
+impl Versioned for ProofRecord {
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn backfill(&mut self, _from: u8) {
+        // v1 records predate `rent_payer` and `bump`. The farmer paid for
+        // them, and closing one checks the PDA bump.
+        if self.rent_payer == Pubkey::default() {
+            self.rent_payer = self.farmer;
+            self.bump = Pubkey::find_program_address(&[PROOF_SEED, &self.commitment], &crate::ID).1;
+        }
+        // v3 appended `claim_id`, left 0: the mint order of existing
+        // records isn't known
+        self.version = ACCOUNT_VERSION;
+    }
+}
+
+/// Realloc `target` to `T`'s current size, zero-filling the new tail and
+/// topping up rent from `payer`, then backfill it. Returns the version
+/// the account was at.
+fn migrate<'info, T: Versioned>(
+    target: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+) -> Result<u8> {
+    let space = 8 + T::INIT_SPACE;
+    require!(target.data_len() <= space, KisanError::UnknownAccountType);
+
+    let rent = Rent::get()?.minimum_balance(space);
+    let shortfall = rent.saturating_sub(target.lamports());
+    if shortfall > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.clone(),
+                    to: target.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    target.realloc(space, true)?;
+
+    let mut account = T::try_deserialize(&mut &target.try_borrow_data()?[..])?;
+    let from = account.version();
+    require!(from < ACCOUNT_VERSION, KisanError::AccountAlreadyMigrated);
+    account.backfill(from);
+    account.try_serialize(&mut &mut target.try_borrow_mut_data()?[..])?;
+
+    Ok(from)
+}
+
+// ─────────────────────────────────────────────────────────────
+// Account Structures
+// ─────────────────────────────────────────────────────────────
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    /// Upgrade authority of this program
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key())
+            @ KisanError::UnauthorizedDeployer,
+    )]
+    pub program: Program<'info, crate::program::KisanDepin>,
+
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(authority.key())
+            @ KisanError::UnauthorizedDeployer,
+    )]
+    pub program_data: Account<'info, ProgramData>,
+
+    // `init_if_needed` so a repeat call reaches the handler and fails with
+    // `AlreadyInitialized` rather than a system-program "already in use"
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProgramState::INIT_SPACE,
+        seeds = [STATE_SEED],
+        bump,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        mint::decimals = GREEN_TOKEN_DECIMALS,
+        mint::authority = program_state,
+        mint::freeze_authority = program_state,
+        seeds = [MINT_SEED],
+        bump,
+    )]
+    pub green_mint: Account<'info, Mint>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CapTable::INIT_SPACE,
+        seeds = [CAP_TABLE_SEED],
+        bump,
+    )]
+    pub cap_table: Box<Account<'info, CapTable>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [ALLOCATION_VAULT_SEED, &[AllocationKind::Community as u8]],
+        bump,
+    )]
+    pub community_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [ALLOCATION_VAULT_SEED, &[AllocationKind::Team as u8]],
+        bump,
+    )]
+    pub team_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [ALLOCATION_VAULT_SEED, &[AllocationKind::Investors as u8]],
+        bump,
+    )]
+    pub investors_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [ALLOCATION_VAULT_SEED, &[AllocationKind::Ecosystem as u8]],
+        bump,
+    )]
+    pub ecosystem_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(kind: AllocationKind)]
+pub struct ReleaseAllocation<'info> {
+    #[account(seeds = [STATE_SEED], bump = program_state.bump)]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [CAP_TABLE_SEED], bump = cap_table.bump)]
+    pub cap_table: Box<Account<'info, CapTable>>,
+
+    #[account(
+        mut,
+        seeds = [ALLOCATION_VAULT_SEED, &[kind as u8]],
+        bump,
+    )]
+    pub allocation_vault: Box<Account<'info, TokenAccount>>,
+
+    /// Must be owned by the allocation's beneficiary
+    #[account(
+        mut,
+        token::mint = allocation_vault.mint,
+        token::authority = cap_table.allocations[kind as usize].beneficiary,
+    )]
+    pub beneficiary_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SetRecovery<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RecoveryConfig::INIT_SPACE,
+        seeds = [RECOVERY_SEED],
+        bump,
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitiateRecovery<'info> {
+    pub recovery_key: Signer<'info>,
+
+    #[account(seeds = [STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [RECOVERY_SEED],
+        bump = recovery_config.bump,
+        has_one = recovery_key @ KisanError::UnauthorizedRecoveryKey,
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
+}
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [RECOVERY_SEED], bump = recovery_config.bump)]
+    pub recovery_config: Account<'info, RecoveryConfig>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRecovery<'info> {
+    pub recovery_key: Signer<'info>,
+
+    #[account(mut, seeds = [STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [RECOVERY_SEED],
+        bump = recovery_config.bump,
+        has_one = recovery_key @ KisanError::UnauthorizedRecoveryKey,
+    )]
+    pub recovery_config: Account<'info, RecoveryConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(
+    payload: ProofPayload,
+    compliance_commitment: [u8; 32],
+    opening: CommitmentOpening,
+)]
+pub struct VerifyAndMint<'info> {
+    /// Pays proof-record rent; the claimant or an allowlisted relayer
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// The farmer, or a delegate acting on the farmer's behalf
+    pub claimant: Signer<'info>,
+
+    /// CHECK: Beneficiary wallet. Only used as the token-account authority
+    /// and as a delegation seed; authorization is enforced in the handler.
+    pub farmer: UncheckedAccount<'info>,
+
+    /// Required when `claimant` is not the farmer
+    #[account(
+        mut,
+        seeds = [DELEGATION_SEED, farmer.key().as_ref(), claimant.key().as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Option<Account<'info, Delegation>>,
+
+    /// Mobile session key; takes precedence over `delegation` when present
+    #[account(
+        seeds = [SESSION_SEED, farmer.key().as_ref(), claimant.key().as_ref()],
+        bump = session_key.bump,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+
+    /// Required when `payer` is not the claimant
+    #[account(
+        mut,
+        seeds = [RELAYER_SEED, payer.key().as_ref()],
+        bump = relayer_info.bump,
+    )]
+    pub relayer_info: Option<Account<'info, RelayerInfo>>,
+
+    /// When present, reimburses the proof-record rent to `payer`
+    #[account(
+        mut,
+        seeds = [RENT_POOL_SEED],
+        bump = rent_pool.bump,
+    )]
+    pub rent_pool: Option<Account<'info, RentPool>>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Account<'info, Mint>,
+
+    /// The farmer's $GREEN token account (ATA), or their custodial wallet's
+    #[account(
+        mut,
+        token::mint = green_mint,
+        constraint = is_farmer_wallet(
+            &farmer_token_account.owner,
+            &farmer.key(),
+            custodial_link.as_deref(),
+        ) @ KisanError::InvalidFarmerTokenAccount,
+    )]
+    pub farmer_token_account: Account<'info, TokenAccount>,
+
+    /// Required when a custodial wallet holds `farmer_token_account`
+    #[account(
+        seeds = [CUSTODIAL_LINK_SEED, farmer.key().as_ref()],
+        bump = custodial_link.bump,
+    )]
+    pub custodial_link: Option<Account<'info, CustodialLink>>,
+
+    /// CHECK: the claim parcel's PayoutSplit PDA, passed whether or not it
+    /// exists so a split can't be skipped; read by `PayoutSplit::load`
+    #[account(seeds = [PAYOUT_SPLIT_SEED, opening.parcel.as_ref()], bump)]
+    pub payout_split: UncheckedAccount<'info>,
+
+    /// The landowner's $GREEN account; required when the parcel is split
+    #[account(
+        mut,
+        token::mint = green_mint,
+    )]
+    pub landowner_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Required unless unregistered claims are enabled; carries the
+    /// farmer's season cap, streak, region and referral state
+    #[account(
+        mut,
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump = farmer_profile.bump,
+        constraint = farmer_profile.compromised_at == 0 @ KisanError::WalletCompromised,
+    )]
+    pub farmer_profile: Option<Box<Account<'info, FarmerProfile>>>,
+
+    /// Page of the farmer's claim index this claim is recorded on;
+    /// required with `farmer_profile`
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ClaimIndexPage::INIT_SPACE,
+        seeds = [
+            CLAIM_INDEX_SEED,
+            farmer.key().as_ref(),
+            farmer_profile
+                .as_ref()
+                .map_or(0, |p| p.claim_index_page())
+                .to_le_bytes()
+                .as_ref(),
+        ],
+        bump,
+    )]
+    pub claim_index: Option<Box<Account<'info, ClaimIndexPage>>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ KisanError::ProgramPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_TALLY_SEED, season.index.to_le_bytes().as_ref()],
+        bump = season_tally.bump,
+    )]
+    pub season_tally: Box<Account<'info, SeasonTally>>,
+
+    #[account(mut, seeds = [MINT_STATS_SEED], bump = mint_stats.load()?.bump)]
+    pub mint_stats: AccountLoader<'info, MintStats>,
+
+    /// The farmer's region; required with `farmer_profile`
+    #[account(
+        seeds = [REGION_SEED, region_config.region_code.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Option<Box<Account<'info, RegionConfig>>>,
+
+    /// Required when the farmer's region demands an identity attestation;
+    /// must be the one on `farmer_profile`
+    pub identity_attestation: Option<Box<Account<'info, IdentityAttestation>>>,
+
+    /// Required while the PM-KISAN beneficiary gate is on; must be the one
+    /// on `farmer_profile`
+    pub pm_kisan_attestation: Option<Box<Account<'info, PmKisanAttestation>>>,
+
+    /// Required on the first claim of a referred farmer; must be the one
+    /// on `farmer_profile`
+    #[account(mut)]
+    pub referral_code: Option<Box<Account<'info, ReferralCode>>>,
+
+    /// Referrer's $GREEN account; must be owned by `referral_code.referrer`
+    #[account(
+        mut,
+        token::mint = green_mint,
+    )]
+    pub referrer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Required while the farmer has an unexpired onboarding bonus
+    #[account(
+        mut,
+        seeds = [ONBOARDING_VAULT_SEED],
+        bump,
+    )]
+    pub onboarding_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// The farmer's pre-commitment of this claim, if they made one
+    #[account(
+        mut,
+        seeds = [PRE_COMMIT_SEED, farmer.key().as_ref(), pre_commit.hash.as_ref()],
+        bump = pre_commit.bump,
+    )]
+    pub pre_commit: Option<Box<Account<'info, PreCommit>>>,
+
+    /// PDA derived from commitment — ensures each proof is used only once.
+    /// Opened with `init_if_needed` so a reused commitment reaches the
+    /// handler and fails with `ProofAlreadyUsed`
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProofRecord::INIT_SPACE,
+        seeds = [PROOF_SEED, compliance_commitment.as_ref()],
+        bump,
+    )]
+    pub proof_record: Account<'info, ProofRecord>,
+
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED],
+        bump = leaderboard.load()?.bump,
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, program_state.current_season.to_le_bytes().as_ref()],
+        bump = season.bump,
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    /// Circuit the proof was generated for; decides which mints pay out
+    #[account(
+        mut,
+        seeds = [CIRCUIT_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Box<Account<'info, CircuitConfig>>,
+
+    #[account(seeds = [FEATURE_FLAGS_SEED], bump = feature_flags.bump)]
+    pub feature_flags: Box<Account<'info, FeatureFlags>>,
+
+    /// Required while dynamic pricing is enabled
+    #[account(seeds = [PRICING_SEED], bump = pricing_config.bump)]
+    pub pricing_config: Option<Box<Account<'info, PricingConfig>>>,
+
+    /// CHECK: Pyth price update; address pinned by `pricing_config`, layout
+    /// checked by `oracle::load_pyth_price`
+    #[account(address = pricing_config.as_ref().map(|p| p.price_feed).unwrap_or_default())]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    /// Required when the circuit uses Groth16
+    #[account(
+        seeds = [GROTH16_VK_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = groth16_vk.bump,
+    )]
+    pub groth16_vk: Option<Box<Account<'info, Groth16VerifyingKey>>>,
+
+    /// For proofs made against a key being rotated out: verifies them
+    /// instead of `groth16_vk` until its overlap window ends
+    #[account(
+        seeds = [GROTH16_VK_PREVIOUS_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = previous_groth16_vk.bump,
+    )]
+    pub previous_groth16_vk: Option<Box<Account<'info, PreviousGroth16VerifyingKey>>>,
+
+    /// Required when the circuit uses PLONK
+    #[account(
+        seeds = [PLONK_VK_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = plonk_vk.bump,
+    )]
+    pub plonk_vk: Option<Box<Account<'info, PlonkVerifyingKey>>>,
+
+    /// For proofs made against a key being rotated out: verifies them
+    /// instead of `plonk_vk` until its overlap window ends
+    #[account(
+        seeds = [PLONK_VK_PREVIOUS_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = previous_plonk_vk.bump,
+    )]
+    pub previous_plonk_vk: Option<Box<Account<'info, PreviousPlonkVerifyingKey>>>,
+
+    /// CHECK: Required when the circuit has an external verifier; address
+    /// pinned by `circuit_config`, interface in `verifier.rs`
+    #[account(address = circuit_config.verifier_program @ KisanError::InvalidVerifierProgram)]
+    pub verifier_program: Option<UncheckedAccount<'info>>,
+
+    /// Required when the circuit pays $WATER
+    #[account(
+        mut,
+        seeds = [WATER_MINT_SEED],
+        bump = program_state.water_mint_bump,
+    )]
+    pub water_mint: Option<Box<Account<'info, Mint>>>,
+
+    #[account(mut)]
+    pub water_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Required when the circuit pays $SOIL
+    #[account(
+        mut,
+        seeds = [SOIL_MINT_SEED],
+        bump = program_state.soil_mint_bump,
+    )]
+    pub soil_mint: Option<Box<Account<'info, Mint>>>,
+
+    #[account(mut)]
+    pub soil_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// Required while issuance receipts are on
+    #[account(
+        mut,
+        seeds = [RECEIPT_CONFIG_SEED],
+        bump = receipt_config.bump,
+    )]
+    pub receipt_config: Option<Box<Account<'info, ReceiptConfig>>>,
+
+    /// CHECK: Signing PDA that is the receipt tree's creator/delegate
+    #[account(
+        seeds = [RECEIPT_AUTHORITY_SEED],
+        bump = receipt_config.as_ref().map(|c| c.authority_bump).unwrap_or_default(),
+    )]
+    pub receipt_authority: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Bubblegum tree config PDA; validated by Bubblegum
+    #[account(mut)]
+    pub receipt_tree_config: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Receipt merkle tree; address pinned by `receipt_config`
+    #[account(
+        mut,
+        address = receipt_config.as_ref().map(|c| c.merkle_tree).unwrap_or_default(),
+    )]
+    pub receipt_merkle_tree: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: SPL Noop log wrapper
+    #[account(address = bubblegum::NOOP_PROGRAM_ID)]
+    pub log_wrapper: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: SPL Account Compression program
+    #[account(address = bubblegum::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Metaplex Bubblegum program
+    #[account(address = bubblegum::BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: Instructions sysvar; required when invoked via CPI
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: Option<UncheckedAccount<'info>>,
+
+    /// Required when invoked via CPI: the calling integrator's allowlist entry
+    #[account(
+        seeds = [CPI_INTEGRATOR_SEED, cpi_integrator.program.as_ref()],
+        bump = cpi_integrator.bump,
+    )]
+    pub cpi_integrator: Option<Box<Account<'info, CpiIntegrator>>>,
+
+    /// CHECK: SPL Memo program; pass it to attach a claim descriptor memo
+    #[account(address = memo::MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct GrantDelegate<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = farmer,
+        space = 8 + Delegation::INIT_SPACE,
+        seeds = [DELEGATION_SEED, farmer.key().as_ref(), delegate.as_ref()],
+        bump,
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeDelegate<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = farmer,
+        has_one = farmer,
+        seeds = [DELEGATION_SEED, farmer.key().as_ref(), delegation.delegate.as_ref()],
+        bump = delegation.bump,
+    )]
+    pub delegation: Account<'info, Delegation>,
+}
+
+#[derive(Accounts)]
+#[instruction(ephemeral_pubkey: Pubkey)]
+pub struct CreateSessionKey<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = farmer,
+        space = 8 + SessionKey::INIT_SPACE,
+        seeds = [SESSION_SEED, farmer.key().as_ref(), ephemeral_pubkey.as_ref()],
+        bump,
+    )]
+    pub session_key: Account<'info, SessionKey>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeSessionKey<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = farmer,
+        has_one = farmer,
+        seeds = [SESSION_SEED, farmer.key().as_ref(), session_key.ephemeral_key.as_ref()],
+        bump = session_key.bump,
+    )]
+    pub session_key: Account<'info, SessionKey>,
+}
+
+#[derive(Accounts)]
+#[instruction(program: Pubkey)]
+pub struct RegisterCustodian<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Custodian::INIT_SPACE,
+        seeds = [CUSTODIAN_SEED, program.as_ref()],
+        bump,
+    )]
+    pub custodian: Account<'info, Custodian>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveCustodian<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [CUSTODIAN_SEED, custodian.program.as_ref()],
+        bump = custodian.bump,
+    )]
+    pub custodian: Account<'info, Custodian>,
+}
+
+#[derive(Accounts)]
+pub struct LinkCustodialWallet<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        seeds = [CUSTODIAN_SEED, custodian.program.as_ref()],
+        bump = custodian.bump,
+    )]
+    pub custodian: Account<'info, Custodian>,
+
+    #[account(
+        init,
+        payer = farmer,
+        space = 8 + CustodialLink::INIT_SPACE,
+        seeds = [CUSTODIAL_LINK_SEED, farmer.key().as_ref()],
+        bump,
+    )]
+    pub custodial_link: Account<'info, CustodialLink>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UnlinkCustodialWallet<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = farmer,
+        has_one = farmer,
+        seeds = [CUSTODIAL_LINK_SEED, farmer.key().as_ref()],
+        bump = custodial_link.bump,
+    )]
+    pub custodial_link: Account<'info, CustodialLink>,
+}
+
+#[derive(Accounts)]
+#[instruction(hash: [u8; 32])]
+pub struct CreatePreCommit<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = farmer,
+        space = 8 + PreCommit::INIT_SPACE,
+        seeds = [PRE_COMMIT_SEED, farmer.key().as_ref(), hash.as_ref()],
+        bump,
+    )]
+    pub pre_commit: Account<'info, PreCommit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePreCommit<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = farmer,
+        has_one = farmer,
+        seeds = [PRE_COMMIT_SEED, farmer.key().as_ref(), pre_commit.hash.as_ref()],
+        bump = pre_commit.bump,
+    )]
+    pub pre_commit: Account<'info, PreCommit>,
+}
+
+#[derive(Accounts)]
+#[instruction(relayer: Pubkey)]
+pub struct RegisterRelayer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RelayerInfo::INIT_SPACE,
+        seeds = [RELAYER_SEED, relayer.as_ref()],
+        bump,
+    )]
+    pub relayer_info: Account<'info, RelayerInfo>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveRelayer<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [RELAYER_SEED, relayer_info.relayer.as_ref()],
+        bump = relayer_info.bump,
+    )]
+    pub relayer_info: Account<'info, RelayerInfo>,
+}
+
+#[derive(Accounts)]
+pub struct StakeRelayer<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RELAYER_SEED, relayer.key().as_ref()],
+        bump = relayer_info.bump,
+    )]
+    pub relayer_info: Account<'info, RelayerInfo>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct SlashRelayer<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RELAYER_SEED, relayer_info.relayer.as_ref()],
+        bump = relayer_info.bump,
+    )]
+    pub relayer_info: Account<'info, RelayerInfo>,
+
+    /// CHECK: the commitment's ProofRecord PDA, which must not exist
+    #[account(seeds = [PROOF_SEED, commitment.as_ref()], bump)]
+    pub proof_record: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + RelaySlash::INIT_SPACE,
+        seeds = [RELAY_SLASH_SEED, relayer_info.relayer.as_ref(), commitment.as_ref()],
+        bump,
+    )]
+    pub relay_slash: Account<'info, RelaySlash>,
+
+    /// CHECK: instructions sysvar, read for the Ed25519 precompile
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BeginRelayerUnbonding<'info> {
+    pub relayer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RELAYER_SEED, relayer.key().as_ref()],
+        bump = relayer_info.bump,
+    )]
+    pub relayer_info: Account<'info, RelayerInfo>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawRelayerStake<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = relayer,
+        seeds = [RELAYER_SEED, relayer.key().as_ref()],
+        bump = relayer_info.bump,
+    )]
+    pub relayer_info: Account<'info, RelayerInfo>,
+}
+
+#[derive(Accounts)]
+#[instruction(program: Pubkey)]
+pub struct RegisterCpiIntegrator<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CpiIntegrator::INIT_SPACE,
+        seeds = [CPI_INTEGRATOR_SEED, program.as_ref()],
+        bump,
+    )]
+    pub cpi_integrator: Account<'info, CpiIntegrator>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveCpiIntegrator<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [CPI_INTEGRATOR_SEED, cpi_integrator.program.as_ref()],
+        bump = cpi_integrator.bump,
+    )]
+    pub cpi_integrator: Account<'info, CpiIntegrator>,
+}
+
+/// Shared by `freeze_account` and `thaw_account`.
+#[derive(Accounts)]
+pub struct FreezeGreenAccount<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = green_mint)]
+    pub token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureBounty<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + BountyConfig::INIT_SPACE,
+        seeds = [BOUNTY_CONFIG_SEED],
+        bump,
+    )]
+    pub bounty_config: Box<Account<'info, BountyConfig>>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [BOUNTY_VAULT_SEED],
+        bump,
+    )]
+    pub bounty_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct FundBountyPool<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [BOUNTY_CONFIG_SEED], bump = bounty_config.bump)]
+    pub bounty_config: Box<Account<'info, BountyConfig>>,
+
+    /// CHECK: PDA that owns the treasury vaults; signs for them only
+    #[account(seeds = [TREASURY_AUTHORITY_SEED], bump)]
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_GREEN_SEED],
+        bump,
+    )]
+    pub treasury_green: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [BOUNTY_VAULT_SEED],
+        bump,
+    )]
+    pub bounty_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(claim_id: u64)]
+pub struct ReportFraud<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+
+    #[account(seeds = [BOUNTY_CONFIG_SEED], bump = bounty_config.bump)]
+    pub bounty_config: Box<Account<'info, BountyConfig>>,
+
+    #[account(
+        seeds = [PROOF_SEED, proof_record.commitment.as_ref()],
+        bump = proof_record.bump,
+    )]
+    pub proof_record: Box<Account<'info, ProofRecord>>,
+
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + FraudReport::INIT_SPACE,
+        seeds = [FRAUD_REPORT_SEED, claim_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub fraud_report: Box<Account<'info, FraudReport>>,
+
+    #[account(
+        mut,
+        seeds = [BOUNTY_VAULT_SEED],
+        bump,
+    )]
+    pub bounty_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = bounty_vault.mint,
+        token::authority = reporter,
+    )]
+    pub reporter_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveReport<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [BOUNTY_CONFIG_SEED], bump = bounty_config.bump)]
+    pub bounty_config: Box<Account<'info, BountyConfig>>,
+
+    #[account(
+        mut,
+        has_one = reporter,
+        seeds = [FRAUD_REPORT_SEED, fraud_report.claim_id.to_le_bytes().as_ref()],
+        bump = fraud_report.bump,
+    )]
+    pub fraud_report: Box<Account<'info, FraudReport>>,
+
+    /// CHECK: the report's reporter, who gets its rent back if rejected
+    #[account(mut)]
+    pub reporter: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [BOUNTY_VAULT_SEED],
+        bump,
+    )]
+    pub bounty_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = bounty_vault.mint,
+        token::authority = reporter,
+    )]
+    pub reporter_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// The reported farmer's frozen $GREEN account; required to uphold
+    #[account(token::mint = bounty_vault.mint)]
+    pub farmer_token_account: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RecoverClawback<'info> {
+    pub farmer: Signer<'info>,
+
+    #[account(seeds = [STATE_SEED], bump = program_state.bump)]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, seeds = [BOUNTY_CONFIG_SEED], bump = bounty_config.bump)]
+    pub bounty_config: Box<Account<'info, BountyConfig>>,
+
+    #[account(
+        mut,
+        has_one = farmer,
+        seeds = [FRAUD_REPORT_SEED, fraud_report.claim_id.to_le_bytes().as_ref()],
+        bump = fraud_report.bump,
+    )]
+    pub fraud_report: Box<Account<'info, FraudReport>>,
+
+    /// The farmer's frozen $GREEN account
+    #[account(
+        mut,
+        token::mint = green_mint,
+        token::authority = farmer,
+    )]
+    pub farmer_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_GREEN_SEED],
+        bump,
+    )]
+    pub treasury_green: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [BOUNTY_VAULT_SEED],
+        bump,
+    )]
+    pub bounty_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        token::authority = fraud_report.reporter,
+    )]
+    pub reporter_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CreateLookupTable<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    /// CHECK: Signing PDA that owns the program's lookup tables
+    #[account(seeds = [LOOKUP_TABLE_AUTHORITY_SEED], bump)]
+    pub lookup_table_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Table to create; address checked against the derivation
+    /// from `lookup_table_authority` and `recent_slot`
+    #[account(mut)]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LookupTableInfo::INIT_SPACE,
+        seeds = [LOOKUP_TABLE_SEED, lookup_table.key().as_ref()],
+        bump,
+    )]
+    pub lookup_table_info: Account<'info, LookupTableInfo>,
+
+    /// CHECK: Address Lookup Table program
+    #[account(address = lookup_table::LOOKUP_TABLE_PROGRAM_ID)]
+    pub lookup_table_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExtendLookupTable<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    /// CHECK: Signing PDA that owns the program's lookup tables
+    #[account(seeds = [LOOKUP_TABLE_AUTHORITY_SEED], bump = lookup_table_info.authority_bump)]
+    pub lookup_table_authority: UncheckedAccount<'info>,
+
+    /// CHECK: The table; address pinned by `lookup_table_info`
+    #[account(mut, address = lookup_table_info.table)]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOOKUP_TABLE_SEED, lookup_table_info.table.as_ref()],
+        bump = lookup_table_info.bump,
+    )]
+    pub lookup_table_info: Account<'info, LookupTableInfo>,
+
+    /// CHECK: Address Lookup Table program
+    #[account(address = lookup_table::LOOKUP_TABLE_PROGRAM_ID)]
+    pub lookup_table_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DeactivateLookupTable<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    /// CHECK: Signing PDA that owns the program's lookup tables
+    #[account(seeds = [LOOKUP_TABLE_AUTHORITY_SEED], bump = lookup_table_info.authority_bump)]
+    pub lookup_table_authority: UncheckedAccount<'info>,
+
+    /// CHECK: The table; address pinned by `lookup_table_info`
+    #[account(mut, address = lookup_table_info.table)]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [LOOKUP_TABLE_SEED, lookup_table_info.table.as_ref()],
+        bump = lookup_table_info.bump,
+    )]
+    pub lookup_table_info: Account<'info, LookupTableInfo>,
+
+    /// CHECK: Address Lookup Table program
+    #[account(address = lookup_table::LOOKUP_TABLE_PROGRAM_ID)]
+    pub lookup_table_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CloseLookupTable<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    /// CHECK: Signing PDA that owns the program's lookup tables
+    #[account(seeds = [LOOKUP_TABLE_AUTHORITY_SEED], bump = lookup_table_info.authority_bump)]
+    pub lookup_table_authority: UncheckedAccount<'info>,
+
+    /// CHECK: The table; address pinned by `lookup_table_info`
+    #[account(mut, address = lookup_table_info.table)]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [LOOKUP_TABLE_SEED, lookup_table_info.table.as_ref()],
+        bump = lookup_table_info.bump,
+    )]
+    pub lookup_table_info: Account<'info, LookupTableInfo>,
+
+    /// CHECK: Address Lookup Table program
+    #[account(address = lookup_table::LOOKUP_TABLE_PROGRAM_ID)]
+    pub lookup_table_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: A `ProgramState`, `FarmerProfile`, `ProofRecord` or `Config`,
+    /// told apart by its discriminator in `migrate_account`
+    #[account(mut, owner = crate::ID)]
+    pub target: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeAdminLog<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<AdminLog>(),
+        seeds = [ADMIN_LOG_SEED],
+        bump,
+    )]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeFeatureFlags<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FeatureFlags::INIT_SPACE,
+        seeds = [FEATURE_FLAGS_SEED],
+        bump,
+    )]
+    pub feature_flags: Account<'info, FeatureFlags>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetFeature<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [FEATURE_FLAGS_SEED],
+        bump = feature_flags.bump,
+    )]
+    pub feature_flags: Account<'info, FeatureFlags>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [CONFIG_SEED],
+        bump,
+    )]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct SetIncentivesBudget<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeOnboardingVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [ONBOARDING_VAULT_SEED],
+        bump,
+    )]
+    pub onboarding_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct SetOnboardingBonus<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimOnboardingBonus<'info> {
+    #[account(
+        mut,
+        seeds = [FARMER_SEED, farmer_profile.farmer.as_ref()],
+        bump = farmer_profile.bump,
+    )]
+    pub farmer_profile: Account<'info, FarmerProfile>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [ONBOARDING_VAULT_SEED],
+        bump,
+    )]
+    pub onboarding_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+/// One `distribute` payment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Distribution {
+    /// Owner of the matching token account in `remaining_accounts`
+    pub wallet: Pubkey,
+    /// $GREEN base units
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct Distribute<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ResetState<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+}
+
+#[derive(Accounts)]
+pub struct AirdropGreen<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Account<'info, Mint>,
+
+    #[account(mut, token::mint = green_mint)]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeRentPool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RentPool::INIT_SPACE,
+        seeds = [RENT_POOL_SEED],
+        bump,
+    )]
+    pub rent_pool: Account<'info, RentPool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundRentPool<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [RENT_POOL_SEED],
+        bump = rent_pool.bump,
+    )]
+    pub rent_pool: Account<'info, RentPool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseProofRecord<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [PROOF_SEED, proof_record.commitment.as_ref()],
+        bump = proof_record.bump,
+    )]
+    pub proof_record: Account<'info, ProofRecord>,
+
+    /// Required when the record's rent was sponsored
+    #[account(
+        mut,
+        seeds = [RENT_POOL_SEED],
+        bump = rent_pool.bump,
+    )]
+    pub rent_pool: Option<Account<'info, RentPool>>,
+
+    /// CHECK: Original rent payer; checked against `proof_record.rent_payer`
+    #[account(mut)]
+    pub rent_recipient: Option<UncheckedAccount<'info>>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeStateTree<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + StateTreeConfig::INIT_SPACE,
+        seeds = [STATE_TREE_SEED],
+        bump,
+    )]
+    pub state_tree_config: Account<'info, StateTreeConfig>,
+
+    /// CHECK: Signing PDA set as the tree authority; holds no data
+    #[account(seeds = [STATE_TREE_AUTHORITY_SEED], bump)]
+    pub tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Pre-allocated concurrent merkle tree; validated by the
+    /// compression program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Noop log wrapper
+    #[account(address = compression::NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program
+    #[account(address = compression::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CompressProofRecord<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [PROOF_SEED, proof_record.commitment.as_ref()],
+        bump = proof_record.bump,
+    )]
+    pub proof_record: Box<Account<'info, ProofRecord>>,
+
+    /// Required when the record's rent was sponsored
+    #[account(
+        mut,
+        seeds = [RENT_POOL_SEED],
+        bump = rent_pool.bump,
+    )]
+    pub rent_pool: Option<Account<'info, RentPool>>,
+
+    /// CHECK: Original rent payer; checked against `proof_record.rent_payer`
+    #[account(mut)]
+    pub rent_recipient: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        mut,
+        seeds = [STATE_TREE_SEED],
+        bump = state_tree_config.bump,
+    )]
+    pub state_tree_config: Account<'info, StateTreeConfig>,
+
+    /// CHECK: Signing PDA, the tree authority
+    #[account(seeds = [STATE_TREE_AUTHORITY_SEED], bump = state_tree_config.authority_bump)]
+    pub tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: The state tree; address pinned by `state_tree_config`
+    #[account(mut, address = state_tree_config.merkle_tree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Noop log wrapper
+    #[account(address = compression::NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program
+    #[account(address = compression::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct CompressFarmerProfile<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = farmer,
+        close = farmer,
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump = farmer_profile.bump,
+    )]
+    pub farmer_profile: Box<Account<'info, FarmerProfile>>,
+
+    #[account(
+        mut,
+        seeds = [STATE_TREE_SEED],
+        bump = state_tree_config.bump,
+    )]
+    pub state_tree_config: Account<'info, StateTreeConfig>,
+
+    /// CHECK: Signing PDA, the tree authority
+    #[account(seeds = [STATE_TREE_AUTHORITY_SEED], bump = state_tree_config.authority_bump)]
+    pub tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: The state tree; address pinned by `state_tree_config`
+    #[account(mut, address = state_tree_config.merkle_tree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Noop log wrapper
+    #[account(address = compression::NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program
+    #[account(address = compression::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DecompressFarmerProfile<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = farmer,
+        space = 8 + FarmerProfile::INIT_SPACE,
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump,
+    )]
+    pub farmer_profile: Box<Account<'info, FarmerProfile>>,
+
+    #[account(
+        seeds = [STATE_TREE_SEED],
+        bump = state_tree_config.bump,
+    )]
+    pub state_tree_config: Account<'info, StateTreeConfig>,
+
+    /// CHECK: Signing PDA, the tree authority
+    #[account(seeds = [STATE_TREE_AUTHORITY_SEED], bump = state_tree_config.authority_bump)]
+    pub tree_authority: UncheckedAccount<'info>,
+
+    /// CHECK: The state tree; address pinned by `state_tree_config`
+    #[account(mut, address = state_tree_config.merkle_tree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Noop log wrapper
+    #[account(address = compression::NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program
+    #[account(address = compression::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct GetCompressedProofStatus<'info> {
+    #[account(seeds = [STATE_TREE_SEED], bump = state_tree_config.bump)]
+    pub state_tree_config: Account<'info, StateTreeConfig>,
+
+    /// CHECK: The state tree; address pinned by `state_tree_config`
+    #[account(address = state_tree_config.merkle_tree)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program
+    #[account(address = compression::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(attester: Pubkey)]
+pub struct RegisterAttester<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + AttesterInfo::INIT_SPACE,
+        seeds = [ATTESTER_SEED, attester.as_ref()],
+        bump,
+    )]
+    pub attester_info: Account<'info, AttesterInfo>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveAttester<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [ATTESTER_SEED, attester_info.attester.as_ref()],
+        bump = attester_info.bump,
+    )]
+    pub attester_info: Account<'info, AttesterInfo>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeEscrowVault<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Account<'info, Mint>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [ESCROW_VAULT_SEED],
+        bump,
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(identity_hash: [u8; 32])]
+pub struct RegisterEscrowIdentity<'info> {
+    #[account(mut)]
+    pub attester: Signer<'info>,
+
+    #[account(
+        seeds = [ATTESTER_SEED, attester.key().as_ref()],
+        bump = attester_info.bump,
+    )]
+    pub attester_info: Account<'info, AttesterInfo>,
+
+    #[account(
+        init,
+        payer = attester,
+        space = 8 + EscrowAccount::INIT_SPACE,
+        seeds = [ESCROW_SEED, identity_hash.as_ref()],
+        bump,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(payload: ProofPayload, compliance_commitment: [u8; 32], identity_hash: [u8; 32])]
+pub struct VerifyAndEscrow<'info> {
+    #[account(mut)]
+    pub attester: Signer<'info>,
+
+    #[account(
+        seeds = [ATTESTER_SEED, attester.key().as_ref()],
+        bump = attester_info.bump,
+    )]
+    pub attester_info: Account<'info, AttesterInfo>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, identity_hash.as_ref()],
+        bump = escrow_account.bump,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED],
+        bump,
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, program_state.current_season.to_le_bytes().as_ref()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Box<Account<'info, CircuitConfig>>,
+
+    #[account(
+        seeds = [GROTH16_VK_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = groth16_vk.bump,
+    )]
+    pub groth16_vk: Box<Account<'info, Groth16VerifyingKey>>,
+
+    /// For proofs made against a key being rotated out: verifies them
+    /// instead of `groth16_vk` until its overlap window ends
+    #[account(
+        seeds = [GROTH16_VK_PREVIOUS_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = previous_groth16_vk.bump,
+    )]
+    pub previous_groth16_vk: Option<Box<Account<'info, PreviousGroth16VerifyingKey>>>,
+
+    #[account(
+        init_if_needed,
+        payer = attester,
+        space = 8 + ProofRecord::INIT_SPACE,
+        seeds = [PROOF_SEED, compliance_commitment.as_ref()],
+        bump,
+    )]
+    pub proof_record: Account<'info, ProofRecord>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimEscrow<'info> {
+    pub farmer: Signer<'info>,
+
+    #[account(
+        constraint = attester.key() == escrow_account.registered_by
+            @ KisanError::UnauthorizedAttester,
+    )]
+    pub attester: Signer<'info>,
+
+    #[account(
+        seeds = [ATTESTER_SEED, attester.key().as_ref()],
+        bump = attester_info.bump,
+    )]
+    pub attester_info: Account<'info, AttesterInfo>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_SEED, escrow_account.identity_hash.as_ref()],
+        bump = escrow_account.bump,
+    )]
+    pub escrow_account: Account<'info, EscrowAccount>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [ESCROW_VAULT_SEED],
+        bump,
+    )]
+    pub escrow_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = program_state.mint,
+        token::authority = farmer,
+    )]
+    pub farmer_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(region_code: u16)]
+pub struct ConfigureRegion<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RegionConfig::INIT_SPACE,
+        seeds = [REGION_SEED, region_code.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub region_config: Account<'info, RegionConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(region_code: u16, personhood_nullifier: [u8; 32])]
+pub struct RegisterFarmer<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub farmer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// Proof-of-personhood verifier; required when the gate is enabled
+    pub personhood_verifier: Option<Signer<'info>>,
+
+    /// Claims the nullifier so the same human can't register twice
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PersonhoodRecord::INIT_SPACE,
+        seeds = [PERSONHOOD_SEED, personhood_nullifier.as_ref()],
+        bump,
+    )]
+    pub personhood_record: Option<Account<'info, PersonhoodRecord>>,
+
+    /// Referral code the farmer signed up with, if any
+    #[account(
+        mut,
+        seeds = [REFERRAL_SEED, referral_code.code.as_ref()],
+        bump = referral_code.bump,
+    )]
+    pub referral_code: Option<Account<'info, ReferralCode>>,
+
+    #[account(
+        seeds = [REGION_SEED, region_code.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Account<'info, RegionConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FarmerProfile::INIT_SPACE,
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump,
+    )]
+    pub farmer_profile: Account<'info, FarmerProfile>,
+
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    /// The next three are required while an onboarding bonus is set
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Option<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        seeds = [ONBOARDING_VAULT_SEED],
+        bump,
+    )]
+    pub onboarding_vault: Option<Account<'info, TokenAccount>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_region: u16)]
+pub struct UpdateFarmerProfile<'info> {
+    pub farmer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump = farmer_profile.bump,
+        constraint = farmer_profile.compromised_at == 0 @ KisanError::WalletCompromised,
+    )]
+    pub farmer_profile: Account<'info, FarmerProfile>,
+
+    /// The region being moved to must be configured
+    #[account(
+        seeds = [REGION_SEED, new_region.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Account<'info, RegionConfig>,
+
+    #[account(
+        seeds = [ATTESTER_SEED, attester_info.attester.as_ref()],
+        bump = attester_info.bump,
+    )]
+    pub attester_info: Account<'info, AttesterInfo>,
+
+    /// CHECK: instructions sysvar, read for the Ed25519 precompile
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+#[instruction(wallet: Pubkey)]
+pub struct FlagCompromised<'info> {
+    pub attester: Signer<'info>,
+
+    #[account(
+        seeds = [ATTESTER_SEED, attester.key().as_ref()],
+        bump = attester_info.bump,
+    )]
+    pub attester_info: Account<'info, AttesterInfo>,
+
+    #[account(
+        mut,
+        seeds = [FARMER_SEED, wallet.as_ref()],
+        bump = farmer_profile.bump,
+        constraint = farmer_profile.compromised_at == 0 @ KisanError::WalletCompromised,
+    )]
+    pub farmer_profile: Account<'info, FarmerProfile>,
+}
+
+#[derive(Accounts)]
+#[instruction(old: Pubkey)]
+pub struct MigrateProfile<'info> {
+    /// The farmer's new wallet; pays for the new profile
+    #[account(mut)]
+    pub new_wallet: Signer<'info>,
+
+    pub attester: Signer<'info>,
+
+    #[account(
+        seeds = [ATTESTER_SEED, attester.key().as_ref()],
+        bump = attester_info.bump,
+    )]
+    pub attester_info: Account<'info, AttesterInfo>,
+
+    #[account(
+        mut,
+        seeds = [FARMER_SEED, old.as_ref()],
+        bump = old_profile.bump,
+        constraint = old_profile.compromised_at != 0 @ KisanError::WalletNotCompromised,
+        constraint = old_profile.migrated_to == Pubkey::default()
+            @ KisanError::ProfileAlreadyMigrated,
+    )]
+    pub old_profile: Box<Account<'info, FarmerProfile>>,
+
+    #[account(
+        init,
+        payer = new_wallet,
+        space = 8 + FarmerProfile::INIT_SPACE,
+        seeds = [FARMER_SEED, new_wallet.key().as_ref()],
+        bump,
+    )]
+    pub new_profile: Box<Account<'info, FarmerProfile>>,
+
+    /// Required when the old profile has an identity attestation
+    #[account(
+        mut,
+        address = old_profile.identity_attestation @ KisanError::IdentityAttestationRequired,
+    )]
+    pub identity_attestation: Option<Box<Account<'info, IdentityAttestation>>>,
+
+    /// Required when the old profile has a personhood record
+    #[account(
+        mut,
+        address = old_profile.personhood_record @ KisanError::PersonhoodRequired,
+    )]
+    pub personhood_record: Option<Box<Account<'info, PersonhoodRecord>>>,
+
+    /// Required when the old profile has a PM-KISAN attestation
+    #[account(
+        mut,
+        address = old_profile.pm_kisan_attestation @ KisanError::PmKisanAttestationRequired,
+    )]
+    pub pm_kisan_attestation: Option<Box<Account<'info, PmKisanAttestation>>>,
+
+    /// The old wallet's royalties, if it has earned any
+    #[account(
+        mut,
+        seeds = [ROYALTY_SEED, old.as_ref()],
+        bump = old_royalty.bump,
+    )]
+    pub old_royalty: Option<Box<Account<'info, RoyaltyAccount>>>,
+
+    /// Receives `old_royalty`'s balance; required with it
+    #[account(
+        init_if_needed,
+        payer = new_wallet,
+        space = 8 + RoyaltyAccount::INIT_SPACE,
+        seeds = [ROYALTY_SEED, new_wallet.key().as_ref()],
+        bump,
+    )]
+    pub new_royalty: Option<Box<Account<'info, RoyaltyAccount>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(id_hash: [u8; 32])]
+pub struct AttestIdentity<'info> {
+    #[account(mut)]
+    pub attester: Signer<'info>,
+
+    #[account(
+        seeds = [ATTESTER_SEED, attester.key().as_ref()],
+        bump = attester_info.bump,
+    )]
+    pub attester_info: Account<'info, AttesterInfo>,
+
+    #[account(
+        mut,
+        seeds = [FARMER_SEED, farmer_profile.farmer.as_ref()],
+        bump = farmer_profile.bump,
+    )]
+    pub farmer_profile: Account<'info, FarmerProfile>,
+
+    #[account(
+        init,
+        payer = attester,
+        space = 8 + IdentityAttestation::INIT_SPACE,
+        seeds = [IDENTITY_SEED, id_hash.as_ref()],
+        bump,
+    )]
+    pub identity_attestation: Account<'info, IdentityAttestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(code: [u8; 8])]
+pub struct CreateReferralCode<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = referrer,
+        space = 8 + ReferralCode::INIT_SPACE,
+        seeds = [REFERRAL_SEED, code.as_ref()],
+        bump,
+    )]
+    pub referral_code: Account<'info, ReferralCode>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetReferralBudget<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeBadgeTree<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BadgeConfig::INIT_SPACE,
+        seeds = [BADGE_CONFIG_SEED],
+        bump,
+    )]
+    pub badge_config: Account<'info, BadgeConfig>,
+
+    /// CHECK: Signing PDA set as the tree creator; holds no data
+    #[account(seeds = [BADGE_AUTHORITY_SEED], bump)]
+    pub badge_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Bubblegum tree config PDA; validated by Bubblegum
+    #[account(mut)]
+    pub tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Pre-allocated concurrent merkle tree; validated by Bubblegum
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Noop log wrapper
+    #[account(address = bubblegum::NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program
+    #[account(address = bubblegum::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Bubblegum program
+    #[account(address = bubblegum::BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimBadge<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub farmer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump = farmer_profile.bump,
+        constraint = farmer_profile.compromised_at == 0 @ KisanError::WalletCompromised,
+    )]
+    pub farmer_profile: Account<'info, FarmerProfile>,
+
+    #[account(
+        mut,
+        seeds = [BADGE_CONFIG_SEED],
+        bump = badge_config.bump,
+        has_one = merkle_tree,
+    )]
+    pub badge_config: Account<'info, BadgeConfig>,
+
+    /// CHECK: Signing PDA that is the tree's creator/delegate
+    #[account(seeds = [BADGE_AUTHORITY_SEED], bump = badge_config.authority_bump)]
+    pub badge_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Bubblegum tree config PDA; validated by Bubblegum
+    #[account(mut)]
+    pub tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Badge merkle tree; checked against `badge_config`
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Noop log wrapper
+    #[account(address = bubblegum::NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program
+    #[account(address = bubblegum::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Bubblegum program
+    #[account(address = bubblegum::BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeReceiptTree<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ReceiptConfig::INIT_SPACE,
+        seeds = [RECEIPT_CONFIG_SEED],
+        bump,
+    )]
+    pub receipt_config: Account<'info, ReceiptConfig>,
+
+    /// CHECK: Signing PDA set as the tree creator; holds no data
+    #[account(seeds = [RECEIPT_AUTHORITY_SEED], bump)]
+    pub receipt_authority: UncheckedAccount<'info>,
+
+    /// CHECK: Bubblegum tree config PDA; validated by Bubblegum
+    #[account(mut)]
+    pub tree_config: UncheckedAccount<'info>,
+
+    /// CHECK: Pre-allocated concurrent merkle tree; validated by Bubblegum
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Noop log wrapper
+    #[account(address = bubblegum::NOOP_PROGRAM_ID)]
+    pub log_wrapper: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Account Compression program
+    #[account(address = bubblegum::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: Metaplex Bubblegum program
+    #[account(address = bubblegum::BUBBLEGUM_PROGRAM_ID)]
+    pub bubblegum_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetIssuanceReceipts<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeLeaderboard<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<Leaderboard>(),
+        seeds = [LEADERBOARD_SEED],
+        bump,
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeMintStats<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<MintStats>(),
+        seeds = [MINT_STATS_SEED],
+        bump,
+    )]
+    pub mint_stats: AccountLoader<'info, MintStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ResetLeaderboard<'info> {
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED],
+        bump = leaderboard.load()?.bump,
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeSeasons<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Season::INIT_SPACE,
+        seeds = [SEASON_SEED, 0u32.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SeasonTally::INIT_SPACE,
+        seeds = [SEASON_TALLY_SEED, 0u32.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub season_tally: Box<Account<'info, SeasonTally>>,
+
+    #[account(mut, seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetSeasonConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+#[instruction(kind: MintKind)]
+pub struct InitializeMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    // `init_if_needed` so a repeat call reaches the handler and fails with
+    // `AlreadyInitialized` rather than a system-program "already in use"
+    #[account(
+        init_if_needed,
+        payer = authority,
+        mint::decimals = GREEN_TOKEN_DECIMALS,
+        mint::authority = program_state,
+        seeds = [kind.seed()],
+        bump,
+    )]
+    pub mint: Account<'info, Mint>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+#[instruction(circuit_id: u16)]
+pub struct ConfigureCircuit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CircuitConfig::INIT_SPACE,
+        seeds = [CIRCUIT_SEED, circuit_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub circuit_config: Account<'info, CircuitConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetGroth16VerifyingKey<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Account<'info, CircuitConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Groth16VerifyingKey::INIT_SPACE,
+        seeds = [GROTH16_VK_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub groth16_vk: Box<Account<'info, Groth16VerifyingKey>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PreviousGroth16VerifyingKey::INIT_SPACE,
+        seeds = [GROTH16_VK_PREVIOUS_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub previous_groth16_vk: Box<Account<'info, PreviousGroth16VerifyingKey>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPlonkVerifyingKey<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Account<'info, CircuitConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PlonkVerifyingKey::INIT_SPACE,
+        seeds = [PLONK_VK_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub plonk_vk: Box<Account<'info, PlonkVerifyingKey>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PreviousPlonkVerifyingKey::INIT_SPACE,
+        seeds = [PLONK_VK_PREVIOUS_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub previous_plonk_vk: Box<Account<'info, PreviousPlonkVerifyingKey>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RotatePlonkVerifyingKey<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Account<'info, CircuitConfig>,
+
+    #[account(
+        mut,
+        seeds = [PLONK_VK_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = plonk_vk.bump,
+    )]
+    pub plonk_vk: Box<Account<'info, PlonkVerifyingKey>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PreviousPlonkVerifyingKey::INIT_SPACE,
+        seeds = [PLONK_VK_PREVIOUS_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub previous_plonk_vk: Box<Account<'info, PreviousPlonkVerifyingKey>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RotateGroth16VerifyingKey<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Account<'info, CircuitConfig>,
+
+    #[account(
+        mut,
+        seeds = [GROTH16_VK_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = groth16_vk.bump,
+    )]
+    pub groth16_vk: Box<Account<'info, Groth16VerifyingKey>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PreviousGroth16VerifyingKey::INIT_SPACE,
+        seeds = [GROTH16_VK_PREVIOUS_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub previous_groth16_vk: Box<Account<'info, PreviousGroth16VerifyingKey>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetCircuitVerifier<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [CIRCUIT_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Account<'info, CircuitConfig>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigurePricing<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PricingConfig::INIT_SPACE,
+        seeds = [PRICING_SEED],
+        bump,
+    )]
+    pub pricing_config: Account<'info, PricingConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetStreakCurve<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+}
+
+#[derive(Accounts)]
+pub struct AdvanceSeason<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [SEASON_SEED, program_state.current_season.to_le_bytes().as_ref()],
+        bump = current_season.bump,
+    )]
+    pub current_season: Account<'info, Season>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Season::INIT_SPACE,
+        seeds = [SEASON_SEED, (program_state.current_season + 1).to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub next_season: Account<'info, Season>,
+
+    #[account(
+        mut,
+        close = payer,
+        seeds = [SEASON_TALLY_SEED, current_season.index.to_le_bytes().as_ref()],
+        bump = current_tally.bump,
+    )]
+    pub current_tally: Box<Account<'info, SeasonTally>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SeasonSnapshot::INIT_SPACE,
+        seeds = [SEASON_SNAPSHOT_SEED, current_season.index.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub snapshot: Box<Account<'info, SeasonSnapshot>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SeasonTally::INIT_SPACE,
+        seeds = [SEASON_TALLY_SEED, (current_season.index + 1).to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub next_tally: Box<Account<'info, SeasonTally>>,
+
+    #[account(
+        mut,
+        seeds = [LEADERBOARD_SEED],
+        bump = leaderboard.load()?.bump,
+    )]
+    pub leaderboard: AccountLoader<'info, Leaderboard>,
+
+    #[account(seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Account<'info, Config>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeSeasonTally<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SeasonTally::INIT_SPACE,
+        seeds = [SEASON_TALLY_SEED, program_state.current_season.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub season_tally: Box<Account<'info, SeasonTally>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetPersonhoodRequirement<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+}
+
+#[derive(Accounts)]
+pub struct SetPmKisanRequirement<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+}
+
+#[derive(Accounts)]
+pub struct AttestPmKisanBeneficiary<'info> {
+    #[account(mut)]
+    pub attester: Signer<'info>,
+
+    #[account(seeds = [STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [FARMER_SEED, farmer_profile.farmer.as_ref()],
+        bump = farmer_profile.bump,
+    )]
+    pub farmer_profile: Account<'info, FarmerProfile>,
+
+    #[account(
+        address = farmer_profile.identity_attestation @ KisanError::IdentityAttestationRequired,
+    )]
+    pub identity_attestation: Account<'info, IdentityAttestation>,
+
+    #[account(
+        init,
+        payer = attester,
+        space = 8 + PmKisanAttestation::INIT_SPACE,
+        seeds = [PM_KISAN_SEED, identity_attestation.id_hash.as_ref()],
+        bump,
+    )]
+    pub pm_kisan_attestation: Account<'info, PmKisanAttestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokePmKisanAttestation<'info> {
+    pub attester: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = attester,
+        seeds = [PM_KISAN_SEED, pm_kisan_attestation.id_hash.as_ref()],
+        bump = pm_kisan_attestation.bump,
+    )]
+    pub pm_kisan_attestation: Account<'info, PmKisanAttestation>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeIdentityAttestation<'info> {
+    pub attester: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = attester,
+        seeds = [IDENTITY_SEED, identity_attestation.id_hash.as_ref()],
+        bump = identity_attestation.bump,
+    )]
+    pub identity_attestation: Account<'info, IdentityAttestation>,
+}
+
+#[derive(Accounts)]
+#[instruction(parcel_id: u32)]
+pub struct RegisterParcel<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub farmer: Signer<'info>,
+
+    #[account(
+        has_one = farmer,
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump = farmer_profile.bump,
+        constraint = farmer_profile.compromised_at == 0 @ KisanError::WalletCompromised,
+    )]
+    pub farmer_profile: Account<'info, FarmerProfile>,
+
+    #[account(
+        seeds = [REGION_SEED, farmer_profile.region_code.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Account<'info, RegionConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Parcel::INIT_SPACE,
+        seeds = [PARCEL_SEED, farmer.key().as_ref(), parcel_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub parcel: Account<'info, Parcel>,
+
+    /// Anchored land record to link; required when the region says so
+    #[account(
+        mut,
+        seeds = [LAND_RECORD_SEED, land_record.parcel_id_hash.as_ref()],
+        bump = land_record.bump,
+    )]
+    pub land_record: Option<Account<'info, LandRecord>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CreatePayoutSplit<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    pub landowner: Signer<'info>,
+
+    #[account(
+        has_one = farmer,
+        seeds = [PARCEL_SEED, farmer.key().as_ref(), parcel.parcel_id.to_le_bytes().as_ref()],
+        bump = parcel.bump,
+    )]
+    pub parcel: Account<'info, Parcel>,
+
+    #[account(
+        init,
+        payer = farmer,
+        space = 8 + PayoutSplit::INIT_SPACE,
+        seeds = [PAYOUT_SPLIT_SEED, parcel.key().as_ref()],
+        bump,
+    )]
+    pub payout_split: Account<'info, PayoutSplit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DissolvePayoutSplit<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    pub landowner: Signer<'info>,
+
+    #[account(
+        mut,
+        close = farmer,
+        has_one = farmer,
+        has_one = landowner,
+        seeds = [PAYOUT_SPLIT_SEED, payout_split.parcel.as_ref()],
+        bump = payout_split.bump,
+    )]
+    pub payout_split: Account<'info, PayoutSplit>,
+}
+
+#[derive(Accounts)]
+pub struct SetWeatherThresholds<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [REGION_SEED, region_config.region_code.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Account<'info, RegionConfig>,
+}
+
+#[derive(Accounts)]
+#[instruction(reporter: Pubkey)]
+pub struct AddOracleReporter<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + OracleReporter::INIT_SPACE,
+        seeds = [ORACLE_REPORTER_SEED, reporter.as_ref()],
+        bump,
+    )]
+    pub oracle_reporter: Account<'info, OracleReporter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetOracleReporterScopes<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [ORACLE_REPORTER_SEED, oracle_reporter.reporter.as_ref()],
+        bump = oracle_reporter.bump,
+    )]
+    pub oracle_reporter: Account<'info, OracleReporter>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveOracleReporter<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [ORACLE_REPORTER_SEED, oracle_reporter.reporter.as_ref()],
+        bump = oracle_reporter.bump,
+    )]
+    pub oracle_reporter: Account<'info, OracleReporter>,
+}
+
+#[derive(Accounts)]
+#[instruction(new_reporter: Pubkey)]
+pub struct RotateOracleReporter<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    /// The key being retired
+    #[account(
+        mut,
+        close = authority,
+        seeds = [ORACLE_REPORTER_SEED, oracle_reporter.reporter.as_ref()],
+        bump = oracle_reporter.bump,
+    )]
+    pub oracle_reporter: Account<'info, OracleReporter>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + OracleReporter::INIT_SPACE,
+        seeds = [ORACLE_REPORTER_SEED, new_reporter.as_ref()],
+        bump,
+    )]
+    pub new_oracle_reporter: Account<'info, OracleReporter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureOracleQuorum<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + OracleConfig::INIT_SPACE,
+        seeds = [ORACLE_CONFIG_SEED],
+        bump,
+    )]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveWeatherOracle<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [WEATHER_ORACLE_SEED, oracle_info.oracle.as_ref()],
+        bump = oracle_info.bump,
+    )]
+    pub oracle_info: Account<'info, WeatherOracleInfo>,
+}
+
+#[derive(Accounts)]
+#[instruction(region_code: u16, season: u32)]
+pub struct ReportWeather<'info> {
+    #[account(mut)]
+    pub oracle: Signer<'info>,
+
+    /// Proves the signer is a reporter scoped to weather
+    #[account(
+        seeds = [ORACLE_REPORTER_SEED, oracle.key().as_ref()],
+        bump = oracle_reporter.bump,
+        constraint = oracle_reporter.scopes & ORACLE_SCOPE_WEATHER != 0
+            @ KisanError::OracleScopeMissing,
+    )]
+    pub oracle_reporter: Account<'info, OracleReporter>,
+
+    #[account(seeds = [ORACLE_CONFIG_SEED], bump = oracle_config.bump)]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = oracle,
+        space = 8 + WeatherReport::INIT_SPACE,
+        seeds = [
+            WEATHER_REPORT_SEED,
+            region_code.to_le_bytes().as_ref(),
+            season.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub weather_report: Account<'info, WeatherReport>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeInsurancePool<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + InsurancePool::INIT_SPACE,
+        seeds = [INSURANCE_POOL_SEED],
+        bump,
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FundInsurancePool<'info> {
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_POOL_SEED],
+        bump = insurance_pool.bump,
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(season: u32)]
+pub struct BuyCover<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        has_one = farmer,
+        seeds = [PARCEL_SEED, farmer.key().as_ref(), parcel.parcel_id.to_le_bytes().as_ref()],
+        bump = parcel.bump,
+    )]
+    pub parcel: Account<'info, Parcel>,
+
+    #[account(
+        seeds = [REGION_SEED, parcel.region_code.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Account<'info, RegionConfig>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_POOL_SEED],
+        bump = insurance_pool.bump,
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    #[account(
+        init,
+        payer = farmer,
+        space = 8 + Cover::INIT_SPACE,
+        seeds = [COVER_SEED, parcel.key().as_ref(), season.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub cover: Account<'info, Cover>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimPayout<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        mut,
+        close = farmer,
+        has_one = farmer,
+        seeds = [COVER_SEED, cover.parcel.as_ref(), cover.season.to_le_bytes().as_ref()],
+        bump = cover.bump,
+    )]
+    pub cover: Account<'info, Cover>,
+
+    #[account(
+        seeds = [REGION_SEED, cover.region_code.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Account<'info, RegionConfig>,
+
+    #[account(
+        seeds = [
+            WEATHER_REPORT_SEED,
+            cover.region_code.to_le_bytes().as_ref(),
+            cover.season.to_le_bytes().as_ref(),
+        ],
+        bump = weather_report.bump,
+    )]
+    pub weather_report: Account<'info, WeatherReport>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_POOL_SEED],
+        bump = insurance_pool.bump,
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+}
+
+#[derive(Accounts)]
+pub struct ExpireCover<'info> {
+    /// CHECK: receives the cover's rent; pinned to the cover's farmer
+    #[account(mut, address = cover.farmer)]
+    pub farmer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = farmer,
+        seeds = [COVER_SEED, cover.parcel.as_ref(), cover.season.to_le_bytes().as_ref()],
+        bump = cover.bump,
+    )]
+    pub cover: Account<'info, Cover>,
+
+    #[account(
+        seeds = [REGION_SEED, cover.region_code.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Account<'info, RegionConfig>,
+
+    #[account(
+        seeds = [
+            WEATHER_REPORT_SEED,
+            cover.region_code.to_le_bytes().as_ref(),
+            cover.season.to_le_bytes().as_ref(),
+        ],
+        bump = weather_report.bump,
+    )]
+    pub weather_report: Account<'info, WeatherReport>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_POOL_SEED],
+        bump = insurance_pool.bump,
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+}
+
+#[derive(Accounts)]
+pub struct SettleCover<'info> {
+    /// CHECK: receives the payout and the cover's rent; pinned to the
+    /// cover's farmer
+    #[account(mut, address = cover.farmer)]
+    pub farmer: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        close = farmer,
+        seeds = [COVER_SEED, cover.parcel.as_ref(), cover.season.to_le_bytes().as_ref()],
+        bump = cover.bump,
+    )]
+    pub cover: Account<'info, Cover>,
+
+    #[account(
+        seeds = [REGION_SEED, cover.region_code.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Account<'info, RegionConfig>,
+
+    #[account(
+        seeds = [
+            WEATHER_REPORT_SEED,
+            cover.region_code.to_le_bytes().as_ref(),
+            cover.season.to_le_bytes().as_ref(),
+        ],
+        bump = weather_report.bump,
+    )]
+    pub weather_report: Account<'info, WeatherReport>,
+
+    #[account(
+        mut,
+        seeds = [INSURANCE_POOL_SEED],
+        bump = insurance_pool.bump,
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureLoans<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + LoanConfig::INIT_SPACE,
+        seeds = [LOAN_CONFIG_SEED],
+        bump,
+    )]
+    pub loan_config: Account<'info, LoanConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(loan_id: u32)]
+pub struct RequestLoan<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        seeds = [LOAN_CONFIG_SEED],
+        bump = loan_config.bump,
+    )]
+    pub loan_config: Box<Account<'info, LoanConfig>>,
+
+    #[account(
+        has_one = farmer,
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump = farmer_profile.bump,
+        constraint = farmer_profile.compromised_at == 0 @ KisanError::WalletCompromised,
+    )]
+    pub farmer_profile: Box<Account<'info, FarmerProfile>>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        token::authority = farmer,
+    )]
+    pub farmer_green_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = farmer,
+        space = 8 + Loan::INIT_SPACE,
+        seeds = [LOAN_SEED, farmer.key().as_ref(), loan_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+
+    #[account(
+        init,
+        payer = farmer,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [LOAN_COLLATERAL_SEED, loan.key().as_ref()],
+        bump,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct CancelLoan<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        close = farmer,
+        has_one = farmer,
+        seeds = [LOAN_SEED, farmer.key().as_ref(), loan.loan_id.to_le_bytes().as_ref()],
+        bump = loan.bump,
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        seeds = [LOAN_COLLATERAL_SEED, loan.key().as_ref()],
+        bump,
+    )]
+    pub collateral_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = program_state.mint,
+        token::authority = farmer,
+    )]
+    pub farmer_green_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct FundLoan<'info> {
+    pub lender: Signer<'info>,
+
+    #[account(
+        seeds = [LOAN_CONFIG_SEED],
+        bump = loan_config.bump,
+    )]
+    pub loan_config: Account<'info, LoanConfig>,
+
+    #[account(
+        mut,
+        seeds = [LOAN_SEED, loan.farmer.as_ref(), loan.loan_id.to_le_bytes().as_ref()],
+        bump = loan.bump,
+    )]
+    pub loan: Account<'info, Loan>,
+
+    #[account(
+        mut,
+        token::mint = loan_config.usdc_mint,
+        token::authority = lender,
+    )]
+    pub lender_usdc_account: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        token::mint = loan_config.usdc_mint,
+        token::authority = loan.farmer,
+    )]
+    pub farmer_usdc_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct RepayLoan<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        close = farmer,
+        has_one = farmer,
+        seeds = [LOAN_SEED, farmer.key().as_ref(), loan.loan_id.to_le_bytes().as_ref()],
+        bump = loan.bump,
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+
+    #[account(
+        mut,
+        seeds = [LOAN_COLLATERAL_SEED, loan.key().as_ref()],
+        bump,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = program_state.mint,
+        token::authority = farmer,
+    )]
+    pub farmer_green_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = loan.usdc_mint,
+        token::authority = farmer,
+    )]
+    pub farmer_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = loan.usdc_mint,
+        token::authority = loan.lender,
+    )]
+    pub lender_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct LiquidateLoan<'info> {
+    pub lender: Signer<'info>,
+
+    /// CHECK: receives the loan and vault rent; pinned to the loan's farmer
+    #[account(mut, address = loan.farmer)]
+    pub farmer: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        close = farmer,
+        has_one = lender,
+        seeds = [LOAN_SEED, loan.farmer.as_ref(), loan.loan_id.to_le_bytes().as_ref()],
+        bump = loan.bump,
+    )]
+    pub loan: Box<Account<'info, Loan>>,
+
+    #[account(
+        mut,
+        seeds = [LOAN_COLLATERAL_SEED, loan.key().as_ref()],
+        bump,
+    )]
+    pub collateral_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = program_state.mint,
+        token::authority = lender,
+    )]
+    pub lender_green_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: PDA that owns the treasury vaults; signs for them only
+    #[account(seeds = [TREASURY_AUTHORITY_SEED], bump)]
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = usdc_mint,
+        token::authority = treasury_authority,
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = green_mint,
+        token::authority = treasury_authority,
+        seeds = [TREASURY_GREEN_SEED],
+        bump,
+    )]
+    pub treasury_green: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct BuybackAndBurn<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, seeds = [TREASURY_CONFIG_SEED], bump = treasury_config.bump)]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    /// The treasury guardian, when the buyback takes the day's outflows
+    /// past the daily limit
+    pub guardian: Option<Signer<'info>>,
+
+    #[account(seeds = [PRICING_SEED], bump = pricing_config.bump)]
+    pub pricing_config: Box<Account<'info, PricingConfig>>,
+
+    /// CHECK: Pyth price update; address pinned by `pricing_config`, layout
+    /// checked by `oracle::load_pyth_price`
+    #[account(address = pricing_config.price_feed @ KisanError::InvalidPriceFeed)]
+    pub price_feed: UncheckedAccount<'info>,
+
+    #[account(address = treasury_usdc.mint)]
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: PDA that owns the treasury vaults; signs for them only
+    #[account(seeds = [TREASURY_AUTHORITY_SEED], bump)]
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_GREEN_SEED],
+        bump,
+    )]
+    pub treasury_green: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: checked against the DEX allowlist in the handler
+    pub swap_program: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureTreasury<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + TreasuryConfig::INIT_SPACE,
+        seeds = [TREASURY_CONFIG_SEED],
+        bump,
+    )]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    /// The current guardian; required once one is set
+    pub guardian: Option<Signer<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [TREASURY_CONFIG_SEED], bump = treasury_config.bump)]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    /// CHECK: PDA that owns the treasury vaults; signs for them only
+    #[account(seeds = [TREASURY_AUTHORITY_SEED], bump)]
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, token::mint = treasury_usdc.mint)]
+    pub destination: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeTreasuryWithdrawal<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [TREASURY_CONFIG_SEED], bump = treasury_config.bump)]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    #[account(
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(token::mint = treasury_usdc.mint)]
+    pub destination: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TreasuryWithdrawal::INIT_SPACE,
+        seeds = [
+            TREASURY_WITHDRAWAL_SEED,
+            treasury_config.next_withdrawal_id.to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub withdrawal: Box<Account<'info, TreasuryWithdrawal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteTreasuryWithdrawal<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(address = treasury_config.guardian @ KisanError::GuardianSignatureRequired)]
+    pub guardian: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [TREASURY_CONFIG_SEED], bump = treasury_config.bump)]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [TREASURY_WITHDRAWAL_SEED, withdrawal.id.to_le_bytes().as_ref()],
+        bump = withdrawal.bump,
+    )]
+    pub withdrawal: Box<Account<'info, TreasuryWithdrawal>>,
+
+    /// CHECK: PDA that owns the treasury vaults; signs for them only
+    #[account(seeds = [TREASURY_AUTHORITY_SEED], bump)]
+    pub treasury_authority: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, address = withdrawal.destination)]
+    pub destination: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CancelTreasuryWithdrawal<'info> {
+    /// The authority or the guardian
+    #[account(
+        constraint = signer.key() == program_state.authority
+            || signer.key() == treasury_config.guardian
+            @ KisanError::UnauthorizedAuthority,
+    )]
+    pub signer: Signer<'info>,
+
+    /// CHECK: receives the proposal's rent; pinned to the authority
+    #[account(mut, address = program_state.authority)]
+    pub authority: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(seeds = [TREASURY_CONFIG_SEED], bump = treasury_config.bump)]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [TREASURY_WITHDRAWAL_SEED, withdrawal.id.to_le_bytes().as_ref()],
+        bump = withdrawal.bump,
+    )]
+    pub withdrawal: Box<Account<'info, TreasuryWithdrawal>>,
+}
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct SubmitClaim<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        has_one = farmer,
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump = farmer_profile.bump,
+        constraint = farmer_profile.compromised_at == 0 @ KisanError::WalletCompromised,
+    )]
+    pub farmer_profile: Account<'info, FarmerProfile>,
+
+    #[account(seeds = [STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    /// Required while the PM-KISAN beneficiary gate is on
+    #[account(
+        address = farmer_profile.pm_kisan_attestation @ KisanError::PmKisanAttestationRequired,
+    )]
+    pub pm_kisan_attestation: Option<Box<Account<'info, PmKisanAttestation>>>,
+
+    #[account(
+        init,
+        payer = farmer,
+        space = 8 + Claim::INIT_SPACE,
+        seeds = [CLAIM_SEED, commitment.as_ref()],
+        bump,
+    )]
+    pub claim: Account<'info, Claim>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(chain: u16)]
+pub struct RegisterForeignEmitter<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ForeignEmitter::INIT_SPACE,
+        seeds = [FOREIGN_EMITTER_SEED, chain.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub foreign_emitter: Account<'info, ForeignEmitter>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(vaa_hash: [u8; 32], commitment: [u8; 32])]
+pub struct MintFromVaa<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    /// CHECK: core bridge PostedVAA; address and owner checked by
+    /// `wormhole::load_posted_vaa`
+    pub posted_vaa: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [FOREIGN_EMITTER_SEED, foreign_emitter.chain.to_le_bytes().as_ref()],
+        bump = foreign_emitter.bump,
+    )]
+    pub foreign_emitter: Box<Account<'info, ForeignEmitter>>,
+
+    /// Replay protection per VAA
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VaaReceipt::INIT_SPACE,
+        seeds = [VAA_RECEIPT_SEED, vaa_hash.as_ref()],
+        bump,
+    )]
+    pub vaa_receipt: Box<Account<'info, VaaReceipt>>,
+
+    /// Replay protection per commitment, shared with local proofs
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProofRecord::INIT_SPACE,
+        seeds = [PROOF_SEED, commitment.as_ref()],
+        bump,
+    )]
+    pub proof_record: Box<Account<'info, ProofRecord>>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, program_state.current_season.to_le_bytes().as_ref()],
+        bump = season.bump,
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    /// Must belong to the farmer named in the payload
+    #[account(
+        mut,
+        token::mint = green_mint,
+    )]
+    pub farmer_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(batch_root: [u8; 32])]
+pub struct VerifyAggregateAndMint<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(seeds = [FEATURE_FLAGS_SEED], bump = feature_flags.bump)]
+    pub feature_flags: Box<Account<'info, FeatureFlags>>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Box<Account<'info, CircuitConfig>>,
+
+    /// Required when the circuit uses PLONK
+    #[account(
+        seeds = [PLONK_VK_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = plonk_vk.bump,
+    )]
+    pub plonk_vk: Option<Box<Account<'info, PlonkVerifyingKey>>>,
+
+    /// For proofs made against a key being rotated out: verifies them
+    /// instead of `plonk_vk` until its overlap window ends
+    #[account(
+        seeds = [PLONK_VK_PREVIOUS_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = previous_plonk_vk.bump,
+    )]
+    pub previous_plonk_vk: Option<Box<Account<'info, PreviousPlonkVerifyingKey>>>,
+
+    /// CHECK: Required when the circuit has an external verifier; address
+    /// pinned by `circuit_config`, interface in `verifier.rs`
+    #[account(address = circuit_config.verifier_program @ KisanError::InvalidVerifierProgram)]
+    pub verifier_program: Option<UncheckedAccount<'info>>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AggregateBatch::INIT_SPACE,
+        seeds = [AGGREGATE_SEED, batch_root.as_ref()],
+        bump,
+    )]
+    pub aggregate_batch: Box<Account<'info, AggregateBatch>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct RedeemAggregateLeaf<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub farmer: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = farmer,
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump = farmer_profile.bump,
+        constraint = farmer_profile.compromised_at == 0 @ KisanError::WalletCompromised,
+    )]
+    pub farmer_profile: Box<Account<'info, FarmerProfile>>,
+
+    /// Page of the farmer's claim index this claim is recorded on
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ClaimIndexPage::INIT_SPACE,
+        seeds = [
+            CLAIM_INDEX_SEED,
+            farmer_profile.farmer.as_ref(),
+            farmer_profile.claim_index_page().to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub claim_index: Box<Account<'info, ClaimIndexPage>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ KisanError::ProgramPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_TALLY_SEED, season.index.to_le_bytes().as_ref()],
+        bump = season_tally.bump,
+    )]
+    pub season_tally: Box<Account<'info, SeasonTally>>,
+
+    #[account(mut, seeds = [MINT_STATS_SEED], bump = mint_stats.load()?.bump)]
+    pub mint_stats: AccountLoader<'info, MintStats>,
+
+    /// The farmer's region
+    #[account(
+        seeds = [REGION_SEED, farmer_profile.region_code.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Box<Account<'info, RegionConfig>>,
+
+    /// Required when the farmer's region demands an identity attestation;
+    /// must be the one on `farmer_profile`
+    pub identity_attestation: Option<Box<Account<'info, IdentityAttestation>>>,
+
+    /// Required while the PM-KISAN beneficiary gate is on
+    #[account(
+        address = farmer_profile.pm_kisan_attestation @ KisanError::PmKisanAttestationRequired,
+    )]
+    pub pm_kisan_attestation: Option<Box<Account<'info, PmKisanAttestation>>>,
+
+    #[account(
+        mut,
+        seeds = [AGGREGATE_SEED, aggregate_batch.batch_root.as_ref()],
+        bump = aggregate_batch.bump,
+    )]
+    pub aggregate_batch: Box<Account<'info, AggregateBatch>>,
+
+    #[account(
+        mut,
+        seeds = [CIRCUIT_SEED, aggregate_batch.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Box<Account<'info, CircuitConfig>>,
+
+    /// Replay protection per commitment, shared with local proofs
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProofRecord::INIT_SPACE,
+        seeds = [PROOF_SEED, commitment.as_ref()],
+        bump,
+    )]
+    pub proof_record: Box<Account<'info, ProofRecord>>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, program_state.current_season.to_le_bytes().as_ref()],
+        bump = season.bump,
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        constraint = is_farmer_wallet(
+            &farmer_token_account.owner,
+            &farmer.key(),
+            custodial_link.as_deref(),
+        ) @ KisanError::InvalidFarmerTokenAccount,
+    )]
+    pub farmer_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Required when a custodial wallet holds `farmer_token_account`
+    #[account(
+        seeds = [CUSTODIAL_LINK_SEED, farmer.key().as_ref()],
+        bump = custodial_link.bump,
+    )]
+    pub custodial_link: Option<Account<'info, CustodialLink>>,
+
+    /// CHECK: SPL Memo program; pass it to attach a claim descriptor memo
+    #[account(address = memo::MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(eth_address: [u8; 20])]
+pub struct RegisterEvmOracle<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + EvmOracle::INIT_SPACE,
+        seeds = [EVM_ORACLE_SEED, eth_address.as_ref()],
+        bump,
+    )]
+    pub evm_oracle: Account<'info, EvmOracle>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveEvmOracle<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [EVM_ORACLE_SEED, evm_oracle.eth_address.as_ref()],
+        bump = evm_oracle.bump,
+    )]
+    pub evm_oracle: Account<'info, EvmOracle>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyClaimWithEvmReport<'info> {
+    #[account(
+        seeds = [EVM_ORACLE_SEED, evm_oracle.eth_address.as_ref()],
+        bump = evm_oracle.bump,
+    )]
+    pub evm_oracle: Account<'info, EvmOracle>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_SEED, claim.commitment.as_ref()],
+        bump = claim.bump,
+    )]
+    pub claim: Account<'info, Claim>,
+
+    /// CHECK: instructions sysvar, read for the Secp256k1 precompile
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureImagery<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Account<'info, CircuitConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ImageryConfig::INIT_SPACE,
+        seeds = [IMAGERY_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub imagery_config: Account<'info, ImageryConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct VerifyImageryAndMint<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump = farmer_profile.bump,
+        constraint = farmer_profile.compromised_at == 0 @ KisanError::WalletCompromised,
+    )]
+    pub farmer_profile: Box<Account<'info, FarmerProfile>>,
+
+    /// Page of the farmer's claim index this claim is recorded on
+    #[account(
+        init_if_needed,
+        payer = farmer,
+        space = 8 + ClaimIndexPage::INIT_SPACE,
+        seeds = [
+            CLAIM_INDEX_SEED,
+            farmer_profile.farmer.as_ref(),
+            farmer_profile.claim_index_page().to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub claim_index: Box<Account<'info, ClaimIndexPage>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ KisanError::ProgramPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_TALLY_SEED, season.index.to_le_bytes().as_ref()],
+        bump = season_tally.bump,
+    )]
+    pub season_tally: Box<Account<'info, SeasonTally>>,
+
+    #[account(mut, seeds = [MINT_STATS_SEED], bump = mint_stats.load()?.bump)]
+    pub mint_stats: AccountLoader<'info, MintStats>,
+
+    #[account(
+        mut,
+        seeds = [CIRCUIT_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Box<Account<'info, CircuitConfig>>,
+
+    #[account(
+        seeds = [IMAGERY_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = imagery_config.bump,
+    )]
+    pub imagery_config: Box<Account<'info, ImageryConfig>>,
+
+    #[account(
+        seeds = [GROTH16_VK_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = groth16_vk.bump,
+    )]
+    pub groth16_vk: Box<Account<'info, Groth16VerifyingKey>>,
+
+    /// For proofs made against a key being rotated out: verifies them
+    /// instead of `groth16_vk` until its overlap window ends
+    #[account(
+        seeds = [GROTH16_VK_PREVIOUS_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = previous_groth16_vk.bump,
+    )]
+    pub previous_groth16_vk: Option<Box<Account<'info, PreviousGroth16VerifyingKey>>>,
+
+    /// The circuit's imagery provider
+    #[account(address = imagery_config.provider)]
+    pub provider: Box<Account<'info, EvmOracle>>,
+
+    /// The farmer's region
+    #[account(
+        seeds = [REGION_SEED, farmer_profile.region_code.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Box<Account<'info, RegionConfig>>,
+
+    /// Required when the farmer's region demands an identity attestation;
+    /// must be the one on `farmer_profile`
+    pub identity_attestation: Option<Box<Account<'info, IdentityAttestation>>>,
+
+    /// Required while the PM-KISAN beneficiary gate is on
+    #[account(
+        address = farmer_profile.pm_kisan_attestation @ KisanError::PmKisanAttestationRequired,
+    )]
+    pub pm_kisan_attestation: Option<Box<Account<'info, PmKisanAttestation>>>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, program_state.current_season.to_le_bytes().as_ref()],
+        bump = season.bump,
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    #[account(
+        init_if_needed,
+        payer = farmer,
+        space = 8 + ProofRecord::INIT_SPACE,
+        seeds = [PROOF_SEED, commitment.as_ref()],
+        bump,
+    )]
+    pub proof_record: Box<Account<'info, ProofRecord>>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        constraint = is_farmer_wallet(
+            &farmer_token_account.owner,
+            &farmer.key(),
+            custodial_link.as_deref(),
+        ) @ KisanError::InvalidFarmerTokenAccount,
+    )]
+    pub farmer_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Required when a custodial wallet holds `farmer_token_account`
+    #[account(
+        seeds = [CUSTODIAL_LINK_SEED, farmer.key().as_ref()],
+        bump = custodial_link.bump,
+    )]
+    pub custodial_link: Option<Account<'info, CustodialLink>>,
+
+    /// CHECK: instructions sysvar, read for the Secp256k1 precompile
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// CHECK: SPL Memo program; pass it to attach a claim descriptor memo
+    #[account(address = memo::MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureDrones<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + DroneConfig::INIT_SPACE,
+        seeds = [DRONE_CONFIG_SEED],
+        bump,
+    )]
+    pub drone_config: Account<'info, DroneConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(operator: Pubkey)]
+pub struct RegisterDroneOperator<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + DroneOperator::INIT_SPACE,
+        seeds = [DRONE_OPERATOR_SEED, operator.as_ref()],
+        bump,
+    )]
+    pub drone_operator: Account<'info, DroneOperator>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct StakeDroneOperator<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [DRONE_OPERATOR_SEED, operator.key().as_ref()],
+        bump = drone_operator.bump,
+    )]
+    pub drone_operator: Account<'info, DroneOperator>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyClaimWithDrone<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [DRONE_OPERATOR_SEED, operator.key().as_ref()],
+        bump = drone_operator.bump,
+    )]
+    pub drone_operator: Account<'info, DroneOperator>,
+
+    #[account(seeds = [DRONE_CONFIG_SEED], bump = drone_config.bump)]
+    pub drone_config: Account<'info, DroneConfig>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_SEED, claim.commitment.as_ref()],
+        bump = claim.bump,
+    )]
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        init,
+        payer = operator,
+        space = 8 + DroneFlight::INIT_SPACE,
+        seeds = [DRONE_FLIGHT_SEED, claim.commitment.as_ref()],
+        bump,
+    )]
+    pub drone_flight: Account<'info, DroneFlight>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SlashDroneOperator<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [DRONE_OPERATOR_SEED, drone_operator.operator.as_ref()],
+        bump = drone_operator.bump,
+    )]
+    pub drone_operator: Account<'info, DroneOperator>,
+}
+
+#[derive(Accounts)]
+pub struct BeginUnbonding<'info> {
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [DRONE_OPERATOR_SEED, operator.key().as_ref()],
+        bump = drone_operator.bump,
+    )]
+    pub drone_operator: Account<'info, DroneOperator>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawDroneStake<'info> {
+    #[account(mut)]
+    pub operator: Signer<'info>,
+
+    #[account(
+        mut,
+        close = operator,
+        seeds = [DRONE_OPERATOR_SEED, operator.key().as_ref()],
+        bump = drone_operator.bump,
+    )]
+    pub drone_operator: Account<'info, DroneOperator>,
+
+    #[account(seeds = [DRONE_CONFIG_SEED], bump = drone_config.bump)]
+    pub drone_config: Account<'info, DroneConfig>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveMandiReporter<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [MANDI_REPORTER_SEED, reporter_info.reporter.as_ref()],
+        bump = reporter_info.bump,
+    )]
+    pub reporter_info: Account<'info, MandiReporter>,
+}
+
+#[derive(Accounts)]
+#[instruction(commodity: u16, region: u16)]
+pub struct InitializeMandiFeed<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<MandiPriceFeed>(),
+        seeds = [MANDI_FEED_SEED, commodity.to_le_bytes().as_ref(), region.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub mandi_feed: AccountLoader<'info, MandiPriceFeed>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(commodity: u16, region: u16)]
+pub struct PublishMandiPrice<'info> {
+    pub reporter: Signer<'info>,
+
+    /// Proves the signer is a reporter scoped to mandi prices
+    #[account(
+        seeds = [ORACLE_REPORTER_SEED, reporter.key().as_ref()],
+        bump = oracle_reporter.bump,
+        constraint = oracle_reporter.scopes & ORACLE_SCOPE_MANDI_PRICE != 0
+            @ KisanError::OracleScopeMissing,
+    )]
+    pub oracle_reporter: Account<'info, OracleReporter>,
+
+    #[account(seeds = [ORACLE_CONFIG_SEED], bump = oracle_config.bump)]
+    pub oracle_config: Account<'info, OracleConfig>,
+
+    #[account(
+        mut,
+        seeds = [MANDI_FEED_SEED, commodity.to_le_bytes().as_ref(), region.to_le_bytes().as_ref()],
+        bump = mandi_feed.load()?.bump,
+    )]
+    pub mandi_feed: AccountLoader<'info, MandiPriceFeed>,
+}
+
+#[derive(Accounts)]
+#[instruction(parcel_id_hash: [u8; 32])]
+pub struct AnchorLandRecord<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [ATTESTER_SEED, attester_info.attester.as_ref()],
+        bump = attester_info.bump,
+    )]
+    pub attester_info: Account<'info, AttesterInfo>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + LandRecord::INIT_SPACE,
+        seeds = [LAND_RECORD_SEED, parcel_id_hash.as_ref()],
+        bump,
+    )]
+    pub land_record: Account<'info, LandRecord>,
+
+    /// CHECK: instructions sysvar, read for the Ed25519 precompile
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetLandRecordRequirement<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [REGION_SEED, region_config.region_code.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Account<'info, RegionConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SetCrossChainConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+}
+
+#[derive(Accounts)]
+pub struct EmitCrossChainAttestation<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        seeds = [PROOF_SEED, proof_record.commitment.as_ref()],
+        bump = proof_record.bump,
+    )]
+    pub proof_record: Box<Account<'info, ProofRecord>>,
+
+    /// CHECK: core bridge program configured in program state
+    #[account(address = program_state.wormhole_program)]
+    pub wormhole_program: UncheckedAccount<'info>,
+
+    /// CHECK: core bridge config; validated by the core bridge
+    #[account(mut)]
+    pub wormhole_bridge: UncheckedAccount<'info>,
+
+    /// CHECK: message account created by the core bridge; one per commitment
+    #[account(
+        mut,
+        seeds = [WORMHOLE_MESSAGE_SEED, proof_record.commitment.as_ref()],
+        bump,
+    )]
+    pub wormhole_message: UncheckedAccount<'info>,
+
+    /// CHECK: this program's emitter PDA; signs the message
+    #[account(seeds = [WORMHOLE_EMITTER_SEED], bump)]
+    pub wormhole_emitter: UncheckedAccount<'info>,
+
+    /// CHECK: emitter sequence tracker; validated by the core bridge
+    #[account(mut)]
+    pub wormhole_sequence: UncheckedAccount<'info>,
+
+    /// CHECK: bridge fee collector; validated by the core bridge
+    #[account(mut)]
+    pub wormhole_fee_collector: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32], circuit_id: u16)]
+pub struct VerifyProof<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub farmer: Signer<'info>,
+
+    #[account(
+        has_one = farmer,
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump = farmer_profile.bump,
+    )]
+    pub farmer_profile: Account<'info, FarmerProfile>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Claim::INIT_SPACE,
+        seeds = [CLAIM_SEED, commitment.as_ref()],
+        bump,
+    )]
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Box<Account<'info, CircuitConfig>>,
+
+    #[account(
+        seeds = [GROTH16_VK_SEED, circuit_id.to_le_bytes().as_ref()],
+        bump = groth16_vk.bump,
+    )]
+    pub groth16_vk: Box<Account<'info, Groth16VerifyingKey>>,
+
+    /// For proofs made against a key being rotated out: verifies them
+    /// instead of `groth16_vk` until its overlap window ends
+    #[account(
+        seeds = [GROTH16_VK_PREVIOUS_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = previous_groth16_vk.bump,
+    )]
+    pub previous_groth16_vk: Option<Box<Account<'info, PreviousGroth16VerifyingKey>>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyClaim<'info> {
+    pub verifier: Signer<'info>,
+
+    /// Required for attestation-based verification
+    #[account(
+        seeds = [ATTESTER_SEED, verifier.key().as_ref()],
+        bump = attester_info.bump,
+    )]
+    pub attester_info: Option<Account<'info, AttesterInfo>>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_SEED, claim.commitment.as_ref()],
+        bump = claim.bump,
+    )]
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        seeds = [CIRCUIT_SEED, claim.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Box<Account<'info, CircuitConfig>>,
+
+    /// Required for proof-based verification
+    #[account(
+        seeds = [GROTH16_VK_SEED, claim.circuit_id.to_le_bytes().as_ref()],
+        bump = groth16_vk.bump,
+    )]
+    pub groth16_vk: Option<Box<Account<'info, Groth16VerifyingKey>>>,
+
+    /// For proofs made against a key being rotated out: verifies them
+    /// instead of `groth16_vk` until its overlap window ends
+    #[account(
+        seeds = [GROTH16_VK_PREVIOUS_SEED, circuit_config.circuit_id.to_le_bytes().as_ref()],
+        bump = previous_groth16_vk.bump,
+    )]
+    pub previous_groth16_vk: Option<Box<Account<'info, PreviousGroth16VerifyingKey>>>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyClaimWithQuorum<'info> {
+    #[account(mut)]
+    pub attester: Signer<'info>,
+
+    #[account(
+        seeds = [ATTESTER_SEED, attester.key().as_ref()],
+        bump = attester_info.bump,
+    )]
+    pub attester_info: Account<'info, AttesterInfo>,
+
+    #[account(
+        seeds = [NODE_REWARDS_SEED, attester.key().as_ref()],
+        bump = node_rewards.bump,
+    )]
+    pub node_rewards: Account<'info, NodeRewards>,
+
+    #[account(seeds = [NODE_REWARD_CONFIG_SEED], bump = node_reward_config.bump)]
+    pub node_reward_config: Account<'info, NodeRewardConfig>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_SEED, claim.commitment.as_ref()],
+        bump = claim.bump,
+    )]
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        init,
+        payer = attester,
+        space = 8 + ClaimAttestation::INIT_SPACE,
+        seeds = [CLAIM_ATTESTATION_SEED, claim.key().as_ref()],
+        bump,
+    )]
+    pub claim_attestation: Account<'info, ClaimAttestation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureNodeRewards<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + NodeRewardConfig::INIT_SPACE,
+        seeds = [NODE_REWARD_CONFIG_SEED],
+        bump,
+    )]
+    pub node_reward_config: Box<Account<'info, NodeRewardConfig>>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [NODE_REWARD_VAULT_SEED],
+        bump,
+    )]
+    pub node_reward_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct OpenNodeRewards<'info> {
+    #[account(mut)]
+    pub node: Signer<'info>,
+
+    #[account(
+        seeds = [ATTESTER_SEED, node.key().as_ref()],
+        bump = attester_info.bump,
+    )]
+    pub attester_info: Account<'info, AttesterInfo>,
+
+    #[account(
+        init,
+        payer = node,
+        space = 8 + NodeRewards::INIT_SPACE,
+        seeds = [NODE_REWARDS_SEED, node.key().as_ref()],
+        bump,
+    )]
+    pub node_rewards: Account<'info, NodeRewards>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimNodeRewards<'info> {
+    pub node: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        has_one = node,
+        seeds = [NODE_REWARDS_SEED, node.key().as_ref()],
+        bump = node_rewards.bump,
+    )]
+    pub node_rewards: Box<Account<'info, NodeRewards>>,
+
+    #[account(
+        mut,
+        seeds = [NODE_REWARD_VAULT_SEED],
+        bump,
+    )]
+    pub node_reward_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = node_reward_vault.mint,
+        token::authority = node,
+    )]
+    pub node_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct IssueCredits<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_SEED, claim.commitment.as_ref()],
+        bump = claim.bump,
+    )]
+    pub claim: Box<Account<'info, Claim>>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        constraint = is_farmer_wallet(
+            &farmer_token_account.owner,
+            &claim.farmer,
+            custodial_link.as_deref(),
+        ) @ KisanError::InvalidFarmerTokenAccount,
+    )]
+    pub farmer_token_account: Box<Account<'info, TokenAccount>>,
+
+    /// Required when a custodial wallet holds `farmer_token_account`
+    #[account(
+        seeds = [CUSTODIAL_LINK_SEED, claim.farmer.as_ref()],
+        bump = custodial_link.bump,
+    )]
+    pub custodial_link: Option<Account<'info, CustodialLink>>,
+
+    #[account(
+        mut,
+        seeds = [FARMER_SEED, claim.farmer.as_ref()],
+        bump = farmer_profile.bump,
+        constraint = farmer_profile.compromised_at == 0 @ KisanError::WalletCompromised,
+    )]
+    pub farmer_profile: Box<Account<'info, FarmerProfile>>,
+
+    /// Page of the farmer's claim index this claim is recorded on
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ClaimIndexPage::INIT_SPACE,
+        seeds = [
+            CLAIM_INDEX_SEED,
+            farmer_profile.farmer.as_ref(),
+            farmer_profile.claim_index_page().to_le_bytes().as_ref(),
+        ],
+        bump,
+    )]
+    pub claim_index: Box<Account<'info, ClaimIndexPage>>,
+
+    /// The farmer's region
+    #[account(
+        seeds = [REGION_SEED, farmer_profile.region_code.to_le_bytes().as_ref()],
+        bump = region_config.bump,
+    )]
+    pub region_config: Box<Account<'info, RegionConfig>>,
+
+    /// Required when the farmer's region demands an identity attestation;
+    /// must be the one on `farmer_profile`
+    pub identity_attestation: Option<Box<Account<'info, IdentityAttestation>>>,
+
+    #[account(
+        seeds = [CONFIG_SEED],
+        bump = config.bump,
+        constraint = !config.paused @ KisanError::ProgramPaused,
+    )]
+    pub config: Box<Account<'info, Config>>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_TALLY_SEED, season.index.to_le_bytes().as_ref()],
+        bump = season_tally.bump,
+    )]
+    pub season_tally: Box<Account<'info, SeasonTally>>,
+
+    #[account(mut, seeds = [MINT_STATS_SEED], bump = mint_stats.load()?.bump)]
+    pub mint_stats: AccountLoader<'info, MintStats>,
+
+    #[account(
+        mut,
+        seeds = [CIRCUIT_SEED, claim.circuit_id.to_le_bytes().as_ref()],
+        bump = circuit_config.bump,
+    )]
+    pub circuit_config: Box<Account<'info, CircuitConfig>>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, program_state.current_season.to_le_bytes().as_ref()],
+        bump = season.bump,
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    #[account(seeds = [FEATURE_FLAGS_SEED], bump = feature_flags.bump)]
+    pub feature_flags: Box<Account<'info, FeatureFlags>>,
+
+    /// Required while dynamic pricing is enabled
+    #[account(seeds = [PRICING_SEED], bump = pricing_config.bump)]
+    pub pricing_config: Option<Box<Account<'info, PricingConfig>>>,
+
+    /// CHECK: Pyth price update; address pinned by `pricing_config`, layout
+    /// checked by `oracle::load_pyth_price`
+    #[account(address = pricing_config.as_ref().map(|p| p.price_feed).unwrap_or_default())]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    /// Shares the commitment namespace with `verify_and_mint`, so a
+    /// commitment is only ever paid once
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProofRecord::INIT_SPACE,
+        seeds = [PROOF_SEED, claim.commitment.as_ref()],
+        bump,
+    )]
+    pub proof_record: Box<Account<'info, ProofRecord>>,
+
+    /// Required when an attester quorum verified the claim
+    #[account(
+        seeds = [CLAIM_ATTESTATION_SEED, claim.key().as_ref()],
+        bump = claim_attestation.bump,
+    )]
+    pub claim_attestation: Option<Box<Account<'info, ClaimAttestation>>>,
+
+    /// Required with `claim_attestation`
+    #[account(
+        mut,
+        seeds = [NODE_REWARD_CONFIG_SEED],
+        bump = node_reward_config.bump,
+    )]
+    pub node_reward_config: Option<Box<Account<'info, NodeRewardConfig>>>,
+
+    /// Required with `claim_attestation`
+    #[account(
+        mut,
+        seeds = [NODE_REWARD_VAULT_SEED],
+        bump,
+    )]
+    pub node_reward_vault: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// CHECK: SPL Memo program; pass it to attach a claim descriptor memo
+    #[account(address = memo::MEMO_PROGRAM_ID)]
+    pub memo_program: Option<UncheckedAccount<'info>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct QueueClaim<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_SEED, claim.commitment.as_ref()],
+        bump = claim.bump,
+    )]
+    pub claim: Account<'info, Claim>,
+
+    #[account(seeds = [STATE_SEED], bump = program_state.bump)]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        seeds = [SEASON_SEED, program_state.current_season.to_le_bytes().as_ref()],
+        bump = season.bump,
+    )]
+    pub season: Account<'info, Season>,
+
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ClaimQueue::INIT_SPACE,
+        seeds = [CLAIM_QUEUE_SEED],
+        bump,
+    )]
+    pub claim_queue: Account<'info, ClaimQueue>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + QueuedClaim::INIT_SPACE,
+        seeds = [QUEUED_CLAIM_SEED, claim_queue.tail.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub queued_claim: Account<'info, QueuedClaim>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProcessQueuedClaim<'info> {
+    pub issue: IssueCredits<'info>,
+
+    #[account(mut, seeds = [CLAIM_QUEUE_SEED], bump = claim_queue.bump)]
+    pub claim_queue: Account<'info, ClaimQueue>,
+
+    /// The head of the queue, which must be `issue.claim`
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [QUEUED_CLAIM_SEED, claim_queue.head.to_le_bytes().as_ref()],
+        bump = queued_claim.bump,
+        constraint = queued_claim.claim == issue.claim.key() @ KisanError::QueueOrder,
+    )]
+    pub queued_claim: Account<'info, QueuedClaim>,
+
+    /// CHECK: receives the entry's rent; pinned to whoever queued it
+    #[account(mut, address = queued_claim.payer)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SkipQueuedClaim<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_SEED, claim.commitment.as_ref()],
+        bump = claim.bump,
+    )]
+    pub claim: Account<'info, Claim>,
+
+    #[account(mut, seeds = [CLAIM_QUEUE_SEED], bump = claim_queue.bump)]
+    pub claim_queue: Account<'info, ClaimQueue>,
+
+    #[account(
+        mut,
+        close = rent_payer,
+        seeds = [QUEUED_CLAIM_SEED, claim_queue.head.to_le_bytes().as_ref()],
+        bump = queued_claim.bump,
+        constraint = queued_claim.claim == claim.key() @ KisanError::QueueOrder,
+    )]
+    pub queued_claim: Account<'info, QueuedClaim>,
+
+    /// CHECK: receives the entry's rent; pinned to whoever queued it
+    #[account(mut, address = queued_claim.payer)]
+    pub rent_payer: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct Retire<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [CLAIM_SEED, claim.commitment.as_ref()],
+        bump = claim.bump,
+    )]
+    pub claim: Account<'info, Claim>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        token::authority = holder,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = holder,
+        space = 8 + BuyerProfile::INIT_SPACE,
+        seeds = [BUYER_PROFILE_SEED, holder.key().as_ref()],
+        bump,
+    )]
+    pub buyer_profile: Account<'info, BuyerProfile>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigurePayouts<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PayoutConfig::INIT_SPACE,
+        seeds = [PAYOUT_CONFIG_SEED],
+        bump,
+    )]
+    pub payout_config: Account<'info, PayoutConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64, payout_ref_hash: [u8; 32])]
+pub struct RedeemForVoucher<'info> {
+    #[account(mut)]
+    pub holder: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [PAYOUT_CONFIG_SEED], bump = payout_config.bump)]
+    pub payout_config: Account<'info, PayoutConfig>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        token::authority = holder,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = holder,
+        space = 8 + VoucherRedemption::INIT_SPACE,
+        seeds = [VOUCHER_SEED, payout_ref_hash.as_ref()],
+        bump,
+    )]
+    pub voucher: Account<'info, VoucherRedemption>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MarkVoucherPaid<'info> {
+    pub partner: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [PAYOUT_CONFIG_SEED],
+        bump = payout_config.bump,
+        has_one = partner @ KisanError::UnauthorizedPayoutPartner,
+    )]
+    pub payout_config: Account<'info, PayoutConfig>,
+
+    #[account(
+        mut,
+        seeds = [VOUCHER_SEED, voucher.payout_ref_hash.as_ref()],
+        bump = voucher.bump,
+    )]
+    pub voucher: Account<'info, VoucherRedemption>,
+}
+
+#[derive(Accounts)]
+pub struct DisputeVoucher<'info> {
+    pub holder: Signer<'info>,
+
+    #[account(seeds = [PAYOUT_CONFIG_SEED], bump = payout_config.bump)]
+    pub payout_config: Account<'info, PayoutConfig>,
+
+    #[account(
+        mut,
+        has_one = holder,
+        seeds = [VOUCHER_SEED, voucher.payout_ref_hash.as_ref()],
+        bump = voucher.bump,
+    )]
+    pub voucher: Account<'info, VoucherRedemption>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveVoucherDispute<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        mut,
+        seeds = [VOUCHER_SEED, voucher.payout_ref_hash.as_ref()],
+        bump = voucher.bump,
+    )]
+    pub voucher: Account<'info, VoucherRedemption>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Account<'info, Mint>,
+
+    /// Receives a refund; any $GREEN account of the holder
+    #[account(
+        mut,
+        token::mint = green_mint,
+        token::authority = voucher.holder,
+    )]
+    pub holder_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(methodology_id: u16)]
+pub struct SetPriceFloor<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Account<'info, ProgramState>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PriceFloor::INIT_SPACE,
+        seeds = [PRICE_FLOOR_SEED, methodology_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub price_floor: Account<'info, PriceFloor>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(listing_id: u64)]
+pub struct CreateListing<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(seeds = [FEATURE_FLAGS_SEED], bump = feature_flags.bump)]
+    pub feature_flags: Box<Account<'info, FeatureFlags>>,
+
+    /// Issuance the credits came from; fixes the listing's methodology and
+    /// who is paid resale royalties
+    #[account(
+        seeds = [PROOF_SEED, proof_record.commitment.as_ref()],
+        bump = proof_record.bump,
+    )]
+    pub proof_record: Box<Account<'info, ProofRecord>>,
+
+    /// Must exist: a methodology can't be listed until a floor is set
+    #[account(
+        seeds = [PRICE_FLOOR_SEED, proof_record.methodology_id.to_le_bytes().as_ref()],
+        bump = price_floor.bump,
+    )]
+    pub price_floor: Box<Account<'info, PriceFloor>>,
+
+    /// CHECK: Pyth price update; address pinned by `price_floor`, layout
+    /// checked by `oracle::load_pyth_price`
+    #[account(address = price_floor.price_feed)]
+    pub price_feed: Option<UncheckedAccount<'info>>,
+
+    /// Fixes the settlement currency to the treasury's
+    #[account(
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = treasury_usdc.mint)]
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        token::authority = seller,
+    )]
+    pub seller_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = seller,
+        space = 8 + Listing::INIT_SPACE,
+        seeds = [LISTING_SEED, seller.key().as_ref(), listing_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    #[account(
+        init,
+        payer = seller,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [LISTING_ESCROW_SEED, listing.key().as_ref()],
+        bump,
+    )]
+    pub listing_escrow: Box<Account<'info, TokenAccount>>,
+
+    /// The originating farmer's royalty balance, created on first listing
+    #[account(
+        init_if_needed,
+        payer = seller,
+        space = 8 + RoyaltyAccount::INIT_SPACE,
+        seeds = [ROYALTY_SEED, proof_record.farmer.as_ref()],
+        bump,
+    )]
+    pub royalty: Box<Account<'info, RoyaltyAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct BuyListing<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(seeds = [FEATURE_FLAGS_SEED], bump = feature_flags.bump)]
+    pub feature_flags: Box<Account<'info, FeatureFlags>>,
+
+    #[account(
+        mut,
+        seeds = [
+            LISTING_SEED,
+            listing.seller.as_ref(),
+            listing.listing_id.to_le_bytes().as_ref(),
+        ],
+        bump = listing.bump,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    #[account(
+        mut,
+        seeds = [LISTING_ESCROW_SEED, listing.key().as_ref()],
+        bump,
+    )]
+    pub listing_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = listing.usdc_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = listing.usdc_mint,
+        token::authority = listing.seller,
+    )]
+    pub seller_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = listing_escrow.mint,
+    )]
+    pub buyer_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [MARKET_CONFIG_SEED], bump = market_config.bump)]
+    pub market_config: Box<Account<'info, MarketConfig>>,
+
+    #[account(
+        mut,
+        seeds = [ROYALTY_VAULT_SEED],
+        bump,
+        token::mint = listing.usdc_mint,
+    )]
+    pub royalty_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [ROYALTY_SEED, listing.origin_farmer.as_ref()],
+        bump = royalty.bump,
+    )]
+    pub royalty: Box<Account<'info, RoyaltyAccount>>,
+
+    #[account(mut, seeds = [TREASURY_CONFIG_SEED], bump = treasury_config.bump)]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+        token::mint = listing.usdc_mint,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init_if_needed,
+        payer = buyer,
+        space = 8 + BuyerProfile::INIT_SPACE,
+        seeds = [BUYER_PROFILE_SEED, buyer.key().as_ref()],
+        bump,
+    )]
+    pub buyer_profile: Box<Account<'info, BuyerProfile>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelListing<'info> {
+    #[account(mut)]
+    pub seller: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        close = seller,
+        has_one = seller,
+        seeds = [LISTING_SEED, seller.key().as_ref(), listing.listing_id.to_le_bytes().as_ref()],
+        bump = listing.bump,
+    )]
+    pub listing: Box<Account<'info, Listing>>,
+
+    #[account(
+        mut,
+        seeds = [LISTING_ESCROW_SEED, listing.key().as_ref()],
+        bump,
+    )]
+    pub listing_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = listing_escrow.mint,
+        token::authority = seller,
+    )]
+    pub seller_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(counterparty: Pubkey)]
+pub struct CreateOtc<'info> {
+    #[account(mut)]
+    pub maker: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(seeds = [FEATURE_FLAGS_SEED], bump = feature_flags.bump)]
+    pub feature_flags: Box<Account<'info, FeatureFlags>>,
+
+    /// Fixes the settlement currency to the treasury's
+    #[account(
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = treasury_usdc.mint)]
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        token::authority = maker,
+    )]
+    pub maker_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        init,
+        payer = maker,
+        space = 8 + OtcDeal::INIT_SPACE,
+        seeds = [OTC_SEED, maker.key().as_ref(), counterparty.as_ref()],
+        bump,
+    )]
+    pub otc: Box<Account<'info, OtcDeal>>,
+
+    #[account(
+        init,
+        payer = maker,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [OTC_ESCROW_SEED, otc.key().as_ref()],
+        bump,
+    )]
+    pub otc_escrow: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct AcceptOtc<'info> {
+    pub counterparty: Signer<'info>,
+
+    /// CHECK: the deal's seller, refunded the rent; address pinned to the deal
+    #[account(mut, address = otc.maker)]
+    pub maker: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(seeds = [FEATURE_FLAGS_SEED], bump = feature_flags.bump)]
+    pub feature_flags: Box<Account<'info, FeatureFlags>>,
+
+    #[account(
+        mut,
+        close = maker,
+        has_one = counterparty @ KisanError::InvalidCounterparty,
+        seeds = [OTC_SEED, otc.maker.as_ref(), counterparty.key().as_ref()],
+        bump = otc.bump,
+    )]
+    pub otc: Box<Account<'info, OtcDeal>>,
+
+    #[account(
+        mut,
+        seeds = [OTC_ESCROW_SEED, otc.key().as_ref()],
+        bump,
+    )]
+    pub otc_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = otc.usdc_mint,
+        token::authority = counterparty,
+    )]
+    pub counterparty_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = otc.usdc_mint,
+        token::authority = otc.maker,
+    )]
+    pub maker_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = otc_escrow.mint,
+    )]
+    pub counterparty_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [TREASURY_CONFIG_SEED], bump = treasury_config.bump)]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+        token::mint = otc.usdc_mint,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CancelOtc<'info> {
+    /// The seller, or anyone once the deal has expired
+    pub caller: Signer<'info>,
+
+    /// CHECK: the deal's seller, refunded the rent; address pinned to the deal
+    #[account(mut, address = otc.maker)]
+    pub maker: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        close = maker,
+        seeds = [OTC_SEED, otc.maker.as_ref(), otc.counterparty.as_ref()],
+        bump = otc.bump,
+    )]
+    pub otc: Box<Account<'info, OtcDeal>>,
+
+    #[account(
+        mut,
+        seeds = [OTC_ESCROW_SEED, otc.key().as_ref()],
+        bump,
+    )]
+    pub otc_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = otc_escrow.mint,
+        token::authority = otc.maker,
+    )]
+    pub maker_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeSgreen<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SgreenPool::INIT_SPACE,
+        seeds = [SGREEN_POOL_SEED],
+        bump,
+    )]
+    pub sgreen_pool: Box<Account<'info, SgreenPool>>,
+
+    /// CHECK: created here as a Token-2022 mint with the interest-bearing
+    /// extension, which Anchor's `init` can't set up
+    #[account(mut, seeds = [SGREEN_MINT_SEED], bump)]
+    pub sgreen_mint: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [SGREEN_VAULT_SEED],
+        bump,
+    )]
+    pub sgreen_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct SetSgreenRate<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [SGREEN_POOL_SEED], bump = sgreen_pool.bump)]
+    pub sgreen_pool: Box<Account<'info, SgreenPool>>,
+
+    #[account(mut, seeds = [SGREEN_MINT_SEED], bump = sgreen_pool.mint_bump)]
+    pub sgreen_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+/// Shared by `stake_green` and `redeem_sgreen`.
+#[derive(Accounts)]
+pub struct SgreenExchange<'info> {
+    pub holder: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [SGREEN_POOL_SEED], bump = sgreen_pool.bump)]
+    pub sgreen_pool: Box<Account<'info, SgreenPool>>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut, seeds = [SGREEN_MINT_SEED], bump = sgreen_pool.mint_bump)]
+    pub sgreen_mint: Box<InterfaceAccount<'info, token_interface::Mint>>,
+
+    #[account(
+        mut,
+        seeds = [SGREEN_VAULT_SEED],
+        bump,
+    )]
+    pub sgreen_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        token::authority = holder,
+    )]
+    pub holder_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = sgreen_mint,
+        token::authority = holder,
+        token::token_program = token_2022_program,
+    )]
+    pub holder_sgreen_account: Box<InterfaceAccount<'info, token_interface::TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub token_2022_program: Program<'info, Token2022>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureGovernance<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + GovernanceConfig::INIT_SPACE,
+        seeds = [GOVERNANCE_SEED],
+        bump,
+    )]
+    pub governance_config: Box<Account<'info, GovernanceConfig>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [GOVERNANCE_VAULT_SEED],
+        bump,
+    )]
+    pub governance_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct SetVoteWeighting<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(mut, seeds = [GOVERNANCE_SEED], bump = governance_config.bump)]
+    pub governance_config: Box<Account<'info, GovernanceConfig>>,
+}
+
+#[derive(Accounts)]
+pub struct DepositVotes<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [VOTER_SEED, owner.key().as_ref()],
+        bump,
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_VAULT_SEED],
+        bump,
+    )]
+    pub governance_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawVotes<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [VOTER_SEED, owner.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    #[account(
+        mut,
+        seeds = [GOVERNANCE_VAULT_SEED],
+        bump,
+    )]
+    pub governance_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = governance_vault.mint,
+        token::authority = owner,
+    )]
+    pub owner_token_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct DelegateVotes<'info> {
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [VOTER_SEED, owner.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    #[account(
+        init_if_needed,
+        payer = owner,
+        space = 8 + VoterRecord::INIT_SPACE,
+        seeds = [VOTER_SEED, delegate.as_ref()],
+        bump,
+        constraint = delegate != owner.key() @ KisanError::InvalidVoteDelegate,
+    )]
+    pub delegate_record: Box<Account<'info, VoterRecord>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevokeVoteDelegation<'info> {
+    pub owner: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = owner,
+        seeds = [VOTER_SEED, owner.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    #[account(
+        mut,
+        seeds = [VOTER_SEED, voter_record.delegate.as_ref()],
+        bump = delegate_record.bump,
+        constraint = voter_record.delegate != Pubkey::default() @ KisanError::InvalidVoteDelegate,
+    )]
+    pub delegate_record: Box<Account<'info, VoterRecord>>,
+}
+
+#[derive(Accounts)]
+pub struct CreateProposal<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    #[account(mut, seeds = [GOVERNANCE_SEED], bump = governance_config.bump)]
+    pub governance_config: Box<Account<'info, GovernanceConfig>>,
+
+    #[account(
+        seeds = [VOTER_SEED, proposer.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + Proposal::INIT_SPACE,
+        seeds = [PROPOSAL_SEED, governance_config.next_proposal_id.to_le_bytes().as_ref()],
+        bump,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CastVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VOTER_SEED, voter.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    /// One per voter and proposal, so each deposit votes once
+    #[account(
+        init,
+        payer = voter,
+        space = 8 + VoteRecord::INIT_SPACE,
+        seeds = [VOTE_SEED, proposal.key().as_ref(), voter.key().as_ref()],
+        bump,
+    )]
+    pub vote_record: Box<Account<'info, VoteRecord>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RelinquishVote<'info> {
+    #[account(mut)]
+    pub voter: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [VOTER_SEED, voter.key().as_ref()],
+        bump = voter_record.bump,
+    )]
+    pub voter_record: Box<Account<'info, VoterRecord>>,
+
+    #[account(
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(
+        mut,
+        close = voter,
+        seeds = [VOTE_SEED, proposal.key().as_ref(), voter.key().as_ref()],
+        bump = vote_record.bump,
+    )]
+    pub vote_record: Box<Account<'info, VoteRecord>>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteProposal<'info> {
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        seeds = [PROPOSAL_SEED, proposal.id.to_le_bytes().as_ref()],
+        bump = proposal.bump,
+    )]
+    pub proposal: Box<Account<'info, Proposal>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    /// `SetRewardAmount` and `Pause`
+    #[account(mut, seeds = [CONFIG_SEED], bump = config.bump)]
+    pub config: Option<Box<Account<'info, Config>>>,
+
+    /// `SetFee` and `TreasurySpend`
+    #[account(mut, seeds = [TREASURY_CONFIG_SEED], bump = treasury_config.bump)]
+    pub treasury_config: Option<Box<Account<'info, TreasuryConfig>>>,
+
+    /// `SetVerifyingKey` with a Groth16 key: the proposal circuit's key
+    #[account(
+        mut,
+        seeds = [GROTH16_VK_SEED, groth16_vk.circuit_id.to_le_bytes().as_ref()],
+        bump = groth16_vk.bump,
+    )]
+    pub groth16_vk: Option<Box<Account<'info, Groth16VerifyingKey>>>,
+
+    /// And where its outgoing key is kept
+    #[account(
+        mut,
+        seeds = [GROTH16_VK_PREVIOUS_SEED, previous_groth16_vk.circuit_id.to_le_bytes().as_ref()],
+        bump = previous_groth16_vk.bump,
+    )]
+    pub previous_groth16_vk: Option<Box<Account<'info, PreviousGroth16VerifyingKey>>>,
+
+    /// `SetVerifyingKey` with a PLONK key: the proposal circuit's key
+    #[account(
+        mut,
+        seeds = [PLONK_VK_SEED, plonk_vk.circuit_id.to_le_bytes().as_ref()],
+        bump = plonk_vk.bump,
+    )]
+    pub plonk_vk: Option<Box<Account<'info, PlonkVerifyingKey>>>,
+
+    /// And where its outgoing key is kept
+    #[account(
+        mut,
+        seeds = [PLONK_VK_PREVIOUS_SEED, previous_plonk_vk.circuit_id.to_le_bytes().as_ref()],
+        bump = previous_plonk_vk.bump,
+    )]
+    pub previous_plonk_vk: Option<Box<Account<'info, PreviousPlonkVerifyingKey>>>,
+
+    /// CHECK: `TreasurySpend`: PDA that owns the treasury vaults
+    #[account(seeds = [TREASURY_AUTHORITY_SEED], bump)]
+    pub treasury_authority: Option<UncheckedAccount<'info>>,
+
+    /// `TreasurySpend`
+    #[account(
+        mut,
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+    )]
+    pub treasury_usdc: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// `TreasurySpend` and `MigrateLiquidity`: the proposal's destination
+    #[account(mut)]
+    pub destination: Option<Box<Account<'info, TokenAccount>>>,
+
+    /// `TreasurySpend` past the daily limit: the treasury guardian
+    pub guardian: Option<Signer<'info>>,
+
+    /// `MigrateLiquidity`
+    #[account(mut, seeds = [CURVE_SEED], bump = bonding_curve.bump)]
+    pub bonding_curve: Option<Box<Account<'info, BondingCurve>>>,
+
+    /// `MigrateLiquidity`
+    #[account(
+        mut,
+        seeds = [CURVE_RESERVE_SEED],
+        bump,
+    )]
+    pub curve_reserve: Option<Box<Account<'info, TokenAccount>>>,
+
+    pub token_program: Option<Program<'info, Token>>,
+}
+
+#[derive(Accounts)]
+pub struct ConfigureMarketplace<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MarketConfig::INIT_SPACE,
+        seeds = [MARKET_CONFIG_SEED],
+        bump,
+    )]
+    pub market_config: Box<Account<'info, MarketConfig>>,
+
+    /// Fixes the royalty currency to the treasury's, the same currency
+    /// listings settle in
+    #[account(
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = treasury_usdc.mint)]
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = usdc_mint,
+        token::authority = program_state,
+        seeds = [ROYALTY_VAULT_SEED],
+        bump,
+    )]
+    pub royalty_vault: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRoyalties<'info> {
+    pub farmer: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        has_one = farmer,
+        seeds = [ROYALTY_SEED, farmer.key().as_ref()],
+        bump = royalty.bump,
+    )]
+    pub royalty: Box<Account<'info, RoyaltyAccount>>,
+
+    #[account(
+        mut,
+        seeds = [ROYALTY_VAULT_SEED],
+        bump,
+    )]
+    pub royalty_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = royalty_vault.mint,
+        token::authority = farmer,
+    )]
+    pub farmer_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+#[instruction(commitment: [u8; 32])]
+pub struct GetProofStatus<'info> {
+    /// CHECK: the commitment's ProofRecord PDA, which may not exist yet
+    #[account(seeds = [PROOF_SEED, commitment.as_ref()], bump)]
+    pub proof_record: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct GetFarmerStats<'info> {
+    #[account(
+        seeds = [FARMER_SEED, farmer_profile.farmer.as_ref()],
+        bump = farmer_profile.bump,
+    )]
+    pub farmer_profile: Account<'info, FarmerProfile>,
+}
+
+#[derive(Accounts)]
+pub struct GetAddresses {}
+
+#[derive(Accounts)]
+pub struct UpdateEvidence<'info> {
+    pub farmer: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = farmer,
+        seeds = [PROOF_SEED, proof_record.commitment.as_ref()],
+        bump = proof_record.bump,
+    )]
+    pub proof_record: Account<'info, ProofRecord>,
+}
+
+#[derive(Accounts)]
+pub struct LookupSerial<'info> {
+    #[account(
+        seeds = [PROOF_SEED, proof_record.commitment.as_ref()],
+        bump = proof_record.bump,
+    )]
+    pub proof_record: Account<'info, ProofRecord>,
+}
+
+#[derive(Accounts)]
+pub struct CreateSubscription<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    /// Fixes the subscription currency to the treasury's
+    #[account(
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    #[account(address = treasury_usdc.mint)]
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = buyer,
+        space = 8 + Subscription::INIT_SPACE,
+        seeds = [SUBSCRIPTION_SEED, buyer.key().as_ref()],
+        bump,
+    )]
+    pub subscription: Box<Account<'info, Subscription>>,
+
+    #[account(
+        init,
+        payer = buyer,
+        token::mint = usdc_mint,
+        token::authority = program_state,
+        seeds = [SUBSCRIPTION_ESCROW_SEED, subscription.key().as_ref()],
+        bump,
+    )]
+    pub subscription_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = usdc_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct SettleSubscription<'info> {
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_SEED, subscription.buyer.as_ref()],
+        bump = subscription.bump,
+    )]
+    pub subscription: Box<Account<'info, Subscription>>,
+
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_ESCROW_SEED, subscription.key().as_ref()],
+        bump,
+    )]
+    pub subscription_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(mut, seeds = [TREASURY_CONFIG_SEED], bump = treasury_config.bump)]
+    pub treasury_config: Box<Account<'info, TreasuryConfig>>,
+
+    #[account(
+        mut,
+        seeds = [TREASURY_USDC_SEED],
+        bump,
+    )]
+    pub treasury_usdc: Box<Account<'info, TokenAccount>>,
+
+    /// The farmer whose credits fill this settlement
+    #[account(
+        mut,
+        seeds = [OFFTAKE_OFFER_SEED, offtake_offer.farmer.as_ref()],
+        bump = offtake_offer.bump,
+    )]
+    pub offtake_offer: Box<Account<'info, OfftakeOffer>>,
+
+    #[account(
+        mut,
+        seeds = [OFFTAKE_VAULT_SEED, offtake_offer.key().as_ref()],
+        bump,
+    )]
+    pub offtake_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = subscription_escrow.mint,
+        token::authority = offtake_offer.farmer,
+    )]
+    pub farmer_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = offtake_vault.mint,
+        token::authority = subscription.buyer,
+    )]
+    pub buyer_green_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct CancelSubscription<'info> {
+    #[account(mut)]
+    pub buyer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        close = buyer,
+        seeds = [SUBSCRIPTION_SEED, buyer.key().as_ref()],
+        bump = subscription.bump,
+    )]
+    pub subscription: Box<Account<'info, Subscription>>,
+
+    #[account(
+        mut,
+        seeds = [SUBSCRIPTION_ESCROW_SEED, subscription.key().as_ref()],
+        bump,
+    )]
+    pub subscription_escrow: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = subscription_escrow.mint,
+        token::authority = buyer,
+    )]
+    pub buyer_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct OfferToOfftake<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    /// Only registered farmers sell into offtake
+    #[account(
+        seeds = [FARMER_SEED, farmer.key().as_ref()],
+        bump = farmer_profile.bump,
+        constraint = farmer_profile.compromised_at == 0 @ KisanError::WalletCompromised,
+    )]
+    pub farmer_profile: Box<Account<'info, FarmerProfile>>,
+
+    #[account(
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = farmer,
+        space = 8 + OfftakeOffer::INIT_SPACE,
+        seeds = [OFFTAKE_OFFER_SEED, farmer.key().as_ref()],
+        bump,
+    )]
+    pub offtake_offer: Box<Account<'info, OfftakeOffer>>,
+
+    #[account(
+        init_if_needed,
+        payer = farmer,
+        token::mint = green_mint,
+        token::authority = program_state,
+        seeds = [OFFTAKE_VAULT_SEED, offtake_offer.key().as_ref()],
+        bump,
+    )]
+    pub offtake_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        token::authority = farmer,
+    )]
+    pub farmer_green_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawOfftakeOffer<'info> {
+    #[account(mut)]
+    pub farmer: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        close = farmer,
+        seeds = [OFFTAKE_OFFER_SEED, farmer.key().as_ref()],
+        bump = offtake_offer.bump,
+    )]
+    pub offtake_offer: Box<Account<'info, OfftakeOffer>>,
+
+    #[account(
+        mut,
+        seeds = [OFFTAKE_VAULT_SEED, offtake_offer.key().as_ref()],
+        bump,
+    )]
+    pub offtake_vault: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = offtake_vault.mint,
+        token::authority = farmer,
+    )]
+    pub farmer_green_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeCurve<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+        has_one = authority @ KisanError::UnauthorizedAuthority,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(mut, seeds = [ADMIN_LOG_SEED], bump = admin_log.load()?.bump)]
+    pub admin_log: AccountLoader<'info, AdminLog>,
+
+    pub usdc_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BondingCurve::INIT_SPACE,
+        seeds = [CURVE_SEED],
+        bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        init,
+        payer = authority,
+        token::mint = usdc_mint,
+        token::authority = program_state,
+        seeds = [CURVE_RESERVE_SEED],
+        bump,
+    )]
+    pub curve_reserve: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+#[derive(Accounts)]
+pub struct BuyFromCurve<'info> {
+    pub buyer: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        seeds = [CURVE_SEED],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [CURVE_RESERVE_SEED],
+        bump,
+    )]
+    pub curve_reserve: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = bonding_curve.usdc_mint,
+        token::authority = buyer,
+    )]
+    pub buyer_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+    )]
+    pub buyer_green_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct SellToCurve<'info> {
+    pub seller: Signer<'info>,
+
+    #[account(
+        seeds = [STATE_SEED],
+        bump = program_state.bump,
+    )]
+    pub program_state: Box<Account<'info, ProgramState>>,
+
+    #[account(
+        mut,
+        seeds = [CURVE_SEED],
+        bump = bonding_curve.bump,
+    )]
+    pub bonding_curve: Box<Account<'info, BondingCurve>>,
+
+    #[account(
+        mut,
+        seeds = [CURVE_RESERVE_SEED],
+        bump,
+    )]
+    pub curve_reserve: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [MINT_SEED],
+        bump = program_state.mint_bump,
+    )]
+    pub green_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = green_mint,
+        token::authority = seller,
+    )]
+    pub seller_green_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = bonding_curve.usdc_mint,
+    )]
+    pub seller_usdc_account: Box<Account<'info, TokenAccount>>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+// ─────────────────────────────────────────────────────────────
+// State Accounts
+// ─────────────────────────────────────────────────────────────
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramState {
+    pub authority: Pubkey,            // 32
+    pub mint: Pubkey,                 // 32
+    pub total_proofs_verified: u64,   // 8
+    pub total_tokens_minted: u64,     // 8
+    pub bump: u8,                     // 1
+    pub mint_bump: u8,                // 1
+    pub require_personhood: bool,     // 1  — new profiles need a personhood proof
+    pub personhood_verifier: Pubkey,  // 32 — proof-of-personhood verifier key
+    pub referral_bonus: u64,          // 8  — $GREEN paid per successful referral
+    pub referral_budget_remaining: u64, // 8 — cap on outstanding referral bonuses
+    pub current_season: u32,          // 4  — index of the open Season PDA
+    pub legacy_season_duration: i64,  // 8  — moved to Config; read by initialize_config
+    pub legacy_season_reward_amount: u64, // 8 — moved to Config
+    pub legacy_season_mint_cap: u64,  // 8  — moved to Config
+    pub legacy_season_max_claims_per_farmer: u32, // 4 — moved to Config
+    pub legacy_streak_curve: [u16; STREAK_CURVE_LEN], // 16 — moved to Config
+    pub water_mint_bump: u8,          // 1  — 0 until the $WATER mint exists
+    pub soil_mint_bump: u8,           // 1  — 0 until the $SOIL mint exists
+    pub dynamic_pricing: bool,        // 1  — $GREEN reward priced by the oracle
+    pub total_burned: u64,            // 8  — $GREEN burned by treasury buybacks
+    pub offtake_share_bps: u16,       // 2  — issuance share committed to subscriptions
+    pub total_offtake_delivered: u64, // 8  — $GREEN delivered to offtake buyers
+    pub next_serial: u64,             // 8  — first unassigned credit serial
+    pub cross_chain_enabled: bool,    // 1  — publish Wormhole attestations
+    pub wormhole_program: Pubkey,     // 32 — core bridge for this cluster
+    pub require_pm_kisan: bool,       // 1  — claims need a PM-KISAN attestation
+    pub pm_kisan_attester: Pubkey,    // 32 — government-integration attester
+    pub issuance_receipts: bool,      // 1  — mint a cNFT receipt on every claim
+    pub version: u8,                  // 1  — layout version (ACCOUNT_VERSION)
+    pub last_claim_id: u64,           // 8  — ID of the latest claim (v3; 0 before any)
+    pub devnet: bool,                 // 1  — initialized by a `devnet` build (v9)
+}
+
+impl ProgramState {
+    /// Reserve the next `amount` credit serials, one per $GREEN base unit,
+    /// returning the half-open range `[start, end)`.
+    fn allocate_serials(&mut self, amount: u64) -> (u64, u64) {
+        let start = self.next_serial;
+        self.next_serial += amount;
+        (start, self.next_serial)
+    }
+
+    /// Assign the next claim ID. IDs start at 1, so 0 marks a record
+    /// minted before claim IDs existed.
+    fn next_claim_id(&mut self) -> u64 {
+        self.last_claim_id += 1;
+        self.last_claim_id
+    }
+}
+
+/// Settings the authority changes after launch. Kept apart from
+/// `ProgramState` so configuration changes don't write the account every
+/// claim mutates, and so new settings can be added by realloc through
+/// `migrate_account` without touching the state layout.
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub season_duration: i64,         // 8  — length of the next season
+    pub season_reward_amount: u64,    // 8  — per-claim reward for the next season
+    pub season_mint_cap: u64,         // 8  — claim mint cap for the next season
+    pub season_max_claims_per_farmer: u32, // 4 — per-farmer cap for the next season
+    pub streak_curve: [u16; STREAK_CURVE_LEN], // 16 — streak bonus in bps, by streak length
+    pub paused: bool,                 // 1  — claim minting halted
+    pub updated_at: i64,              // 8  — last change
+    pub bump: u8,                     // 1
+    pub version: u8,                  // 1  — layout version (ACCOUNT_VERSION)
+    pub incentives_budget: u64,       // 8  — lifetime cap on `distribute`
+    pub incentives_distributed: u64,  // 8  — minted by `distribute` so far
+    pub onboarding_bonus: u64,        // 8  — escrowed per new farmer (0 = off)
+    pub onboarding_window: i64,       // 8  — seconds from registration to claim it
+    pub emission_curve: EmissionCurve, // 21 — decays the season reward by season index
+}
+
+impl Config {
+    /// Bonus, in basis points of the season reward, for a streak of
+    /// `streak` consecutive seasons (the current one included).
+    fn streak_bonus_bps(&self, streak: u32) -> u16 {
+        let idx = (streak.max(1) as usize - 1).min(STREAK_CURVE_LEN - 1);
+        self.streak_curve[idx]
+    }
+
+    fn set_season_config(
+        &mut self,
+        duration: i64,
+        reward_amount: u64,
+        mint_cap: u64,
+        max_claims_per_farmer: u32,
+    ) -> Result<()> {
+        require!(
+            duration > 0 && reward_amount > 0 && mint_cap >= reward_amount && max_claims_per_farmer > 0,
+            KisanError::InvalidSeasonConfig
+        );
+        self.season_duration = duration;
+        self.season_reward_amount = reward_amount;
+        self.season_mint_cap = mint_cap;
+        self.season_max_claims_per_farmer = max_claims_per_farmer;
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ProofRecord {
+    pub commitment: [u8; 32],         // 32 — unique proof commitment
+    pub farmer: Pubkey,               // 32 — farmer wallet
+    pub timestamp: i64,               // 8  — verification timestamp
+    pub verified: bool,               // 1  — true once written; see `ensure_unused`
+    pub rent_payer: Pubkey,           // 32 — receives rent when the record is closed
+    pub bump: u8,                     // 1
+    pub season: u32,                  // 4  — season the claim was minted in
+    pub vintage: u16,                 // 2  — calendar year of issuance
+    pub methodology_id: u16,          // 2  — registry methodology (0 = unspecified)
+    pub serial_start: u64,            // 8  — first serial issued, one per base unit
+    pub serial_end: u64,              // 8  — end of the serial range, exclusive
+    pub evidence_cid: [u8; EVIDENCE_CID_LEN], // 34 — IPFS CID of source evidence (zeros if none)
+    pub version: u8,                  // 1  — layout version (ACCOUNT_VERSION)
+    pub claim_id: u64,                // 8  — sequential claim ID (v3; 0 for earlier claims)
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct StateTreeConfig {
+    pub merkle_tree: Pubkey,          // 32 — concurrent merkle tree of compressed records
+    pub leaves_appended: u64,         // 8  — next leaf index
+    pub bump: u8,                     // 1
+    pub authority_bump: u8,           // 1  — bump of the tree-authority PDA
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ForeignEmitter {
+    pub chain: u16,                   // 2  — Wormhole chain id
+    pub address: [u8; 32],            // 32 — whitelisted emitter on that chain
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VaaReceipt {
+    pub vaa_hash: [u8; 32],           // 32 — redeemed VAA
+    pub emitter_chain: u16,           // 2
+    pub sequence: u64,                // 8  — emitter sequence number
+    pub commitment: [u8; 32],         // 32 — commitment minted against
+    pub redeemed_at: i64,             // 8
+    pub bump: u8,                     // 1
+}
+
+/// Claim sent by a partner registry on another chain. Integers are
+/// big-endian:
+/// version (1) | commitment (32) | farmer (32) | amount (8) |
+/// vintage (2) | methodology_id (2)
+struct RegistryClaim {
+    commitment: [u8; 32],
+    farmer: Pubkey,
+    amount: u64,
+    vintage: u16,
+    methodology_id: u16,
+}
+
+impl RegistryClaim {
+    const LEN: usize = 1 + 32 + 32 + 8 + 2 + 2;
+
+    fn parse(payload: &[u8]) -> Result<Self> {
+        require!(
+            payload.len() == Self::LEN && payload[0] == REGISTRY_CLAIM_PAYLOAD_VERSION,
+            KisanError::InvalidVaa
+        );
+        Ok(Self {
+            commitment: payload[1..33].try_into().unwrap(),
+            farmer: Pubkey::try_from(&payload[33..65]).unwrap(),
+            amount: u64::from_be_bytes(payload[65..73].try_into().unwrap()),
+            vintage: u16::from_be_bytes(payload[73..75].try_into().unwrap()),
+            methodology_id: u16::from_be_bytes(payload[75..77].try_into().unwrap()),
+        })
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AggregateBatch {
+    pub batch_root: [u8; 32],         // 32 — Merkle root of the batch's leaves
+    pub circuit_id: u16,              // 2  — circuit the recursive proof was checked on
+    pub leaf_count: u32,              // 4  — claims covered by the proof
+    pub leaves_redeemed: u32,         // 4
+    pub amount_redeemed: u64,         // 8  — $GREEN minted from the batch
+    pub submitter: Pubkey,            // 32 — payer who submitted the proof
+    pub verified_at: i64,             // 8
+    pub bump: u8,                     // 1
+}
+
+impl AggregateBatch {
+    /// Leaf committed to by the recursive proof:
+    /// keccak256(farmer (32) | commitment (32) | amount (8, big-endian))
+    fn leaf(farmer: &Pubkey, commitment: &[u8; 32], amount: u64) -> [u8; 32] {
+        keccak::hashv(&[farmer.as_ref(), commitment, &amount.to_be_bytes()]).to_bytes()
+    }
+
+    /// Whether `proof` links `leaf` to the batch root. Each pair is hashed
+    /// in sorted order, so the proof needs no leaf index.
+    fn contains(&self, leaf: [u8; 32], proof: &[[u8; 32]]) -> bool {
+        let root = proof.iter().fold(leaf, |node, sibling| {
+            let (lo, hi) = if node <= *sibling { (&node, sibling) } else { (sibling, &node) };
+            keccak::hashv(&[lo.as_ref(), hi.as_ref()]).to_bytes()
+        });
+        root == self.batch_root
+    }
+}
+
+/// Public signals of an aggregate proof, as 32-byte big-endian words:
+/// [batch_root, leaf_count]
+struct AggregateSignals {
+    batch_root: [u8; 32],
+    leaf_count: u32,
+}
+
+impl AggregateSignals {
+    const WORDS: usize = 2;
+
+    fn parse(signals: &[u8]) -> Result<Self> {
+        require!(signals.len() == Self::WORDS * 32, KisanError::InvalidPublicSignals);
+        let count = &signals[32..];
+        require!(count[..28].iter().all(|&b| b == 0), KisanError::InvalidPublicSignals);
+        let leaf_count = u32::from_be_bytes(count[28..].try_into().unwrap());
+        require!(leaf_count > 0, KisanError::InvalidPublicSignals);
+        Ok(Self {
+            batch_root: signals[..32].try_into().unwrap(),
+            leaf_count,
+        })
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct EvmOracle {
+    pub eth_address: [u8; 20],        // 20 — Ethereum address signing reports
+    pub registered_at: i64,           // 8
+    pub bump: u8,                     // 1
+}
+
+/// Report signed by an EVM oracle. Integers are big-endian:
+/// version (1) | program_id (32) | commitment (32) | farmer (32) |
+/// circuit_id (2) | expires_at (8)
+///
+/// The program id binds the report to this deployment.
+struct EvmReport {
+    commitment: [u8; 32],
+    farmer: Pubkey,
+    circuit_id: u16,
+    expires_at: i64,
+}
+
+impl EvmReport {
+    const LEN: usize = 1 + 32 + 32 + 32 + 2 + 8;
+
+    fn parse(message: &[u8]) -> Result<Self> {
+        require!(
+            message.len() == Self::LEN
+                && message[0] == EVM_REPORT_VERSION
+                && message[1..33] == crate::ID.to_bytes(),
+            KisanError::InvalidEvmReport
+        );
+        Ok(Self {
+            commitment: message[33..65].try_into().unwrap(),
+            farmer: Pubkey::try_from(&message[65..97]).unwrap(),
+            circuit_id: u16::from_be_bytes(message[97..99].try_into().unwrap()),
+            expires_at: i64::from_be_bytes(message[99..107].try_into().unwrap()),
+        })
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ImageryConfig {
+    pub circuit_id: u16,              // 2  — imagery circuit
+    pub provider: Pubkey,             // 32 — EvmOracle of the imagery provider
+    pub min_ndvi_delta: u16,          // 2  — minimum NDVI improvement, ×10⁴
+    pub min_window: i64,              // 8  — minimum observation window (s)
+    pub max_observation_age: i64,     // 8  — max age of the window end (s)
+    pub reward_amount: u64,           // 8  — $GREEN per claim
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DroneConfig {
+    pub min_stake: u64,               // 8  — lamports an operator must bond
+    pub unbonding_period: i64,        // 8  — seconds before stake can leave
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DroneOperator {
+    pub operator: Pubkey,             // 32 — operator signing wallet
+    pub operator_id: u32,             // 4  — licence / pilot number
+    pub stake: u64,                   // 8  — bonded lamports (above rent)
+    pub total_slashed: u64,           // 8  — lamports slashed to date
+    pub flights_attested: u64,        // 8  — claims co-signed
+    pub registered_at: i64,           // 8
+    pub unbonding_at: i64,            // 8  — 0 while bonded
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct DroneFlight {
+    pub commitment: [u8; 32],         // 32 — claim / proof record commitment
+    pub operator: Pubkey,             // 32 — DroneOperator that co-signed
+    pub operator_id: u32,             // 4
+    pub flight_log_root: [u8; 32],    // 32 — Merkle root of the flight log
+    pub attested_at: i64,             // 8
+    pub bump: u8,                     // 1
+}
+
+/// Public signals of the NDVI circuit, as 32-byte big-endian words:
+/// [commitment, ndvi_delta (×10⁴), window_start, window_end]
+struct NdviSignals {
+    commitment: [u8; 32],
+    ndvi_delta: u64,
+    window_start: i64,
+    window_end: i64,
+}
+
+impl NdviSignals {
+    const WORDS: usize = 4;
+
+    fn parse(signals: &[u8]) -> Result<Self> {
+        require!(signals.len() == Self::WORDS * 32, KisanError::InvalidPublicSignals);
+        let word = |i: usize| -> Result<u64> {
+            let w = &signals[i * 32..(i + 1) * 32];
+            require!(w[..24].iter().all(|&b| b == 0), KisanError::InvalidPublicSignals);
+            Ok(u64::from_be_bytes(w[24..].try_into().unwrap()))
+        };
+        let window_start =
+            i64::try_from(word(2)?).map_err(|_| KisanError::InvalidPublicSignals)?;
+        let window_end =
+            i64::try_from(word(3)?).map_err(|_| KisanError::InvalidPublicSignals)?;
+        Ok(Self {
+            commitment: signals[..32].try_into().unwrap(),
+            ndvi_delta: word(1)?,
+            window_start,
+            window_end,
+        })
+    }
+
+    /// Message the imagery provider signs. Integers are big-endian:
+    /// version (1) | program_id (32) | commitment (32) | ndvi_delta (8) |
+    /// window_start (8) | window_end (8)
+    fn observation_message(&self) -> Vec<u8> {
+        let mut message = Vec::with_capacity(1 + 32 + 32 + 8 * 3);
+        message.push(IMAGERY_OBSERVATION_VERSION);
+        message.extend_from_slice(crate::ID.as_ref());
+        message.extend_from_slice(&self.commitment);
+        message.extend_from_slice(&self.ndvi_delta.to_be_bytes());
+        message.extend_from_slice(&self.window_start.to_be_bytes());
+        message.extend_from_slice(&self.window_end.to_be_bytes());
+        message
+    }
+}
+
+impl ProofRecord {
+    /// Fail with `ProofAlreadyUsed` if an earlier claim already wrote this
+    /// record. Contexts open it with `init_if_needed` so a reused
+    /// commitment gets here instead of failing in the system program with
+    /// "account already in use"; `ProofReused` names the original claim
+    /// in the failed transaction's logs. Call before anything is written.
+    fn ensure_unused(&self) -> Result<()> {
+        if self.verified {
+            emit!(ProofReused {
+                commitment: self.commitment,
+                farmer: self.farmer,
+                timestamp: self.timestamp,
+                season: self.season,
+                claim_id: self.claim_id,
+            });
+            return err!(KisanError::ProofAlreadyUsed);
+        }
+        Ok(())
+    }
+
+    /// Fail unless the record may be closed. The PDA is the commitment's
+    /// only replay guard; once it is gone, what stops the claim minting
+    /// again is the season in its opening (`commitment::verify`), so the
+    /// record must be past `PROOF_RETENTION_PERIOD` and from a season that
+    /// has ended and can't come back. Seasons only advance, except through
+    /// `reset_state`, which reopens all of them on a devnet deployment.
+    fn ensure_closable(&self, state: &ProgramState, now: i64) -> Result<()> {
+        require!(
+            now >= self.timestamp + PROOF_RETENTION_PERIOD,
+            KisanError::ProofRecordRetained
+        );
+        require!(
+            self.season < state.current_season && !state.devnet,
+            KisanError::ProofRecordSeasonOpen
+        );
+        Ok(())
+    }
+
+    /// Cross-chain attestation payload. Integers are big-endian for EVM
+    /// consumers:
+    /// version (1) | commitment (32) | farmer (32) | amount (8) |
+    /// serial_start (8) | serial_end (8) | vintage (2) | methodology_id (2)
+    fn attestation_payload(&self) -> Vec<u8> {
+        let mut payload = Vec::with_capacity(93);
+        payload.push(ATTESTATION_PAYLOAD_VERSION);
+        payload.extend_from_slice(&self.commitment);
+        payload.extend_from_slice(self.farmer.as_ref());
+        payload.extend_from_slice(&(self.serial_end - self.serial_start).to_be_bytes());
+        payload.extend_from_slice(&self.serial_start.to_be_bytes());
+        payload.extend_from_slice(&self.serial_end.to_be_bytes());
+        payload.extend_from_slice(&self.vintage.to_be_bytes());
+        payload.extend_from_slice(&self.methodology_id.to_be_bytes());
+        payload
+    }
+}
+
+/// Groth16 proof with its public inputs, as accepted by `verify_claim`
+/// and `verify_proof`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct Groth16Proof {
+    pub points: ProofPoints,
+    pub public_signals: Vec<u8>,
+}
+
+impl Groth16Proof {
+    /// Check the proof against `circuit`'s registered key (or `previous`,
+    /// its rotated-out key), with `commitment` as its first public input,
+    /// without touching any state.
+    pub fn verify(
+        &self,
+        circuit: &CircuitConfig,
+        vk: Option<&Account<Groth16VerifyingKey>>,
+        previous: Option<&Account<PreviousGroth16VerifyingKey>>,
+        commitment: &[u8; 32],
+    ) -> Result<()> {
+        require!(circuit.active, KisanError::CircuitInactive);
+        require!(
+            self.public_signals.get(..32) == Some(&commitment[..]),
+            KisanError::InvalidPublicSignals
+        );
+        verify_circuit_proof(
+            circuit,
+            vk,
+            previous,
+            None,
+            None,
+            None,
+            &[],
+            &Proof::Groth16(Box::new(self.points.clone())),
+            &self.public_signals,
+        )
+    }
+}
+
+/// Groth16 proof points, uncompressed (G1 = x | y, G2 = x | y) or in
+/// alt_bn128 compressed form (x only, y's sign in the top bits), which
+/// halves the proof's share of a claim transaction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub enum ProofPoints {
+    Uncompressed {
+        a: [u8; 64],
+        b: [u8; 128],
+        c: [u8; 64],
+    },
+    Compressed {
+        a: [u8; 32],
+        b: [u8; 64],
+        c: [u8; 32],
+    },
+}
+
+impl ProofPoints {
+    /// The points in uncompressed form, as the pairing syscall takes them.
+    fn decompress(&self) -> Result<([u8; 64], [u8; 128], [u8; 64])> {
+        match self {
+            ProofPoints::Uncompressed { a, b, c } => Ok((*a, *b, *c)),
+            ProofPoints::Compressed { a, b, c } => Ok((
+                bn254::decompress_g1(a)?,
+                bn254::decompress_g2(b)?,
+                bn254::decompress_g1(c)?,
+            )),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum ClaimStatus {
+    Submitted,
+    Verified,
+    Issued,
+    Retired,
+    /// Verified, waiting in the claim queue for a season with budget
+    Queued,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PayoutConfig {
+    pub partner: Pubkey,              // 32 — fiat payout partner, signs mark_paid
+    pub dispute_window: i64,          // 8  — seconds to dispute / to pay
+    pub total_redeemed: u64,          // 8  — $GREEN burned for vouchers
+    pub total_paid: u64,              // 8  — $GREEN of vouchers marked paid
+    pub bump: u8,                     // 1
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum VoucherStatus {
+    Pending,
+    Paid,
+    Disputed,
+    Refunded,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoucherRedemption {
+    pub holder: Pubkey,               // 32 — redeemer, refunded on a lost dispute
+    pub amount: u64,                  // 8  — $GREEN burned
+    pub payout_ref_hash: [u8; 32],    // 32 — off-chain payout request
+    pub payment_ref_hash: [u8; 32],   // 32 — partner's transfer reference, once paid
+    pub dispute_reason_hash: [u8; 32], // 32 — holder's complaint, if disputed
+    pub status: VoucherStatus,        // 1
+    pub requested_at: i64,            // 8
+    pub paid_at: i64,                 // 8  — 0 until mark_paid
+    pub disputed_at: i64,             // 8  — 0 unless disputed
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PriceFloor {
+    pub methodology_id: u16,          // 2  — registry methodology
+    pub min_price: u64,               // 8  — USDC base units per whole $GREEN
+    pub price_feed: Pubkey,           // 32 — Pyth PriceUpdateV2 account (default = fixed floor)
+    pub feed_id: [u8; 32],            // 32 — Pyth feed id
+    pub oracle_floor_bps: u16,        // 2  — floor as bps of the oracle price
+    pub max_staleness: i64,           // 8  — seconds
+    pub bump: u8,                     // 1
+}
+
+impl PriceFloor {
+    /// Lowest listing price, in base units of a USDC mint with
+    /// `usdc_decimals` per whole $GREEN: `min_price`, raised to the oracle
+    /// floor while a feed is set. A stale oracle price rejects the listing
+    /// rather than falling back, so the floor can't be dodged by waiting.
+    fn floor(&self, feed: Option<&UncheckedAccount>, usdc_decimals: u8, now: i64) -> Result<u64> {
+        if self.price_feed == Pubkey::default() {
+            return Ok(self.min_price);
+        }
+        let feed = feed.ok_or(KisanError::PricingAccountsRequired)?;
+        let price = oracle::load_pyth_price(feed, &self.feed_id)?;
+        price.validate(now, self.max_staleness, BPS_DENOMINATOR as u16)?;
+        let value = price
+            .unit_value(usdc_decimals)
+            .ok_or_else(|| error!(KisanError::InvalidPriceFeed))?;
+        let oracle_floor = value as u128 * self.oracle_floor_bps as u128 / BPS_DENOMINATOR as u128;
+        Ok(self.min_price.max(oracle_floor as u64))
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Listing {
+    pub seller: Pubkey,               // 32 — farmer selling, paid in USDC
+    pub listing_id: u64,              // 8  — seller-chosen id, PDA seed
+    pub proof_record: Pubkey,         // 32 — issuance the credits were listed from
+    pub origin_farmer: Pubkey,        // 32 — the issuance's farmer, paid resale royalties
+    pub methodology_id: u16,          // 2  — the issuance's methodology
+    pub usdc_mint: Pubkey,            // 32 — settlement currency
+    pub price: u64,                   // 8  — USDC base units per whole $GREEN
+    pub remaining: u64,               // 8  — $GREEN base units left in escrow
+    pub listed_at: i64,               // 8
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OtcDeal {
+    pub maker: Pubkey,                // 32 — seller, escrowed the $GREEN
+    pub counterparty: Pubkey,         // 32 — the only wallet that can accept
+    pub usdc_mint: Pubkey,            // 32 — settlement currency
+    pub green_amount: u64,            // 8  — $GREEN base units in escrow
+    pub usdc_amount: u64,             // 8  — USDC base units owed on acceptance
+    pub expiry: i64,                  // 8  — last moment it can be accepted
+    pub created_at: i64,              // 8
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SgreenPool {
+    pub rate_bps: i16,                // 2  — current yearly yield, mirrored on the mint
+    pub pre_update_average_rate: i16, // 2  — average yield before `last_update`
+    pub initialized_at: i64,          // 8  — the mint's interest start
+    pub last_update: i64,             // 8  — last rate change
+    pub total_staked: u64,            // 8  — $GREEN staked, lifetime
+    pub total_redeemed: u64,          // 8  — $GREEN paid out on redemption, lifetime
+    pub total_rewards: u64,           // 8  — yield minted into the vault, lifetime
+    pub mint_bump: u8,                // 1
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct GovernanceConfig {
+    pub voting_period: i64,           // 8  — seconds a proposal is open for votes
+    pub quorum: u64,                  // 8  — weight a proposal needs to pass
+    pub proposal_threshold: u64,      // 8  — deposit needed to propose
+    pub weightings: [VoteWeighting; PROPOSAL_KINDS], // 54 — by `ProposalAction::kind`
+    pub next_proposal_id: u64,        // 8  — id of the next proposal, PDA seed
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoterRecord {
+    pub owner: Pubkey,                // 32
+    pub deposited: u64,               // 8  — $GREEN deposited as voting power
+    pub active_votes: u32,            // 4  — votes not yet relinquished; locks the deposit
+    pub last_change_slot: u64,        // 8  — votes only on proposals snapshotted after this
+    pub bump: u8,                     // 1
+    pub delegate: Pubkey,             // 32 — votes with this deposit (default = self)
+    pub delegated: u64,               // 8  — deposits delegated to this voter
+}
+
+impl VoterRecord {
+    /// Weight before the proposal's strategy: own deposit plus delegations.
+    fn voting_power(&self) -> u64 {
+        self.deposited + self.delegated
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Proposal {
+    pub id: u64,                      // 8  — PDA seed
+    pub proposer: Pubkey,             // 32
+    pub kind: u8,                     // 1  — `action.kind()`
+    pub action: ProposalAction,       // 1552 — see governance.rs
+    pub weighting: VoteWeighting,     // 9  — the kind's weighting at creation
+    pub snapshot_slot: u64,           // 8  — deposits changed after this can't vote
+    pub created_at: i64,              // 8
+    pub voting_ends_at: i64,          // 8
+    pub quorum: u64,                  // 8  — quorum at creation
+    pub yes_weight: u64,              // 8
+    pub no_weight: u64,               // 8
+    pub executed: bool,               // 1
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct VoteRecord {
+    pub proposal: Pubkey,             // 32
+    pub voter: Pubkey,                // 32
+    pub approve: bool,                // 1
+    pub weight: u64,                  // 8
+    pub bump: u8,                     // 1
+}
+
+/// A reserved share of the token distribution. Also the index into
+/// `CapTable::allocations` and the seed of its escrow.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AllocationKind {
+    Community,
+    Team,
+    Investors,
+    Ecosystem,
+}
+
+/// One allocation's terms, as passed to `initialize`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct AllocationTerms {
+    /// Owner of the token account releases are paid to
+    pub beneficiary: Pubkey,
+    /// $GREEN base units minted into the escrow
+    pub amount: u64,
+    /// Seconds after `initialize` before anything vests
+    pub cliff: i64,
+    /// Seconds after `initialize` until everything has vested
+    pub duration: i64,
+}
+
+impl AllocationTerms {
+    fn is_valid(&self) -> bool {
+        self.cliff >= 0
+            && self.duration >= self.cliff
+            && (self.amount == 0 || self.beneficiary != Pubkey::default())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct Allocation {
+    pub beneficiary: Pubkey,          // 32
+    pub amount: u64,                  // 8  — minted into escrow at initialize
+    pub cliff: i64,                   // 8  — seconds before anything vests
+    pub duration: i64,                // 8  — seconds until fully vested
+    pub released: u64,                // 8  — paid to the beneficiary so far
+}
+
+impl Allocation {
+    /// Amount vested at `now` for a schedule starting at `start`: nothing
+    /// before the cliff, then linear in the time since `start`.
+    fn vested(&self, start: i64, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(start);
+        if elapsed < self.cliff {
+            0
+        } else if elapsed >= self.duration {
+            self.amount
+        } else {
+            (self.amount as u128 * elapsed as u128 / self.duration as u128) as u64
+        }
+    }
+}
+
+/// The reserved allocations minted at `initialize`, each held in its own
+/// `ALLOCATION_VAULT_SEED` escrow until it vests.
+#[account]
+#[derive(InitSpace)]
+pub struct CapTable {
+    pub allocations: [Allocation; ALLOCATION_KINDS], // 4 × 64, in `AllocationKind` order
+    pub start: i64,                   // 8  — vesting start, the initialize time
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryConfig {
+    pub guardian: Pubkey,             // 32 — co-signer of large withdrawals
+    pub daily_limit: u64,             // 8  — USDC base units withdrawable per window
+    pub timelock: i64,                // 8  — seconds before a proposal can execute
+    pub market_fee_bps: u16,          // 2  — protocol fee on marketplace purchases
+    pub window_start: i64,            // 8  — start of the current withdrawal window
+    pub withdrawn_today: u64,         // 8  — withdrawn in the current window
+    pub total_withdrawn: u64,         // 8  — lifetime withdrawals, both paths
+    pub total_fees: u64,              // 8  — marketplace fees received
+    pub next_withdrawal_id: u64,      // 8  — id of the next proposal
+    pub bump: u8,                     // 1
+    pub buyback_slippage_bps: u16,    // 2  — buyback minimum below the oracle quote
+}
+
+impl TreasuryConfig {
+    /// Open a new withdrawal window if the current one has ended.
+    fn roll_window(&mut self, now: i64) {
+        if now >= self.window_start + TREASURY_WINDOW {
+            self.window_start = now;
+            self.withdrawn_today = 0;
+        }
+    }
+
+    /// Count `amount` of USDC leaving the treasury against the current
+    /// window. Every outflow goes through here: admin withdrawals,
+    /// timelocked withdrawals, governance spends and buybacks. Past
+    /// `daily_limit` the guardian must co-sign. Returns the window's
+    /// outflows including `amount`.
+    fn authorize_outflow(
+        &mut self,
+        amount: u64,
+        now: i64,
+        guardian: Option<&Signer>,
+    ) -> Result<u64> {
+        self.roll_window(now);
+        let withdrawn = self.withdrawn_today.saturating_add(amount);
+        let cosigned = guardian.is_some_and(|g| g.key() == self.guardian);
+        if withdrawn > self.daily_limit && !cosigned {
+            return Err(report::exceeded(
+                KisanError::TreasuryDailyLimitExceeded,
+                withdrawn,
+                self.daily_limit,
+            ));
+        }
+        self.withdrawn_today = withdrawn;
+        self.total_withdrawn += amount;
+        Ok(withdrawn)
+    }
+
+    /// Least $GREEN a buyback of `usdc_amount` may accept: the oracle's
+    /// quote, taken as USDC per $GREEN, less `buyback_slippage_bps`.
+    fn buyback_floor(
+        &self,
+        price: &oracle::OraclePrice,
+        usdc_amount: u64,
+        usdc_decimals: u8,
+    ) -> Result<u64> {
+        let quote = price
+            .amount_for_value(usdc_amount, usdc_decimals, GREEN_TOKEN_DECIMALS)
+            .ok_or(KisanError::InvalidPriceFeed)?;
+        let keep = BPS_DENOMINATOR - self.buyback_slippage_bps as u64;
+        Ok((quote as u128 * keep as u128 / BPS_DENOMINATOR as u128) as u64)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryWithdrawal {
+    pub id: u64,                      // 8  — sequence number, part of the seeds
+    pub amount: u64,                  // 8  — USDC base units
+    pub destination: Pubkey,          // 32 — USDC token account paid
+    pub proposed_at: i64,             // 8
+    pub executable_at: i64,           // 8  — proposed_at + the treasury timelock
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct MarketConfig {
+    pub royalty_bps: u16,             // 2  — resale royalty, bps of the price
+    pub total_royalties: u64,         // 8  — USDC base units paid into the royalty vault
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RoyaltyAccount {
+    pub farmer: Pubkey,               // 32 — originating farmer
+    pub unclaimed: u64,               // 8  — USDC base units held in the royalty vault
+    pub total_earned: u64,            // 8  — lifetime royalties
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BuyerProfile {
+    pub buyer: Pubkey,                // 32 — buyer wallet
+    pub total_purchased: u64,         // 8  — $GREEN bought on the marketplace
+    pub total_spent: u64,             // 8  — USDC paid for it
+    pub purchases: u64,               // 8
+    pub total_retired: u64,           // 8  — $GREEN retired, however acquired
+    pub retirements: u64,             // 8
+    pub first_activity_at: i64,       // 8
+    pub last_activity_at: i64,        // 8
+    pub bump: u8,                     // 1
+}
+
+impl BuyerProfile {
+    /// Fill in a freshly created profile and stamp the activity time.
+    fn open(&mut self, buyer: Pubkey, bump: u8, now: i64) {
+        if self.first_activity_at == 0 {
+            self.buyer = buyer;
+            self.bump = bump;
+            self.first_activity_at = now;
+        }
+        self.last_activity_at = now;
+    }
+
+    fn emit_activity(
+        &self,
+        kind: BuyerActivityKind,
+        reference: Pubkey,
+        amount: u64,
+        usdc_paid: u64,
+        now: i64,
+    ) {
+        emit!(BuyerActivity {
+            buyer: self.buyer,
+            kind,
+            reference,
+            amount,
+            usdc_paid,
+            total_purchased: self.total_purchased,
+            total_spent: self.total_spent,
+            total_retired: self.total_retired,
+            timestamp: now,
+        });
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Claim {
+    pub farmer: Pubkey,               // 32 — claiming farmer
+    pub commitment: [u8; 32],         // 32 — compliance commitment
+    pub circuit_id: u16,              // 2  — circuit the claim is paid under
+    pub status: ClaimStatus,          // 1
+    pub submitted_at: i64,            // 8
+    pub verified_by: Pubkey,          // 32 — this program (ZK) or the attester
+    pub verified_at: i64,             // 8
+    pub issued_amount: u64,           // 8  — $GREEN base units issued
+    pub issued_at: i64,               // 8
+    pub retired_by: Pubkey,           // 32 — holder who retired the credits
+    pub retired_at: i64,              // 8
+    pub bump: u8,                     // 1
+}
+
+/// FIFO cursor over `QueuedClaim` entries: `head` is the next to issue,
+/// `tail` the next free position.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimQueue {
+    pub head: u64,                    // 8
+    pub tail: u64,                    // 8
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct QueuedClaim {
+    pub position: u64,                // 8  — place in the queue
+    pub claim: Pubkey,                // 32 — Claim waiting for budget
+    pub farmer: Pubkey,               // 32
+    pub season: u32,                  // 4  — season it was queued in
+    pub queued_at: i64,               // 8
+    pub payer: Pubkey,                // 32 — paid the rent, refunded on close
+    pub bump: u8,                     // 1
+}
+
+/// Verification status of a commitment, returned by `get_proof_status`.
+/// All fields are zero when the commitment hasn't been verified.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ProofStatus {
+    pub verified: bool,
+    pub farmer: Pubkey,
+    pub timestamp: i64,
+    pub season: u32,
+    pub amount: u64,
+}
+
+/// A farmer's claim statistics, returned by `get_farmer_stats`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FarmerStats {
+    pub farmer: Pubkey,
+    pub region_code: u16,
+    pub total_proofs: u64,
+    pub season_index: u32,
+    pub season_claims: u32,
+    pub streak: u32,
+    pub badges_claimed: u8,
+    pub registered_at: i64,
+    pub last_claim_at: i64,
+}
+
+/// Registry details of an issued serial, returned by `lookup_serial`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SerialInfo {
+    pub commitment: [u8; 32],
+    pub farmer: Pubkey,
+    pub vintage: u16,
+    pub methodology_id: u16,
+    pub serial_start: u64,
+    pub serial_end: u64,
+    /// Sequential claim ID; 0 for claims minted before IDs existed
+    pub claim_id: u64,
+}
+
+/// The program's PDAs, returned by `get_addresses`. Global accounts come
+/// first, then those keyed by the circuit, region, season and farmer that
+/// were asked about. Addresses are returned whether or not the account
+/// exists yet.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProgramAddresses {
+    pub program_state: Pubkey,
+    pub green_mint: Pubkey,
+    pub water_mint: Pubkey,
+    pub soil_mint: Pubkey,
+    pub admin_log: Pubkey,
+    pub feature_flags: Pubkey,
+    pub config: Pubkey,
+    pub leaderboard: Pubkey,
+    pub mint_stats: Pubkey,
+    pub rent_pool: Pubkey,
+    pub treasury_usdc: Pubkey,
+    pub treasury_green: Pubkey,
+    pub treasury_config: Pubkey,
+    pub treasury_authority: Pubkey,
+    pub insurance_pool: Pubkey,
+    pub market_config: Pubkey,
+    pub royalty_vault: Pubkey,
+    pub onboarding_vault: Pubkey,
+    pub escrow_vault: Pubkey,
+    pub sgreen_mint: Pubkey,
+    pub cap_table: Pubkey,
+    pub oracle_config: Pubkey,
+    pub recovery_config: Pubkey,
+    pub circuit_config: Pubkey,
+    pub groth16_vk: Pubkey,
+    pub plonk_vk: Pubkey,
+    pub previous_plonk_vk: Pubkey,
+    pub previous_groth16_vk: Pubkey,
+    pub region_config: Pubkey,
+    pub season: Pubkey,
+    pub season_tally: Pubkey,
+    pub season_snapshot: Pubkey,
+    pub farmer_profile: Pubkey,
+    pub royalty: Pubkey,
+    pub buyer_profile: Pubkey,
+}
+
+impl ProgramAddresses {
+    /// Derive every address from the program's own seed constants. Also
+    /// usable off-chain, where it's what the SDK's `pda::addresses` calls.
+    pub fn derive(circuit_id: u16, region_code: u16, season: u32, farmer: &Pubkey) -> Self {
+        let find = |seeds: &[&[u8]]| Pubkey::find_program_address(seeds, &crate::ID).0;
+        let circuit = circuit_id.to_le_bytes();
+        let region = region_code.to_le_bytes();
+        let season = season.to_le_bytes();
+        Self {
+            program_state: find(&[STATE_SEED]),
+            green_mint: find(&[MINT_SEED]),
+            water_mint: find(&[WATER_MINT_SEED]),
+            soil_mint: find(&[SOIL_MINT_SEED]),
+            admin_log: find(&[ADMIN_LOG_SEED]),
+            feature_flags: find(&[FEATURE_FLAGS_SEED]),
+            config: find(&[CONFIG_SEED]),
+            leaderboard: find(&[LEADERBOARD_SEED]),
+            mint_stats: find(&[MINT_STATS_SEED]),
+            rent_pool: find(&[RENT_POOL_SEED]),
+            treasury_usdc: find(&[TREASURY_USDC_SEED]),
+            treasury_green: find(&[TREASURY_GREEN_SEED]),
+            treasury_config: find(&[TREASURY_CONFIG_SEED]),
+            treasury_authority: find(&[TREASURY_AUTHORITY_SEED]),
+            insurance_pool: find(&[INSURANCE_POOL_SEED]),
+            market_config: find(&[MARKET_CONFIG_SEED]),
+            royalty_vault: find(&[ROYALTY_VAULT_SEED]),
+            onboarding_vault: find(&[ONBOARDING_VAULT_SEED]),
+            escrow_vault: find(&[ESCROW_VAULT_SEED]),
+            sgreen_mint: find(&[SGREEN_MINT_SEED]),
+            cap_table: find(&[CAP_TABLE_SEED]),
+            oracle_config: find(&[ORACLE_CONFIG_SEED]),
+            recovery_config: find(&[RECOVERY_SEED]),
+            circuit_config: find(&[CIRCUIT_SEED, &circuit]),
+            groth16_vk: find(&[GROTH16_VK_SEED, &circuit]),
+            plonk_vk: find(&[PLONK_VK_SEED, &circuit]),
+            previous_plonk_vk: find(&[PLONK_VK_PREVIOUS_SEED, &circuit]),
+            previous_groth16_vk: find(&[GROTH16_VK_PREVIOUS_SEED, &circuit]),
+            region_config: find(&[REGION_SEED, &region]),
+            season: find(&[SEASON_SEED, &season]),
+            season_tally: find(&[SEASON_TALLY_SEED, &season]),
+            season_snapshot: find(&[SEASON_SNAPSHOT_SEED, &season]),
+            farmer_profile: find(&[FARMER_SEED, farmer.as_ref()]),
+            royalty: find(&[ROYALTY_SEED, farmer.as_ref()]),
+            buyer_profile: find(&[BUYER_PROFILE_SEED, farmer.as_ref()]),
+        }
+    }
+}
+
+/// Reward tokens the program can mint. Each kind has its own PDA mint
+/// with the program-state PDA as mint authority.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MintKind {
+    Green,
+    Water,
+    Soil,
+}
+
+impl MintKind {
+    fn seed(&self) -> &'static [u8] {
+        match self {
+            MintKind::Green => MINT_SEED,
+            MintKind::Water => WATER_MINT_SEED,
+            MintKind::Soil => SOIL_MINT_SEED,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PricingConfig {
+    pub price_feed: Pubkey,           // 32 — Pyth PriceUpdateV2 account
+    pub feed_id: [u8; 32],            // 32 — Pyth feed id
+    pub target_value: u64,            // 8  — value per claim, PRICE_TARGET_DECIMALS
+    pub max_staleness: i64,           // 8  — seconds
+    pub max_conf_bps: u16,            // 2  — max confidence interval, bps of price
+    pub fallback_amount: u64,         // 8  — $GREEN base units if the price is unusable
+    pub bump: u8,                     // 1
+}
+
+impl PricingConfig {
+    /// $GREEN base reward for one claim at the current oracle price, or the
+    /// fallback amount if the price fails `OraclePrice::validate`.
+    fn reward_amount(&self, feed: &AccountInfo, now: i64) -> Result<u64> {
+        let price = oracle::load_pyth_price(feed, &self.feed_id)?;
+        if let Err(e) = price.validate(now, self.max_staleness, self.max_conf_bps) {
+            if self.fallback_amount == 0 {
+                return Err(e);
+            }
+            msg!("Oracle price unusable; paying fallback amount");
+            return Ok(self.fallback_amount);
+        }
+        price
+            .amount_for_value(self.target_value, PRICE_TARGET_DECIMALS, GREEN_TOKEN_DECIMALS)
+            .ok_or_else(|| error!(KisanError::InvalidPriceFeed))
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CircuitConfig {
+    pub circuit_id: u16,              // 2  — compliance circuit identifier
+    pub active: bool,                 // 1  — claims accepted for this circuit
+    pub green_enabled: bool,          // 1  — pays the season's $GREEN reward
+    pub water_amount: u64,            // 8  — $WATER per claim (0 = none)
+    pub soil_amount: u64,             // 8  — $SOIL per claim (0 = none)
+    pub methodology_id: u16,          // 2  — registry methodology of issued credits
+    pub total_claims: u64,            // 8  — claims verified against this circuit
+    pub bump: u8,                     // 1
+    pub proof_system: ProofSystem,    // 1  — how verify_and_mint checks proofs
+    pub verifier_program: Pubkey,     // 32 — external verifier (ProofSystem::External)
+}
+
+/// Proof systems a circuit can be verified with.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug, InitSpace)]
+pub enum ProofSystem {
+    Groth16,
+    Plonk,
+    /// Verified by CPI into `CircuitConfig::verifier_program`
+    External,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Groth16VerifyingKey {
+    pub circuit_id: u16,              // 2
+    pub key: groth16::VerifyingKey,   // 1540 — see groth16.rs
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PlonkVerifyingKey {
+    pub circuit_id: u16,              // 2
+    pub key: plonk::VerifyingKey,     // 741 — see plonk.rs
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PreviousPlonkVerifyingKey {
+    pub circuit_id: u16,              // 2
+    pub key: plonk::VerifyingKey,     // 741 — the key last rotated out
+    pub valid_until_slot: u64,        // 8  — last slot proofs against it verify
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PreviousGroth16VerifyingKey {
+    pub circuit_id: u16,              // 2
+    pub key: groth16::VerifyingKey,   // 1540 — the key last rotated out
+    pub valid_until_slot: u64,        // 8  — last slot proofs against it verify
+    pub bump: u8,                     // 1
+}
+
+impl PlonkVerifyingKey {
+    /// Install `key`, keeping the outgoing one in `previous` for
+    /// `overlap_slots` more slots. Returns the last slot it verifies.
+    fn rotate(
+        &mut self,
+        previous: &mut PreviousPlonkVerifyingKey,
+        key: plonk::VerifyingKey,
+        overlap_slots: u64,
+        slot: u64,
+    ) -> Result<u64> {
+        require!(key.is_well_formed(), KisanError::InvalidVerifyingKey);
+        check_vk_overlap(overlap_slots)?;
+        previous.circuit_id = self.circuit_id;
+        previous.key = std::mem::replace(&mut self.key, key);
+        previous.valid_until_slot = slot + overlap_slots;
+        Ok(previous.valid_until_slot)
+    }
+}
+
+impl Groth16VerifyingKey {
+    /// Install `key`, keeping the outgoing one in `previous` for
+    /// `overlap_slots` more slots. Returns the last slot it verifies.
+    fn rotate(
+        &mut self,
+        previous: &mut PreviousGroth16VerifyingKey,
+        key: groth16::VerifyingKey,
+        overlap_slots: u64,
+        slot: u64,
+    ) -> Result<u64> {
+        require!(key.is_well_formed(), KisanError::InvalidVerifyingKey);
+        check_vk_overlap(overlap_slots)?;
+        previous.circuit_id = self.circuit_id;
+        previous.key = std::mem::replace(&mut self.key, key);
+        previous.valid_until_slot = slot + overlap_slots;
+        Ok(previous.valid_until_slot)
+    }
+}
+
+fn check_vk_overlap(overlap_slots: u64) -> Result<()> {
+    if overlap_slots > MAX_VK_OVERLAP_SLOTS {
+        return Err(report::exceeded(
+            KisanError::InvalidVkOverlap,
+            overlap_slots,
+            MAX_VK_OVERLAP_SLOTS,
+        ));
+    }
+    Ok(())
+}
+
+/// `key` while `valid_until_slot` hasn't passed: a rotated-out key in its
+/// overlap window.
+fn overlap_key<K>(key: &K, valid_until_slot: u64) -> Result<&K> {
+    let slot = Clock::get()?.slot;
+    if slot > valid_until_slot {
+        return Err(report::exceeded(
+            KisanError::VerifyingKeyRetired,
+            slot,
+            valid_until_slot,
+        ));
+    }
+    Ok(key)
+}
+
+impl CircuitConfig {
+    /// Companion-token payout for `kind`; $GREEN is priced by the season.
+    fn reward_for(&self, kind: MintKind) -> u64 {
+        match kind {
+            MintKind::Green => 0,
+            MintKind::Water => self.water_amount,
+            MintKind::Soil => self.soil_amount,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Season {
+    pub index: u32,                   // 4  — season number, from 0
+    pub start: i64,                   // 8  — unix timestamp, inclusive
+    pub end: i64,                     // 8  — unix timestamp, exclusive
+    pub reward_amount: u64,           // 8  — $GREEN per verified claim
+    pub mint_cap: u64,                // 8  — max $GREEN minted through claims
+    pub max_claims_per_farmer: u32,   // 4  — per-farmer claim cap
+    pub total_proofs: u64,            // 8  — claims verified this season
+    pub total_minted: u64,            // 8  — $GREEN minted through claims
+    pub bump: u8,                     // 1
+}
+
+impl Season {
+    fn open(&mut self, index: u32, start: i64, config: &Config, bump: u8) {
+        self.index = index;
+        self.start = start;
+        self.end = start + config.season_duration;
+        self.reward_amount = config.emission_curve.reward(config.season_reward_amount, index);
+        self.mint_cap = config.season_mint_cap;
+        self.max_claims_per_farmer = config.season_max_claims_per_farmer;
+        self.bump = bump;
+    }
+
+    /// Account for one verified claim and return the $GREEN reward to
+    /// mint: `base_reward` plus a bonus of `bonus_bps` basis points.
+    /// Claims on circuits that don't pay $GREEN pass a zero base.
+    fn record_claim(&mut self, now: i64, base_reward: u64, bonus_bps: u16) -> Result<u64> {
+        require!(now >= self.start && now < self.end, KisanError::SeasonClosed);
+        let bonus = base_reward * bonus_bps as u64 / BPS_DENOMINATOR;
+        let reward = base_reward + bonus;
+        let minted = self.total_minted + reward;
+        if minted > self.mint_cap {
+            return Err(report::exceeded(KisanError::SeasonCapReached, minted, self.mint_cap));
+        }
+        self.total_minted = minted;
+        self.total_proofs += 1;
+        Ok(reward)
+    }
+
+    /// Less than one fixed reward of the mint budget is left.
+    fn exhausted(&self) -> bool {
+        self.mint_cap.saturating_sub(self.total_minted) < self.reward_amount
+    }
+}
+
+/// One region's share of a season's claims.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct RegionTally {
+    pub region_code: u16,             // 2
+    pub proofs: u64,                  // 8  — claims by the region's farmers
+    pub minted: u64,                  // 8  — $GREEN minted for them
+    pub farmers: u64,                 // 8  — distinct farmers who claimed
+}
+
+/// Running breakdown of the open season's farmer claims, kept next to the
+/// `Season` totals and frozen into a `SeasonSnapshot` at rollover. Escrow
+/// and cross-chain mints have no farmer profile and only show in the
+/// `Season` totals.
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonTally {
+    pub season: u32,                  // 4  — season index
+    pub unique_farmers: u64,          // 8  — distinct farmers who claimed
+    pub untracked_proofs: u64,        // 8  — claims from regions past the cap, or unregistered
+    pub untracked_minted: u64,        // 8  — $GREEN minted for them
+    #[max_len(MAX_TALLY_REGIONS)]
+    pub regions: Vec<RegionTally>,    // 4 + 64 × 26 — in order of first claim
+    pub bump: u8,                     // 1
+}
+
+impl SeasonTally {
+    fn open(&mut self, season: u32, bump: u8) {
+        self.season = season;
+        self.bump = bump;
+    }
+
+    /// Count a claim made without a farmer profile, which has no region.
+    fn record_unregistered(&mut self, amount: u64) {
+        self.untracked_proofs += 1;
+        self.untracked_minted += amount;
+    }
+
+    /// Count a claim by `profile`, just recorded by `record_season_claim`,
+    /// that minted `amount`.
+    fn record(&mut self, profile: &FarmerProfile, amount: u64) {
+        let new_farmer = profile.season_claims == 1;
+        if new_farmer {
+            self.unique_farmers += 1;
+        }
+        let code = profile.region_code;
+        let idx = match self.regions.iter().position(|r| r.region_code == code) {
+            Some(idx) => idx,
+            None if self.regions.len() < MAX_TALLY_REGIONS => {
+                self.regions.push(RegionTally {
+                    region_code: code,
+                    proofs: 0,
+                    minted: 0,
+                    farmers: 0,
+                });
+                self.regions.len() - 1
+            }
+            None => {
+                self.untracked_proofs += 1;
+                self.untracked_minted += amount;
+                return;
+            }
+        };
+        let region = &mut self.regions[idx];
+        region.proofs += 1;
+        region.minted += amount;
+        region.farmers += new_farmer as u64;
+    }
+}
+
+/// A closed season's totals, written once by `advance_season` and never
+/// modified, so impact reports can be built from on-chain data alone.
+#[account]
+#[derive(InitSpace)]
+pub struct SeasonSnapshot {
+    pub season: u32,                  // 4  — season index
+    pub start: i64,                   // 8
+    pub end: i64,                     // 8
+    pub closed_at: i64,               // 8  — when the rollover ran
+    pub total_proofs: u64,            // 8  — every claim minted in the season
+    pub total_minted: u64,            // 8  — $GREEN minted through them
+    pub unique_farmers: u64,          // 8  — distinct farmers with a profile claim
+    pub untracked_proofs: u64,        // 8  — profile claims outside `regions`
+    pub untracked_minted: u64,        // 8
+    #[max_len(MAX_TALLY_REGIONS)]
+    pub regions: Vec<RegionTally>,    // 4 + 64 × 26
+    pub bump: u8,                     // 1
+}
+
+impl SeasonSnapshot {
+    fn take(season: &Season, tally: &SeasonTally, now: i64, bump: u8) -> Self {
+        SeasonSnapshot {
+            season: season.index,
+            start: season.start,
+            end: season.end,
+            closed_at: now,
+            total_proofs: season.total_proofs,
+            total_minted: season.total_minted,
+            unique_farmers: tally.unique_farmers,
+            untracked_proofs: tally.untracked_proofs,
+            untracked_minted: tally.untracked_minted,
+            regions: tally.regions.clone(),
+            bump,
+        }
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Delegation {
+    pub farmer: Pubkey,               // 32 — farmer granting the delegation
+    pub delegate: Pubkey,             // 32 — field agent allowed to claim
+    pub expiry: i64,                  // 8  — unix timestamp, exclusive
+    pub max_claims: u32,              // 4  — claim allowance
+    pub claims_used: u32,             // 4  — claims submitted so far
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Custodian {
+    pub program: Pubkey,              // 32 — custodial wallet program
+    #[max_len(32)]
+    pub seed_prefix: Vec<u8>,         // 4 + 32 — wallet PDAs are [seed_prefix, farmer]
+    pub registered_at: i64,           // 8
+    pub bump: u8,                     // 1
+}
+
+impl Custodian {
+    /// The custodian's wallet PDA for `farmer`.
+    fn wallet_of(&self, farmer: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[&self.seed_prefix, farmer.as_ref()], &self.program).0
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CustodialLink {
+    pub farmer: Pubkey,               // 32
+    pub custodian: Pubkey,            // 32 — custodial wallet program
+    pub wallet: Pubkey,               // 32 — the custodian's PDA for this farmer
+    pub linked_at: i64,               // 8
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SessionKey {
+    pub farmer: Pubkey,               // 32 — farmer who authorized the key
+    pub ephemeral_key: Pubkey,        // 32 — device-held signing key
+    pub expiry: i64,                  // 8  — unix timestamp, exclusive
+    pub scope: u8,                    // 1  — SESSION_SCOPE_* bitmask
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PreCommit {
+    pub farmer: Pubkey,               // 32 — farmer who committed
+    pub hash: [u8; 32],               // 32 — hash_of(commitment, farmer)
+    pub committed_slot: u64,          // 8  — slot of pre_commit
+    pub committed_at: i64,            // 8  — timestamp of pre_commit
+    pub revealed_slot: u64,           // 8  — slot of the revealing claim (0 = unrevealed)
+    pub bump: u8,                     // 1
+}
+
+impl PreCommit {
+    /// `keccak256(commitment || farmer)`; binding the farmer keeps one
+    /// farmer's pre-commit from vouching for another's claim.
+    pub fn hash_of(commitment: &[u8; 32], farmer: &Pubkey) -> [u8; 32] {
+        keccak::hashv(&[commitment, farmer.as_ref()]).to_bytes()
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RelayerInfo {
+    pub relayer: Pubkey,              // 32 — allowlisted fee payer
+    pub max_claims_per_window: u32,   // 4  — rate limit
+    pub window_seconds: i64,          // 8  — rate-limit window length
+    pub window_start: i64,            // 8  — start of the current window
+    pub claims_in_window: u32,        // 4  — claims relayed this window
+    pub total_relayed: u64,           // 8  — lifetime relayed claims
+    pub stake_per_claim: u64,         // 8  — lamports bonded per claim of quota, 0 = flat
+    pub stake: u64,                   // 8  — bonded lamports (above rent)
+    pub total_slashed: u64,           // 8  — lamports slashed to date
+    pub unbonding_at: i64,            // 8  — 0 while bonded
+    pub bump: u8,                     // 1
+}
+
+impl RelayerInfo {
+    /// Claims allowed per window: `max_claims_per_window`, cut to what the
+    /// stake covers when the relayer is staked, and zero once unbonding.
+    pub fn quota(&self) -> u32 {
+        if self.unbonding_at != 0 {
+            return 0;
+        }
+        if self.stake_per_claim == 0 {
+            return self.max_claims_per_window;
+        }
+        let covered = self.stake / self.stake_per_claim;
+        covered.min(self.max_claims_per_window as u64) as u32
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RelaySlash {
+    pub relayer: Pubkey,              // 32 — relayer that dropped the claim
+    pub commitment: [u8; 32],         // 32 — commitment on its receipt
+    pub reporter: Pubkey,             // 32 — who reported it and was paid
+    pub amount: u64,                  // 8  — lamports slashed
+    pub slashed_at: i64,              // 8
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct CpiIntegrator {
+    pub program: Pubkey,              // 32 — program allowed to CPI into verify_and_mint
+    pub registered_at: i64,           // 8
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct LookupTableInfo {
+    pub table: Pubkey,                // 32 — program-owned lookup table
+    pub address_count: u16,           // 2  — addresses added so far
+    pub deactivated: bool,            // 1  — closable after the cooldown
+    pub created_at: i64,              // 8
+    pub bump: u8,                     // 1
+    pub authority_bump: u8,           // 1  — bump of the lookup-table-authority PDA
+}
+
+/// Authority actions recorded in the admin log, stored as `u16` in
+/// `AdminLogEntry::action`. Append new variants at the end only.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+#[repr(u16)]
+pub enum AdminAction {
+    InitializeAdminLog,
+    RegisterRelayer,
+    RemoveRelayer,
+    CreateLookupTable,
+    ExtendLookupTable,
+    DeactivateLookupTable,
+    CloseLookupTable,
+    InitializeFeatureFlags,
+    SetFeature,
+    InitializeRentPool,
+    CloseProofRecord,
+    InitializeStateTree,
+    CompressProofRecord,
+    RegisterAttester,
+    RemoveAttester,
+    InitializeEscrowVault,
+    ConfigureRegion,
+    SetReferralBudget,
+    InitializeBadgeTree,
+    InitializeReceiptTree,
+    SetIssuanceReceipts,
+    InitializeLeaderboard,
+    InitializeSeasons,
+    SetSeasonConfig,
+    InitializeMint,
+    ConfigureCircuit,
+    SetPlonkVerifyingKey,
+    SetCircuitVerifier,
+    ConfigurePricing,
+    SetStreakCurve,
+    SetPersonhoodRequirement,
+    SetPmKisanRequirement,
+    SetWeatherThresholds,
+    RegisterWeatherOracle,
+    RemoveWeatherOracle,
+    InitializeInsurancePool,
+    ConfigureLoans,
+    InitializeTreasury,
+    BuybackAndBurn,
+    RegisterForeignEmitter,
+    RegisterEvmOracle,
+    RemoveEvmOracle,
+    ConfigureImagery,
+    ConfigureDrones,
+    RegisterDroneOperator,
+    SlashDroneOperator,
+    RegisterMandiReporter,
+    RemoveMandiReporter,
+    InitializeMandiFeed,
+    SetLandRecordRequirement,
+    SetCrossChainConfig,
+    InitializeCurve,
+    /// No longer written: liquidity migrates by governance proposal
+    MigrateLiquidity,
+    ResetState,
+    AirdropGreen,
+    RegisterCpiIntegrator,
+    RemoveCpiIntegrator,
+    FreezeAccount,
+    ThawAccount,
+    ConfigurePayouts,
+    ResolveVoucherDispute,
+    SetPriceFloor,
+    ConfigureMarketplace,
+    InitializeConfig,
+    SetPaused,
+    InitializeMintStats,
+    SetIncentivesBudget,
+    Distribute,
+    InitializeOnboardingVault,
+    SetOnboardingBonus,
+    ConfigureTreasury,
+    WithdrawTreasury,
+    ProposeTreasuryWithdrawal,
+    ExecuteTreasuryWithdrawal,
+    CancelTreasuryWithdrawal,
+    RotatePlonkVerifyingKey,
+    InitializeSgreen,
+    SetSgreenRate,
+    ConfigureGovernance,
+    SetVoteWeighting,
+    SetEmissionCurve,
+    AddOracleReporter,
+    SetOracleReporterScopes,
+    RemoveOracleReporter,
+    RotateOracleReporter,
+    ConfigureOracleQuorum,
+    SetRecovery,
+    InitiateRecovery,
+    CancelRecovery,
+    ClaimRecovery,
+    SkipQueuedClaim,
+    RegisterCustodian,
+    RemoveCustodian,
+    ConfigureNodeRewards,
+    ConfigureBounty,
+    FundBountyPool,
+    ResolveFraudReport,
+    SetGroth16VerifyingKey,
+    RotateGroth16VerifyingKey,
+    /// A passed governance proposal; the entry's authority is the proposal
+    ExecuteProposal,
+}
+
+#[zero_copy]
+#[derive(Default)]
+pub struct AdminLogEntry {
+    pub authority: Pubkey,            // 32 — signer of the action, or the proposal
+    pub target: Pubkey,               // 32 — account or key acted on
+    pub old_value: u64,               // 8  — main setting before the action
+    pub new_value: u64,               // 8  — and after it
+    pub timestamp: i64,               // 8
+    pub action: u16,                  // 2  — AdminAction
+    pub _padding: [u8; 6],            // 6
+}
+
+/// Most recent `ADMIN_LOG_LEN` authority actions, oldest overwritten
+/// first. `entries[head]` is the next slot to write; `total_actions`
+/// lets auditors notice entries that have rolled off.
+#[account(zero_copy)]
+pub struct AdminLog {
+    pub entries: [AdminLogEntry; ADMIN_LOG_LEN],
+    pub total_actions: u64,           // 8  — lifetime actions logged
+    pub head: u32,                    // 4
+    pub len: u32,                     // 4  — number of populated entries
+    pub bump: u8,                     // 1
+    pub _padding: [u8; 7],            // 7
+}
+
+impl AdminLog {
+    fn push(&mut self, entry: AdminLogEntry) {
+        self.entries[self.head as usize] = entry;
+        self.head = (self.head + 1) % ADMIN_LOG_LEN as u32;
+        self.len = (self.len + 1).min(ADMIN_LOG_LEN as u32);
+        self.total_actions += 1;
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct FeatureFlags {
+    pub flags: u64,                   // 8  — bitset of enabled FEATURE_* bits
+    pub updated_at: i64,              // 8  — last toggle
+    pub bump: u8,                     // 1
+}
+
+impl FeatureFlags {
+    fn is_enabled(&self, feature: u64) -> bool {
+        self.flags & feature == feature
+    }
+
+    fn require_enabled(&self, feature: u64) -> Result<()> {
+        require!(self.is_enabled(feature), KisanError::FeatureDisabled);
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RentPool {
+    pub total_deposited: u64,         // 8  — lamports ever deposited
+    pub total_sponsored: u64,         // 8  — lamports paid out as proof rent
+    pub total_reclaimed: u64,         // 8  — lamports returned by closed records
+    pub active_sponsorships: u64,     // 8  — open records whose rent the pool paid
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AttesterInfo {
+    pub attester: Pubkey,             // 32 — whitelisted attester wallet
+    pub registered_at: i64,           // 8  — registration timestamp
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct NodeRewardConfig {
+    pub share_bps: u16,               // 2  — attesting nodes' share of an issuance
+    pub quorum: u8,                   // 1  — attesters signing each quorum verification
+    pub total_accrued: u64,           // 8  — $GREEN base units minted into the vault
+    pub bump: u8,                     // 1
+}
+
+/// An attester node's $GREEN earned from the claims its quorums verified.
+#[account]
+#[derive(InitSpace)]
+pub struct NodeRewards {
+    pub node: Pubkey,                 // 32 — attester wallet
+    pub unclaimed: u64,               // 8  — $GREEN base units held in the vault
+    pub total_earned: u64,            // 8  — lifetime $GREEN base units
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BountyConfig {
+    pub share_bps: u16,               // 2  — reporter's share of the clawed amount
+    pub min_bond: u64,                // 8  — least $GREEN a report bonds
+    pub pool: u64,                    // 8  — $GREEN in the vault available for bounties
+    pub total_funded: u64,            // 8  — lifetime $GREEN moved in from the treasury
+    pub total_paid: u64,              // 8  — lifetime bounties paid
+    pub bump: u8,                     // 1
+}
+
+impl BountyConfig {
+    /// Reporter's share of `recovered` $GREEN, as far as the pool covers it.
+    pub fn bounty(&self, recovered: u64) -> u64 {
+        ((recovered as u128 * self.share_bps as u128 / BPS_DENOMINATOR as u128) as u64)
+            .min(self.pool)
+    }
+}
+
+/// A bonded report that a minted claim was fraudulent. Closed if rejected;
+/// kept once upheld.
+#[account]
+#[derive(InitSpace)]
+pub struct FraudReport {
+    pub reporter: Pubkey,             // 32 — whistleblower, paid the bounty
+    pub claim_id: u64,                // 8  — reported claim's ID
+    pub farmer: Pubkey,               // 32 — farmer the claim was minted to
+    pub claim_amount: u64,            // 8  — $GREEN the claim issued
+    pub evidence_hash: [u8; 32],      // 32 — hash of the off-chain evidence
+    pub bond: u64,                    // 8  — $GREEN held in the bounty vault
+    pub reported_at: i64,             // 8
+    pub resolved_at: i64,             // 8  — 0 while open
+    pub owed: u64,                    // 8  — frozen $GREEN the farmer owes, once upheld
+    pub clawed: u64,                  // 8  — $GREEN recovered to the treasury
+    pub bounty: u64,                  // 8  — $GREEN paid on top of the bond
+    pub bump: u8,                     // 1
+}
+
+/// The attesters whose quorum verified a claim, paid at issuance.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimAttestation {
+    pub claim: Pubkey,                // 32 — Claim PDA
+    #[max_len(MAX_ATTESTATION_QUORUM)]
+    pub nodes: Vec<Pubkey>,           // 4 + 5 × 32 — distinct signing attesters
+    pub attested_at: i64,             // 8
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct EscrowAccount {
+    pub identity_hash: [u8; 32],      // 32 — salted phone-number hash
+    pub registered_by: Pubkey,        // 32 — attester who registered the identity
+    pub balance: u64,                 // 8  — $GREEN held in escrow
+    pub total_escrowed: u64,          // 8  — lifetime $GREEN escrowed
+    pub claimed_wallet: Pubkey,       // 32 — wallet bound by the first claim (default if never)
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RegionConfig {
+    pub region_code: u16,             // 2  — numeric region identifier
+    pub require_identity: bool,       // 1  — claims need an identity attestation
+    pub min_rainfall_mm: u32,         // 4  — drought trigger (season total)
+    pub max_rainfall_mm: u32,         // 4  — flood trigger; 0 = no weather cover
+    pub max_temperature_dc: i16,      // 2  — heat trigger, tenths of °C
+    pub bump: u8,                     // 1
+    pub require_land_record: bool,    // 1  — parcels must link a land record
+}
+
+impl RegionConfig {
+    /// Whether `report` is outside this region's thresholds. Low rainfall
+    /// only counts once the season's report is final.
+    pub fn weather_triggered(&self, report: &WeatherReport) -> bool {
+        report.rainfall_mm > self.max_rainfall_mm
+            || report.max_temperature_dc > self.max_temperature_dc
+            || (report.is_final && report.rainfall_mm < self.min_rainfall_mm)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Parcel {
+    pub farmer: Pubkey,               // 32 — owning farmer
+    pub parcel_id: u32,               // 4  — farmer-scoped parcel number
+    pub region_code: u16,             // 2  — region, copied from the farmer profile
+    pub registered_at: i64,           // 8  — registration timestamp
+    pub bump: u8,                     // 1
+    pub land_record: Pubkey,          // 32 — linked LandRecord (default = none)
+}
+
+/// How the rewards of claims on one parcel divide between the farmer
+/// working it and its landowner. Created and dissolved with both
+/// signatures.
+#[account]
+#[derive(InitSpace)]
+pub struct PayoutSplit {
+    pub parcel: Pubkey,               // 32 — Parcel PDA the split applies to
+    pub farmer: Pubkey,               // 32 — tenant farming the parcel
+    pub landowner: Pubkey,            // 32 — receives the landowner share
+    pub farmer_bps: u16,              // 2  — farmer's share of each reward
+    pub landowner_bps: u16,           // 2  — landowner's share; sums to 10 000
+    pub agreed_at: i64,               // 8  — when both parties signed
+    pub bump: u8,                     // 1
+}
+
+impl PayoutSplit {
+    /// The split behind `info`, a parcel's PayoutSplit PDA, if one was
+    /// created.
+    fn load(info: &UncheckedAccount) -> Result<Option<PayoutSplit>> {
+        if info.owner != &crate::ID || info.data_is_empty() {
+            return Ok(None);
+        }
+        let split = PayoutSplit::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        Ok(Some(split))
+    }
+
+    /// The landowner's part of `reward`; rounding favours the farmer.
+    pub fn landowner_share(&self, reward: u64) -> u64 {
+        (reward as u128 * self.landowner_bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct LandRecord {
+    pub parcel_id_hash: [u8; 32],     // 32 — hash of the survey / khasra number
+    pub record_hash: [u8; 32],        // 32 — hash of the record contents
+    pub attester: Pubkey,             // 32 — land-records attester that signed
+    pub anchored_at: i64,             // 8
+    pub parcel: Pubkey,               // 32 — linked Parcel (default = none)
+    pub bump: u8,                     // 1
+}
+
+/// Legacy weather oracle whitelist entry, superseded by `OracleReporter`;
+/// kept so existing entries can be closed.
+#[account]
+#[derive(InitSpace)]
+pub struct WeatherOracleInfo {
+    pub oracle: Pubkey,               // 32 — whitelisted weather reporter
+    pub registered_at: i64,           // 8  — registration timestamp
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct WeatherReport {
+    pub region_code: u16,             // 2  — region observed
+    pub season: u32,                  // 4  — season observed
+    pub rainfall_mm: u32,             // 4  — cumulative season rainfall
+    pub max_temperature_dc: i16,      // 2  — season peak, tenths of °C
+    pub is_final: bool,               // 1  — closing report; no further updates
+    pub reported_by: Pubkey,          // 32 — oracle of the latest update
+    pub reported_at: i64,             // 8  — timestamp of the latest update
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct InsurancePool {
+    pub payout_multiple_bps: u32,     // 4  — payout as bps of premium
+    pub total_funded: u64,            // 8  — lamports deposited with fund_insurance_pool
+    pub total_premiums: u64,          // 8  — lamports received as premiums
+    pub cover_outstanding: u64,       // 8  — payouts reserved by open cover
+    pub total_paid_out: u64,          // 8  — lamports paid to farmers
+    pub bump: u8,                     // 1
+}
+
+impl InsurancePool {
+    /// The payout `premium` buys, provided the pool's `balance` plus the
+    /// premium still covers every outstanding payout, this one included,
+    /// above the rent-exempt `floor`.
+    fn cover_payout(&self, premium: u64, balance: u64, floor: u64) -> Result<u64> {
+        let payout = premium as u128 * self.payout_multiple_bps as u128 / BPS_DENOMINATOR as u128;
+        let reserved = floor as u128 + self.cover_outstanding as u128 + payout;
+        require!(
+            balance as u128 + premium as u128 >= reserved,
+            KisanError::InsurancePoolDepleted
+        );
+        Ok(u64::try_from(payout).map_err(|_| KisanError::InsurancePoolDepleted)?)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct LoanConfig {
+    pub usdc_mint: Pubkey,            // 32 — loan currency
+    pub green_price: u64,             // 8  — USDC base units per 1 $GREEN of collateral
+    pub base_ltv_bps: u16,            // 2  — LTV for a farmer with no proofs
+    pub ltv_per_proof_bps: u16,       // 2  — LTV added per verified proof
+    pub max_ltv_bps: u16,             // 2  — LTV cap
+    pub grace_period: i64,            // 8  — seconds after due before liquidation
+    pub bump: u8,                     // 1
+}
+
+impl LoanConfig {
+    /// Largest principal `collateral` $GREEN can back for a farmer with
+    /// `total_proofs` verified proofs.
+    fn max_principal(&self, collateral: u64, total_proofs: u64) -> u64 {
+        let ltv_bps = (self.base_ltv_bps as u64)
+            .saturating_add((self.ltv_per_proof_bps as u64).saturating_mul(total_proofs))
+            .min(self.max_ltv_bps as u64);
+        let value = collateral as u128 * self.green_price as u128 / GREEN_UNIT as u128;
+        (value * ltv_bps as u128 / BPS_DENOMINATOR as u128).min(u64::MAX as u128) as u64
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum LoanStatus {
+    Requested,
+    Active,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Loan {
+    pub farmer: Pubkey,               // 32 — borrower
+    pub loan_id: u32,                 // 4  — farmer-scoped loan number
+    pub lender: Pubkey,               // 32 — funder (default until funded)
+    pub principal: u64,               // 8  — USDC base units
+    pub collateral: u64,              // 8  — $GREEN base units locked
+    pub interest_bps: u16,            // 2  — flat interest over the term
+    pub duration: i64,                // 8  — term in seconds
+    pub funded_at: i64,               // 8  — 0 until funded
+    pub due_at: i64,                  // 8  — funded_at + duration
+    pub status: LoanStatus,           // 1
+    pub bump: u8,                     // 1
+    pub usdc_mint: Pubkey,            // 32 — loan currency, fixed at funding
+    pub grace_period: i64,            // 8  — fixed at funding
+}
+
+impl Loan {
+    /// Principal plus flat interest; `None` if that overflows, which
+    /// `request_loan` refuses.
+    fn amount_due(&self) -> Option<u64> {
+        let interest = self.principal as u128 * self.interest_bps as u128 / BPS_DENOMINATOR as u128;
+        self.principal.checked_add(u64::try_from(interest).ok()?)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Subscription {
+    pub buyer: Pubkey,                // 32 — corporate offtaker
+    pub rate_per_epoch: u64,          // 8  — USDC base units per epoch
+    pub share_bps: u16,               // 2  — share of farmer issuance delivered
+    pub epoch_seconds: i64,           // 8  — settlement interval
+    pub next_settlement_at: i64,      // 8  — earliest time of the next settlement
+    pub issued_snapshot: u64,         // 8  — next_serial at the last settlement
+    pub total_paid: u64,              // 8  — USDC paid to the treasury
+    pub total_delivered: u64,         // 8  — $GREEN delivered
+    pub bump: u8,                     // 1
+}
+
+impl Subscription {
+    /// Epochs elapsed and unsettled at `now`, and the USDC they cost.
+    pub fn amount_due(&self, now: i64) -> Result<(i64, u64)> {
+        require!(
+            now >= self.next_settlement_at,
+            KisanError::SubscriptionNotDue
+        );
+        let epochs = 1 + (now - self.next_settlement_at) / self.epoch_seconds;
+        let due = self
+            .rate_per_epoch
+            .checked_mul(epochs as u64)
+            .ok_or(KisanError::SubscriptionUnderfunded)?;
+        Ok((epochs, due))
+    }
+
+    /// The buyer's share of credits issued to farmers since the last
+    /// settlement, `next_serial` being the first unassigned serial.
+    pub fn credits_due(&self, next_serial: u64) -> u64 {
+        let issued = next_serial.saturating_sub(self.issued_snapshot);
+        (issued as u128 * self.share_bps as u128 / BPS_DENOMINATOR as u128) as u64
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OfftakeOffer {
+    pub farmer: Pubkey,               // 32 — seller; paid on each settlement it fills
+    pub total_sold: u64,              // 8  — $GREEN delivered to subscribers
+    pub total_earned: u64,            // 8  — USDC received, after the protocol fee
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BondingCurve {
+    pub usdc_mint: Pubkey,            // 32 — reserve currency
+    pub base_price: u64,              // 8  — USDC base units for the first $GREEN
+    pub slope: u64,                   // 8  — USDC base units added per $GREEN sold
+    pub max_supply: u64,              // 8  — $GREEN base units the curve may sell
+    pub sold: u64,                    // 8  — $GREEN base units outstanding
+    pub reserve: u64,                 // 8  — USDC held for sellers
+    pub active: bool,                 // 1  — false once liquidity is migrated
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Cover {
+    pub farmer: Pubkey,               // 32 — insured farmer
+    pub parcel: Pubkey,               // 32 — Parcel PDA
+    pub region_code: u16,             // 2  — parcel's region at purchase
+    pub season: u32,                  // 4  — season covered
+    pub premium: u64,                 // 8  — lamports paid
+    pub payout: u64,                  // 8  — lamports paid if triggered
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct FarmerProfile {
+    pub farmer: Pubkey,               // 32 — farmer wallet
+    pub region_code: u16,             // 2  — region the farmer is registered in
+    pub identity_attestation: Pubkey, // 32 — IdentityAttestation PDA (default if none)
+    pub personhood_record: Pubkey,    // 32 — PersonhoodRecord PDA (default if none)
+    pub referred_by: Pubkey,          // 32 — ReferralCode PDA (default if none)
+    pub badges_claimed: u8,           // 1  — bitmask over BADGE_MILESTONES
+    pub leaderboard_epoch: u32,       // 4  — leaderboard epoch of `epoch_proofs`
+    pub epoch_proofs: u64,            // 8  — verified proofs in that epoch
+    pub season_index: u32,            // 4  — season of `season_claims`
+    pub season_claims: u32,           // 4  — claims in that season
+    pub streak: u32,                  // 4  — consecutive seasons with a claim
+    pub total_proofs: u64,            // 8  — verified proofs for this farmer
+    pub registered_at: i64,           // 8  — registration timestamp
+    pub last_claim_at: i64,           // 8  — timestamp of the latest verified claim
+    pub bump: u8,                     // 1
+    pub pm_kisan_attestation: Pubkey, // 32 — PmKisanAttestation PDA (default if none)
+    pub version: u8,                  // 1  — layout version (ACCOUNT_VERSION)
+    pub contact_hash: [u8; 32],       // 32 — salted hash of contact details (zero if unset)
+    pub profile_updates: u32,         // 4  — attested updates, signed into the next
+    pub compromised_at: i64,          // 8  — when an attester flagged the wallet (0 = never)
+    pub migrated_to: Pubkey,          // 32 — wallet the profile moved to (default if none)
+    pub onboarding_bonus: u64,        // 8  — escrowed bonus awaiting the first claim
+    pub onboarding_deadline: i64,     // 8  — after this the bonus can be reclaimed
+}
+
+/// One page of a farmer's claim history: the commitments of claims
+/// `page * CLAIM_INDEX_PAGE_LEN ..` in the order they were minted. Pages
+/// are derived from the claim count, so a client walks pages up to the
+/// profile's `total_proofs` instead of scanning program accounts.
+#[account]
+#[derive(InitSpace)]
+pub struct ClaimIndexPage {
+    pub farmer: Pubkey,               // 32 — farmer whose claims are listed
+    pub page: u64,                    // 8
+    pub count: u8,                    // 1  — commitments filled in
+    pub commitments: [[u8; 32]; CLAIM_INDEX_PAGE_LEN], // 1024 — ProofRecord commitments
+    pub bump: u8,                     // 1
+}
+
+impl ClaimIndexPage {
+    /// Record the farmer's next claim; called before `total_proofs` is
+    /// incremented, which is what picked this page.
+    fn record(&mut self, profile: &FarmerProfile, commitment: [u8; 32], bump: u8) {
+        let slot = (profile.total_proofs % CLAIM_INDEX_PAGE_LEN as u64) as usize;
+        self.farmer = profile.farmer;
+        self.page = profile.claim_index_page();
+        self.commitments[slot] = commitment;
+        self.count = slot as u8 + 1;
+        self.bump = bump;
+    }
+}
+
+impl FarmerProfile {
+    /// Claim index page the farmer's next claim is recorded on.
+    fn claim_index_page(&self) -> u64 {
+        self.total_proofs / CLAIM_INDEX_PAGE_LEN as u64
+    }
+
+    /// Enforce the season's per-farmer claim cap, count the claim and
+    /// return the farmer's streak. The streak grows on the first claim of
+    /// the season directly after the previous one and resets to 1 after a
+    /// missed season.
+    fn record_season_claim(&mut self, season: &Season) -> Result<u32> {
+        let first_claim = self.streak == 0;
+        if first_claim || self.season_index != season.index {
+            let consecutive = !first_claim && season.index == self.season_index + 1;
+            self.streak = if consecutive { self.streak + 1 } else { 1 };
+            self.season_index = season.index;
+            self.season_claims = 0;
+        }
+        require_ctx!(
+            self.season_claims < season.max_claims_per_farmer,
+            KisanError::FarmerSeasonCapReached,
+            "Farmer has {} of {} claims this season",
+            self.season_claims,
+            season.max_claims_per_farmer
+        );
+        self.season_claims += 1;
+        Ok(self.streak)
+    }
+}
+
+#[zero_copy]
+#[derive(Default)]
+pub struct LeaderboardEntry {
+    pub farmer: Pubkey,               // 32
+    pub proofs: u64,                  // 8  — verified proofs this epoch
+}
+
+/// Top `LEADERBOARD_SIZE` farmers for the current epoch, sorted by
+/// `proofs` descending. Only `entries[..len]` are meaningful.
+#[account(zero_copy)]
+pub struct Leaderboard {
+    pub entries: [LeaderboardEntry; LEADERBOARD_SIZE],
+    pub reset_at: i64,                // 8  — start of the current epoch
+    pub period_seconds: i64,          // 8  — epoch length
+    pub epoch: u32,                   // 4  — incremented on every reset
+    pub len: u32,                     // 4  — number of populated entries
+    pub bump: u8,                     // 1
+    pub _padding: [u8; 7],            // 7
+}
+
+impl Leaderboard {
+    /// Clear all entries and start a new epoch.
+    pub fn reset(&mut self, now: i64) {
+        self.entries = [LeaderboardEntry::default(); LEADERBOARD_SIZE];
+        self.len = 0;
+        self.epoch += 1;
+        self.reset_at = now;
+    }
+
+    /// Record that `farmer` now has `proofs` verified proofs this epoch,
+    /// keeping the table sorted. Counts only ever grow within an epoch,
+    /// so an entry can only move up.
+    pub fn record(&mut self, farmer: Pubkey, proofs: u64) {
+        let len = self.len as usize;
+        let mut idx = match self.entries[..len].iter().position(|e| e.farmer == farmer) {
+            Some(i) => i,
+            None if len < LEADERBOARD_SIZE => {
+                self.len += 1;
+                len
+            }
+            None if proofs > self.entries[len - 1].proofs => len - 1,
+            None => return,
+        };
+
+        self.entries[idx] = LeaderboardEntry { farmer, proofs };
+        while idx > 0 && self.entries[idx - 1].proofs < proofs {
+            self.entries.swap(idx - 1, idx);
+            idx -= 1;
+        }
+    }
+}
+
+#[zero_copy]
+#[derive(Default)]
+pub struct MintStatsEntry {
+    pub proofs: u64,                  // 8  — claims minted
+    pub minted: u64,                  // 8  — $GREEN minted through them
+    pub key: u16,                     // 2  — region code or circuit id
+    pub _padding: [u8; 6],            // 6
+}
+
+/// Lifetime farmer-claim totals per region and per circuit, so dashboards
+/// can chart them from one account read. Only `regions[..region_len]` and
+/// `circuits[..circuit_len]` are meaningful, in order of first mint.
+/// Escrow and cross-chain mints have no region and aren't counted.
+#[account(zero_copy)]
+pub struct MintStats {
+    pub regions: [MintStatsEntry; MINT_STATS_REGIONS],
+    pub circuits: [MintStatsEntry; MINT_STATS_CIRCUITS],
+    pub other_regions: MintStatsEntry, // 24 — regions past the cap
+    pub other_circuits: MintStatsEntry, // 24 — circuits past the cap
+    pub region_len: u32,              // 4  — number of populated regions
+    pub circuit_len: u32,             // 4  — number of populated circuits
+    pub bump: u8,                     // 1
+    pub _padding: [u8; 7],            // 7
+}
+
+impl MintStats {
+    /// Count a claim from `region_code` against `circuit_id` that minted
+    /// `amount`.
+    pub fn record(&mut self, region_code: u16, circuit_id: u16, amount: u64) {
+        let region = Self::slot(&mut self.regions, &mut self.region_len, region_code)
+            .unwrap_or(&mut self.other_regions);
+        region.proofs += 1;
+        region.minted += amount;
+
+        let circuit = Self::slot(&mut self.circuits, &mut self.circuit_len, circuit_id)
+            .unwrap_or(&mut self.other_circuits);
+        circuit.proofs += 1;
+        circuit.minted += amount;
+    }
+
+    /// The entry for `key`, claiming the next free one on first use.
+    fn slot<'a>(
+        entries: &'a mut [MintStatsEntry],
+        len: &mut u32,
+        key: u16,
+    ) -> Option<&'a mut MintStatsEntry> {
+        let n = *len as usize;
+        let idx = match entries[..n].iter().position(|e| e.key == key) {
+            Some(idx) => idx,
+            None if n < entries.len() => {
+                entries[n].key = key;
+                *len += 1;
+                n
+            }
+            None => return None,
+        };
+        Some(&mut entries[idx])
+    }
+}
+
+/// Legacy mandi reporter whitelist entry, superseded by `OracleReporter`;
+/// kept so existing entries can be closed.
+#[account]
+#[derive(InitSpace)]
+pub struct MandiReporter {
+    pub reporter: Pubkey,             // 32 — whitelisted reporter wallet
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OracleReporter {
+    pub reporter: Pubkey,             // 32 — reporter signing key
+    pub scopes: u8,                   // 1  — ORACLE_SCOPE_* feeds it may publish to
+    pub added_at: i64,                // 8  — registration (or rotation) time
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct OracleConfig {
+    pub weather_quorum: u8,           // 1  — reporters signing each weather report
+    pub mandi_price_quorum: u8,       // 1  — reporters signing each mandi price
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct RecoveryConfig {
+    pub recovery_key: Pubkey,         // 32 — wallet that may claim authority
+    pub inactivity_period: i64,       // 8  — seconds the authority must stay silent
+    pub initiated_at: i64,            // 8  — countdown start, 0 when none is running
+    pub actions_at_initiation: u64,   // 8  — AdminLog::total_actions when it started
+    pub bump: u8,                     // 1
+}
+
+#[zero_copy]
+#[derive(Default)]
+pub struct MandiPrice {
+    pub reporter: Pubkey,             // 32
+    pub price: u64,                   // 8  — paise per quintal
+    pub published_at: i64,            // 8
+}
+
+/// Most recent `MANDI_HISTORY_LEN` prices for one market, oldest
+/// overwritten first. `entries[head]` is the next slot to write.
+#[account(zero_copy)]
+pub struct MandiPriceFeed {
+    pub entries: [MandiPrice; MANDI_HISTORY_LEN],
+    pub total_reports: u64,           // 8  — lifetime reports published
+    pub head: u32,                    // 4
+    pub len: u32,                     // 4  — number of populated entries
+    pub commodity: u16,               // 2
+    pub region: u16,                  // 2
+    pub bump: u8,                     // 1
+    pub _padding: [u8; 3],            // 3
+}
+
+impl MandiPriceFeed {
+    fn push(&mut self, price: MandiPrice) {
+        self.entries[self.head as usize] = price;
+        self.head = (self.head + 1) % MANDI_HISTORY_LEN as u32;
+        self.len = (self.len + 1).min(MANDI_HISTORY_LEN as u32);
+        self.total_reports += 1;
+    }
+
+    /// Most recent report, if any.
+    pub fn latest(&self) -> Option<&MandiPrice> {
+        if self.len == 0 {
+            return None;
+        }
+        let idx = (self.head as usize + MANDI_HISTORY_LEN - 1) % MANDI_HISTORY_LEN;
+        Some(&self.entries[idx])
+    }
+
+    /// Most recent report, failing with `OracleDataStale` if it is more than
+    /// `max_age` seconds old.
+    pub fn latest_within(&self, now: i64, max_age: i64) -> Result<&MandiPrice> {
+        let latest = self.latest().ok_or(KisanError::OracleDataStale)?;
+        oracle::check_age(latest.published_at, now, max_age)?;
+        Ok(latest)
+    }
+
+    /// Mean of the reports published at or after `since`, for consumers
+    /// that want to smooth out a single outlying report.
+    pub fn mean_since(&self, since: i64) -> Option<u64> {
+        let (sum, count) = self.entries[..self.len as usize]
+            .iter()
+            .filter(|e| e.published_at >= since)
+            .fold((0u128, 0u128), |(s, c), e| (s + e.price as u128, c + 1));
+        (count > 0).then(|| (sum / count) as u64)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct BadgeConfig {
+    pub merkle_tree: Pubkey,          // 32 — Bubblegum tree holding badges
+    #[max_len(64)]
+    pub uri_prefix: String,           // 4 + 64 — metadata URI prefix
+    pub badges_minted: u64,           // 8  — lifetime badges minted
+    pub bump: u8,                     // 1
+    pub authority_bump: u8,           // 1  — bump of the badge-authority PDA
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReceiptConfig {
+    pub merkle_tree: Pubkey,          // 32 — Bubblegum tree holding issuance receipts
+    #[max_len(64)]
+    pub uri_prefix: String,           // 4 + 64 — metadata URI prefix
+    pub receipts_minted: u64,         // 8  — lifetime receipts minted
+    pub bump: u8,                     // 1
+    pub authority_bump: u8,           // 1  — bump of the receipt-authority PDA
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ReferralCode {
+    pub code: [u8; 8],                // 8  — shareable code
+    pub referrer: Pubkey,             // 32 — wallet that receives bonuses
+    pub referrals: u32,               // 4  — farmers registered with this code
+    pub bonuses_paid: u64,            // 8  — lifetime $GREEN paid out
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PersonhoodRecord {
+    pub nullifier: [u8; 32],          // 32 — provider's unique-human identifier
+    pub farmer: Pubkey,               // 32 — the one profile this human operates
+    pub verifier: Pubkey,             // 32 — verifier that vouched for it
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct IdentityAttestation {
+    pub id_hash: [u8; 32],            // 32 — salted hash of the government ID
+    pub farmer: Pubkey,               // 32 — farmer the ID is bound to
+    pub attester: Pubkey,             // 32 — KYC partner that attested
+    pub attested_at: i64,             // 8  — attestation timestamp
+    pub revoked: bool,                // 1  — set when the attester withdraws it
+    pub bump: u8,                     // 1
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PmKisanAttestation {
+    pub id_hash: [u8; 32],            // 32 — ID hash of the beneficiary
+    pub farmer: Pubkey,               // 32 — farmer the ID is bound to
+    pub attester: Pubkey,             // 32 — government-integration attester
+    pub attested_at: i64,             // 8  — attestation timestamp
+    pub revoked: bool,                // 1  — set when the attester withdraws it
+    pub bump: u8,                     // 1
+}
+
+// ─────────────────────────────────────────────────────────────
+// Error Codes
+// ─────────────────────────────────────────────────────────────
+
+#[error_code]
+pub enum KisanError {
+    #[msg("Invalid ZK-SNARK proof: proof components must be non-zero")]
+    InvalidProof,
+
+    #[msg("Invalid public signals: signals array must not be empty")]
+    InvalidPublicSignals,
+
+    #[msg("Invalid commitment: commitment hash must be non-zero")]
+    InvalidCommitment,
+
+    #[msg("Proof already used: this compliance commitment has been verified before")]
+    ProofAlreadyUsed,
+
+    #[msg("Unauthorized claimant: signer is neither the farmer nor a delegate")]
+    UnauthorizedClaimant,
+
+    #[msg("Invalid delegate: a farmer cannot delegate to themselves")]
+    InvalidDelegate,
+
+    #[msg("Delegation expired: the power-of-attorney is no longer valid")]
+    DelegationExpired,
+
+    #[msg("Delegation exhausted: the delegate has used all permitted claims")]
+    DelegationExhausted,
+
+    #[msg("Session expired: the session key is no longer valid")]
+    SessionExpired,
+
+    #[msg("Session too long: session keys may last at most 24 hours")]
+    SessionTooLong,
+
+    #[msg("Session scope violation: the key is not authorized for this instruction")]
+    SessionScopeViolation,
+
+    #[msg("Unauthorized relayer: fee payer is not on the relayer allowlist")]
+    UnauthorizedRelayer,
+
+    #[msg("Relayer rate limited: the relayer's quota for the current window is used up")]
+    RelayerRateLimited,
+
+    #[msg("Invalid rate limit: claims per window and window length must be positive")]
+    InvalidRateLimit,
+
+    #[msg("Invalid amount: amount must be greater than zero")]
+    InvalidAmount,
+
+    #[msg("Rent pool depleted: not enough lamports to sponsor this claim")]
+    RentPoolDepleted,
+
+    #[msg("Invalid rent recipient: account does not match the record's rent payer")]
+    InvalidRentRecipient,
+
+    #[msg("Proof record retained: record is still within its retention period")]
+    ProofRecordRetained,
+
+    #[msg("Invalid identity hash: identity hash must be non-zero")]
+    InvalidIdentityHash,
+
+    #[msg("Empty escrow: there is no escrowed balance to claim")]
+    EmptyEscrow,
+
+    #[msg("Identity attestation required: this region requires a verified government ID")]
+    IdentityAttestationRequired,
+
+    #[msg("Identity attestation revoked: the farmer's ID attestation is no longer valid")]
+    IdentityAttestationRevoked,
+
+    #[msg("Identity already attested: the farmer profile already has an attestation")]
+    IdentityAlreadyAttested,
+
+    #[msg("Proof of personhood required: registration needs a verifier co-signature and nullifier")]
+    PersonhoodRequired,
+
+    #[msg("Invalid personhood verifier: signer is not the configured verifier")]
+    InvalidPersonhoodVerifier,
+
+    #[msg("Invalid referral code: code must be non-zero")]
+    InvalidReferralCode,
+
+    #[msg("Self-referral: a farmer cannot register with their own referral code")]
+    SelfReferral,
+
+    #[msg("Referral accounts required: pass the referral code and referrer token account")]
+    ReferralAccountsRequired,
+
+    #[msg("Badge URI too long: prefix exceeds 64 bytes")]
+    BadgeUriTooLong,
+
+    #[msg("Invalid milestone: no badge exists at this index")]
+    InvalidMilestone,
+
+    #[msg("Milestone not reached: farmer has too few verified proofs for this badge")]
+    MilestoneNotReached,
+
+    #[msg("Badge already claimed: this milestone badge was already minted")]
+    BadgeAlreadyClaimed,
+
+    #[msg("Invalid leaderboard period: period must be positive")]
+    InvalidLeaderboardPeriod,
+
+    #[msg("Leaderboard period active: the current epoch has not ended yet")]
+    LeaderboardPeriodActive,
+
+    #[msg("Invalid season config: duration, reward, cap and per-farmer limit must be positive")]
+    InvalidSeasonConfig,
+
+    #[msg("Season closed: claims are only accepted while the current season is open")]
+    SeasonClosed,
+
+    #[msg("Season active: the current season has not ended yet")]
+    SeasonActive,
+
+    #[msg("Season cap reached: this season's mint budget is exhausted")]
+    SeasonCapReached,
+
+    #[msg("Farmer season cap reached: no more claims allowed for this farmer this season")]
+    FarmerSeasonCapReached,
+
+    #[msg("Invalid streak curve: bonuses may not exceed 10000 bps")]
+    InvalidStreakCurve,
+
+    #[msg("Invalid mint kind: mint already exists or has not been initialized")]
+    InvalidMintKind,
+
+    #[msg("Circuit inactive: claims against this circuit are not accepted")]
+    CircuitInactive,
+
+    #[msg("Reward accounts required: pass the companion mint and farmer token account")]
+    RewardAccountsRequired,
+
+    #[msg("Invalid pricing config: target and staleness must be positive, confidence ≤ 10000 bps")]
+    InvalidPricingConfig,
+
+    #[msg("Pricing accounts required: pass pricing_config and price_feed while dynamic pricing is on")]
+    PricingAccountsRequired,
+
+    #[msg("Invalid price feed: wrong owner, layout, feed id or unverified update")]
+    InvalidPriceFeed,
+
+    #[msg("Invalid weather thresholds: minimum rainfall must be below the maximum")]
+    InvalidWeatherThresholds,
+
+    #[msg("Weather report final: the season's closing report can't be updated")]
+    WeatherReportFinal,
+
+    #[msg("Weather report pending: the season's final report hasn't been published")]
+    WeatherReportPending,
+
+    #[msg("Invalid payout multiple: payouts must exceed the premium (> 10000 bps)")]
+    InvalidPayoutMultiple,
+
+    #[msg("Cover season started: cover can only be bought for upcoming seasons")]
+    CoverSeasonStarted,
+
+    #[msg("Insurance pool depleted: not enough SOL to back this cover")]
+    InsurancePoolDepleted,
+
+    #[msg("Cover not triggered: reported weather is within the region's thresholds")]
+    CoverNotTriggered,
+
+    #[msg("Cover triggered: the farmer can claim this payout")]
+    CoverTriggered,
+
+    #[msg("Invalid loan config: price must be positive and LTVs ≤ 10000 bps")]
+    InvalidLoanConfig,
+
+    #[msg("Invalid loan terms: duration must be positive")]
+    InvalidLoanTerms,
+
+    #[msg("Loan exceeds LTV: principal is above the collateral's borrowing limit")]
+    LoanExceedsLtv,
+
+    #[msg("Invalid loan status for this action")]
+    InvalidLoanStatus,
+
+    #[msg("Loan not in default: the due date plus grace period hasn't passed")]
+    LoanNotInDefault,
+
+    #[msg("Swap route not whitelisted: buybacks must go through an approved DEX")]
+    SwapRouteNotWhitelisted,
+
+    #[msg("Slippage exceeded: the swap spent too much or returned too little")]
+    SlippageExceeded,
+
+    #[msg("Invalid curve config: base price and supply must be positive and cost must fit in a u64")]
+    InvalidCurveConfig,
+
+    #[msg("Curve closed: liquidity has migrated to a DEX")]
+    CurveClosed,
+
+    #[msg("Curve sold out: amount exceeds the curve's remaining supply")]
+    CurveSoldOut,
+
+    #[msg("Invalid subscription: rate, share and epoch length must be positive")]
+    InvalidSubscription,
+
+    #[msg("Offtake oversubscribed: subscription shares would exceed 10000 bps")]
+    OfftakeOversubscribed,
+
+    #[msg("Subscription not due: the current epoch hasn't elapsed")]
+    SubscriptionNotDue,
+
+    #[msg("Subscription underfunded: top up the escrow to cover every elapsed epoch")]
+    SubscriptionUnderfunded,
+
+    #[msg("Serial not in record: the serial was issued by a different proof record")]
+    SerialNotInRecord,
+
+    #[msg("Evidence window closed: evidence can only be updated within 24 hours of verification")]
+    EvidenceWindowClosed,
+
+    #[msg("Invalid claim status: the claim is not in the state this step requires")]
+    InvalidClaimStatus,
+
+    #[msg("Unauthorized attester: signer is not a whitelisted attester")]
+    UnauthorizedAttester,
+
+    #[msg("Cross-chain disabled: Wormhole attestations are off for this deployment")]
+    CrossChainDisabled,
+
+    #[msg("Invalid VAA: not a posted VAA for this hash, or malformed claim payload")]
+    InvalidVaa,
+
+    #[msg("Unknown emitter: the VAA was not sent by the chain's whitelisted emitter")]
+    UnknownEmitter,
+
+    #[msg("Invalid EVM signature: no matching Secp256k1 precompile check by a whitelisted oracle")]
+    InvalidEvmSignature,
+
+    #[msg("Invalid EVM report: malformed, or not about this claim")]
+    InvalidEvmReport,
+
+    #[msg("EVM report expired: the oracle's report is past its expiry")]
+    EvmReportExpired,
+
+    #[msg("Invalid imagery config: windows must be non-negative and the age limit positive")]
+    InvalidImageryConfig,
+
+    #[msg("Imagery threshold not met: NDVI delta too small, or window too short or stale")]
+    ImageryThresholdNotMet,
+
+    #[msg("Invalid drone config: minimum stake and unbonding period must be positive")]
+    InvalidDroneConfig,
+
+    #[msg("Operator stake too low: bond at least the minimum stake to attest")]
+    OperatorStakeTooLow,
+
+    #[msg("Operator unbonding: the operator has begun withdrawing its stake")]
+    OperatorUnbonding,
+
+    #[msg("Unbonding incomplete: the unbonding period hasn't passed")]
+    UnbondingIncomplete,
+
+    #[msg("Invalid Ed25519 signature: no matching precompile check by the attester")]
+    InvalidEd25519Signature,
+
+    #[msg("Land record required: parcels in this region must link an anchored land record")]
+    LandRecordRequired,
+
+    #[msg("Land record linked: the record already backs another parcel")]
+    LandRecordLinked,
+
+    #[msg("Invalid PM-KISAN attester: signer is not the configured attester")]
+    InvalidPmKisanAttester,
+
+    #[msg("PM-KISAN attestation required: claims need a registered-beneficiary attestation")]
+    PmKisanAttestationRequired,
+
+    #[msg("PM-KISAN attestation revoked: the farmer's beneficiary attestation is no longer valid")]
+    PmKisanAttestationRevoked,
+
+    #[msg("Invalid verifying key: domain size, public input count or constants out of range")]
+    InvalidVerifyingKey,
+
+    #[msg("Verifying key required: pass the circuit's verifying key account")]
+    VerifyingKeyRequired,
+
+    #[msg("Invalid verifier program: pass the circuit's executable external verifier")]
+    InvalidVerifierProgram,
+
+    #[msg("Proof system unsupported: this instruction needs a PLONK or external-verifier circuit")]
+    ProofSystemUnsupported,
+
+    #[msg("Invalid Merkle proof: the leaf is not in the aggregate batch")]
+    InvalidMerkleProof,
+
+    #[msg("Profile not dormant: only profiles without a claim for a year can be compressed")]
+    ProfileNotDormant,
+
+    #[msg("Invalid compressed record: it does not belong to the signer")]
+    InvalidCompressedRecord,
+
+    #[msg("Receipt accounts required: pass the receipt tree accounts while receipts are on")]
+    ReceiptAccountsRequired,
+
+    #[msg("Invalid lookup table: wrong address, deactivated, or too many addresses")]
+    InvalidLookupTable,
+
+    #[msg("Unknown account type: only ProgramState, FarmerProfile and ProofRecord migrate")]
+    UnknownAccountType,
+
+    #[msg("Account already migrated: it is at the current layout version")]
+    AccountAlreadyMigrated,
+
+    #[msg("Feature disabled: this code path is not enabled on this cluster yet")]
+    FeatureDisabled,
+
+    #[msg("Unknown feature: pass exactly one known feature bit")]
+    UnknownFeature,
+
+    #[msg("Unauthorized authority: signer is not the program authority")]
+    UnauthorizedAuthority,
+
+    // Superseded by the `Oracle*` errors; kept so later codes don't shift
+    #[msg("Stale oracle price: the feed is too old or too uncertain and no fallback is set")]
+    StaleOraclePrice,
+
+    #[msg("Budget exhausted: the pool funding this payout is empty")]
+    BudgetExhausted,
+
+    #[msg("Program paused: this instruction is disabled by the authority")]
+    ProgramPaused,
+
+    #[msg("Invalid point encoding: coordinates out of range or not on the curve")]
+    InvalidPointEncoding,
+
+    #[msg("Point not in subgroup: the proof point is outside the prime-order subgroup")]
+    PointNotInSubgroup,
+
+    #[msg("Commitment mismatch: the commitment does not open to the claim's farmer, circuit and season")]
+    CommitmentMismatch,
+
+    #[msg("Unsupported commitment scheme: unknown scheme version byte")]
+    UnsupportedCommitmentScheme,
+
+    #[msg("Invalid Poseidon input: too many inputs or an input not below the field modulus")]
+    InvalidPoseidonInput,
+
+    #[msg("Protected account: reset_state only closes writable accounts owned by the program, other than its state and admin log")]
+    ProtectedAccount,
+
+    #[msg("Airdrop too large: the amount must be non-zero and within the devnet airdrop limit")]
+    AirdropTooLarge,
+
+    #[msg("Already initialized: the program state and $GREEN mint already exist")]
+    AlreadyInitialized,
+
+    #[msg("Unauthorized deployer: initialize must be signed by the program's upgrade authority")]
+    UnauthorizedDeployer,
+
+    #[msg("CPI not allowed: verify_and_mint must be top-level or called from a registered integrator")]
+    CpiNotAllowed,
+
+    #[msg("Missing reason hash: freezes and thaws must reference an off-chain case record")]
+    MissingReasonHash,
+
+    #[msg("Invalid payout config: the dispute window must be positive")]
+    InvalidPayoutConfig,
+
+    #[msg("Invalid payout reference: the reference hash must be non-zero")]
+    InvalidPayoutReference,
+
+    #[msg("Invalid voucher status: the voucher is not in the state this step requires")]
+    InvalidVoucherStatus,
+
+    #[msg("Dispute window closed: paid vouchers can only be disputed within the window")]
+    DisputeWindowClosed,
+
+    #[msg("Dispute window open: the payout partner still has time to pay this voucher")]
+    DisputeWindowOpen,
+
+    #[msg("Unauthorized payout partner: signer is not the configured payout partner")]
+    UnauthorizedPayoutPartner,
+
+    #[msg("Invalid price floor: oracle floors need a share of at most 100% and a staleness bound")]
+    InvalidPriceFloor,
+
+    #[msg("Price below floor: the listing price is under the methodology's floor")]
+    PriceBelowFloor,
+
+    #[msg("Listing exceeds issuance: amount is more than the issuance or the listing holds")]
+    ListingExceedsIssuance,
+
+    #[msg("Royalty too high: resale royalties are capped at MAX_ROYALTY_BPS")]
+    RoyaltyTooHigh,
+
+    #[msg("No royalties to claim: nothing has accrued since the last claim")]
+    NoRoyaltiesToClaim,
+
+    #[msg("Oracle data stale: the reading is older than the feed's maximum age")]
+    OracleDataStale,
+
+    #[msg("Oracle confidence too wide: the reading's uncertainty exceeds the feed's limit")]
+    OracleConfidenceTooWide,
+
+    #[msg("Oracle value out of bounds: the reading is outside the feed's plausible range")]
+    OracleValueOutOfBounds,
+
+    #[msg("Wallet compromised: the farmer's profile must be migrated to a new wallet")]
+    WalletCompromised,
+
+    #[msg("Wallet not compromised: an attester must flag it before its profile can move")]
+    WalletNotCompromised,
+
+    #[msg("Profile already migrated: this compromised profile has moved to a new wallet")]
+    ProfileAlreadyMigrated,
+
+    #[msg("Royalty account required: pass the new wallet's royalty account with the old")]
+    RoyaltyAccountRequired,
+
+    #[msg("Incentives budget exceeded: the distribution would pass the configured cap")]
+    IncentivesBudgetExceeded,
+
+    #[msg("Distribution mismatch: pass one $GREEN token account per recipient, in order")]
+    DistributionMismatch,
+
+    #[msg("Onboarding accounts required: pass the $GREEN mint, onboarding vault and token program")]
+    OnboardingAccountsRequired,
+
+    #[msg("Invalid onboarding bonus: a nonzero bonus needs a positive claim window")]
+    InvalidOnboardingBonus,
+
+    #[msg("No onboarding bonus: the farmer has no escrowed bonus")]
+    NoOnboardingBonus,
+
+    #[msg("Onboarding bonus not expired: the farmer can still claim it")]
+    OnboardingBonusNotExpired,
+
+    #[msg("Invalid guardian: the treasury needs a guardian key")]
+    InvalidGuardian,
+
+    #[msg("Invalid timelock: large withdrawals wait at least MIN_TREASURY_TIMELOCK")]
+    InvalidTimelock,
+
+    #[msg("Market fee too high: protocol fees are capped at MAX_MARKET_FEE_BPS")]
+    MarketFeeTooHigh,
+
+    #[msg("Guardian signature required: the treasury guardian must co-sign")]
+    GuardianSignatureRequired,
+
+    #[msg("Treasury daily limit exceeded: have the guardian co-sign, or propose a timelocked withdrawal")]
+    TreasuryDailyLimitExceeded,
+
+    #[msg("Withdrawal timelocked: the proposal's timelock hasn't passed")]
+    WithdrawalTimelocked,
+
+    #[msg("Invalid VK overlap: the overlap window is capped at MAX_VK_OVERLAP_SLOTS")]
+    InvalidVkOverlap,
+
+    #[msg("Verifying key retired: the previous key's overlap window has ended")]
+    VerifyingKeyRetired,
+
+    #[msg("Pre-commit mismatch: the pre-commit wasn't made for this commitment")]
+    PreCommitMismatch,
+
+    #[msg("Invalid counterparty: the deal isn't offered to this wallet")]
+    InvalidCounterparty,
+
+    #[msg("OTC deal expired: its expiry has passed")]
+    OtcExpired,
+
+    #[msg("OTC deal not expired: only the seller can cancel before expiry")]
+    OtcNotExpired,
+
+    #[msg("sGREEN rate too high: the staking yield exceeds the maximum")]
+    SgreenRateTooHigh,
+
+    #[msg("Invalid governance config: voting period and quorum must be positive")]
+    InvalidGovernanceConfig,
+
+    #[msg("Invalid proposal action: the payload or action kind is malformed")]
+    InvalidProposalAction,
+
+    #[msg("Proposal accounts required: pass the accounts the action changes")]
+    ProposalAccountsRequired,
+
+    #[msg("Proposal threshold not met: deposit more $GREEN to propose")]
+    ProposalThresholdNotMet,
+
+    #[msg("Votes locked: relinquish outstanding votes before changing the deposit")]
+    VotesLocked,
+
+    #[msg("Voter weight changed: the deposit changed after the proposal's snapshot")]
+    VoterWeightChanged,
+
+    #[msg("No voting weight: the deposit carries no weight")]
+    NoVotingWeight,
+
+    #[msg("Voting closed: the proposal's voting period has ended")]
+    VotingClosed,
+
+    #[msg("Voting open: the proposal's voting period hasn't ended")]
+    VotingOpen,
+
+    #[msg("Proposal not passed: too few votes for, or quorum not met")]
+    ProposalNotPassed,
+
+    #[msg("Proposal already executed: a proposal's action runs once")]
+    ProposalAlreadyExecuted,
+
+    #[msg("Votes delegated: revoke the delegation first")]
+    VotesDelegated,
+
+    #[msg("Invalid vote delegate: delegations can't chain or point to oneself")]
+    InvalidVoteDelegate,
+
+    #[msg("Invalid emission curve: the curve's parameters don't decay the reward")]
+    InvalidEmissionCurve,
+
+    #[msg("Invalid allocation: cliff exceeds duration, or a funded allocation lacks a beneficiary")]
+    InvalidAllocation,
+
+    #[msg("Nothing vested: the allocation has nothing releasable yet")]
+    NothingVested,
+
+    #[msg("Invalid oracle scopes: scopes must be a non-empty set of ORACLE_SCOPE_* bits")]
+    InvalidOracleScopes,
+
+    #[msg("Oracle scope missing: the reporter is not registered for this feed")]
+    OracleScopeMissing,
+
+    #[msg("Invalid oracle quorum: each feed's quorum must be between 1 and 5 reporters")]
+    InvalidOracleQuorum,
+
+    #[msg("Oracle quorum not met: too few distinct registered reporters signed")]
+    OracleQuorumNotMet,
+
+    #[msg("Invalid co-signer accounts: pass each co-signing reporter's wallet then its entry")]
+    InvalidOracleCosigner,
+
+    #[msg("Unauthorized recovery key: only the designated recovery key can do this")]
+    UnauthorizedRecoveryKey,
+
+    #[msg("Recovery period too short: the inactivity period must be at least 30 days")]
+    RecoveryPeriodTooShort,
+
+    #[msg("Recovery in progress: a recovery countdown is already running")]
+    RecoveryInProgress,
+
+    #[msg("No recovery in progress: initiate_recovery first")]
+    RecoveryNotInProgress,
+
+    #[msg("Recovery countdown running: the inactivity period has not elapsed")]
+    RecoveryCountdownRunning,
+
+    #[msg("Authority active: an admin action was taken since recovery was initiated")]
+    AuthorityActive,
+
+    #[msg("Unsupported payload version: the proof payload's layout version is unknown")]
+    UnsupportedPayloadVersion,
+
+    #[msg("Malformed proof payload: a section has the wrong length for its format")]
+    MalformedProofPayload,
+
+    #[msg("Proof format mismatch: the payload's proof system is not the circuit's")]
+    ProofFormatMismatch,
+
+    #[msg("Relayer unbonding: the relayer has begun withdrawing its stake")]
+    RelayerUnbonding,
+
+    #[msg("Relayer stake outstanding: a staked relayer leaves by unbonding, not removal")]
+    RelayerStakeOutstanding,
+
+    #[msg("Relay deadline pending: the relayer still has time to land the claim")]
+    RelayDeadlinePending,
+
+    #[msg("Relay report expired: receipts are reportable for 30 days after the deadline")]
+    RelayReportExpired,
+
+    #[msg("Relay landed: the receipt's claim has a proof record")]
+    RelayLanded,
+
+    #[msg("Season budget available: claims are only queued once the season can't pay another reward")]
+    SeasonBudgetAvailable,
+
+    #[msg("Queued for next season: queued claims are processed from the season after they were queued")]
+    QueuedForNextSeason,
+
+    #[msg("Queue order: the entry is not the head of the queue or not for this claim")]
+    QueueOrder,
+
+    #[msg("Invalid custodian: the wallet seed prefix is longer than 32 bytes")]
+    InvalidCustodian,
+
+    #[msg("Invalid farmer token account: owned by neither the farmer nor their linked custodial wallet")]
+    InvalidFarmerTokenAccount,
+
+    #[msg("Invalid payout split: shares must sum to 10 000 bps with a non-zero landowner share")]
+    InvalidPayoutSplit,
+
+    #[msg("Landowner account required: the parcel splits its payout; pass the landowner's $GREEN account")]
+    LandownerAccountRequired,
+
+    #[msg("Node share too high: attester nodes can get at most 20% of an issuance")]
+    NodeShareTooHigh,
+
+    #[msg("Invalid attestation quorum: must be between 1 and 5 attesters")]
+    InvalidAttestationQuorum,
+
+    #[msg("Invalid attestation co-signer: pass distinct signing attesters with node rewards open, at most 5")]
+    InvalidAttestationCosigner,
+
+    #[msg("Attestation quorum not met: too few distinct whitelisted attesters signed")]
+    AttestationQuorumNotMet,
+
+    #[msg("Node reward accounts required: pass the claim's attestation, the node reward vault and each node's rewards account")]
+    NodeRewardAccountsRequired,
+
+    #[msg("No node rewards to claim: nothing has accrued since the last claim")]
+    NoNodeRewardsToClaim,
+
+    #[msg("Bounty too high: at most 25% of the clawed amount")]
+    BountyTooHigh,
+
+    #[msg("Missing evidence hash: fraud reports must reference off-chain evidence")]
+    MissingEvidenceHash,
+
+    #[msg("Claim ID mismatch: the proof record is for another claim, or predates claim IDs")]
+    ClaimIdMismatch,
+
+    #[msg("Report bond too low: bond at least the configured minimum")]
+    ReportBondTooLow,
+
+    #[msg("Report already resolved")]
+    ReportResolved,
+
+    #[msg("Clawback account required: upholding a report needs the farmer's frozen $GREEN account")]
+    ClawbackAccountRequired,
+
+    #[msg("Devnet only: the program was not built and initialized with the devnet feature")]
+    DevnetOnly,
+
+    #[msg("Farmer profile required: register first, unless unregistered claims are enabled")]
+    FarmerProfileRequired,
+
+    #[msg("Profile accounts required: pass the profile's region config and claim index page")]
+    ProfileAccountsRequired,
+
+    #[msg("Proof record's season is current or can reopen: closing it would allow the claim again")]
+    ProofRecordSeasonOpen,
+
+    #[msg("Escrow wallet mismatch: this escrow was already released to another wallet")]
+    EscrowWalletMismatch,
+
+    #[msg("Swap route accounts mismatch: the route must swap between the treasury vaults under the treasury authority")]
+    SwapRouteAccountsMismatch,
+
+    #[msg("Buyback minimum too low: min_green_out must be at least the oracle quote less the allowed slippage")]
+    BuybackMinimumTooLow,
+
+    #[msg("Buyback slippage too high: capped at MAX_BUYBACK_SLIPPAGE_BPS")]
+    BuybackSlippageTooHigh,
+
+    #[msg("No farmer credits issued since the last settlement: nothing to deliver")]
+    NoOfftakeIssuance,
+
+    #[msg("Offtake offer too small: it must hold every credit the settlement delivers")]
+    OfftakeOfferShort,
+
+    #[msg("No clawback owed: the report isn't upheld or its clawback was already recovered")]
+    NoClawbackOwed,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The versioned accounts as deployed at the baseline, before the
+    // version byte. A field inserted mid-struct since then shifts the bytes
+    // after it and fails these tests instead of corrupting deployed
+    // accounts. `FarmerProfile` and `Config` were first deployed with the
+    // version byte, so they have no v1 layout.
+
+    #[derive(AnchorSerialize)]
+    struct ProgramStateV1 {
+        authority: Pubkey,
+        mint: Pubkey,
+        total_proofs_verified: u64,
+        total_tokens_minted: u64,
+        bump: u8,
+        mint_bump: u8,
+    }
+
+    #[derive(AnchorSerialize)]
+    struct ProofRecordV1 {
+        commitment: [u8; 32],
+        farmer: Pubkey,
+        timestamp: i64,
+        verified: bool,
+    }
+
+    fn key(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    /// What `migrate` does to a v1 account of `v1_space` bytes: zero-extend
+    /// it to `T`'s size, read it as `T` and backfill it. Checks the
+    /// re-serialized account still starts with the v1 bytes.
+    fn migrate_v1<T: Versioned + Discriminator>(v1: &impl AnchorSerialize, v1_space: usize) -> T {
+        let mut data = T::DISCRIMINATOR.to_vec();
+        v1.serialize(&mut data).unwrap();
+        assert_eq!(data.len(), 8 + v1_space, "v1 layout size changed");
+        let v1_data = data.clone();
+        data.resize(8 + T::INIT_SPACE, 0);
+
+        let mut account = T::try_deserialize(&mut &data[..]).unwrap();
+        assert_eq!(account.version(), 0);
+        account.backfill(account.version());
+
+        let mut migrated = Vec::new();
+        account.try_serialize(&mut migrated).unwrap();
+        assert_eq!(migrated.len(), 8 + T::INIT_SPACE);
+        assert_eq!(&migrated[..v1_data.len()], &v1_data[..], "v1 fields moved");
+        account
+    }
+
+    #[test]
+    fn program_state_v1_migrates() {
+        let v1 = ProgramStateV1 {
+            authority: key(1),
+            mint: key(2),
+            total_proofs_verified: 1_234,
+            total_tokens_minted: 5_678_000,
+            bump: 254,
+            mint_bump: 253,
+        };
+        let state: ProgramState = migrate_v1(&v1, 82);
+        assert_eq!(state.authority, v1.authority);
+        assert_eq!(state.mint, v1.mint);
+        assert_eq!(state.total_proofs_verified, v1.total_proofs_verified);
+        assert_eq!(state.total_tokens_minted, v1.total_tokens_minted);
+        assert_eq!((state.bump, state.mint_bump), (v1.bump, v1.mint_bump));
+        assert_eq!(state.version, ACCOUNT_VERSION);
+        // Claims before v3 are numbered as if IDs had existed
+        assert_eq!(state.last_claim_id, v1.total_proofs_verified);
+        assert_eq!(state.current_season, 0);
+        assert!(!state.devnet);
+    }
+
+    #[test]
+    fn proof_record_v1_migrates() {
+        let v1 = ProofRecordV1 {
+            commitment: [1; 32],
+            farmer: key(2),
+            timestamp: 1_700_000_000,
+            verified: true,
+        };
+        let record: ProofRecord = migrate_v1(&v1, 73);
+        assert_eq!(record.commitment, v1.commitment);
+        assert_eq!(record.farmer, v1.farmer);
+        assert_eq!(record.timestamp, v1.timestamp);
+        assert!(record.verified);
+        assert_eq!(record.version, ACCOUNT_VERSION);
+        assert_eq!(record.claim_id, 0);
+        // The farmer paid for v1 records, and closing one checks its bump
+        assert_eq!(record.rent_payer, v1.farmer);
+        let (_, bump) = Pubkey::find_program_address(&[PROOF_SEED, &v1.commitment], &crate::ID);
+        assert_eq!(record.bump, bump);
+    }
+
+    fn zeroed<T: AnchorDeserialize + Space>() -> T {
+        T::deserialize(&mut &vec![0; T::INIT_SPACE][..]).unwrap()
+    }
+
+    #[test]
+    fn proof_record_closes_only_once_its_season_is_over() {
+        let mut record: ProofRecord = zeroed();
+        record.timestamp = 1_700_000_000;
+        record.season = 4;
+        let mut state: ProgramState = zeroed();
+        state.current_season = 5;
+        let retained = record.timestamp + PROOF_RETENTION_PERIOD;
+
+        assert_eq!(
+            record.ensure_closable(&state, retained - 1).unwrap_err(),
+            error!(KisanError::ProofRecordRetained)
+        );
+        assert!(record.ensure_closable(&state, retained).is_ok());
+
+        // The claim's own season could still take the commitment again
+        state.current_season = 4;
+        assert_eq!(
+            record.ensure_closable(&state, retained).unwrap_err(),
+            error!(KisanError::ProofRecordSeasonOpen)
+        );
+
+        // `reset_state` can bring every season back
+        state.current_season = 5;
+        state.devnet = true;
+        assert_eq!(
+            record.ensure_closable(&state, retained).unwrap_err(),
+            error!(KisanError::ProofRecordSeasonOpen)
+        );
+    }
+
+    #[test]
+    fn weather_triggers_cover() {
+        let mut region: RegionConfig = zeroed();
+        region.min_rainfall_mm = 300;
+        region.max_rainfall_mm = 1_200;
+        region.max_temperature_dc = 450;
+        let mut report: WeatherReport = zeroed();
+        report.rainfall_mm = 800;
+        report.max_temperature_dc = 400;
+        assert!(!region.weather_triggered(&report));
+
+        report.rainfall_mm = 1_201;
+        assert!(region.weather_triggered(&report));
+        report.rainfall_mm = 800;
+        report.max_temperature_dc = 451;
+        assert!(region.weather_triggered(&report));
+
+        // Drought is only known once the season is over
+        report.max_temperature_dc = 400;
+        report.rainfall_mm = 299;
+        assert!(!region.weather_triggered(&report));
+        report.is_final = true;
+        assert!(region.weather_triggered(&report));
+    }
+
+    #[test]
+    fn cover_needs_a_solvent_pool() {
+        let mut pool: InsurancePool = zeroed();
+        pool.payout_multiple_bps = 25_000;
+        pool.cover_outstanding = 10_000;
+        let floor = 1_000;
+
+        // The premium counts towards its own payout
+        let balance = floor + 10_000 + 2_500 - 1_000;
+        assert_eq!(pool.cover_payout(1_000, balance, floor).unwrap(), 2_500);
+        assert_eq!(
+            pool.cover_payout(1_000, balance - 1, floor).unwrap_err(),
+            error!(KisanError::InsurancePoolDepleted)
+        );
+
+        // A payout past u64 is refused rather than wrapped
+        pool.payout_multiple_bps = u32::MAX;
+        pool.cover_outstanding = 0;
+        assert_eq!(
+            pool.cover_payout(u64::MAX, u64::MAX, 0).unwrap_err(),
+            error!(KisanError::InsurancePoolDepleted)
+        );
+    }
+
+    #[test]
+    fn loan_ltv_grows_with_proofs() {
+        let mut config: LoanConfig = zeroed();
+        config.green_price = 2_000_000;
+        config.base_ltv_bps = 3_000;
+        config.ltv_per_proof_bps = 500;
+        config.max_ltv_bps = 6_000;
+        let collateral = 10 * GREEN_UNIT;
+
+        assert_eq!(config.max_principal(collateral, 0), 6_000_000);
+        assert_eq!(config.max_principal(collateral, 2), 8_000_000);
+        assert_eq!(config.max_principal(collateral, 100), 12_000_000);
+
+        // Saturates at the cap instead of overflowing
+        assert_eq!(config.max_principal(collateral, u64::MAX), 12_000_000);
+        config.green_price = u64::MAX;
+        assert_eq!(config.max_principal(u64::MAX, u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn loan_amount_due_rejects_overflow() {
+        let mut loan: Loan = zeroed();
+        loan.principal = 1_000_000;
+        loan.interest_bps = 1_250;
+        assert_eq!(loan.amount_due(), Some(1_125_000));
+
+        loan.principal = u64::MAX - 1;
+        loan.interest_bps = 1;
+        assert_eq!(loan.amount_due(), None);
+        loan.interest_bps = u16::MAX;
+        assert_eq!(loan.amount_due(), None);
+    }
+
+    #[test]
+    fn buyback_floor_follows_the_oracle() {
+        let mut config: TreasuryConfig = zeroed();
+        // $0.50 per $GREEN
+        let price = oracle::OraclePrice {
+            price: 50_000_000,
+            conf: 0,
+            exponent: -8,
+            publish_time: 0,
+        };
+        let usdc = 100_000_000; // 100 USDC
+
+        assert_eq!(
+            config.buyback_floor(&price, usdc, 6).unwrap(),
+            200 * GREEN_UNIT
+        );
+        config.buyback_slippage_bps = 100;
+        assert_eq!(
+            config.buyback_floor(&price, usdc, 6).unwrap(),
+            198 * GREEN_UNIT
+        );
+
+        let unpriced = oracle::OraclePrice { price: 0, ..price };
+        assert_eq!(
+            config.buyback_floor(&unpriced, usdc, 6).unwrap_err(),
+            error!(KisanError::InvalidPriceFeed)
+        );
+    }
+
+    #[test]
+    fn treasury_outflows_share_the_daily_limit() {
+        let mut config: TreasuryConfig = zeroed();
+        config.guardian = key(7);
+        config.daily_limit = 1_000;
+
+        let owner = Pubkey::default();
+        let (guardian_key, other_key) = (key(7), key(8));
+        let (mut lamports, mut other_lamports) = (0, 0);
+        let (mut data, mut other_data) = ([0u8; 0], [0u8; 0]);
+        let guardian_info = AccountInfo::new(
+            &guardian_key,
+            true,
+            false,
+            &mut lamports,
+            &mut data,
+            &owner,
+            false,
+            0,
+        );
+        let other_info = AccountInfo::new(
+            &other_key,
+            true,
+            false,
+            &mut other_lamports,
+            &mut other_data,
+            &owner,
+            false,
+            0,
+        );
+        let guardian = Signer::try_from(&guardian_info).unwrap();
+        let other = Signer::try_from(&other_info).unwrap();
+
+        assert_eq!(config.authorize_outflow(600, 0, None).unwrap(), 600);
+        let over = error!(KisanError::TreasuryDailyLimitExceeded);
+        assert_eq!(config.authorize_outflow(500, 10, None).unwrap_err(), over);
+        assert_eq!(
+            config.authorize_outflow(500, 10, Some(&other)).unwrap_err(),
+            over
+        );
+
+        // The guardian's signature lifts the limit, and the spend still counts
+        assert_eq!(
+            config.authorize_outflow(500, 10, Some(&guardian)).unwrap(),
+            1_100
+        );
+        assert_eq!(config.total_withdrawn, 1_100);
+        assert_eq!(config.authorize_outflow(1, 20, None).unwrap_err(), over);
+
+        // A new window starts from zero
+        assert_eq!(
+            config
+                .authorize_outflow(1_000, TREASURY_WINDOW, None)
+                .unwrap(),
+            1_000
+        );
+        assert_eq!(config.total_withdrawn, 2_100);
+    }
+    fn groth16_key(n_public: usize) -> groth16::VerifyingKey {
+        // Points at infinity pass `is_well_formed`; only the shape matters
+        groth16::VerifyingKey {
+            alpha_g1: [0; 64],
+            beta_g2: [0; 128],
+            gamma_g2: [0; 128],
+            delta_g2: [0; 128],
+            ic: vec![[0; 64]; n_public + 1],
+        }
+    }
+
+    #[test]
+    fn rotated_key_stays_for_the_overlap() {
+        let mut vk: Groth16VerifyingKey = zeroed();
+        vk.circuit_id = 3;
+        vk.key = groth16_key(1);
+        let mut previous: PreviousGroth16VerifyingKey = zeroed();
+
+        let valid_until = vk.rotate(&mut previous, groth16_key(2), 100, 50).unwrap();
+        assert_eq!(valid_until, 150);
+        assert_eq!(previous.valid_until_slot, 150);
+        assert_eq!(previous.circuit_id, 3);
+        assert_eq!(previous.key.n_public(), 1);
+        assert_eq!(vk.key.n_public(), 2);
+
+        assert_eq!(
+            vk.rotate(&mut previous, groth16_key(3), MAX_VK_OVERLAP_SLOTS + 1, 50)
+                .unwrap_err(),
+            error!(KisanError::InvalidVkOverlap)
+        );
+        assert_eq!(
+            vk.rotate(&mut previous, groth16_key(0), 100, 50).unwrap_err(),
+            error!(KisanError::InvalidVerifyingKey)
+        );
+        // Neither failure touched the keys
+        assert_eq!(vk.key.n_public(), 2);
+        assert_eq!(previous.key.n_public(), 1);
+    }
+
+    #[test]
+    fn verifying_key_proposals_fit_and_bound_the_overlap() {
+        let action = |overlap_slots| ProposalAction::SetVerifyingKey {
+            circuit_id: 1,
+            key: ProposedKey::Groth16(Box::new(groth16_key(groth16::MAX_PUBLIC_INPUTS))),
+            overlap_slots,
+        };
+        let largest = action(MAX_VK_OVERLAP_SLOTS);
+        assert_eq!(largest.try_to_vec().unwrap().len(), ProposalAction::INIT_SPACE);
+        assert!(largest.validate().is_ok());
+        assert_eq!(
+            action(MAX_VK_OVERLAP_SLOTS + 1).validate().unwrap_err(),
+            error!(KisanError::InvalidVkOverlap)
+        );
+    }
+
+    #[test]
+    fn subscriptions_pay_for_every_elapsed_epoch() {
+        let mut sub: Subscription = zeroed();
+        sub.rate_per_epoch = 100;
+        sub.epoch_seconds = 10;
+        sub.next_settlement_at = 1_000;
+        sub.share_bps = 2_500;
+        sub.issued_snapshot = 400;
+
+        assert_eq!(
+            sub.amount_due(999).unwrap_err(),
+            error!(KisanError::SubscriptionNotDue)
+        );
+        assert_eq!(sub.amount_due(1_000).unwrap(), (1, 100));
+        // Settling late charges for the epochs skipped too
+        assert_eq!(sub.amount_due(1_035).unwrap(), (4, 400));
+
+        // A quarter of the 400 credits farmers were issued since
+        assert_eq!(sub.credits_due(800), 100);
+        assert_eq!(sub.credits_due(400), 0);
+        // Serials restart after a devnet reset
+        assert_eq!(sub.credits_due(0), 0);
+    }
+
+    #[test]
+    fn liquidity_migrates_by_its_own_proposal_kind() {
+        let action = |destination| ProposalAction::MigrateLiquidity { destination };
+        assert_eq!(action(key(1)).kind() as usize, PROPOSAL_KINDS - 1);
+        assert!(action(key(1)).validate().is_ok());
+        assert_eq!(
+            action(Pubkey::default()).validate().unwrap_err(),
+            error!(KisanError::InvalidProposalAction)
+        );
+    }
+
+    #[test]
+    fn bounty_is_a_share_of_what_was_recovered() {
+        let config = BountyConfig {
+            share_bps: 1_000,
+            min_bond: 0,
+            pool: 50,
+            total_funded: 50,
+            total_paid: 0,
+            bump: 0,
+        };
+        assert_eq!(config.bounty(0), 0);
+        assert_eq!(config.bounty(300), 30);
+        // The pool caps it
+        assert_eq!(config.bounty(1_000), 50);
+    }
 }
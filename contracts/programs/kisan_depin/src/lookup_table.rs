@@ -0,0 +1,128 @@
+// ============================================================
+// Address Lookup Tables — program-owned tables for relayers
+// ============================================================
+//
+// Thin wrappers over the native Address Lookup Table program.
+// Tables are owned by a program PDA so their contents are
+// controlled on-chain; relayers reference them in v0
+// transactions to fit more claims per transaction.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::address_lookup_table::instruction as alt;
+use anchor_lang::solana_program::program::invoke_signed;
+
+use crate::KisanError;
+
+pub use anchor_lang::solana_program::address_lookup_table::program::ID as LOOKUP_TABLE_PROGRAM_ID;
+
+/// Most addresses a lookup table can hold
+pub const MAX_ADDRESSES: usize = 256;
+
+/// Most addresses added per `extend` call, to stay within transaction size
+pub const MAX_EXTEND_ADDRESSES: usize = 20;
+
+pub struct LookupTableAccounts<'a, 'info> {
+    pub lookup_table: &'a AccountInfo<'info>,
+    pub authority: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub lookup_table_program: &'a AccountInfo<'info>,
+}
+
+/// CPI creating the table derived from `authority` and `recent_slot`;
+/// returns its address.
+pub fn create(
+    accounts: LookupTableAccounts,
+    recent_slot: u64,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<Pubkey> {
+    let (ix, address) =
+        alt::create_lookup_table_signed(accounts.authority.key(), accounts.payer.key(), recent_slot);
+    require_keys_eq!(
+        address,
+        accounts.lookup_table.key(),
+        KisanError::InvalidLookupTable
+    );
+    invoke_signed(
+        &ix,
+        &[
+            accounts.lookup_table.clone(),
+            accounts.authority.clone(),
+            accounts.payer.clone(),
+            accounts.system_program.clone(),
+            accounts.lookup_table_program.clone(),
+        ],
+        signer_seeds,
+    )?;
+    Ok(address)
+}
+
+/// CPI appending `addresses` to the table; the payer tops up its rent.
+pub fn extend(
+    accounts: LookupTableAccounts,
+    addresses: Vec<Pubkey>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = alt::extend_lookup_table(
+        accounts.lookup_table.key(),
+        accounts.authority.key(),
+        Some(accounts.payer.key()),
+        addresses,
+    );
+    invoke_signed(
+        &ix,
+        &[
+            accounts.lookup_table.clone(),
+            accounts.authority.clone(),
+            accounts.payer.clone(),
+            accounts.system_program.clone(),
+            accounts.lookup_table_program.clone(),
+        ],
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// CPI deactivating the table; it can be closed once the deactivation
+/// slot leaves the slot-hashes window (~513 slots).
+pub fn deactivate<'info>(
+    lookup_table: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    lookup_table_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = alt::deactivate_lookup_table(lookup_table.key(), authority.key());
+    invoke_signed(
+        &ix,
+        &[
+            lookup_table.clone(),
+            authority.clone(),
+            lookup_table_program.clone(),
+        ],
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// CPI closing a deactivated table, sending its rent to `recipient`.
+pub fn close<'info>(
+    lookup_table: &AccountInfo<'info>,
+    authority: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    lookup_table_program: &AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = alt::close_lookup_table(lookup_table.key(), authority.key(), recipient.key());
+    invoke_signed(
+        &ix,
+        &[
+            lookup_table.clone(),
+            authority.clone(),
+            recipient.clone(),
+            lookup_table_program.clone(),
+        ],
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
@@ -0,0 +1,48 @@
+// ============================================================
+// SPL Memo — claim descriptor on mints
+// ============================================================
+//
+// Mint paths optionally CPI into the Memo program with a compact
+// JSON descriptor of the claim, so explorers and exchanges that
+// surface memos can show what a $GREEN mint was for. The memo
+// instruction is just UTF-8 data with no accounts, so it's built
+// by hand rather than pulling in spl-memo.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction;
+use anchor_lang::solana_program::program::invoke;
+
+pub const MEMO_PROGRAM_ID: Pubkey = pubkey!("MemoSq4gqABAXKb96qnH8TysNcWxMyWCqXgDLGmfcHr");
+
+/// What a claim minted for, as written to the memo.
+pub struct ClaimMemo {
+    pub circuit_id: u16,
+    pub season: u32,
+    pub region_code: u16,
+}
+
+impl ClaimMemo {
+    /// `{"app":"kisan-depin","circuit":C,"season":S,"region":R}`
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"app\":\"kisan-depin\",\"circuit\":{},\"season\":{},\"region\":{}}}",
+            self.circuit_id, self.season, self.region_code
+        )
+    }
+}
+
+/// CPI into the Memo program with `memo`'s descriptor, if the caller
+/// passed the program. The memo is unsigned: it only annotates the
+/// transaction.
+pub fn attach(memo_program: Option<&AccountInfo>, memo: &ClaimMemo) -> Result<()> {
+    let Some(memo_program) = memo_program else {
+        return Ok(());
+    };
+    let ix = Instruction {
+        program_id: MEMO_PROGRAM_ID,
+        accounts: vec![],
+        data: memo.to_json().into_bytes(),
+    };
+    invoke(&ix, std::slice::from_ref(memo_program)).map_err(Into::into)
+}
@@ -0,0 +1,166 @@
+// ============================================================
+// Oracle adapter and feed sanity checks
+// ============================================================
+//
+// Reads a Pyth pull-oracle `PriceUpdateV2` account and converts
+// a target fiat value into a token amount. The account layout is
+// parsed by hand so we don't depend on pyth-solana-receiver-sdk;
+// it follows the receiver program's v2 layout.
+//
+// Every external reading (prices, weather reports, satellite
+// observations) goes through the same age, confidence and
+// plausibility checks below before the program acts on it, and
+// fails with the same typed errors whichever feed it came from.
+// ============================================================
+
+use std::fmt::Display;
+
+use anchor_lang::prelude::*;
+
+use crate::report;
+use crate::KisanError;
+
+/// Plausible season rainfall; India's wettest stations see ~12,000 mm a
+/// year.
+pub const RAINFALL_MM_BOUNDS: (u32, u32) = (0, 30_000);
+/// Plausible peak temperature, tenths of °C.
+pub const TEMPERATURE_DC_BOUNDS: (i16, i16) = (-600, 600);
+/// NDVI lies in [-1, 1], so an improvement is at most 2.0 (×10⁴).
+pub const NDVI_DELTA_BOUNDS: (u64, u64) = (0, 20_000);
+
+/// Fail with `OracleDataStale` if a reading taken at `observed_at` is more
+/// than `max_age` seconds old. A timestamp slightly ahead of the cluster
+/// clock counts as fresh: feeds keep their own time.
+pub fn check_age(observed_at: i64, now: i64, max_age: i64) -> Result<()> {
+    let age = now.saturating_sub(observed_at);
+    if age > max_age {
+        return Err(report::exceeded(KisanError::OracleDataStale, age, max_age));
+    }
+    Ok(())
+}
+
+/// Fail with `OracleConfidenceTooWide` if the ± `conf` interval is more
+/// than `max_conf_bps` of `value`.
+pub fn check_confidence(value: u64, conf: u64, max_conf_bps: u16) -> Result<()> {
+    if conf as u128 * 10_000 > value as u128 * max_conf_bps as u128 {
+        let limit = value as u128 * max_conf_bps as u128 / 10_000;
+        return Err(report::exceeded(KisanError::OracleConfidenceTooWide, conf, limit));
+    }
+    Ok(())
+}
+
+/// Fail with `OracleValueOutOfBounds` unless `min <= value <= max`.
+pub fn check_range<T: PartialOrd + Display>(value: T, (min, max): (T, T)) -> Result<()> {
+    if value < min || value > max {
+        return Err(report::exceeded(
+            KisanError::OracleValueOutOfBounds,
+            value,
+            format!("{min}..={max}"),
+        ));
+    }
+    Ok(())
+}
+
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey = pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+/// sha256("account:PriceUpdateV2")[..8]
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// `VerificationLevel::Full` — the update was checked against the full
+/// Wormhole guardian quorum.
+const VERIFICATION_LEVEL_FULL: u8 = 1;
+
+/// A price read from an oracle: `price * 10^exponent` quote units per
+/// base unit, ± `conf` at the same scale.
+pub struct OraclePrice {
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        let bytes = self
+            .data
+            .get(self.offset..self.offset + N)
+            .ok_or(KisanError::InvalidPriceFeed)?;
+        self.offset += N;
+        Ok(bytes.try_into().unwrap())
+    }
+}
+
+/// Load the price from a Pyth `PriceUpdateV2` account, checking the owner,
+/// account type, verification level and that it carries `feed_id`.
+pub fn load_pyth_price(info: &AccountInfo, feed_id: &[u8; 32]) -> Result<OraclePrice> {
+    require_keys_eq!(*info.owner, PYTH_RECEIVER_PROGRAM_ID, KisanError::InvalidPriceFeed);
+
+    let data = info.try_borrow_data()?;
+    let mut r = Reader { data: &data, offset: 0 };
+
+    require!(
+        r.take::<8>()? == PRICE_UPDATE_V2_DISCRIMINATOR,
+        KisanError::InvalidPriceFeed
+    );
+    r.take::<32>()?; // write_authority
+    let [level] = r.take::<1>()?;
+    require!(level == VERIFICATION_LEVEL_FULL, KisanError::InvalidPriceFeed);
+
+    require!(&r.take::<32>()? == feed_id, KisanError::InvalidPriceFeed);
+    let price = i64::from_le_bytes(r.take()?);
+    let conf = u64::from_le_bytes(r.take()?);
+    let exponent = i32::from_le_bytes(r.take()?);
+    let publish_time = i64::from_le_bytes(r.take()?);
+
+    Ok(OraclePrice { price, conf, exponent, publish_time })
+}
+
+impl OraclePrice {
+    /// Check the price is positive, no older than `max_staleness` seconds
+    /// and its confidence interval is within `max_conf_bps` of the price.
+    pub fn validate(&self, now: i64, max_staleness: i64, max_conf_bps: u16) -> Result<()> {
+        check_range(self.price, (1, i64::MAX))?;
+        check_age(self.publish_time, now, max_staleness)?;
+        check_confidence(self.price as u64, self.conf, max_conf_bps)
+    }
+
+    /// Token amount, in base units of a mint with `token_decimals`, worth
+    /// `target_value` quote units expressed with `target_decimals`.
+    /// Returns `None` on overflow or if the result doesn't fit in a u64.
+    pub fn amount_for_value(
+        &self,
+        target_value: u64,
+        target_decimals: u8,
+        token_decimals: u8,
+    ) -> Option<u64> {
+        // amount = target / 10^td / (price * 10^exp) * 10^decimals
+        let scale = token_decimals as i32 - target_decimals as i32 - self.exponent;
+        let price = u128::try_from(self.price).ok().filter(|p| *p > 0)?;
+        let amount = if scale >= 0 {
+            (target_value as u128).checked_mul(10u128.checked_pow(scale as u32)?)? / price
+        } else {
+            target_value as u128 / price.checked_mul(10u128.checked_pow(-scale as u32)?)?
+        };
+        u64::try_from(amount).ok()
+    }
+
+    /// Value of one whole base unit in quote units expressed with
+    /// `value_decimals`, rounded down. Returns `None` for a non-positive
+    /// price or on overflow.
+    pub fn unit_value(&self, value_decimals: u8) -> Option<u64> {
+        // value = price * 10^exp * 10^vd
+        let scale = value_decimals as i32 + self.exponent;
+        let price = u128::try_from(self.price).ok().filter(|p| *p > 0)?;
+        let value = if scale >= 0 {
+            price.checked_mul(10u128.checked_pow(scale as u32)?)?
+        } else {
+            price / 10u128.checked_pow(-scale as u32)?
+        };
+        u64::try_from(value).ok()
+    }
+}
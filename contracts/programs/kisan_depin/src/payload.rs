@@ -0,0 +1,158 @@
+// ============================================================
+// Proof payloads — one versioned, length-prefixed argument
+// ============================================================
+//
+// Every proof-verifying instruction takes a single `ProofPayload`
+// in place of separate proof points, proof bytes and public
+// signals. On the wire (Borsh) it is:
+//
+//   version: u8 | format: u8
+//     | proof_len: u32 LE | proof
+//     | signals_len: u32 LE | public_signals
+//
+// so the same bytes always decode to the same payload, and a new
+// proof system is a new `ProofFormat` variant rather than a new
+// instruction signature. `decode` is the only place sections are
+// interpreted; it checks the version and every section length
+// before anything is verified, which keeps it easy to fuzz.
+// ============================================================
+
+use anchor_lang::prelude::*;
+
+use crate::{report, KisanError, ProofPoints, ProofSystem};
+
+/// Payload layout this build reads and writes
+pub const PROOF_PAYLOAD_VERSION: u8 = 1;
+
+/// Public signals are 32-byte big-endian field elements
+pub const SIGNAL_LEN: usize = 32;
+
+/// Groth16 section lengths: a | b | c, G1 = 64 / 32 bytes, G2 = 128 / 64
+const GROTH16_UNCOMPRESSED_LEN: usize = 64 + 128 + 64;
+const GROTH16_COMPRESSED_LEN: usize = 32 + 64 + 32;
+
+/// How the proof section is encoded.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ProofFormat {
+    /// Groth16 a | b | c, uncompressed
+    Groth16Uncompressed,
+    /// Groth16 a | b | c, alt_bn128 compressed
+    Groth16Compressed,
+    /// A serialized PLONK proof, see `plonk.rs`
+    Plonk,
+    /// Opaque bytes for the circuit's external verifier program
+    External,
+}
+
+impl ProofFormat {
+    pub fn proof_system(self) -> ProofSystem {
+        match self {
+            ProofFormat::Groth16Uncompressed | ProofFormat::Groth16Compressed => {
+                ProofSystem::Groth16
+            }
+            ProofFormat::Plonk => ProofSystem::Plonk,
+            ProofFormat::External => ProofSystem::External,
+        }
+    }
+}
+
+/// A proof and its public inputs, as submitted.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProofPayload {
+    pub version: u8,
+    pub format: ProofFormat,
+    pub proof: Vec<u8>,
+    pub public_signals: Vec<u8>,
+}
+
+/// The proof section of a decoded payload.
+pub enum Proof<'a> {
+    Groth16(Box<ProofPoints>),
+    /// PLONK or external-verifier bytes, checked by the verifier
+    Serialized(&'a [u8]),
+}
+
+impl ProofPayload {
+    /// A Groth16 payload, in the points' own encoding.
+    pub fn groth16(points: &ProofPoints, public_signals: Vec<u8>) -> Self {
+        let (format, proof) = match points {
+            ProofPoints::Uncompressed { a, b, c } => {
+                (ProofFormat::Groth16Uncompressed, [&a[..], b, c].concat())
+            }
+            ProofPoints::Compressed { a, b, c } => {
+                (ProofFormat::Groth16Compressed, [&a[..], b, c].concat())
+            }
+        };
+        ProofPayload {
+            version: PROOF_PAYLOAD_VERSION,
+            format,
+            proof,
+            public_signals,
+        }
+    }
+
+    /// A PLONK or external-verifier payload.
+    pub fn serialized(format: ProofFormat, proof: Vec<u8>, public_signals: Vec<u8>) -> Self {
+        ProofPayload {
+            version: PROOF_PAYLOAD_VERSION,
+            format,
+            proof,
+            public_signals,
+        }
+    }
+
+    /// Check the version and section lengths, and that the payload is for
+    /// `proof_system`; the proof section, ready to verify.
+    pub fn decode(&self, proof_system: ProofSystem) -> Result<Proof<'_>> {
+        if self.version != PROOF_PAYLOAD_VERSION {
+            return Err(report::exceeded(
+                KisanError::UnsupportedPayloadVersion,
+                self.version,
+                PROOF_PAYLOAD_VERSION,
+            ));
+        }
+        require!(
+            self.format.proof_system() == proof_system,
+            KisanError::ProofFormatMismatch
+        );
+        require!(
+            !self.public_signals.is_empty() && self.public_signals.len().is_multiple_of(SIGNAL_LEN),
+            KisanError::MalformedProofPayload
+        );
+
+        match self.format {
+            ProofFormat::Groth16Uncompressed => {
+                let p = self.section(GROTH16_UNCOMPRESSED_LEN)?;
+                Ok(Proof::Groth16(Box::new(ProofPoints::Uncompressed {
+                    a: p[..64].try_into().unwrap(),
+                    b: p[64..192].try_into().unwrap(),
+                    c: p[192..].try_into().unwrap(),
+                })))
+            }
+            ProofFormat::Groth16Compressed => {
+                let p = self.section(GROTH16_COMPRESSED_LEN)?;
+                Ok(Proof::Groth16(Box::new(ProofPoints::Compressed {
+                    a: p[..32].try_into().unwrap(),
+                    b: p[32..96].try_into().unwrap(),
+                    c: p[96..].try_into().unwrap(),
+                })))
+            }
+            ProofFormat::Plonk | ProofFormat::External => {
+                require!(!self.proof.is_empty(), KisanError::MalformedProofPayload);
+                Ok(Proof::Serialized(&self.proof))
+            }
+        }
+    }
+
+    /// The proof section, which must be exactly `len` bytes.
+    fn section(&self, len: usize) -> Result<&[u8]> {
+        if self.proof.len() != len {
+            return Err(report::exceeded(
+                KisanError::MalformedProofPayload,
+                self.proof.len(),
+                len,
+            ));
+        }
+        Ok(&self.proof)
+    }
+}
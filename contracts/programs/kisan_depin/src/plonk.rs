@@ -0,0 +1,627 @@
+// ============================================================
+// PLONK verifier (BN254, snarkjs-compatible)
+// ============================================================
+//
+// Verifies proofs produced by `snarkjs plonk prove`, following
+// snarkjs's plonk_verify: Keccak256 Fiat–Shamir transcript,
+// linearisation, and a single two-pair pairing check. Curve
+// operations use Solana's alt_bn128 syscalls; scalar-field
+// arithmetic is done here in Montgomery form.
+//
+// Encodings are big-endian, as for the Ethereum precompiles:
+// G1 = x | y (64 bytes), G2 = x_c1 | x_c0 | y_c1 | y_c0 (128
+// bytes), field elements 32 bytes.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::alt_bn128::prelude::alt_bn128_pairing;
+use anchor_lang::solana_program::keccak;
+
+use crate::bn254::{
+    self, add_limbs, g1_add, g1_neg, geq, limbs_from_be, limbs_to_be, sub_limbs, G1_GENERATOR,
+    G2_GENERATOR, R,
+};
+use crate::KisanError;
+
+type G1 = [u8; 64];
+
+/// -r⁻¹ mod 2⁶⁴
+const R_INV: u64 = 0xc2e1f593efffffff;
+/// 2⁵¹² mod r, to enter Montgomery form
+const R2: [u64; 4] = [
+    0x1bb8e645ae216da7,
+    0x53fe3ab1e35c59e3,
+    0x8c49833d53bb8085,
+    0x0216d0b17f4e44a5,
+];
+/// r - 2, the inversion exponent
+const R_MINUS_2: [u64; 4] = [
+    0x43e1f593efffffff,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// Proof length: 9 G1 commitments and 6 evaluations
+pub const PROOF_LEN: usize = 9 * 64 + 6 * 32;
+
+/// A circuit's PLONK verification key, as exported by
+/// `snarkjs zkey export verificationkey`.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct VerifyingKey {
+    pub power: u8,                    // log2 of the domain size
+    pub n_public: u32,                // number of public inputs
+    pub k1: [u8; 32],
+    pub k2: [u8; 32],
+    pub w: [u8; 32],                  // generator of the 2^power domain
+    pub qm: [u8; 64],
+    pub ql: [u8; 64],
+    pub qr: [u8; 64],
+    pub qo: [u8; 64],
+    pub qc: [u8; 64],
+    pub s1: [u8; 64],
+    pub s2: [u8; 64],
+    pub s3: [u8; 64],
+    pub x_2: [u8; 128],
+}
+
+impl VerifyingKey {
+    /// Domain size and public input count within what `verify` handles.
+    pub fn is_well_formed(&self) -> bool {
+        self.power > 0 && self.power <= 28 && self.n_public > 0
+    }
+}
+
+// ─────────────────────────────────────────────────────────────
+// Scalar field
+// ─────────────────────────────────────────────────────────────
+
+/// Element of the scalar field, in Montgomery form.
+#[derive(Clone, Copy, PartialEq, Eq)]
+struct Fr([u64; 4]);
+
+impl Fr {
+    const ZERO: Fr = Fr([0; 4]);
+
+    fn one() -> Fr {
+        Fr::from_limbs([1, 0, 0, 0])
+    }
+
+    fn from_limbs(limbs: [u64; 4]) -> Fr {
+        Fr(limbs).mul(&Fr(R2))
+    }
+
+    fn from_u64(value: u64) -> Fr {
+        Fr::from_limbs([value, 0, 0, 0])
+    }
+
+    /// Parse a canonical (< r) big-endian field element.
+    fn from_be(bytes: &[u8; 32]) -> Option<Fr> {
+        let limbs = limbs_from_be(bytes);
+        (!geq(&limbs, &R)).then(|| Fr::from_limbs(limbs))
+    }
+
+    /// Reduce any 256-bit big-endian value (e.g. a hash) mod r.
+    fn from_be_reduced(bytes: &[u8; 32]) -> Fr {
+        let mut limbs = limbs_from_be(bytes);
+        while geq(&limbs, &R) {
+            limbs = sub_limbs(&limbs, &R).0;
+        }
+        Fr::from_limbs(limbs)
+    }
+
+    fn to_be(self) -> [u8; 32] {
+        limbs_to_be(&self.mul(&Fr([1, 0, 0, 0])).0)
+    }
+
+    fn add(&self, other: &Fr) -> Fr {
+        let (sum, carry) = add_limbs(&self.0, &other.0);
+        if carry || geq(&sum, &R) {
+            Fr(sub_limbs(&sum, &R).0)
+        } else {
+            Fr(sum)
+        }
+    }
+
+    fn sub(&self, other: &Fr) -> Fr {
+        let (diff, borrow) = sub_limbs(&self.0, &other.0);
+        if borrow {
+            Fr(add_limbs(&diff, &R).0)
+        } else {
+            Fr(diff)
+        }
+    }
+
+    fn neg(&self) -> Fr {
+        Fr::ZERO.sub(self)
+    }
+
+    /// Montgomery multiplication (CIOS).
+    fn mul(&self, other: &Fr) -> Fr {
+        let (a, b) = (&self.0, &other.0);
+        let mut t = [0u64; 6];
+        for &bi in b.iter() {
+            let mut carry = 0u128;
+            for j in 0..4 {
+                let s = t[j] as u128 + a[j] as u128 * bi as u128 + carry;
+                t[j] = s as u64;
+                carry = s >> 64;
+            }
+            let s = t[4] as u128 + carry;
+            t[4] = s as u64;
+            t[5] = (s >> 64) as u64;
+
+            let m = t[0].wrapping_mul(R_INV);
+            let s = t[0] as u128 + m as u128 * R[0] as u128;
+            let mut carry = s >> 64;
+            for j in 1..4 {
+                let s = t[j] as u128 + m as u128 * R[j] as u128 + carry;
+                t[j - 1] = s as u64;
+                carry = s >> 64;
+            }
+            let s = t[4] as u128 + carry;
+            t[3] = s as u64;
+            t[4] = t[5] + (s >> 64) as u64;
+        }
+        let out = [t[0], t[1], t[2], t[3]];
+        if t[4] != 0 || geq(&out, &R) {
+            Fr(sub_limbs(&out, &R).0)
+        } else {
+            Fr(out)
+        }
+    }
+
+    fn square(&self) -> Fr {
+        self.mul(self)
+    }
+
+    fn pow(&self, exponent: &[u64; 4]) -> Fr {
+        let mut result = Fr::one();
+        for limb in exponent.iter().rev() {
+            for bit in (0..64).rev() {
+                result = result.square();
+                if (limb >> bit) & 1 == 1 {
+                    result = result.mul(self);
+                }
+            }
+        }
+        result
+    }
+
+    fn inverse(&self) -> Option<Fr> {
+        (*self != Fr::ZERO).then(|| self.pow(&R_MINUS_2))
+    }
+}
+
+/// Invert every element with a single field inversion.
+fn batch_inverse(values: &[Fr]) -> Option<Vec<Fr>> {
+    let mut prefix = Vec::with_capacity(values.len());
+    let mut acc = Fr::one();
+    for v in values {
+        prefix.push(acc);
+        acc = acc.mul(v);
+    }
+    let mut inv = acc.inverse()?;
+    let mut out = vec![Fr::ZERO; values.len()];
+    for i in (0..values.len()).rev() {
+        out[i] = inv.mul(&prefix[i]);
+        inv = inv.mul(&values[i]);
+    }
+    Some(out)
+}
+
+// ─────────────────────────────────────────────────────────────
+// Curve operations
+// ─────────────────────────────────────────────────────────────
+
+fn g1_mul(p: &G1, s: &Fr) -> Result<G1> {
+    bn254::g1_mul(p, &s.to_be())
+}
+
+fn g1_sub(p: &G1, q: &G1) -> Result<G1> {
+    g1_add(p, &g1_neg(q))
+}
+
+// ─────────────────────────────────────────────────────────────
+// Verifier
+// ─────────────────────────────────────────────────────────────
+
+struct Proof {
+    a: G1,
+    b: G1,
+    c: G1,
+    z: G1,
+    t1: G1,
+    t2: G1,
+    t3: G1,
+    wxi: G1,
+    wxiw: G1,
+    eval_a: Fr,
+    eval_b: Fr,
+    eval_c: Fr,
+    eval_s1: Fr,
+    eval_s2: Fr,
+    eval_zw: Fr,
+}
+
+impl Proof {
+    /// Parse `A | B | C | Z | T1 | T2 | T3 | Wxi | Wxiw | eval_a | eval_b |
+    /// eval_c | eval_s1 | eval_s2 | eval_zw`, the order of snarkjs's
+    /// Solidity calldata.
+    fn parse(bytes: &[u8]) -> Result<Proof> {
+        require!(bytes.len() == PROOF_LEN, KisanError::InvalidProof);
+        let point = |i: usize| -> G1 { bytes[i * 64..(i + 1) * 64].try_into().unwrap() };
+        for i in 0..9 {
+            bn254::validate_g1(&point(i))?;
+        }
+        let scalar = |i: usize| -> Result<Fr> {
+            let start = 9 * 64 + i * 32;
+            Fr::from_be(bytes[start..start + 32].try_into().unwrap())
+                .ok_or(KisanError::InvalidProof.into())
+        };
+        Ok(Proof {
+            a: point(0),
+            b: point(1),
+            c: point(2),
+            z: point(3),
+            t1: point(4),
+            t2: point(5),
+            t3: point(6),
+            wxi: point(7),
+            wxiw: point(8),
+            eval_a: scalar(0)?,
+            eval_b: scalar(1)?,
+            eval_c: scalar(2)?,
+            eval_s1: scalar(3)?,
+            eval_s2: scalar(4)?,
+            eval_zw: scalar(5)?,
+        })
+    }
+}
+
+/// Keccak256 Fiat–Shamir transcript, as snarkjs's Keccak256Transcript.
+struct Transcript(Vec<u8>);
+
+impl Transcript {
+    fn new() -> Self {
+        Transcript(Vec::new())
+    }
+
+    fn point(mut self, p: &G1) -> Self {
+        self.0.extend_from_slice(p);
+        self
+    }
+
+    fn scalar(mut self, s: &Fr) -> Self {
+        self.0.extend_from_slice(&s.to_be());
+        self
+    }
+
+    fn challenge(self) -> Fr {
+        Fr::from_be_reduced(&keccak::hash(&self.0).to_bytes())
+    }
+}
+
+/// Verify a PLONK proof against `vk` and the circuit's public inputs,
+/// given as concatenated 32-byte big-endian field elements.
+pub fn verify(vk: &VerifyingKey, proof: &[u8], public_inputs: &[u8]) -> Result<()> {
+    let proof = Proof::parse(proof)?;
+    require!(
+        public_inputs.len() == vk.n_public as usize * 32,
+        KisanError::InvalidPublicSignals
+    );
+    let inputs = public_inputs
+        .chunks(32)
+        .map(|c| Fr::from_be(c.try_into().unwrap()))
+        .collect::<Option<Vec<_>>>()
+        .ok_or(KisanError::InvalidPublicSignals)?;
+    let k1 = Fr::from_be(&vk.k1).ok_or(KisanError::InvalidVerifyingKey)?;
+    let k2 = Fr::from_be(&vk.k2).ok_or(KisanError::InvalidVerifyingKey)?;
+    let w = Fr::from_be(&vk.w).ok_or(KisanError::InvalidVerifyingKey)?;
+
+    // ── Challenges ──
+    let mut transcript = Transcript::new();
+    for p in [&vk.qm, &vk.ql, &vk.qr, &vk.qo, &vk.qc, &vk.s1, &vk.s2, &vk.s3] {
+        transcript = transcript.point(p);
+    }
+    for input in &inputs {
+        transcript = transcript.scalar(input);
+    }
+    let beta = transcript.point(&proof.a).point(&proof.b).point(&proof.c).challenge();
+    let gamma = Transcript::new().scalar(&beta).challenge();
+    let alpha = Transcript::new()
+        .scalar(&beta)
+        .scalar(&gamma)
+        .point(&proof.z)
+        .challenge();
+    let xi = Transcript::new()
+        .scalar(&alpha)
+        .point(&proof.t1)
+        .point(&proof.t2)
+        .point(&proof.t3)
+        .challenge();
+    let v1 = Transcript::new()
+        .scalar(&xi)
+        .scalar(&proof.eval_a)
+        .scalar(&proof.eval_b)
+        .scalar(&proof.eval_c)
+        .scalar(&proof.eval_s1)
+        .scalar(&proof.eval_s2)
+        .scalar(&proof.eval_zw)
+        .challenge();
+    let v2 = v1.mul(&v1);
+    let v3 = v2.mul(&v1);
+    let v4 = v3.mul(&v1);
+    let v5 = v4.mul(&v1);
+    let u = Transcript::new().point(&proof.wxi).point(&proof.wxiw).challenge();
+
+    // ── Vanishing polynomial and Lagrange evaluations ──
+    let mut xin = xi;
+    for _ in 0..vk.power {
+        xin = xin.square();
+    }
+    let zh = xin.sub(&Fr::one());
+    let n = Fr::from_u64(1u64 << vk.power);
+
+    let count = inputs.len().max(1);
+    let mut roots = Vec::with_capacity(count);
+    let mut denominators = Vec::with_capacity(count);
+    let mut root = Fr::one();
+    for _ in 0..count {
+        roots.push(root);
+        denominators.push(n.mul(&xi.sub(&root)));
+        root = root.mul(&w);
+    }
+    let inverses = batch_inverse(&denominators).ok_or(KisanError::InvalidProof)?;
+    let lagrange: Vec<Fr> = roots
+        .iter()
+        .zip(&inverses)
+        .map(|(root, inv)| root.mul(&zh).mul(inv))
+        .collect();
+    let l1 = lagrange[0];
+
+    let pi = inputs
+        .iter()
+        .zip(&lagrange)
+        .fold(Fr::ZERO, |acc, (input, l)| acc.sub(&input.mul(l)));
+
+    // ── r0 ──
+    let alpha2 = alpha.square();
+    let e3a = proof.eval_a.add(&beta.mul(&proof.eval_s1)).add(&gamma);
+    let e3b = proof.eval_b.add(&beta.mul(&proof.eval_s2)).add(&gamma);
+    let e3c = proof.eval_c.add(&gamma);
+    let e3 = e3a.mul(&e3b).mul(&e3c).mul(&proof.eval_zw).mul(&alpha);
+    let r0 = pi.sub(&l1.mul(&alpha2)).sub(&e3);
+
+    // ── D, the linearisation commitment ──
+    let mut d1 = g1_mul(&vk.qm, &proof.eval_a.mul(&proof.eval_b))?;
+    d1 = g1_add(&d1, &g1_mul(&vk.ql, &proof.eval_a)?)?;
+    d1 = g1_add(&d1, &g1_mul(&vk.qr, &proof.eval_b)?)?;
+    d1 = g1_add(&d1, &g1_mul(&vk.qo, &proof.eval_c)?)?;
+    d1 = g1_add(&d1, &vk.qc)?;
+
+    let betaxi = beta.mul(&xi);
+    let d2a1 = proof.eval_a.add(&betaxi).add(&gamma);
+    let d2a2 = proof.eval_b.add(&betaxi.mul(&k1)).add(&gamma);
+    let d2a3 = proof.eval_c.add(&betaxi.mul(&k2)).add(&gamma);
+    let d2a = d2a1.mul(&d2a2).mul(&d2a3).mul(&alpha);
+    let d2b = l1.mul(&alpha2);
+    let d2 = g1_mul(&proof.z, &d2a.add(&d2b).add(&u))?;
+
+    let d3c = alpha.mul(&beta).mul(&proof.eval_zw);
+    let d3 = g1_mul(&vk.s3, &e3a.mul(&e3b).mul(&d3c))?;
+
+    let d4mid = g1_mul(&proof.t2, &xin)?;
+    let d4high = g1_mul(&proof.t3, &xin.square())?;
+    let d4 = g1_mul(&g1_add(&proof.t1, &g1_add(&d4mid, &d4high)?)?, &zh)?;
+
+    let d = g1_sub(&g1_sub(&g1_add(&d1, &d2)?, &d3)?, &d4)?;
+
+    // ── F and E ──
+    let mut f = g1_add(&d, &g1_mul(&proof.a, &v1)?)?;
+    f = g1_add(&f, &g1_mul(&proof.b, &v2)?)?;
+    f = g1_add(&f, &g1_mul(&proof.c, &v3)?)?;
+    f = g1_add(&f, &g1_mul(&vk.s1, &v4)?)?;
+    f = g1_add(&f, &g1_mul(&vk.s2, &v5)?)?;
+
+    let e_scalar = r0
+        .neg()
+        .add(&v1.mul(&proof.eval_a))
+        .add(&v2.mul(&proof.eval_b))
+        .add(&v3.mul(&proof.eval_c))
+        .add(&v4.mul(&proof.eval_s1))
+        .add(&v5.mul(&proof.eval_s2))
+        .add(&u.mul(&proof.eval_zw));
+    let e = g1_mul(&G1_GENERATOR, &e_scalar)?;
+
+    // ── Pairing: e(-A1, X_2) · e(B1, G2) == 1 ──
+    let a1 = g1_add(&proof.wxi, &g1_mul(&proof.wxiw, &u)?)?;
+    let mut b1 = g1_mul(&proof.wxi, &xi)?;
+    b1 = g1_add(&b1, &g1_mul(&proof.wxiw, &u.mul(&xi).mul(&w))?)?;
+    b1 = g1_sub(&g1_add(&b1, &f)?, &e)?;
+
+    let mut input = Vec::with_capacity(2 * 192);
+    input.extend_from_slice(&g1_neg(&a1));
+    input.extend_from_slice(&vk.x_2);
+    input.extend_from_slice(&b1);
+    input.extend_from_slice(&G2_GENERATOR);
+    let result = alt_bn128_pairing(&input).map_err(|_| KisanError::InvalidProof)?;
+    require!(result.last() == Some(&1), KisanError::InvalidProof);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A five-gate circuit on an 8-row domain with two public inputs
+    // (w0, and w1 = x·y). Proved outside this crate by a standalone
+    // port of snarkjs's PLONK prover (same transcript and proof layout),
+    // unblinded and from a fixed τ
+    const PROOF: [&str; 24] = [
+        "0948b9f1b90e66aa32dc4d47f222a076458e7c633e60e97b52ffbaf68bfbe436",
+        "103498c7feb3701fb27ce632e8e00c0efc13875e30cca59931af557e6bf0b68f",
+        "03c044ea0b7941db9ffc2f29316df328cc8280943515938d1d75bc897420ab45",
+        "2bf4f3861bb9cdd4b8588f5267d1255a549f518f4b0c76bbc0d56c785e82efaa",
+        "15b1920917d17cce53442b4807484dac23d3c64967de23e90af9d4c66fddcde5",
+        "23e7e6f5cef50d21a5ed77205cdc26a856a6baabb152d9ad2c2caf4a11ffa281",
+        "1663b42241d3368646a3a4118a92c197bca6c280f405bf4769a7e64a5e0f5d2a",
+        "122caf6aca2f7db0f64e496dd0464e82af93ecbabe8c026a2772dff74772b776",
+        "1e7c5e98fc0e7fbbe39deec44c8d2652b719f0b0a7ed9f65a5666a4ead56c190",
+        "1aee5be0a055a0f4bc9afbd390d1911e813e8cf0d7061e2e8fd21045704e1184",
+        "2e9eb4563971cddda061014a11afa3cf1208dbd20ccf5864c864c687f1a135ca",
+        "10c22d58cf93a8b8fec4dfa86c6d5555c064ca01f15cdb48b3aaa2e70f07422b",
+        "1eb5db535dbb79e485839c52f701850f92f7ffd789f63ab1cb10f5128c29a338",
+        "1ddc8d76309c82032b15b3478f29f866036307a2e4f71e2982e1a537de8cde4e",
+        "301ec985f0c3e2468bfb6c54cb1be546f76e73c8650b591e63ba624ed23c3027",
+        "095cec8d128afc5e6adb9ea5b5bcfa210bc19279c8ca5bf94193dca1453d2915",
+        "0a8dba1bc0081676bf2bde30a4e29862dfb835bb1c85c760b7720fe11b9da5b4",
+        "0cc1f64952a0545c2acdd4d53bcbde2a8fee254d13492549bfc846901bf882a5",
+        "2ca376237fedda739b2f782e1ed36499eb9bd8496f285fa8ac8f207f30314154",
+        "01ab6ffcb1d4e760f6361504a7c86ea02c22d34f06d2532f5c78754fb243082b",
+        "2bd5339b4b2270c059a1413bfb8919884337dc6287d505194c1bd23a51e34eca",
+        "03afe3d210c205717111da10907d978baef55ffe0270e571d7a0b4873ec9bdc5",
+        "2f7732252c5af34884ac4ed2c77114283b19d016568d1e708da897b1ffa37ca2",
+        "1bf9ccc43c83c10f18d972c165bf76ab2e64a6d9ceed4bc929526dcc878aa470",
+    ];
+
+    fn hex<const N: usize>(s: &str) -> [u8; N] {
+        let mut out = [0u8; N];
+        for (i, byte) in out.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&s[2 * i..2 * i + 2], 16).unwrap();
+        }
+        out
+    }
+
+    fn proof() -> Vec<u8> {
+        PROOF.iter().flat_map(|x| hex::<32>(x)).collect()
+    }
+
+    fn inputs() -> Vec<u8> {
+        [
+            "03f2928cec9575cfa81633b9bbf96cbc7e925d0954bfb0729ef7567de598c61a",
+            "0000000000000000000000000000000000000000000000000000000000000015",
+        ]
+        .iter()
+        .flat_map(|x| hex::<32>(x))
+        .collect()
+    }
+
+    fn key() -> VerifyingKey {
+        VerifyingKey {
+            power: 3,
+            n_public: 2,
+            k1: hex("0000000000000000000000000000000000000000000000000000000000000002"),
+            k2: hex("0000000000000000000000000000000000000000000000000000000000000003"),
+            w: hex("2b337de1c8c14f22ec9b9e2f96afef3652627366f8170a0a948dad4ac1bd5e80"),
+            qm: hex(concat!(
+                "08785c030deb643e38e30b436384ae28593e5da0a3df8724a0c0411efecc93c0",
+                "2f1ede4e7d09c2d017c82cfecd9792be4242617d3e7a78571eab8f5f272c4a90",
+            )),
+            ql: hex(concat!(
+                "241fa4b5d49b10fb0c8a34c647623eae9dec468ff26a43c37a8bba3aae95ad5c",
+                "22ee536b3441ac61333654e680f00f7a3b703f2dbd2b7a81be440457884430a2",
+            )),
+            qr: hex(concat!(
+                "06c629e761d57682da4b0adc13169394a61ee0643f436458cd17606b41b666b2",
+                "1b6c520371ea84e8f7fab286c5878fdd70c80c94512f5c73a856920363262055",
+            )),
+            qo: hex(concat!(
+                "2803a73d317fe4fbc5f388b5137efca275f20d01f64247965ba3041d8d931767",
+                "0cddb10a7fcd81de7beb8ba3a350618ec4023d0210d4c15e2ef6baa7555106ab",
+            )),
+            qc: hex(concat!(
+                "28a0856638500b3d086dd876392be554da3db675464a69515b8c5b6824f390a1",
+                "2b2f61a86b21e37cf78cf1f02d4e8392f8e1ba14416c4dcb3448e9ba859a8c8f",
+            )),
+            s1: hex(concat!(
+                "112c45ac35f40e152b817ae408467adb0600c2656a2cf978816ce7770bd19675",
+                "165a4fd6d4c2d1566b640db622f86787c15b1a9a5b26b4295a24701a88ad2715",
+            )),
+            s2: hex(concat!(
+                "149a85e2793b8d8cec160f172fcd01944509013345b199d8d9c8c8f70e84635d",
+                "1bce259ae1f5bf40a92af2ee174ca2ce0aab4f4a1410f349cb14b05a4a51ce51",
+            )),
+            s3: hex(concat!(
+                "13e26cb3717f68cad2a1d6955a34f584bd954988762e01f06ed4640e12ed0ca4",
+                "1ac51edc3ea8c86ff16a850bc000cd4f3f248ba45bd5ceee404dea579cb9489d",
+            )),
+            x_2: hex(concat!(
+                "0cfaa1bf894294b02f0b9f89fe0a681005d0520cd3ab72c7d92dc2bdcefb358d",
+                "14c5531b88bd45bac9f4766b2c8bd60f2c8e90415f5d8ce24c5c9a8041355699",
+                "14e11c6ed01343e135614500b734f3022f5e234971b8203883b81c10be21991e",
+                "223d058e88d1d409ee565dd6ac8d2200c93dbd775069e730c45c4249efaf2224",
+            )),
+        }
+    }
+
+    fn code(result: Result<()>) -> u32 {
+        match result {
+            Err(Error::AnchorError(e)) => e.error_code_number,
+            other => panic!("expected a program error, got {other:?}"),
+        }
+    }
+
+    fn expected(e: KisanError) -> u32 {
+        e.into()
+    }
+
+    #[test]
+    fn accepts_a_valid_proof() {
+        verify(&key(), &proof(), &inputs()).unwrap();
+    }
+
+    #[test]
+    fn rejects_a_flipped_proof_byte() {
+        // In eval_a: still a field element, so only the pairing catches it
+        let mut tampered = proof();
+        tampered[9 * 64 + 31] ^= 1;
+        assert_eq!(
+            code(verify(&key(), &tampered, &inputs())),
+            expected(KisanError::InvalidProof)
+        );
+        // In A's y coordinate: off the curve
+        let mut tampered = proof();
+        tampered[63] ^= 1;
+        assert_eq!(
+            code(verify(&key(), &tampered, &inputs())),
+            expected(KisanError::InvalidPointEncoding)
+        );
+    }
+
+    #[test]
+    fn rejects_a_wrong_public_input() {
+        let mut inputs = inputs();
+        inputs[63] ^= 1;
+        assert_eq!(
+            code(verify(&key(), &proof(), &inputs)),
+            expected(KisanError::InvalidProof)
+        );
+    }
+
+    #[test]
+    fn rejects_a_wrong_input_count() {
+        let inputs = inputs();
+        assert_eq!(
+            code(verify(&key(), &proof(), &inputs[32..])),
+            expected(KisanError::InvalidPublicSignals)
+        );
+    }
+
+    #[test]
+    fn rejects_the_wrong_key() {
+        let mut vk = key();
+        std::mem::swap(&mut vk.ql, &mut vk.qr);
+        assert_eq!(
+            code(verify(&vk, &proof(), &inputs())),
+            expected(KisanError::InvalidProof)
+        );
+        let mut vk = key();
+        vk.x_2 = G2_GENERATOR;
+        assert_eq!(
+            code(verify(&vk, &proof(), &inputs())),
+            expected(KisanError::InvalidProof)
+        );
+    }
+}
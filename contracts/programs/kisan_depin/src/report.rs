@@ -0,0 +1,44 @@
+// ============================================================
+// Error reporting — KisanError with the values behind it
+// ============================================================
+//
+// A custom error code alone ("0x1770") tells field support
+// nothing about why a claim failed. These helpers log the
+// offending values next to the error, so they show up in the
+// transaction logs and explorer output. Progress logs go the
+// other way: `verbose!` keeps them out of release builds.
+// ============================================================
+
+use anchor_lang::prelude::*;
+
+use crate::KisanError;
+
+/// `require!` that logs context before failing, e.g.
+///
+/// `require_ctx!(now < expiry, KisanError::SessionExpired, "expiry {} now {}", expiry, now)`
+macro_rules! require_ctx {
+    ($cond:expr, $err:expr, $($arg:tt)+) => {
+        if !($cond) {
+            anchor_lang::prelude::msg!($($arg)+);
+            return Err(anchor_lang::error!($err));
+        }
+    };
+}
+pub(crate) use require_ctx;
+
+/// `msg!` compiled in only with the `verbose-logs` feature, for progress
+/// logs on hot paths. Operational data goes in events instead.
+macro_rules! verbose {
+    ($($arg:tt)+) => {
+        if cfg!(feature = "verbose-logs") {
+            anchor_lang::prelude::msg!($($arg)+);
+        }
+    };
+}
+pub(crate) use verbose;
+
+/// `err` carrying the value found and the limit it broke; Anchor logs
+/// them as "Left" / "Right" along with the error code.
+pub fn exceeded(err: KisanError, found: impl ToString, limit: impl ToString) -> Error {
+    error!(err).with_values((found, limit))
+}
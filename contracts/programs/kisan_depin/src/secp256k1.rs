@@ -0,0 +1,76 @@
+// ============================================================
+// secp256k1 signature introspection
+// ============================================================
+//
+// Partners in the EVM ecosystem sign reports with an Ethereum
+// key. Recovering a secp256k1 key on-chain is too expensive, so
+// the client puts a Secp256k1 precompile instruction in the same
+// transaction and we read it back through the instructions
+// sysvar: if the transaction executed, the precompile verified
+// that `message` was signed by `eth_address`.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::secp256k1_program;
+use anchor_lang::solana_program::sysvar::instructions::load_instruction_at_checked;
+
+use crate::KisanError;
+
+const SIGNATURE_OFFSETS_START: usize = 1;
+/// signature_offset (2) | signature_instruction_index (1) |
+/// eth_address_offset (2) | eth_address_instruction_index (1) |
+/// message_data_offset (2) | message_data_size (2) |
+/// message_instruction_index (1)
+const SIGNATURE_OFFSETS_LEN: usize = 11;
+const ETH_ADDRESS_LEN: usize = 20;
+
+/// A message verified by the Secp256k1 precompile.
+pub struct EvmSignedMessage {
+    pub eth_address: [u8; ETH_ADDRESS_LEN],
+    pub message: Vec<u8>,
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Result<usize> {
+    let bytes = data
+        .get(offset..offset + 2)
+        .ok_or(KisanError::InvalidEvmSignature)?;
+    Ok(u16::from_le_bytes([bytes[0], bytes[1]]) as usize)
+}
+
+/// Read the single signature checked by the Secp256k1 precompile
+/// instruction at `ix_index`. The signature, address and message must all
+/// live in that instruction's own data, so a client can't point the
+/// offsets at data from an unrelated instruction.
+pub fn load_evm_signed_message(
+    instructions_sysvar: &AccountInfo,
+    ix_index: u8,
+) -> Result<EvmSignedMessage> {
+    let ix = load_instruction_at_checked(ix_index as usize, instructions_sysvar)?;
+    require_keys_eq!(ix.program_id, secp256k1_program::ID, KisanError::InvalidEvmSignature);
+
+    let data = &ix.data;
+    require!(data.first() == Some(&1), KisanError::InvalidEvmSignature);
+    let offsets = data
+        .get(SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_LEN)
+        .ok_or(KisanError::InvalidEvmSignature)?;
+    require!(
+        offsets[2] == ix_index && offsets[5] == ix_index && offsets[10] == ix_index,
+        KisanError::InvalidEvmSignature
+    );
+
+    let eth_address_offset = read_u16(offsets, 3)?;
+    let message_offset = read_u16(offsets, 6)?;
+    let message_size = read_u16(offsets, 8)?;
+
+    let eth_address = data
+        .get(eth_address_offset..eth_address_offset + ETH_ADDRESS_LEN)
+        .ok_or(KisanError::InvalidEvmSignature)?
+        .try_into()
+        .unwrap();
+    let message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(KisanError::InvalidEvmSignature)?
+        .to_vec();
+
+    Ok(EvmSignedMessage { eth_address, message })
+}
@@ -0,0 +1,150 @@
+// ============================================================
+// sGREEN — interest-bearing staked $GREEN
+// ============================================================
+//
+// Staked $GREEN sits in a program vault and the staker receives
+// sGREEN, a Token-2022 mint with the interest-bearing extension.
+// The extension only changes how wallets display a balance: the
+// UI amount of `a` raw sGREEN at time t is
+//
+//   a · exp(r̄ · (t − t₀) / year)
+//
+// with r̄ the time-weighted average rate in basis points. The
+// program mirrors the mint's rate history in `SgreenPool` and uses
+// the same formula as the exchange rate, so the displayed balance
+// is exactly the $GREEN a redemption pays. Rewards are minted into
+// the vault lazily, whenever staking or redeeming needs the vault
+// to back the full supply at the current rate.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use anchor_spl::token_2022::spl_token_2022::extension::ExtensionType;
+use anchor_spl::token_2022::spl_token_2022::state::Mint as MintState;
+use anchor_spl::token_2022::{self, InitializeMint2, Token2022};
+use anchor_spl::token_2022_extensions::interest_bearing_mint::{
+    interest_bearing_mint_initialize, interest_bearing_mint_update_rate,
+    InterestBearingMintInitialize, InterestBearingMintUpdateRate,
+};
+use kisan_common::seeds::{SGREEN_MINT_SEED, STATE_SEED};
+
+use crate::ProgramState;
+
+/// Token-2022's year for interest-bearing mints, in seconds.
+const SECONDS_PER_YEAR: f64 = 60.0 * 60.0 * 24.0 * 365.24;
+const ONE_IN_BASIS_POINTS: f64 = 10_000.0;
+
+/// $GREEN per raw sGREEN at `now`, the same scale Token-2022 applies to
+/// the mint's UI amount.
+pub fn scale(
+    initialized_at: i64,
+    last_update: i64,
+    pre_update_average_rate: i16,
+    current_rate: i16,
+    now: i64,
+) -> f64 {
+    let pre = pre_update_average_rate as f64 * (last_update - initialized_at) as f64;
+    let post = current_rate as f64 * (now - last_update) as f64;
+    ((pre + post) / SECONDS_PER_YEAR / ONE_IN_BASIS_POINTS).exp()
+}
+
+/// Time-weighted average of the rate so far, which Token-2022 stores as
+/// the pre-update average when the rate changes at `now`.
+pub fn average_rate(
+    initialized_at: i64,
+    last_update: i64,
+    pre_update_average_rate: i16,
+    current_rate: i16,
+    now: i64,
+) -> i16 {
+    let pre = last_update - initialized_at;
+    let post = now - last_update;
+    if pre + post == 0 {
+        return current_rate;
+    }
+    let weighted = pre_update_average_rate as i64 * pre + current_rate as i64 * post;
+    (weighted / (pre + post)) as i16
+}
+
+/// $GREEN paid for `sgreen` raw units, rounded down.
+pub fn to_green(sgreen: u64, scale: f64) -> u64 {
+    (sgreen as f64 * scale) as u64
+}
+
+/// Raw sGREEN minted for `green`, rounded down.
+pub fn to_sgreen(green: u64, scale: f64) -> u64 {
+    (green as f64 / scale) as u64
+}
+
+/// Create the sGREEN mint at its PDA with the interest-bearing extension,
+/// minted and rate-controlled by the program-state PDA.
+#[allow(clippy::too_many_arguments)]
+pub fn create_mint<'info>(
+    payer: &Signer<'info>,
+    mint: &AccountInfo<'info>,
+    mint_bump: u8,
+    program_state: &Account<'info, ProgramState>,
+    token_2022_program: &Program<'info, Token2022>,
+    system_program: &Program<'info, System>,
+    decimals: u8,
+    rate_bps: i16,
+) -> Result<()> {
+    let extensions = [ExtensionType::InterestBearingConfig];
+    let space = ExtensionType::try_calculate_account_len::<MintState>(&extensions)?;
+    system_program::create_account(
+        CpiContext::new_with_signer(
+            system_program.to_account_info(),
+            CreateAccount {
+                from: payer.to_account_info(),
+                to: mint.clone(),
+            },
+            &[&[SGREEN_MINT_SEED, &[mint_bump]]],
+        ),
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &token_2022::ID,
+    )?;
+
+    interest_bearing_mint_initialize(
+        CpiContext::new(
+            token_2022_program.to_account_info(),
+            InterestBearingMintInitialize {
+                token_program_id: token_2022_program.to_account_info(),
+                mint: mint.clone(),
+            },
+        ),
+        Some(program_state.key()),
+        rate_bps,
+    )?;
+
+    token_2022::initialize_mint2(
+        CpiContext::new(
+            token_2022_program.to_account_info(),
+            InitializeMint2 { mint: mint.clone() },
+        ),
+        decimals,
+        &program_state.key(),
+        None,
+    )
+}
+
+/// Set the mint's displayed rate, signed by the program-state PDA.
+pub fn update_rate<'info>(
+    mint: &AccountInfo<'info>,
+    program_state: &Account<'info, ProgramState>,
+    token_2022_program: &Program<'info, Token2022>,
+    rate_bps: i16,
+) -> Result<()> {
+    interest_bearing_mint_update_rate(
+        CpiContext::new_with_signer(
+            token_2022_program.to_account_info(),
+            InterestBearingMintUpdateRate {
+                token_program_id: token_2022_program.to_account_info(),
+                mint: mint.clone(),
+                rate_authority: program_state.to_account_info(),
+            },
+            &[&[STATE_SEED, &[program_state.bump]]],
+        ),
+        rate_bps,
+    )
+}
@@ -0,0 +1,68 @@
+// ============================================================
+// Verifier router — external proof-system programs
+// ============================================================
+//
+// Circuits whose proofs this program can't check natively (SP1,
+// RISC Zero receipts, ...) point at a verifier program instead.
+// Every verifier implements one Anchor-compatible instruction:
+//
+//   verify(circuit_id: u16, proof: Vec<u8>, public_inputs: Vec<u8>)
+//
+// and on success sets its return data to keccak256(public_inputs),
+// so a program that returns nothing, or answers for different
+// inputs, is rejected. Any accounts the verifier needs (keys,
+// image ids) are forwarded read-only from the caller.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::keccak;
+use anchor_lang::solana_program::program::{get_return_data, invoke};
+
+use crate::KisanError;
+
+/// Anchor discriminator of `verify`: sha256("global:verify")[..8]
+pub const VERIFY_DISCRIMINATOR: [u8; 8] = [0x85, 0xa1, 0x8d, 0x30, 0x78, 0xc6, 0x58, 0x96];
+
+/// Return data a verifier must set to accept `public_inputs`.
+pub fn expected_result(public_inputs: &[u8]) -> [u8; 32] {
+    keccak::hash(public_inputs).to_bytes()
+}
+
+/// CPI into `verifier_program`'s `verify` and check its answer. The
+/// verifier gets `accounts` read-only and without signer privileges.
+pub fn verify<'info>(
+    verifier_program: &AccountInfo<'info>,
+    accounts: &[AccountInfo<'info>],
+    circuit_id: u16,
+    proof: &[u8],
+    public_inputs: &[u8],
+) -> Result<()> {
+    require!(verifier_program.executable, KisanError::InvalidVerifierProgram);
+
+    let mut data = VERIFY_DISCRIMINATOR.to_vec();
+    circuit_id.serialize(&mut data)?;
+    proof.to_vec().serialize(&mut data)?;
+    public_inputs.to_vec().serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: verifier_program.key(),
+        accounts: accounts
+            .iter()
+            .map(|a| AccountMeta::new_readonly(a.key(), false))
+            .collect(),
+        data,
+    };
+
+    let mut infos = accounts.to_vec();
+    infos.push(verifier_program.clone());
+    invoke(&ix, &infos)?;
+
+    let (program_id, result) = get_return_data().ok_or(KisanError::InvalidProof)?;
+    require_keys_eq!(program_id, verifier_program.key(), KisanError::InvalidProof);
+    require!(
+        result == expected_result(public_inputs),
+        KisanError::InvalidProof
+    );
+    Ok(())
+}
@@ -0,0 +1,145 @@
+// ============================================================
+// Wormhole core bridge — minimal CPI interface
+// ============================================================
+//
+// Hand-rolled `post_message` for publishing issuance
+// attestations to other chains, so we don't pull in the
+// wormhole-anchor-sdk and its dependency tree. Layouts follow
+// the Solana core bridge v1 program.
+// ============================================================
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::sysvar;
+
+use crate::KisanError;
+
+/// Core bridge instruction index of `PostMessage`
+const POST_MESSAGE_INSTRUCTION: u8 = 1;
+
+/// Finality required before guardians sign the message
+pub const CONSISTENCY_FINALIZED: u8 = 1;
+
+/// Offset of `config.fee` in the bridge account: guardian_set_index (u32),
+/// last_lamports (u64), config.guardian_set_expiration_time (u32)
+const BRIDGE_FEE_OFFSET: usize = 16;
+
+/// Message fee currently charged by the bridge, in lamports.
+pub fn message_fee(bridge: &AccountInfo) -> Result<u64> {
+    let data = bridge.try_borrow_data()?;
+    let bytes = data
+        .get(BRIDGE_FEE_OFFSET..BRIDGE_FEE_OFFSET + 8)
+        .ok_or(ProgramError::InvalidAccountData)?;
+    Ok(u64::from_le_bytes(bytes.try_into().unwrap()))
+}
+
+pub struct PostMessageAccounts<'a, 'info> {
+    pub bridge: &'a AccountInfo<'info>,
+    pub message: &'a AccountInfo<'info>,
+    pub emitter: &'a AccountInfo<'info>,
+    pub sequence: &'a AccountInfo<'info>,
+    pub payer: &'a AccountInfo<'info>,
+    pub fee_collector: &'a AccountInfo<'info>,
+    pub clock: &'a AccountInfo<'info>,
+    pub rent: &'a AccountInfo<'info>,
+    pub system_program: &'a AccountInfo<'info>,
+    pub wormhole_program: &'a AccountInfo<'info>,
+}
+
+/// CPI into the core bridge's `post_message`. The bridge fee must already
+/// have been paid to the fee collector. `message` and `emitter` are PDAs of
+/// the calling program and sign with `signer_seeds`.
+pub fn post_message(
+    accounts: PostMessageAccounts,
+    nonce: u32,
+    payload: Vec<u8>,
+    consistency_level: u8,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let mut data = vec![POST_MESSAGE_INSTRUCTION];
+    nonce.serialize(&mut data)?;
+    payload.serialize(&mut data)?;
+    consistency_level.serialize(&mut data)?;
+
+    let ix = Instruction {
+        program_id: accounts.wormhole_program.key(),
+        accounts: vec![
+            AccountMeta::new(accounts.bridge.key(), false),
+            AccountMeta::new(accounts.message.key(), true),
+            AccountMeta::new_readonly(accounts.emitter.key(), true),
+            AccountMeta::new(accounts.sequence.key(), false),
+            AccountMeta::new(accounts.payer.key(), true),
+            AccountMeta::new(accounts.fee_collector.key(), false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+        ],
+        data,
+    };
+
+    invoke_signed(
+        &ix,
+        &[
+            accounts.bridge.clone(),
+            accounts.message.clone(),
+            accounts.emitter.clone(),
+            accounts.sequence.clone(),
+            accounts.payer.clone(),
+            accounts.fee_collector.clone(),
+            accounts.clock.clone(),
+            accounts.rent.clone(),
+            accounts.system_program.clone(),
+            accounts.wormhole_program.clone(),
+        ],
+        signer_seeds,
+    )
+    .map_err(Into::into)
+}
+
+/// Account-type prefix of a core bridge `PostedVAA` account
+const POSTED_VAA_PREFIX: &[u8; 3] = b"vaa";
+
+/// The parts of a verified, posted VAA this program reads.
+pub struct PostedVaa {
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub sequence: u64,
+    pub payload: Vec<u8>,
+}
+
+/// Load a `PostedVAA` account, checking it is the core bridge's account for
+/// `vaa_hash`. The core bridge only creates it after verifying the guardian
+/// signatures, so its existence is the proof of validity.
+pub fn load_posted_vaa(
+    info: &AccountInfo,
+    wormhole_program: &Pubkey,
+    vaa_hash: &[u8; 32],
+) -> Result<PostedVaa> {
+    let (expected, _) =
+        Pubkey::find_program_address(&[b"PostedVAA", vaa_hash.as_ref()], wormhole_program);
+    require_keys_eq!(info.key(), expected, KisanError::InvalidVaa);
+    require_keys_eq!(*info.owner, *wormhole_program, KisanError::InvalidVaa);
+
+    let data = info.try_borrow_data()?;
+    require!(
+        data.get(..3) == Some(POSTED_VAA_PREFIX.as_ref()),
+        KisanError::InvalidVaa
+    );
+
+    // vaa_version (1) | consistency_level (1) | vaa_time (4) |
+    // vaa_signature_account (32) | submission_time (4) | nonce (4) |
+    // sequence (8) | emitter_chain (2) | emitter_address (32) | payload
+    let mut body = data.get(3 + 1 + 1 + 4 + 32 + 4 + 4..).ok_or(KisanError::InvalidVaa)?;
+    let sequence = u64::deserialize(&mut body)?;
+    let emitter_chain = u16::deserialize(&mut body)?;
+    let emitter_address = <[u8; 32]>::deserialize(&mut body)?;
+    let payload = Vec::<u8>::deserialize(&mut body)?;
+
+    Ok(PostedVaa {
+        emitter_chain,
+        emitter_address,
+        sequence,
+        payload,
+    })
+}
@@ -0,0 +1,775 @@
+//! Adversarial tests for `verify_and_mint`.
+//!
+//! Throws malformed proofs, truncated public signals, corrupted
+//! instruction data, substituted accounts and unregistered CPI wrappers
+//! at the program on a `solana-program-test` bank, and checks that none
+//! of them mints: the $GREEN supply must not move. Build the program
+//! first:
+//!
+//!     anchor build && SBF_OUT_DIR=../../target/deploy cargo test --test adversarial
+//!
+//! The "valid" claim used as a baseline carries a real Groth16 proof of
+//! its commitment from the client's seeded dev circuit
+//! (`kisan_depin_client::dev_circuit`), whose key the harness registers
+//! for the circuit.
+
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::{InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use kisan_depin::bn254::{G1_GENERATOR, G2_GENERATOR};
+use kisan_depin::commitment::{CommitmentOpening, SCHEME_POSEIDON};
+use kisan_depin::payload::ProofPayload;
+use kisan_depin::{accounts, compute, instruction, KisanError, ProofPoints, ProofSystem};
+use kisan_depin_client::{dev_circuit, pda as client_pda};
+use proptest::prelude::*;
+use proptest::test_runner::{Config, TestRunner};
+use solana_program_test::{processor, BanksClient};
+use solana_sdk::account_info::AccountInfo;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::entrypoint::ProgramResult;
+use solana_sdk::instruction::{AccountMeta, Instruction, InstructionError};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+mod common;
+
+const REGION: u16 = 1;
+const CIRCUIT: u16 = 1;
+const CASES: u32 = 48;
+/// Every harness claim is on this parcel, which has no payout split
+const PARCEL: Pubkey = Pubkey::new_from_array([7; 32]);
+const CPI_WRAPPER: Pubkey = Pubkey::new_from_array([0xc9; 32]);
+
+/// A third-party program that forwards its instruction to Kisan-DePIN:
+/// account 0 is Kisan-DePIN, the rest and the data are passed through.
+fn cpi_wrapper(_: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let ix = Instruction {
+        program_id: kisan_depin::ID,
+        accounts: accounts[1..]
+            .iter()
+            .map(|a| AccountMeta {
+                pubkey: *a.key,
+                is_signer: a.is_signer,
+                is_writable: a.is_writable,
+            })
+            .collect(),
+        data: data.to_vec(),
+    };
+    solana_sdk::program::invoke(&ix, accounts)
+}
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &kisan_depin::ID).0
+}
+
+fn program_ix(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: kisan_depin::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+struct Harness {
+    banks: BanksClient,
+    authority: Keypair,
+    farmer: Keypair,
+    token_account: Pubkey,
+    nonce: u64,
+}
+
+impl Harness {
+    /// Program, season, region and circuit set up, and one registered
+    /// farmer with an empty $GREEN account.
+    async fn new() -> Harness {
+        let authority = Keypair::new();
+        let mut test = common::program_test(&authority.pubkey());
+        test.add_program("cpi_wrapper", CPI_WRAPPER, processor!(cpi_wrapper));
+        let (banks, _, _) = test.start().await;
+        let mut h = Harness {
+            banks,
+            authority,
+            farmer: Keypair::new(),
+            token_account: Pubkey::default(),
+            nonce: 0,
+        };
+        let me = h.authority.pubkey();
+        let system_program = solana_sdk::system_program::ID;
+
+        let bootstrap = kisan_depin_client::bootstrap(&me);
+        h.send_ok(&bootstrap[..2]).await;
+        let mut setup = bootstrap[2..].to_vec();
+        setup.extend([
+            program_ix(
+                accounts::InitializeSeasons {
+                    authority: me,
+                    program_state: client_pda::program_state(),
+                    admin_log: client_pda::admin_log(),
+                    season: client_pda::season(0),
+                    season_tally: client_pda::season_tally(0),
+                    config: client_pda::config(),
+                    system_program,
+                },
+                instruction::InitializeSeasons {
+                    duration: 180 * 86_400,
+                    reward_amount: 1_000_000_000,
+                    mint_cap: u64::MAX / 2,
+                    max_claims_per_farmer: u32::MAX,
+                },
+            ),
+            program_ix(
+                accounts::ConfigureRegion {
+                    authority: me,
+                    program_state: client_pda::program_state(),
+                    admin_log: client_pda::admin_log(),
+                    region_config: client_pda::region(REGION),
+                    system_program,
+                },
+                instruction::ConfigureRegion {
+                    region_code: REGION,
+                    require_identity: false,
+                },
+            ),
+            program_ix(
+                accounts::ConfigureCircuit {
+                    authority: me,
+                    program_state: client_pda::program_state(),
+                    admin_log: client_pda::admin_log(),
+                    circuit_config: client_pda::circuit(CIRCUIT),
+                    system_program,
+                },
+                instruction::ConfigureCircuit {
+                    circuit_id: CIRCUIT,
+                    active: true,
+                    green_enabled: true,
+                    water_amount: 0,
+                    soil_amount: 0,
+                    methodology_id: 0,
+                    proof_system: ProofSystem::Groth16,
+                },
+            ),
+        ]);
+        h.send_ok(&setup).await;
+        h.send_ok(&[program_ix(
+            accounts::SetGroth16VerifyingKey {
+                authority: me,
+                program_state: client_pda::program_state(),
+                admin_log: client_pda::admin_log(),
+                circuit_config: client_pda::circuit(CIRCUIT),
+                groth16_vk: client_pda::groth16_vk(CIRCUIT),
+                previous_groth16_vk: client_pda::previous_groth16_vk(CIRCUIT),
+                system_program,
+            },
+            instruction::SetGroth16VerifyingKey {
+                key: dev_circuit::verifying_key(1),
+            },
+        )])
+        .await;
+
+        let farmer = h.farmer.insecure_clone();
+        h.token_account = h.register(&farmer).await;
+        h
+    }
+
+    /// Register `wallet` as a farmer, fund it and give it a $GREEN account.
+    async fn register(&mut self, wallet: &Keypair) -> Pubkey {
+        let me = self.authority.pubkey();
+        let token_account = Keypair::new();
+        let rent = self.banks.get_rent().await.unwrap();
+        let len = spl_token::state::Account::LEN;
+        let ixs = [
+            system_instruction::transfer(&me, &wallet.pubkey(), 10_000_000_000),
+            program_ix(
+                accounts::RegisterFarmer {
+                    payer: me,
+                    farmer: wallet.pubkey(),
+                    program_state: pda(&[b"kisan-depin-state"]),
+                    personhood_verifier: None,
+                    personhood_record: None,
+                    referral_code: None,
+                    region_config: pda(&[b"region", &REGION.to_le_bytes()]),
+                    farmer_profile: pda(&[b"farmer", wallet.pubkey().as_ref()]),
+                    config: pda(&[b"config"]),
+                    green_mint: None,
+                    onboarding_vault: None,
+                    token_program: None,
+                    system_program: solana_sdk::system_program::ID,
+                },
+                instruction::RegisterFarmer {
+                    region_code: REGION,
+                    personhood_nullifier: [0; 32],
+                },
+            ),
+            system_instruction::create_account(
+                &me,
+                &token_account.pubkey(),
+                rent.minimum_balance(len),
+                len as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_account3(
+                &spl_token::ID,
+                &token_account.pubkey(),
+                &pda(&[b"green-token-mint"]),
+                &wallet.pubkey(),
+            )
+            .unwrap(),
+        ];
+        assert!(self.send(&ixs, &[wallet, &token_account]).await);
+        token_account.pubkey()
+    }
+
+    async fn send(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> bool {
+        let mut all = vec![&self.authority];
+        all.extend_from_slice(signers);
+        let blockhash = self.banks.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            ixs,
+            Some(&self.authority.pubkey()),
+            &all,
+            blockhash,
+        );
+        self.banks.process_transaction(tx).await.is_ok()
+    }
+
+    async fn send_ok(&mut self, ixs: &[Instruction]) {
+        assert!(self.send(ixs, &[]).await);
+    }
+
+    /// Total $GREEN supply.
+    async fn supply(&mut self) -> u64 {
+        self.banks
+            .get_packed_account_data::<spl_token::state::Mint>(pda(&[b"green-token-mint"]))
+            .await
+            .unwrap()
+            .supply
+    }
+
+    /// A valid claim by the harness farmer under a fresh nonce, with the
+    /// commitment as the only public signal.
+    fn claim(&mut self) -> instruction::VerifyAndMint {
+        self.nonce += 1;
+        let mut nonce = [0u8; 32];
+        nonce[24..].copy_from_slice(&self.nonce.to_be_bytes());
+        let opening = CommitmentOpening {
+            scheme: SCHEME_POSEIDON,
+            parcel: PARCEL,
+            nonce,
+        };
+        let commitment = opening.commit(CIRCUIT, &self.farmer.pubkey(), 0).unwrap();
+        let proof = dev_circuit::prove(&commitment);
+        instruction::VerifyAndMint {
+            payload: ProofPayload::groth16(&proof, commitment.to_vec()),
+            compliance_commitment: commitment,
+            opening,
+            evidence_cid: None,
+        }
+    }
+
+    /// The accounts of the farmer's own claim on `commitment`.
+    fn accounts(&self, commitment: &[u8; 32]) -> accounts::VerifyAndMint {
+        let farmer = self.farmer.pubkey();
+        accounts::VerifyAndMint {
+            payer: farmer,
+            claimant: farmer,
+            farmer,
+            delegation: None,
+            session_key: None,
+            relayer_info: None,
+            rent_pool: None,
+            program_state: pda(&[b"kisan-depin-state"]),
+            green_mint: pda(&[b"green-token-mint"]),
+            farmer_token_account: self.token_account,
+            custodial_link: None,
+            payout_split: pda(&[b"payout-split", PARCEL.as_ref()]),
+            landowner_token_account: None,
+            farmer_profile: Some(pda(&[b"farmer", farmer.as_ref()])),
+            // No test mints a full page of claims
+            claim_index: Some(pda(&[b"claim-index", farmer.as_ref(), &0u64.to_le_bytes()])),
+            config: pda(&[b"config"]),
+            region_config: Some(pda(&[b"region", &REGION.to_le_bytes()])),
+            identity_attestation: None,
+            pm_kisan_attestation: None,
+            referral_code: None,
+            referrer_token_account: None,
+            onboarding_vault: None,
+            proof_record: pda(&[b"proof", commitment]),
+            leaderboard: pda(&[b"leaderboard"]),
+            season: pda(&[b"season", &0u32.to_le_bytes()]),
+            season_tally: pda(&[b"season-tally", &0u32.to_le_bytes()]),
+            mint_stats: pda(&[b"mint-stats"]),
+            circuit_config: pda(&[b"circuit", &CIRCUIT.to_le_bytes()]),
+            feature_flags: pda(&[b"feature-flags"]),
+            pricing_config: None,
+            price_feed: None,
+            groth16_vk: Some(pda(&[b"groth16-vk", &CIRCUIT.to_le_bytes()])),
+            previous_groth16_vk: None,
+            plonk_vk: None,
+            previous_plonk_vk: None,
+            pre_commit: None,
+            verifier_program: None,
+            water_mint: None,
+            water_token_account: None,
+            soil_mint: None,
+            soil_token_account: None,
+            receipt_config: None,
+            receipt_authority: None,
+            receipt_tree_config: None,
+            receipt_merkle_tree: None,
+            log_wrapper: None,
+            compression_program: None,
+            bubblegum_program: None,
+            instructions_sysvar: None,
+            cpi_integrator: None,
+            memo_program: None,
+            token_program: spl_token::ID,
+            system_program: solana_sdk::system_program::ID,
+        }
+    }
+
+    /// Send a claim instruction signed by `signers`; true if it landed.
+    async fn submit(&mut self, ix: Instruction, signers: &[&Keypair]) -> bool {
+        let budget =
+            ComputeBudgetInstruction::set_compute_unit_limit(compute::VERIFY_AND_MINT_COMPRESSED);
+        self.send(&[budget, ix], signers).await
+    }
+
+    /// Submit `data` with the farmer's own accounts and signature.
+    async fn submit_claim(&mut self, data: Vec<u8>, commitment: &[u8; 32]) -> bool {
+        let ix = Instruction {
+            program_id: kisan_depin::ID,
+            accounts: self.accounts(commitment).to_account_metas(None),
+            data,
+        };
+        let farmer = self.farmer.insecure_clone();
+        self.submit(ix, &[&farmer]).await
+    }
+}
+
+/// Run `cases` generated inputs against one harness and check that no
+/// attempt lands and the supply never moves.
+fn never_mints<S: Strategy>(
+    strategy: S,
+    attempt: impl Fn(&mut Harness, S::Value) -> (Vec<u8>, [u8; 32]),
+) {
+    let rt = tokio::runtime::Runtime::new().unwrap();
+    let mut h = rt.block_on(Harness::new());
+    let mut runner = TestRunner::new(Config {
+        cases: CASES,
+        ..Config::default()
+    });
+    let h = std::cell::RefCell::new(&mut h);
+    runner
+        .run(&strategy, |input| {
+            let mut h = h.borrow_mut();
+            let (data, commitment) = attempt(&mut h, input);
+            let landed = rt.block_on(h.submit_claim(data, &commitment));
+            prop_assert!(!landed, "malformed claim was accepted");
+            prop_assert_eq!(rt.block_on(h.supply()), 0);
+            Ok(())
+        })
+        .unwrap();
+}
+
+fn bytes(len: usize) -> impl Strategy<Value = Vec<u8>> {
+    prop::collection::vec(any::<u8>(), len)
+}
+
+// ─────────────────────────────────────────────────────────────
+// Generated inputs
+// ─────────────────────────────────────────────────────────────
+
+#[test]
+fn random_proof_points_never_mint() {
+    never_mints((bytes(64), bytes(128), bytes(64)), |h, (a, b, c)| {
+        let mut claim = h.claim();
+        let points = ProofPoints::Uncompressed {
+            a: a.try_into().unwrap(),
+            b: b.try_into().unwrap(),
+            c: c.try_into().unwrap(),
+        };
+        claim.payload = ProofPayload::groth16(&points, claim.payload.public_signals);
+        let commitment = claim.compliance_commitment;
+        (claim.data(), commitment)
+    });
+}
+
+#[test]
+fn random_compressed_points_never_mint() {
+    never_mints((bytes(32), bytes(64), bytes(32)), |h, (a, b, c)| {
+        let mut claim = h.claim();
+        let points = ProofPoints::Compressed {
+            a: a.try_into().unwrap(),
+            b: b.try_into().unwrap(),
+            c: c.try_into().unwrap(),
+        };
+        claim.payload = ProofPayload::groth16(&points, claim.payload.public_signals);
+        let commitment = claim.compliance_commitment;
+        (claim.data(), commitment)
+    });
+}
+
+/// Variants of a valid proof with one coordinate byte changed.
+#[test]
+fn perturbed_proofs_never_mint() {
+    never_mints(
+        (0usize..3, 0usize..128, 1u8..=255),
+        |h, (point, at, xor)| {
+            let mut claim = h.claim();
+            let ProofPoints::Uncompressed {
+                mut a,
+                mut b,
+                mut c,
+            } = dev_circuit::prove(&claim.compliance_commitment)
+            else {
+                unreachable!()
+            };
+            match point {
+                0 => a[at % 64] ^= xor,
+                1 => b[at] ^= xor,
+                _ => c[at % 64] ^= xor,
+            }
+            let points = ProofPoints::Uncompressed { a, b, c };
+            claim.payload = ProofPayload::groth16(&points, claim.payload.public_signals);
+            let commitment = claim.compliance_commitment;
+            (claim.data(), commitment)
+        },
+    );
+}
+
+#[test]
+fn truncated_public_signals_never_mint() {
+    never_mints(0usize..32, |h, len| {
+        let mut claim = h.claim();
+        claim.payload.public_signals.truncate(len);
+        let commitment = claim.compliance_commitment;
+        (claim.data(), commitment)
+    });
+}
+
+#[test]
+fn unrelated_public_signals_never_mint() {
+    never_mints(
+        (1usize..4).prop_flat_map(|n| bytes(32 * n)),
+        |h, signals| {
+            let mut claim = h.claim();
+            claim.payload.public_signals = signals;
+            let commitment = claim.compliance_commitment;
+            (claim.data(), commitment)
+        },
+    );
+}
+
+/// Commitments that don't open to the claim: a changed commitment, or an
+/// opening for another circuit, farmer or season.
+#[test]
+fn mismatched_commitments_never_mint() {
+    never_mints((any::<[u8; 32]>(), 0u8..4), |h, (other, field)| {
+        let mut claim = h.claim();
+        match field {
+            0 => {
+                claim.compliance_commitment = other;
+                claim.payload.public_signals = other.to_vec();
+            }
+            1 => claim.opening.parcel = Pubkey::new_from_array(other),
+            2 => claim.opening.nonce = other,
+            _ => claim.opening.scheme = other[0] | 0x80,
+        }
+        let commitment = claim.compliance_commitment;
+        (claim.data(), commitment)
+    });
+}
+
+/// Single-byte corruption anywhere in the serialized arguments, and
+/// truncation (short commitments, missing fields).
+#[test]
+fn corrupted_instruction_data_never_mints() {
+    never_mints(
+        (any::<prop::sample::Index>(), 1u8..=255, any::<bool>()),
+        |h, (at, xor, cut)| {
+            let claim = h.claim();
+            let commitment = claim.compliance_commitment;
+            let mut data = claim.data();
+            let at = 8 + at.index(data.len() - 8);
+            if cut {
+                data.truncate(at);
+            } else {
+                data[at] ^= xor;
+            }
+            (data, commitment)
+        },
+    );
+}
+
+// ─────────────────────────────────────────────────────────────
+// Account substitutions
+// ─────────────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn substituted_accounts_never_mint() {
+    let mut h = Harness::new().await;
+    let attacker = Keypair::new();
+    let attacker_token_account = h.register(&attacker).await;
+
+    // A mint the attacker controls, and an account of it for the farmer
+    let fake_mint = Keypair::new();
+    let rent = h.banks.get_rent().await.unwrap();
+    let len = spl_token::state::Mint::LEN;
+    assert!(
+        h.send(
+            &[
+                system_instruction::create_account(
+                    &h.authority.pubkey(),
+                    &fake_mint.pubkey(),
+                    rent.minimum_balance(len),
+                    len as u64,
+                    &spl_token::ID,
+                ),
+                spl_token::instruction::initialize_mint2(
+                    &spl_token::ID,
+                    &fake_mint.pubkey(),
+                    &attacker.pubkey(),
+                    None,
+                    9,
+                )
+                .unwrap(),
+            ],
+            &[&fake_mint],
+        )
+        .await
+    );
+
+    type Substitution = Box<dyn Fn(&mut accounts::VerifyAndMint)>;
+    let attacker_key = attacker.pubkey();
+    let substitutions: Vec<(&str, bool, Substitution)> = vec![
+        (
+            "attacker claims for the farmer without a delegation",
+            true,
+            Box::new(move |a| {
+                a.payer = attacker_key;
+                a.claimant = attacker_key;
+            }),
+        ),
+        (
+            "attacker redirects the farmer's claim to its own token account",
+            true,
+            Box::new(move |a| {
+                a.payer = attacker_key;
+                a.claimant = attacker_key;
+                a.farmer_token_account = attacker_token_account;
+            }),
+        ),
+        (
+            "attacker presents the farmer's profile as its own",
+            true,
+            Box::new(move |a| {
+                a.payer = attacker_key;
+                a.claimant = attacker_key;
+                a.farmer = attacker_key;
+                a.farmer_token_account = attacker_token_account;
+            }),
+        ),
+        (
+            "attacker-controlled mint as $GREEN",
+            false,
+            Box::new({
+                let mint = fake_mint.pubkey();
+                move |a| a.green_mint = mint
+            }),
+        ),
+        (
+            "unregistered circuit",
+            false,
+            Box::new(|a| a.circuit_config = pda(&[b"circuit", &2u16.to_le_bytes()])),
+        ),
+        (
+            "region config in place of the circuit config",
+            false,
+            Box::new(|a| a.circuit_config = pda(&[b"region", &REGION.to_le_bytes()])),
+        ),
+        (
+            "season that hasn't opened",
+            false,
+            Box::new(|a| a.season = pda(&[b"season", &1u32.to_le_bytes()])),
+        ),
+        (
+            "unconfigured region",
+            false,
+            Box::new(|a| a.region_config = Some(pda(&[b"region", &2u16.to_le_bytes()]))),
+        ),
+        (
+            "proof record off its PDA",
+            false,
+            Box::new(|a| a.proof_record = Pubkey::new_unique()),
+        ),
+        (
+            "feature flags replaced by program state",
+            false,
+            Box::new(|a| a.feature_flags = pda(&[b"kisan-depin-state"])),
+        ),
+        (
+            "leaderboard in place of the mint stats",
+            false,
+            Box::new(|a| a.mint_stats = pda(&[b"leaderboard"])),
+        ),
+        (
+            "program state replaced by a system account",
+            false,
+            Box::new(move |a| a.program_state = attacker_key),
+        ),
+        (
+            "system program as the token program",
+            false,
+            Box::new(|a| a.token_program = solana_sdk::system_program::ID),
+        ),
+    ];
+
+    let farmer = h.farmer.insecure_clone();
+    for (name, by_attacker, substitute) in substitutions {
+        let claim = h.claim();
+        let mut accounts = h.accounts(&claim.compliance_commitment);
+        substitute(&mut accounts);
+        let ix = program_ix(accounts, claim);
+        let signer = if by_attacker { &attacker } else { &farmer };
+        assert!(!h.submit(ix, &[signer]).await, "{name}: claim was accepted");
+        assert_eq!(h.supply().await, 0, "{name}: supply moved");
+    }
+}
+
+#[tokio::test]
+async fn replayed_commitment_mints_once() {
+    let mut h = Harness::new().await;
+    let claim = h.claim();
+    let commitment = claim.compliance_commitment;
+    let data = claim.data();
+
+    assert!(h.submit_claim(data.clone(), &commitment).await);
+    let supply = h.supply().await;
+    assert!(supply > 0, "baseline claim minted nothing");
+
+    // Same claim in a different transaction, so the bank doesn't drop it
+    // as already processed
+    let ix = Instruction {
+        program_id: kisan_depin::ID,
+        accounts: h.accounts(&commitment).to_account_metas(None),
+        data,
+    };
+    let farmer = h.farmer.insecure_clone();
+    let replay = [ComputeBudgetInstruction::set_compute_unit_price(1), ix];
+
+    // It fails with `ProofAlreadyUsed`, not the system program's "account
+    // already in use", and logs the original claim as an event
+    let blockhash = h.banks.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &replay,
+        Some(&h.authority.pubkey()),
+        &[&h.authority, &farmer],
+        blockhash,
+    );
+    let simulated = h.banks.simulate_transaction(tx).await.unwrap();
+    assert_eq!(
+        simulated.result.unwrap().unwrap_err(),
+        TransactionError::InstructionError(
+            1,
+            InstructionError::Custom(KisanError::ProofAlreadyUsed.into())
+        )
+    );
+    let logs = simulated.simulation_details.unwrap().logs;
+    assert!(logs.iter().any(|l| l.starts_with("Program data: ")), "no ProofReused event");
+
+    assert!(!h.send(&replay, &[&farmer]).await, "replay was accepted");
+    assert_eq!(h.supply().await, supply);
+}
+
+// ─────────────────────────────────────────────────────────────
+// CPI wrapping
+// ─────────────────────────────────────────────────────────────
+
+impl Harness {
+    /// The farmer's claim routed through `CPI_WRAPPER`, with or without
+    /// the CPI-policy accounts.
+    fn wrapped_claim(&mut self, with_policy: bool) -> Instruction {
+        let claim = self.claim();
+        let mut accounts = self.accounts(&claim.compliance_commitment);
+        if with_policy {
+            accounts.instructions_sysvar = Some(solana_sdk::sysvar::instructions::ID);
+            accounts.cpi_integrator = Some(pda(&[b"cpi-integrator", CPI_WRAPPER.as_ref()]));
+        }
+        let mut metas = vec![AccountMeta::new_readonly(kisan_depin::ID, false)];
+        metas.extend(accounts.to_account_metas(None));
+        Instruction {
+            program_id: CPI_WRAPPER,
+            accounts: metas,
+            data: claim.data(),
+        }
+    }
+
+    async fn register_wrapper(&mut self) {
+        let me = self.authority.pubkey();
+        self.send_ok(&[program_ix(
+            accounts::RegisterCpiIntegrator {
+                authority: me,
+                program_state: pda(&[b"kisan-depin-state"]),
+                admin_log: pda(&[b"admin-log"]),
+                cpi_integrator: pda(&[b"cpi-integrator", CPI_WRAPPER.as_ref()]),
+                system_program: solana_sdk::system_program::ID,
+            },
+            instruction::RegisterCpiIntegrator {
+                program: CPI_WRAPPER,
+            },
+        )])
+        .await;
+    }
+}
+
+#[tokio::test]
+async fn unregistered_wrapper_never_mints() {
+    let mut h = Harness::new().await;
+    let farmer = h.farmer.insecure_clone();
+    for with_policy in [false, true] {
+        let ix = h.wrapped_claim(with_policy);
+        assert!(
+            !h.submit(ix, &[&farmer]).await,
+            "wrapped claim was accepted"
+        );
+        assert_eq!(h.supply().await, 0);
+    }
+}
+
+#[tokio::test]
+async fn registered_wrapper_mints() {
+    let mut h = Harness::new().await;
+    h.register_wrapper().await;
+    let farmer = h.farmer.insecure_clone();
+
+    // Registered, but the policy accounts are still required
+    let ix = h.wrapped_claim(false);
+    assert!(!h.submit(ix, &[&farmer]).await);
+    assert_eq!(h.supply().await, 0);
+
+    let ix = h.wrapped_claim(true);
+    assert!(h.submit(ix, &[&farmer]).await);
+    assert!(h.supply().await > 0);
+}
+
+/// Proofs that are well-formed but prove nothing about the claim: valid
+/// curve points, and a valid proof of another commitment.
+#[tokio::test]
+async fn well_formed_false_proof_never_mints() {
+    let mut h = Harness::new().await;
+    let generators = ProofPoints::Uncompressed {
+        a: G1_GENERATOR,
+        b: G2_GENERATOR,
+        c: G1_GENERATOR,
+    };
+    let other = h.claim().compliance_commitment;
+    for points in [generators, dev_circuit::prove(&other)] {
+        let mut claim = h.claim();
+        claim.payload = ProofPayload::groth16(&points, claim.payload.public_signals);
+        let commitment = claim.compliance_commitment;
+        assert!(!h.submit_claim(claim.data(), &commitment).await);
+        assert_eq!(h.supply().await, 0);
+    }
+}
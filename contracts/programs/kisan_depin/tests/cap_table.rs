@@ -0,0 +1,249 @@
+//! Reserved allocations: `initialize` mints each into its escrow, and
+//! `release_allocation` pays only what has vested, only to the
+//! beneficiary.
+//!
+//!     anchor build && SBF_OUT_DIR=../../target/deploy cargo test --test cap_table
+
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use kisan_common::seeds::{ALLOCATION_VAULT_SEED, CAP_TABLE_SEED, MINT_SEED, STATE_SEED};
+use kisan_depin::{
+    accounts, instruction, AllocationKind, AllocationTerms, CapTable, KisanError, ProgramState,
+};
+use solana_program_test::BanksClient;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+mod common;
+
+const YEAR: i64 = 365 * 24 * 60 * 60;
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &kisan_depin::ID).0
+}
+
+fn allocation_vault(kind: AllocationKind) -> Pubkey {
+    pda(&[ALLOCATION_VAULT_SEED, &[kind as u8]])
+}
+
+fn initialize(authority: Pubkey, allocations: [AllocationTerms; 4]) -> Instruction {
+    Instruction {
+        program_id: kisan_depin::ID,
+        accounts: accounts::Initialize {
+            authority,
+            program: kisan_depin::ID,
+            program_data: common::program_data(),
+            program_state: pda(&[STATE_SEED]),
+            green_mint: pda(&[MINT_SEED]),
+            cap_table: pda(&[CAP_TABLE_SEED]),
+            community_vault: allocation_vault(AllocationKind::Community),
+            team_vault: allocation_vault(AllocationKind::Team),
+            investors_vault: allocation_vault(AllocationKind::Investors),
+            ecosystem_vault: allocation_vault(AllocationKind::Ecosystem),
+            token_program: spl_token::ID,
+            system_program: solana_sdk::system_program::ID,
+            rent: solana_sdk::sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::Initialize { allocations }.data(),
+    }
+}
+
+fn release(kind: AllocationKind, beneficiary_token_account: Pubkey) -> Instruction {
+    Instruction {
+        program_id: kisan_depin::ID,
+        accounts: accounts::ReleaseAllocation {
+            program_state: pda(&[STATE_SEED]),
+            cap_table: pda(&[CAP_TABLE_SEED]),
+            allocation_vault: allocation_vault(kind),
+            beneficiary_token_account,
+            token_program: spl_token::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::ReleaseAllocation { kind }.data(),
+    }
+}
+
+/// Send `ixs` signed by `payer` and `signers`; the custom error code on
+/// failure.
+async fn send(
+    banks: &mut BanksClient,
+    payer: &Keypair,
+    signers: &[&Keypair],
+    ixs: &[Instruction],
+) -> Result<(), u32> {
+    let mut all = vec![payer];
+    all.extend_from_slice(signers);
+    let blockhash = banks.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &all, blockhash);
+    match banks.process_transaction(tx).await {
+        Ok(()) => Ok(()),
+        Err(e) => match e.unwrap() {
+            TransactionError::InstructionError(_, InstructionError::Custom(code)) => Err(code),
+            other => panic!("unexpected failure: {other:?}"),
+        },
+    }
+}
+
+/// A $GREEN token account owned by `owner`.
+async fn token_account(banks: &mut BanksClient, payer: &Keypair, owner: &Pubkey) -> Pubkey {
+    let account = Keypair::new();
+    let len = spl_token::state::Account::LEN;
+    let ixs = [
+        system_instruction::create_account(
+            &payer.pubkey(),
+            &account.pubkey(),
+            Rent::default().minimum_balance(len),
+            len as u64,
+            &spl_token::ID,
+        ),
+        spl_token::instruction::initialize_account3(
+            &spl_token::ID,
+            &account.pubkey(),
+            &pda(&[MINT_SEED]),
+            owner,
+        )
+        .unwrap(),
+    ];
+    send(banks, payer, &[&account], &ixs).await.unwrap();
+    account.pubkey()
+}
+
+async fn balance(banks: &mut BanksClient, account: Pubkey) -> u64 {
+    banks
+        .get_packed_account_data::<spl_token::state::Account>(account)
+        .await
+        .unwrap()
+        .amount
+}
+
+async fn cap_table(banks: &mut BanksClient) -> CapTable {
+    let account = banks.get_account(pda(&[CAP_TABLE_SEED])).await.unwrap().unwrap();
+    CapTable::try_deserialize(&mut &account.data[..]).unwrap()
+}
+
+fn code(e: KisanError) -> u32 {
+    e.into()
+}
+
+/// Community unlocked at once, team behind a one-year cliff, no
+/// investor allocation, ecosystem vesting linearly over four years.
+fn table(community: Pubkey, team: Pubkey, ecosystem: Pubkey) -> [AllocationTerms; 4] {
+    [
+        AllocationTerms {
+            beneficiary: community,
+            amount: 400,
+            cliff: 0,
+            duration: 0,
+        },
+        AllocationTerms {
+            beneficiary: team,
+            amount: 200,
+            cliff: YEAR,
+            duration: 4 * YEAR,
+        },
+        AllocationTerms::default(),
+        AllocationTerms {
+            beneficiary: ecosystem,
+            amount: 300,
+            cliff: 0,
+            duration: 4 * YEAR,
+        },
+    ]
+}
+
+#[tokio::test]
+async fn allocations_are_minted_into_escrow() {
+    let deployer = Keypair::new();
+    let (mut banks, _, _) = common::program_test(&deployer.pubkey()).start().await;
+    let (community, team, ecosystem) = (Keypair::new(), Keypair::new(), Keypair::new());
+    let ix = initialize(
+        deployer.pubkey(),
+        table(community.pubkey(), team.pubkey(), ecosystem.pubkey()),
+    );
+    send(&mut banks, &deployer, &[], &[ix]).await.unwrap();
+
+    assert_eq!(balance(&mut banks, allocation_vault(AllocationKind::Community)).await, 400);
+    assert_eq!(balance(&mut banks, allocation_vault(AllocationKind::Team)).await, 200);
+    assert_eq!(balance(&mut banks, allocation_vault(AllocationKind::Investors)).await, 0);
+    assert_eq!(balance(&mut banks, allocation_vault(AllocationKind::Ecosystem)).await, 300);
+
+    let account = banks.get_account(pda(&[STATE_SEED])).await.unwrap().unwrap();
+    let state = ProgramState::try_deserialize(&mut &account.data[..]).unwrap();
+    assert_eq!(state.total_tokens_minted, 900);
+
+    let table = cap_table(&mut banks).await;
+    assert_eq!(table.allocations[AllocationKind::Team as usize].beneficiary, team.pubkey());
+    assert_eq!(table.allocations[AllocationKind::Team as usize].cliff, YEAR);
+}
+
+#[tokio::test]
+async fn release_pays_only_vested_and_only_the_beneficiary() {
+    let deployer = Keypair::new();
+    let (mut banks, _, _) = common::program_test(&deployer.pubkey()).start().await;
+    let (community, team, ecosystem) = (Keypair::new(), Keypair::new(), Keypair::new());
+    let ix = initialize(
+        deployer.pubkey(),
+        table(community.pubkey(), team.pubkey(), ecosystem.pubkey()),
+    );
+    send(&mut banks, &deployer, &[], &[ix]).await.unwrap();
+
+    // An unlocked allocation can't be redirected to someone else's account
+    let outsider = token_account(&mut banks, &deployer, &deployer.pubkey()).await;
+    let err = send(&mut banks, &deployer, &[], &[release(AllocationKind::Community, outsider)])
+        .await
+        .unwrap_err();
+    assert_eq!(err, u32::from(anchor_lang::error::ErrorCode::ConstraintTokenOwner));
+
+    // Anyone can release it to the beneficiary, once
+    let to = token_account(&mut banks, &deployer, &community.pubkey()).await;
+    send(&mut banks, &deployer, &[], &[release(AllocationKind::Community, to)]).await.unwrap();
+    assert_eq!(balance(&mut banks, to).await, 400);
+    assert_eq!(balance(&mut banks, allocation_vault(AllocationKind::Community)).await, 0);
+    let err = send(&mut banks, &deployer, &[], &[release(AllocationKind::Community, to)])
+        .await
+        .unwrap_err();
+    assert_eq!(err, code(KisanError::NothingVested));
+
+    // Nothing before the cliff
+    let to = token_account(&mut banks, &deployer, &team.pubkey()).await;
+    let err = send(&mut banks, &deployer, &[], &[release(AllocationKind::Team, to)])
+        .await
+        .unwrap_err();
+    assert_eq!(err, code(KisanError::NothingVested));
+    assert_eq!(cap_table(&mut banks).await.allocations[AllocationKind::Team as usize].released, 0);
+}
+
+#[tokio::test]
+async fn cliff_past_duration_is_rejected() {
+    let deployer = Keypair::new();
+    let (mut banks, _, _) = common::program_test(&deployer.pubkey()).start().await;
+    let mut allocations = [AllocationTerms::default(); 4];
+    allocations[AllocationKind::Team as usize] = AllocationTerms {
+        beneficiary: Pubkey::new_unique(),
+        amount: 100,
+        cliff: 2 * YEAR,
+        duration: YEAR,
+    };
+    let err = send(&mut banks, &deployer, &[], &[initialize(deployer.pubkey(), allocations)])
+        .await
+        .unwrap_err();
+    assert_eq!(err, code(KisanError::InvalidAllocation));
+}
+
+#[tokio::test]
+async fn funded_allocation_needs_a_beneficiary() {
+    let deployer = Keypair::new();
+    let (mut banks, _, _) = common::program_test(&deployer.pubkey()).start().await;
+    let mut allocations = [AllocationTerms::default(); 4];
+    allocations[AllocationKind::Investors as usize].amount = 100;
+    let err = send(&mut banks, &deployer, &[], &[initialize(deployer.pubkey(), allocations)])
+        .await
+        .unwrap_err();
+    assert_eq!(err, code(KisanError::InvalidAllocation));
+}
@@ -0,0 +1,64 @@
+//! `solana-program-test` setup shared by the program's tests and the
+//! compute-unit bench.
+//!
+//! `initialize` must be signed by the program's upgrade authority, so
+//! the program is deployed through the upgradeable loader, as on a real
+//! cluster, rather than with `ProgramTest::new`.
+
+use solana_program_test::{find_file, read_file, ProgramTest};
+use solana_sdk::account::Account;
+use solana_sdk::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+
+/// A bank with `kisan_depin.so` (from `SBF_OUT_DIR`) deployed
+/// upgradeable under `deployer`, and `deployer` funded to pay for setup.
+pub fn program_test(deployer: &Pubkey) -> ProgramTest {
+    let elf =
+        read_file(find_file("kisan_depin.so").expect("kisan_depin.so not found; run anchor build"));
+    let rent = Rent::default();
+    let loader = bpf_loader_upgradeable::id();
+    let (programdata_address, _) =
+        Pubkey::find_program_address(&[kisan_depin::ID.as_ref()], &loader);
+
+    let program = UpgradeableLoaderState::Program {
+        programdata_address,
+    };
+    let mut program_account = Account::new_data(
+        rent.minimum_balance(UpgradeableLoaderState::size_of_program()),
+        &program,
+        &loader,
+    )
+    .unwrap();
+    program_account.executable = true;
+
+    let metadata_len = UpgradeableLoaderState::size_of_programdata_metadata();
+    let programdata = UpgradeableLoaderState::ProgramData {
+        slot: 0,
+        upgrade_authority_address: Some(*deployer),
+    };
+    let mut programdata_account = Account::new_data_with_space(
+        rent.minimum_balance(metadata_len + elf.len()),
+        &programdata,
+        metadata_len + elf.len(),
+        &loader,
+    )
+    .unwrap();
+    programdata_account.data[metadata_len..].copy_from_slice(&elf);
+
+    let mut test = ProgramTest::default();
+    test.add_account(kisan_depin::ID, program_account);
+    test.add_account(programdata_address, programdata_account);
+    test.add_account(
+        *deployer,
+        Account::new(1_000 * LAMPORTS_PER_SOL, 0, &solana_sdk::system_program::ID),
+    );
+    test
+}
+
+/// The program's `ProgramData` account.
+#[allow(dead_code)]
+pub fn program_data() -> Pubkey {
+    Pubkey::find_program_address(&[kisan_depin::ID.as_ref()], &bpf_loader_upgradeable::id()).0
+}
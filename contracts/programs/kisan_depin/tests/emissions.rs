@@ -0,0 +1,169 @@
+//! Emission curves at the seasons where they change behaviour: the first
+//! season, the season a decay reaches its floor, halving boundaries, and
+//! seasons far enough out to overflow naive arithmetic.
+//!
+//!     cargo test --test emissions
+
+use kisan_depin::emissions::{CurveKind, EmissionCurve};
+use proptest::prelude::*;
+
+const BASE: u64 = 1_000_000_000;
+
+fn curve(kind: CurveKind, rate: u64, interval: u32, floor: u64) -> EmissionCurve {
+    EmissionCurve {
+        kind,
+        rate,
+        interval,
+        floor,
+    }
+}
+
+fn kinds() -> [EmissionCurve; 4] {
+    [
+        EmissionCurve::FIXED,
+        curve(CurveKind::LinearDecay, 1_000, 0, 0),
+        curve(CurveKind::ExponentialDecay, 500, 0, 0),
+        curve(CurveKind::StepHalving, 0, 4, 0),
+    ]
+}
+
+#[test]
+fn season_zero_pays_the_base() {
+    for c in kinds() {
+        assert_eq!(c.reward(BASE, 0), BASE, "{c:?}");
+    }
+}
+
+#[test]
+fn zero_base_pays_nothing() {
+    for c in kinds() {
+        assert_eq!(c.reward(0, 0), 0, "{c:?}");
+        assert_eq!(c.reward(0, 1_000), 0, "{c:?}");
+    }
+}
+
+#[test]
+fn fixed_never_decays() {
+    let c = EmissionCurve::FIXED;
+    for season in [1, 2, 1_000, u32::MAX] {
+        assert_eq!(c.reward(BASE, season), BASE);
+    }
+}
+
+#[test]
+fn linear_decays_by_rate_until_the_floor() {
+    let c = curve(CurveKind::LinearDecay, 100_000_000, 0, 200_000_000);
+    assert_eq!(c.reward(BASE, 1), 900_000_000);
+    assert_eq!(c.reward(BASE, 7), 300_000_000);
+    assert_eq!(c.reward(BASE, 8), 200_000_000);
+    assert_eq!(c.reward(BASE, 9), 200_000_000);
+    assert_eq!(c.reward(BASE, u32::MAX), 200_000_000);
+}
+
+#[test]
+fn linear_reaches_zero_without_a_floor() {
+    let c = curve(CurveKind::LinearDecay, 100_000_000, 0, 0);
+    assert_eq!(c.reward(BASE, 9), 100_000_000);
+    assert_eq!(c.reward(BASE, 10), 0);
+    assert_eq!(c.reward(BASE, 11), 0);
+}
+
+#[test]
+fn linear_rate_times_season_saturates() {
+    let c = curve(CurveKind::LinearDecay, u64::MAX, 0, 0);
+    assert_eq!(c.reward(BASE, 1), 0);
+    assert_eq!(c.reward(BASE, u32::MAX), 0);
+}
+
+#[test]
+fn exponential_compounds_per_season() {
+    let c = curve(CurveKind::ExponentialDecay, 1_000, 0, 0);
+    assert_eq!(c.reward(BASE, 1), 900_000_000);
+    assert_eq!(c.reward(BASE, 2), 810_000_000);
+    assert_eq!(c.reward(BASE, 3), 729_000_000);
+    assert_eq!(c.reward(BASE, 10), 348_678_440);
+}
+
+#[test]
+fn exponential_full_decay_pays_the_floor_after_season_zero() {
+    let c = curve(CurveKind::ExponentialDecay, 10_000, 0, 5);
+    assert_eq!(c.reward(BASE, 0), BASE);
+    assert_eq!(c.reward(BASE, 1), 5);
+}
+
+#[test]
+fn exponential_far_seasons_floor_without_overflow() {
+    let c = curve(CurveKind::ExponentialDecay, 1, 0, 7);
+    assert_eq!(c.reward(u64::MAX, u32::MAX), 7);
+    let c = curve(CurveKind::ExponentialDecay, 5_000, 0, 0);
+    assert_eq!(c.reward(u64::MAX, 64), 0);
+    assert_eq!(c.reward(u64::MAX, u32::MAX), 0);
+}
+
+#[test]
+fn halving_steps_at_interval_boundaries() {
+    let c = curve(CurveKind::StepHalving, 0, 4, 0);
+    assert_eq!(c.reward(BASE, 3), BASE);
+    assert_eq!(c.reward(BASE, 4), BASE / 2);
+    assert_eq!(c.reward(BASE, 7), BASE / 2);
+    assert_eq!(c.reward(BASE, 8), BASE / 4);
+    assert_eq!(c.reward(BASE, 4 * 29), BASE >> 29);
+    assert_eq!(c.reward(BASE, 4 * 30), 0);
+}
+
+#[test]
+fn halving_past_sixty_four_steps_is_zero_not_a_panic() {
+    let c = curve(CurveKind::StepHalving, 0, 1, 0);
+    assert_eq!(c.reward(u64::MAX, 63), 1);
+    assert_eq!(c.reward(u64::MAX, 64), 0);
+    assert_eq!(c.reward(u64::MAX, u32::MAX), 0);
+}
+
+#[test]
+fn floor_above_base_caps_at_base() {
+    let c = curve(CurveKind::StepHalving, 0, 1, BASE * 2);
+    assert_eq!(c.reward(BASE, 0), BASE);
+    assert_eq!(c.reward(BASE, 5), BASE);
+}
+
+#[test]
+fn validity() {
+    assert!(EmissionCurve::FIXED.is_valid());
+    assert!(!curve(CurveKind::LinearDecay, 0, 0, 0).is_valid());
+    assert!(curve(CurveKind::LinearDecay, 1, 0, 0).is_valid());
+    assert!(!curve(CurveKind::ExponentialDecay, 0, 0, 0).is_valid());
+    assert!(curve(CurveKind::ExponentialDecay, 10_000, 0, 0).is_valid());
+    assert!(!curve(CurveKind::ExponentialDecay, 10_001, 0, 0).is_valid());
+    assert!(!curve(CurveKind::StepHalving, 0, 0, 0).is_valid());
+    assert!(curve(CurveKind::StepHalving, 0, 1, 0).is_valid());
+}
+
+fn any_curve() -> impl Strategy<Value = EmissionCurve> {
+    (0..4u8, 1..=10_000u64, 1..=100u32, any::<u64>()).prop_map(|(kind, rate, interval, floor)| {
+        let kind = match kind {
+            0 => CurveKind::Fixed,
+            1 => CurveKind::LinearDecay,
+            2 => CurveKind::ExponentialDecay,
+            _ => CurveKind::StepHalving,
+        };
+        curve(kind, rate, interval, floor)
+    })
+}
+
+proptest! {
+    #[test]
+    fn rewards_never_increase(c in any_curve(), base in any::<u64>(), season in 0..10_000u32) {
+        prop_assert!(c.reward(base, season + 1) <= c.reward(base, season));
+    }
+
+    #[test]
+    fn rewards_stay_between_floor_and_base(
+        c in any_curve(),
+        base in any::<u64>(),
+        season in any::<u32>(),
+    ) {
+        let reward = c.reward(base, season);
+        prop_assert!(reward <= base);
+        prop_assert!(reward >= c.floor.min(base));
+    }
+}
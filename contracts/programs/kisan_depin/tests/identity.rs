@@ -0,0 +1,566 @@
+//! Regional identity requirement: in a region that requires an identity
+//! attestation, every path that mints to a registered farmer refuses a
+//! farmer without one, with `IdentityAttestationRequired`.
+//!
+//! The aggregate batch, imagery setup and verified dMRV claim each path
+//! starts from are written into the bank directly, so every test reaches
+//! the identity check without the recursive proof, EVM signature or
+//! attester quorum that would create them on a cluster.
+//!
+//!     anchor build && SBF_OUT_DIR=../../target/deploy cargo test --test identity
+
+use anchor_lang::solana_program::program_pack::Pack;
+use anchor_lang::{AccountSerialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use kisan_common::seeds::{
+    AGGREGATE_SEED, CIRCUIT_SEED, CLAIM_INDEX_SEED, CLAIM_SEED, CONFIG_SEED, FARMER_SEED,
+    FEATURE_FLAGS_SEED, GROTH16_VK_SEED, IMAGERY_SEED, LEADERBOARD_SEED, MINT_SEED,
+    MINT_STATS_SEED, PAYOUT_SPLIT_SEED, PROOF_SEED, REGION_SEED, SEASON_SEED, SEASON_TALLY_SEED,
+    STATE_SEED,
+};
+use kisan_depin::commitment::{CommitmentOpening, SCHEME_POSEIDON};
+use kisan_depin::payload::ProofPayload;
+use kisan_depin::{
+    accounts, compute, instruction, AggregateBatch, Claim, ClaimStatus, EvmOracle, Groth16Proof,
+    ImageryConfig, KisanError, ProofPoints, ProofSystem,
+};
+use kisan_depin_client::{dev_circuit, pda as client_pda};
+use solana_program_test::BanksClient;
+use solana_sdk::account::Account;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::keccak;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::rent::Rent;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::sysvar;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+mod common;
+
+const REGION: u16 = 1;
+const CIRCUIT: u16 = 1;
+/// Every claim is on this parcel, which has no payout split
+const PARCEL: Pubkey = Pubkey::new_from_array([7; 32]);
+/// Amount of the farmer's leaf in the aggregate batch
+const LEAF_AMOUNT: u64 = 1_000_000_000;
+const IMAGERY_PROVIDER: Pubkey = Pubkey::new_from_array([0xe7; 32]);
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &kisan_depin::ID).0
+}
+
+fn bump(seeds: &[&[u8]]) -> u8 {
+    Pubkey::find_program_address(seeds, &kisan_depin::ID).1
+}
+
+fn program_ix(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: kisan_depin::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// `data` as a rent-exempt account owned by the program.
+fn program_account(data: &impl AccountSerialize) -> Account {
+    let mut bytes = Vec::new();
+    data.try_serialize(&mut bytes).unwrap();
+    Account {
+        lamports: Rent::default().minimum_balance(bytes.len()),
+        data: bytes,
+        owner: kisan_depin::ID,
+        executable: false,
+        rent_epoch: 0,
+    }
+}
+
+fn code(e: KisanError) -> u32 {
+    e.into()
+}
+
+struct Harness {
+    banks: BanksClient,
+    authority: Keypair,
+    farmer: Keypair,
+    token_account: Pubkey,
+}
+
+impl Harness {
+    /// Program, season and circuit set up, in a region that requires
+    /// identity, with one registered farmer who has no attestation. The
+    /// farmer has a leaf in an aggregate batch, a verified dMRV claim on
+    /// `claim_commitment()`, and the circuit takes imagery claims.
+    async fn new() -> Harness {
+        let authority = Keypair::new();
+        let farmer = Keypair::new();
+        let mut test = common::program_test(&authority.pubkey());
+
+        let root = Self::leaf(&farmer.pubkey());
+        let batch_seeds: &[&[u8]] = &[AGGREGATE_SEED, &root];
+        test.add_account(
+            pda(batch_seeds),
+            program_account(&AggregateBatch {
+                batch_root: root,
+                circuit_id: CIRCUIT,
+                leaf_count: 1,
+                leaves_redeemed: 0,
+                amount_redeemed: 0,
+                submitter: authority.pubkey(),
+                verified_at: 0,
+                bump: bump(batch_seeds),
+            }),
+        );
+        let commitment = Self::claim_commitment();
+        let claim_seeds: &[&[u8]] = &[CLAIM_SEED, &commitment];
+        test.add_account(
+            pda(claim_seeds),
+            program_account(&Claim {
+                farmer: farmer.pubkey(),
+                commitment,
+                circuit_id: CIRCUIT,
+                status: ClaimStatus::Verified,
+                submitted_at: 0,
+                verified_by: kisan_depin::ID,
+                verified_at: 0,
+                issued_amount: 0,
+                issued_at: 0,
+                retired_by: Pubkey::default(),
+                retired_at: 0,
+                bump: bump(claim_seeds),
+            }),
+        );
+        let imagery_seeds: &[&[u8]] = &[IMAGERY_SEED, &CIRCUIT.to_le_bytes()];
+        test.add_account(
+            pda(imagery_seeds),
+            program_account(&ImageryConfig {
+                circuit_id: CIRCUIT,
+                provider: IMAGERY_PROVIDER,
+                min_ndvi_delta: 0,
+                min_window: 0,
+                max_observation_age: i64::MAX,
+                reward_amount: LEAF_AMOUNT,
+                bump: bump(imagery_seeds),
+            }),
+        );
+        test.add_account(
+            IMAGERY_PROVIDER,
+            program_account(&EvmOracle {
+                eth_address: [0xe7; 20],
+                registered_at: 0,
+                bump: 0,
+            }),
+        );
+
+        let (banks, _, _) = test.start().await;
+        let mut h = Harness {
+            banks,
+            authority,
+            farmer,
+            token_account: Pubkey::default(),
+        };
+        let me = h.authority.pubkey();
+        let system_program = solana_sdk::system_program::ID;
+
+        let bootstrap = kisan_depin_client::bootstrap(&me);
+        h.send(&bootstrap[..2], &[]).await.unwrap();
+        let mut setup = bootstrap[2..].to_vec();
+        setup.extend([
+            program_ix(
+                accounts::InitializeSeasons {
+                    authority: me,
+                    program_state: client_pda::program_state(),
+                    admin_log: client_pda::admin_log(),
+                    season: client_pda::season(0),
+                    season_tally: client_pda::season_tally(0),
+                    config: client_pda::config(),
+                    system_program,
+                },
+                instruction::InitializeSeasons {
+                    duration: 180 * 86_400,
+                    reward_amount: LEAF_AMOUNT,
+                    mint_cap: u64::MAX / 2,
+                    max_claims_per_farmer: u32::MAX,
+                },
+            ),
+            program_ix(
+                accounts::ConfigureRegion {
+                    authority: me,
+                    program_state: client_pda::program_state(),
+                    admin_log: client_pda::admin_log(),
+                    region_config: client_pda::region(REGION),
+                    system_program,
+                },
+                instruction::ConfigureRegion {
+                    region_code: REGION,
+                    require_identity: true,
+                },
+            ),
+            program_ix(
+                accounts::ConfigureCircuit {
+                    authority: me,
+                    program_state: client_pda::program_state(),
+                    admin_log: client_pda::admin_log(),
+                    circuit_config: client_pda::circuit(CIRCUIT),
+                    system_program,
+                },
+                instruction::ConfigureCircuit {
+                    circuit_id: CIRCUIT,
+                    active: true,
+                    green_enabled: true,
+                    water_amount: 0,
+                    soil_amount: 0,
+                    methodology_id: 0,
+                    proof_system: ProofSystem::Groth16,
+                },
+            ),
+        ]);
+        h.send(&setup, &[]).await.unwrap();
+        h.send(
+            &[program_ix(
+                accounts::SetGroth16VerifyingKey {
+                    authority: me,
+                    program_state: client_pda::program_state(),
+                    admin_log: client_pda::admin_log(),
+                    circuit_config: client_pda::circuit(CIRCUIT),
+                    groth16_vk: client_pda::groth16_vk(CIRCUIT),
+                    previous_groth16_vk: client_pda::previous_groth16_vk(CIRCUIT),
+                    system_program,
+                },
+                instruction::SetGroth16VerifyingKey {
+                    key: dev_circuit::verifying_key(1),
+                },
+            )],
+            &[],
+        )
+        .await
+        .unwrap();
+
+        h.token_account = h.register().await;
+        h
+    }
+
+    /// Register the farmer in `REGION`, fund it and give it a $GREEN
+    /// account.
+    async fn register(&mut self) -> Pubkey {
+        let me = self.authority.pubkey();
+        let wallet = self.farmer.insecure_clone();
+        let token_account = Keypair::new();
+        let len = spl_token::state::Account::LEN;
+        let ixs = [
+            system_instruction::transfer(&me, &wallet.pubkey(), 10_000_000_000),
+            program_ix(
+                accounts::RegisterFarmer {
+                    payer: me,
+                    farmer: wallet.pubkey(),
+                    program_state: pda(&[STATE_SEED]),
+                    personhood_verifier: None,
+                    personhood_record: None,
+                    referral_code: None,
+                    region_config: pda(&[REGION_SEED, &REGION.to_le_bytes()]),
+                    farmer_profile: pda(&[FARMER_SEED, wallet.pubkey().as_ref()]),
+                    config: pda(&[CONFIG_SEED]),
+                    green_mint: None,
+                    onboarding_vault: None,
+                    token_program: None,
+                    system_program: solana_sdk::system_program::ID,
+                },
+                instruction::RegisterFarmer {
+                    region_code: REGION,
+                    personhood_nullifier: [0; 32],
+                },
+            ),
+            system_instruction::create_account(
+                &me,
+                &token_account.pubkey(),
+                Rent::default().minimum_balance(len),
+                len as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_account3(
+                &spl_token::ID,
+                &token_account.pubkey(),
+                &pda(&[MINT_SEED]),
+                &wallet.pubkey(),
+            )
+            .unwrap(),
+        ];
+        self.send(&ixs, &[&wallet, &token_account]).await.unwrap();
+        token_account.pubkey()
+    }
+
+    /// Send `ixs` signed by the authority and `signers`; the custom error
+    /// code on failure.
+    async fn send(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> Result<(), u32> {
+        let mut all = vec![&self.authority];
+        all.extend_from_slice(signers);
+        let blockhash = self.banks.get_latest_blockhash().await.unwrap();
+        let tx = Transaction::new_signed_with_payer(
+            ixs,
+            Some(&self.authority.pubkey()),
+            &all,
+            blockhash,
+        );
+        match self.banks.process_transaction(tx).await {
+            Ok(()) => Ok(()),
+            Err(e) => match e.unwrap() {
+                TransactionError::InstructionError(_, InstructionError::Custom(code)) => Err(code),
+                other => panic!("unexpected failure: {other:?}"),
+            },
+        }
+    }
+
+    /// Send `ix` signed by the farmer, with the claim compute budget.
+    async fn claim(&mut self, ix: Instruction) -> Result<(), u32> {
+        let budget =
+            ComputeBudgetInstruction::set_compute_unit_limit(compute::VERIFY_AND_MINT_COMPRESSED);
+        let farmer = self.farmer.insecure_clone();
+        self.send(&[budget, ix], &[&farmer]).await
+    }
+
+    /// The farmer's commitment on `PARCEL` under `nonce`.
+    fn commitment(farmer: &Pubkey, nonce: u8) -> (CommitmentOpening, [u8; 32]) {
+        let opening = CommitmentOpening {
+            scheme: SCHEME_POSEIDON,
+            parcel: PARCEL,
+            nonce: [nonce; 32],
+        };
+        let commitment = opening.commit(CIRCUIT, farmer, 0).unwrap();
+        (opening, commitment)
+    }
+
+    /// Commitment of the farmer's verified dMRV claim.
+    fn claim_commitment() -> [u8; 32] {
+        [0xc1; 32]
+    }
+
+    /// Commitment of the farmer's leaf in the aggregate batch.
+    fn leaf_commitment() -> [u8; 32] {
+        [0xa9; 32]
+    }
+
+    /// The farmer's leaf, which is also the root of its one-leaf batch.
+    fn leaf(farmer: &Pubkey) -> [u8; 32] {
+        keccak::hashv(&[
+            farmer.as_ref(),
+            &Self::leaf_commitment(),
+            &LEAF_AMOUNT.to_be_bytes(),
+        ])
+        .to_bytes()
+    }
+
+    fn claim_index(&self) -> Pubkey {
+        let farmer = self.farmer.pubkey();
+        pda(&[CLAIM_INDEX_SEED, farmer.as_ref(), &0u64.to_le_bytes()])
+    }
+}
+
+#[tokio::test]
+async fn verify_and_mint_requires_identity() {
+    let mut h = Harness::new().await;
+    let farmer = h.farmer.pubkey();
+    let (opening, commitment) = Harness::commitment(&farmer, 1);
+    let proof = dev_circuit::prove(&commitment);
+    let ix = program_ix(
+        accounts::VerifyAndMint {
+            payer: farmer,
+            claimant: farmer,
+            farmer,
+            delegation: None,
+            session_key: None,
+            relayer_info: None,
+            rent_pool: None,
+            program_state: pda(&[STATE_SEED]),
+            green_mint: pda(&[MINT_SEED]),
+            farmer_token_account: h.token_account,
+            custodial_link: None,
+            payout_split: pda(&[PAYOUT_SPLIT_SEED, PARCEL.as_ref()]),
+            landowner_token_account: None,
+            farmer_profile: Some(pda(&[FARMER_SEED, farmer.as_ref()])),
+            claim_index: Some(h.claim_index()),
+            config: pda(&[CONFIG_SEED]),
+            region_config: Some(pda(&[REGION_SEED, &REGION.to_le_bytes()])),
+            identity_attestation: None,
+            pm_kisan_attestation: None,
+            referral_code: None,
+            referrer_token_account: None,
+            onboarding_vault: None,
+            proof_record: pda(&[PROOF_SEED, &commitment]),
+            leaderboard: pda(&[LEADERBOARD_SEED]),
+            season: pda(&[SEASON_SEED, &0u32.to_le_bytes()]),
+            season_tally: pda(&[SEASON_TALLY_SEED, &0u32.to_le_bytes()]),
+            mint_stats: pda(&[MINT_STATS_SEED]),
+            circuit_config: pda(&[CIRCUIT_SEED, &CIRCUIT.to_le_bytes()]),
+            feature_flags: pda(&[FEATURE_FLAGS_SEED]),
+            pricing_config: None,
+            price_feed: None,
+            groth16_vk: Some(pda(&[GROTH16_VK_SEED, &CIRCUIT.to_le_bytes()])),
+            previous_groth16_vk: None,
+            plonk_vk: None,
+            previous_plonk_vk: None,
+            pre_commit: None,
+            verifier_program: None,
+            water_mint: None,
+            water_token_account: None,
+            soil_mint: None,
+            soil_token_account: None,
+            receipt_config: None,
+            receipt_authority: None,
+            receipt_tree_config: None,
+            receipt_merkle_tree: None,
+            log_wrapper: None,
+            compression_program: None,
+            bubblegum_program: None,
+            instructions_sysvar: None,
+            cpi_integrator: None,
+            memo_program: None,
+            token_program: spl_token::ID,
+            system_program: solana_sdk::system_program::ID,
+        },
+        instruction::VerifyAndMint {
+            payload: ProofPayload::groth16(&proof, commitment.to_vec()),
+            compliance_commitment: commitment,
+            opening,
+            evidence_cid: None,
+        },
+    );
+    assert_eq!(
+        h.claim(ix).await,
+        Err(code(KisanError::IdentityAttestationRequired))
+    );
+}
+
+#[tokio::test]
+async fn redeem_aggregate_leaf_requires_identity() {
+    let mut h = Harness::new().await;
+    let farmer = h.farmer.pubkey();
+    let commitment = Harness::leaf_commitment();
+    let ix = program_ix(
+        accounts::RedeemAggregateLeaf {
+            payer: farmer,
+            farmer,
+            farmer_profile: pda(&[FARMER_SEED, farmer.as_ref()]),
+            claim_index: h.claim_index(),
+            config: pda(&[CONFIG_SEED]),
+            season_tally: pda(&[SEASON_TALLY_SEED, &0u32.to_le_bytes()]),
+            mint_stats: pda(&[MINT_STATS_SEED]),
+            region_config: pda(&[REGION_SEED, &REGION.to_le_bytes()]),
+            identity_attestation: None,
+            pm_kisan_attestation: None,
+            aggregate_batch: pda(&[AGGREGATE_SEED, &Harness::leaf(&farmer)]),
+            circuit_config: pda(&[CIRCUIT_SEED, &CIRCUIT.to_le_bytes()]),
+            proof_record: pda(&[PROOF_SEED, &commitment]),
+            program_state: pda(&[STATE_SEED]),
+            season: pda(&[SEASON_SEED, &0u32.to_le_bytes()]),
+            green_mint: pda(&[MINT_SEED]),
+            farmer_token_account: h.token_account,
+            custodial_link: None,
+            memo_program: None,
+            token_program: spl_token::ID,
+            system_program: solana_sdk::system_program::ID,
+        },
+        instruction::RedeemAggregateLeaf {
+            commitment,
+            amount: LEAF_AMOUNT,
+            merkle_proof: vec![],
+        },
+    );
+    assert_eq!(
+        h.claim(ix).await,
+        Err(code(KisanError::IdentityAttestationRequired))
+    );
+}
+
+#[tokio::test]
+async fn verify_imagery_and_mint_requires_identity() {
+    let mut h = Harness::new().await;
+    let farmer = h.farmer.pubkey();
+    let (_, commitment) = Harness::commitment(&farmer, 2);
+    let ix = program_ix(
+        accounts::VerifyImageryAndMint {
+            farmer,
+            program_state: pda(&[STATE_SEED]),
+            farmer_profile: pda(&[FARMER_SEED, farmer.as_ref()]),
+            claim_index: h.claim_index(),
+            config: pda(&[CONFIG_SEED]),
+            season_tally: pda(&[SEASON_TALLY_SEED, &0u32.to_le_bytes()]),
+            mint_stats: pda(&[MINT_STATS_SEED]),
+            circuit_config: pda(&[CIRCUIT_SEED, &CIRCUIT.to_le_bytes()]),
+            imagery_config: pda(&[IMAGERY_SEED, &CIRCUIT.to_le_bytes()]),
+            groth16_vk: pda(&[GROTH16_VK_SEED, &CIRCUIT.to_le_bytes()]),
+            previous_groth16_vk: None,
+            provider: IMAGERY_PROVIDER,
+            region_config: pda(&[REGION_SEED, &REGION.to_le_bytes()]),
+            identity_attestation: None,
+            pm_kisan_attestation: None,
+            season: pda(&[SEASON_SEED, &0u32.to_le_bytes()]),
+            proof_record: pda(&[PROOF_SEED, &commitment]),
+            green_mint: pda(&[MINT_SEED]),
+            farmer_token_account: h.token_account,
+            custodial_link: None,
+            instructions_sysvar: sysvar::instructions::ID,
+            memo_program: None,
+            token_program: spl_token::ID,
+            system_program: solana_sdk::system_program::ID,
+        },
+        instruction::VerifyImageryAndMint {
+            commitment,
+            // Never checked: the identity requirement fails first
+            proof: Groth16Proof {
+                points: ProofPoints::Uncompressed {
+                    a: [0; 64],
+                    b: [0; 128],
+                    c: [0; 64],
+                },
+                public_signals: commitment.to_vec(),
+            },
+            secp_ix_index: 0,
+        },
+    );
+    assert_eq!(
+        h.claim(ix).await,
+        Err(code(KisanError::IdentityAttestationRequired))
+    );
+}
+
+#[tokio::test]
+async fn issue_credits_requires_identity() {
+    let mut h = Harness::new().await;
+    let farmer = h.farmer.pubkey();
+    let commitment = Harness::claim_commitment();
+    let ix = program_ix(
+        accounts::IssueCredits {
+            payer: h.authority.pubkey(),
+            claim: pda(&[CLAIM_SEED, &commitment]),
+            program_state: pda(&[STATE_SEED]),
+            green_mint: pda(&[MINT_SEED]),
+            farmer_token_account: h.token_account,
+            custodial_link: None,
+            farmer_profile: pda(&[FARMER_SEED, farmer.as_ref()]),
+            claim_index: h.claim_index(),
+            region_config: pda(&[REGION_SEED, &REGION.to_le_bytes()]),
+            identity_attestation: None,
+            config: pda(&[CONFIG_SEED]),
+            season_tally: pda(&[SEASON_TALLY_SEED, &0u32.to_le_bytes()]),
+            mint_stats: pda(&[MINT_STATS_SEED]),
+            circuit_config: pda(&[CIRCUIT_SEED, &CIRCUIT.to_le_bytes()]),
+            season: pda(&[SEASON_SEED, &0u32.to_le_bytes()]),
+            feature_flags: pda(&[FEATURE_FLAGS_SEED]),
+            pricing_config: None,
+            price_feed: None,
+            proof_record: pda(&[PROOF_SEED, &commitment]),
+            claim_attestation: None,
+            node_reward_config: None,
+            node_reward_vault: None,
+            memo_program: None,
+            token_program: spl_token::ID,
+            system_program: solana_sdk::system_program::ID,
+        },
+        instruction::IssueCredits {},
+    );
+    assert_eq!(
+        h.claim(ix).await,
+        Err(code(KisanError::IdentityAttestationRequired))
+    );
+}
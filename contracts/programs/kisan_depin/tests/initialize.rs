@@ -0,0 +1,189 @@
+//! `initialize` access control: only the upgrade authority can
+//! initialize, and only once.
+//!
+//!     anchor build && SBF_OUT_DIR=../../target/deploy cargo test --test initialize
+
+use anchor_lang::{AccountDeserialize, InstructionData, ToAccountMetas};
+use anchor_spl::token::spl_token;
+use kisan_depin::{accounts, instruction, AllocationKind, KisanError, ProgramState};
+use solana_program_test::BanksClient;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::{Instruction, InstructionError};
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::{Transaction, TransactionError};
+
+mod common;
+
+fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &kisan_depin::ID).0
+}
+
+fn initialize(authority: Pubkey, program_data: Pubkey) -> Instruction {
+    Instruction {
+        program_id: kisan_depin::ID,
+        accounts: accounts::Initialize {
+            authority,
+            program: kisan_depin::ID,
+            program_data,
+            program_state: pda(&[b"kisan-depin-state"]),
+            green_mint: pda(&[b"green-token-mint"]),
+            cap_table: pda(&[b"cap-table"]),
+            community_vault: pda(&[b"allocation-vault", &[AllocationKind::Community as u8]]),
+            team_vault: pda(&[b"allocation-vault", &[AllocationKind::Team as u8]]),
+            investors_vault: pda(&[b"allocation-vault", &[AllocationKind::Investors as u8]]),
+            ecosystem_vault: pda(&[b"allocation-vault", &[AllocationKind::Ecosystem as u8]]),
+            token_program: spl_token::ID,
+            system_program: solana_sdk::system_program::ID,
+            rent: solana_sdk::sysvar::rent::ID,
+        }
+        .to_account_metas(None),
+        data: instruction::Initialize {
+            allocations: Default::default(),
+        }
+        .data(),
+    }
+}
+
+/// Send `ixs` signed and paid for by `signer`; the custom error code on
+/// failure.
+async fn send(banks: &mut BanksClient, signer: &Keypair, ixs: &[Instruction]) -> Result<(), u32> {
+    let blockhash = banks.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(ixs, Some(&signer.pubkey()), &[signer], blockhash);
+    match banks.process_transaction(tx).await {
+        Ok(()) => Ok(()),
+        Err(e) => match e.unwrap() {
+            TransactionError::InstructionError(_, InstructionError::Custom(code)) => Err(code),
+            other => panic!("unexpected failure: {other:?}"),
+        },
+    }
+}
+
+/// A bank deployed under a fresh upgrade authority, plus a funded
+/// outsider.
+async fn setup() -> (BanksClient, Keypair, Keypair) {
+    let deployer = Keypair::new();
+    let outsider = Keypair::new();
+    let (mut banks, payer, _) = common::program_test(&deployer.pubkey()).start().await;
+    let blockhash = banks.get_latest_blockhash().await.unwrap();
+    let fund = system_instruction::transfer(&payer.pubkey(), &outsider.pubkey(), 1_000_000_000);
+    let tx =
+        Transaction::new_signed_with_payer(&[fund], Some(&payer.pubkey()), &[&payer], blockhash);
+    banks.process_transaction(tx).await.unwrap();
+    (banks, deployer, outsider)
+}
+
+async fn program_state(banks: &mut BanksClient) -> ProgramState {
+    let account = banks
+        .get_account(pda(&[b"kisan-depin-state"]))
+        .await
+        .unwrap()
+        .expect("program state exists");
+    ProgramState::try_deserialize(&mut &account.data[..]).unwrap()
+}
+
+fn code(e: KisanError) -> u32 {
+    e.into()
+}
+
+#[tokio::test]
+async fn upgrade_authority_initializes() {
+    let (mut banks, deployer, _) = setup().await;
+    send(
+        &mut banks,
+        &deployer,
+        &[initialize(deployer.pubkey(), common::program_data())],
+    )
+    .await
+    .unwrap();
+
+    let state = program_state(&mut banks).await;
+    assert_eq!(state.authority, deployer.pubkey());
+    assert_eq!(state.mint, pda(&[b"green-token-mint"]));
+
+    // The state PDA can freeze $GREEN accounts
+    let mint = banks
+        .get_packed_account_data::<spl_token::state::Mint>(state.mint)
+        .await
+        .unwrap();
+    assert_eq!(
+        mint.freeze_authority,
+        Some(pda(&[b"kisan-depin-state"])).into()
+    );
+}
+
+#[tokio::test]
+async fn outsider_cannot_front_run() {
+    let (mut banks, deployer, outsider) = setup().await;
+    let err = send(
+        &mut banks,
+        &outsider,
+        &[initialize(outsider.pubkey(), common::program_data())],
+    )
+    .await
+    .unwrap_err();
+    assert_eq!(err, code(KisanError::UnauthorizedDeployer));
+
+    // The seat is still free for the deployer
+    send(
+        &mut banks,
+        &deployer,
+        &[initialize(deployer.pubkey(), common::program_data())],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn substituted_program_data_is_rejected() {
+    let (mut banks, _, outsider) = setup().await;
+    // Any account other than the program's own ProgramData, here one the
+    // outsider controls, fails the `programdata_address` check or fails to
+    // deserialize as ProgramData.
+    let ix = initialize(outsider.pubkey(), outsider.pubkey());
+    let blockhash = banks.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&outsider.pubkey()),
+        &[&outsider],
+        blockhash,
+    );
+    assert!(banks.process_transaction(tx).await.is_err());
+    assert!(banks
+        .get_account(pda(&[b"kisan-depin-state"]))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn second_initialize_fails_cleanly() {
+    let (mut banks, deployer, _) = setup().await;
+    send(
+        &mut banks,
+        &deployer,
+        &[initialize(deployer.pubkey(), common::program_data())],
+    )
+    .await
+    .unwrap();
+    let before = program_state(&mut banks).await;
+
+    // Priced differently, so the bank doesn't drop it as a duplicate of
+    // the first transaction
+    let err = send(
+        &mut banks,
+        &deployer,
+        &[
+            ComputeBudgetInstruction::set_compute_unit_price(1),
+            initialize(deployer.pubkey(), common::program_data()),
+        ],
+    )
+    .await;
+    assert_eq!(err, Err(code(KisanError::AlreadyInitialized)));
+
+    let after = program_state(&mut banks).await;
+    assert_eq!(after.authority, before.authority);
+    assert_eq!(after.mint, before.mint);
+    assert_eq!(after.bump, before.bump);
+}
@@ -0,0 +1,131 @@
+//! `ProofPayload::decode` on well-formed payloads, on each way a section
+//! can be the wrong length, and on arbitrary bytes.
+//!
+//!     cargo test --test payload
+
+use anchor_lang::{AnchorDeserialize, AnchorSerialize};
+use kisan_depin::payload::{Proof, ProofFormat, ProofPayload, PROOF_PAYLOAD_VERSION};
+use kisan_depin::{KisanError, ProofPoints, ProofSystem};
+use proptest::prelude::*;
+
+fn code(result: anchor_lang::Result<Proof<'_>>) -> u32 {
+    match result {
+        Err(anchor_lang::error::Error::AnchorError(e)) => e.error_code_number,
+        Err(e) => panic!("unexpected error: {e}"),
+        Ok(_) => panic!("decoded"),
+    }
+}
+
+fn expected(e: KisanError) -> u32 {
+    e.into()
+}
+
+#[test]
+fn groth16_round_trips_in_both_encodings() {
+    let points = ProofPoints::Uncompressed {
+        a: [1; 64],
+        b: [2; 128],
+        c: [3; 64],
+    };
+    let payload = ProofPayload::groth16(&points, vec![9; 32]);
+    assert_eq!(payload.format, ProofFormat::Groth16Uncompressed);
+    let Ok(Proof::Groth16(points)) = payload.decode(ProofSystem::Groth16) else {
+        panic!("not Groth16");
+    };
+    let ProofPoints::Uncompressed { a, b, c } = *points else {
+        panic!("not uncompressed Groth16");
+    };
+    assert_eq!((a, b, c), ([1; 64], [2; 128], [3; 64]));
+
+    let points = ProofPoints::Compressed {
+        a: [4; 32],
+        b: [5; 64],
+        c: [6; 32],
+    };
+    let payload = ProofPayload::groth16(&points, vec![9; 64]);
+    let Ok(Proof::Groth16(points)) = payload.decode(ProofSystem::Groth16) else {
+        panic!("not Groth16");
+    };
+    let ProofPoints::Compressed { a, b, c } = *points else {
+        panic!("not compressed Groth16");
+    };
+    assert_eq!((a, b, c), ([4; 32], [5; 64], [6; 32]));
+}
+
+#[test]
+fn wire_format_is_length_prefixed() {
+    let payload = ProofPayload::serialized(ProofFormat::Plonk, vec![0xaa; 3], vec![0xbb; 32]);
+    let bytes = payload.try_to_vec().unwrap();
+    assert_eq!(bytes[0], PROOF_PAYLOAD_VERSION);
+    assert_eq!(bytes[1], ProofFormat::Plonk as u8);
+    assert_eq!(&bytes[2..6], &3u32.to_le_bytes());
+    assert_eq!(&bytes[6..9], &[0xaa; 3]);
+    assert_eq!(&bytes[9..13], &32u32.to_le_bytes());
+    assert_eq!(bytes.len(), 13 + 32);
+}
+
+#[test]
+fn unknown_version_is_rejected() {
+    let mut payload = ProofPayload::serialized(ProofFormat::Plonk, vec![1], vec![0; 32]);
+    payload.version = PROOF_PAYLOAD_VERSION + 1;
+    assert_eq!(
+        code(payload.decode(ProofSystem::Plonk)),
+        expected(KisanError::UnsupportedPayloadVersion)
+    );
+}
+
+#[test]
+fn format_must_match_the_circuit() {
+    let payload = ProofPayload::serialized(ProofFormat::External, vec![1], vec![0; 32]);
+    assert_eq!(
+        code(payload.decode(ProofSystem::Plonk)),
+        expected(KisanError::ProofFormatMismatch)
+    );
+}
+
+#[test]
+fn malformed_sections_are_rejected() {
+    let malformed = expected(KisanError::MalformedProofPayload);
+    let groth16 = |len, signals| {
+        ProofPayload::serialized(ProofFormat::Groth16Compressed, vec![1; len], signals)
+    };
+    assert_eq!(code(groth16(127, vec![0; 32]).decode(ProofSystem::Groth16)), malformed);
+    assert_eq!(code(groth16(129, vec![0; 32]).decode(ProofSystem::Groth16)), malformed);
+    assert_eq!(code(groth16(128, vec![]).decode(ProofSystem::Groth16)), malformed);
+    assert_eq!(code(groth16(128, vec![0; 33]).decode(ProofSystem::Groth16)), malformed);
+
+    let empty = ProofPayload::serialized(ProofFormat::Plonk, vec![], vec![0; 32]);
+    assert_eq!(code(empty.decode(ProofSystem::Plonk)), malformed);
+}
+
+fn any_system() -> impl Strategy<Value = ProofSystem> {
+    prop_oneof![
+        Just(ProofSystem::Groth16),
+        Just(ProofSystem::Plonk),
+        Just(ProofSystem::External),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn arbitrary_bytes_never_panic(
+        bytes in prop::collection::vec(any::<u8>(), 0..600),
+        system in any_system(),
+    ) {
+        if let Ok(payload) = ProofPayload::try_from_slice(&bytes) {
+            let _ = payload.decode(system);
+            prop_assert_eq!(payload.try_to_vec().unwrap(), bytes);
+        }
+    }
+
+    #[test]
+    fn decoded_groth16_has_exact_sections(len in 0..300usize, words in 0..4usize) {
+        let payload = ProofPayload::serialized(
+            ProofFormat::Groth16Uncompressed,
+            vec![7; len],
+            vec![0; 32 * words],
+        );
+        let ok = payload.decode(ProofSystem::Groth16).is_ok();
+        prop_assert_eq!(ok, len == 256 && words > 0);
+    }
+}
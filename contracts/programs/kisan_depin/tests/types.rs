@@ -0,0 +1,123 @@
+//! `kisan-types` mirrors the program's layouts by hand; these fail when
+//! one side changes without the other.
+//!
+//!     cargo test --test types
+
+use anchor_lang::{AnchorDeserialize, AnchorSerialize, Discriminator, Space};
+use solana_sdk::pubkey::Pubkey;
+
+#[test]
+fn program_id_matches() {
+    assert_eq!(kisan_types::ID, kisan_depin::ID);
+}
+
+/// Anchor's tag for `kisan_depin::$name` equals the mirror's.
+macro_rules! same_discriminator {
+    ($($name:ident),+) => {
+        $(
+            assert_eq!(
+                <kisan_types::$name as kisan_types::Discriminator>::DISCRIMINATOR,
+                <kisan_depin::$name as Discriminator>::DISCRIMINATOR,
+                stringify!($name),
+            );
+        )+
+    };
+}
+
+#[test]
+fn discriminators_match() {
+    same_discriminator!(ProgramState, FarmerProfile, ProofRecord, Season);
+    same_discriminator!(
+        ProofMinted,
+        ClaimSubmitted,
+        ClaimVerified,
+        CreditsIssued,
+        ClaimRetired,
+        BuyerActivity
+    );
+}
+
+/// A mirror decodes exactly `INIT_SPACE` bytes: a field added, dropped or
+/// resized on either side changes that.
+fn same_size<T: AnchorDeserialize>(init_space: usize) {
+    let zeros = vec![0u8; init_space];
+    T::try_from_slice(&zeros).unwrap_or_else(|e| panic!("{}: {e}", std::any::type_name::<T>()));
+}
+
+#[test]
+fn account_sizes_match() {
+    same_size::<kisan_types::ProgramState>(kisan_depin::ProgramState::INIT_SPACE);
+    same_size::<kisan_types::FarmerProfile>(kisan_depin::FarmerProfile::INIT_SPACE);
+    same_size::<kisan_types::ProofRecord>(kisan_depin::ProofRecord::INIT_SPACE);
+    same_size::<kisan_types::Season>(kisan_depin::Season::INIT_SPACE);
+}
+
+#[test]
+fn events_round_trip_field_for_field() {
+    let (farmer, claimant, payer) =
+        (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+    let minted = kisan_depin::ProofMinted {
+        farmer,
+        claimant,
+        payer,
+        commitment: [7; 32],
+        circuit_id: 3,
+        season: 11,
+        streak: 4,
+        amount: 1_000_000_000,
+        referral_bonus: 5,
+        serial_start: 100,
+        serial_end: 1_000_000_100,
+        claim_id: 42,
+    };
+    let mirror = kisan_types::ProofMinted::try_from_slice(&minted.try_to_vec().unwrap()).unwrap();
+    assert_eq!(
+        mirror,
+        kisan_types::ProofMinted {
+            farmer,
+            claimant,
+            payer,
+            commitment: [7; 32],
+            circuit_id: 3,
+            season: 11,
+            streak: 4,
+            amount: 1_000_000_000,
+            referral_bonus: 5,
+            serial_start: 100,
+            serial_end: 1_000_000_100,
+            claim_id: 42,
+        }
+    );
+
+    let activity = kisan_depin::BuyerActivity {
+        buyer: farmer,
+        kind: kisan_depin::BuyerActivityKind::Retirement,
+        reference: payer,
+        amount: 9,
+        usdc_paid: 8,
+        total_purchased: 7,
+        total_spent: 6,
+        total_retired: 5,
+        timestamp: 4,
+    };
+    let mirror =
+        kisan_types::BuyerActivity::try_from_slice(&activity.try_to_vec().unwrap()).unwrap();
+    assert_eq!(mirror.kind, kisan_types::BuyerActivityKind::Retirement);
+    assert_eq!(mirror.reference, payer);
+    assert_eq!(mirror.timestamp, 4);
+}
+
+#[test]
+fn decode_event_checks_the_discriminator() {
+    let retired = kisan_depin::ClaimRetired {
+        claim: Pubkey::new_unique(),
+        retired_by: Pubkey::new_unique(),
+        amount: 12,
+    };
+    let mut data = kisan_depin::ClaimRetired::DISCRIMINATOR.to_vec();
+    data.extend(retired.try_to_vec().unwrap());
+
+    let decoded = kisan_types::decode_event::<kisan_types::ClaimRetired>(&data).unwrap();
+    assert_eq!(decoded.amount, 12);
+    assert!(kisan_types::decode_event::<kisan_types::ClaimVerified>(&data).is_none());
+}
@@ -0,0 +1,176 @@
+// ============================================================
+// Kisan-DePIN prover — compliance proofs for verify_and_mint
+// ============================================================
+//
+// Proves `zk-proofs/verify_compliance.circom` with ark-groth16,
+// using the circuit's compiled wasm/r1cs and the zkey from its
+// trusted setup, and returns the arguments `verify_and_mint`
+// takes: proof points, public signals, commitment and opening.
+//
+// The circuit's commitment is the program's Poseidon scheme
+// (`commitment::SCHEME_POSEIDON`); every proof is checked
+// against the program's own implementation before it is
+// returned, so a drift between the two fails here rather than
+// on-chain.
+// ============================================================
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use anchor_lang::prelude::Pubkey;
+use ark_bn254::{Bn254, Fr};
+use ark_circom::{read_zkey, CircomBuilder, CircomConfig, CircomReduction};
+use ark_ff::{BigInteger, PrimeField};
+use ark_groth16::{Groth16, ProvingKey};
+use ark_std::rand::{thread_rng, RngCore};
+use kisan_depin::commitment::{CommitmentOpening, SCHEME_POSEIDON};
+use kisan_depin::{groth16, ProofPoints};
+use kisan_depin_client::ark;
+use num_bigint::{BigInt, Sign};
+
+#[derive(Debug, thiserror::Error)]
+pub enum ProverError {
+    #[error("loading circuit artifacts: {0}")]
+    Artifacts(String),
+    #[error("witness generation failed: {0}")]
+    Witness(String),
+    #[error("proving failed: {0}")]
+    Proving(String),
+    #[error("circuit commitment does not match the program's commitment scheme")]
+    CommitmentMismatch,
+}
+
+pub type Result<T> = std::result::Result<T, ProverError>;
+
+/// Field data behind a compliance claim, as the circuit's private inputs.
+pub struct PracticeData {
+    /// Hash of the AI system's compliance status
+    pub compliance_status_hash: BigInt,
+    /// Hash the status must equal ("COMPLIANT")
+    pub expected_compliance_hash: BigInt,
+    /// Latitude × 10 000
+    pub gps_latitude: i64,
+    /// Longitude × 10 000
+    pub gps_longitude: i64,
+}
+
+/// Who and what the claim is for; bound into the commitment.
+pub struct ClaimContext {
+    pub circuit_id: u16,
+    pub farmer: Pubkey,
+    pub parcel: Pubkey,
+    pub season: u32,
+    /// Must be below the field modulus; see `random_nonce`
+    pub nonce: [u8; 32],
+}
+
+/// A random nonce below 2²⁵³, so it is a valid field element.
+pub fn random_nonce() -> [u8; 32] {
+    let mut nonce = [0u8; 32];
+    thread_rng().fill_bytes(&mut nonce);
+    nonce[0] &= 0x1f;
+    nonce
+}
+
+/// Everything `verify_and_mint` needs from the prover.
+pub struct ClaimProof {
+    pub proof: ProofPoints,
+    /// [commitment, expectedComplianceHash], 32 bytes each
+    pub public_signals: Vec<u8>,
+    pub commitment: [u8; 32],
+    pub opening: CommitmentOpening,
+}
+
+pub struct Prover {
+    // The witness calculator owns its wasm store and can't be cloned, so
+    // each proof loads a fresh one
+    wasm: PathBuf,
+    r1cs: PathBuf,
+    proving_key: ProvingKey<Bn254>,
+}
+
+fn be_bytes(bytes: &[u8]) -> BigInt {
+    BigInt::from_bytes_be(Sign::Plus, bytes)
+}
+
+impl Prover {
+    /// Load the compiled circuit (`.wasm`, `.r1cs`) and its proving key
+    /// (`.zkey`), as produced by `zk-proofs/setup_and_prove.sh`.
+    pub fn load(wasm: &Path, r1cs: &Path, zkey: &Path) -> Result<Prover> {
+        let mut file = File::open(zkey).map_err(|e| ProverError::Artifacts(e.to_string()))?;
+        let (proving_key, _) =
+            read_zkey(&mut file).map_err(|e| ProverError::Artifacts(e.to_string()))?;
+        Ok(Prover {
+            wasm: wasm.to_path_buf(),
+            r1cs: r1cs.to_path_buf(),
+            proving_key,
+        })
+    }
+
+    /// The zkey's verifying key, as `set_groth16_verifying_key` takes it.
+    pub fn verifying_key(&self) -> groth16::VerifyingKey {
+        ark::verifying_key(&self.proving_key.vk)
+    }
+
+    /// Prove a claim.
+    pub fn prove(&self, data: &PracticeData, claim: &ClaimContext) -> Result<ClaimProof> {
+        let farmer = claim.farmer.to_bytes();
+        let parcel = claim.parcel.to_bytes();
+
+        let config = CircomConfig::<Fr>::new(&self.wasm, &self.r1cs)
+            .map_err(|e| ProverError::Artifacts(e.to_string()))?;
+        let mut builder = CircomBuilder::new(config);
+        builder.push_input("complianceStatusHash", data.compliance_status_hash.clone());
+        builder.push_input(
+            "expectedComplianceHash",
+            data.expected_compliance_hash.clone(),
+        );
+        builder.push_input("gpsLatitude", data.gps_latitude);
+        builder.push_input("gpsLongitude", data.gps_longitude);
+        builder.push_input("circuitId", claim.circuit_id);
+        builder.push_input("season", claim.season);
+        builder.push_input("farmerHi", be_bytes(&farmer[..16]));
+        builder.push_input("farmerLo", be_bytes(&farmer[16..]));
+        builder.push_input("parcelHi", be_bytes(&parcel[..16]));
+        builder.push_input("parcelLo", be_bytes(&parcel[16..]));
+        builder.push_input("nonce", be_bytes(&claim.nonce));
+
+        let circuit = builder
+            .build()
+            .map_err(|e| ProverError::Witness(e.to_string()))?;
+        let inputs = circuit
+            .get_public_inputs()
+            .ok_or_else(|| ProverError::Witness("missing public inputs".into()))?;
+        let proof = Groth16::<Bn254, CircomReduction>::create_random_proof_with_reduction(
+            circuit,
+            &self.proving_key,
+            &mut thread_rng(),
+        )
+        .map_err(|e| ProverError::Proving(e.to_string()))?;
+
+        let public_signals: Vec<u8> = inputs
+            .iter()
+            .flat_map(|x| x.into_bigint().to_bytes_be())
+            .collect();
+        let commitment: [u8; 32] = public_signals[..32].try_into().unwrap();
+
+        let opening = CommitmentOpening {
+            scheme: SCHEME_POSEIDON,
+            parcel: claim.parcel,
+            nonce: claim.nonce,
+        };
+        let expected = opening
+            .commit(claim.circuit_id, &claim.farmer, claim.season)
+            .map_err(|_| ProverError::CommitmentMismatch)?;
+        if expected != commitment {
+            return Err(ProverError::CommitmentMismatch);
+        }
+
+        Ok(ClaimProof {
+            proof: ark::proof_points(&proof),
+            public_signals,
+            commitment,
+            opening,
+        })
+    }
+}
@@ -0,0 +1,289 @@
+//! Round-trips a real proof through `verify_and_mint` on a
+//! `solana-program-test` bank.
+//!
+//! Needs the circuit artifacts from `zk-proofs/setup_and_prove.sh` (or
+//! `KISAN_CIRCUIT_BUILD`) and the program built with `anchor build`;
+//! skipped when the artifacts are missing.
+
+use std::path::PathBuf;
+
+use anchor_lang::solana_program::program_pack::Pack;
+use kisan_depin::payload::ProofPayload;
+use kisan_depin::ProofSystem;
+use kisan_depin_client::{accounts, bootstrap, instruction, ix, pda};
+use kisan_prover::{random_nonce, ClaimContext, PracticeData, Prover};
+use num_bigint::BigInt;
+use solana_program_test::BanksClient;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::Transaction;
+
+#[path = "../../programs/kisan_depin/tests/common/mod.rs"]
+mod common;
+
+const REGION: u16 = 1;
+const CIRCUIT: u16 = 1;
+
+/// Poseidon("COMPLIANT"), as in zk-proofs/input.json
+const COMPLIANT_HASH: &str =
+    "7853200120776062878684798364095072458815029376092732009249414926327459813530";
+
+async fn send(banks: &mut BanksClient, payer: &Keypair, ixs: &[Instruction], signers: &[&Keypair]) {
+    let mut all = vec![payer];
+    all.extend_from_slice(signers);
+    let blockhash = banks.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &all, blockhash);
+    banks.process_transaction(tx).await.unwrap();
+}
+
+#[tokio::test]
+async fn proof_verifies_and_mints() {
+    let build = std::env::var("KISAN_CIRCUIT_BUILD")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| {
+            PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../../zk-proofs/build")
+        });
+    let zkey = build.join("verify_compliance.zkey");
+    if !zkey.exists() {
+        eprintln!("skipping: no circuit artifacts in {}", build.display());
+        return;
+    }
+    let prover = Prover::load(
+        &build.join("verify_compliance_js/verify_compliance.wasm"),
+        &build.join("verify_compliance.r1cs"),
+        &zkey,
+    )
+    .unwrap();
+
+    let authority = Keypair::new();
+    let (mut banks, _, _) = common::program_test(&authority.pubkey()).start().await;
+    let me = authority.pubkey();
+    let system_program = solana_sdk::system_program::ID;
+    let token_program = anchor_spl::token::spl_token::ID;
+
+    let bootstrap = bootstrap(&me);
+    send(&mut banks, &authority, &bootstrap[..2], &[]).await;
+    let mut setup = bootstrap[2..].to_vec();
+    setup.extend([
+        ix(
+            accounts::InitializeSeasons {
+                authority: me,
+                program_state: pda::program_state(),
+                admin_log: pda::admin_log(),
+                season: pda::season(0),
+                season_tally: pda::season_tally(0),
+                config: pda::config(),
+                system_program,
+            },
+            instruction::InitializeSeasons {
+                duration: 180 * 86_400,
+                reward_amount: 1_000_000_000,
+                mint_cap: 1_000_000_000_000,
+                max_claims_per_farmer: 10,
+            },
+        ),
+        ix(
+            accounts::ConfigureRegion {
+                authority: me,
+                program_state: pda::program_state(),
+                admin_log: pda::admin_log(),
+                region_config: pda::region(REGION),
+                system_program,
+            },
+            instruction::ConfigureRegion {
+                region_code: REGION,
+                require_identity: false,
+            },
+        ),
+        ix(
+            accounts::ConfigureCircuit {
+                authority: me,
+                program_state: pda::program_state(),
+                admin_log: pda::admin_log(),
+                circuit_config: pda::circuit(CIRCUIT),
+                system_program,
+            },
+            instruction::ConfigureCircuit {
+                circuit_id: CIRCUIT,
+                active: true,
+                green_enabled: true,
+                water_amount: 0,
+                soil_amount: 0,
+                methodology_id: 0,
+                proof_system: ProofSystem::Groth16,
+            },
+        ),
+    ]);
+    send(&mut banks, &authority, &setup, &[]).await;
+    send(
+        &mut banks,
+        &authority,
+        &[ix(
+            accounts::SetGroth16VerifyingKey {
+                authority: me,
+                program_state: pda::program_state(),
+                admin_log: pda::admin_log(),
+                circuit_config: pda::circuit(CIRCUIT),
+                groth16_vk: pda::groth16_vk(CIRCUIT),
+                previous_groth16_vk: pda::previous_groth16_vk(CIRCUIT),
+                system_program,
+            },
+            instruction::SetGroth16VerifyingKey {
+                key: prover.verifying_key(),
+            },
+        )],
+        &[],
+    )
+    .await;
+
+    let farmer = Keypair::new();
+    let token_account = Keypair::new();
+    let rent = banks.get_rent().await.unwrap();
+    let token_len = anchor_spl::token::spl_token::state::Account::LEN;
+    send(
+        &mut banks,
+        &authority,
+        &[
+            system_instruction::transfer(&me, &farmer.pubkey(), 1_000_000_000),
+            ix(
+                accounts::RegisterFarmer {
+                    payer: me,
+                    farmer: farmer.pubkey(),
+                    program_state: pda::program_state(),
+                    personhood_verifier: None,
+                    personhood_record: None,
+                    referral_code: None,
+                    region_config: pda::region(REGION),
+                    farmer_profile: pda::farmer_profile(&farmer.pubkey()),
+                    config: pda::config(),
+                    green_mint: None,
+                    onboarding_vault: None,
+                    token_program: None,
+                    system_program,
+                },
+                instruction::RegisterFarmer {
+                    region_code: REGION,
+                    personhood_nullifier: [0; 32],
+                },
+            ),
+            system_instruction::create_account(
+                &me,
+                &token_account.pubkey(),
+                rent.minimum_balance(token_len),
+                token_len as u64,
+                &token_program,
+            ),
+            anchor_spl::token::spl_token::instruction::initialize_account3(
+                &token_program,
+                &token_account.pubkey(),
+                &pda::green_mint(),
+                &farmer.pubkey(),
+            )
+            .unwrap(),
+        ],
+        &[&farmer, &token_account],
+    )
+    .await;
+
+    // ── Prove and claim ──
+    let claim = prover
+        .prove(
+            &PracticeData {
+                compliance_status_hash: COMPLIANT_HASH.parse::<BigInt>().unwrap(),
+                expected_compliance_hash: COMPLIANT_HASH.parse::<BigInt>().unwrap(),
+                gps_latitude: 286_139,
+                gps_longitude: 772_090,
+            },
+            &ClaimContext {
+                circuit_id: CIRCUIT,
+                farmer: farmer.pubkey(),
+                parcel: Pubkey::new_unique(),
+                season: 0,
+                nonce: random_nonce(),
+            },
+        )
+        .unwrap();
+
+    let verify = ix(
+        accounts::VerifyAndMint {
+            payer: farmer.pubkey(),
+            claimant: farmer.pubkey(),
+            farmer: farmer.pubkey(),
+            delegation: None,
+            session_key: None,
+            relayer_info: None,
+            rent_pool: None,
+            program_state: pda::program_state(),
+            green_mint: pda::green_mint(),
+            farmer_token_account: token_account.pubkey(),
+            custodial_link: None,
+            payout_split: pda::payout_split(&claim.opening.parcel),
+            landowner_token_account: None,
+            farmer_profile: Some(pda::farmer_profile(&farmer.pubkey())),
+            claim_index: Some(pda::claim_index(&farmer.pubkey(), 0)),
+            config: pda::config(),
+            region_config: Some(pda::region(REGION)),
+            identity_attestation: None,
+            pm_kisan_attestation: None,
+            referral_code: None,
+            referrer_token_account: None,
+            onboarding_vault: None,
+            proof_record: pda::proof_record(&claim.commitment),
+            leaderboard: pda::leaderboard(),
+            season: pda::season(0),
+            season_tally: pda::season_tally(0),
+            mint_stats: pda::mint_stats(),
+            circuit_config: pda::circuit(CIRCUIT),
+            feature_flags: pda::feature_flags(),
+            pricing_config: None,
+            price_feed: None,
+            groth16_vk: Some(pda::groth16_vk(CIRCUIT)),
+            previous_groth16_vk: None,
+            plonk_vk: None,
+            previous_plonk_vk: None,
+            pre_commit: None,
+            verifier_program: None,
+            water_mint: None,
+            water_token_account: None,
+            soil_mint: None,
+            soil_token_account: None,
+            receipt_config: None,
+            receipt_authority: None,
+            receipt_tree_config: None,
+            receipt_merkle_tree: None,
+            log_wrapper: None,
+            compression_program: None,
+            bubblegum_program: None,
+            instructions_sysvar: None,
+            cpi_integrator: None,
+            memo_program: None,
+            token_program,
+            system_program,
+        },
+        instruction::VerifyAndMint {
+            payload: ProofPayload::groth16(&claim.proof, claim.public_signals),
+            compliance_commitment: claim.commitment,
+            opening: claim.opening,
+            evidence_cid: None,
+        },
+    );
+    send(
+        &mut banks,
+        &authority,
+        &[
+            ComputeBudgetInstruction::set_compute_unit_limit(kisan_depin::compute::VERIFY_AND_MINT),
+            verify,
+        ],
+        &[&farmer],
+    )
+    .await;
+
+    let record = banks
+        .get_account(pda::proof_record(&claim.commitment))
+        .await
+        .unwrap();
+    assert!(record.is_some(), "proof record was not created");
+}
@@ -0,0 +1,139 @@
+//! Account layouts, current version (`ACCOUNT_VERSION` 9). Accounts not
+//! yet migrated by `migrate_account` may be shorter and fail to decode.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::serde_bytes::{hex, pubkey};
+use crate::Discriminator;
+
+/// Points on the streak bonus curve
+pub const STREAK_CURVE_LEN: usize = 8;
+/// Length of a binary CIDv0 (sha2-256 multihash)
+pub const EVIDENCE_CID_LEN: usize = 34;
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ProgramState {
+    #[serde(with = "pubkey")]
+    pub authority: Pubkey,
+    #[serde(with = "pubkey")]
+    pub mint: Pubkey,
+    pub total_proofs_verified: u64,
+    pub total_tokens_minted: u64,
+    pub bump: u8,
+    pub mint_bump: u8,
+    pub require_personhood: bool,
+    #[serde(with = "pubkey")]
+    pub personhood_verifier: Pubkey,
+    pub referral_bonus: u64,
+    pub referral_budget_remaining: u64,
+    pub current_season: u32,
+    pub legacy_season_duration: i64,
+    pub legacy_season_reward_amount: u64,
+    pub legacy_season_mint_cap: u64,
+    pub legacy_season_max_claims_per_farmer: u32,
+    pub legacy_streak_curve: [u16; STREAK_CURVE_LEN],
+    pub water_mint_bump: u8,
+    pub soil_mint_bump: u8,
+    pub dynamic_pricing: bool,
+    pub total_burned: u64,
+    pub offtake_share_bps: u16,
+    pub total_offtake_delivered: u64,
+    pub next_serial: u64,
+    pub cross_chain_enabled: bool,
+    #[serde(with = "pubkey")]
+    pub wormhole_program: Pubkey,
+    pub require_pm_kisan: bool,
+    #[serde(with = "pubkey")]
+    pub pm_kisan_attester: Pubkey,
+    pub issuance_receipts: bool,
+    pub version: u8,
+    pub last_claim_id: u64,
+    pub devnet: bool,
+}
+
+impl Discriminator for ProgramState {
+    const DISCRIMINATOR: [u8; 8] = [77, 209, 137, 229, 149, 67, 167, 230];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct FarmerProfile {
+    #[serde(with = "pubkey")]
+    pub farmer: Pubkey,
+    pub region_code: u16,
+    #[serde(with = "pubkey")]
+    pub identity_attestation: Pubkey,
+    #[serde(with = "pubkey")]
+    pub personhood_record: Pubkey,
+    #[serde(with = "pubkey")]
+    pub referred_by: Pubkey,
+    pub badges_claimed: u8,
+    pub leaderboard_epoch: u32,
+    pub epoch_proofs: u64,
+    pub season_index: u32,
+    pub season_claims: u32,
+    pub streak: u32,
+    pub total_proofs: u64,
+    pub registered_at: i64,
+    pub last_claim_at: i64,
+    pub bump: u8,
+    #[serde(with = "pubkey")]
+    pub pm_kisan_attestation: Pubkey,
+    pub version: u8,
+    #[serde(with = "hex")]
+    pub contact_hash: [u8; 32],
+    pub profile_updates: u32,
+    pub compromised_at: i64,
+    #[serde(with = "pubkey")]
+    pub migrated_to: Pubkey,
+    pub onboarding_bonus: u64,
+    pub onboarding_deadline: i64,
+}
+
+impl Discriminator for FarmerProfile {
+    const DISCRIMINATOR: [u8; 8] = [167, 109, 11, 146, 241, 174, 172, 255];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ProofRecord {
+    #[serde(with = "hex")]
+    pub commitment: [u8; 32],
+    #[serde(with = "pubkey")]
+    pub farmer: Pubkey,
+    pub timestamp: i64,
+    pub verified: bool,
+    #[serde(with = "pubkey")]
+    pub rent_payer: Pubkey,
+    pub bump: u8,
+    pub season: u32,
+    pub vintage: u16,
+    pub methodology_id: u16,
+    pub serial_start: u64,
+    pub serial_end: u64,
+    #[serde(with = "hex")]
+    pub evidence_cid: [u8; EVIDENCE_CID_LEN],
+    pub version: u8,
+    pub claim_id: u64,
+}
+
+impl Discriminator for ProofRecord {
+    const DISCRIMINATOR: [u8; 8] = [237, 59, 155, 172, 204, 117, 87, 44];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Season {
+    pub index: u32,
+    pub start: i64,
+    pub end: i64,
+    pub reward_amount: u64,
+    pub mint_cap: u64,
+    pub max_claims_per_farmer: u32,
+    pub total_proofs: u64,
+    pub total_minted: u64,
+    pub bump: u8,
+}
+
+impl Discriminator for Season {
+    const DISCRIMINATOR: [u8; 8] = [76, 67, 93, 156, 180, 157, 248, 47];
+}
@@ -0,0 +1,29 @@
+//! Instruction arguments shared with off-chain provers and relayers.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::serde_bytes::hex;
+
+/// Groth16 proof points, uncompressed (G1 = x | y, G2 = x | y) or in
+/// alt_bn128 compressed form (x only, y's sign in the top bits).
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum ProofPoints {
+    Uncompressed {
+        #[serde(with = "hex")]
+        a: [u8; 64],
+        #[serde(with = "hex")]
+        b: [u8; 128],
+        #[serde(with = "hex")]
+        c: [u8; 64],
+    },
+    Compressed {
+        #[serde(with = "hex")]
+        a: [u8; 32],
+        #[serde(with = "hex")]
+        b: [u8; 64],
+        #[serde(with = "hex")]
+        c: [u8; 32],
+    },
+}
@@ -0,0 +1,118 @@
+//! Events the indexer materializes, as logged in `Program data:` lines.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+
+use crate::serde_bytes::{hex, pubkey};
+use crate::Discriminator;
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ProofMinted {
+    #[serde(with = "pubkey")]
+    pub farmer: Pubkey,
+    #[serde(with = "pubkey")]
+    pub claimant: Pubkey,
+    #[serde(with = "pubkey")]
+    pub payer: Pubkey,
+    #[serde(with = "hex")]
+    pub commitment: [u8; 32],
+    pub circuit_id: u16,
+    pub season: u32,
+    pub streak: u32,
+    pub amount: u64,
+    pub referral_bonus: u64,
+    pub serial_start: u64,
+    pub serial_end: u64,
+    pub claim_id: u64,
+}
+
+impl Discriminator for ProofMinted {
+    const DISCRIMINATOR: [u8; 8] = [109, 230, 47, 195, 30, 214, 224, 55];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ClaimSubmitted {
+    #[serde(with = "pubkey")]
+    pub claim: Pubkey,
+    #[serde(with = "pubkey")]
+    pub farmer: Pubkey,
+    #[serde(with = "hex")]
+    pub commitment: [u8; 32],
+    pub circuit_id: u16,
+}
+
+impl Discriminator for ClaimSubmitted {
+    const DISCRIMINATOR: [u8; 8] = [95, 1, 120, 227, 177, 240, 174, 52];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ClaimVerified {
+    #[serde(with = "pubkey")]
+    pub claim: Pubkey,
+    #[serde(with = "pubkey")]
+    pub verified_by: Pubkey,
+}
+
+impl Discriminator for ClaimVerified {
+    const DISCRIMINATOR: [u8; 8] = [90, 196, 170, 218, 88, 102, 26, 4];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct CreditsIssued {
+    #[serde(with = "pubkey")]
+    pub claim: Pubkey,
+    #[serde(with = "pubkey")]
+    pub farmer: Pubkey,
+    pub amount: u64,
+    pub serial_start: u64,
+    pub serial_end: u64,
+    pub claim_id: u64,
+}
+
+impl Discriminator for CreditsIssued {
+    const DISCRIMINATOR: [u8; 8] = [90, 165, 150, 63, 233, 97, 54, 90];
+}
+
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ClaimRetired {
+    #[serde(with = "pubkey")]
+    pub claim: Pubkey,
+    #[serde(with = "pubkey")]
+    pub retired_by: Pubkey,
+    pub amount: u64,
+}
+
+impl Discriminator for ClaimRetired {
+    const DISCRIMINATOR: [u8; 8] = [201, 203, 134, 41, 120, 126, 112, 125];
+}
+
+#[derive(
+    BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq,
+)]
+#[serde(rename_all = "snake_case")]
+pub enum BuyerActivityKind {
+    Purchase,
+    Retirement,
+}
+
+/// One change to a buyer profile, with the running totals after it.
+#[derive(BorshSerialize, BorshDeserialize, Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct BuyerActivity {
+    #[serde(with = "pubkey")]
+    pub buyer: Pubkey,
+    pub kind: BuyerActivityKind,
+    /// Listing bought from, or claim retired
+    #[serde(with = "pubkey")]
+    pub reference: Pubkey,
+    pub amount: u64,
+    pub usdc_paid: u64,
+    pub total_purchased: u64,
+    pub total_spent: u64,
+    pub total_retired: u64,
+    pub timestamp: i64,
+}
+
+impl Discriminator for BuyerActivity {
+    const DISCRIMINATOR: [u8; 8] = [248, 214, 19, 8, 109, 131, 174, 238];
+}
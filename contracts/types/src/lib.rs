@@ -0,0 +1,51 @@
+//! Layouts of the Kisan-DePIN accounts, events and instruction arguments
+//! that off-chain services read, for the indexer, SDK, CLI and gateway to
+//! share without linking the program or the Anchor runtime.
+//!
+//! Each type is Borsh-compatible with its `kisan_depin` counterpart, field
+//! for field, and derives serde for JSON. Anchor prefixes accounts and
+//! events with an 8-byte discriminator; [`decode_account`] and
+//! [`decode_event`] check and strip it. The program's `types` test fails
+//! if a layout or discriminator here drifts from the program's.
+
+pub mod accounts;
+pub mod args;
+pub mod events;
+mod serde_bytes;
+
+use borsh::BorshDeserialize;
+
+pub use accounts::{FarmerProfile, ProgramState, ProofRecord, Season};
+pub use args::ProofPoints;
+pub use events::{
+    BuyerActivity, BuyerActivityKind, ClaimRetired, ClaimSubmitted, ClaimVerified, CreditsIssued,
+    ProofMinted,
+};
+
+solana_program::declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
+
+/// Anchor's 8-byte type tag: the first bytes of
+/// `sha256("account:<Name>")` or `sha256("event:<Name>")`.
+pub trait Discriminator {
+    const DISCRIMINATOR: [u8; 8];
+}
+
+/// An account's data, as fetched, decoded as `T`. `None` if it's another
+/// account type or doesn't decode. Trailing bytes (unused space) are
+/// ignored, as the program does.
+pub fn decode_account<T: Discriminator + BorshDeserialize>(data: &[u8]) -> Option<T> {
+    decode(data)
+}
+
+/// One `Program data:` payload, base64-decoded, as event `T`.
+pub fn decode_event<T: Discriminator + BorshDeserialize>(data: &[u8]) -> Option<T> {
+    decode(data)
+}
+
+fn decode<T: Discriminator + BorshDeserialize>(data: &[u8]) -> Option<T> {
+    let (tag, mut body) = (data.get(..8)?, data.get(8..)?);
+    if tag != T::DISCRIMINATOR {
+        return None;
+    }
+    T::deserialize(&mut body).ok()
+}
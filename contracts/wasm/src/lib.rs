@@ -0,0 +1,420 @@
+// ============================================================
+// Kisan-DePIN wasm bindings
+// ============================================================
+//
+// The web and React-Native apps call these instead of
+// re-implementing byte layouts in TypeScript. Everything here
+// delegates to the program crate or `kisan-depin-client`, so
+// the apps and the program share one implementation of:
+//
+//   - PDA derivation (`pda*`)
+//   - commitments (`commitment`)
+//   - public-signal and proof encoding (`encode*`)
+//   - `verify_and_mint` serialization (`verifyAndMintInstruction`)
+//
+// Keys cross the boundary as base58 strings and byte strings as
+// `Uint8Array`. Build with `wasm-pack build --target web` (or
+// `--target bundler` for React Native / Metro).
+// ============================================================
+
+use anchor_lang::prelude::Pubkey;
+use anchor_lang::{AnchorSerialize, InstructionData, ToAccountMetas};
+use kisan_depin::commitment::CommitmentOpening;
+use kisan_depin::payload::ProofPayload;
+use kisan_depin::{accounts, compute, instruction, ProofPoints};
+use kisan_depin_client::{pda, snarkjs};
+use serde::{Deserialize, Serialize};
+use wasm_bindgen::prelude::*;
+
+type Result<T> = std::result::Result<T, JsError>;
+
+fn pubkey(s: &str) -> Result<Pubkey> {
+    s.parse()
+        .map_err(|_| JsError::new(&format!("invalid pubkey {s}")))
+}
+
+fn bytes32(bytes: &[u8], what: &str) -> Result<[u8; 32]> {
+    bytes
+        .try_into()
+        .map_err(|_| JsError::new(&format!("{what} must be 32 bytes")))
+}
+
+fn js(e: impl std::fmt::Display) -> JsError {
+    JsError::new(&e.to_string())
+}
+
+// ─────────────────────────────────────────────────────────────
+// PDAs
+// ─────────────────────────────────────────────────────────────
+
+#[wasm_bindgen(js_name = programId)]
+pub fn program_id() -> String {
+    kisan_depin::ID.to_string()
+}
+
+#[wasm_bindgen(js_name = pdaProgramState)]
+pub fn pda_program_state() -> String {
+    pda::program_state().to_string()
+}
+
+#[wasm_bindgen(js_name = pdaGreenMint)]
+pub fn pda_green_mint() -> String {
+    pda::green_mint().to_string()
+}
+
+#[wasm_bindgen(js_name = pdaWaterMint)]
+pub fn pda_water_mint() -> String {
+    pda::water_mint().to_string()
+}
+
+#[wasm_bindgen(js_name = pdaSoilMint)]
+pub fn pda_soil_mint() -> String {
+    pda::soil_mint().to_string()
+}
+
+#[wasm_bindgen(js_name = pdaFeatureFlags)]
+pub fn pda_feature_flags() -> String {
+    pda::feature_flags().to_string()
+}
+
+#[wasm_bindgen(js_name = pdaConfig)]
+pub fn pda_config() -> String {
+    pda::config().to_string()
+}
+
+#[wasm_bindgen(js_name = pdaLeaderboard)]
+pub fn pda_leaderboard() -> String {
+    pda::leaderboard().to_string()
+}
+
+#[wasm_bindgen(js_name = pdaMintStats)]
+pub fn pda_mint_stats() -> String {
+    pda::mint_stats().to_string()
+}
+
+#[wasm_bindgen(js_name = pdaSeason)]
+pub fn pda_season(index: u32) -> String {
+    pda::season(index).to_string()
+}
+
+#[wasm_bindgen(js_name = pdaRegion)]
+pub fn pda_region(region_code: u16) -> String {
+    pda::region(region_code).to_string()
+}
+
+#[wasm_bindgen(js_name = pdaCircuit)]
+pub fn pda_circuit(circuit_id: u16) -> String {
+    pda::circuit(circuit_id).to_string()
+}
+
+#[wasm_bindgen(js_name = pdaFarmerProfile)]
+pub fn pda_farmer_profile(farmer: &str) -> Result<String> {
+    Ok(pda::farmer_profile(&pubkey(farmer)?).to_string())
+}
+
+#[wasm_bindgen(js_name = pdaParcel)]
+pub fn pda_parcel(farmer: &str, parcel_id: u32) -> Result<String> {
+    Ok(pda::parcel(&pubkey(farmer)?, parcel_id).to_string())
+}
+
+#[wasm_bindgen(js_name = pdaProofRecord)]
+pub fn pda_proof_record(commitment: &[u8]) -> Result<String> {
+    Ok(pda::proof_record(&bytes32(commitment, "commitment")?).to_string())
+}
+
+#[wasm_bindgen(js_name = pdaClaim)]
+pub fn pda_claim(commitment: &[u8]) -> Result<String> {
+    Ok(pda::claim(&bytes32(commitment, "commitment")?).to_string())
+}
+
+#[wasm_bindgen(js_name = pdaDelegation)]
+pub fn pda_delegation(farmer: &str, delegate: &str) -> Result<String> {
+    Ok(pda::delegation(&pubkey(farmer)?, &pubkey(delegate)?).to_string())
+}
+
+#[wasm_bindgen(js_name = pdaSessionKey)]
+pub fn pda_session_key(farmer: &str, ephemeral: &str) -> Result<String> {
+    Ok(pda::session_key(&pubkey(farmer)?, &pubkey(ephemeral)?).to_string())
+}
+
+#[wasm_bindgen(js_name = pdaReferral)]
+pub fn pda_referral(code: &[u8]) -> Result<String> {
+    let code: [u8; 8] = code
+        .try_into()
+        .map_err(|_| JsError::new("referral code must be 8 bytes"))?;
+    Ok(pda::referral(&code).to_string())
+}
+
+// ─────────────────────────────────────────────────────────────
+// Commitments and encodings
+// ─────────────────────────────────────────────────────────────
+
+/// The claim commitment for an opening, exactly as `verify_and_mint`
+/// recomputes it. `scheme` is `SCHEME_SHA256` (1) or `SCHEME_POSEIDON` (2).
+#[wasm_bindgen]
+pub fn commitment(
+    scheme: u8,
+    circuit_id: u16,
+    farmer: &str,
+    parcel: &str,
+    season: u32,
+    nonce: &[u8],
+) -> Result<Vec<u8>> {
+    let opening = CommitmentOpening {
+        scheme,
+        parcel: pubkey(parcel)?,
+        nonce: bytes32(nonce, "nonce")?,
+    };
+    Ok(opening
+        .commit(circuit_id, &pubkey(farmer)?, season)
+        .map_err(js)?
+        .to_vec())
+}
+
+/// A decimal field element as 32 big-endian bytes.
+#[wasm_bindgen(js_name = encodeField)]
+pub fn encode_field(decimal: &str) -> Result<Vec<u8>> {
+    Ok(snarkjs::field(decimal).map_err(js)?.to_vec())
+}
+
+/// snarkjs `public.json` as the `public_signals` bytes.
+#[wasm_bindgen(js_name = encodePublicSignals)]
+pub fn encode_public_signals(public_json: &str) -> Result<Vec<u8>> {
+    snarkjs::public_signals(public_json).map_err(js)
+}
+
+fn proof_points(proof_json: &str, compressed: bool) -> Result<ProofPoints> {
+    let proof = snarkjs::groth16_proof(proof_json).map_err(js)?;
+    if compressed {
+        snarkjs::compress(proof).map_err(js)
+    } else {
+        Ok(proof)
+    }
+}
+
+/// snarkjs `proof.json` as the Borsh-encoded `ProofPoints` argument.
+#[wasm_bindgen(js_name = encodeProof)]
+pub fn encode_proof(proof_json: &str, compressed: bool) -> Result<Vec<u8>> {
+    proof_points(proof_json, compressed)?
+        .try_to_vec()
+        .map_err(js)
+}
+
+/// Compute-unit limit to request for a `verify_and_mint` transaction.
+#[wasm_bindgen(js_name = verifyAndMintComputeUnits)]
+pub fn verify_and_mint_compute_units(compressed: bool) -> u32 {
+    if compressed {
+        compute::VERIFY_AND_MINT_COMPRESSED
+    } else {
+        compute::VERIFY_AND_MINT
+    }
+}
+
+// ─────────────────────────────────────────────────────────────
+// Instructions
+// ─────────────────────────────────────────────────────────────
+
+/// Accounts of a farmer's own claim. Mints, PDAs and programs are
+/// derived; the rest comes from the app's reads of on-chain state,
+/// as in `kisan_depin_client::claim`.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ClaimAccounts {
+    farmer: String,
+    farmer_token_account: String,
+    /// `farmer_token_account` is owned by the farmer's linked custodial
+    /// wallet rather than the farmer
+    #[serde(default)]
+    custodial: bool,
+    /// The landowner's $GREEN account, when the claim's parcel splits its
+    /// payout
+    landowner_token_account: Option<String>,
+    region_code: u16,
+    /// The profile's `total_proofs`, which picks the claim index page
+    total_proofs: u64,
+    season: u32,
+    circuit_id: u16,
+    identity_attestation: Option<String>,
+    pm_kisan_attestation: Option<String>,
+    referral_code: Option<String>,
+    referrer_token_account: Option<String>,
+    /// The profile's `onboarding_bonus` is nonzero
+    #[serde(default)]
+    onboarding_bonus: bool,
+    /// The farmer ran `pre_commit` for this claim's commitment
+    #[serde(default)]
+    pre_committed: bool,
+    /// Oracle feed, when the program prices rewards dynamically
+    price_feed: Option<String>,
+    water_token_account: Option<String>,
+    soil_token_account: Option<String>,
+    /// Receipt tree, when issuance receipts are on
+    receipt_merkle_tree: Option<String>,
+    /// Attach a memo describing the claim, for explorers
+    #[serde(default)]
+    memo: bool,
+}
+
+/// Opening the app chose when it built the commitment.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Opening {
+    scheme: u8,
+    parcel: String,
+    #[serde(with = "serde_bytes")]
+    nonce: Vec<u8>,
+}
+
+/// Same shape as web3.js `TransactionInstruction` fields, keys as strings.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsInstruction {
+    program_id: String,
+    keys: Vec<JsAccountMeta>,
+    #[serde(with = "serde_bytes")]
+    data: Vec<u8>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JsAccountMeta {
+    pubkey: String,
+    is_signer: bool,
+    is_writable: bool,
+}
+
+fn optional(key: &Option<String>) -> Result<Option<Pubkey>> {
+    key.as_deref().map(pubkey).transpose()
+}
+
+/// `verify_and_mint` for the farmer in `accounts`, who signs and pays.
+///
+/// `accounts` is a `ClaimAccounts` object and `opening` an `Opening`;
+/// `evidenceCid` is the 34-byte IPFS multihash of the source evidence.
+#[wasm_bindgen(js_name = verifyAndMintInstruction)]
+pub fn verify_and_mint_instruction(
+    accounts: JsValue,
+    proof_json: &str,
+    public_json: &str,
+    opening: JsValue,
+    compressed: bool,
+    evidence_cid: Option<Vec<u8>>,
+) -> Result<JsValue> {
+    let a: ClaimAccounts = serde_wasm_bindgen::from_value(accounts)?;
+    let o: Opening = serde_wasm_bindgen::from_value(opening)?;
+    let farmer = pubkey(&a.farmer)?;
+    let parcel = pubkey(&o.parcel)?;
+
+    let public_signals = snarkjs::public_signals(public_json).map_err(js)?;
+    let commitment = bytes32(
+        public_signals
+            .get(..32)
+            .ok_or_else(|| JsError::new("public.json has no inputs"))?,
+        "commitment",
+    )?;
+    let evidence_cid = evidence_cid
+        .map(|cid| {
+            <[u8; 34]>::try_from(cid.as_slice())
+                .map_err(|_| JsError::new("evidence CID must be 34 bytes"))
+        })
+        .transpose()?;
+
+    let water_token_account = optional(&a.water_token_account)?;
+    let soil_token_account = optional(&a.soil_token_account)?;
+    let price_feed = optional(&a.price_feed)?;
+    let receipt_tree = optional(&a.receipt_merkle_tree)?;
+    let receipt = |key: Pubkey| receipt_tree.map(|_| key);
+    let metas = accounts::VerifyAndMint {
+        payer: farmer,
+        claimant: farmer,
+        farmer,
+        delegation: None,
+        session_key: None,
+        relayer_info: None,
+        rent_pool: None,
+        program_state: pda::program_state(),
+        green_mint: pda::green_mint(),
+        farmer_token_account: pubkey(&a.farmer_token_account)?,
+        custodial_link: a.custodial.then(|| pda::custodial_link(&farmer)),
+        payout_split: pda::payout_split(&parcel),
+        landowner_token_account: optional(&a.landowner_token_account)?,
+        farmer_profile: Some(pda::farmer_profile(&farmer)),
+        claim_index: Some(pda::claim_index(
+            &farmer,
+            a.total_proofs / kisan_depin::CLAIM_INDEX_PAGE_LEN as u64,
+        )),
+        config: pda::config(),
+        region_config: Some(pda::region(a.region_code)),
+        identity_attestation: optional(&a.identity_attestation)?,
+        pm_kisan_attestation: optional(&a.pm_kisan_attestation)?,
+        referral_code: optional(&a.referral_code)?,
+        referrer_token_account: optional(&a.referrer_token_account)?,
+        onboarding_vault: a.onboarding_bonus.then(pda::onboarding_vault),
+        proof_record: pda::proof_record(&commitment),
+        leaderboard: pda::leaderboard(),
+        season: pda::season(a.season),
+        season_tally: pda::season_tally(a.season),
+        mint_stats: pda::mint_stats(),
+        circuit_config: pda::circuit(a.circuit_id),
+        feature_flags: pda::feature_flags(),
+        pricing_config: price_feed.map(|_| pda::pricing_config()),
+        price_feed,
+        groth16_vk: Some(pda::groth16_vk(a.circuit_id)),
+        previous_groth16_vk: None,
+        plonk_vk: None,
+        previous_plonk_vk: None,
+        pre_commit: a.pre_committed.then(|| {
+            pda::pre_commit(&farmer, &kisan_depin::PreCommit::hash_of(&commitment, &farmer))
+        }),
+        verifier_program: None,
+        water_mint: water_token_account.map(|_| pda::water_mint()),
+        water_token_account,
+        soil_mint: soil_token_account.map(|_| pda::soil_mint()),
+        soil_token_account,
+        receipt_config: receipt(pda::receipt_config()),
+        receipt_authority: receipt(pda::receipt_authority()),
+        receipt_tree_config: receipt_tree.map(|tree| {
+            Pubkey::find_program_address(
+                &[tree.as_ref()],
+                &kisan_depin::bubblegum::BUBBLEGUM_PROGRAM_ID,
+            )
+            .0
+        }),
+        receipt_merkle_tree: receipt_tree,
+        log_wrapper: receipt(kisan_depin::bubblegum::NOOP_PROGRAM_ID),
+        compression_program: receipt(kisan_depin::bubblegum::COMPRESSION_PROGRAM_ID),
+        bubblegum_program: receipt(kisan_depin::bubblegum::BUBBLEGUM_PROGRAM_ID),
+        instructions_sysvar: None,
+        cpi_integrator: None,
+        memo_program: a.memo.then_some(kisan_depin::memo::MEMO_PROGRAM_ID),
+        token_program: anchor_spl::token::ID,
+        system_program: anchor_lang::system_program::ID,
+    }
+    .to_account_metas(None);
+
+    let data = instruction::VerifyAndMint {
+        payload: ProofPayload::groth16(&proof_points(proof_json, compressed)?, public_signals),
+        compliance_commitment: commitment,
+        opening: CommitmentOpening {
+            scheme: o.scheme,
+            parcel,
+            nonce: bytes32(&o.nonce, "nonce")?,
+        },
+        evidence_cid,
+    }
+    .data();
+
+    let ix = JsInstruction {
+        program_id: kisan_depin::ID.to_string(),
+        keys: metas
+            .into_iter()
+            .map(|m| JsAccountMeta {
+                pubkey: m.pubkey.to_string(),
+                is_signer: m.is_signer,
+                is_writable: m.is_writable,
+            })
+            .collect(),
+        data,
+    };
+    Ok(serde_wasm_bindgen::to_value(&ix)?)
+}
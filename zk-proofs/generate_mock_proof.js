@@ -19,7 +19,7 @@ console.log("");
 console.log("[1/4] Validating private inputs...");
 console.log(`  Compliance Hash: ${input.complianceStatusHash.substring(0, 20)}...`);
 console.log(`  GPS (encoded):   ${input.gpsLatitude}, ${input.gpsLongitude}`);
-console.log(`  Wallet Hash:     ${input.farmerWalletHash.substring(0, 20)}...`);
+console.log(`  Farmer:          ${input.farmerHi.substring(0, 20)}...`);
 console.log(`  Nonce:           ${input.nonce}`);
 
 // Verify compliance hash matches expected
@@ -35,10 +35,12 @@ if (!statusMatch) {
 // ── Step 2: Compute commitment (simulated Poseidon hash) ──
 console.log("\n[2/4] Computing commitment hash (simulated Poseidon)...");
 const commitmentInput = [
-    input.complianceStatusHash,
-    input.gpsLatitude,
-    input.gpsLongitude,
-    input.farmerWalletHash,
+    input.circuitId,
+    input.farmerHi,
+    input.farmerLo,
+    input.parcelHi,
+    input.parcelLo,
+    input.season,
     input.nonce,
 ].join("|");
 
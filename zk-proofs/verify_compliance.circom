@@ -9,19 +9,24 @@
 //   - The raw AI analysis hash (data protection)
 //
 // Public Inputs (visible on-chain):
-//   - complianceCommitment: Poseidon hash of all private inputs
+//   - complianceCommitment: Poseidon commitment to the claim
+//   - expectedComplianceHash: Known hash of "COMPLIANT"
 //
 // Private Inputs (known only to prover):
 //   - complianceStatusHash: Hash of "COMPLIANT" from AI system
 //   - gpsLatitude: GPS latitude * 10000 (integer representation)  
 //   - gpsLongitude: GPS longitude * 10000 (integer representation)
-//   - farmerWalletHash: Hash of farmer's Solana wallet pubkey
-//   - nonce: Random nonce for uniqueness
+//   - circuitId, season: Circuit and season the claim is made under
+//   - farmerHi, farmerLo: Farmer's wallet pubkey, as 16-byte halves
+//   - parcelHi, parcelLo: Parcel pubkey, as 16-byte halves
+//   - nonce: Random nonce for uniqueness (< 2^253)
 //
 // The circuit verifies:
 //   1. The compliance status hash matches the expected "COMPLIANT" hash
 //   2. GPS coordinates are within valid range
-//   3. The commitment is correctly computed from all private inputs
+//   3. The commitment is the program's Poseidon commitment scheme
+//      (see contracts/.../commitment.rs), which verify_and_mint
+//      recomputes from the farmer, circuit and season it pays
 // ============================================================
 
 pragma circom 2.1.0;
@@ -38,7 +43,12 @@ template VerifyCompliance() {
     signal input complianceStatusHash;   // Poseidon("COMPLIANT") 
     signal input gpsLatitude;            // lat * 10000 (e.g., 28.6139 → 286139)
     signal input gpsLongitude;           // lng * 10000 (e.g., 77.2090 → 772090)
-    signal input farmerWalletHash;       // Poseidon(wallet_pubkey_bytes)
+    signal input circuitId;              // CircuitConfig the claim is paid under
+    signal input season;                 // Season index
+    signal input farmerHi;               // farmer pubkey bytes [0..16)
+    signal input farmerLo;               // farmer pubkey bytes [16..32)
+    signal input parcelHi;               // parcel pubkey bytes [0..16)
+    signal input parcelLo;               // parcel pubkey bytes [16..32)
     signal input nonce;                  // Random nonce for replay protection
 
     // ── Public Inputs ──
@@ -78,14 +88,19 @@ template VerifyCompliance() {
     lngCheck.out === 1;
 
     // ── Step 3: Compute commitment hash ──
-    // commitment = Poseidon(statusHash, lat, lng, walletHash, nonce)
-    // This single hash commits to ALL private inputs
-    component hasher = Poseidon(5);
-    hasher.inputs[0] <== complianceStatusHash;
-    hasher.inputs[1] <== gpsLatitude;
-    hasher.inputs[2] <== gpsLongitude;
-    hasher.inputs[3] <== farmerWalletHash;
-    hasher.inputs[4] <== nonce;
+    // commitment = Poseidon(domainTag, circuitId, farmerHi, farmerLo,
+    //                       parcelHi, parcelLo, season, nonce)
+    // domainTag is "kisan-depin:commitment" as a big-endian integer,
+    // the program's POSEIDON_DOMAIN_TAG
+    component hasher = Poseidon(8);
+    hasher.inputs[0] <== 40187569228396564149369066897910947555038373089734260;
+    hasher.inputs[1] <== circuitId;
+    hasher.inputs[2] <== farmerHi;
+    hasher.inputs[3] <== farmerLo;
+    hasher.inputs[4] <== parcelHi;
+    hasher.inputs[5] <== parcelLo;
+    hasher.inputs[6] <== season;
+    hasher.inputs[7] <== nonce;
 
     complianceCommitment <== hasher.out;
 }